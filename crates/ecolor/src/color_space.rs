@@ -0,0 +1,466 @@
+use crate::{
+    gamma_u8_from_linear_f32, linear_f32_from_gamma_u8, linear_f32_from_linear_u8,
+    linear_u8_from_linear_f32, Color32,
+};
+
+/// The color space of the RGB values of an image.
+///
+/// egui paints everything in [`ColorSpace::Srgb`].
+/// Images in other color spaces needs to be converted,
+/// or they will look oversaturated or washed out.
+///
+/// Renderers that can output to a wide gamut display (e.g. `egui-wgpu` with its
+/// `output_color_space` set to [`ColorSpace::DisplayP3`]) convert to the output color space instead,
+/// so that Display P3 images keep their saturated colors.
+///
+/// See [`Self::convert_pixels`] and [`Self::from_icc_profile`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ColorSpace {
+    /// sRGB primaries and transfer function.
+    ///
+    /// This is what most images and all of egui use.
+    #[default]
+    Srgb,
+
+    /// The wide gamut Display P3 primaries, with the sRGB transfer function.
+    ///
+    /// Used by photos from many phones and cameras.
+    DisplayP3,
+
+    /// sRGB primaries, but with linear values (no transfer function).
+    LinearSrgb,
+}
+
+/// Linear Display P3 to linear sRGB.
+const P3_TO_SRGB: [[f32; 3]; 3] = [
+    [1.224_940_1, -0.224_940_4, 0.0],
+    [-0.042_056_9, 1.042_057_1, 0.0],
+    [-0.019_637_6, -0.078_636_1, 1.098_273_5],
+];
+
+/// Linear sRGB to linear Display P3.
+const SRGB_TO_P3: [[f32; 3]; 3] = [
+    [0.822_462_1, 0.177_538, 0.0],
+    [0.033_194_1, 0.966_805_8, 0.0],
+    [0.017_082_7, 0.072_397_4, 0.910_519_9],
+];
+
+/// Number of entries in the lookup table used when encoding linear values.
+const ENCODE_TABLE_SIZE: usize = 1 << 14;
+
+impl ColorSpace {
+    /// u8 value -> linear [0, 1].
+    fn linear_from_u8(self, value: u8) -> f32 {
+        match self {
+            Self::Srgb | Self::DisplayP3 => linear_f32_from_gamma_u8(value),
+            Self::LinearSrgb => linear_f32_from_linear_u8(value),
+        }
+    }
+
+    /// linear [0, 1] -> u8 value (clamped).
+    fn u8_from_linear(self, linear: f32) -> u8 {
+        match self {
+            Self::Srgb | Self::DisplayP3 => gamma_u8_from_linear_f32(linear),
+            Self::LinearSrgb => linear_u8_from_linear_f32(linear.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Converts linear values in this color space to linear values in the `target` color space.
+    fn gamut_matrix(self, target: Self) -> Option<[[f32; 3]; 3]> {
+        match (self, target) {
+            (Self::DisplayP3, Self::Srgb | Self::LinearSrgb) => Some(P3_TO_SRGB),
+            (Self::Srgb | Self::LinearSrgb, Self::DisplayP3) => Some(SRGB_TO_P3),
+            _ => None, // same primaries
+        }
+    }
+
+    /// Convert a color in this color space to the `target` color space.
+    ///
+    /// Colors outside the gamut of `target` are clamped.
+    /// Alpha is unchanged.
+    ///
+    /// Use [`Self::convert_pixels`] to convert many colors at once.
+    pub fn convert_color(self, color: Color32, target: Self) -> Color32 {
+        let mut pixels = [color];
+        self.convert_pixels(&mut pixels, target);
+        pixels[0]
+    }
+
+    /// Convert pixels in this color space to the `target` color space.
+    ///
+    /// Colors outside the gamut of `target` are clamped.
+    /// Alpha is unchanged.
+    pub fn convert_pixels(self, pixels: &mut [Color32], target: Self) {
+        if self == target {
+            return;
+        }
+
+        let decode: Vec<f32> = (0..=255).map(|value| self.linear_from_u8(value)).collect();
+        let matrix = self.gamut_matrix(target);
+
+        // Encoding with `powf` is slow, so for big images we use a lookup table:
+        let encode_table: Vec<u8> = if ENCODE_TABLE_SIZE < pixels.len() {
+            (0..ENCODE_TABLE_SIZE)
+                .map(|i| target.u8_from_linear(i as f32 / (ENCODE_TABLE_SIZE - 1) as f32))
+                .collect()
+        } else {
+            vec![]
+        };
+        let encode = |linear: f32| {
+            if encode_table.is_empty() {
+                target.u8_from_linear(linear)
+            } else {
+                let index = (linear.clamp(0.0, 1.0) * (ENCODE_TABLE_SIZE - 1) as f32).round();
+                encode_table[index as usize]
+            }
+        };
+
+        for pixel in pixels {
+            let [r, g, b, a] = pixel.to_array();
+            let rgb = [decode[r as usize], decode[g as usize], decode[b as usize]];
+
+            // Since premultiplication happens in linear space, and the gamut conversion is linear,
+            // we don't need to unmultiply the colors first.
+            let rgb = matrix.map_or(rgb, |m| {
+                let max = if a == 0 { 1.0 } else { a as f32 / 255.0 };
+                [0, 1, 2].map(|row| {
+                    (m[row][0] * rgb[0] + m[row][1] * rgb[1] + m[row][2] * rgb[2]).clamp(0.0, max)
+                })
+            });
+
+            *pixel =
+                Color32::from_rgba_premultiplied(encode(rgb[0]), encode(rgb[1]), encode(rgb[2]), a);
+        }
+    }
+
+    /// Find the color space described by an [ICC profile](https://www.color.org/icc_specs2.xalter),
+    /// e.g. one embedded in a JPEG or PNG file.
+    ///
+    /// Returns `None` if the profile could not be parsed,
+    /// or if it describes a color space that is not one of the [`ColorSpace`] variants
+    /// (e.g. Adobe RGB, or a CMYK or grayscale profile).
+    pub fn from_icc_profile(profile: &[u8]) -> Option<Self> {
+        let profile = icc::Profile::parse(profile)?;
+
+        let srgb_transfer = crate::linear_from_gamma;
+        let linear_transfer = |x: f32| x;
+
+        let color_space = if profile.has_primaries(&icc::SRGB_PRIMARIES) {
+            if profile.has_transfer(srgb_transfer) {
+                Self::Srgb
+            } else if profile.has_transfer(linear_transfer) {
+                Self::LinearSrgb
+            } else {
+                return None;
+            }
+        } else if profile.has_primaries(&icc::DISPLAY_P3_PRIMARIES)
+            && profile.has_transfer(srgb_transfer)
+        {
+            Self::DisplayP3
+        } else {
+            return None;
+        };
+        Some(color_space)
+    }
+}
+
+/// Just enough of the ICC profile format to identify matrix/TRC RGB profiles.
+mod icc {
+    /// `rXYZ`, `gXYZ` and `bXYZ` of the sRGB profile (D50 adapted).
+    pub const SRGB_PRIMARIES: [[f32; 3]; 3] = [
+        [0.436_07, 0.222_49, 0.013_92],
+        [0.385_15, 0.716_87, 0.097_08],
+        [0.143_07, 0.060_61, 0.714_10],
+    ];
+
+    /// `rXYZ`, `gXYZ` and `bXYZ` of the Display P3 profile (D50 adapted).
+    pub const DISPLAY_P3_PRIMARIES: [[f32; 3]; 3] = [
+        [0.515_12, 0.241_19, -0.001_05],
+        [0.291_98, 0.692_25, 0.041_89],
+        [0.157_10, 0.066_57, 0.784_07],
+    ];
+
+    /// A tone reproduction curve, mapping encoded values to linear values.
+    #[derive(Debug)]
+    pub enum Curve {
+        Gamma(f32),
+
+        /// Evenly spaced samples, linearly interpolated.
+        Table(Vec<f32>),
+
+        /// The parameters `[g, a, b, c, d, e, f]` of an ICC `parametricCurveType`.
+        Parametric([f32; 7]),
+    }
+
+    impl Curve {
+        pub fn eval(&self, x: f32) -> f32 {
+            match self {
+                Self::Gamma(gamma) => x.powf(*gamma),
+                Self::Table(table) => {
+                    let pos = x * (table.len() - 1) as f32;
+                    let i = (pos.floor() as usize).min(table.len() - 2);
+                    let t = pos - i as f32;
+                    table[i] + t * (table[i + 1] - table[i])
+                }
+                Self::Parametric([g, a, b, c, d, e, f]) => {
+                    if x < *d {
+                        c * x + f
+                    } else {
+                        (a * x + b).max(0.0).powf(*g) + e
+                    }
+                }
+            }
+        }
+    }
+
+    pub struct Profile {
+        /// The XYZ of the red, green and blue primaries.
+        primaries: [[f32; 3]; 3],
+
+        /// The red, green and blue tone reproduction curves.
+        curves: [Curve; 3],
+    }
+
+    impl Profile {
+        pub fn parse(data: &[u8]) -> Option<Self> {
+            const HEADER_SIZE: usize = 128;
+
+            if data.get(16..20)? != b"RGB " {
+                return None; // e.g. CMYK or grayscale
+            }
+
+            let tag_count = read_u32(data, HEADER_SIZE)? as usize;
+            let tag = |signature: &[u8; 4]| -> Option<&[u8]> {
+                (0..tag_count.min(1024)).find_map(|i| {
+                    let entry = HEADER_SIZE + 4 + 12 * i;
+                    if data.get(entry..entry + 4)? != signature {
+                        return None;
+                    }
+                    let offset = read_u32(data, entry + 4)? as usize;
+                    let size = read_u32(data, entry + 8)? as usize;
+                    data.get(offset..offset.checked_add(size)?)
+                })
+            };
+
+            let primaries = [
+                parse_xyz(tag(b"rXYZ")?)?,
+                parse_xyz(tag(b"gXYZ")?)?,
+                parse_xyz(tag(b"bXYZ")?)?,
+            ];
+            let curves = [
+                parse_curve(tag(b"rTRC")?)?,
+                parse_curve(tag(b"gTRC")?)?,
+                parse_curve(tag(b"bTRC")?)?,
+            ];
+
+            Some(Self { primaries, curves })
+        }
+
+        pub fn has_primaries(&self, primaries: &[[f32; 3]; 3]) -> bool {
+            const TOLERANCE: f32 = 0.005;
+            self.primaries
+                .iter()
+                .flatten()
+                .zip(primaries.iter().flatten())
+                .all(|(a, b)| (a - b).abs() < TOLERANCE)
+        }
+
+        /// Are all three curves close to the given transfer function?
+        pub fn has_transfer(&self, transfer: impl Fn(f32) -> f32) -> bool {
+            const TOLERANCE: f32 = 0.01;
+            self.curves.iter().all(|curve| {
+                (0..=10).all(|i| {
+                    let x = i as f32 / 10.0;
+                    (curve.eval(x) - transfer(x)).abs() < TOLERANCE
+                })
+            })
+        }
+    }
+
+    fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+        Some(u16::from_be_bytes(
+            data.get(offset..offset + 2)?.try_into().ok()?,
+        ))
+    }
+
+    fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+        Some(u32::from_be_bytes(
+            data.get(offset..offset + 4)?.try_into().ok()?,
+        ))
+    }
+
+    /// `s15Fixed16Number`
+    fn read_fixed(data: &[u8], offset: usize) -> Option<f32> {
+        Some(read_u32(data, offset)? as i32 as f32 / 65536.0)
+    }
+
+    fn parse_xyz(data: &[u8]) -> Option<[f32; 3]> {
+        if data.get(0..4)? != b"XYZ " {
+            return None;
+        }
+        Some([
+            read_fixed(data, 8)?,
+            read_fixed(data, 12)?,
+            read_fixed(data, 16)?,
+        ])
+    }
+
+    fn parse_curve(data: &[u8]) -> Option<Curve> {
+        match data.get(0..4)? {
+            b"curv" => {
+                let count = read_u32(data, 8)? as usize;
+                match count {
+                    0 => Some(Curve::Gamma(1.0)),
+                    1 => Some(Curve::Gamma(read_u16(data, 12)? as f32 / 256.0)),
+                    _ => {
+                        let table = (0..count)
+                            .map(|i| Some(read_u16(data, 12 + 2 * i)? as f32 / 65535.0))
+                            .collect::<Option<Vec<f32>>>()?;
+                        Some(Curve::Table(table))
+                    }
+                }
+            }
+            b"para" => {
+                let function_type = read_u16(data, 8)?;
+                let num_params = match function_type {
+                    0 => 1,
+                    1 => 3,
+                    2 => 4,
+                    3 => 5,
+                    4 => 7,
+                    _ => return None,
+                };
+                let mut params = [0.0; 7];
+                for (i, param) in params.iter_mut().enumerate().take(num_params) {
+                    *param = read_fixed(data, 12 + 4 * i)?;
+                }
+                let [g, a, b, c, d, e, f] = params;
+                // Convert all function types to the most general one (type 4):
+                let params = match function_type {
+                    0 => [g, 1.0, 0.0, 0.0, f32::NEG_INFINITY, 0.0, 0.0],
+                    1 => [g, a, b, 0.0, -b / a, 0.0, 0.0],
+                    2 => [g, a, b, 0.0, -b / a, c, c],
+                    3 => [g, a, b, c, d, 0.0, 0.0],
+                    _ => [g, a, b, c, d, e, f],
+                };
+                Some(Curve::Parametric(params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a minimal ICC profile with the given primaries and a parametric curve.
+    fn icc_profile(primaries: [[f32; 3]; 3], curve_params: &[f32]) -> Vec<u8> {
+        let fixed = |value: f32| ((value * 65536.0).round() as i32).to_be_bytes();
+
+        let mut tags: Vec<(&[u8; 4], Vec<u8>)> = vec![];
+        for (signature, xyz) in [b"rXYZ", b"gXYZ", b"bXYZ"].into_iter().zip(primaries) {
+            let mut data = b"XYZ \0\0\0\0".to_vec();
+            for value in xyz {
+                data.extend(fixed(value));
+            }
+            tags.push((signature, data));
+        }
+        let function_type: u16 = if curve_params.len() == 1 { 0 } else { 3 };
+        let mut curve = b"para\0\0\0\0".to_vec();
+        curve.extend(function_type.to_be_bytes());
+        curve.extend([0, 0]);
+        for &param in curve_params {
+            curve.extend(fixed(param));
+        }
+        for signature in [b"rTRC", b"gTRC", b"bTRC"] {
+            tags.push((signature, curve.clone()));
+        }
+
+        let mut profile = vec![0; 128];
+        profile[16..20].copy_from_slice(b"RGB ");
+        profile.extend((tags.len() as u32).to_be_bytes());
+        let mut offset = profile.len() + 12 * tags.len();
+        for (signature, data) in &tags {
+            profile.extend(*signature);
+            profile.extend((offset as u32).to_be_bytes());
+            profile.extend((data.len() as u32).to_be_bytes());
+            offset += data.len();
+        }
+        for (_, data) in tags {
+            profile.extend(data);
+        }
+        profile
+    }
+
+    const SRGB_CURVE: [f32; 5] = [2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045];
+
+    #[test]
+    fn test_from_icc_profile() {
+        assert_eq!(
+            ColorSpace::from_icc_profile(&icc_profile(icc::SRGB_PRIMARIES, &SRGB_CURVE)),
+            Some(ColorSpace::Srgb)
+        );
+        assert_eq!(
+            ColorSpace::from_icc_profile(&icc_profile(icc::DISPLAY_P3_PRIMARIES, &SRGB_CURVE)),
+            Some(ColorSpace::DisplayP3)
+        );
+        assert_eq!(
+            ColorSpace::from_icc_profile(&icc_profile(icc::SRGB_PRIMARIES, &[1.0])),
+            Some(ColorSpace::LinearSrgb)
+        );
+        assert_eq!(
+            ColorSpace::from_icc_profile(&icc_profile(icc::DISPLAY_P3_PRIMARIES, &[1.8])),
+            None
+        );
+        assert_eq!(ColorSpace::from_icc_profile(b"garbage"), None);
+    }
+
+    fn assert_close(a: Color32, b: Color32) {
+        let close = a
+            .to_array()
+            .into_iter()
+            .zip(b.to_array())
+            .all(|(a, b)| a.abs_diff(b) <= 1);
+        assert!(close, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_convert() {
+        let p3_red = Color32::from_rgb(255, 0, 0);
+        let srgb = ColorSpace::DisplayP3.convert_color(p3_red, ColorSpace::Srgb);
+        assert_eq!(
+            srgb,
+            Color32::from_rgb(255, 0, 0),
+            "clamped to the sRGB gamut"
+        );
+
+        let srgb_red = ColorSpace::Srgb.convert_color(p3_red, ColorSpace::DisplayP3);
+        assert!(
+            srgb_red.r() < 255 && srgb_red.g() > 0,
+            "less saturated in P3"
+        );
+
+        for color in [
+            Color32::from_rgb(200, 100, 50),
+            Color32::from_gray(128),
+            Color32::from_rgba_unmultiplied(50, 150, 250, 100),
+        ] {
+            let mut pixels = vec![color; 2 * ENCODE_TABLE_SIZE];
+            ColorSpace::Srgb.convert_pixels(&mut pixels, ColorSpace::DisplayP3);
+            assert_close(
+                pixels[0],
+                ColorSpace::Srgb.convert_color(color, ColorSpace::DisplayP3),
+            );
+            ColorSpace::DisplayP3.convert_pixels(&mut pixels, ColorSpace::Srgb);
+            assert_close(pixels[0], color);
+        }
+
+        let gray = Color32::from_gray(128);
+        assert_eq!(
+            ColorSpace::Srgb.convert_color(gray, ColorSpace::LinearSrgb),
+            Color32::from_gray(55)
+        );
+    }
+}
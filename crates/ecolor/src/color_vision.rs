@@ -0,0 +1,118 @@
+use crate::{gamma_u8_from_linear_f32, linear_f32_from_gamma_u8, Color32, Rgba};
+
+/// A kind of color blindness, for simulating how colors look to people who have it.
+///
+/// Use this to check that your UI doesn't rely on colors that some of your users can't tell apart.
+///
+/// The simulation uses the matrices of
+/// [Machado et al. 2009](https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html)
+/// at full severity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ColorVisionDeficiency {
+    /// No red cones: red looks dark, and is hard to tell apart from green.
+    Protanopia,
+
+    /// No green cones: red and green are hard to tell apart. The most common kind.
+    Deuteranopia,
+
+    /// No blue cones: blue and green, and yellow and red, are hard to tell apart.
+    Tritanopia,
+}
+
+impl ColorVisionDeficiency {
+    pub const ALL: [Self; 3] = [Self::Protanopia, Self::Deuteranopia, Self::Tritanopia];
+
+    /// Human-readable name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Protanopia => "Protanopia",
+            Self::Deuteranopia => "Deuteranopia",
+            Self::Tritanopia => "Tritanopia",
+        }
+    }
+
+    /// The transform in linear RGB, row by row.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Self::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Self::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+
+    /// How `rgba` looks to someone with this color vision deficiency.
+    ///
+    /// Works on premultiplied alpha, and keeps alpha as is.
+    pub fn simulate_rgba(self, rgba: Rgba) -> Rgba {
+        let [r, g, b, a] = rgba.to_array();
+        let [x, y, z] = self.matrix();
+        let transform = |row: [f32; 3]| (row[0] * r + row[1] * g + row[2] * b).clamp(0.0, a);
+        Rgba::from_rgba_premultiplied(transform(x), transform(y), transform(z), a)
+    }
+
+    /// How `color` looks to someone with this color vision deficiency.
+    ///
+    /// Works on premultiplied alpha, and keeps alpha as is.
+    pub fn simulate(self, color: Color32) -> Color32 {
+        // Color32 is premultiplied in gamma space, so we can't go via `Rgba` without
+        // changing the meaning of alpha. Transform the unmultiplied color instead:
+        let [r, g, b, a] = color.to_srgba_unmultiplied();
+        let linear = Rgba::from_rgb(
+            linear_f32_from_gamma_u8(r),
+            linear_f32_from_gamma_u8(g),
+            linear_f32_from_gamma_u8(b),
+        );
+        let [r, g, b, _] = self.simulate_rgba(linear).to_array();
+        Color32::from_rgba_unmultiplied(
+            gamma_u8_from_linear_f32(r),
+            gamma_u8_from_linear_f32(g),
+            gamma_u8_from_linear_f32(b),
+            a,
+        )
+    }
+}
+
+#[test]
+fn test_color_vision_deficiency() {
+    for cvd in ColorVisionDeficiency::ALL {
+        // Grays look the same to everyone:
+        for gray in [0, 64, 128, 255] {
+            let color = Color32::from_gray(gray);
+            let simulated = cvd.simulate(color);
+            for (a, b) in simulated.to_array().into_iter().zip(color.to_array()) {
+                assert!(a.abs_diff(b) <= 1, "{cvd:?}: {color:?} -> {simulated:?}");
+            }
+        }
+        assert_eq!(cvd.simulate(Color32::TRANSPARENT), Color32::TRANSPARENT);
+    }
+
+    // Protanopes and deuteranopes see both red and green as shades of yellow-brown:
+    for cvd in [
+        ColorVisionDeficiency::Protanopia,
+        ColorVisionDeficiency::Deuteranopia,
+    ] {
+        for color in [
+            Color32::from_rgb(200, 60, 40),
+            Color32::from_rgb(100, 150, 40),
+        ] {
+            let simulated = cvd.simulate(color);
+            assert!(
+                simulated.r().abs_diff(simulated.g()) < 20,
+                "{cvd:?}: {color:?} -> {simulated:?}"
+            );
+        }
+    }
+}
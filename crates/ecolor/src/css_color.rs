@@ -0,0 +1,391 @@
+//! Convert colors to and from CSS color strings at runtime.
+//!
+//! Supports hex colors, `rgb()`/`rgba()`, `hsl()`/`hsla()` and the named colors of
+//! <https://drafts.csswg.org/css-color-4/>.
+
+use std::str::FromStr as _;
+
+use crate::{rgb_from_hsv, Color32, HexColor, ParseHexColorError};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseCssColorError {
+    /// A `#` color that couldn't be parsed.
+    InvalidHex(ParseHexColorError),
+
+    /// Not a hex color, a known function, or a named color.
+    UnknownColor,
+
+    /// A known function like `rgb(…)`, but with the wrong number of arguments, or invalid ones.
+    InvalidArguments,
+}
+
+impl Color32 {
+    /// Parses a CSS color string.
+    ///
+    /// Supports:
+    /// * hex colors: `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`
+    /// * `rgb()` and `rgba()`, with numbers (0-255) or percentages,
+    ///   e.g. `rgb(255, 128, 0)`, `rgba(100%, 50%, 0%, 0.5)` or `rgb(255 128 0 / 50%)`
+    /// * `hsl()` and `hsla()`, with the hue in degrees (or `deg`, `rad`, `grad` and `turn`),
+    ///   e.g. `hsl(30, 100%, 50%)` or `hsl(0.5turn 50% 50% / 0.5)`
+    /// * the named colors, e.g. `rebeccapurple`, and `transparent`
+    ///
+    /// Parsing is case-insensitive, and ignores surrounding whitespace.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ecolor::Color32;
+    /// assert_eq!(Color32::from_css_str("#f00"), Ok(Color32::RED));
+    /// assert_eq!(Color32::from_css_str("rgb(0, 255, 0)"), Ok(Color32::GREEN));
+    /// assert_eq!(Color32::from_css_str("hsl(240 100% 50%)"), Ok(Color32::BLUE));
+    /// assert_eq!(Color32::from_css_str("White"), Ok(Color32::WHITE));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the string isn't one of the supported formats.
+    pub fn from_css_str(css: &str) -> Result<Self, ParseCssColorError> {
+        let css = css.trim().to_ascii_lowercase();
+
+        if css.starts_with('#') {
+            return HexColor::from_str(&css)
+                .map(|hex| hex.color())
+                .map_err(ParseCssColorError::InvalidHex);
+        }
+
+        if let Some((function, arguments)) = css.split_once('(') {
+            let arguments = arguments
+                .strip_suffix(')')
+                .ok_or(ParseCssColorError::InvalidArguments)?;
+            let arguments: Vec<&str> = arguments
+                .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+                .filter(|argument| !argument.is_empty())
+                .collect();
+            let parse = match function.trim() {
+                "rgb" | "rgba" => parse_rgb,
+                "hsl" | "hsla" => parse_hsl,
+                _ => return Err(ParseCssColorError::UnknownColor),
+            };
+            return parse(&arguments).ok_or(ParseCssColorError::InvalidArguments);
+        }
+
+        if css == "transparent" {
+            return Ok(Self::TRANSPARENT);
+        }
+
+        NAMED_COLORS
+            .binary_search_by_key(&css.as_str(), |&(name, _)| name)
+            .ok()
+            .map(|index| {
+                let [_, r, g, b] = NAMED_COLORS[index].1.to_be_bytes();
+                Self::from_rgb(r, g, b)
+            })
+            .ok_or(ParseCssColorError::UnknownColor)
+    }
+
+    /// Formats the color as a CSS color string.
+    ///
+    /// Opaque colors are formatted as `#rrggbb`, and others as `rgba(r, g, b, a)`.
+    /// [`Self::from_css_str`] parses the result back to the same color.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ecolor::Color32;
+    /// assert_eq!(Color32::RED.to_css_string(), "#ff0000");
+    /// assert_eq!(
+    ///     Color32::from_rgba_unmultiplied(255, 0, 0, 128).to_css_string(),
+    ///     "rgba(255, 0, 0, 0.502)"
+    /// );
+    /// ```
+    pub fn to_css_string(&self) -> String {
+        if self.is_opaque() {
+            HexColor::Hex6(*self).to_string()
+        } else {
+            let [r, g, b, a] = self.to_srgba_unmultiplied();
+            let alpha = format!("{:.3}", a as f32 / 255.0);
+            let alpha = alpha.trim_end_matches('0').trim_end_matches('.');
+            format!("rgba({r}, {g}, {b}, {alpha})")
+        }
+    }
+}
+
+fn parse_rgb(arguments: &[&str]) -> Option<Color32> {
+    let (rgb, alpha) = split_alpha(arguments)?;
+    let [r, g, b] = [rgb[0], rgb[1], rgb[2]].map(parse_rgb_channel);
+    Some(Color32::from_rgba_unmultiplied(r?, g?, b?, alpha))
+}
+
+fn parse_hsl(arguments: &[&str]) -> Option<Color32> {
+    let (hsl, alpha) = split_alpha(arguments)?;
+    let hue = parse_hue(hsl[0])?;
+    let saturation = parse_percentage_or_number(hsl[1], 100.0)?.clamp(0.0, 1.0);
+    let lightness = parse_percentage_or_number(hsl[2], 100.0)?.clamp(0.0, 1.0);
+
+    // HSL to HSV:
+    let value = lightness + saturation * lightness.min(1.0 - lightness);
+    let saturation = if value == 0.0 {
+        0.0
+    } else {
+        2.0 * (1.0 - lightness / value)
+    };
+
+    let [r, g, b] = rgb_from_hsv((hue, saturation, value)).map(|c| (c * 255.0).round() as u8);
+    Some(Color32::from_rgba_unmultiplied(r, g, b, alpha))
+}
+
+/// The three color arguments, and the alpha as `u8` (opaque if missing).
+fn split_alpha<'a>(arguments: &'a [&'a str]) -> Option<(&'a [&'a str], u8)> {
+    match arguments {
+        [_, _, _] => Some((arguments, 255)),
+        [color @ .., alpha] if color.len() == 3 => {
+            let alpha = parse_percentage_or_number(alpha, 1.0)?.clamp(0.0, 1.0);
+            Some((color, (alpha * 255.0).round() as u8))
+        }
+        _ => None,
+    }
+}
+
+/// A number 0-255, or a percentage.
+fn parse_rgb_channel(argument: &str) -> Option<u8> {
+    let value = parse_percentage_or_number(argument, 255.0)?;
+    Some((value * 255.0).round().clamp(0.0, 255.0) as u8)
+}
+
+/// Hue in turns (0-1), from degrees (the default), or a number with a unit.
+fn parse_hue(argument: &str) -> Option<f32> {
+    let (number, turns_per_unit) = if let Some(number) = argument.strip_suffix("deg") {
+        (number, 1.0 / 360.0)
+    } else if let Some(number) = argument.strip_suffix("grad") {
+        (number, 1.0 / 400.0)
+    } else if let Some(number) = argument.strip_suffix("rad") {
+        (number, 1.0 / std::f32::consts::TAU)
+    } else if let Some(number) = argument.strip_suffix("turn") {
+        (number, 1.0)
+    } else {
+        (argument, 1.0 / 360.0)
+    };
+    let turns = parse_number(number)? * turns_per_unit;
+    Some(turns.rem_euclid(1.0))
+}
+
+/// A percentage, or a number that is `100%` at `full`. Returns `0-1` for values in range.
+fn parse_percentage_or_number(argument: &str, full: f32) -> Option<f32> {
+    if let Some(percentage) = argument.strip_suffix('%') {
+        Some(parse_number(percentage)? / 100.0)
+    } else {
+        Some(parse_number(argument)? / full)
+    }
+}
+
+fn parse_number(argument: &str) -> Option<f32> {
+    f32::from_str(argument)
+        .ok()
+        .filter(|number| number.is_finite())
+}
+
+/// The named colors of <https://drafts.csswg.org/css-color-4/#named-colors>, sorted by name.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_colors_are_sorted() {
+        assert!(NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(NAMED_COLORS.len(), 148);
+    }
+
+    #[test]
+    fn css_string_formats() {
+        use Color32 as C;
+        let orange = C::from_rgb(255, 165, 0);
+        let cases = [
+            ("#ffa500", orange),
+            ("#FFA500", orange),
+            ("orange", orange),
+            ("  Orange ", orange),
+            ("rgb(255, 165, 0)", orange),
+            ("rgb(255 165 0)", orange),
+            ("rgb(100% 64.7% 0%)", orange),
+            ("rgba(255, 165, 0, 1)", orange),
+            ("hsl(38.8, 100%, 50%)", orange),
+            ("hsl(38.8deg 100% 50% / 100%)", orange),
+            ("transparent", C::TRANSPARENT),
+            ("hsl(0.5turn, 0%, 100%)", C::WHITE),
+            ("hsl(120, 100%, 25%)", C::from_rgb(0, 128, 0)),
+            (
+                "rgba(255, 0, 0, 0.5)",
+                C::from_rgba_unmultiplied(255, 0, 0, 128),
+            ),
+            (
+                "rgb(255 0 0 / 50%)",
+                C::from_rgba_unmultiplied(255, 0, 0, 128),
+            ),
+        ];
+        for (css, color) in cases {
+            assert_eq!(C::from_css_str(css), Ok(color), "{css}");
+        }
+
+        for css in ["", "rgb(1, 2)", "rgb(1, 2, 3", "hsl(a, b, c)", "foo", "#12"] {
+            assert!(C::from_css_str(css).is_err(), "{css}");
+        }
+    }
+
+    #[test]
+    fn css_string_round_trip() {
+        use Color32 as C;
+        for a in [0, 1, 40, 128, 254, 255] {
+            let color = C::from_rgba_unmultiplied(10, 20, 30, a);
+            assert_eq!(C::from_css_str(&color.to_css_string()), Ok(color));
+        }
+    }
+}
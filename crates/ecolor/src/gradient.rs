@@ -0,0 +1,192 @@
+use crate::{Color32, Oklab, Rgba};
+
+/// The color space a [`Gradient`] interpolates in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GradientSpace {
+    /// Interpolate the sRGB gamma values, like CSS gradients do by default.
+    ///
+    /// Cheap, but the middle between two saturated colors can look dull.
+    Gamma,
+
+    /// Interpolate in linear RGB, which is how light mixes.
+    ///
+    /// The middle of a gradient between a dark and a bright color looks too bright.
+    Linear,
+
+    /// Interpolate in [`Oklab`], so that the colors change evenly to the eye.
+    #[default]
+    Oklab,
+}
+
+/// A color gradient made of color stops, for heat maps, progress bars, plots, …
+///
+/// ```
+/// use ecolor::{Color32, Gradient};
+/// let gradient = Gradient::new([(0.0, Color32::BLACK), (1.0, Color32::WHITE)]);
+/// assert_eq!(gradient.sample(0.0), Color32::BLACK);
+/// assert_eq!(gradient.sample(2.0), Color32::WHITE);
+///
+/// let heat = Gradient::turbo();
+/// let color = heat.sample(0.75);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Gradient {
+    /// Sorted by position.
+    stops: Vec<(f32, Color32)>,
+    space: GradientSpace,
+}
+
+impl Gradient {
+    /// A gradient through the given `(position, color)` stops.
+    ///
+    /// The stops don't need to be sorted, and positions are usually in `0-1`.
+    /// Interpolates in [`GradientSpace::Oklab`] unless you change it with [`Self::space`].
+    pub fn new(stops: impl IntoIterator<Item = (f32, Color32)>) -> Self {
+        let mut stops: Vec<(f32, Color32)> = stops.into_iter().collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            stops,
+            space: GradientSpace::default(),
+        }
+    }
+
+    /// A gradient through the given colors, spaced evenly from `0` to `1`.
+    pub fn evenly_spaced(colors: impl IntoIterator<Item = Color32>) -> Self {
+        let colors: Vec<Color32> = colors.into_iter().collect();
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self::new(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(i, color)| (i as f32 / last, color)),
+        )
+    }
+
+    /// Which color space to interpolate in. Default: [`GradientSpace::Oklab`].
+    #[inline]
+    pub fn space(mut self, space: GradientSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// The `(position, color)` stops, sorted by position.
+    #[inline]
+    pub fn stops(&self) -> &[(f32, Color32)] {
+        &self.stops
+    }
+
+    /// The color at position `t`.
+    ///
+    /// Before the first stop this is the color of the first stop,
+    /// and after the last stop the color of the last stop.
+    /// An empty gradient is [`Color32::TRANSPARENT`].
+    pub fn sample(&self, t: f32) -> Color32 {
+        let Some(&(first_t, first_color)) = self.stops.first() else {
+            return Color32::TRANSPARENT;
+        };
+        if t.is_nan() || t <= first_t {
+            return first_color;
+        }
+
+        let next = self.stops.partition_point(|&(stop_t, _)| stop_t <= t);
+        let Some(&(to_t, to_color)) = self.stops.get(next) else {
+            return self.stops[self.stops.len() - 1].1;
+        };
+        let (from_t, from_color) = self.stops[next - 1];
+
+        let t = (t - from_t) / (to_t - from_t);
+        self.lerp(from_color, to_color, t)
+    }
+
+    /// `n` colors sampled evenly from `0` to `1`, e.g. for a discrete palette or a lookup table.
+    pub fn samples(&self, n: usize) -> Vec<Color32> {
+        let last = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(|i| self.sample(i as f32 / last)).collect()
+    }
+
+    fn lerp(&self, from: Color32, to: Color32, t: f32) -> Color32 {
+        match self.space {
+            GradientSpace::Gamma => from.lerp_to_gamma(to, t),
+            GradientSpace::Linear => {
+                let (from, to) = (Rgba::from(from), Rgba::from(to));
+                (from * (1.0 - t) + to * t).into()
+            }
+            GradientSpace::Oklab => Oklab::from(from).lerp(Oklab::from(to), t).into(),
+        }
+    }
+
+    /// From black to white, evenly in brightness.
+    pub fn grayscale() -> Self {
+        Self::new([(0.0, Color32::BLACK), (1.0, Color32::WHITE)]).space(GradientSpace::Gamma)
+    }
+
+    /// The [viridis](https://bids.github.io/colormap/) color map, from dark purple to yellow.
+    ///
+    /// Perceptually uniform, readable by color blind people, and still ordered when printed in gray.
+    pub fn viridis() -> Self {
+        Self::from_hex_stops(&[
+            0x440154, 0x482878, 0x3e4989, 0x31688e, 0x26828e, 0x1f9e89, 0x35b779, 0x6ece58,
+            0xb5de2b, 0xfde725,
+        ])
+    }
+
+    /// The [turbo](https://research.google/blog/turbo-an-improved-rainbow-colormap-for-visualization/)
+    /// rainbow color map, from dark blue via green to dark red.
+    ///
+    /// Shows more detail than [`Self::viridis`], but isn't suitable for color blind people.
+    pub fn turbo() -> Self {
+        Self::from_hex_stops(&[
+            0x30123b, 0x4145ab, 0x4675ed, 0x39a2fc, 0x1bcfd4, 0x24eca6, 0x61fc6c, 0xa4fc3b,
+            0xd1e834, 0xf3c63a, 0xfe9b2d, 0xf36315, 0xd93806, 0xb11901, 0x7a0402,
+        ])
+    }
+
+    /// Evenly spaced `0xRRGGBB` stops of a color map defined in sRGB.
+    fn from_hex_stops(stops: &[u32]) -> Self {
+        Self::evenly_spaced(stops.iter().map(|&rgb| {
+            let [_, r, g, b] = rgb.to_be_bytes();
+            Color32::from_rgb(r, g, b)
+        }))
+        .space(GradientSpace::Gamma)
+    }
+}
+
+#[test]
+fn test_gradient() {
+    let red_blue = Gradient::new([(1.0, Color32::BLUE), (0.0, Color32::RED)]);
+    assert_eq!(red_blue.stops()[0].1, Color32::RED);
+    assert_eq!(red_blue.sample(-1.0), Color32::RED);
+    assert_eq!(red_blue.sample(0.0), Color32::RED);
+    assert_eq!(red_blue.sample(1.0), Color32::BLUE);
+    assert_eq!(red_blue.sample(f32::NAN), Color32::RED);
+
+    for space in [
+        GradientSpace::Gamma,
+        GradientSpace::Linear,
+        GradientSpace::Oklab,
+    ] {
+        let gradient = red_blue.clone().space(space);
+        let middle = gradient.sample(0.5);
+        assert!(0 < middle.r() && 0 < middle.b(), "{space:?}: {middle:?}");
+    }
+
+    assert_eq!(Gradient::new([]).sample(0.5), Color32::TRANSPARENT);
+    assert_eq!(
+        Gradient::evenly_spaced([Color32::RED]).sample(0.5),
+        Color32::RED
+    );
+
+    let gray = Gradient::grayscale().samples(3);
+    assert_eq!(
+        gray,
+        [Color32::BLACK, Color32::from_gray(128), Color32::WHITE]
+    );
+
+    for color_map in [Gradient::viridis(), Gradient::turbo()] {
+        let stops = color_map.stops();
+        assert_eq!(stops[0].0, 0.0);
+        assert_eq!(stops[stops.len() - 1].0, 1.0);
+    }
+}
@@ -3,6 +3,8 @@
 //! If you want a compact color representation, use [`Color32`].
 //! If you want to manipulate RGBA colors use [`Rgba`].
 //! If you want to manipulate colors in a way closer to how humans think about colors, use [`HsvaGamma`].
+//! If you want to blend colors or define palettes that look perceptually even, use [`Oklab`] or [`Oklch`].
+//! For color maps and multi-stop gradients, use [`Gradient`].
 //!
 //! ## Feature flags
 #![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
@@ -16,6 +18,18 @@ mod cint_impl;
 mod color32;
 pub use color32::*;
 
+mod color_space;
+pub use color_space::*;
+
+mod color_vision;
+pub use color_vision::*;
+
+mod css_color;
+pub use css_color::*;
+
+mod gradient;
+pub use gradient::*;
+
 mod hsva_gamma;
 pub use hsva_gamma::*;
 
@@ -28,12 +42,21 @@ mod hex_color_macro;
 #[doc(hidden)]
 pub use color_hex;
 
+mod oklab;
+pub use oklab::*;
+
+mod palette;
+pub use palette::*;
+
 mod rgba;
 pub use rgba::*;
 
 mod hex_color_runtime;
 pub use hex_color_runtime::*;
 
+mod tone_mapping;
+pub use tone_mapping::*;
+
 // ----------------------------------------------------------------------------
 // Color conversion:
 
@@ -0,0 +1,307 @@
+use crate::{Color32, Rgba};
+
+/// A color in the [Oklab](https://bottosson.github.io/posts/oklab/) color space, with alpha.
+///
+/// Oklab is perceptually uniform: the same distance between two colors looks like
+/// the same amount of change, whatever the colors are.
+/// This makes it good for blending colors (see [`Self::lerp`]), and for generating palettes.
+///
+/// No premultiplied alpha.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Oklab {
+    /// Perceived lightness, 0-1.
+    pub l: f32,
+
+    /// How green (negative) or red (positive) the color is, roughly -0.4 to 0.4.
+    pub a: f32,
+
+    /// How blue (negative) or yellow (positive) the color is, roughly -0.4 to 0.4.
+    pub b: f32,
+
+    /// alpha 0-1
+    pub alpha: f32,
+}
+
+impl Oklab {
+    #[inline]
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    /// From linear RGB, without alpha.
+    pub fn from_rgb([r, g, b]: [f32; 3]) -> Self {
+        let l = 0.412_221_47 * r + 0.536_332_54 * g + 0.051_445_993 * b;
+        let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+        let s = 0.088_302_46 * r + 0.281_718_84 * g + 0.629_978_7 * b;
+
+        let l = l.cbrt();
+        let m = m.cbrt();
+        let s = s.cbrt();
+
+        Self {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha: 1.0,
+        }
+    }
+
+    /// To linear RGB, ignoring alpha.
+    ///
+    /// The result can be outside of the `[0, 1]` range for colors outside of the sRGB gamut.
+    pub fn to_rgb(&self) -> [f32; 3] {
+        let l = self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b;
+        let m = self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b;
+        let s = self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b;
+
+        let l = l * l * l;
+        let m = m * m * m;
+        let s = s * s * s;
+
+        [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_93 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_4 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+    }
+
+    /// From linear RGBA with premultiplied alpha.
+    pub fn from_rgba_premultiplied(r: f32, g: f32, b: f32, a: f32) -> Self {
+        if a <= 0.0 {
+            Self::new(0.0, 0.0, 0.0, 0.0)
+        } else {
+            Self {
+                alpha: a,
+                ..Self::from_rgb([r / a, g / a, b / a])
+            }
+        }
+    }
+
+    /// To linear RGBA with premultiplied alpha.
+    pub fn to_rgba_premultiplied(&self) -> [f32; 4] {
+        let [r, g, b] = self.to_rgb();
+        let a = self.alpha;
+        [a * r, a * g, a * b, a]
+    }
+
+    /// Perceptually uniform interpolation from `self` (`t == 0`) to `other` (`t == 1`).
+    ///
+    /// Unlike interpolating in RGB, this doesn't produce dull or dark colors half-way.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        use emath::lerp;
+        Self {
+            l: lerp(self.l..=other.l, t),
+            a: lerp(self.a..=other.a, t),
+            b: lerp(self.b..=other.b, t),
+            alpha: lerp(self.alpha..=other.alpha, t),
+        }
+    }
+}
+
+impl From<Rgba> for Oklab {
+    #[inline]
+    fn from(rgba: Rgba) -> Self {
+        Self::from_rgba_premultiplied(rgba.r(), rgba.g(), rgba.b(), rgba.a())
+    }
+}
+
+impl From<Oklab> for Rgba {
+    #[inline]
+    fn from(oklab: Oklab) -> Self {
+        let [r, g, b, a] = oklab.to_rgba_premultiplied();
+        Self::from_rgba_premultiplied(r, g, b, a)
+    }
+}
+
+impl From<Color32> for Oklab {
+    #[inline]
+    fn from(srgba: Color32) -> Self {
+        Rgba::from(srgba).into()
+    }
+}
+
+impl From<Oklab> for Color32 {
+    #[inline]
+    fn from(oklab: Oklab) -> Self {
+        Rgba::from(oklab).into()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A color in the cylindrical form of [`Oklab`]: lightness, chroma and hue, with alpha.
+///
+/// This is the most convenient way to define palettes:
+/// colors with the same lightness look equally bright,
+/// and colors with the same chroma look equally colorful.
+///
+/// No premultiplied alpha.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Oklch {
+    /// Perceived lightness, 0-1.
+    pub l: f32,
+
+    /// Chroma (colorfulness), from 0 (gray) to roughly 0.4.
+    pub c: f32,
+
+    /// hue 0-1, like in [`crate::Hsva`].
+    ///
+    /// `0` is pinkish red, `0.25` is yellowish, `0.5` is cyan, and `0.75` is blue-violet.
+    pub h: f32,
+
+    /// alpha 0-1
+    pub alpha: f32,
+}
+
+impl Oklch {
+    #[inline]
+    pub fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+
+    /// Perceptually uniform interpolation from `self` (`t == 0`) to `other` (`t == 1`),
+    /// going the shortest way around the hue circle.
+    ///
+    /// The hue of gray colors (zero chroma) is ignored.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        use emath::lerp;
+
+        let (from_h, to_h) = if self.c == 0.0 {
+            (other.h, other.h)
+        } else if other.c == 0.0 {
+            (self.h, self.h)
+        } else {
+            (self.h, other.h)
+        };
+        let mut hue_delta = (to_h - from_h).rem_euclid(1.0);
+        if 0.5 < hue_delta {
+            hue_delta -= 1.0;
+        }
+
+        Self {
+            l: lerp(self.l..=other.l, t),
+            c: lerp(self.c..=other.c, t),
+            h: (from_h + t * hue_delta).rem_euclid(1.0),
+            alpha: lerp(self.alpha..=other.alpha, t),
+        }
+    }
+
+    /// Reduce the chroma just enough for the color to fit in the sRGB gamut,
+    /// keeping the lightness and hue.
+    ///
+    /// Converting a color outside of the gamut to [`Color32`] would otherwise clamp each channel,
+    /// which changes the hue.
+    pub fn fit_to_srgb_gamut(self) -> Self {
+        let color = Self {
+            l: self.l.clamp(0.0, 1.0),
+            ..self
+        };
+        let in_gamut = |c: f32| {
+            Oklab::from(Self { c, ..color })
+                .to_rgb()
+                .iter()
+                .all(|&channel| (-1e-4..=1.0 + 1e-4).contains(&channel))
+        };
+        if in_gamut(color.c) {
+            return color;
+        }
+
+        let (mut inside, mut outside) = (0.0, color.c);
+        for _ in 0..16 {
+            let c = (inside + outside) / 2.0;
+            if in_gamut(c) {
+                inside = c;
+            } else {
+                outside = c;
+            }
+        }
+        Self { c: inside, ..color }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        let Oklab { l, a, b, alpha } = oklab;
+        let c = a.hypot(b);
+        let h = if c == 0.0 {
+            0.0
+        } else {
+            (b.atan2(a) / std::f32::consts::TAU).rem_euclid(1.0)
+        };
+        Self { l, c, h, alpha }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(oklch: Oklch) -> Self {
+        let Oklch { l, c, h, alpha } = oklch;
+        let (sin, cos) = (h * std::f32::consts::TAU).sin_cos();
+        Self {
+            l,
+            a: c * cos,
+            b: c * sin,
+            alpha,
+        }
+    }
+}
+
+impl From<Rgba> for Oklch {
+    #[inline]
+    fn from(rgba: Rgba) -> Self {
+        Oklab::from(rgba).into()
+    }
+}
+
+impl From<Oklch> for Rgba {
+    #[inline]
+    fn from(oklch: Oklch) -> Self {
+        Oklab::from(oklch).into()
+    }
+}
+
+impl From<Color32> for Oklch {
+    #[inline]
+    fn from(srgba: Color32) -> Self {
+        Oklab::from(srgba).into()
+    }
+}
+
+impl From<Oklch> for Color32 {
+    #[inline]
+    fn from(oklch: Oklch) -> Self {
+        Oklab::from(oklch).into()
+    }
+}
+
+#[test]
+fn test_oklab_roundtrip() {
+    for r in (0..=255).step_by(15) {
+        for g in (0..=255).step_by(15) {
+            for b in (0..=255).step_by(15) {
+                let color = Color32::from_rgb(r, g, b);
+                assert_eq!(Color32::from(Oklab::from(color)), color);
+                assert_eq!(Color32::from(Oklch::from(color)), color);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_oklab_known_values() {
+    let white = Oklab::from(Color32::WHITE);
+    assert!((white.l - 1.0).abs() < 1e-3, "{white:?}");
+    assert!(white.a.abs() < 1e-3 && white.b.abs() < 1e-3, "{white:?}");
+
+    let black = Oklab::from(Color32::BLACK);
+    assert!(black.l.abs() < 1e-3, "{black:?}");
+}
+
+#[test]
+fn test_oklch_lerp_shortest_hue() {
+    let a = Oklch::new(0.7, 0.1, 0.9, 1.0);
+    let b = Oklch::new(0.7, 0.1, 0.1, 1.0);
+    let mid = a.lerp(b, 0.5);
+    assert!(mid.h < 0.01 || 0.99 < mid.h, "{mid:?}");
+}
@@ -0,0 +1,273 @@
+//! Derive palettes from a single seed color.
+
+use crate::{linear_f32_from_gamma_u8, Color32, Oklch};
+
+/// The levels of [`shades`], from the lightest (`50`) to the darkest (`900`),
+/// like in many design systems.
+pub const SHADE_LEVELS: [u16; 10] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900];
+
+/// Oklch lightness, and chroma relative to the seed, for each of [`SHADE_LEVELS`].
+const SHADE_LIGHTNESS_CHROMA: [(f32, f32); 10] = [
+    (0.97, 0.25),
+    (0.94, 0.4),
+    (0.88, 0.6),
+    (0.80, 0.8),
+    (0.71, 0.95),
+    (0.62, 1.0),
+    (0.54, 1.0),
+    (0.46, 0.9),
+    (0.38, 0.8),
+    (0.30, 0.65),
+];
+
+/// A lightness ramp of ten shades with the hue of `seed`, one for each of [`SHADE_LEVELS`].
+///
+/// `50` is almost white and good for backgrounds, `500` has about the lightness of
+/// a typical saturated brand color, and `900` is almost black and good for text.
+/// The colors are evenly spaced in perceived lightness, and all in the sRGB gamut.
+///
+/// ```
+/// use ecolor::{shades, Color32};
+/// let blue = shades(Color32::from_rgb(40, 100, 220));
+/// let (background, text) = (blue[0], blue[9]);
+/// ```
+pub fn shades(seed: Color32) -> [Color32; 10] {
+    SHADE_LIGHTNESS_CHROMA.map(|(l, c)| shade_color(seed, l, c))
+}
+
+/// The shade of `seed` at any level from `0` (white) to `1000` (black).
+///
+/// The levels of [`SHADE_LEVELS`] give the same colors as [`shades`], and levels in between
+/// are interpolated.
+pub fn shade(seed: Color32, level: f32) -> Color32 {
+    let levels = SHADE_LEVELS.map(|level| level as f32);
+    let (l, c) = if level <= levels[0] {
+        let t = (level / levels[0]).max(0.0);
+        let (l, c) = SHADE_LIGHTNESS_CHROMA[0];
+        (emath::lerp(1.0..=l, t), emath::lerp(0.0..=c, t))
+    } else if levels[levels.len() - 1] <= level {
+        let last = levels[levels.len() - 1];
+        let t = ((level - last) / (1000.0 - last)).min(1.0);
+        let (l, c) = SHADE_LIGHTNESS_CHROMA[levels.len() - 1];
+        (emath::lerp(l..=0.0, t), emath::lerp(c..=0.0, t))
+    } else {
+        let next = levels.partition_point(|&l| l <= level);
+        let t = (level - levels[next - 1]) / (levels[next] - levels[next - 1]);
+        let (l0, c0) = SHADE_LIGHTNESS_CHROMA[next - 1];
+        let (l1, c1) = SHADE_LIGHTNESS_CHROMA[next];
+        (emath::lerp(l0..=l1, t), emath::lerp(c0..=c1, t))
+    };
+    shade_color(seed, l, c)
+}
+
+fn shade_color(seed: Color32, lightness: f32, relative_chroma: f32) -> Color32 {
+    let seed = Oklch::from(seed.to_opaque());
+    Oklch {
+        l: lightness,
+        c: seed.c * relative_chroma,
+        ..seed
+    }
+    .fit_to_srgb_gamut()
+    .into()
+}
+
+/// A color harmony: colors that go well with a seed color, found by rotating its hue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Harmony {
+    /// The seed, and the color opposite to it on the color wheel.
+    Complementary,
+
+    /// The seed, and the colors 30° on either side of it.
+    Analogous,
+
+    /// The seed, and the colors 120° on either side of it.
+    Triadic,
+
+    /// The seed, and the colors 30° on either side of its complement.
+    SplitComplementary,
+
+    /// The seed, and the colors at 90°, 180° and 270° from it.
+    Tetradic,
+}
+
+impl Harmony {
+    /// The hue offsets from the seed, in turns.
+    fn hue_offsets(self) -> &'static [f32] {
+        match self {
+            Self::Complementary => &[0.0, 0.5],
+            Self::Analogous => &[-1.0 / 12.0, 0.0, 1.0 / 12.0],
+            Self::Triadic => &[0.0, 1.0 / 3.0, 2.0 / 3.0],
+            Self::SplitComplementary => &[0.0, 5.0 / 12.0, 7.0 / 12.0],
+            Self::Tetradic => &[0.0, 0.25, 0.5, 0.75],
+        }
+    }
+
+    /// The colors of this harmony for `seed`.
+    ///
+    /// The colors are rotated in [`Oklch`], so they all keep the lightness of the seed.
+    /// The seed itself is included (first, except for [`Self::Analogous`],
+    /// where it is in the middle).
+    pub fn colors(self, seed: Color32) -> Vec<Color32> {
+        let seed_oklch = Oklch::from(seed);
+        self.hue_offsets()
+            .iter()
+            .map(|&offset| {
+                if offset == 0.0 {
+                    seed
+                } else {
+                    Oklch {
+                        h: (seed_oklch.h + offset).rem_euclid(1.0),
+                        ..seed_oklch
+                    }
+                    .fit_to_srgb_gamut()
+                    .into()
+                }
+            })
+            .collect()
+    }
+}
+
+/// The minimum WCAG contrast ratio for normal text (level AA).
+pub const MIN_TEXT_CONTRAST: f32 = 4.5;
+
+/// Pick a text color for `background` that is as close as possible to `preferred`,
+/// but with a [WCAG contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio)
+/// of at least `min_contrast` (e.g. [`MIN_TEXT_CONTRAST`]).
+///
+/// If `preferred` has too little contrast, it is made darker or lighter (keeping its hue).
+/// Falls back to black or white if not even that is enough.
+///
+/// ```
+/// use ecolor::{readable_text_color, Color32, MIN_TEXT_CONTRAST};
+/// let background = Color32::from_rgb(40, 100, 220);
+/// let text = readable_text_color(background, Color32::LIGHT_BLUE, MIN_TEXT_CONTRAST);
+/// assert_ne!(text, Color32::LIGHT_BLUE);
+/// ```
+pub fn readable_text_color(background: Color32, preferred: Color32, min_contrast: f32) -> Color32 {
+    if contrast_ratio(background, preferred) >= min_contrast {
+        return preferred;
+    }
+
+    // Go towards whichever of black and white has the most contrast:
+    let extreme = best_text_color_on(background);
+    let darken = extreme == Color32::BLACK;
+    if contrast_ratio(background, extreme) < min_contrast {
+        return extreme;
+    }
+
+    let preferred = Oklch::from(preferred.to_opaque());
+    let with_lightness =
+        |l: f32| -> Color32 { Oklch { l, ..preferred }.fit_to_srgb_gamut().into() };
+    let (mut failing, mut passing) = (preferred.l, if darken { 0.0 } else { 1.0 });
+    for _ in 0..16 {
+        let l = (failing + passing) / 2.0;
+        if contrast_ratio(background, with_lightness(l)) >= min_contrast {
+            passing = l;
+        } else {
+            failing = l;
+        }
+    }
+    with_lightness(passing)
+}
+
+/// Black or white, whichever is most readable on `background`.
+///
+/// See also [`readable_text_color`], for text in other colors.
+///
+/// ```
+/// use ecolor::{best_text_color_on, Color32};
+/// assert_eq!(best_text_color_on(Color32::YELLOW), Color32::BLACK);
+/// assert_eq!(best_text_color_on(Color32::DARK_BLUE), Color32::WHITE);
+/// ```
+pub fn best_text_color_on(background: Color32) -> Color32 {
+    if contrast_ratio(background, Color32::BLACK) >= contrast_ratio(background, Color32::WHITE) {
+        Color32::BLACK
+    } else {
+        Color32::WHITE
+    }
+}
+
+/// The [WCAG contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) between two colors,
+/// from `1` (no contrast) to `21` (black on white).
+///
+/// Text should have a contrast of at least [`MIN_TEXT_CONTRAST`] to its background.
+/// Alpha is ignored.
+pub fn contrast_ratio(a: Color32, b: Color32) -> f32 {
+    let (a, b) = (a.relative_luminance(), b.relative_luminance());
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+impl Color32 {
+    /// The [relative luminance](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance)
+    /// of the color, from `0` (black) to `1` (white), as used by WCAG.
+    ///
+    /// This is how bright the color looks, ignoring alpha.
+    pub fn relative_luminance(&self) -> f32 {
+        let [r, g, b, _] = self.to_srgba_unmultiplied().map(linear_f32_from_gamma_u8);
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+}
+
+#[test]
+fn test_shades() {
+    let seed = Color32::from_rgb(40, 100, 220);
+    let shades = shades(seed);
+    for pair in shades.windows(2) {
+        assert!(pair[0].relative_luminance() > pair[1].relative_luminance());
+    }
+    for (level, color) in SHADE_LEVELS.into_iter().zip(shades) {
+        assert_eq!(shade(seed, level as f32), color, "level {level}");
+    }
+    assert_eq!(shade(seed, 0.0), Color32::WHITE);
+    assert_eq!(shade(seed, 1000.0), Color32::BLACK);
+}
+
+#[test]
+fn test_harmony() {
+    let seed = Color32::from_rgb(200, 80, 40);
+    assert_eq!(Harmony::Complementary.colors(seed)[0], seed);
+    assert_eq!(Harmony::Analogous.colors(seed)[1], seed);
+    assert_eq!(Harmony::Tetradic.colors(seed).len(), 4);
+
+    let complement = Oklch::from(Harmony::Complementary.colors(seed)[1]);
+    let hue_distance = (complement.h - Oklch::from(seed).h).rem_euclid(1.0);
+    assert!((hue_distance - 0.5).abs() < 0.02, "{hue_distance}");
+}
+
+#[test]
+fn test_readable_text_color() {
+    for background in [
+        Color32::WHITE,
+        Color32::BLACK,
+        Color32::from_rgb(40, 100, 220),
+        Color32::from_rgb(250, 200, 40),
+        Color32::from_gray(128),
+    ] {
+        for preferred in [Color32::GRAY, Color32::LIGHT_BLUE, Color32::DARK_RED] {
+            let text = readable_text_color(background, preferred, MIN_TEXT_CONTRAST);
+            assert!(
+                contrast_ratio(background, text) >= MIN_TEXT_CONTRAST,
+                "{preferred:?} on {background:?} -> {text:?}"
+            );
+        }
+    }
+    assert_eq!(
+        readable_text_color(Color32::WHITE, Color32::BLACK, MIN_TEXT_CONTRAST),
+        Color32::BLACK
+    );
+}
+
+#[test]
+fn test_contrast_ratio() {
+    assert!((contrast_ratio(Color32::BLACK, Color32::WHITE) - 21.0).abs() < 0.01);
+    assert_eq!(contrast_ratio(Color32::RED, Color32::RED), 1.0);
+    assert_eq!(
+        contrast_ratio(Color32::RED, Color32::BLUE),
+        contrast_ratio(Color32::BLUE, Color32::RED)
+    );
+
+    // Known values from the WCAG contrast checker:
+    let gray = Color32::from_rgb(0x76, 0x76, 0x76);
+    assert!((contrast_ratio(gray, Color32::WHITE) - 4.54).abs() < 0.01);
+}
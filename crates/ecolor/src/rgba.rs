@@ -4,6 +4,10 @@ use crate::{
 };
 
 /// 0-1 linear space `RGBA` color with premultiplied alpha.
+///
+/// The color channels may also go above `1.0`, for HDR colors (see [`Self::is_hdr`]).
+/// Converting to [`crate::Color32`] clamps them.
+/// Use [`crate::ToneMapping`] to map them into `0-1` with less loss of detail.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -167,6 +171,13 @@ impl Rgba {
         0.3 * self.r() + 0.59 * self.g() + 0.11 * self.b()
     }
 
+    /// Is any color channel brighter than SDR white, i.e. above the alpha?
+    #[inline]
+    pub fn is_hdr(&self) -> bool {
+        let limit = if self.a() == 0.0 { 1.0 } else { self.a() };
+        limit < self.r() || limit < self.g() || limit < self.b()
+    }
+
     /// Returns an opaque version of self
     #[inline]
     pub fn to_opaque(&self) -> Self {
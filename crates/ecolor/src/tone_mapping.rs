@@ -0,0 +1,84 @@
+use crate::Rgba;
+
+/// How to show HDR colors (linear values above `1.0`) on an output that can only show `0-1`.
+///
+/// Only renderers with an SDR output apply this. On an HDR output (e.g. a wgpu surface
+/// with a floating point format) the values are shown as they are.
+///
+/// Vertex colors and the textures managed by egui are 8 bit, so HDR colors reach the renderer
+/// through floating point textures, e.g. from `egui_wgpu::Renderer::register_hdr_texture`.
+/// Use [`Self::map_rgba`] to tone map HDR pixels before turning them into an 8 bit image instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ToneMapping {
+    /// Clamp each channel to `0-1`.
+    ///
+    /// Bright HDR colors lose all detail, but SDR colors are unchanged.
+    #[default]
+    Clamp,
+
+    /// Leave values below `knee` unchanged, and smoothly compress everything above it into `knee-1`.
+    ///
+    /// Keeps SDR UI colors (almost) as they are, while showing detail in bright HDR content.
+    /// A `knee` of `0.8` is a good start.
+    SoftClip { knee: f32 },
+
+    /// The filmic curve of the Academy Color Encoding System (as fitted by Krzysztof Narkowicz).
+    ///
+    /// Good for HDR photos and renders, but darkens and changes the contrast of SDR colors too.
+    AcesFilmic,
+}
+
+impl ToneMapping {
+    /// Tone map one linear color channel.
+    pub fn map_linear(self, linear: f32) -> f32 {
+        let linear = linear.max(0.0);
+        match self {
+            Self::Clamp => linear.min(1.0),
+            Self::SoftClip { knee } => {
+                let knee = knee.clamp(0.0, 0.999);
+                if linear <= knee {
+                    linear
+                } else {
+                    let t = (linear - knee) / (1.0 - knee);
+                    knee + (1.0 - knee) * t / (1.0 + t)
+                }
+            }
+            Self::AcesFilmic => {
+                let x = linear;
+                ((x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14)).clamp(0.0, 1.0)
+            }
+        }
+    }
+
+    /// Tone map a linear color with premultiplied alpha. Alpha is kept as is.
+    pub fn map_rgba(self, rgba: Rgba) -> Rgba {
+        let a = rgba.a();
+        if a <= 0.0 {
+            return Rgba::from_rgba_premultiplied(
+                self.map_linear(rgba.r()),
+                self.map_linear(rgba.g()),
+                self.map_linear(rgba.b()),
+                a,
+            );
+        }
+        Rgba::from_rgba_premultiplied(
+            a * self.map_linear(rgba.r() / a),
+            a * self.map_linear(rgba.g() / a),
+            a * self.map_linear(rgba.b() / a),
+            a,
+        )
+    }
+}
+
+#[test]
+fn test_tone_mapping() {
+    let soft = ToneMapping::SoftClip { knee: 0.8 };
+    assert_eq!(soft.map_linear(0.5), 0.5);
+    assert!(soft.map_linear(1.0) < 1.0);
+    assert!(soft.map_linear(2.0) < soft.map_linear(4.0));
+    assert!(soft.map_linear(1000.0) <= 1.0);
+
+    assert_eq!(ToneMapping::Clamp.map_linear(2.0), 1.0);
+    assert!(ToneMapping::AcesFilmic.map_linear(100.0) <= 1.0);
+}
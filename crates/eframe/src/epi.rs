@@ -153,12 +153,48 @@ pub trait App {
     ///
     /// On native the path is picked using [`crate::storage_dir`].
     /// The path can be customized via [`NativeOptions::persistence_path`].
+    ///
+    /// For large state, consider [`set_value_deferred`], which lets the storage
+    /// serialize it on a background thread, and [`Self::save_snapshot`] for the auto-saves.
     fn save(&mut self, _storage: &mut dyn Storage) {}
 
+    /// Called instead of [`Self::save`] for the auto-saves, unless it returns `None` (the default).
+    ///
+    /// The snapshot is stored at [`APP_KEY`] and serialized on the save thread,
+    /// so auto-saves never block the frame, however large your state is.
+    /// Keep the snapshot cheap to take, e.g. by sharing your state in an [`std::sync::Arc`].
+    ///
+    /// [`Self::save`] is still called on shutdown.
+    ///
+    /// ```
+    /// # #[derive(Clone, serde::Serialize)]
+    /// # struct Document;
+    /// struct MyApp {
+    ///     document: std::sync::Arc<Document>,
+    /// }
+    ///
+    /// impl eframe::App for MyApp {
+    ///     fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {}
+    ///
+    ///     fn save(&mut self, storage: &mut dyn eframe::Storage) {
+    ///         eframe::set_value(storage, eframe::APP_KEY, &*self.document);
+    ///     }
+    ///
+    ///     fn save_snapshot(&mut self) -> Option<eframe::Snapshot> {
+    ///         Some(eframe::Snapshot::new(self.document.clone()))
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "persistence")]
+    fn save_snapshot(&mut self) -> Option<Snapshot> {
+        None
+    }
+
     /// Called once on shutdown, after [`Self::save`].
     ///
     /// If you need to abort an exit check `ctx.input(|i| i.viewport().close_requested())`
     /// and respond with [`egui::ViewportCommand::CancelClose`].
+    /// To ask the user first (e.g. "Save changes?"), use [`egui::Context::defer_close`].
     ///
     /// To get a [`glow`] context you need to compile with the `glow` feature flag,
     /// and run eframe with the glow backend.
@@ -167,7 +203,9 @@ pub trait App {
 
     /// Called once on shutdown, after [`Self::save`].
     ///
-    /// If you need to abort an exit use [`Self::on_close_event`].
+    /// If you need to abort an exit check `ctx.input(|i| i.viewport().close_requested())`
+    /// and respond with [`egui::ViewportCommand::CancelClose`].
+    /// To ask the user first (e.g. "Save changes?"), use [`egui::Context::defer_close`].
     #[cfg(not(feature = "glow"))]
     fn on_exit(&mut self) {}
 
@@ -267,6 +305,18 @@ pub struct NativeOptions {
     /// The default is `true`.
     pub vsync: bool,
 
+    /// Limit the frame rate to at most this many frames per second.
+    ///
+    /// eframe will sleep between frames, so this reduces power usage
+    /// for apps that repaint continuously, e.g. because of an animation.
+    /// Unlike [`Self::vsync`] this works independently of the display refresh rate,
+    /// so you can cap a 144 Hz display at 60 or 30 Hz.
+    ///
+    /// Can be changed at runtime with [`Frame::set_max_fps`].
+    ///
+    /// The default is `None` (no limit).
+    pub max_fps: Option<f32>,
+
     /// Set the level of the multisampling anti-aliasing (MSAA).
     ///
     /// Must be a power-of-two. Higher = more smooth 3D.
@@ -400,6 +450,7 @@ impl Default for NativeOptions {
             viewport: Default::default(),
 
             vsync: true,
+            max_fps: None,
             multisampling: 0,
             depth_buffer: 0,
             stencil_buffer: 0,
@@ -627,6 +678,10 @@ pub struct Frame {
     #[cfg(feature = "wgpu")]
     pub(crate) wgpu_render_state: Option<egui_wgpu::RenderState>,
 
+    /// See [`NativeOptions::max_fps`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) max_fps: Option<f32>,
+
     /// Raw platform window handle
     #[cfg(not(target_arch = "wasm32"))]
     pub(crate) raw_window_handle: Result<RawWindowHandle, HandleError>,
@@ -717,6 +772,22 @@ impl Frame {
     pub fn wgpu_render_state(&self) -> Option<&egui_wgpu::RenderState> {
         self.wgpu_render_state.as_ref()
     }
+
+    /// The current frame rate limit, if any.
+    ///
+    /// See [`NativeOptions::max_fps`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn max_fps(&self) -> Option<f32> {
+        self.max_fps
+    }
+
+    /// Limit the frame rate to at most this many frames per second, or `None` for no limit.
+    ///
+    /// Takes effect from the next frame. See [`NativeOptions::max_fps`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_max_fps(&mut self, max_fps: Option<f32>) {
+        self.max_fps = max_fps;
+    }
 }
 
 /// Information about the web environment (if applicable).
@@ -823,8 +894,33 @@ pub trait Storage {
     /// Set the value for the given key.
     fn set_string(&mut self, key: &str, value: String);
 
+    /// Set the value for the given key, produced by `serialize` when it is needed.
+    ///
+    /// This lets a storage serialize large values later, e.g. on a background thread when flushing,
+    /// instead of on the main thread. `serialize` returns `None` if serialization failed.
+    ///
+    /// The default implementation calls `serialize` right away.
+    fn set_string_deferred(
+        &mut self,
+        key: &str,
+        serialize: Box<dyn FnOnce() -> Option<String> + Send>,
+    ) {
+        if let Some(value) = serialize() {
+            self.set_string(key, value);
+        }
+    }
+
     /// write-to-disk or similar
     fn flush(&mut self);
+
+    /// Like [`Self::flush`], but never blocks the calling thread.
+    ///
+    /// If a previous flush is still in progress, the changes are written by a later flush instead.
+    ///
+    /// The default implementation calls [`Self::flush`].
+    fn flush_async(&mut self) {
+        self.flush();
+    }
 }
 
 /// Stores nothing.
@@ -867,5 +963,53 @@ pub fn set_value<T: serde::Serialize>(storage: &mut dyn Storage, key: &str, valu
     }
 }
 
+/// Store `value` with the given key, serialized as [RON](https://github.com/ron-rs/ron) when needed.
+///
+/// Unlike [`set_value`], this takes ownership of `value`,
+/// so that the storage can serialize it on a background thread.
+/// See [`Storage::set_string_deferred`].
+#[cfg(feature = "ron")]
+pub fn set_value_deferred<T: serde::Serialize + Send + 'static>(
+    storage: &mut dyn Storage,
+    key: &str,
+    value: T,
+) {
+    let key_for_errors = key.to_owned();
+    storage.set_string_deferred(
+        key,
+        Box::new(move || {
+            crate::profile_scope!("set_value_deferred", key_for_errors.as_str());
+            match ron::ser::to_string(&value) {
+                Ok(string) => Some(string),
+                Err(err) => {
+                    log::error!("eframe failed to encode {key_for_errors:?} using ron: {err}");
+                    None
+                }
+            }
+        }),
+    );
+}
+
+/// A value to store, which is serialized as [RON](https://github.com/ron-rs/ron) when needed.
+///
+/// See [`App::save_snapshot`].
+#[cfg(feature = "ron")]
+pub struct Snapshot(Box<dyn FnOnce(&mut dyn Storage, &str)>);
+
+#[cfg(feature = "ron")]
+impl Snapshot {
+    /// Takes ownership of `value`, so that the storage can serialize it on a background thread.
+    pub fn new<T: serde::Serialize + Send + 'static>(value: T) -> Self {
+        Self(Box::new(move |storage, key| {
+            set_value_deferred(storage, key, value);
+        }))
+    }
+
+    /// Store the value at the given key, see [`set_value_deferred`].
+    pub fn store(self, storage: &mut dyn Storage, key: &str) {
+        (self.0)(storage, key);
+    }
+}
+
 /// [`Storage`] key used for app
 pub const APP_KEY: &str = "app";
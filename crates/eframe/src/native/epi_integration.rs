@@ -166,6 +166,7 @@ pub struct EpiIntegration {
     #[cfg(feature = "persistence")]
     persist_window: bool,
     app_icon_setter: super::app_icon::AppTitleIconSetter,
+    frame_limiter: super::frame_limiter::FrameLimiter,
 }
 
 impl EpiIntegration {
@@ -195,6 +196,7 @@ impl EpiIntegration {
             glow_register_native_texture,
             #[cfg(feature = "wgpu")]
             wgpu_render_state,
+            max_fps: native_options.max_fps,
             raw_display_handle: window.display_handle().map(|h| h.as_raw()),
             raw_window_handle: window.window_handle().map(|h| h.as_raw()),
         };
@@ -205,6 +207,8 @@ impl EpiIntegration {
             .clone()
             .unwrap_or_else(|| std::sync::Arc::new(load_default_egui_icon()));
 
+        super::session_end::install(window);
+
         let app_icon_setter = super::app_icon::AppTitleIconSetter::new(
             native_options
                 .viewport
@@ -225,6 +229,7 @@ impl EpiIntegration {
             #[cfg(feature = "persistence")]
             persist_window: native_options.persist_window,
             app_icon_setter,
+            frame_limiter: Default::default(),
             beginning: Instant::now(),
             is_first_frame: true,
             frame_start: Instant::now(),
@@ -319,6 +324,9 @@ impl EpiIntegration {
         });
 
         let is_root_viewport = viewport_ui_cb.is_none();
+        if is_root_viewport {
+            super::session_end::update(&self.egui_ctx, close_requested);
+        }
         if is_root_viewport && close_requested {
             let canceled = full_output.viewport_output[&ViewportId::ROOT]
                 .commands
@@ -347,6 +355,13 @@ impl EpiIntegration {
         }
     }
 
+    /// Sleep until it is time for the next frame, if [`epi::Frame::max_fps`] is set.
+    ///
+    /// Call after painting the root viewport.
+    pub fn limit_frame_rate(&mut self) {
+        self.frame_limiter.wait_for_next_frame(self.frame.max_fps);
+    }
+
     // ------------------------------------------------------------------------
     // Persistence stuff:
 
@@ -357,13 +372,23 @@ impl EpiIntegration {
     ) {
         let now = Instant::now();
         if now - self.last_auto_save > app.auto_save_interval() {
-            self.save(app, window);
+            self.save_impl(app, window, false);
             self.last_auto_save = now;
         }
     }
 
+    /// Save everything, and wait for any previous save to finish before writing.
+    pub fn save(&mut self, app: &mut dyn epi::App, window: Option<&winit::window::Window>) {
+        self.save_impl(app, window, true);
+    }
+
     #[allow(clippy::unused_self)]
-    pub fn save(&mut self, _app: &mut dyn epi::App, _window: Option<&winit::window::Window>) {
+    fn save_impl(
+        &mut self,
+        _app: &mut dyn epi::App,
+        _window: Option<&winit::window::Window>,
+        _wait_for_previous_save: bool,
+    ) {
         #[cfg(feature = "persistence")]
         if let Some(storage) = self.frame.storage_mut() {
             crate::profile_function!();
@@ -380,16 +405,29 @@ impl EpiIntegration {
             }
             if _app.persist_egui_memory() {
                 crate::profile_scope!("egui_memory");
-                self.egui_ctx
-                    .memory(|mem| epi::set_value(storage, STORAGE_EGUI_MEMORY_KEY, mem));
+                // Serialized by the storage, e.g. on a background thread:
+                let memory = self.egui_ctx.memory(|mem| mem.clone_persisted());
+                epi::set_value_deferred(storage, STORAGE_EGUI_MEMORY_KEY, memory);
             }
-            {
+            let snapshot = if _wait_for_previous_save {
+                None
+            } else {
+                crate::profile_scope!("App::save_snapshot");
+                _app.save_snapshot()
+            };
+            if let Some(snapshot) = snapshot {
+                snapshot.store(storage, epi::APP_KEY);
+            } else {
                 crate::profile_scope!("App::save");
                 _app.save(storage);
             }
 
             crate::profile_scope!("Storage::flush");
-            storage.flush();
+            if _wait_for_previous_save {
+                storage.flush();
+            } else {
+                storage.flush_async();
+            }
         }
     }
 }
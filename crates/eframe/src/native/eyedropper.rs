@@ -0,0 +1,689 @@
+//! Picking colors anywhere on the screen, for the eyedropper of the egui color picker.
+//!
+//! See [`egui::Context::set_screen_color_picker`].
+
+use egui::Color32;
+
+#[allow(dead_code)] // Not without the `eyedropper` feature, or on platforms without a screen color picker
+type Callback = Box<dyn FnOnce(Option<Color32>) + Send>;
+
+/// Set the screen color picker of the context, if this platform supports it.
+///
+/// Supported on Windows, Mac, and Linux, with the `eyedropper` feature.
+pub fn install(_egui_ctx: &egui::Context) {
+    #[cfg(all(target_os = "windows", feature = "eyedropper"))]
+    _egui_ctx.set_screen_color_picker(windows::pick);
+
+    #[cfg(all(target_os = "macos", feature = "eyedropper"))]
+    _egui_ctx.set_screen_color_picker(mac::pick);
+
+    #[cfg(all(target_os = "linux", feature = "eyedropper"))]
+    _egui_ctx.set_screen_color_picker(linux::pick);
+}
+
+/// Freezes the screen in a window covering all monitors, with a crosshair cursor
+/// and a magnifying loupe next to it.
+///
+/// A left click picks the color under the cursor.
+/// A right click, Escape, or switching to another window cancels.
+/// The clicks go to our window, so they never reach the windows under it.
+#[cfg(all(target_os = "windows", feature = "eyedropper"))]
+#[allow(unsafe_code)]
+mod windows {
+    use std::cell::RefCell;
+
+    use winapi::{
+        shared::{
+            minwindef::{LPARAM, LRESULT, UINT, WPARAM},
+            windef::{HBITMAP, HDC, HGDIOBJ, HWND, POINT, RECT},
+        },
+        um::{libloaderapi, wingdi, winuser},
+    };
+
+    use super::{Callback, Color32};
+
+    /// How many screen pixels the loupe shows in each direction from the cursor.
+    const LOUPE_RADIUS: i32 = 5;
+
+    /// How much the loupe magnifies.
+    const LOUPE_ZOOM: i32 = 11;
+
+    /// The size of the loupe in physical pixels.
+    const LOUPE_SIZE: i32 = (2 * LOUPE_RADIUS + 1) * LOUPE_ZOOM;
+
+    /// Distance between the cursor and the loupe.
+    const LOUPE_OFFSET: i32 = 24;
+
+    /// A copy of everything on the screen, taken before showing our window.
+    struct Screenshot {
+        dc: HDC,
+        bitmap: HBITMAP,
+        old_bitmap: HGDIOBJ,
+
+        /// The virtual screen, which covers all monitors.
+        rect: RECT,
+    }
+
+    impl Screenshot {
+        fn take() -> Option<Self> {
+            // SAFETY: all handles are checked, and released in `Drop` or right away.
+            unsafe {
+                let rect = RECT {
+                    left: winuser::GetSystemMetrics(winuser::SM_XVIRTUALSCREEN),
+                    top: winuser::GetSystemMetrics(winuser::SM_YVIRTUALSCREEN),
+                    right: winuser::GetSystemMetrics(winuser::SM_XVIRTUALSCREEN)
+                        + winuser::GetSystemMetrics(winuser::SM_CXVIRTUALSCREEN),
+                    bottom: winuser::GetSystemMetrics(winuser::SM_YVIRTUALSCREEN)
+                        + winuser::GetSystemMetrics(winuser::SM_CYVIRTUALSCREEN),
+                };
+                let (width, height) = (rect.right - rect.left, rect.bottom - rect.top);
+
+                let screen = winuser::GetDC(std::ptr::null_mut());
+                if screen.is_null() {
+                    return None;
+                }
+                let dc = wingdi::CreateCompatibleDC(screen);
+                let bitmap = wingdi::CreateCompatibleBitmap(screen, width, height);
+                if dc.is_null() || bitmap.is_null() {
+                    if !dc.is_null() {
+                        wingdi::DeleteDC(dc);
+                    }
+                    winuser::ReleaseDC(std::ptr::null_mut(), screen);
+                    return None;
+                }
+                let old_bitmap = wingdi::SelectObject(dc, bitmap.cast());
+                wingdi::BitBlt(
+                    dc,
+                    0,
+                    0,
+                    width,
+                    height,
+                    screen,
+                    rect.left,
+                    rect.top,
+                    wingdi::SRCCOPY | wingdi::CAPTUREBLT,
+                );
+                winuser::ReleaseDC(std::ptr::null_mut(), screen);
+
+                Some(Self {
+                    dc,
+                    bitmap,
+                    old_bitmap,
+                    rect,
+                })
+            }
+        }
+
+        /// The color at the given position in the window, which covers the screenshot.
+        fn pixel_at(&self, pos: POINT) -> Option<Color32> {
+            // SAFETY: `self.dc` is valid until `Drop`.
+            let color = unsafe { wingdi::GetPixel(self.dc, pos.x, pos.y) };
+            (color != wingdi::CLR_INVALID).then(|| {
+                Color32::from_rgb(
+                    wingdi::GetRValue(color),
+                    wingdi::GetGValue(color),
+                    wingdi::GetBValue(color),
+                )
+            })
+        }
+    }
+
+    impl Drop for Screenshot {
+        fn drop(&mut self) {
+            // SAFETY: the handles were created in `Screenshot::take`, and are not used after this.
+            unsafe {
+                wingdi::SelectObject(self.dc, self.old_bitmap);
+                wingdi::DeleteObject(self.bitmap.cast());
+                wingdi::DeleteDC(self.dc);
+            }
+        }
+    }
+
+    /// The state of the picking window, which runs on its own thread.
+    struct Picking {
+        screenshot: Screenshot,
+
+        /// In window coordinates.
+        cursor: Option<POINT>,
+
+        picked: Option<Color32>,
+    }
+
+    thread_local! {
+        static PICKING: RefCell<Option<Picking>> = const { RefCell::new(None) };
+    }
+
+    pub fn pick(callback: Callback) {
+        let spawned = std::thread::Builder::new()
+            .name("eframe_eyedropper".to_owned())
+            .spawn(move || callback(run_picking_window()));
+        if let Err(err) = spawned {
+            log::warn!("Failed to spawn the eyedropper thread: {err}");
+        }
+    }
+
+    /// Show the picking window, and run its message loop until the user picks or cancels.
+    fn run_picking_window() -> Option<Color32> {
+        let Some(screenshot) = Screenshot::take() else {
+            log::warn!("Failed to take a screenshot for the eyedropper");
+            return None;
+        };
+        let rect = screenshot.rect;
+        PICKING.with(|picking| {
+            *picking.borrow_mut() = Some(Picking {
+                screenshot,
+                cursor: None,
+                picked: None,
+            });
+        });
+
+        let class_name: Vec<u16> = "eframe_eyedropper\0".encode_utf16().collect();
+
+        // SAFETY: the window is created, used and destroyed on this thread,
+        // and `window_proc` only touches state of this thread.
+        unsafe {
+            let instance = libloaderapi::GetModuleHandleW(std::ptr::null());
+            let class = winuser::WNDCLASSEXW {
+                cbSize: std::mem::size_of::<winuser::WNDCLASSEXW>() as u32,
+                lpfnWndProc: Some(window_proc),
+                hInstance: instance,
+                hCursor: winuser::LoadCursorW(std::ptr::null_mut(), winuser::IDC_CROSS),
+                lpszClassName: class_name.as_ptr(),
+                ..std::mem::zeroed()
+            };
+            // Fails if it is already registered by an earlier pick, which is fine:
+            winuser::RegisterClassExW(&class);
+
+            let hwnd = winuser::CreateWindowExW(
+                winuser::WS_EX_TOPMOST | winuser::WS_EX_TOOLWINDOW,
+                class_name.as_ptr(),
+                std::ptr::null(),
+                winuser::WS_POPUP,
+                rect.left,
+                rect.top,
+                rect.right - rect.left,
+                rect.bottom - rect.top,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                instance,
+                std::ptr::null_mut(),
+            );
+            if hwnd.is_null() {
+                log::warn!("Failed to create the eyedropper window");
+            } else {
+                winuser::ShowWindow(hwnd, winuser::SW_SHOW);
+                winuser::SetForegroundWindow(hwnd);
+
+                let mut msg: winuser::MSG = std::mem::zeroed();
+                while 0 < winuser::GetMessageW(&mut msg, std::ptr::null_mut(), 0, 0) {
+                    winuser::TranslateMessage(&msg);
+                    winuser::DispatchMessageW(&msg);
+                }
+            }
+        }
+
+        PICKING
+            .with(|picking| picking.borrow_mut().take())
+            .and_then(|picking| picking.picked)
+    }
+
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: UINT,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        match msg {
+            winuser::WM_MOUSEMOVE => {
+                let cursor = point_from_lparam(lparam);
+                PICKING.with(|picking| {
+                    if let Some(picking) = picking.borrow_mut().as_mut() {
+                        // SAFETY: `hwnd` is our window.
+                        unsafe {
+                            if let Some(old_cursor) = picking.cursor {
+                                winuser::InvalidateRect(hwnd, &loupe_rect(old_cursor), 0);
+                            }
+                            winuser::InvalidateRect(hwnd, &loupe_rect(cursor), 0);
+                        }
+                        picking.cursor = Some(cursor);
+                    }
+                });
+                0
+            }
+
+            winuser::WM_LBUTTONUP => {
+                let cursor = point_from_lparam(lparam);
+                PICKING.with(|picking| {
+                    if let Some(picking) = picking.borrow_mut().as_mut() {
+                        picking.picked = picking.screenshot.pixel_at(cursor);
+                    }
+                });
+                // SAFETY: `hwnd` is our window.
+                unsafe { winuser::DestroyWindow(hwnd) };
+                0
+            }
+
+            winuser::WM_RBUTTONUP => {
+                // SAFETY: `hwnd` is our window.
+                unsafe { winuser::DestroyWindow(hwnd) };
+                0
+            }
+
+            winuser::WM_KEYDOWN if wparam == winuser::VK_ESCAPE as WPARAM => {
+                // SAFETY: `hwnd` is our window.
+                unsafe { winuser::DestroyWindow(hwnd) };
+                0
+            }
+
+            winuser::WM_ACTIVATE if wparam == winuser::WA_INACTIVE as WPARAM => {
+                // Don't leave a frozen screen behind when the user switches to another window:
+                // SAFETY: `hwnd` is our window.
+                unsafe { winuser::DestroyWindow(hwnd) };
+                0
+            }
+
+            winuser::WM_ERASEBKGND => 1, // We paint everything in `WM_PAINT`
+
+            winuser::WM_PAINT => {
+                // SAFETY: `hwnd` is our window, and we paint between `BeginPaint` and `EndPaint`.
+                unsafe {
+                    let mut paint: winuser::PAINTSTRUCT = std::mem::zeroed();
+                    let dc = winuser::BeginPaint(hwnd, &mut paint);
+                    PICKING.with(|picking| {
+                        if let Some(picking) = picking.borrow().as_ref() {
+                            paint_picking(dc, &paint.rcPaint, picking);
+                        }
+                    });
+                    winuser::EndPaint(hwnd, &paint);
+                }
+                0
+            }
+
+            winuser::WM_DESTROY => {
+                // SAFETY: quits the message loop of `run_picking_window`, which runs on this thread.
+                unsafe { winuser::PostQuitMessage(0) };
+                0
+            }
+
+            // SAFETY: passing on the arguments we were given.
+            _ => unsafe { winuser::DefWindowProcW(hwnd, msg, wparam, lparam) },
+        }
+    }
+
+    /// Paint the frozen screen, and the loupe next to the cursor.
+    unsafe fn paint_picking(dc: HDC, dirty: &RECT, picking: &Picking) {
+        let screenshot = &picking.screenshot;
+
+        // SAFETY: `dc` is being painted, and `screenshot.dc` is valid.
+        unsafe {
+            wingdi::BitBlt(
+                dc,
+                dirty.left,
+                dirty.top,
+                dirty.right - dirty.left,
+                dirty.bottom - dirty.top,
+                screenshot.dc,
+                dirty.left,
+                dirty.top,
+                wingdi::SRCCOPY,
+            );
+
+            let Some(cursor) = picking.cursor else {
+                return;
+            };
+            let loupe = loupe_rect(cursor);
+            wingdi::SetStretchBltMode(dc, wingdi::COLORONCOLOR as _);
+            wingdi::StretchBlt(
+                dc,
+                loupe.left,
+                loupe.top,
+                LOUPE_SIZE,
+                LOUPE_SIZE,
+                screenshot.dc,
+                cursor.x - LOUPE_RADIUS,
+                cursor.y - LOUPE_RADIUS,
+                2 * LOUPE_RADIUS + 1,
+                2 * LOUPE_RADIUS + 1,
+                wingdi::SRCCOPY,
+            );
+
+            // A frame around the loupe, and around the pixel that will be picked:
+            let black = wingdi::GetStockObject(wingdi::BLACK_BRUSH as _).cast();
+            let white = wingdi::GetStockObject(wingdi::WHITE_BRUSH as _).cast();
+            winuser::FrameRect(dc, &loupe, black);
+            let center = RECT {
+                left: loupe.left + LOUPE_RADIUS * LOUPE_ZOOM - 1,
+                top: loupe.top + LOUPE_RADIUS * LOUPE_ZOOM - 1,
+                right: loupe.left + (LOUPE_RADIUS + 1) * LOUPE_ZOOM + 1,
+                bottom: loupe.top + (LOUPE_RADIUS + 1) * LOUPE_ZOOM + 1,
+            };
+            winuser::FrameRect(dc, &center, black);
+            let center = RECT {
+                left: center.left + 1,
+                top: center.top + 1,
+                right: center.right - 1,
+                bottom: center.bottom - 1,
+            };
+            winuser::FrameRect(dc, &center, white);
+        }
+    }
+
+    /// Where the loupe is shown for the given cursor position, in window coordinates.
+    fn loupe_rect(cursor: POINT) -> RECT {
+        // Below and to the right of the cursor, where it doesn't hide what the user points at:
+        let left = cursor.x + LOUPE_OFFSET;
+        let top = cursor.y + LOUPE_OFFSET;
+        RECT {
+            left,
+            top,
+            right: left + LOUPE_SIZE,
+            bottom: top + LOUPE_SIZE,
+        }
+    }
+
+    fn point_from_lparam(lparam: LPARAM) -> POINT {
+        POINT {
+            x: (lparam & 0xffff) as u16 as i16 as i32,
+            y: ((lparam >> 16) & 0xffff) as u16 as i16 as i32,
+        }
+    }
+}
+
+/// Uses `NSColorSampler`, the magnifying loupe of the system color panel.
+#[cfg(all(target_os = "macos", feature = "eyedropper"))]
+#[allow(unsafe_code)]
+mod mac {
+    use std::cell::Cell;
+
+    use objc2_app_kit::{NSColor, NSColorSampler, NSColorSpace};
+
+    use super::{Callback, Color32};
+
+    pub fn pick(callback: Callback) {
+        let callback = Cell::new(Some(callback));
+        let handler = block2::RcBlock::new(move |color: *mut NSColor| {
+            let Some(callback) = callback.take() else {
+                return;
+            };
+
+            // SAFETY: `color` is either null (cancelled) or a valid color.
+            let color = unsafe { color.as_ref() };
+            // SAFETY: plain getters on a valid color.
+            let picked = color.and_then(|color| unsafe {
+                let color = color.colorUsingColorSpace(&NSColorSpace::sRGBColorSpace())?;
+                let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+                Some(Color32::from_rgb(
+                    channel(color.redComponent()),
+                    channel(color.greenComponent()),
+                    channel(color.blueComponent()),
+                ))
+            });
+            callback(picked);
+        });
+
+        // SAFETY: called on the main thread, where egui runs on Mac.
+        unsafe {
+            NSColorSampler::new().showSamplerWithSelectionHandler(&handler);
+        }
+    }
+}
+
+/// Asks the desktop portal to pick a color, which works on both Wayland and X11.
+///
+/// Without a portal, we grab the pointer on X11 and read the pixel under it when the user clicks.
+/// Wayland doesn't let apps read the screen, so there we need the portal.
+#[cfg(all(target_os = "linux", feature = "eyedropper"))]
+mod linux {
+    use super::{Callback, Color32};
+
+    pub fn pick(callback: Callback) {
+        let spawned = std::thread::Builder::new()
+            .name("eframe_eyedropper".to_owned())
+            .spawn(move || callback(pick_blocking()));
+        if let Err(err) = spawned {
+            log::warn!("Failed to spawn the eyedropper thread: {err}");
+        }
+    }
+
+    /// `None` if the user cancelled, or if picking failed.
+    fn pick_blocking() -> Option<Color32> {
+        let portal_err = match portal::pick_color() {
+            Ok(picked) => return picked,
+            Err(err) => err,
+        };
+
+        if std::env::var_os("WAYLAND_DISPLAY").is_none() {
+            match x11::pick_color() {
+                Ok(picked) => return picked,
+                Err(err) => {
+                    log::warn!(
+                        "Picking colors from the screen is not supported here. \
+                        The desktop portal failed: {portal_err}. Grabbing the X11 pointer failed: {err}"
+                    );
+                    return None;
+                }
+            }
+        }
+
+        log::warn!(
+            "Picking colors from the screen is not supported here. \
+            The desktop portal failed: {portal_err}"
+        );
+        None
+    }
+
+    /// `org.freedesktop.portal.Screenshot.PickColor`, see
+    /// <https://flatpak.github.io/xdg-desktop-portal/docs/doc-org.freedesktop.portal.Screenshot.html>.
+    mod portal {
+        use std::collections::HashMap;
+
+        use zbus::{
+            blocking::{Connection, Proxy},
+            zvariant::{OwnedValue, Value},
+        };
+
+        use super::Color32;
+
+        const DESTINATION: &str = "org.freedesktop.portal.Desktop";
+
+        pub fn pick_color() -> zbus::Result<Option<Color32>> {
+            let connection = Connection::session()?;
+
+            // Subscribe to the response before asking, so we can't miss it.
+            // The portal puts it at a path made from our name and the token we give it.
+            let sender = connection
+                .unique_name()
+                .ok_or_else(|| zbus::Error::Failure("No unique name on the session bus".into()))?
+                .trim_start_matches(':')
+                .replace('.', "_");
+            let token = format!("eframe_{}", std::process::id());
+            let request_path = format!("/org/freedesktop/portal/desktop/request/{sender}/{token}");
+            let request = Proxy::new(
+                &connection,
+                DESTINATION,
+                request_path,
+                "org.freedesktop.portal.Request",
+            )?;
+            let mut responses = request.receive_signal("Response")?;
+
+            let screenshot = Proxy::new(
+                &connection,
+                DESTINATION,
+                "/org/freedesktop/portal/desktop",
+                "org.freedesktop.portal.Screenshot",
+            )?;
+            let options = HashMap::from([("handle_token", Value::from(token.as_str()))]);
+            let _: zbus::zvariant::OwnedObjectPath =
+                screenshot.call("PickColor", &("", options))?;
+
+            let response = responses
+                .next()
+                .ok_or_else(|| zbus::Error::Failure("No response from the portal".into()))?;
+            let (code, results): (u32, HashMap<String, OwnedValue>) = response.body()?;
+            match code {
+                0 => {
+                    let color = results
+                        .get("color")
+                        .ok_or_else(|| zbus::Error::Failure("No color in the response".into()))?;
+                    let (r, g, b) = <(f64, f64, f64)>::try_from(color.clone())?;
+                    let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+                    Ok(Some(Color32::from_rgb(channel(r), channel(g), channel(b))))
+                }
+                1 => Ok(None), // cancelled by the user
+                _ => Err(zbus::Error::Failure(format!(
+                    "The portal failed to pick a color (response {code})"
+                ))),
+            }
+        }
+    }
+
+    /// Grabs the pointer and keyboard with a crosshair cursor.
+    ///
+    /// Releasing the left button picks the pixel under the cursor.
+    /// Any other button or key cancels.
+    mod x11 {
+        use x11rb::{
+            connection::Connection as _,
+            protocol::{
+                xproto::{
+                    ButtonIndex, ConnectionExt as _, EventMask, GrabMode, GrabStatus, ImageFormat,
+                    ImageOrder, Window,
+                },
+                Event,
+            },
+            CURRENT_TIME, NONE,
+        };
+
+        use super::Color32;
+
+        /// The crosshair in the X11 cursor font.
+        const XC_CROSSHAIR: u16 = 34;
+
+        type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        pub fn pick_color() -> Result<Option<Color32>> {
+            let (connection, screen_num) = x11rb::connect(None)?;
+            let screen = &connection.setup().roots[screen_num];
+            let root = screen.root;
+
+            let font = connection.generate_id()?;
+            connection.open_font(font, b"cursor")?;
+            let cursor = connection.generate_id()?;
+            connection.create_glyph_cursor(
+                cursor,
+                font,
+                font,
+                XC_CROSSHAIR,
+                XC_CROSSHAIR + 1,
+                0,
+                0,
+                0,
+                u16::MAX,
+                u16::MAX,
+                u16::MAX,
+            )?;
+            connection.close_font(font)?;
+
+            let picked = grab_and_pick(&connection, root, cursor);
+
+            connection.ungrab_pointer(CURRENT_TIME)?;
+            connection.ungrab_keyboard(CURRENT_TIME)?;
+            connection.free_cursor(cursor)?;
+            connection.flush()?;
+
+            picked
+        }
+
+        fn grab_and_pick(
+            connection: &impl x11rb::connection::Connection,
+            root: Window,
+            cursor: u32,
+        ) -> Result<Option<Color32>> {
+            let grabbed = connection
+                .grab_pointer(
+                    false,
+                    root,
+                    EventMask::BUTTON_PRESS | EventMask::BUTTON_RELEASE,
+                    GrabMode::ASYNC,
+                    GrabMode::ASYNC,
+                    NONE,
+                    cursor,
+                    CURRENT_TIME,
+                )?
+                .reply()?;
+            if grabbed.status != GrabStatus::SUCCESS {
+                return Err(format!("failed to grab the pointer: {:?}", grabbed.status).into());
+            }
+            // Without the keyboard, Escape can't cancel, which is fine:
+            let _ = connection
+                .grab_keyboard(false, root, CURRENT_TIME, GrabMode::ASYNC, GrabMode::ASYNC)?
+                .reply();
+
+            loop {
+                match connection.wait_for_event()? {
+                    Event::ButtonRelease(event) if event.detail == u8::from(ButtonIndex::M1) => {
+                        return pixel_at(connection, root, event.root_x, event.root_y);
+                    }
+                    Event::ButtonRelease(_) | Event::KeyPress(_) => return Ok(None),
+                    _ => {}
+                }
+            }
+        }
+
+        fn pixel_at(
+            connection: &impl x11rb::connection::Connection,
+            root: Window,
+            x: i16,
+            y: i16,
+        ) -> Result<Option<Color32>> {
+            let image = connection
+                .get_image(ImageFormat::Z_PIXMAP, root, x, y, 1, 1, u32::MAX)?
+                .reply()?;
+
+            let setup = connection.setup();
+            let Some(visual) = setup
+                .roots
+                .iter()
+                .flat_map(|screen| &screen.allowed_depths)
+                .flat_map(|depth| &depth.visuals)
+                .find(|visual| visual.visual_id == image.visual)
+            else {
+                return Ok(None);
+            };
+            let Some(format) = setup
+                .pixmap_formats
+                .iter()
+                .find(|format| format.depth == image.depth)
+            else {
+                return Ok(None);
+            };
+
+            let bytes_per_pixel = (format.bits_per_pixel as usize / 8).min(4);
+            let Some(bytes) = image.data.get(..bytes_per_pixel) else {
+                return Ok(None);
+            };
+            let pixel = if setup.image_byte_order == ImageOrder::LSB_FIRST {
+                bytes
+                    .iter()
+                    .rev()
+                    .fold(0, |pixel, &b| pixel << 8 | b as u32)
+            } else {
+                bytes.iter().fold(0, |pixel, &b| pixel << 8 | b as u32)
+            };
+
+            let channel = |mask: u32| {
+                if mask == 0 {
+                    return 0;
+                }
+                let value = (pixel & mask) >> mask.trailing_zeros();
+                let max = mask >> mask.trailing_zeros();
+                (value * 255 / max) as u8
+            };
+            Ok(Some(Color32::from_rgb(
+                channel(visual.red_mask),
+                channel(visual.green_mask),
+                channel(visual.blue_mask),
+            )))
+        }
+    }
+}
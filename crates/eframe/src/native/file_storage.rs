@@ -2,8 +2,11 @@ use std::{
     collections::HashMap,
     io::Write,
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
+use egui::mutex::Mutex;
+
 /// The folder where `eframe` will store its state.
 ///
 /// The given `app_id` is either the
@@ -26,6 +29,10 @@ pub fn storage_dir(app_id: &str) -> Option<PathBuf> {
 pub struct FileStorage {
     ron_filepath: PathBuf,
     kv: HashMap<String, String>,
+
+    /// Values set with [`crate::Storage::set_string_deferred`], serialized by the save thread.
+    deferred: HashMap<String, Arc<Mutex<DeferredValue>>>,
+
     dirty: bool,
     last_save_join_handle: Option<std::thread::JoinHandle<()>>,
 }
@@ -48,6 +55,7 @@ impl FileStorage {
         Self {
             kv: read_ron(&ron_filepath).unwrap_or_default(),
             ron_filepath,
+            deferred: Default::default(),
             dirty: false,
             last_save_join_handle: None,
         }
@@ -76,16 +84,36 @@ impl FileStorage {
 
 impl crate::Storage for FileStorage {
     fn get_string(&self, key: &str) -> Option<String> {
+        if let Some(deferred) = self.deferred.get(key) {
+            return deferred.lock().value().cloned();
+        }
         self.kv.get(key).cloned()
     }
 
     fn set_string(&mut self, key: &str, value: String) {
-        if self.kv.get(key) != Some(&value) {
+        let was_deferred = self.deferred.remove(key).is_some();
+        if was_deferred || self.kv.get(key) != Some(&value) {
             self.kv.insert(key.to_owned(), value);
             self.dirty = true;
         }
     }
 
+    fn set_string_deferred(
+        &mut self,
+        key: &str,
+        serialize: Box<dyn FnOnce() -> Option<String> + Send>,
+    ) {
+        self.kv.remove(key);
+        self.deferred.insert(
+            key.to_owned(),
+            Arc::new(Mutex::new(DeferredValue {
+                serialize: Some(serialize),
+                value: None,
+            })),
+        );
+        self.dirty = true;
+    }
+
     fn flush(&mut self) {
         if self.dirty {
             crate::profile_function!();
@@ -93,6 +121,11 @@ impl crate::Storage for FileStorage {
 
             let file_path = self.ron_filepath.clone();
             let kv = self.kv.clone();
+            let deferred: Vec<_> = self
+                .deferred
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
 
             if let Some(join_handle) = self.last_save_join_handle.take() {
                 // wait for previous save to complete.
@@ -102,6 +135,12 @@ impl crate::Storage for FileStorage {
             let result = std::thread::Builder::new()
                 .name("eframe_persist".to_owned())
                 .spawn(move || {
+                    let mut kv = kv;
+                    for (key, value) in deferred {
+                        if let Some(value) = value.lock().value() {
+                            kv.insert(key, value.clone());
+                        }
+                    }
                     save_to_disk(&file_path, &kv);
                 });
             match result {
@@ -114,6 +153,35 @@ impl crate::Storage for FileStorage {
             }
         }
     }
+
+    fn flush_async(&mut self) {
+        let is_saving = self
+            .last_save_join_handle
+            .as_ref()
+            .is_some_and(|join_handle| !join_handle.is_finished());
+        if is_saving {
+            // Stay dirty, and save on a later flush instead of waiting here.
+            return;
+        }
+        self.flush();
+    }
+}
+
+/// A value that is serialized the first time it is needed:
+/// usually on the save thread, or when it is read back before that.
+struct DeferredValue {
+    serialize: Option<Box<dyn FnOnce() -> Option<String> + Send>>,
+    value: Option<String>,
+}
+
+impl DeferredValue {
+    fn value(&mut self) -> Option<&String> {
+        if let Some(serialize) = self.serialize.take() {
+            crate::profile_scope!("serialize");
+            self.value = serialize();
+        }
+        self.value.as_ref()
+    }
 }
 
 fn save_to_disk(file_path: &PathBuf, kv: &HashMap<String, String>) {
@@ -0,0 +1,60 @@
+//! Limit the frame rate by sleeping between frames.
+//!
+//! See [`crate::NativeOptions::max_fps`].
+
+use std::time::{Duration, Instant};
+
+/// Keeps track of when the next frame is due.
+#[derive(Default)]
+pub struct FrameLimiter {
+    next_frame: Option<Instant>,
+}
+
+impl FrameLimiter {
+    /// Call once per frame, after painting.
+    ///
+    /// Sleeps until it is time for the next frame, unless we are already late.
+    /// With `max_fps == None` this does nothing.
+    pub fn wait_for_next_frame(&mut self, max_fps: Option<f32>) {
+        let Some(frame_time) = max_fps
+            .filter(|max_fps| max_fps.is_finite() && 0.0 < *max_fps)
+            .and_then(|max_fps| Duration::try_from_secs_f32(1.0 / max_fps).ok())
+        else {
+            self.next_frame = None;
+            return;
+        };
+
+        let now = Instant::now();
+
+        // Schedule relative to the previous deadline rather than to `now`,
+        // so that oversleeping one frame doesn't lower the average frame rate.
+        // [`std::thread::sleep`] often oversleeps by a millisecond or more (much more on Windows).
+        let deadline = match self.next_frame {
+            Some(next_frame)
+                if next_frame
+                    .checked_add(frame_time)
+                    .map_or(false, |too_late| now < too_late) =>
+            {
+                next_frame
+            }
+            _ => now, // First frame, or we fell far behind (e.g. the app was idle): start over
+        };
+
+        if now < deadline {
+            crate::profile_scope!("frame_limiter");
+            sleep_until(deadline);
+        }
+
+        self.next_frame = deadline.checked_add(frame_time);
+    }
+}
+
+fn sleep_until(deadline: Instant) {
+    // A sleep can also end early, so check how much is left after each one:
+    while let Some(duration) = deadline
+        .checked_duration_since(Instant::now())
+        .filter(|duration| !duration.is_zero())
+    {
+        std::thread::sleep(duration);
+    }
+}
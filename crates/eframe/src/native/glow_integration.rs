@@ -740,6 +740,10 @@ impl GlowWinitRunning {
 
         integration.maybe_autosave(app.as_mut(), Some(&window));
 
+        if viewport_id == ViewportId::ROOT {
+            integration.limit_frame_rate();
+        }
+
         if window.is_minimized() == Some(true) {
             // On Mac, a minimized Window uses up all CPU:
             // https://github.com/emilk/egui/issues/325
@@ -782,6 +786,37 @@ impl GlowWinitRunning {
         match event {
             winit::event::WindowEvent::Focused(new_focused) => {
                 glutin.focused_viewport = new_focused.then(|| viewport_id).flatten();
+
+                if *new_focused {
+                    // A viewport that is not focusable (e.g. a tool palette) gives focus back to its parent:
+                    let parent_window = viewport_id
+                        .and_then(|viewport_id| glutin.viewports.get(&viewport_id))
+                        .filter(|viewport| viewport.builder.focusable == Some(false))
+                        .and_then(|viewport| glutin.viewports.get(&viewport.ids.parent))
+                        .and_then(|parent| parent.window.clone());
+                    if let Some(parent_window) = parent_window {
+                        parent_window.focus_window();
+                    }
+                }
+            }
+
+            winit::event::WindowEvent::ScaleFactorChanged { .. } => {
+                // The window moved to a monitor with a different scale factor.
+                // Resize the surface and repaint right away, instead of showing
+                // one frame laid out for the old scale factor.
+                if let Some(viewport_id) = viewport_id {
+                    let window = glutin
+                        .viewports
+                        .get(&viewport_id)
+                        .and_then(|viewport| viewport.window.clone());
+                    if let Some(window) = window {
+                        let physical_size = window.inner_size();
+                        if 0 < physical_size.width && 0 < physical_size.height {
+                            glutin.resize(viewport_id, physical_size);
+                        }
+                    }
+                    repaint_asap = true;
+                }
             }
 
             winit::event::WindowEvent::Resized(physical_size) => {
@@ -1091,6 +1126,12 @@ impl GlutinWindowContext {
     ) -> Result {
         crate::profile_function!();
 
+        let parent_window = self
+            .viewports
+            .get(&viewport_id)
+            .and_then(|viewport| self.viewports.get(&viewport.ids.parent))
+            .and_then(|parent| parent.window.clone());
+
         let viewport = self
             .viewports
             .get_mut(&viewport_id)
@@ -1100,11 +1141,18 @@ impl GlutinWindowContext {
             window
         } else {
             log::debug!("Creating a window for viewport {viewport_id:?}");
-            let window_builder = egui_winit::create_winit_window_builder(
+            let mut window_builder = egui_winit::create_winit_window_builder(
                 &self.egui_ctx,
                 event_loop,
                 viewport.builder.clone(),
             );
+            if let Some(parent_window) = &parent_window {
+                window_builder = egui_winit::with_parent_window(
+                    window_builder,
+                    &viewport.builder,
+                    parent_window,
+                );
+            }
             if window_builder.transparent() && self.gl_config.supports_transparency() == Some(false)
             {
                 log::error!("Cannot create transparent window: the GL config does not support it");
@@ -1,6 +1,9 @@
 mod app_icon;
 mod epi_integration;
+mod eyedropper;
+mod frame_limiter;
 pub mod run;
+mod session_end;
 
 /// File storage which can be used by native backends.
 #[cfg(feature = "persistence")]
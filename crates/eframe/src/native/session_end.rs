@@ -0,0 +1,143 @@
+//! Asking the OS to wait when the user logs out or shuts down the computer.
+//!
+//! See [`egui::Context::set_session_end_blocker`].
+//!
+//! Only supported on Windows, with the `session_end_blocker` feature.
+
+/// Start listening for the end of the session on the root window, if this platform supports it.
+pub fn install(_window: &winit::window::Window) {
+    #[cfg(all(target_os = "windows", feature = "session_end_blocker"))]
+    windows::install(_window);
+}
+
+/// Call after each frame of the root viewport.
+///
+/// `close_requested` is whether the close was requested in that frame.
+pub fn update(_egui_ctx: &egui::Context, _close_requested: bool) {
+    #[cfg(all(target_os = "windows", feature = "session_end_blocker"))]
+    windows::update(_egui_ctx, _close_requested);
+}
+
+/// Replaces the window procedure of the root window to answer `WM_QUERYENDSESSION`.
+///
+/// Windows sends that message straight to the window procedure, and winit doesn't forward it,
+/// so we subclass the window with `SetWindowLongPtrW`, and pass everything else on to winit's procedure.
+/// The state is global, so this only works for a single root window per process:
+/// later windows are not subclassed.
+///
+/// When the app has a blocker, we tell Windows to wait, show the reason with
+/// `ShutdownBlockReasonCreate`, and post a `WM_CLOSE` so that the app gets a normal close request.
+/// Windows then lets the user choose between waiting for the app and shutting down anyway.
+#[cfg(all(target_os = "windows", feature = "session_end_blocker"))]
+#[allow(unsafe_code)]
+mod windows {
+    use std::sync::{
+        atomic::{AtomicIsize, AtomicU8, Ordering},
+        Mutex,
+    };
+
+    use raw_window_handle::{HasWindowHandle as _, RawWindowHandle};
+    use winapi::{
+        shared::{
+            minwindef::{LPARAM, LRESULT, UINT, WPARAM},
+            windef::HWND,
+        },
+        um::winuser,
+    };
+
+    /// The root window, which is the only one we listen to.
+    static WINDOW: AtomicIsize = AtomicIsize::new(0);
+
+    /// The window procedure of winit, which we pass everything else on to.
+    static ORIGINAL_WNDPROC: AtomicIsize = AtomicIsize::new(0);
+
+    /// From [`egui::Context::session_end_blocker`], as a nul-terminated UTF-16 string.
+    static BLOCKER: Mutex<Option<Vec<u16>>> = Mutex::new(None);
+
+    /// Nothing is blocking the end of the session.
+    const IDLE: u8 = 0;
+
+    /// We told Windows to wait, and are waiting for the app to see the close request.
+    const ASKED: u8 = 1;
+
+    /// The app deferred the close, and Windows waits until it decides.
+    const DEFERRED: u8 = 2;
+
+    static STATE: AtomicU8 = AtomicU8::new(IDLE);
+
+    pub fn install(window: &winit::window::Window) {
+        let Ok(handle) = window.window_handle() else {
+            return;
+        };
+        let RawWindowHandle::Win32(handle) = handle.as_raw() else {
+            return;
+        };
+        let hwnd = handle.hwnd.get();
+
+        if WINDOW.swap(hwnd, Ordering::SeqCst) != 0 {
+            return; // there is only one root window
+        }
+
+        // SAFETY: `hwnd` is a live window of this thread, and `wnd_proc` passes on
+        // everything it doesn't handle to the procedure it replaces.
+        let original = unsafe {
+            winuser::SetWindowLongPtrW(
+                hwnd as HWND,
+                winuser::GWLP_WNDPROC,
+                wnd_proc as usize as isize,
+            )
+        };
+        ORIGINAL_WNDPROC.store(original, Ordering::SeqCst);
+    }
+
+    pub fn update(egui_ctx: &egui::Context, close_requested: bool) {
+        let blocker = egui_ctx.session_end_blocker().map(|reason| {
+            reason
+                .encode_utf16()
+                .take(winuser::MAX_STR_BLOCKREASON - 1)
+                .chain(std::iter::once(0))
+                .collect()
+        });
+        *BLOCKER.lock().unwrap_or_else(|err| err.into_inner()) = blocker;
+
+        let is_deferred = egui_ctx.is_close_deferred_of(egui::ViewportId::ROOT);
+        let state = STATE.load(Ordering::SeqCst);
+        if state == ASKED && close_requested && is_deferred {
+            STATE.store(DEFERRED, Ordering::SeqCst);
+        } else if (state == ASKED && close_requested) || (state == DEFERRED && !is_deferred) {
+            // The app closes, or stays open and wants the session to end after all.
+            STATE.store(IDLE, Ordering::SeqCst);
+            let hwnd = WINDOW.load(Ordering::SeqCst) as HWND;
+            // SAFETY: called on the thread of the window, which is still alive.
+            unsafe { winuser::ShutdownBlockReasonDestroy(hwnd) };
+        }
+    }
+
+    unsafe extern "system" fn wnd_proc(
+        hwnd: HWND,
+        msg: UINT,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        if msg == winuser::WM_QUERYENDSESSION {
+            let blocker = BLOCKER.lock().unwrap_or_else(|err| err.into_inner());
+            if let Some(reason) = blocker.as_ref() {
+                if STATE.swap(ASKED, Ordering::SeqCst) == IDLE {
+                    // SAFETY: `reason` is nul-terminated, and `hwnd` is the window we were called for.
+                    unsafe {
+                        winuser::ShutdownBlockReasonCreate(hwnd, reason.as_ptr());
+                        winuser::PostMessageW(hwnd, winuser::WM_CLOSE, 0, 0);
+                    }
+                }
+                return 0; // Wait for us
+            }
+        }
+
+        // SAFETY: `ORIGINAL_WNDPROC` was returned by `SetWindowLongPtrW` for this window.
+        unsafe {
+            let original: winuser::WNDPROC =
+                std::mem::transmute(ORIGINAL_WNDPROC.load(Ordering::SeqCst));
+            winuser::CallWindowProcW(original, hwnd, msg, wparam, lparam)
+        }
+    }
+}
@@ -131,13 +131,18 @@ impl WgpuWinitApp {
             ..
         } = &mut *shared;
 
-        for viewport in viewports.values_mut() {
-            viewport.initialize_window(
-                event_loop,
-                &running.integration.egui_ctx,
-                viewport_from_window,
-                painter,
-            );
+        let viewport_ids: Vec<ViewportId> = viewports.keys().copied().collect();
+        for viewport_id in viewport_ids {
+            let parent_window = parent_window(viewports, viewport_id);
+            if let Some(viewport) = viewports.get_mut(&viewport_id) {
+                viewport.initialize_window(
+                    event_loop,
+                    &running.integration.egui_ctx,
+                    viewport_from_window,
+                    painter,
+                    parent_window.as_deref(),
+                );
+            }
         }
     }
 
@@ -162,7 +167,7 @@ impl WgpuWinitApp {
             self.native_options.viewport.clone(),
             None,
         )
-        .initialize_window(event_loop, egui_ctx, viewport_from_window, painter);
+        .initialize_window(event_loop, egui_ctx, viewport_from_window, painter, None);
     }
 
     #[cfg(target_os = "android")]
@@ -740,6 +745,10 @@ impl WgpuWinitRunning {
 
         integration.maybe_autosave(app.as_mut(), window.map(|w| w.as_ref()));
 
+        if viewport_id == ViewportId::ROOT {
+            integration.limit_frame_rate();
+        }
+
         if let Some(window) = window {
             if window.is_minimized() == Some(true) {
                 // On Mac, a minimized Window uses up all CPU:
@@ -790,6 +799,40 @@ impl WgpuWinitRunning {
         match event {
             winit::event::WindowEvent::Focused(new_focused) => {
                 shared.focused_viewport = new_focused.then(|| viewport_id).flatten();
+
+                if *new_focused {
+                    // A viewport that is not focusable (e.g. a tool palette) gives focus back to its parent:
+                    let parent_window = viewport_id
+                        .and_then(|viewport_id| shared.viewports.get(&viewport_id))
+                        .filter(|viewport| viewport.builder.focusable == Some(false))
+                        .and_then(|viewport| shared.viewports.get(&viewport.ids.parent))
+                        .and_then(|parent| parent.window.clone());
+                    if let Some(parent_window) = parent_window {
+                        parent_window.focus_window();
+                    }
+                }
+            }
+
+            winit::event::WindowEvent::ScaleFactorChanged { .. } => {
+                // The window moved to a monitor with a different scale factor.
+                // Resize the surface and repaint right away, instead of showing
+                // one frame laid out for the old scale factor.
+                if let Some(viewport_id) = viewport_id {
+                    let window = shared
+                        .viewports
+                        .get(&viewport_id)
+                        .and_then(|viewport| viewport.window.clone());
+                    if let Some(window) = window {
+                        let physical_size = window.inner_size();
+                        if let (Some(width), Some(height)) = (
+                            NonZeroU32::new(physical_size.width),
+                            NonZeroU32::new(physical_size.height),
+                        ) {
+                            shared.painter.on_window_resized(viewport_id, width, height);
+                        }
+                    }
+                    repaint_asap = true;
+                }
             }
 
             winit::event::WindowEvent::Resized(physical_size) => {
@@ -868,6 +911,7 @@ impl Viewport {
         egui_ctx: &egui::Context,
         windows_id: &mut HashMap<WindowId, ViewportId>,
         painter: &mut egui_wgpu::winit::Painter,
+        parent_window: Option<&Window>,
     ) {
         if self.window.is_some() {
             return; // we already have one
@@ -877,7 +921,12 @@ impl Viewport {
 
         let viewport_id = self.ids.this;
 
-        match egui_winit::create_window(egui_ctx, event_loop, &self.builder) {
+        match egui_winit::create_window_with_parent(
+            egui_ctx,
+            event_loop,
+            &self.builder,
+            parent_window,
+        ) {
             Ok(window) => {
                 windows_id.insert(window.id(), viewport_id);
 
@@ -952,10 +1001,19 @@ fn render_immediate_viewport(
             ..
         } = &mut *shared.borrow_mut();
 
+        let parent_window = viewports
+            .get(&ids.parent)
+            .and_then(|parent| parent.window.clone());
         let viewport =
             initialize_or_update_viewport(viewports, ids, ViewportClass::Immediate, builder, None);
         if viewport.window.is_none() {
-            viewport.initialize_window(event_loop, egui_ctx, viewport_from_window, painter);
+            viewport.initialize_window(
+                event_loop,
+                egui_ctx,
+                viewport_from_window,
+                painter,
+                parent_window.as_deref(),
+            );
         }
 
         let (Some(window), Some(egui_winit)) = (&viewport.window, &mut viewport.egui_winit) else {
@@ -1107,6 +1165,12 @@ fn handle_viewport_output(
     remove_viewports_not_in(viewports, painter, viewport_from_window, viewport_output);
 }
 
+/// The window of the parent of the given viewport, if it has one yet.
+fn parent_window(viewports: &Viewports, viewport_id: ViewportId) -> Option<Arc<Window>> {
+    let parent_id = viewports.get(&viewport_id)?.ids.parent;
+    viewports.get(&parent_id)?.window.clone()
+}
+
 fn initialize_or_update_viewport(
     viewports: &mut Viewports,
     ids: ViewportIdPair,
@@ -24,6 +24,7 @@ pub fn create_egui_context(storage: Option<&dyn crate::Storage>) -> egui::Contex
     let egui_ctx = egui::Context::default();
 
     egui_ctx.set_embed_viewports(!IS_DESKTOP);
+    super::eyedropper::install(&egui_ctx);
 
     let memory = crate::native::epi_integration::load_egui_memory(storage).unwrap_or_default();
     egui_ctx.memory_mut(|mem| *mem = memory);
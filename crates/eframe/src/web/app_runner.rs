@@ -60,6 +60,8 @@ impl AppRunner {
             &super::user_agent().unwrap_or_default(),
         ));
         super::storage::load_memory(&egui_ctx);
+        egui_ctx.set_spawner(wasm_bindgen_futures::spawn_local);
+        super::eyedropper::install(&egui_ctx);
 
         egui_ctx.options_mut(|o| {
             // On web by default egui follows the zoom factor of the browser,
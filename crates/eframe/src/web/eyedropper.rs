@@ -0,0 +1,42 @@
+//! Picking colors anywhere on the screen with the
+//! [`EyeDropper` API](https://developer.mozilla.org/en-US/docs/Web/API/EyeDropper),
+//! for the eyedropper of the egui color picker.
+
+use wasm_bindgen::prelude::*;
+
+/// Set the screen color picker of the context, if the browser supports the `EyeDropper` API.
+///
+/// At the time of writing, only Chromium-based browsers do.
+pub(crate) fn install(egui_ctx: &egui::Context) {
+    if !cfg!(feature = "eyedropper") || eye_dropper_constructor().is_err() {
+        return;
+    }
+
+    egui_ctx.set_screen_color_picker(|callback| {
+        wasm_bindgen_futures::spawn_local(async move {
+            match pick().await {
+                Ok(color) => callback(color),
+                Err(err) => {
+                    // Also happens when the user presses escape.
+                    log::debug!("EyeDropper failed: {}", super::string_from_js_value(&err));
+                    callback(None);
+                }
+            }
+        });
+    });
+}
+
+fn eye_dropper_constructor() -> Result<js_sys::Function, JsValue> {
+    let window = web_sys::window().ok_or("No window")?;
+    js_sys::Reflect::get(&window, &"EyeDropper".into())?.dyn_into()
+}
+
+async fn pick() -> Result<Option<egui::Color32>, JsValue> {
+    let eye_dropper =
+        js_sys::Reflect::construct(&eye_dropper_constructor()?, &js_sys::Array::new())?;
+    let open: js_sys::Function = js_sys::Reflect::get(&eye_dropper, &"open".into())?.dyn_into()?;
+    let promise: js_sys::Promise = open.call0(&eye_dropper)?.dyn_into()?;
+    let result = wasm_bindgen_futures::JsFuture::from(promise).await?;
+    let hex = js_sys::Reflect::get(&result, &"sRGBHex".into())?.as_string();
+    Ok(hex.and_then(|hex| egui::Color32::from_hex(&hex).ok()))
+}
@@ -5,6 +5,7 @@
 mod app_runner;
 mod backend;
 mod events;
+mod eyedropper;
 mod input;
 mod panic_handler;
 mod text_agent;
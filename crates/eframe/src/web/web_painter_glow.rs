@@ -51,6 +51,9 @@ impl WebPainter for WebPainterGlow {
     ) -> Result<(), JsValue> {
         let canvas_dimension = [self.canvas.width(), self.canvas.height()];
 
+        for &(id, size, options) in &textures_delta.allocate {
+            self.painter.allocate_texture(id, size, options);
+        }
         for (id, image_delta) in &textures_delta.set {
             self.painter.set_texture(*id, image_delta);
         }
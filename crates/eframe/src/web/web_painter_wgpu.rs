@@ -238,6 +238,9 @@ impl WebPainter for WebPainterWgpu {
 
         let user_cmd_bufs = {
             let mut renderer = render_state.renderer.write();
+            for &(id, size, options) in &textures_delta.allocate {
+                renderer.allocate_texture(&render_state.device, id, size, options);
+            }
             for (id, image_delta) in &textures_delta.set {
                 renderer.update_texture(
                     &render_state.device,
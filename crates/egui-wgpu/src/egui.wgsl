@@ -8,9 +8,14 @@ struct VertexOutput {
 
 struct Locals {
     screen_size: vec2<f32>,
-    // Uniform buffers need to be at least 16 bytes in WebGL.
+    // 0 = none, 1 = soft clip, 2 = ACES filmic. See `ecolor::ToneMapping`.
+    // Also pads the buffer: uniform buffers need to be at least 16 bytes in WebGL.
     // See https://github.com/gfx-rs/wgpu/issues/2072
-    _padding: vec2<u32>,
+    tone_mapping: u32,
+    tone_mapping_knee: f32,
+    // 0 = sRGB, 1 = Display P3
+    output_color_space: u32,
+    _padding: u32,
 };
 @group(0) @binding(0) var<uniform> r_locals: Locals;
 
@@ -35,6 +40,91 @@ fn gamma_from_linear_rgba(linear_rgba: vec4<f32>) -> vec4<f32> {
     return vec4<f32>(gamma_from_linear_rgb(linear_rgba.rgb), linear_rgba.a);
 }
 
+// 0-1 linear  from  0-inf linear, using the tone mapping in `r_locals`
+fn tone_map_linear_rgb(linear: vec3<f32>) -> vec3<f32> {
+    let x = max(linear, vec3<f32>(0.0));
+    if r_locals.tone_mapping == 1u {
+        let knee = r_locals.tone_mapping_knee;
+        let t = (x - vec3<f32>(knee)) / (1.0 - knee);
+        let compressed = vec3<f32>(knee) + (1.0 - knee) * t / (vec3<f32>(1.0) + t);
+        return select(x, compressed, x > vec3<f32>(knee));
+    } else if r_locals.tone_mapping == 2u {
+        return clamp((x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14), vec3<f32>(0.0), vec3<f32>(1.0));
+    }
+    return x; // The framebuffer clamps
+}
+
+// Tone map a premultiplied sRGBA gamma color (which can only be HDR because of HDR textures,
+// see `Renderer::register_hdr_texture`). Used by all paths, so that SDR colors are mapped the same everywhere.
+fn tone_map_gamma_rgba(color_gamma: vec4<f32>) -> vec4<f32> {
+    if r_locals.tone_mapping == 0u || color_gamma.a <= 0.0 {
+        return color_gamma;
+    }
+    let linear = linear_from_gamma_rgb(color_gamma.rgb / color_gamma.a);
+    return vec4<f32>(gamma_from_linear_rgb(tone_map_linear_rgb(linear)) * color_gamma.a, color_gamma.a);
+}
+
+// linear Display P3  from  linear sRGB
+fn display_p3_from_linear_srgb(rgb: vec3<f32>) -> vec3<f32> {
+    return vec3<f32>(
+        dot(vec3<f32>(0.8224621, 0.177538, 0.0), rgb),
+        dot(vec3<f32>(0.0331941, 0.9668058, 0.0), rgb),
+        dot(vec3<f32>(0.0170827, 0.0723974, 0.9105199), rgb),
+    );
+}
+
+// linear sRGB  from  linear Display P3.
+// Colors outside of the sRGB gamut get channels below 0 or above 1.
+fn linear_srgb_from_display_p3(rgb: vec3<f32>) -> vec3<f32> {
+    return vec3<f32>(
+        dot(vec3<f32>(1.2249401, -0.2249404, 0.0), rgb),
+        dot(vec3<f32>(-0.0420569, 1.0420571, 0.0), rgb),
+        dot(vec3<f32>(-0.0196376, -0.0786361, 1.0982735), rgb),
+    );
+}
+
+// Like `linear_from_gamma_rgb` and `gamma_from_linear_rgb`, but mirrored for values below 0,
+// which colors outside of the sRGB gamut have.
+fn linear_from_gamma_rgb_extended(srgb: vec3<f32>) -> vec3<f32> {
+    return sign(srgb) * linear_from_gamma_rgb(abs(srgb));
+}
+fn gamma_from_linear_rgb_extended(rgb: vec3<f32>) -> vec3<f32> {
+    return sign(rgb) * gamma_from_linear_rgb(abs(rgb));
+}
+
+// Convert a premultiplied sRGBA gamma color to the primaries of the output.
+fn output_from_srgb_gamma_rgba(color_gamma: vec4<f32>) -> vec4<f32> {
+    if r_locals.output_color_space == 0u || color_gamma.a <= 0.0 {
+        return color_gamma;
+    }
+    let linear = linear_from_gamma_rgb_extended(color_gamma.rgb / color_gamma.a);
+    return vec4<f32>(gamma_from_linear_rgb_extended(display_p3_from_linear_srgb(linear)) * color_gamma.a, color_gamma.a);
+}
+
+// Convert a premultiplied gamma color with the primaries of the output to sRGBA gamma.
+// Textures are uploaded in the color space of the output, so that they keep their wide gamut colors.
+fn srgb_from_output_gamma_rgba(color_gamma: vec4<f32>) -> vec4<f32> {
+    if r_locals.output_color_space == 0u || color_gamma.a <= 0.0 {
+        return color_gamma;
+    }
+    let linear = linear_from_gamma_rgb(color_gamma.rgb / color_gamma.a);
+    return vec4<f32>(gamma_from_linear_rgb_extended(linear_srgb_from_display_p3(linear)) * color_gamma.a, color_gamma.a);
+}
+
+// The color of a color texture times the vertex color, in the color space of the output.
+//
+// The colors are multiplied in sRGB, like when the output is sRGB, and only the product is converted.
+fn output_color_gamma(in_color: vec4<f32>, tex_gamma: vec4<f32>) -> vec4<f32> {
+    return output_from_srgb_gamma_rgba(tone_map_gamma_rgba(in_color * srgb_from_output_gamma_rgba(tex_gamma)));
+}
+
+// The vertex color times the coverage of a font texture, in the color space of the output.
+//
+// Coverage is not a color, so only the vertex color is converted.
+fn output_coverage_gamma(in_color: vec4<f32>, coverage_gamma: vec4<f32>) -> vec4<f32> {
+    return output_from_srgb_gamma_rgba(tone_map_gamma_rgba(in_color)) * coverage_gamma;
+}
+
 // [u8; 4] SRGB as u32 -> [r, g, b, a] in 0.-1
 fn unpack_color(color: u32) -> vec4<f32> {
     return vec4<f32>(
@@ -59,11 +149,13 @@ fn vs_main(
     @location(0) a_pos: vec2<f32>,
     @location(1) a_tex_coord: vec2<f32>,
     @location(2) a_color: u32,
+    // Per instance: translation (xy) and scaling (z), for precomputed meshes.
+    @location(3) a_transform: vec3<f32>,
 ) -> VertexOutput {
     var out: VertexOutput;
     out.tex_coord = a_tex_coord;
     out.color = unpack_color(a_color);
-    out.position = position_from_screen(a_pos);
+    out.position = position_from_screen(a_transform.z * a_pos + a_transform.xy);
     return out;
 }
 
@@ -77,7 +169,7 @@ fn fs_main_linear_framebuffer(in: VertexOutput) -> @location(0) vec4<f32> {
     // We always have an sRGB aware texture at the moment.
     let tex_linear = textureSample(r_tex_color, r_tex_sampler, in.tex_coord);
     let tex_gamma = gamma_from_linear_rgba(tex_linear);
-    let out_color_gamma = in.color * tex_gamma;
+    let out_color_gamma = output_color_gamma(in.color, tex_gamma);
     return vec4<f32>(linear_from_gamma_rgb(out_color_gamma.rgb), out_color_gamma.a);
 }
 
@@ -86,6 +178,48 @@ fn fs_main_gamma_framebuffer(in: VertexOutput) -> @location(0) vec4<f32> {
     // We always have an sRGB aware texture at the moment.
     let tex_linear = textureSample(r_tex_color, r_tex_sampler, in.tex_coord);
     let tex_gamma = gamma_from_linear_rgba(tex_linear);
-    let out_color_gamma = in.color * tex_gamma;
-    return out_color_gamma;
+    return output_color_gamma(in.color, tex_gamma);
+}
+
+// Font textures with signed distance fields store the distance to the glyph edge in alpha,
+// with the edge at 0.5. Turn that into coverage, anti-aliased over about one screen pixel.
+fn sdf_tex_gamma(tex_coord: vec2<f32>) -> vec4<f32> {
+    let distance = textureSample(r_tex_color, r_tex_sampler, tex_coord).a;
+    let half_width = max(0.7 * fwidth(distance), 0.00001);
+    let coverage = smoothstep(0.5 - half_width, 0.5 + half_width, distance);
+    return vec4<f32>(coverage);
+}
+
+@fragment
+fn fs_main_sdf_linear_framebuffer(in: VertexOutput) -> @location(0) vec4<f32> {
+    let out_color_gamma = output_coverage_gamma(in.color, sdf_tex_gamma(in.tex_coord));
+    return vec4<f32>(linear_from_gamma_rgb(out_color_gamma.rgb), out_color_gamma.a);
+}
+
+@fragment
+fn fs_main_sdf_gamma_framebuffer(in: VertexOutput) -> @location(0) vec4<f32> {
+    return output_coverage_gamma(in.color, sdf_tex_gamma(in.tex_coord));
+}
+
+// Font textures with subpixel anti-aliasing store the coverage of each color channel in rgb.
+// They are drawn in two passes: first this darkens the framebuffer by the coverage of each channel
+// (blending `dst * (1 - src)`), then `fs_main_subpixel_color_*` adds the color (blending `dst + src`).
+@fragment
+fn fs_main_subpixel_mask(in: VertexOutput) -> @location(0) vec4<f32> {
+    let tex_linear = textureSample(r_tex_color, r_tex_sampler, in.tex_coord);
+    let coverage_gamma = gamma_from_linear_rgba(tex_linear);
+    return in.color.a * coverage_gamma;
+}
+
+@fragment
+fn fs_main_subpixel_color_linear_framebuffer(in: VertexOutput) -> @location(0) vec4<f32> {
+    let tex_linear = textureSample(r_tex_color, r_tex_sampler, in.tex_coord);
+    let out_color_gamma = output_coverage_gamma(in.color, gamma_from_linear_rgba(tex_linear));
+    return vec4<f32>(linear_from_gamma_rgb(out_color_gamma.rgb), out_color_gamma.a);
+}
+
+@fragment
+fn fs_main_subpixel_color_gamma_framebuffer(in: VertexOutput) -> @location(0) vec4<f32> {
+    let tex_linear = textureSample(r_tex_color, r_tex_sampler, in.tex_coord);
+    return output_coverage_gamma(in.color, gamma_from_linear_rgba(tex_linear));
 }
@@ -155,7 +155,15 @@ impl RenderState {
             crate::profile_scope!("get_capabilities");
             surface.get_capabilities(&adapter).formats
         };
-        let target_format = crate::preferred_framebuffer_format(&capabilities)?;
+        let target_format = if config.hdr_output {
+            crate::preferred_hdr_framebuffer_format(&capabilities)
+        } else {
+            None
+        };
+        let target_format = match target_format {
+            Some(format) => format,
+            None => crate::preferred_framebuffer_format(&capabilities)?,
+        };
 
         let (device, queue) = {
             crate::profile_scope!("request_device");
@@ -164,7 +172,9 @@ impl RenderState {
                 .await?
         };
 
-        let renderer = Renderer::new(&device, target_format, depth_format, msaa_samples);
+        let mut renderer = Renderer::new(&device, target_format, depth_format, msaa_samples);
+        renderer.set_tone_mapping(config.tone_mapping);
+        renderer.set_output_color_space(config.output_color_space);
 
         Ok(Self {
             adapter: Arc::new(adapter),
@@ -242,6 +252,20 @@ pub struct WgpuConfiguration {
 
     /// Callback for surface errors.
     pub on_surface_error: Arc<dyn Fn(wgpu::SurfaceError) -> SurfaceErrorAction>,
+
+    /// Use a floating point surface if the adapter supports one,
+    /// so that HDR colors (linear values above `1.0`) are shown as they are.
+    ///
+    /// Falls back to the normal surface format (and [`Self::tone_mapping`]) if not.
+    pub hdr_output: bool,
+
+    /// How to fit HDR colors into a surface that can't show them.
+    pub tone_mapping: epaint::ToneMapping,
+
+    /// The color space the surface is shown in: sRGB, or the wide gamut Display P3.
+    ///
+    /// See [`Renderer::set_output_color_space`] for when to use Display P3.
+    pub output_color_space: epaint::ColorSpace,
 }
 
 impl std::fmt::Debug for WgpuConfiguration {
@@ -253,6 +277,9 @@ impl std::fmt::Debug for WgpuConfiguration {
             desired_maximum_frame_latency,
             power_preference,
             on_surface_error: _,
+            hdr_output,
+            tone_mapping,
+            output_color_space,
         } = self;
         f.debug_struct("WgpuConfiguration")
             .field("supported_backends", &supported_backends)
@@ -262,6 +289,9 @@ impl std::fmt::Debug for WgpuConfiguration {
                 &desired_maximum_frame_latency,
             )
             .field("power_preference", &power_preference)
+            .field("hdr_output", &hdr_output)
+            .field("tone_mapping", &tone_mapping)
+            .field("output_color_space", &output_color_space)
             .finish_non_exhaustive()
     }
 }
@@ -310,6 +340,12 @@ impl Default for WgpuConfiguration {
                 }
                 SurfaceErrorAction::SkipFrame
             }),
+
+            hdr_output: false,
+
+            tone_mapping: epaint::ToneMapping::default(),
+
+            output_color_space: epaint::ColorSpace::Srgb,
         }
     }
 }
@@ -336,6 +372,18 @@ pub fn preferred_framebuffer_format(
         .ok_or(WgpuError::NoSurfaceFormatsAvailable)
 }
 
+/// Find a floating point framebuffer format, for showing HDR colors.
+///
+/// Returns `None` if none of the given formats is one.
+pub fn preferred_hdr_framebuffer_format(
+    formats: &[wgpu::TextureFormat],
+) -> Option<wgpu::TextureFormat> {
+    formats
+        .iter()
+        .copied()
+        .find(|&format| format == wgpu::TextureFormat::Rgba16Float)
+}
+
 /// Take's epi's depth/stencil bits and returns the corresponding wgpu format.
 pub fn depth_format_from_bits(depth_buffer: u8, stencil_buffer: u8) -> Option<wgpu::TextureFormat> {
     match (depth_buffer, stencil_buffer) {
@@ -1,8 +1,8 @@
 #![allow(unsafe_code)]
 
-use std::{borrow::Cow, num::NonZeroU64, ops::Range};
+use std::{borrow::Cow, num::NonZeroU64, ops::Range, sync::Arc};
 
-use ahash::HashMap;
+use ahash::{HashMap, HashSet};
 use epaint::{emath::NumExt, PaintCallbackInfo, Primitive, Vertex};
 
 use wgpu::util::DeviceExt as _;
@@ -129,18 +129,44 @@ impl ScreenDescriptor {
 }
 
 /// Uniform buffer used when rendering.
-#[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C)]
 struct UniformBuffer {
     screen_size_in_points: [f32; 2],
-    // Uniform buffers need to be at least 16 bytes in WebGL.
-    // See https://github.com/gfx-rs/wgpu/issues/2072
-    _padding: [u32; 2],
+
+    /// 0 = none (the framebuffer clamps), 1 = soft clip, 2 = ACES filmic.
+    ///
+    /// Also pads the buffer: uniform buffers need to be at least 16 bytes in WebGL.
+    /// See <https://github.com/gfx-rs/wgpu/issues/2072>
+    tone_mapping: u32,
+
+    /// The knee of [`epaint::ToneMapping::SoftClip`].
+    tone_mapping_knee: f32,
+
+    /// 0 = sRGB, 1 = Display P3.
+    output_color_space: u32,
+
+    _padding: u32,
 }
 
-impl PartialEq for UniformBuffer {
-    fn eq(&self, other: &Self) -> bool {
-        self.screen_size_in_points == other.screen_size_in_points
+impl UniformBuffer {
+    fn new(
+        screen_size_in_points: [f32; 2],
+        tone_mapping: Option<epaint::ToneMapping>,
+        output_color_space: epaint::ColorSpace,
+    ) -> Self {
+        let (tone_mapping, tone_mapping_knee) = match tone_mapping {
+            None | Some(epaint::ToneMapping::Clamp) => (0, 0.0),
+            Some(epaint::ToneMapping::SoftClip { knee }) => (1, knee.clamp(0.0, 0.999)),
+            Some(epaint::ToneMapping::AcesFilmic) => (2, 0.0),
+        };
+        Self {
+            screen_size_in_points,
+            tone_mapping,
+            tone_mapping_knee,
+            output_color_space: u32::from(output_color_space == epaint::ColorSpace::DisplayP3),
+            _padding: 0,
+        }
     }
 }
 
@@ -150,15 +176,54 @@ struct SlicedBuffer {
     capacity: wgpu::BufferAddress,
 }
 
+/// A [`Primitive::PrecomputedMesh`] uploaded to the GPU.
+struct PrecomputedMeshBuffers {
+    /// The mesh that was uploaded. Also keeps it alive, so that it isn't confused with a new one.
+    mesh: Arc<epaint::Mesh>,
+    vertex_buffer: wgpu::Buffer,
+    index_buffer: wgpu::Buffer,
+}
+
+/// The per-instance transform of the vertices: translation and scaling.
+type InstanceTransform = [f32; 3];
+
+const IDENTITY_TRANSFORM: InstanceTransform = [0.0, 0.0, 1.0];
+
 /// Renderer for a egui based GUI.
 pub struct Renderer {
     pipeline: wgpu::RenderPipeline,
 
+    /// Used for textures in [`Self::sdf_textures`].
+    sdf_pipeline: wgpu::RenderPipeline,
+
+    /// Used for textures in [`Self::subpixel_textures`], followed by [`Self::subpixel_color_pipeline`].
+    subpixel_mask_pipeline: wgpu::RenderPipeline,
+
+    /// Adds the color of subpixel anti-aliased text after [`Self::subpixel_mask_pipeline`].
+    subpixel_color_pipeline: wgpu::RenderPipeline,
+
     index_buffer: SlicedBuffer,
     vertex_buffer: SlicedBuffer,
 
+    /// The identity, followed by the transform of each [`Primitive::PrecomputedMesh`] of the frame.
+    transform_buffer: wgpu::Buffer,
+    transform_buffer_capacity: wgpu::BufferAddress,
+
+    /// Meshes registered with an [`epaint::MeshManager`], kept on the GPU between frames.
+    precomputed_meshes: HashMap<epaint::MeshId, PrecomputedMeshBuffers>,
+
     uniform_buffer: wgpu::Buffer,
     previous_uniform_buffer_content: UniformBuffer,
+
+    /// How to fit HDR colors into the output. Ignored for floating point (HDR) outputs.
+    tone_mapping: epaint::ToneMapping,
+
+    /// Is the output a floating point format, that can show colors above `1.0`?
+    hdr_output: bool,
+
+    /// The primaries of the output. See [`Self::set_output_color_space`].
+    output_color_space: epaint::ColorSpace,
+
     uniform_bind_group: wgpu::BindGroup,
     texture_bind_group_layout: wgpu::BindGroupLayout,
 
@@ -166,6 +231,17 @@ pub struct Renderer {
     /// sampler). The texture may be None if the `TextureId` is just a handle to a user-provided
     /// sampler.
     textures: HashMap<epaint::TextureId, (Option<wgpu::Texture>, wgpu::BindGroup)>,
+
+    /// Font textures that contain signed distance fields instead of coverage.
+    ///
+    /// See [`epaint::text::GlyphRasterization::SignedDistanceField`].
+    sdf_textures: HashSet<epaint::TextureId>,
+
+    /// Font textures that contain the coverage of each color channel, which are drawn in two passes.
+    ///
+    /// See [`epaint::text::GlyphRasterization::Subpixel`].
+    subpixel_textures: HashSet<epaint::TextureId>,
+
     next_user_texture_id: u64,
     samplers: HashMap<epaint::textures::TextureOptions, wgpu::Sampler>,
 
@@ -179,7 +255,8 @@ impl Renderer {
     /// Creates a renderer for a egui UI.
     ///
     /// `output_color_format` should preferably be [`wgpu::TextureFormat::Rgba8Unorm`] or
-    /// [`wgpu::TextureFormat::Bgra8Unorm`], i.e. in gamma-space.
+    /// [`wgpu::TextureFormat::Bgra8Unorm`], i.e. in gamma-space,
+    /// or a floating point format like [`wgpu::TextureFormat::Rgba16Float`] to show HDR colors.
     pub fn new(
         device: &wgpu::Device,
         output_color_format: wgpu::TextureFormat,
@@ -199,10 +276,11 @@ impl Renderer {
 
         let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("egui_uniform_buffer"),
-            contents: bytemuck::cast_slice(&[UniformBuffer {
-                screen_size_in_points: [0.0, 0.0],
-                _padding: Default::default(),
-            }]),
+            contents: bytemuck::cast_slice(&[UniformBuffer::new(
+                [0.0, 0.0],
+                None,
+                epaint::ColorSpace::Srgb,
+            )]),
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
@@ -212,7 +290,7 @@ impl Renderer {
                 label: Some("egui_uniform_bind_group_layout"),
                 entries: &[wgpu::BindGroupLayoutEntry {
                     binding: 0,
-                    visibility: wgpu::ShaderStages::VERTEX,
+                    visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
                     ty: wgpu::BindingType::Buffer {
                         has_dynamic_offset: false,
                         min_binding_size: NonZeroU64::new(std::mem::size_of::<UniformBuffer>() as _),
@@ -278,22 +356,42 @@ impl Renderer {
             bias: wgpu::DepthBiasState::default(),
         });
 
-        let pipeline = {
+        // egui outputs colors with premultiplied alpha:
+        let premultiplied_blend = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        };
+        let alpha_blend = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::OneMinusDstAlpha,
+            dst_factor: wgpu::BlendFactor::One,
+            operation: wgpu::BlendOperation::Add,
+        };
+
+        let create_pipeline = |label: &str, fragment_entry_point: &str, blend: wgpu::BlendState| {
             crate::profile_scope!("create_render_pipeline");
             device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                label: Some("egui_pipeline"),
+                label: Some(label),
                 layout: Some(&pipeline_layout),
                 vertex: wgpu::VertexState {
                     entry_point: "vs_main",
                     module: &module,
-                    buffers: &[wgpu::VertexBufferLayout {
-                        array_stride: 5 * 4,
-                        step_mode: wgpu::VertexStepMode::Vertex,
-                        // 0: vec2 position
-                        // 1: vec2 texture coordinates
-                        // 2: uint color
-                        attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Uint32],
-                    }],
+                    buffers: &[
+                        wgpu::VertexBufferLayout {
+                            array_stride: 5 * 4,
+                            step_mode: wgpu::VertexStepMode::Vertex,
+                            // 0: vec2 position
+                            // 1: vec2 texture coordinates
+                            // 2: uint color
+                            attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Uint32],
+                        },
+                        wgpu::VertexBufferLayout {
+                            array_stride: std::mem::size_of::<InstanceTransform>() as _,
+                            step_mode: wgpu::VertexStepMode::Instance,
+                            // 3: vec3 translation and scaling
+                            attributes: &wgpu::vertex_attr_array![3 => Float32x3],
+                        },
+                    ],
                     compilation_options: wgpu::PipelineCompilationOptions::default()
                 },
                 primitive: wgpu::PrimitiveState {
@@ -305,7 +403,7 @@ impl Renderer {
                     polygon_mode: wgpu::PolygonMode::default(),
                     strip_index_format: None,
                 },
-                depth_stencil,
+                depth_stencil: depth_stencil.clone(),
                 multisample: wgpu::MultisampleState {
                     alpha_to_coverage_enabled: false,
                     count: msaa_samples,
@@ -314,26 +412,10 @@ impl Renderer {
 
                 fragment: Some(wgpu::FragmentState {
                     module: &module,
-                    entry_point: if output_color_format.is_srgb() {
-                        log::warn!("Detected a linear (sRGBA aware) framebuffer {:?}. egui prefers Rgba8Unorm or Bgra8Unorm", output_color_format);
-                        "fs_main_linear_framebuffer"
-                    } else {
-                        "fs_main_gamma_framebuffer" // this is what we prefer
-                    },
+                    entry_point: fragment_entry_point,
                     targets: &[Some(wgpu::ColorTargetState {
                         format: output_color_format,
-                        blend: Some(wgpu::BlendState {
-                            color: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::One,
-                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                                operation: wgpu::BlendOperation::Add,
-                            },
-                            alpha: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::OneMinusDstAlpha,
-                                dst_factor: wgpu::BlendFactor::One,
-                                operation: wgpu::BlendOperation::Add,
-                            },
-                        }),
+                        blend: Some(blend),
                         write_mask: wgpu::ColorWrites::ALL,
                     })],
                     compilation_options: wgpu::PipelineCompilationOptions::default()
@@ -343,13 +425,69 @@ impl Renderer {
         )
         };
 
+        let hdr_output = is_hdr_format(output_color_format);
+        let framebuffer = if hdr_output {
+            "linear_framebuffer" // Floating point formats are always linear
+        } else if output_color_format.is_srgb() {
+            log::warn!("Detected a linear (sRGBA aware) framebuffer {:?}. egui prefers Rgba8Unorm or Bgra8Unorm", output_color_format);
+            "linear_framebuffer"
+        } else {
+            "gamma_framebuffer" // this is what we prefer
+        };
+        let blend = wgpu::BlendState {
+            color: premultiplied_blend,
+            alpha: alpha_blend,
+        };
+        let pipeline = create_pipeline("egui_pipeline", &format!("fs_main_{framebuffer}"), blend);
+        let sdf_pipeline = create_pipeline(
+            "egui_sdf_pipeline",
+            &format!("fs_main_sdf_{framebuffer}"),
+            blend,
+        );
+        let subpixel_mask_pipeline = create_pipeline(
+            "egui_subpixel_mask_pipeline",
+            "fs_main_subpixel_mask",
+            wgpu::BlendState {
+                // dst * (1 - coverage):
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::Zero,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrc,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                // Leave alpha to the color pass:
+                alpha: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::Zero,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
+            },
+        );
+        let subpixel_color_pipeline = create_pipeline(
+            "egui_subpixel_color_pipeline",
+            &format!("fs_main_subpixel_color_{framebuffer}"),
+            wgpu::BlendState {
+                // dst + color * coverage:
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha: alpha_blend,
+            },
+        );
+
         const VERTEX_BUFFER_START_CAPACITY: wgpu::BufferAddress =
             (std::mem::size_of::<Vertex>() * 1024) as _;
         const INDEX_BUFFER_START_CAPACITY: wgpu::BufferAddress =
             (std::mem::size_of::<u32>() * 1024 * 3) as _;
+        const TRANSFORM_BUFFER_START_CAPACITY: wgpu::BufferAddress =
+            (std::mem::size_of::<InstanceTransform>() * 64) as _;
 
         Self {
             pipeline,
+            sdf_pipeline,
+            subpixel_mask_pipeline,
+            subpixel_color_pipeline,
             vertex_buffer: SlicedBuffer {
                 buffer: create_vertex_buffer(device, VERTEX_BUFFER_START_CAPACITY),
                 slices: Vec::with_capacity(64),
@@ -360,21 +498,69 @@ impl Renderer {
                 slices: Vec::with_capacity(64),
                 capacity: INDEX_BUFFER_START_CAPACITY,
             },
+            transform_buffer: create_transform_buffer(device, TRANSFORM_BUFFER_START_CAPACITY),
+            transform_buffer_capacity: TRANSFORM_BUFFER_START_CAPACITY,
+            precomputed_meshes: HashMap::default(),
             uniform_buffer,
             // Buffers on wgpu are zero initialized, so this is indeed its current state!
-            previous_uniform_buffer_content: UniformBuffer {
-                screen_size_in_points: [0.0, 0.0],
-                _padding: [0, 0],
-            },
+            previous_uniform_buffer_content: UniformBuffer::new(
+                [0.0, 0.0],
+                None,
+                epaint::ColorSpace::Srgb,
+            ),
+            tone_mapping: epaint::ToneMapping::default(),
+            hdr_output,
+            output_color_space: epaint::ColorSpace::Srgb,
             uniform_bind_group,
             texture_bind_group_layout,
             textures: HashMap::default(),
+            sdf_textures: HashSet::default(),
+            subpixel_textures: HashSet::default(),
             next_user_texture_id: 0,
             samplers: HashMap::default(),
             callback_resources: CallbackResources::default(),
         }
     }
 
+    /// How to fit HDR colors (linear values above `1.0`) into the output.
+    ///
+    /// Only used if the output format can't show HDR colors,
+    /// i.e. if it isn't a floating point format. Default: [`epaint::ToneMapping::Clamp`].
+    pub fn set_tone_mapping(&mut self, tone_mapping: epaint::ToneMapping) {
+        self.tone_mapping = tone_mapping;
+    }
+
+    /// See [`Self::set_tone_mapping`].
+    pub fn tone_mapping(&self) -> epaint::ToneMapping {
+        self.tone_mapping
+    }
+
+    /// Which primaries the output is shown with: [`epaint::ColorSpace::Srgb`] (the default)
+    /// or the wide gamut [`epaint::ColorSpace::DisplayP3`].
+    ///
+    /// With Display P3, egui's sRGB colors are converted so they look the same as before,
+    /// while images in Display P3 (see [`epaint::ColorImage::color_space`]) keep their
+    /// saturated colors instead of being squeezed into sRGB.
+    /// The transfer function is always decided by the output format.
+    ///
+    /// The renderer only changes the colors it outputs. With the `winit` feature,
+    /// [`crate::winit::Painter`] also tags the surfaces it creates with the color space,
+    /// which is only supported on macOS.
+    /// On other platforms, only use Display P3 if the surface is shown in it anyway.
+    ///
+    /// Set this before any textures are uploaded, as it affects how they are converted.
+    pub fn set_output_color_space(&mut self, color_space: epaint::ColorSpace) {
+        self.output_color_space = match color_space {
+            epaint::ColorSpace::DisplayP3 => epaint::ColorSpace::DisplayP3,
+            epaint::ColorSpace::Srgb | epaint::ColorSpace::LinearSrgb => epaint::ColorSpace::Srgb,
+        };
+    }
+
+    /// See [`Self::set_output_color_space`].
+    pub fn output_color_space(&self) -> epaint::ColorSpace {
+        self.output_color_space
+    }
+
     /// Executes the egui renderer onto an existing wgpu renderpass.
     pub fn render<'rp>(
         &'rp self,
@@ -391,9 +577,15 @@ impl Renderer {
         // run.
         let mut needs_reset = true;
 
+        // The pipeline that was last set, so we only switch when needed.
+        let mut current_pipeline = &self.pipeline;
+
         let mut index_buffer_slices = self.index_buffer.slices.iter();
         let mut vertex_buffer_slices = self.vertex_buffer.slices.iter();
 
+        // Index into `transform_buffer`, where 0 is the identity:
+        let mut precomputed_mesh_instance = 0;
+
         for epaint::ClippedPrimitive {
             clip_rect,
             primitive,
@@ -410,6 +602,8 @@ impl Renderer {
                 );
                 render_pass.set_pipeline(&self.pipeline);
                 render_pass.set_bind_group(0, &self.uniform_bind_group, &[]);
+                render_pass.set_vertex_buffer(1, self.transform_buffer.slice(..));
+                current_pipeline = &self.pipeline;
                 needs_reset = false;
             }
 
@@ -418,10 +612,16 @@ impl Renderer {
 
                 if rect.width == 0 || rect.height == 0 {
                     // Skip rendering zero-sized clip areas.
-                    if let Primitive::Mesh(_) = primitive {
-                        // If this is a mesh, we need to advance the index and vertex buffer iterators:
-                        index_buffer_slices.next().unwrap();
-                        vertex_buffer_slices.next().unwrap();
+                    match primitive {
+                        Primitive::Mesh(_) => {
+                            // If this is a mesh, we need to advance the index and vertex buffer iterators:
+                            index_buffer_slices.next().unwrap();
+                            vertex_buffer_slices.next().unwrap();
+                        }
+                        Primitive::PrecomputedMesh(_) => {
+                            precomputed_mesh_instance += 1;
+                        }
+                        Primitive::Callback(_) => {}
                     }
                     continue;
                 }
@@ -434,24 +634,45 @@ impl Renderer {
                     let index_buffer_slice = index_buffer_slices.next().unwrap();
                     let vertex_buffer_slice = vertex_buffer_slices.next().unwrap();
 
-                    if let Some((_texture, bind_group)) = self.textures.get(&mesh.texture_id) {
-                        render_pass.set_bind_group(1, bind_group, &[]);
-                        render_pass.set_index_buffer(
-                            self.index_buffer.buffer.slice(
-                                index_buffer_slice.start as u64..index_buffer_slice.end as u64,
-                            ),
-                            wgpu::IndexFormat::Uint32,
-                        );
-                        render_pass.set_vertex_buffer(
-                            0,
-                            self.vertex_buffer.buffer.slice(
-                                vertex_buffer_slice.start as u64..vertex_buffer_slice.end as u64,
-                            ),
-                        );
-                        render_pass.draw_indexed(0..mesh.indices.len() as u32, 0, 0..1);
-                    } else {
-                        log::warn!("Missing texture: {:?}", mesh.texture_id);
-                    }
+                    render_pass.set_index_buffer(
+                        self.index_buffer
+                            .buffer
+                            .slice(index_buffer_slice.start as u64..index_buffer_slice.end as u64),
+                        wgpu::IndexFormat::Uint32,
+                    );
+                    render_pass.set_vertex_buffer(
+                        0,
+                        self.vertex_buffer.buffer.slice(
+                            vertex_buffer_slice.start as u64..vertex_buffer_slice.end as u64,
+                        ),
+                    );
+                    self.draw_mesh(
+                        render_pass,
+                        &mut current_pipeline,
+                        mesh.texture_id,
+                        mesh.indices.len() as u32,
+                        0,
+                    );
+                }
+                Primitive::PrecomputedMesh(primitive) => {
+                    precomputed_mesh_instance += 1;
+
+                    let Some(buffers) = self.precomputed_meshes.get(&primitive.id) else {
+                        // Empty meshes are not uploaded.
+                        continue;
+                    };
+                    render_pass.set_index_buffer(
+                        buffers.index_buffer.slice(..),
+                        wgpu::IndexFormat::Uint32,
+                    );
+                    render_pass.set_vertex_buffer(0, buffers.vertex_buffer.slice(..));
+                    self.draw_mesh(
+                        render_pass,
+                        &mut current_pipeline,
+                        buffers.mesh.texture_id,
+                        buffers.mesh.indices.len() as u32,
+                        precomputed_mesh_instance,
+                    );
                 }
                 Primitive::Callback(callback) => {
                     let Some(cbfn) = callback.callback.downcast_ref::<Callback>() else {
@@ -498,6 +719,42 @@ impl Renderer {
         render_pass.set_scissor_rect(0, 0, size_in_pixels[0], size_in_pixels[1]);
     }
 
+    /// Draw the mesh in the bound index and vertex buffers, with the transform at `instance`.
+    fn draw_mesh<'rp>(
+        &'rp self,
+        render_pass: &mut wgpu::RenderPass<'rp>,
+        current_pipeline: &mut &'rp wgpu::RenderPipeline,
+        texture_id: epaint::TextureId,
+        index_count: u32,
+        instance: u32,
+    ) {
+        let Some((_texture, bind_group)) = self.textures.get(&texture_id) else {
+            log::warn!("Missing texture: {:?}", texture_id);
+            return;
+        };
+
+        let is_subpixel = self.subpixel_textures.contains(&texture_id);
+        let pipeline = if is_subpixel {
+            &self.subpixel_mask_pipeline
+        } else if self.sdf_textures.contains(&texture_id) {
+            &self.sdf_pipeline
+        } else {
+            &self.pipeline
+        };
+        if !std::ptr::eq(pipeline, *current_pipeline) {
+            render_pass.set_pipeline(pipeline);
+            *current_pipeline = pipeline;
+        }
+        render_pass.set_bind_group(1, bind_group, &[]);
+        render_pass.draw_indexed(0..index_count, 0, instance..instance + 1);
+        if is_subpixel {
+            // Second pass, adding the color:
+            render_pass.set_pipeline(&self.subpixel_color_pipeline);
+            *current_pipeline = &self.subpixel_color_pipeline;
+            render_pass.draw_indexed(0..index_count, 0, instance..instance + 1);
+        }
+    }
+
     /// Should be called before [`Self::render`].
     pub fn update_texture(
         &mut self,
@@ -524,7 +781,8 @@ impl Renderer {
                     image.pixels.len(),
                     "Mismatch between texture size and texel count"
                 );
-                Cow::Borrowed(&image.pixels)
+                crate::profile_scope!("color image -> sRGBA");
+                image.pixels_in(self.output_color_space)
             }
             epaint::ImageData::Font(image) => {
                 assert_eq!(
@@ -536,7 +794,7 @@ impl Renderer {
                 Cow::Owned(image.srgba_pixels(None).collect::<Vec<egui::Color32>>())
             }
         };
-        let data_bytes: &[u8] = bytemuck::cast_slice(data_color32.as_slice());
+        let data_bytes: &[u8] = bytemuck::cast_slice(&data_color32);
 
         let queue_write_data_to_texture = |texture, origin| {
             crate::profile_scope!("write_texture");
@@ -574,50 +832,101 @@ impl Renderer {
             );
         } else {
             // allocate a new texture
-            // Use same label for all resources associated with this texture id (no point in retyping the type)
-            let label_str = format!("egui_texid_{id:?}");
-            let label = Some(label_str.as_str());
-            let texture = {
-                crate::profile_scope!("create_texture");
-                device.create_texture(&wgpu::TextureDescriptor {
-                    label,
-                    size,
-                    mip_level_count: 1,
-                    sample_count: 1,
-                    dimension: wgpu::TextureDimension::D2,
-                    format: wgpu::TextureFormat::Rgba8UnormSrgb, // Minspec for wgpu WebGL emulation is WebGL2, so this should always be supported.
-                    usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
-                    view_formats: &[wgpu::TextureFormat::Rgba8UnormSrgb],
-                })
-            };
-            let sampler = self
-                .samplers
-                .entry(image_delta.options)
-                .or_insert_with(|| create_sampler(image_delta.options, device));
-            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label,
-                layout: &self.texture_bind_group_layout,
-                entries: &[
-                    wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::TextureView(
-                            &texture.create_view(&wgpu::TextureViewDescriptor::default()),
-                        ),
-                    },
-                    wgpu::BindGroupEntry {
-                        binding: 1,
-                        resource: wgpu::BindingResource::Sampler(sampler),
-                    },
-                ],
-            });
-            let origin = wgpu::Origin3d::ZERO;
-            queue_write_data_to_texture(&texture, origin);
+            let (texture, bind_group) = self.create_texture(device, id, size, image_delta.options);
+            queue_write_data_to_texture(&texture, wgpu::Origin3d::ZERO);
             self.textures.insert(id, (Some(texture), bind_group));
+
+            let is_sdf =
+                matches!(&image_delta.image, epaint::ImageData::Font(image) if image.is_sdf());
+            if is_sdf {
+                self.sdf_textures.insert(id);
+            } else {
+                self.sdf_textures.remove(&id);
+            }
+            let is_subpixel =
+                matches!(&image_delta.image, epaint::ImageData::Font(image) if image.is_subpixel());
+            if is_subpixel {
+                self.subpixel_textures.insert(id);
+            } else {
+                self.subpixel_textures.remove(&id);
+            }
+        };
+    }
+
+    /// Allocate a color texture of the given size, leaving its contents uninitialized.
+    ///
+    /// Should be called before [`Self::update_texture`], see [`epaint::TexturesDelta::allocate`].
+    pub fn allocate_texture(
+        &mut self,
+        device: &wgpu::Device,
+        id: epaint::TextureId,
+        [width, height]: [usize; 2],
+        options: epaint::textures::TextureOptions,
+    ) {
+        crate::profile_function!();
+
+        let size = wgpu::Extent3d {
+            width: width as u32,
+            height: height as u32,
+            depth_or_array_layers: 1,
+        };
+        let (texture, bind_group) = self.create_texture(device, id, size, options);
+        self.textures.insert(id, (Some(texture), bind_group));
+        self.sdf_textures.remove(&id);
+        self.subpixel_textures.remove(&id);
+    }
+
+    /// Create a texture and its bind group.
+    fn create_texture(
+        &mut self,
+        device: &wgpu::Device,
+        id: epaint::TextureId,
+        size: wgpu::Extent3d,
+        options: epaint::textures::TextureOptions,
+    ) -> (wgpu::Texture, wgpu::BindGroup) {
+        // Use same label for all resources associated with this texture id (no point in retyping the type)
+        let label_str = format!("egui_texid_{id:?}");
+        let label = Some(label_str.as_str());
+        let texture = {
+            crate::profile_scope!("create_texture");
+            device.create_texture(&wgpu::TextureDescriptor {
+                label,
+                size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb, // Minspec for wgpu WebGL emulation is WebGL2, so this should always be supported.
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[wgpu::TextureFormat::Rgba8UnormSrgb],
+            })
         };
+        let sampler = self
+            .samplers
+            .entry(options)
+            .or_insert_with(|| create_sampler(options, device));
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label,
+            layout: &self.texture_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(
+                        &texture.create_view(&wgpu::TextureViewDescriptor::default()),
+                    ),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+            ],
+        });
+        (texture, bind_group)
     }
 
     pub fn free_texture(&mut self, id: &epaint::TextureId) {
         self.textures.remove(id);
+        self.sdf_textures.remove(id);
+        self.subpixel_textures.remove(id);
     }
 
     /// Get the WGPU texture and bind group associated to a texture that has been allocated by egui.
@@ -721,6 +1030,72 @@ impl Renderer {
         id
     }
 
+    /// Uploads HDR pixels (linear, premultiplied alpha, channels may exceed `1.0`)
+    /// into a floating point texture, and registers it with a [`epaint::TextureId`].
+    ///
+    /// Unlike textures managed by egui, which are 8 bit, this keeps the colors above `1.0`.
+    /// They are shown as they are on floating point outputs,
+    /// and otherwise mapped with [`Self::set_tone_mapping`].
+    ///
+    /// Meshes are tinted with 8 bit colors, so to paint bright (emissive) colors,
+    /// e.g. in accents of the UI, use a small texture of the HDR color with a white tint.
+    ///
+    /// Panics if `size[0] * size[1] != pixels.len()`.
+    pub fn register_hdr_texture(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: [usize; 2],
+        pixels: &[epaint::Rgba],
+        texture_filter: wgpu::FilterMode,
+    ) -> epaint::TextureId {
+        crate::profile_function!();
+
+        assert_eq!(size[0] * size[1], pixels.len());
+        let size = wgpu::Extent3d {
+            width: size[0] as u32,
+            height: size[1] as u32,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some(format!("egui_user_hdr_image_{}", self.next_user_texture_id).as_str()),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba16Float,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+
+        let data: Vec<u16> = pixels
+            .iter()
+            .flat_map(|rgba| rgba.to_array().map(f16_bits_from_f32))
+            .collect();
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytemuck::cast_slice(&data),
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(8 * size.width),
+                rows_per_image: Some(size.height),
+            },
+            size,
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let id = self.register_native_texture(device, &view, texture_filter);
+        if let Some((user_texture, _bind_group)) = self.textures.get_mut(&id) {
+            *user_texture = Some(texture);
+        }
+        id
+    }
+
     /// Registers a [`wgpu::Texture`] with an existing [`epaint::TextureId`] while also accepting custom
     /// [`wgpu::SamplerDescriptor`] options.
     ///
@@ -779,10 +1154,9 @@ impl Renderer {
 
         let screen_size_in_points = screen_descriptor.screen_size_in_points();
 
-        let uniform_buffer_content = UniformBuffer {
-            screen_size_in_points,
-            _padding: Default::default(),
-        };
+        let tone_mapping = (!self.hdr_output).then_some(self.tone_mapping);
+        let uniform_buffer_content =
+            UniformBuffer::new(screen_size_in_points, tone_mapping, self.output_color_space);
         if uniform_buffer_content != self.previous_uniform_buffer_content {
             crate::profile_scope!("update uniforms");
             queue.write_buffer(
@@ -802,6 +1176,7 @@ impl Renderer {
                     Primitive::Mesh(mesh) => {
                         (acc.0 + mesh.vertices.len(), acc.1 + mesh.indices.len())
                     }
+                    Primitive::PrecomputedMesh(_) => acc,
                     Primitive::Callback(callback) => {
                         if let Some(c) = callback.callback.downcast_ref::<Callback>() {
                             callbacks.push(c.0.as_ref());
@@ -848,7 +1223,7 @@ impl Renderer {
                         self.index_buffer.slices.push(slice);
                         index_offset += size;
                     }
-                    Primitive::Callback(_) => {}
+                    Primitive::PrecomputedMesh(_) | Primitive::Callback(_) => {}
                 }
             }
         }
@@ -887,11 +1262,13 @@ impl Renderer {
                         self.vertex_buffer.slices.push(slice);
                         vertex_offset += size;
                     }
-                    Primitive::Callback(_) => {}
+                    Primitive::PrecomputedMesh(_) | Primitive::Callback(_) => {}
                 }
             }
         }
 
+        self.update_precomputed_meshes(device, queue, paint_jobs);
+
         let mut user_cmd_bufs = Vec::new();
         {
             crate::profile_scope!("prepare callbacks");
@@ -919,6 +1296,72 @@ impl Renderer {
 
         user_cmd_bufs
     }
+
+    /// Upload new precomputed meshes and their transforms, and free the ones that are gone.
+    fn update_precomputed_meshes(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        paint_jobs: &[epaint::ClippedPrimitive],
+    ) {
+        crate::profile_function!();
+
+        let mut transforms = vec![IDENTITY_TRANSFORM];
+        for epaint::ClippedPrimitive { primitive, .. } in paint_jobs {
+            let Primitive::PrecomputedMesh(primitive) = primitive else {
+                continue;
+            };
+            let transform = primitive.transform;
+            transforms.push([
+                transform.translation.x,
+                transform.translation.y,
+                transform.scaling,
+            ]);
+
+            let is_uploaded = self
+                .precomputed_meshes
+                .get(&primitive.id)
+                .is_some_and(|buffers| Arc::ptr_eq(&buffers.mesh, &primitive.mesh));
+            if is_uploaded {
+                continue;
+            }
+            if primitive.mesh.is_empty() {
+                self.precomputed_meshes.remove(&primitive.id);
+                continue;
+            }
+            crate::profile_scope!("upload precomputed mesh");
+            let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("egui_precomputed_vertex_buffer"),
+                contents: bytemuck::cast_slice(&primitive.mesh.vertices),
+                usage: wgpu::BufferUsages::VERTEX,
+            });
+            let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("egui_precomputed_index_buffer"),
+                contents: bytemuck::cast_slice(&primitive.mesh.indices),
+                usage: wgpu::BufferUsages::INDEX,
+            });
+            self.precomputed_meshes.insert(
+                primitive.id,
+                PrecomputedMeshBuffers {
+                    mesh: primitive.mesh.clone(),
+                    vertex_buffer,
+                    index_buffer,
+                },
+            );
+        }
+
+        // If we hold the last reference, the mesh has been freed from the `MeshManager`:
+        self.precomputed_meshes
+            .retain(|_, buffers| Arc::strong_count(&buffers.mesh) > 1);
+
+        let required_size = std::mem::size_of_val(transforms.as_slice()) as u64;
+        if self.transform_buffer_capacity < required_size {
+            self.transform_buffer_capacity =
+                (self.transform_buffer_capacity * 2).at_least(required_size);
+            self.transform_buffer = create_transform_buffer(device, self.transform_buffer_capacity);
+        }
+        queue.write_buffer(&self.transform_buffer, 0, bytemuck::cast_slice(&transforms));
+    }
 }
 
 fn create_sampler(
@@ -950,6 +1393,40 @@ fn create_sampler(
     })
 }
 
+/// The bits of an `f16` (for [`wgpu::TextureFormat::Rgba16Float`]), rounded to nearest.
+///
+/// Values too large for an `f16` become its largest value,
+/// and values too small for a normal `f16` become zero, which is fine for colors.
+fn f16_bits_from_f32(value: f32) -> u16 {
+    /// The smallest positive normal `f16`.
+    const MIN_POSITIVE: f32 = 6.103_515_6e-5;
+    /// The largest `f16`.
+    const MAX: f32 = 65504.0;
+
+    let sign = ((value.to_bits() >> 16) & 0x8000) as u16;
+    let magnitude = value.abs();
+    if magnitude.is_nan() {
+        return 0x7e00;
+    }
+    if magnitude < MIN_POSITIVE {
+        return sign;
+    }
+    // Rounding may carry into the exponent, which is what we want:
+    let bits = magnitude.min(MAX).to_bits();
+    let bits = bits + 0x0fff + ((bits >> 13) & 1);
+    let exponent = (bits >> 23) as u16 + 15 - 127;
+    let mantissa = ((bits >> 13) & 0x03ff) as u16;
+    sign | (exponent << 10) | mantissa
+}
+
+/// Can the format show colors above `1.0`?
+fn is_hdr_format(format: wgpu::TextureFormat) -> bool {
+    matches!(
+        format,
+        wgpu::TextureFormat::Rgba16Float | wgpu::TextureFormat::Rgba32Float
+    )
+}
+
 fn create_vertex_buffer(device: &wgpu::Device, size: u64) -> wgpu::Buffer {
     crate::profile_function!();
     device.create_buffer(&wgpu::BufferDescriptor {
@@ -960,6 +1437,16 @@ fn create_vertex_buffer(device: &wgpu::Device, size: u64) -> wgpu::Buffer {
     })
 }
 
+fn create_transform_buffer(device: &wgpu::Device, size: u64) -> wgpu::Buffer {
+    crate::profile_function!();
+    device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("egui_transform_buffer"),
+        usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        size,
+        mapped_at_creation: false,
+    })
+}
+
 fn create_index_buffer(device: &wgpu::Device, size: u64) -> wgpu::Buffer {
     crate::profile_function!();
     device.create_buffer(&wgpu::BufferDescriptor {
@@ -1012,3 +1499,18 @@ fn renderer_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Renderer>();
 }
+
+#[test]
+fn test_f16_bits_from_f32() {
+    assert_eq!(f16_bits_from_f32(0.0), 0x0000);
+    assert_eq!(f16_bits_from_f32(-0.0), 0x8000);
+    assert_eq!(f16_bits_from_f32(1.0), 0x3c00);
+    assert_eq!(f16_bits_from_f32(-2.0), 0xc000);
+    assert_eq!(f16_bits_from_f32(0.5), 0x3800);
+    assert_eq!(f16_bits_from_f32(4.0), 0x4400);
+    assert_eq!(f16_bits_from_f32(1.0 + 1.0 / 1024.0), 0x3c01);
+    assert_eq!(f16_bits_from_f32(65504.0), 0x7bff);
+    assert_eq!(f16_bits_from_f32(1e9), 0x7bff, "Clamped to the largest f16");
+    assert_eq!(f16_bits_from_f32(1e-9), 0x0000, "Flushed to zero");
+    assert_eq!(f16_bits_from_f32(f32::NAN), 0x7e00);
+}
@@ -210,7 +210,8 @@ impl Painter {
         if let Some(window) = window {
             let size = window.inner_size();
             if !self.surfaces.contains_key(&viewport_id) {
-                let surface = self.instance.create_surface(window)?;
+                let surface = self.instance.create_surface(window.clone())?;
+                tag_surface_color_space(&window, self.configuration.output_color_space);
                 self.add_surface(surface, viewport_id, size).await?;
             }
         } else {
@@ -240,6 +241,7 @@ impl Painter {
                     self.instance
                         .create_surface_unsafe(wgpu::SurfaceTargetUnsafe::from_window(&window)?)?
                 };
+                tag_surface_color_space(window, self.configuration.output_color_space);
                 self.add_surface(surface, viewport_id, size).await?;
             }
         } else {
@@ -501,10 +503,10 @@ impl Painter {
         }
         buffer.unmap();
 
-        Some(epaint::ColorImage {
-            size: [tex.width() as usize, tex.height() as usize],
-            pixels,
-        })
+        Some(
+            epaint::ColorImage::from_pixels([tex.width() as usize, tex.height() as usize], pixels)
+                .with_color_space(render_state.renderer.read().output_color_space()),
+        )
     }
 
     /// Returns two things:
@@ -546,6 +548,9 @@ impl Painter {
 
         let user_cmd_bufs = {
             let mut renderer = render_state.renderer.write();
+            for &(id, size, options) in &textures_delta.allocate {
+                renderer.allocate_texture(&render_state.device, id, size, options);
+            }
             for (id, image_delta) in &textures_delta.set {
                 renderer.update_texture(
                     &render_state.device,
@@ -714,3 +719,81 @@ impl Painter {
         // TODO(emilk): something here?
     }
 }
+
+/// Tell the platform which color space the surface is shown in, which wgpu can't do.
+///
+/// Only needed for Display P3 on macOS, where we tag the Metal layer of the window.
+/// Everywhere else, the surface is shown as it is.
+fn tag_surface_color_space(_window: &winit::window::Window, _color_space: epaint::ColorSpace) {
+    #[cfg(target_os = "macos")]
+    if _color_space == epaint::ColorSpace::DisplayP3 {
+        macos::tag_display_p3(_window);
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use std::ffi::c_void;
+
+    use objc2::{
+        encode::{Encoding, RefEncode},
+        msg_send,
+        runtime::AnyObject,
+        sel,
+    };
+    use winit::raw_window_handle::{HasWindowHandle as _, RawWindowHandle};
+
+    /// The opaque struct behind a `CGColorSpaceRef`.
+    #[repr(C)]
+    struct CGColorSpace {
+        _private: [u8; 0],
+    }
+
+    // SAFETY: `CGColorSpaceRef` is a pointer to the struct `CGColorSpace`.
+    unsafe impl RefEncode for CGColorSpace {
+        const ENCODING_REF: Encoding = Encoding::Pointer(&Encoding::Struct("CGColorSpace", &[]));
+    }
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    extern "C" {
+        /// A `CFStringRef`.
+        static kCGColorSpaceDisplayP3: *const c_void;
+
+        fn CGColorSpaceCreateWithName(name: *const c_void) -> *mut CGColorSpace;
+
+        fn CGColorSpaceRelease(space: *mut CGColorSpace);
+    }
+
+    /// Set the color space of the `CAMetalLayer` that wgpu put in the view of the window.
+    pub fn tag_display_p3(window: &winit::window::Window) {
+        let Ok(handle) = window.window_handle() else {
+            return;
+        };
+        let RawWindowHandle::AppKit(handle) = handle.as_raw() else {
+            return;
+        };
+
+        // SAFETY: `ns_view` is the live `NSView` of the window, and we only send messages
+        // that `NSView` and `CAMetalLayer` understand, after checking that the layer does.
+        unsafe {
+            let view: &AnyObject = handle.ns_view.cast().as_ref();
+            let layer: Option<&AnyObject> = msg_send![view, layer];
+            let Some(layer) = layer else {
+                log::warn!("The window has no layer to tag with Display P3");
+                return;
+            };
+            let can_tag: bool = msg_send![layer, respondsToSelector: sel!(setColorspace:)];
+            if !can_tag {
+                log::warn!("The layer of the window can't be tagged with Display P3");
+                return;
+            }
+
+            let color_space = CGColorSpaceCreateWithName(kCGColorSpaceDisplayP3);
+            if color_space.is_null() {
+                return;
+            }
+            let _: () = msg_send![layer, setColorspace: color_space];
+            CGColorSpaceRelease(color_space);
+        }
+    }
+}
@@ -305,8 +305,12 @@ impl State {
                 }
             }
             WindowEvent::CursorLeft { .. } => {
-                self.pointer_pos_in_points = None;
-                self.egui_input.events.push(egui::Event::PointerGone);
+                // While a widget has captured the pointer we keep the last position,
+                // so that releasing the button outside the window is still reported.
+                if self.egui_ctx.pointer_capture_id().is_none() {
+                    self.pointer_pos_in_points = None;
+                    self.egui_input.events.push(egui::Event::PointerGone);
+                }
                 EventResponse {
                     repaint: true,
                     consumed: false,
@@ -961,6 +965,16 @@ pub fn update_viewport_info(
     viewport_info.native_pixels_per_point = Some(window.scale_factor() as f32);
 
     viewport_info.monitor_size = monitor_size;
+    viewport_info.monitor_id = window.current_monitor().map(|monitor| {
+        // Identical monitors have the same name, but not the same position:
+        let name = monitor.name().unwrap_or_default();
+        let position = monitor.position();
+        let size = monitor.size();
+        format!(
+            "{name} {}x{} at {},{}",
+            size.width, size.height, position.x, position.y
+        )
+    });
     viewport_info.inner_rect = inner_rect;
     viewport_info.outer_rect = outer_rect;
 
@@ -1514,11 +1528,28 @@ pub fn create_window<T>(
     egui_ctx: &egui::Context,
     event_loop: &EventLoopWindowTarget<T>,
     viewport_builder: &ViewportBuilder,
+) -> Result<Window, winit::error::OsError> {
+    create_window_with_parent(egui_ctx, event_loop, viewport_builder, None)
+}
+
+/// Like [`create_window`], but with the window of the parent viewport,
+/// which is needed for [`ViewportBuilder::owned_by_parent`].
+///
+/// # Errors
+/// Possible causes of error include denied permission, incompatible system, and lack of memory.
+pub fn create_window_with_parent<T>(
+    egui_ctx: &egui::Context,
+    event_loop: &EventLoopWindowTarget<T>,
+    viewport_builder: &ViewportBuilder,
+    parent: Option<&Window>,
 ) -> Result<Window, winit::error::OsError> {
     crate::profile_function!();
 
-    let window_builder =
+    let mut window_builder =
         create_winit_window_builder(egui_ctx, event_loop, viewport_builder.clone());
+    if let Some(parent) = parent {
+        window_builder = with_parent_window(window_builder, viewport_builder, parent);
+    }
     let window = {
         crate::profile_scope!("WindowBuilder::build");
         window_builder.build(event_loop)?
@@ -1588,8 +1619,18 @@ pub fn create_winit_window_builder<T>(
 
         mouse_passthrough: _, // handled in `apply_viewport_builder_to_window`
         clamp_size_to_monitor_size: _, // Handled in `viewport_builder` in `epi_integration.rs`
+        owned_by_parent,
+        focusable,
+        has_shadow: _has_shadow,
     } = viewport_builder;
 
+    let window_level = window_level.or_else(|| {
+        // Owned windows are only supported on Windows and macOS (see `with_parent_window`).
+        let owned_by_parent = owned_by_parent.unwrap_or(false);
+        (owned_by_parent && !cfg!(any(target_os = "windows", target_os = "macos")))
+            .then_some(egui::viewport::WindowLevel::AlwaysOnTop)
+    });
+
     let mut window_builder = winit::window::WindowBuilder::new()
         .with_title(title.unwrap_or_else(|| "egui window".to_owned()))
         .with_transparent(transparent.unwrap_or(false))
@@ -1618,7 +1659,7 @@ pub fn create_winit_window_builder<T>(
             }
             buttons
         })
-        .with_active(active.unwrap_or(true));
+        .with_active(active.unwrap_or_else(|| focusable.unwrap_or(true)));
 
     if let Some(size) = inner_size {
         window_builder = window_builder.with_inner_size(PhysicalSize::new(
@@ -1692,6 +1733,9 @@ pub fn create_winit_window_builder<T>(
         if let Some(show) = _taskbar {
             window_builder = window_builder.with_skip_taskbar(!show);
         }
+        if let Some(shadow) = _has_shadow {
+            window_builder = window_builder.with_undecorated_shadow(shadow);
+        }
     }
 
     #[cfg(target_os = "macos")]
@@ -1702,8 +1746,50 @@ pub fn create_winit_window_builder<T>(
             .with_titlebar_buttons_hidden(!_titlebar_buttons_shown.unwrap_or(true))
             .with_titlebar_transparent(!_titlebar_shown.unwrap_or(true))
             .with_fullsize_content_view(_fullsize_content_view.unwrap_or(false));
+        if let Some(shadow) = _has_shadow {
+            window_builder = window_builder.with_has_shadow(shadow);
+        }
+    }
+
+    window_builder
+}
+
+/// Make the window owned by `parent`, if [`ViewportBuilder::owned_by_parent`] is set,
+/// so that it stays above `parent` and is minimized together with it.
+///
+/// Only supported on Windows and macOS.
+/// On other platforms [`create_winit_window_builder`] makes the window always-on-top instead.
+pub fn with_parent_window(
+    window_builder: winit::window::WindowBuilder,
+    viewport_builder: &ViewportBuilder,
+    parent: &Window,
+) -> winit::window::WindowBuilder {
+    if viewport_builder.owned_by_parent != Some(true) {
+        return window_builder;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use raw_window_handle::{HasWindowHandle as _, RawWindowHandle};
+        use winit::platform::windows::WindowBuilderExtWindows as _;
+        if let Ok(handle) = parent.window_handle() {
+            if let RawWindowHandle::Win32(handle) = handle.as_raw() {
+                return window_builder.with_owner_window(handle.hwnd.get());
+            }
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        use raw_window_handle::HasWindowHandle as _;
+        if let Ok(handle) = parent.window_handle() {
+            #[allow(unsafe_code)]
+            // SAFETY: the handle is of a live window, and is only used while building the new window.
+            return unsafe { window_builder.with_parent_window(Some(handle.as_raw())) };
+        }
     }
 
+    let _ = parent; // Only used on some platforms
     window_builder
 }
 
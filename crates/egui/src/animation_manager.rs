@@ -1,5 +1,5 @@
 use crate::{
-    emath::{remap_clamp, NumExt as _},
+    emath::{lerp, remap_clamp, NumExt as _},
     Id, IdMap, InputState,
 };
 
@@ -65,12 +65,14 @@ impl AnimationManager {
         }
     }
 
+    /// See [`crate::Context::animate_value_with_time_and_easing`] for documentation
     pub fn animate_value(
         &mut self,
         input: &InputState,
         animation_time: f32,
         id: Id,
         value: f32,
+        easing: impl Fn(f32) -> f32,
     ) -> f32 {
         match self.values.get_mut(&id) {
             None => {
@@ -89,11 +91,12 @@ impl AnimationManager {
                 // On the frame we toggle we don't want to return the old value,
                 // so we extrapolate forwards:
                 let time_since_toggle = time_since_toggle + input.predicted_dt;
-                let current_value = remap_clamp(
-                    time_since_toggle,
-                    0.0..=animation_time,
-                    anim.from_value..=anim.to_value,
-                );
+                let t = remap_clamp(time_since_toggle, 0.0..=animation_time, 0.0..=1.0);
+                let current_value = if t < 1.0 {
+                    lerp(anim.from_value..=anim.to_value, easing(t))
+                } else {
+                    anim.to_value
+                };
                 if anim.to_value != value {
                     anim.from_value = current_value; //start new animation from current position of playing animation
                     anim.to_value = value;
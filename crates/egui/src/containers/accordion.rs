@@ -0,0 +1,187 @@
+use std::hash::Hash;
+
+use crate::{
+    collapsing_header::CollapsingState, CollapsingHeader, CollapsingResponse, Context, Id,
+    InnerResponse, Ui, WidgetText,
+};
+
+/// Which header of an [`AccordionGroup`] is open.
+///
+/// Stored in [`crate::Memory`], so you can also read or change it from outside the group.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AccordionState {
+    /// The [`Id`] of the open header, i.e. its [`crate::Response::id`].
+    pub open: Option<Id>,
+}
+
+impl AccordionState {
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// A group of [`CollapsingHeader`]s where at most one is open at a time.
+///
+/// Opening a header closes the one that was open before, and both animate.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::AccordionGroup::new("settings").show(ui, |accordion| {
+///     accordion.collapsing("General", |ui| {
+///         ui.label("General settings");
+///     });
+///     accordion.header(egui::CollapsingHeader::new("Advanced"), |ui| {
+///         ui.label("Advanced settings");
+///     });
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct AccordionGroup {
+    id_source: Id,
+}
+
+impl AccordionGroup {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+        }
+    }
+
+    /// Add the headers of the group with [`Accordion::header`] or [`Accordion::collapsing`].
+    ///
+    /// Other widgets can be added in between using [`Accordion::ui`].
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Accordion<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let id = ui.make_persistent_id(self.id_source);
+        let state = AccordionState::load(ui.ctx(), id).unwrap_or_default();
+
+        let InnerResponse { inner, response } = ui.vertical(|ui| {
+            let mut accordion = Accordion {
+                ui,
+                headers: Vec::new(),
+            };
+            let inner = add_contents(&mut accordion);
+            (inner, accordion.headers)
+        });
+        let (inner, headers) = inner;
+
+        let open = open_header(state.open, &headers);
+        for header in &headers {
+            if header.is_open && Some(header.id) != open {
+                if let Some(mut collapsing) = CollapsingState::load(ui.ctx(), header.id) {
+                    collapsing.set_open(false);
+                    collapsing.store(ui.ctx());
+                    ui.ctx().request_repaint();
+                }
+            }
+        }
+
+        AccordionState { open }.store(ui.ctx(), id);
+
+        InnerResponse { inner, response }
+    }
+}
+
+/// A header shown in an [`AccordionGroup`] this frame.
+struct AccordionHeader {
+    id: Id,
+    is_open: bool,
+
+    /// Was it opened or closed this frame?
+    changed: bool,
+}
+
+/// Adds headers to an [`AccordionGroup`]. See [`AccordionGroup::show`].
+pub struct Accordion<'ui> {
+    ui: &'ui mut Ui,
+    headers: Vec<AccordionHeader>,
+}
+
+impl<'ui> Accordion<'ui> {
+    /// Show a [`CollapsingHeader`] that is part of the group.
+    pub fn header<R>(
+        &mut self,
+        header: CollapsingHeader,
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        let response = header.show(self.ui, add_body);
+        let id = response.header_response.id;
+        self.headers.push(AccordionHeader {
+            id,
+            is_open: CollapsingState::load(self.ui.ctx(), id).map_or(false, |s| s.is_open()),
+            changed: response.header_response.changed(),
+        });
+        response
+    }
+
+    /// Show a [`CollapsingHeader`] with the given heading, like [`Ui::collapsing`].
+    pub fn collapsing<R>(
+        &mut self,
+        heading: impl Into<WidgetText>,
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        self.header(CollapsingHeader::new(heading), add_body)
+    }
+
+    /// The [`Ui`] of the group, for adding other widgets between the headers.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+}
+
+/// Which header should stay open.
+///
+/// A header opened this frame wins, then the one that was open before,
+/// then the first open one (e.g. because of [`CollapsingHeader::default_open`]).
+fn open_header(previous: Option<Id>, headers: &[AccordionHeader]) -> Option<Id> {
+    let opened = headers.iter().rev().find(|h| h.is_open && h.changed);
+    let previous = headers.iter().find(|h| h.is_open && Some(h.id) == previous);
+    let first = headers.iter().find(|h| h.is_open);
+    opened.or(previous).or(first).map(|h| h.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(id: &str, is_open: bool, changed: bool) -> AccordionHeader {
+        AccordionHeader {
+            id: Id::new(id),
+            is_open,
+            changed,
+        }
+    }
+
+    #[test]
+    fn test_open_header() {
+        let (a, b) = (Id::new("a"), Id::new("b"));
+
+        // Nothing open:
+        assert_eq!(open_header(Some(a), &[header("a", false, true)]), None);
+
+        // Default open:
+        let headers = [
+            header("a", false, false),
+            header("b", true, false),
+            header("c", true, false),
+        ];
+        assert_eq!(open_header(None, &headers), Some(b));
+
+        // The open one stays open:
+        let headers = [header("a", true, false), header("b", true, false)];
+        assert_eq!(open_header(Some(b), &headers), Some(b));
+
+        // Opening another one:
+        let headers = [header("a", true, true), header("b", true, false)];
+        assert_eq!(open_header(Some(b), &headers), Some(a));
+    }
+}
@@ -2,7 +2,10 @@
 //! It has no frame or own size. It is potentially movable.
 //! It is the foundation for windows and popups.
 
-use crate::*;
+use crate::{
+    timings::{container_name, TimingScope},
+    *,
+};
 
 /// State of an [`Area`] that is persisted between frames.
 ///
@@ -95,6 +98,7 @@ pub struct Area {
     anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
     fade_in: bool,
+    animation: Option<AreaAnimation>,
 }
 
 impl WidgetWithState for Area {
@@ -120,6 +124,7 @@ impl Area {
             pivot: Align2::LEFT_TOP,
             anchor: None,
             fade_in: true,
+            animation: None,
         }
     }
 
@@ -314,6 +319,17 @@ impl Area {
         self.fade_in = fade_in;
         self
     }
+
+    /// How the area animates when it opens (and closes, for [`Window`]s and popups).
+    ///
+    /// Default: [`crate::style::Style::window_animation`] for windows,
+    /// [`crate::style::Style::popup_animation`] for popups, menus and tooltips,
+    /// and [`AreaAnimation::Fade`] for everything else.
+    #[inline]
+    pub fn animation(mut self, animation: AreaAnimation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
 }
 
 pub(crate) struct Prepared {
@@ -333,6 +349,13 @@ pub(crate) struct Prepared {
     sizing_pass: bool,
 
     fade_in: bool,
+    animation: AreaAnimation,
+
+    /// Set while the area is animating closed: how far from closed it is (`1` = fully open).
+    closing: Option<f32>,
+
+    timing: TimingScope,
+    timing_name: Option<String>,
 }
 
 impl Area {
@@ -349,6 +372,8 @@ impl Area {
     }
 
     pub(crate) fn begin(self, ctx: &Context) -> Prepared {
+        let timing = TimingScope::start(ctx);
+
         let Self {
             id,
             kind,
@@ -365,8 +390,20 @@ impl Area {
             constrain,
             constrain_rect,
             fade_in,
+            animation,
         } = self;
 
+        let animation = animation.unwrap_or_else(|| {
+            let style = ctx.style();
+            match kind {
+                UiKind::Window => style.window_animation,
+                UiKind::Popup | UiKind::Menu | UiKind::Picker | UiKind::Tooltip => {
+                    style.popup_animation
+                }
+                _ => AreaAnimation::Fade,
+            }
+        });
+
         let constrain_rect = constrain_rect.unwrap_or_else(|| ctx.screen_rect());
 
         let layer_id = LayerId::new(order, id);
@@ -483,6 +520,10 @@ impl Area {
             constrain_rect,
             sizing_pass: is_new,
             fade_in,
+            animation,
+            closing: None,
+            timing,
+            timing_name: None,
         }
     }
 }
@@ -504,6 +545,34 @@ impl Prepared {
         self.constrain_rect
     }
 
+    /// Animate the area closing, with `t` going from `1` (open) to `0` (closed).
+    ///
+    /// The contents will not react to any input while closing.
+    pub(crate) fn set_closing(&mut self, t: f32) {
+        self.closing = Some(t);
+    }
+
+    /// What to call this area in [`crate::Context::timings`], e.g. the title of a window.
+    pub(crate) fn set_timing_name(&mut self, name: impl FnOnce() -> String) {
+        if self.timing.is_active() {
+            self.timing_name = Some(name());
+        }
+    }
+
+    /// How far along the open/close animation is, from `0` (closed) to `1` (open).
+    fn animation_t(&self, ctx: &Context) -> f32 {
+        if let Some(t) = self.closing {
+            t
+        } else if self.fade_in {
+            let age =
+                ctx.input(|i| (i.time - self.state.last_became_visible_at) as f32 + i.predicted_dt);
+            let t = crate::remap_clamp(age, 0.0..=ctx.style().animation_time, 0.0..=1.0);
+            emath::easing::cubic_out(t) // slow fade-out = quick fade-in
+        } else {
+            1.0
+        }
+    }
+
     pub(crate) fn content_ui(&self, ctx: &Context) -> Ui {
         let max_rect = Rect::from_min_size(self.state.left_top_pos(), self.state.size);
 
@@ -518,15 +587,13 @@ impl Prepared {
             UiStackInfo::new(self.kind),
         );
 
-        if self.fade_in {
-            let age =
-                ctx.input(|i| (i.time - self.state.last_became_visible_at) as f32 + i.predicted_dt);
-            let opacity = crate::remap_clamp(age, 0.0..=ctx.style().animation_time, 0.0..=1.0);
-            let opacity = emath::easing::cubic_out(opacity); // slow fade-out = quick fade-in
-            ui.multiply_opacity(opacity);
-            if opacity < 1.0 {
-                ctx.request_repaint();
-            }
+        let t = self.animation_t(ctx);
+        ui.multiply_opacity(self.animation.opacity(t));
+        if t < 1.0 && self.closing.is_none() {
+            ctx.request_repaint();
+        }
+        if self.closing.is_some() {
+            ui.disable_interaction();
         }
 
         if !self.enabled {
@@ -540,14 +607,20 @@ impl Prepared {
 
     #[allow(clippy::needless_pass_by_value)] // intentional to swallow up `content_ui`.
     pub(crate) fn end(self, ctx: &Context, content_ui: Ui) -> Response {
+        let t = self.animation_t(ctx);
         let Self {
-            kind: _,
+            kind,
             layer_id,
             mut state,
             move_response: mut response,
+            animation,
+            timing,
+            timing_name,
             ..
         } = self;
 
+        ctx.transform_layer_shapes(layer_id, animation.transform(t, state.pivot_pos));
+
         state.size = content_ui.min_size();
 
         // Make sure we report back the correct size.
@@ -558,6 +631,9 @@ impl Prepared {
 
         ctx.memory_mut(|m| m.areas_mut().set_state(layer_id, state));
 
+        let name = || timing_name.unwrap_or_else(|| container_name(kind, layer_id.id));
+        timing.finish(ctx, layer_id.id, Some(kind), name, final_rect);
+
         response
     }
 }
@@ -41,6 +41,7 @@ pub struct ComboBox {
     height: Option<f32>,
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
+    validation: Option<ValidationState>,
 }
 
 impl ComboBox {
@@ -54,6 +55,7 @@ impl ComboBox {
             height: None,
             icon: None,
             wrap_mode: None,
+            validation: None,
         }
     }
 
@@ -68,6 +70,7 @@ impl ComboBox {
             height: None,
             icon: None,
             wrap_mode: None,
+            validation: None,
         }
     }
 
@@ -81,6 +84,7 @@ impl ComboBox {
             height: None,
             icon: None,
             wrap_mode: None,
+            validation: None,
         }
     }
 
@@ -174,6 +178,15 @@ impl ComboBox {
         self
     }
 
+    /// Show the result of validating the selected value, with a colored border and a message.
+    ///
+    /// See [`ValidationState`].
+    #[inline]
+    pub fn validation(mut self, validation: impl Into<Option<ValidationState>>) -> Self {
+        self.validation = validation.into();
+        self
+    }
+
     /// Show the combo box, with the given ui code for the menu contents.
     ///
     /// Returns `InnerResponse { inner: None }` if the combo box is closed.
@@ -198,31 +211,39 @@ impl ComboBox {
             height,
             icon,
             wrap_mode,
+            validation,
         } = self;
 
         let button_id = ui.make_persistent_id(id_source);
 
-        ui.horizontal(|ui| {
-            let mut ir = combo_box_dyn(
-                ui,
-                button_id,
-                selected_text,
-                menu_contents,
-                icon,
-                wrap_mode,
-                (width, height),
-            );
-            if let Some(label) = label {
-                ir.response
-                    .widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, label.text()));
-                ir.response |= ui.label(label);
-            } else {
-                ir.response
-                    .widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, ""));
-            }
-            ir
-        })
-        .inner
+        let (mut ir, button_rect) = ui
+            .horizontal(|ui| {
+                let mut ir = combo_box_dyn(
+                    ui,
+                    button_id,
+                    selected_text,
+                    menu_contents,
+                    icon,
+                    wrap_mode,
+                    (width, height),
+                );
+                let button_rect = ir.response.rect;
+                if let Some(label) = label {
+                    ir.response
+                        .widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, label.text()));
+                    ir.response |= ui.label(label);
+                } else {
+                    ir.response
+                        .widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, ""));
+                }
+                (ir, button_rect)
+            })
+            .inner;
+
+        if let Some(validation) = validation {
+            ir.response = validation.show(ui, ir.response, button_rect);
+        }
+        ir
     }
 
     /// Show a list of items with the given selected index.
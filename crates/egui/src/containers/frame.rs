@@ -300,8 +300,8 @@ impl Frame {
         if shadow == Default::default() {
             frame_shape
         } else {
-            let shadow = shadow.as_shape(outer_rect, rounding);
-            Shape::Vec(vec![Shape::from(shadow), frame_shape])
+            let shadow = shadow.paint(outer_rect, rounding);
+            Shape::Vec(vec![shadow, frame_shape])
         }
     }
 }
@@ -2,6 +2,7 @@
 //!
 //! For instance, a [`Frame`] adds a frame and background to some contained UI.
 
+mod accordion;
 pub(crate) mod area;
 pub mod collapsing_header;
 mod combo_box;
@@ -13,6 +14,7 @@ pub mod scroll_area;
 pub(crate) mod window;
 
 pub use {
+    accordion::{Accordion, AccordionGroup, AccordionState},
     area::{Area, AreaState},
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
@@ -15,7 +15,10 @@
 //!
 //! Add your [`Window`]:s after any top-level panels.
 
-use crate::*;
+use crate::{
+    timings::{container_name, TimingScope},
+    *,
+};
 
 fn animate_expansion(ctx: &Context, id: Id, is_expanded: bool) -> f32 {
     ctx.animate_bool_responsive(id, is_expanded)
@@ -26,6 +29,12 @@ fn animate_expansion(ctx: &Context, id: Id, is_expanded: bool) -> f32 {
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct PanelState {
     pub rect: Rect,
+
+    /// For panels with fractional sizes (e.g. [`SidePanel::default_width_fraction`]):
+    /// the size of the panel as a fraction of the available space,
+    /// so that it keeps its proportions when the window is resized.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub size_fraction: Option<f32>,
 }
 
 impl PanelState {
@@ -102,6 +111,8 @@ pub struct SidePanel {
     show_separator_line: bool,
     default_width: f32,
     width_range: Rangef,
+    default_width_fraction: Option<f32>,
+    width_fraction_range: Option<Rangef>,
 }
 
 impl SidePanel {
@@ -125,6 +136,8 @@ impl SidePanel {
             show_separator_line: true,
             default_width: 200.0,
             width_range: Rangef::new(96.0, f32::INFINITY),
+            default_width_fraction: None,
+            width_fraction_range: None,
         }
     }
 
@@ -158,6 +171,7 @@ impl SidePanel {
     #[inline]
     pub fn default_width(mut self, default_width: f32) -> Self {
         self.default_width = default_width;
+        self.default_width_fraction = None;
         self.width_range = Rangef::new(
             self.width_range.min.at_most(default_width),
             self.width_range.max.at_least(default_width),
@@ -193,6 +207,28 @@ impl SidePanel {
     pub fn exact_width(mut self, width: f32) -> Self {
         self.default_width = width;
         self.width_range = Rangef::point(width);
+        self.default_width_fraction = None;
+        self.width_fraction_range = None;
+        self
+    }
+
+    /// The initial width as a fraction of the available width, e.g. `0.25` for a quarter of the screen.
+    ///
+    /// When any fractional size is set, the width the user drags the panel to is remembered
+    /// as a fraction too, so the panel keeps its proportions when the window is resized.
+    #[inline]
+    pub fn default_width_fraction(mut self, fraction: f32) -> Self {
+        self.default_width_fraction = Some(fraction);
+        self
+    }
+
+    /// The allowable width range as fractions of the available width, e.g. `0.1..=0.5`.
+    ///
+    /// This applies on top of [`Self::width_range`], so you can combine e.g. a minimum width in points
+    /// with a maximum of half the screen.
+    #[inline]
+    pub fn width_fraction_range(mut self, fraction_range: impl Into<Rangef>) -> Self {
+        self.width_fraction_range = Some(fraction_range.into());
         self
     }
 
@@ -228,14 +264,23 @@ impl SidePanel {
             show_separator_line,
             default_width,
             width_range,
+            default_width_fraction,
+            width_fraction_range,
         } = self;
 
         let available_rect = ui.available_rect_before_wrap();
+        let available_width = available_rect.width();
+        let proportional = default_width_fraction.is_some() || width_fraction_range.is_some();
+        let width_range = apply_fraction_range(width_range, width_fraction_range, available_width);
+
         let mut panel_rect = available_rect;
-        let mut width = default_width;
+        let mut width = default_width_fraction.map_or(default_width, |f| f * available_width);
         {
             if let Some(state) = PanelState::load(ui.ctx(), id) {
-                width = state.rect.width();
+                width = match state.size_fraction {
+                    Some(fraction) if proportional => fraction * available_width,
+                    _ => state.rect.width(),
+                };
             }
             width = clamp_to_range(width, width_range).at_most(available_rect.width());
             side.set_rect_width(&mut panel_rect, width);
@@ -319,7 +364,11 @@ impl SidePanel {
             ui.ctx().set_cursor_icon(cursor_icon);
         }
 
-        PanelState { rect }.store(ui.ctx(), id);
+        PanelState {
+            rect,
+            size_fraction: size_fraction(proportional, rect.width(), available_width),
+        }
+        .store(ui.ctx(), id);
 
         {
             let stroke = if is_resizing {
@@ -358,6 +407,8 @@ impl SidePanel {
         ctx: &Context,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
+        let timing = TimingScope::start(ctx);
+        let id = self.id;
         let layer_id = LayerId::background();
         let side = self.side;
         let available_rect = ctx.available_rect();
@@ -382,6 +433,13 @@ impl SidePanel {
                 state.allocate_right_panel(Rect::from_min_max(rect.min, available_rect.max));
             }),
         }
+
+        let kind = match side {
+            Side::Left => UiKind::LeftPanel,
+            Side::Right => UiKind::RightPanel,
+        };
+        timing.finish(ctx, id, Some(kind), || container_name(kind, id), rect);
+
         inner_response
     }
 
@@ -575,6 +633,8 @@ pub struct TopBottomPanel {
     show_separator_line: bool,
     default_height: Option<f32>,
     height_range: Rangef,
+    default_height_fraction: Option<f32>,
+    height_fraction_range: Option<Rangef>,
 }
 
 impl TopBottomPanel {
@@ -598,6 +658,8 @@ impl TopBottomPanel {
             show_separator_line: true,
             default_height: None,
             height_range: Rangef::new(20.0, f32::INFINITY),
+            default_height_fraction: None,
+            height_fraction_range: None,
         }
     }
 
@@ -632,6 +694,7 @@ impl TopBottomPanel {
     #[inline]
     pub fn default_height(mut self, default_height: f32) -> Self {
         self.default_height = Some(default_height);
+        self.default_height_fraction = None;
         self.height_range = Rangef::new(
             self.height_range.min.at_most(default_height),
             self.height_range.max.at_least(default_height),
@@ -669,6 +732,27 @@ impl TopBottomPanel {
     pub fn exact_height(mut self, height: f32) -> Self {
         self.default_height = Some(height);
         self.height_range = Rangef::point(height);
+        self.default_height_fraction = None;
+        self.height_fraction_range = None;
+        self
+    }
+
+    /// The initial height as a fraction of the available height, e.g. `0.25` for a quarter of the screen.
+    ///
+    /// When any fractional size is set, the height the user drags the panel to is remembered
+    /// as a fraction too, so the panel keeps its proportions when the window is resized.
+    #[inline]
+    pub fn default_height_fraction(mut self, fraction: f32) -> Self {
+        self.default_height_fraction = Some(fraction);
+        self
+    }
+
+    /// The allowable height range as fractions of the available height, e.g. `0.1..=0.5`.
+    ///
+    /// This applies on top of [`Self::height_range`].
+    #[inline]
+    pub fn height_fraction_range(mut self, fraction_range: impl Into<Rangef>) -> Self {
+        self.height_fraction_range = Some(fraction_range.into());
         self
     }
 
@@ -704,13 +788,25 @@ impl TopBottomPanel {
             show_separator_line,
             default_height,
             height_range,
+            default_height_fraction,
+            height_fraction_range,
         } = self;
 
         let available_rect = ui.available_rect_before_wrap();
+        let available_height = available_rect.height();
+        let proportional = default_height_fraction.is_some() || height_fraction_range.is_some();
+        let height_range =
+            apply_fraction_range(height_range, height_fraction_range, available_height);
+
         let mut panel_rect = available_rect;
 
         let mut height = if let Some(state) = PanelState::load(ui.ctx(), id) {
-            state.rect.height()
+            match state.size_fraction {
+                Some(fraction) if proportional => fraction * available_height,
+                _ => state.rect.height(),
+            }
+        } else if let Some(fraction) = default_height_fraction {
+            fraction * available_height
         } else {
             default_height.unwrap_or_else(|| ui.style().spacing.interact_size.y)
         };
@@ -800,7 +896,11 @@ impl TopBottomPanel {
             ui.ctx().set_cursor_icon(cursor_icon);
         }
 
-        PanelState { rect }.store(ui.ctx(), id);
+        PanelState {
+            rect,
+            size_fraction: size_fraction(proportional, rect.height(), available_height),
+        }
+        .store(ui.ctx(), id);
 
         {
             let stroke = if is_resizing {
@@ -839,6 +939,8 @@ impl TopBottomPanel {
         ctx: &Context,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
+        let timing = TimingScope::start(ctx);
+        let id = self.id;
         let layer_id = LayerId::background();
         let available_rect = ctx.available_rect();
         let side = self.side;
@@ -869,6 +971,12 @@ impl TopBottomPanel {
             }
         }
 
+        let kind = match side {
+            TopBottomSide::Top => UiKind::TopPanel,
+            TopBottomSide::Bottom => UiKind::BottomPanel,
+        };
+        timing.finish(ctx, id, Some(kind), || container_name(kind, id), rect);
+
         inner_response
     }
 
@@ -1106,6 +1214,7 @@ impl CentralPanel {
         ctx: &Context,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
+        let timing = TimingScope::start(ctx);
         let available_rect = ctx.available_rect();
         let layer_id = LayerId::background();
         let id = Id::new((ctx.viewport_id(), "central_panel"));
@@ -1125,6 +1234,10 @@ impl CentralPanel {
         // Only inform ctx about what we actually used, so we can shrink the native window to fit.
         ctx.frame_state_mut(|state| state.allocate_central_panel(inner_response.response.rect));
 
+        let kind = UiKind::CentralPanel;
+        let rect = inner_response.response.rect;
+        timing.finish(ctx, id, Some(kind), || container_name(kind, id), rect);
+
         inner_response
     }
 }
@@ -1133,3 +1246,19 @@ fn clamp_to_range(x: f32, range: Rangef) -> f32 {
     let range = range.as_positive();
     x.clamp(range.min, range.max)
 }
+
+/// Narrow the size range in points to the given fractions of the available size.
+fn apply_fraction_range(range: Rangef, fraction_range: Option<Rangef>, available: f32) -> Rangef {
+    if let Some(fraction_range) = fraction_range {
+        let min = range.min.max(fraction_range.min * available);
+        let max = range.max.min(fraction_range.max * available).at_least(min);
+        Rangef::new(min, max)
+    } else {
+        range
+    }
+}
+
+/// The size to remember as a fraction of the available size, for proportional panels.
+fn size_fraction(proportional: bool, size: f32, available: f32) -> Option<f32> {
+    (proportional && 0.0 < available).then(|| size / available)
+}
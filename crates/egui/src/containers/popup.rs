@@ -297,6 +297,9 @@ pub fn popup_below_widget<R>(
 /// You must open the popup with [`Memory::open_popup`] or  [`Memory::toggle_popup`].
 ///
 /// Returns `None` if the popup is not open.
+/// After the popup is closed, it is still shown for a little while
+/// to animate it closing (see [`crate::style::Style::popup_animation`]),
+/// but `add_contents` can't be interacted with, and `None` is returned.
 ///
 /// ```
 /// # egui::__run_test_ui(|ui| {
@@ -322,7 +325,17 @@ pub fn popup_above_or_below_widget<R>(
     close_behavior: PopupCloseBehavior,
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
-    if parent_ui.memory(|mem| mem.is_popup_open(popup_id)) {
+    let is_open = parent_ui.memory(|mem| mem.is_popup_open(popup_id));
+    let openness = parent_ui.ctx().animate_bool_with_easing(
+        popup_id.with("close_animation"),
+        is_open,
+        emath::easing::cubic_out,
+    );
+    let is_closing = !is_open
+        && 0.0 < openness
+        && parent_ui.style().popup_animation != crate::style::AreaAnimation::None;
+
+    if is_open || is_closing {
         let (mut pos, pivot) = match above_or_below {
             AboveOrBelow::Above => (widget_response.rect.left_top(), Align2::LEFT_BOTTOM),
             AboveOrBelow::Below => (widget_response.rect.left_bottom(), Align2::LEFT_TOP),
@@ -338,23 +351,36 @@ pub fn popup_above_or_below_widget<R>(
         let frame_margin = frame.total_margin();
         let inner_width = widget_response.rect.width() - frame_margin.sum().x;
 
-        let response = Area::new(popup_id)
+        let ctx = parent_ui.ctx();
+        let mut area = Area::new(popup_id)
             .kind(UiKind::Popup)
             .order(Order::Foreground)
             .fixed_pos(pos)
             .default_width(inner_width)
             .pivot(pivot)
-            .show(parent_ui.ctx(), |ui| {
-                frame
-                    .show(ui, |ui| {
-                        ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
-                            ui.set_min_width(inner_width);
-                            add_contents(ui)
-                        })
-                        .inner
-                    })
-                    .inner
-            });
+            .interactable(!is_closing)
+            .begin(ctx);
+        if is_closing {
+            area.set_closing(openness);
+        }
+        let mut content_ui = area.content_ui(ctx);
+        let inner = frame
+            .show(&mut content_ui, |ui| {
+                ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                    ui.set_min_width(inner_width);
+                    add_contents(ui)
+                })
+                .inner
+            })
+            .inner;
+        let response = InnerResponse {
+            inner,
+            response: area.end(ctx, content_ui),
+        };
+
+        if is_closing {
+            return None;
+        }
 
         let should_close = match close_behavior {
             PopupCloseBehavior::CloseOnClick => widget_response.clicked_elsewhere(),
@@ -330,7 +330,11 @@ impl Resize {
                 content_ui.min_rect().left_top() + size - corner_size,
                 corner_size,
             );
-            Some(ui.interact(corner_rect, corner_id, Sense::drag()))
+            let corner_response = ui.interact(corner_rect, corner_id, Sense::drag());
+            if corner_response.drag_started() {
+                corner_response.capture_pointer();
+            }
+            Some(corner_response)
         } else {
             None
         };
@@ -1,5 +1,7 @@
 #![allow(clippy::needless_range_loop)]
 
+use std::sync::Arc;
+
 use crate::*;
 
 #[derive(Clone, Copy, Debug)]
@@ -42,6 +44,9 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// The size of the content last frame.
+    content_size: Vec2,
 }
 
 impl Default for State {
@@ -56,6 +61,7 @@ impl Default for State {
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            content_size: Vec2::ZERO,
         }
     }
 }
@@ -93,6 +99,69 @@ pub struct ScrollAreaOutput<R> {
     pub inner_rect: Rect,
 }
 
+/// The scroll position of a [`ScrollArea`], as seen by its contents this frame.
+///
+/// Get it from within the contents with [`Ui::scroll_area_viewport`],
+/// or with [`ScrollArea::on_scroll`].
+///
+/// The contents are laid out using exactly this offset,
+/// so effects built on it (e.g. parallax headers) stay in sync with the contents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScrollAreaViewport {
+    /// [`Id`] of the [`ScrollArea`].
+    pub id: Id,
+
+    /// Positive offset means scrolling down/right.
+    pub offset: Vec2,
+
+    /// The kinetic scrolling velocity, in points per second.
+    ///
+    /// Positive means the content is moving down/right, i.e. the opposite of [`Self::offset`].
+    pub velocity: Vec2,
+
+    /// Relative coordinates: the offset and size of the view of the contents,
+    /// same as the rectangle given to [`ScrollArea::show_viewport`].
+    pub viewport: Rect,
+
+    /// Where on the screen the contents are shown (excludes scroll bars).
+    pub inner_rect: Rect,
+
+    /// The size of the contents last frame.
+    pub content_size: Vec2,
+}
+
+impl ScrollAreaViewport {
+    /// How far it is possible to scroll.
+    pub fn max_offset(&self) -> Vec2 {
+        (self.content_size - self.viewport.size()).max(Vec2::ZERO)
+    }
+
+    /// How far we have scrolled, from `0.0` (at the start) to `1.0` (at the end).
+    ///
+    /// Zero along axes where there is nothing to scroll.
+    pub fn progress(&self) -> Vec2 {
+        let max_offset = self.max_offset();
+        let progress = |d: usize| {
+            if max_offset[d] > 0.0 {
+                (self.offset[d] / max_offset[d]).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+        vec2(progress(0), progress(1))
+    }
+}
+
+/// See [`ScrollArea::on_scroll`].
+#[derive(Clone)]
+struct OnScroll(Arc<dyn Fn(&mut Ui, &ScrollAreaViewport)>);
+
+impl std::fmt::Debug for OnScroll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("OnScroll")
+    }
+}
+
 /// Indicate whether the horizontal and vertical scroll bars must be always visible, hidden or visible when needed.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -183,6 +252,8 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    on_scroll: Option<OnScroll>,
 }
 
 impl ScrollArea {
@@ -227,6 +298,7 @@ impl ScrollArea {
             drag_to_scroll: true,
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            on_scroll: None,
         }
     }
 
@@ -410,6 +482,32 @@ impl ScrollArea {
         self
     }
 
+    /// Call this after the contents have been added, with the scroll position they were laid out with.
+    ///
+    /// The [`Ui`] is that of the contents, so you can use it to paint on top of them,
+    /// e.g. a toolbar that shrinks as you scroll down, or a header with a parallax effect.
+    /// To paint behind the contents, use [`Ui::scroll_area_viewport`] at the start of the contents instead.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical()
+    ///     .on_scroll(|ui, viewport| {
+    ///         // Fade in a shadow at the top as soon as we start scrolling:
+    ///         let t = (viewport.offset.y / 20.0).clamp(0.0, 1.0);
+    ///         let rect = viewport.inner_rect.with_max_y(viewport.inner_rect.top() + 4.0);
+    ///         ui.painter().rect_filled(rect, 0.0, egui::Color32::BLACK.gamma_multiply(0.3 * t));
+    ///     })
+    ///     .show(ui, |ui| {
+    ///         // Add a lot of widgets here.
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn on_scroll(mut self, on_scroll: impl Fn(&mut Ui, &ScrollAreaViewport) + 'static) -> Self {
+        self.on_scroll = Some(OnScroll(Arc::new(on_scroll)));
+        self
+    }
+
     /// Is any scrolling enabled?
     pub(crate) fn is_any_scroll_enabled(&self) -> bool {
         self.scroll_enabled[0] || self.scroll_enabled[1]
@@ -477,6 +575,10 @@ struct Prepared {
     scrolling_enabled: bool,
     stick_to_end: Vec2b,
     animated: bool,
+
+    /// What the contents see, see [`Ui::scroll_area_viewport`].
+    scroll_viewport: ScrollAreaViewport,
+    on_scroll: Option<OnScroll>,
 }
 
 impl ScrollArea {
@@ -494,6 +596,7 @@ impl ScrollArea {
             drag_to_scroll,
             stick_to_end,
             animated,
+            on_scroll,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -559,34 +662,7 @@ impl ScrollArea {
             }
         }
 
-        let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
-        let mut content_ui = ui.child_ui(
-            content_max_rect,
-            *ui.layout(),
-            Some(UiStackInfo::new(UiKind::ScrollArea)),
-        );
-
-        {
-            // Clip the content, but only when we really need to:
-            let clip_rect_margin = ui.visuals().clip_rect_margin;
-            let mut content_clip_rect = ui.clip_rect();
-            for d in 0..2 {
-                if scroll_enabled[d] {
-                    if state.content_is_too_large[d] {
-                        content_clip_rect.min[d] = inner_rect.min[d] - clip_rect_margin;
-                        content_clip_rect.max[d] = inner_rect.max[d] + clip_rect_margin;
-                    }
-                } else {
-                    // Nice handling of forced resizing beyond the possible:
-                    content_clip_rect.max[d] = ui.clip_rect().max[d] - current_bar_use[d];
-                }
-            }
-            // Make sure we didn't accidentally expand the clip rect
-            content_clip_rect = content_clip_rect.intersect(ui.clip_rect());
-            content_ui.set_clip_rect(content_clip_rect);
-        }
-
-        let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
+        // Update the offset before placing the contents, so they are laid out with the latest offset.
         let dt = ui.input(|i| i.stable_dt).at_most(0.1);
 
         if (scrolling_enabled && drag_to_scroll)
@@ -663,6 +739,44 @@ impl ScrollArea {
             }
         }
 
+        let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
+        let mut content_ui = ui.child_ui(
+            content_max_rect,
+            *ui.layout(),
+            Some(UiStackInfo::new(UiKind::ScrollArea)),
+        );
+
+        {
+            // Clip the content, but only when we really need to:
+            let clip_rect_margin = ui.visuals().clip_rect_margin;
+            let mut content_clip_rect = ui.clip_rect();
+            for d in 0..2 {
+                if scroll_enabled[d] {
+                    if state.content_is_too_large[d] {
+                        content_clip_rect.min[d] = inner_rect.min[d] - clip_rect_margin;
+                        content_clip_rect.max[d] = inner_rect.max[d] + clip_rect_margin;
+                    }
+                } else {
+                    // Nice handling of forced resizing beyond the possible:
+                    content_clip_rect.max[d] = ui.clip_rect().max[d] - current_bar_use[d];
+                }
+            }
+            // Make sure we didn't accidentally expand the clip rect
+            content_clip_rect = content_clip_rect.intersect(ui.clip_rect());
+            content_ui.set_clip_rect(content_clip_rect);
+        }
+
+        let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
+        let scroll_viewport = ScrollAreaViewport {
+            id,
+            offset: state.offset,
+            velocity: state.vel,
+            viewport,
+            inner_rect,
+            content_size: state.content_size,
+        };
+        ctx.data_mut(|data| data.insert_temp(content_ui.id(), scroll_viewport));
+
         Prepared {
             id,
             state,
@@ -677,6 +791,8 @@ impl ScrollArea {
             scrolling_enabled,
             stick_to_end,
             animated,
+            scroll_viewport,
+            on_scroll,
         }
     }
 
@@ -761,6 +877,9 @@ impl ScrollArea {
         let id = prepared.id;
         let inner_rect = prepared.inner_rect;
         let inner = add_contents(&mut prepared.content_ui, prepared.viewport);
+        if let Some(on_scroll) = &prepared.on_scroll {
+            (on_scroll.0)(&mut prepared.content_ui, &prepared.scroll_viewport);
+        }
         let (content_size, state) = prepared.end(ui);
         ScrollAreaOutput {
             inner,
@@ -789,6 +908,8 @@ impl Prepared {
             scrolling_enabled,
             stick_to_end,
             animated,
+            scroll_viewport: _,
+            on_scroll: _,
         } = self;
 
         let content_size = content_ui.min_size();
@@ -1206,6 +1327,7 @@ impl Prepared {
         state.show_scroll = show_scroll_this_frame;
         state.content_is_too_large = content_is_too_large;
         state.interact_rect = Some(inner_rect);
+        state.content_size = content_size;
 
         state.store(ui.ctx(), id);
 
@@ -41,6 +41,8 @@ pub struct Window<'open> {
     default_open: bool,
     with_title_bar: bool,
     fade_out: bool,
+    detachable: bool,
+    tool_palette: bool,
 }
 
 impl<'open> Window<'open> {
@@ -64,6 +66,8 @@ impl<'open> Window<'open> {
             default_open: true,
             with_title_bar: true,
             fade_out: true,
+            detachable: false,
+            tool_palette: false,
         }
     }
 
@@ -137,6 +141,50 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// If `true`, dragging the window past the edge of the native window
+    /// turns it into a separate native window (a new viewport).
+    ///
+    /// Moving that native window back so that it is fully inside the original native window
+    /// turns it back into a normal [`Window`].
+    /// Closing the native window also turns it back, or closes the window if you use [`Self::open`].
+    ///
+    /// This only works if the egui backend supports multiple viewports
+    /// (see [`Context::embed_viewports`]).
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn detachable(mut self, detachable: bool) -> Self {
+        self.detachable = detachable;
+        self
+    }
+
+    /// If `true`, the window is shown as a floating tool palette:
+    /// a separate native window without decorations (see [`ViewportBuilder::tool_palette`])
+    /// that stays on top of the native window it belongs to,
+    /// and never takes keyboard focus away from it.
+    ///
+    /// The palette gets a small title bar for moving it around,
+    /// and a close button if you use [`Self::open`].
+    ///
+    /// If the egui backend doesn't support multiple viewports
+    /// (see [`Context::embed_viewports`]), this is shown as a normal [`Window`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn tool_palette(mut self, tool_palette: bool) -> Self {
+        self.tool_palette = tool_palette;
+        self
+    }
+
+    /// How the window animates when it opens and closes.
+    ///
+    /// Default: [`crate::style::Style::window_animation`].
+    #[inline]
+    pub fn animation(mut self, animation: AreaAnimation) -> Self {
+        self.area = self.area.animation(animation);
+        self
+    }
+
     /// Usage: `Window::new(…).mutate(|w| w.resize = w.resize.auto_expand_width(true))`
     // TODO(emilk): I'm not sure this is a good interface for this.
     #[inline]
@@ -429,8 +477,46 @@ impl<'open> Window<'open> {
             default_open,
             with_title_bar,
             fade_out,
+            detachable,
+            tool_palette,
         } = self;
 
+        let detached_id = area.id.with("detached");
+        if tool_palette && !ctx.embed_viewports() && !matches!(open, Some(false)) {
+            // Turn it into a native window where it was first laid out (invisibly, in the sizing pass):
+            let area_rect = ctx.memory(|mem| mem.areas().get(area.id).map(|state| state.rect()));
+            let parent_inner_rect = ctx.input(|i| i.viewport().inner_rect);
+            if let (Some(area_rect), Some(parent_inner_rect)) = (area_rect, parent_inner_rect) {
+                ctx.data_mut(|d| {
+                    if d.get_temp::<DetachedWindow>(detached_id).is_none() {
+                        let rect = area_rect.translate(parent_inner_rect.min.to_vec2());
+                        d.insert_temp(detached_id, DetachedWindow { rect });
+                    }
+                });
+            }
+        }
+        if let Some(detached) = ctx.data(|d| d.get_temp::<DetachedWindow>(detached_id)) {
+            if matches!(open, Some(false)) {
+                ctx.data_mut(|d| d.remove::<DetachedWindow>(detached_id));
+                return None;
+            }
+            return show_detached(
+                ctx,
+                area.id,
+                &title,
+                detached,
+                tool_palette,
+                scroll,
+                open,
+                add_contents,
+            );
+        }
+        let area = match ctx.data_mut(|d| d.remove_temp::<Pos2>(detached_id)) {
+            // We were just docked again:
+            Some(pos) => area.current_pos(pos),
+            None => area,
+        };
+
         let header_color =
             frame.map_or_else(|| ctx.style().visuals.widgets.open.weak_bg_fill, |f| f.fill);
         let mut window_frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
@@ -451,6 +537,14 @@ impl<'open> Window<'open> {
             return None;
         }
 
+        // Don't react to any input while animating closed:
+        let is_closing = !is_open && fade_out;
+        let (area, resize) = if is_closing {
+            (area.interactable(false), resize.resizable(false))
+        } else {
+            (area, resize)
+        };
+
         let area_id = area.id;
         let area_layer_id = area.layer();
         let resize_id = area_id.with("resize");
@@ -465,6 +559,10 @@ impl<'open> Window<'open> {
 
         let on_top = Some(area_layer_id) == ctx.top_layer_id();
         let mut area = area.begin(ctx);
+        if is_closing {
+            area.set_closing(opacity);
+        }
+        area.set_timing_name(|| title.text().to_owned());
 
         // Calculate roughly how much larger the window size is compared to the inner rect
         let (title_bar_height, title_content_spacing) = if with_title_bar {
@@ -506,12 +604,6 @@ impl<'open> Window<'open> {
         );
 
         let mut area_content_ui = area.content_ui(ctx);
-        if is_open {
-            // `Area` already takes care of fade-in animations,
-            // so we only need to handle fade-out animations here.
-        } else if fade_out {
-            area_content_ui.multiply_opacity(opacity);
-        }
 
         let content_inner = {
             // BEGIN FRAME --------------------------------
@@ -622,6 +714,25 @@ impl<'open> Window<'open> {
 
         let full_response = area.end(ctx, area_content_ui);
 
+        if detachable && full_response.dragged() && !ctx.embed_viewports() {
+            let pointer_pos = ctx.input(|i| i.pointer.latest_pos());
+            let parent_inner_rect = ctx.input(|i| i.viewport().inner_rect);
+            if let (Some(pointer_pos), Some(parent_inner_rect)) = (pointer_pos, parent_inner_rect) {
+                if !ctx.screen_rect().contains(pointer_pos) {
+                    // Keep the pointer where it was on the window:
+                    let grab_offset = (pointer_pos - full_response.rect.min)
+                        .clamp(Vec2::ZERO, full_response.rect.size());
+                    let rect = Rect::from_min_size(
+                        parent_inner_rect.min + (pointer_pos - grab_offset).to_vec2(),
+                        full_response.rect.size(),
+                    );
+                    ctx.data_mut(|d| d.insert_temp(detached_id, DetachedWindow { rect }));
+                    ctx.stop_dragging();
+                    ctx.request_repaint();
+                }
+            }
+        }
+
         let inner_response = InnerResponse {
             inner: content_inner,
             response: full_response,
@@ -630,6 +741,139 @@ impl<'open> Window<'open> {
     }
 }
 
+/// State of a [`Window`] that has been turned into a separate native window.
+#[derive(Clone, Copy, Debug)]
+struct DetachedWindow {
+    /// Where to put the native window, in monitor space and ui points.
+    rect: Rect,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn show_detached<R>(
+    ctx: &Context,
+    area_id: Id,
+    title: &WidgetText,
+    detached: DetachedWindow,
+    tool_palette: bool,
+    scroll: ScrollArea,
+    open: Option<&mut bool>,
+    add_contents: Box<dyn FnOnce(&mut Ui) -> R + '_>,
+) -> Option<InnerResponse<Option<R>>> {
+    let detached_id = area_id.with("detached");
+    let parent_inner_rect = ctx.input(|i| i.viewport().inner_rect);
+    let closable = open.is_some();
+
+    let builder = if tool_palette {
+        ViewportBuilder::tool_palette()
+    } else {
+        ViewportBuilder::default()
+    }
+    .with_title(title.text())
+    .with_position(detached.rect.min)
+    .with_inner_size(detached.rect.size());
+
+    let shown =
+        ctx.show_viewport_immediate(ViewportId::from_hash_of(area_id), builder, |ctx, class| {
+            if class == ViewportClass::Embedded {
+                // The backend can't show it as a native window after all.
+                return None;
+            }
+            let (mut close_requested, outer_rect) =
+                ctx.input(|i| (i.viewport().close_requested(), i.viewport().outer_rect));
+            if tool_palette && close_requested && !closable {
+                // There is nowhere to dock a tool palette, so keep it open.
+                ctx.send_viewport_cmd(ViewportCommand::CancelClose);
+                close_requested = false;
+            }
+
+            let panel = if tool_palette {
+                let frame = Frame::window(&ctx.style()).shadow(Shadow::NONE);
+                CentralPanel::default().frame(frame)
+            } else {
+                CentralPanel::default()
+            };
+            let inner_response = panel.show(ctx, |ui| {
+                if tool_palette && tool_palette_title_bar(ui, title, closable) {
+                    close_requested = true;
+                }
+                if scroll.is_any_scroll_enabled() {
+                    scroll.show(ui, add_contents).inner
+                } else {
+                    add_contents(ui)
+                }
+            });
+            Some((inner_response, close_requested, outer_rect))
+        });
+
+    let Some((inner_response, close_requested, outer_rect)) = shown else {
+        ctx.data_mut(|d| d.remove::<DetachedWindow>(detached_id));
+        return None;
+    };
+
+    if tool_palette {
+        if close_requested {
+            ctx.data_mut(|d| d.remove::<DetachedWindow>(detached_id));
+            if let Some(open) = open {
+                *open = false;
+            }
+            ctx.request_repaint();
+        }
+    } else if close_requested {
+        ctx.data_mut(|d| d.remove::<DetachedWindow>(detached_id));
+        if let Some(open) = open {
+            *open = false;
+        }
+        ctx.request_repaint();
+    } else if let (Some(outer_rect), Some(parent_inner_rect)) = (outer_rect, parent_inner_rect) {
+        if parent_inner_rect.contains_rect(outer_rect) {
+            // Dragged back inside the parent: dock it again.
+            ctx.data_mut(|d| {
+                d.remove::<DetachedWindow>(detached_id);
+                d.insert_temp(
+                    detached_id,
+                    outer_rect.min - parent_inner_rect.min.to_vec2(),
+                );
+            });
+            ctx.request_repaint();
+        }
+    }
+
+    Some(InnerResponse {
+        inner: Some(inner_response.inner),
+        response: inner_response.response,
+    })
+}
+
+/// A title bar for moving a tool palette around, since it has no native one.
+///
+/// Returns `true` if the close button was clicked.
+fn tool_palette_title_bar(ui: &mut Ui, title: &WidgetText, closable: bool) -> bool {
+    let height = ui.spacing().interact_size.y;
+    let (rect, response) =
+        ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::click_and_drag());
+    if response.drag_started_by(PointerButton::Primary) {
+        ui.ctx().send_viewport_cmd(ViewportCommand::StartDrag);
+    }
+
+    let text_style = TextStyle::Small;
+    let galley =
+        title
+            .clone()
+            .into_galley(ui, Some(TextWrapMode::Truncate), rect.width(), text_style);
+    let text_pos = pos2(rect.left(), rect.center().y - 0.5 * galley.size().y);
+    ui.painter()
+        .galley(text_pos, galley, ui.visuals().weak_text_color());
+
+    let close_clicked = closable && {
+        let button_size = Vec2::splat(ui.spacing().icon_width);
+        let button_rect = Align2::RIGHT_CENTER.align_size_within_rect(button_size, rect);
+        close_button(ui, button_rect).clicked()
+    };
+
+    ui.separator();
+    close_clicked
+}
+
 fn paint_resize_corner(
     ui: &Ui,
     possible: &PossibleInteractions,
@@ -848,6 +1092,9 @@ fn resize_interaction(
             sense: Sense::drag(),
             enabled: true,
         });
+        if response.drag_started() {
+            response.capture_pointer();
+        }
         SideResponse {
             hover: response.hovered(),
             drag: response.dragged(),
@@ -1207,3 +1454,98 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::DetachedWindow;
+    use crate::*;
+
+    /// The root viewport is 800x600 points, with its inner top left corner at 100,100.
+    fn run(ctx: &Context, open: &mut bool) {
+        let mut viewports = ViewportIdMap::default();
+        viewports.insert(
+            ViewportId::ROOT,
+            ViewportInfo {
+                inner_rect: Some(Rect::from_min_size(pos2(100.0, 100.0), vec2(800.0, 600.0))),
+                ..Default::default()
+            },
+        );
+        let input = RawInput {
+            viewports,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            Window::new("Tools")
+                .tool_palette(true)
+                .open(open)
+                .show(ctx, |ui| {
+                    ui.label("Brush");
+                });
+        });
+    }
+
+    fn is_detached(ctx: &Context) -> bool {
+        let detached_id = Id::new("Tools").with("detached");
+        ctx.data(|d| d.get_temp::<DetachedWindow>(detached_id).is_some())
+    }
+
+    #[test]
+    fn test_tool_palette_without_viewports() {
+        let ctx = Context::default();
+        let mut open = true;
+        for _ in 0..3 {
+            run(&ctx, &mut open);
+        }
+        assert!(!is_detached(&ctx));
+        assert!(ctx.memory(|mem| mem.areas().get(Id::new("Tools")).is_some()));
+    }
+
+    #[test]
+    fn test_tool_palette_viewport() {
+        let builders = Rc::new(RefCell::new(Vec::new()));
+        Context::set_immediate_viewport_renderer({
+            let builders = builders.clone();
+            move |ctx, viewport| {
+                builders.borrow_mut().push(viewport.builder);
+                let input = RawInput {
+                    viewport_id: viewport.ids.this,
+                    viewports: std::iter::once((viewport.ids.this, Default::default())).collect(),
+                    ..Default::default()
+                };
+                let _ = ctx.run(input, viewport.viewport_ui_cb);
+            }
+        });
+
+        let ctx = Context::default();
+        ctx.set_embed_viewports(false);
+        let mut open = true;
+
+        // First laid out in the root viewport, to know where to put it:
+        run(&ctx, &mut open);
+        assert!(builders.borrow().is_empty());
+        let area_rect = ctx.memory(|mem| mem.areas().get(Id::new("Tools")).unwrap().rect());
+
+        run(&ctx, &mut open);
+        assert!(is_detached(&ctx));
+        {
+            let builders = builders.borrow();
+            let builder = builders.last().unwrap();
+            assert_eq!(builder.focusable, Some(false));
+            assert_eq!(builder.owned_by_parent, Some(true));
+            assert_eq!(builder.decorations, Some(false));
+            assert_eq!(builder.position, Some(area_rect.min + vec2(100.0, 100.0)));
+        }
+
+        open = false;
+        let num_shown = builders.borrow().len();
+        run(&ctx, &mut open);
+        assert!(!is_detached(&ctx));
+        assert_eq!(
+            builders.borrow().len(),
+            num_shown,
+            "Closed palettes are not shown"
+        );
+    }
+}
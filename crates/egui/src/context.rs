@@ -23,6 +23,7 @@ use crate::{
 };
 
 use self::{hit_test::WidgetHits, interaction::InteractionSnapshot};
+use crate::tessellation_cache::TessellationCache;
 
 /// Information given to the backend about when it is time to repaint the ui.
 ///
@@ -68,6 +69,49 @@ impl Default for WrappedTextureManager {
     }
 }
 
+/// Upload a change to the font atlas to [`TextureId::default`].
+///
+/// With [`epaint::text::GlyphRasterization::Subpixel`], the change is also uploaded
+/// to `subpixel_glyph_texture`, which is only used for painting glyphs,
+/// while [`TextureId::default`] gets the average coverage of the glyphs.
+fn set_font_texture(
+    tex_mngr: &mut epaint::textures::TextureManager,
+    subpixel_glyph_texture: &mut Option<TextureId>,
+    fonts: &Fonts,
+    delta: ImageDelta,
+) {
+    let coverage_image = match &delta.image {
+        ImageData::Font(image) if image.is_subpixel() => Some(image.without_subpixel_coverage()),
+        _ => None,
+    };
+    let Some(coverage_image) = coverage_image else {
+        if let Some(glyph_texture) = subpixel_glyph_texture.take() {
+            tex_mngr.free(glyph_texture);
+        }
+        tex_mngr.set(TextureId::default(), delta);
+        return;
+    };
+
+    if let Some(glyph_texture) = *subpixel_glyph_texture {
+        tex_mngr.set(glyph_texture, delta.clone());
+    } else {
+        // The delta may be partial, so start from the whole atlas:
+        *subpixel_glyph_texture = Some(tex_mngr.alloc(
+            "egui_subpixel_glyph_texture".into(),
+            fonts.image().into(),
+            TextureAtlas::texture_options(),
+        ));
+    }
+
+    tex_mngr.set(
+        TextureId::default(),
+        ImageDelta {
+            image: ImageData::Font(coverage_image),
+            ..delta
+        },
+    );
+}
+
 // ----------------------------------------------------------------------------
 
 /// Generic event callback.
@@ -84,6 +128,7 @@ struct NamedContextCallback {
 struct Plugins {
     pub on_begin_frame: Vec<NamedContextCallback>,
     pub on_end_frame: Vec<NamedContextCallback>,
+    pub on_interaction: Vec<crate::interaction_events::NamedInteractionCallback>,
 }
 
 impl Plugins {
@@ -198,6 +243,19 @@ impl ContextImpl {
 
 // ----------------------------------------------------------------------------
 
+/// Where a viewport is in the flow of closing, see [`Context::defer_close`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum CloseState {
+    #[default]
+    Open,
+
+    /// The app is deciding whether to close, e.g. by asking the user to save their changes.
+    Deferred,
+
+    /// The app has decided to close. The next close request should not be deferred.
+    Confirmed,
+}
+
 /// State stored per viewport.
 ///
 /// Mostly for internal use.
@@ -226,6 +284,19 @@ pub struct ViewportState {
     /// Has this viewport been updated this frame?
     pub used: bool,
 
+    /// How long areas and panels took during the last complete frame,
+    /// if [`crate::Options::measure_timings`] is on.
+    pub timings: crate::Timings,
+
+    /// What was painted during the last complete frame.
+    pub frame_stats: crate::FrameStats,
+
+    /// Registered with [`Context::register_shortcut`] during the last complete frame.
+    pub shortcuts: Vec<crate::RegisteredShortcut>,
+
+    /// See [`Context::defer_close`].
+    close_state: CloseState,
+
     /// Written to during the frame.
     pub widgets_this_frame: WidgetRects,
 
@@ -370,6 +441,17 @@ struct ContextImpl {
     /// See <https://github.com/emilk/egui/issues/3664>.
     tex_manager: WrappedTextureManager,
 
+    /// The glyphs of a font atlas with [`epaint::text::GlyphRasterization::Subpixel`].
+    ///
+    /// See [`epaint::Tessellator::with_subpixel_glyph_texture`].
+    subpixel_glyph_texture: Option<TextureId>,
+
+    /// See [`Context::register_mesh`].
+    mesh_manager: Arc<RwLock<epaint::MeshManager>>,
+
+    /// See [`crate::Options::cache_tessellation`].
+    tessellation_cache: TessellationCache,
+
     /// Set during the frame, becomes active at the start of the next frame.
     new_zoom_factor: Option<f32>,
 
@@ -396,6 +478,16 @@ struct ContextImpl {
     accesskit_node_classes: accesskit::NodeClassSet,
 
     loaders: Arc<Loaders>,
+
+    #[cfg(feature = "icons")]
+    icons: Arc<crate::IconRegistry>,
+
+    spawner: Option<crate::task::Spawner>,
+
+    screen_color_picker: Option<crate::color_picker::ScreenColorPicker>,
+
+    /// See [`Context::set_session_end_blocker`].
+    session_end_blocker: Option<String>,
 }
 
 impl ContextImpl {
@@ -416,6 +508,14 @@ impl ContextImpl {
         let viewport = self.viewports.entry(viewport_id).or_default();
 
         if is_outermost_viewport {
+            if viewport_id == ViewportId::ROOT && self.memory.options.zoom_per_monitor {
+                let monitor_id = new_raw_input.viewport().monitor_id.as_ref();
+                if let Some(zoom_factor) = self.memory.zoom_factor_for_monitor(monitor_id) {
+                    // Moved to another monitor: use the zoom the user had there.
+                    self.new_zoom_factor.get_or_insert(zoom_factor);
+                }
+            }
+
             if let Some(new_zoom_factor) = self.new_zoom_factor.take() {
                 let ratio = self.memory.options.zoom_factor / new_zoom_factor;
                 self.memory.options.zoom_factor = new_zoom_factor;
@@ -442,6 +542,14 @@ impl ContextImpl {
 
         self.memory.begin_frame(&new_raw_input, &all_viewport_ids);
 
+        // A new viewport has no interaction state yet:
+        if self.memory.interaction_mut().pointer_capture.is_some() {
+            // Keep tracking the pointer when it leaves the window:
+            new_raw_input
+                .events
+                .retain(|event| !matches!(event, Event::PointerGone));
+        }
+
         viewport.input = std::mem::take(&mut viewport.input).begin_frame(
             new_raw_input,
             viewport.repaint.requested_immediate_repaint_prev_frame(),
@@ -533,11 +641,22 @@ impl ContextImpl {
         if let Some(font_definitions) = self.memory.new_font_definitions.take() {
             // New font definition loaded, so we need to reload all fonts.
             self.fonts.clear();
+            self.tessellation_cache.clear();
             self.font_definitions = font_definitions;
             #[cfg(feature = "log")]
             log::debug!("Loading new font definitions");
         }
 
+        let glyph_rasterization = self.memory.options.glyph_rasterization;
+        if self
+            .fonts
+            .values()
+            .any(|fonts| fonts.glyph_rasterization() != glyph_rasterization)
+        {
+            self.fonts.clear();
+            self.tessellation_cache.clear();
+        }
+
         let mut is_new = false;
 
         let fonts = self
@@ -548,11 +667,13 @@ impl ContextImpl {
                 log::trace!("Creating new Fonts for pixels_per_point={pixels_per_point}");
 
                 is_new = true;
+                self.tessellation_cache.clear();
                 crate::profile_scope!("Fonts::new");
-                Fonts::new(
+                Fonts::new_with_glyph_rasterization(
                     pixels_per_point,
                     max_texture_side,
                     self.font_definitions.clone(),
+                    glyph_rasterization,
                 )
             });
 
@@ -708,6 +829,7 @@ impl Default for Context {
         crate::debug_text::register(&ctx);
         crate::text_selection::LabelSelectionState::register(&ctx);
         crate::DragAndDrop::register(&ctx);
+        crate::dialog::register(&ctx);
 
         ctx
     }
@@ -1103,6 +1225,7 @@ impl Context {
             id,
             rect,
             interact_rect,
+            baseline: None,
             sense,
             enabled,
             contains_pointer: false,
@@ -1310,6 +1433,37 @@ impl Context {
         self.output_mut(|o| o.copied_text = text);
     }
 
+    /// Tell egui about a keyboard shortcut of your app, so it can be shown in a [`crate::ShortcutCheatSheet`].
+    ///
+    /// Call this every frame the shortcut is active.
+    /// This does not check if the shortcut was pressed; use [`InputState::consume_shortcut`] for that.
+    ///
+    /// `category` is used for grouping, e.g. "File" or "Navigation".
+    pub fn register_shortcut(
+        &self,
+        category: impl Into<String>,
+        description: impl Into<String>,
+        shortcut: KeyboardShortcut,
+    ) {
+        let shortcut = crate::RegisteredShortcut {
+            shortcut,
+            category: category.into(),
+            description: description.into(),
+        };
+        self.frame_state_mut(|fs| fs.shortcuts.push(shortcut));
+    }
+
+    /// The shortcuts registered with [`Self::register_shortcut`] during the last complete frame,
+    /// in the order they were registered.
+    pub fn registered_shortcuts(&self) -> Vec<crate::RegisteredShortcut> {
+        self.read(|ctx| {
+            ctx.viewports
+                .get(&ctx.viewport_id())
+                .map(|viewport| viewport.shortcuts.clone())
+                .unwrap_or_default()
+        })
+    }
+
     /// Format the given shortcut in a human-readable way (e.g. `Ctrl+Shift+X`).
     ///
     /// Can be used to get the text for [`Button::shortcut_text`].
@@ -1542,6 +1696,54 @@ impl Context {
         };
         self.write(|ctx| ctx.plugins.on_end_frame.push(named_cb));
     }
+
+    /// Call the given callback each time the user interacts with a widget,
+    /// e.g. clicks it, drags it, focuses it or changes its value.
+    ///
+    /// This can be used for analytics, usage heat-maps or tutorial systems.
+    /// Only widgets that call [`Response::widget_info`] are reported, which includes all built-in widgets.
+    ///
+    /// The callback is called while the widget is being added,
+    /// so it should be quick, and must not block on the [`Context`].
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.on_interaction(
+    ///     "analytics",
+    ///     std::sync::Arc::new(|event: &egui::InteractionEvent| {
+    ///         println!("{:?} {:?} {:?}", event.kind, event.info.typ, event.info.label);
+    ///     }),
+    /// );
+    /// ```
+    pub fn on_interaction(
+        &self,
+        debug_name: &'static str,
+        cb: crate::interaction_events::InteractionCallback,
+    ) {
+        let named_cb = crate::interaction_events::NamedInteractionCallback {
+            debug_name,
+            callback: cb,
+        };
+        self.write(|ctx| ctx.plugins.on_interaction.push(named_cb));
+    }
+
+    /// Report interactions with the widget of `response` to the callbacks registered with [`Self::on_interaction`].
+    pub(crate) fn report_interaction(
+        &self,
+        response: &Response,
+        make_info: impl Fn() -> crate::WidgetInfo,
+    ) {
+        let callbacks = self.read(|ctx| {
+            if ctx.plugins.on_interaction.is_empty() {
+                None
+            } else {
+                Some(ctx.plugins.on_interaction.clone())
+            }
+        });
+        if let Some(callbacks) = callbacks {
+            crate::interaction_events::report(&callbacks, response, make_info);
+        }
+    }
 }
 
 impl Context {
@@ -1776,6 +1978,69 @@ impl Context {
         self.read(|ctx| ctx.tex_manager.0.clone())
     }
 
+    /// Register an already tessellated [`Mesh`], so that it can be painted each frame
+    /// without tessellating it again.
+    ///
+    /// Paint it with [`epaint::MeshHandle::shape`], and keep the handle around for as long as you do.
+    /// The mesh is freed when the last clone of the handle is dropped.
+    ///
+    /// ⚠️ Like [`Self::load_texture`], only call this once, NOT every frame.
+    ///
+    /// See also [`Self::register_shape`].
+    pub fn register_mesh(&self, mesh: Mesh) -> epaint::MeshHandle {
+        let mesh_mngr = self.mesh_manager();
+        let id = mesh_mngr.write().alloc(mesh);
+        epaint::MeshHandle::new(mesh_mngr, id)
+    }
+
+    /// Tessellate the given shape once, and register the resulting mesh with [`Self::register_mesh`].
+    ///
+    /// Useful for complex static vector graphics, like a background made of thousands of paths.
+    ///
+    /// The tessellation uses the current [`Self::pixels_per_point`] and [`crate::Options::tessellation_options`],
+    /// so you need to register it again if those change.
+    ///
+    /// All parts of the shape must use the same texture, and it may not contain a [`Shape::Callback`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut background: Option<egui::epaint::MeshHandle> = None;
+    /// let background = background.get_or_insert_with(|| {
+    ///     ui.ctx().register_shape(egui::Shape::circle_filled(
+    ///         egui::Pos2::ZERO,
+    ///         50.0,
+    ///         egui::Color32::DARK_BLUE,
+    ///     ))
+    /// });
+    /// let transform = egui::emath::TSTransform::from_translation(ui.max_rect().center().to_vec2());
+    /// ui.painter().add(background.shape(transform));
+    /// # });
+    /// ```
+    pub fn register_shape(&self, shape: impl Into<Shape>) -> epaint::MeshHandle {
+        let pixels_per_point = self.pixels_per_point();
+        let tessellation_options = self.options(|o| o.tessellation_options);
+        let (font_tex_size, prepared_discs) = self.fonts(|fonts| {
+            let atlas = fonts.texture_atlas();
+            let atlas = atlas.lock();
+            (atlas.size(), atlas.prepared_discs())
+        });
+
+        let mut mesh = Mesh::default();
+        tessellator::Tessellator::new(
+            pixels_per_point,
+            tessellation_options,
+            font_tex_size,
+            prepared_discs,
+        )
+        .tessellate_shape(shape.into(), &mut mesh);
+        self.register_mesh(mesh)
+    }
+
+    /// Low-level manager of the meshes registered with [`Self::register_mesh`].
+    pub fn mesh_manager(&self) -> Arc<RwLock<epaint::MeshManager>> {
+        self.read(|ctx| ctx.mesh_manager.clone())
+    }
+
     // ---------------------------------------------------------------------
 
     /// Constrain the position of a window/area so it fits within the provided boundary.
@@ -1809,12 +2074,84 @@ impl Context {
 
         self.read(|ctx| ctx.plugins.clone()).on_end_frame(self);
 
+        self.loaders_end_frame();
+
+        if let Some(texture_budget) = self.options(|o| o.texture_budget) {
+            self.evict_textures_to_budget(texture_budget);
+        }
+
+        if let Some(timing_budget) = self.options(|o| o.timing_budget.filter(|_| o.measure_timings))
+        {
+            self.paint_timing_warnings(timing_budget);
+        }
+
         #[cfg(debug_assertions)]
         self.debug_painting();
 
         self.write(|ctx| ctx.end_frame())
     }
 
+    /// Outline the areas and panels that took longer than the budget this frame.
+    fn paint_timing_warnings(&self, budget: f32) {
+        let over_budget: Vec<crate::TimingEntry> = self.frame_state(|fs| {
+            fs.timings
+                .iter()
+                .filter(|e| budget < e.seconds)
+                .cloned()
+                .collect()
+        });
+        let painter = self.debug_painter();
+        for entry in over_budget {
+            let text = format!("{}: {:.1} ms", entry.name, 1e3 * entry.seconds);
+            painter.debug_rect(entry.rect, self.style().visuals.error_fg_color, text);
+        }
+    }
+
+    /// How long each area and panel took during the last complete frame, slowest first.
+    ///
+    /// Empty unless [`crate::Options::measure_timings`] is on.
+    /// Use [`Ui::measure_time`] to measure your own parts of the UI.
+    pub fn timings(&self) -> crate::Timings {
+        self.read(|ctx| {
+            ctx.viewports
+                .get(&ctx.viewport_id())
+                .map(|viewport| viewport.timings.clone())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Statistics about what was painted during the last complete frame of the current viewport.
+    ///
+    /// The tessellation statistics (vertex and index counts) are only filled in
+    /// once [`Self::tessellate`] has been called for that frame.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// let output = ctx.run(Default::default(), |ctx| {
+    ///     egui::CentralPanel::default().show(ctx, |ui| {
+    ///         ui.label("Hello");
+    ///     });
+    /// });
+    /// ctx.tessellate(output.shapes, output.pixels_per_point);
+    ///
+    /// let stats = ctx.frame_stats();
+    /// assert!(stats.shapes.texts >= 1);
+    /// assert!(stats.num_vertices < 10_000, "Over vertex budget");
+    /// ```
+    pub fn frame_stats(&self) -> crate::FrameStats {
+        self.frame_stats_for(self.viewport_id())
+    }
+
+    /// Like [`Self::frame_stats`], but for a specific viewport.
+    pub fn frame_stats_for(&self, id: ViewportId) -> crate::FrameStats {
+        self.read(|ctx| {
+            ctx.viewports
+                .get(&id)
+                .map(|viewport| viewport.frame_stats)
+                .unwrap_or_default()
+        })
+    }
+
     /// Called at the end of the frame.
     #[cfg(debug_assertions)]
     fn debug_painting(&self) {
@@ -1941,13 +2278,29 @@ impl ContextImpl {
 
         viewport.repaint.frame_nr += 1;
 
+        if viewport.close_state == CloseState::Confirmed
+            && viewport.input.viewport().close_requested()
+        {
+            // The confirmed close request has been seen by the app.
+            viewport.close_state = CloseState::Open;
+        }
+
+        viewport.timings =
+            crate::Timings::from_entries(std::mem::take(&mut viewport.frame_state.timings));
+        viewport.shortcuts = std::mem::take(&mut viewport.frame_state.shortcuts);
+
         self.memory.end_frame(&viewport.frame_state.used_ids);
 
         if let Some(fonts) = self.fonts.get(&pixels_per_point.into()) {
             let tex_mngr = &mut self.tex_manager.0.write();
             if let Some(font_image_delta) = fonts.font_image_delta() {
                 // A partial font atlas update, e.g. a new glyph has been entered.
-                tex_mngr.set(TextureId::default(), font_image_delta);
+                set_font_texture(
+                    tex_mngr,
+                    &mut self.subpixel_glyph_texture,
+                    fonts,
+                    font_image_delta,
+                );
             }
 
             if 1 < self.fonts.len() {
@@ -1965,12 +2318,22 @@ impl ContextImpl {
                 // (This will override any smaller delta that was uploaded above.)
                 crate::profile_scope!("full_font_atlas_update");
                 let full_delta = ImageDelta::full(fonts.image(), TextureAtlas::texture_options());
-                tex_mngr.set(TextureId::default(), full_delta);
+                set_font_texture(
+                    tex_mngr,
+                    &mut self.subpixel_glyph_texture,
+                    fonts,
+                    full_delta,
+                );
             }
         }
 
         // Inform the backend of all textures that have been updated (including font atlas).
-        let textures_delta = self.tex_manager.0.write().take_delta();
+        let (textures_delta, has_queued_uploads) = {
+            let mut tex_manager = self.tex_manager.0.write();
+            tex_manager.set_upload_budget(self.memory.options.texture_upload_budget);
+            let textures_delta = tex_manager.take_delta();
+            (textures_delta, tex_manager.has_queued_uploads())
+        };
 
         #[cfg_attr(not(feature = "accesskit"), allow(unused_mut))]
         let mut platform_output: PlatformOutput = std::mem::take(&mut viewport.output);
@@ -2005,9 +2368,37 @@ impl ContextImpl {
             }
         }
 
-        let shapes = viewport
+        let layers = viewport
             .graphics
-            .drain(self.memory.areas().order(), &self.memory.layer_transforms);
+            .drain_layers(self.memory.areas().order(), &self.memory.layer_transforms);
+        self.tessellation_cache.set_layers(
+            ended_viewport_id,
+            layers
+                .iter()
+                .map(|(layer_id, shapes)| (*layer_id, shapes.len()))
+                .collect(),
+        );
+        let num_layers = layers
+            .iter()
+            .filter(|(_, shapes)| !shapes.is_empty())
+            .count();
+        let shapes = layers
+            .into_iter()
+            .flat_map(|(_, shapes)| shapes)
+            .collect::<Vec<_>>();
+
+        viewport.frame_stats = {
+            crate::profile_scope!("frame_stats");
+            let mut frame_stats = crate::FrameStats {
+                frame_nr: viewport.repaint.frame_nr - 1,
+                num_layers,
+                num_areas: self.memory.areas().visible_layer_ids().len(),
+                shapes: crate::ShapeCounts::from_shapes(&shapes),
+                ..Default::default()
+            };
+            frame_stats.add_textures_delta(&textures_delta);
+            frame_stats
+        };
 
         let mut repaint_needed = false;
 
@@ -2026,7 +2417,7 @@ impl ContextImpl {
             viewport.widgets_this_frame.clear();
         }
 
-        if repaint_needed || viewport.input.wants_repaint() {
+        if repaint_needed || has_queued_uploads || viewport.input.wants_repaint() {
             self.request_repaint(ended_viewport_id, RepaintCause::new());
         }
 
@@ -2155,9 +2546,9 @@ impl Context {
     ) -> Vec<ClippedPrimitive> {
         crate::profile_function!();
 
-        // A tempting optimization is to reuse the tessellation from last frame if the
-        // shapes are the same, but just comparing the shapes takes about 50% of the time
-        // it takes to tessellate them, so it is not a worth optimization.
+        // Reusing the tessellation from last frame if the shapes are the same is opt-in
+        // (see `Options::cache_tessellation`), because just comparing the shapes takes about 50%
+        // of the time it takes to tessellate them, so it is only worth it when most layers are static.
 
         self.write(|ctx| {
             let tessellation_options = ctx.memory.options.tessellation_options;
@@ -2173,17 +2564,54 @@ impl Context {
             };
 
             let paint_stats = PaintStats::from_shapes(&shapes);
-            let clipped_primitives = {
+            let mut clipped_primitives = {
                 crate::profile_scope!("tessellator::tessellate_shapes");
-                tessellator::Tessellator::new(
+                let mut tessellator = tessellator::Tessellator::new(
                     pixels_per_point,
                     tessellation_options,
                     font_tex_size,
                     prepared_discs,
                 )
-                .tessellate_shapes(shapes)
+                .with_mesh_manager(ctx.mesh_manager.clone());
+                if let Some(glyph_texture) = ctx.subpixel_glyph_texture {
+                    tessellator = tessellator.with_subpixel_glyph_texture(glyph_texture);
+                }
+                if ctx.memory.options.cache_tessellation {
+                    ctx.tessellation_cache.tessellate(
+                        &mut tessellator,
+                        shapes,
+                        pixels_per_point,
+                        tessellation_options,
+                        font_tex_size,
+                        ctx.subpixel_glyph_texture,
+                    )
+                } else {
+                    tessellator.tessellate_shapes(shapes)
+                }
             };
+            if let Some(cvd) = ctx.memory.options.color_vision_simulation {
+                crate::profile_scope!("color_vision_simulation");
+                for clipped_primitive in &mut clipped_primitives {
+                    if let epaint::Primitive::PrecomputedMesh(primitive) =
+                        &clipped_primitive.primitive
+                    {
+                        // Simulate on a copy, leaving the registered mesh alone:
+                        let mut mesh = Mesh::clone(&primitive.mesh);
+                        mesh.transform(primitive.transform);
+                        clipped_primitive.primitive = epaint::Primitive::Mesh(mesh);
+                    }
+                    if let epaint::Primitive::Mesh(mesh) = &mut clipped_primitive.primitive {
+                        for vertex in &mut mesh.vertices {
+                            vertex.color = cvd.simulate(vertex.color);
+                        }
+                    }
+                }
+            }
             ctx.paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
+            let last_viewport = ctx.last_viewport;
+            if let Some(viewport) = ctx.viewports.get_mut(&last_viewport) {
+                viewport.frame_stats.set_clipped_primitives(&clipped_primitives);
+            }
             clipped_primitives
         })
     }
@@ -2325,11 +2753,14 @@ impl Context {
     ///
     /// For a temporary transform, use [`Self::transform_layer_shapes`] instead.
     pub fn set_transform_layer(&self, layer_id: LayerId, transform: TSTransform) {
-        self.memory_mut(|m| {
-            if transform == TSTransform::IDENTITY {
-                m.layer_transforms.remove(&layer_id)
+        self.write(|ctx| {
+            let previous = if transform == TSTransform::IDENTITY {
+                ctx.memory.layer_transforms.remove(&layer_id)
             } else {
-                m.layer_transforms.insert(layer_id, transform)
+                ctx.memory.layer_transforms.insert(layer_id, transform)
+            };
+            if previous.unwrap_or(TSTransform::IDENTITY) != transform {
+                ctx.tessellation_cache.invalidate_layer(layer_id);
             }
         });
     }
@@ -2525,12 +2956,61 @@ impl Context {
     /// When it is called with a new value, it linearly interpolates to it in the given time.
     #[track_caller] // To track repaint cause
     pub fn animate_value_with_time(&self, id: Id, target_value: f32, animation_time: f32) -> f32 {
+        self.animate_value_with_time_and_easing(
+            id,
+            target_value,
+            animation_time,
+            emath::easing::linear,
+        )
+    }
+
+    /// Like [`Self::animate_value_with_time`], but with the animation time taken from
+    /// [`Style::animation_time`] and the given easing function.
+    ///
+    /// See [`Self::animate_value_with_time_and_easing`].
+    #[track_caller] // To track repaint cause
+    pub fn animate_value_with_easing(
+        &self,
+        id: Id,
+        target_value: f32,
+        easing: impl Fn(f32) -> f32,
+    ) -> f32 {
+        let animation_time = self.style().animation_time;
+        self.animate_value_with_time_and_easing(id, target_value, animation_time, easing)
+    }
+
+    /// Smoothly animate an `f32` value, following the given easing curve.
+    ///
+    /// The easing function maps the normalized time `[0, 1]` to the normalized progress `[0, 1]`.
+    /// Use any of the functions in [`emath::easing`], or a custom curve such as
+    /// [`emath::easing::CubicBezierEasing`]:
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # let id = egui::Id::new("my_value");
+    /// use egui::emath::easing::{self, CubicBezierEasing};
+    /// let value = ctx.animate_value_with_time_and_easing(id, 100.0, 0.5, easing::elastic_out);
+    /// let value = ctx.animate_value_with_time_and_easing(id, 100.0, 0.5, |t| {
+    ///     CubicBezierEasing::EASE.ease(t)
+    /// });
+    /// ```
+    ///
+    /// If the target value changes during an animation, a new animation starts from the current value.
+    #[track_caller] // To track repaint cause
+    pub fn animate_value_with_time_and_easing(
+        &self,
+        id: Id,
+        target_value: f32,
+        animation_time: f32,
+        easing: impl Fn(f32) -> f32,
+    ) -> f32 {
         let animated_value = self.write(|ctx| {
             ctx.animation_manager.animate_value(
                 &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
                 animation_time,
                 id,
                 target_value,
+                easing,
             )
         });
         let animation_in_progress = animated_value != target_value;
@@ -2636,6 +3116,58 @@ impl Context {
                 self.texture_ui(ui);
             });
 
+        CollapsingHeader::new("📂 Loaders")
+            .default_open(false)
+            .show(ui, |ui| {
+                let stats = self.loaders().stats();
+                ui.label(format!("Bytes: {:.1} MB", stats.bytes as f64 * 1e-6));
+                ui.label(format!("Images: {:.1} MB", stats.images as f64 * 1e-6));
+                ui.label(format!("Textures: {:.1} MB", stats.textures as f64 * 1e-6));
+                if let Some(texture_budget) = self.options(|o| o.texture_budget) {
+                    ui.label(format!(
+                        "Texture budget: {:.1} MB",
+                        texture_budget as f64 * 1e-6
+                    ));
+                }
+                if ui.button("Forget all images").clicked() {
+                    self.forget_all_images();
+                }
+            });
+
+        CollapsingHeader::new("⏱ Timings")
+            .default_open(false)
+            .show(ui, |ui| {
+                if !self.options(|o| o.measure_timings) {
+                    ui.label("Turn on 'Measure timings' in the options to see how long each area and panel takes.");
+                    return;
+                }
+                let budget = self.options(|o| o.timing_budget);
+                let timings = self.timings();
+                crate::Grid::new("timings").striped(true).show(ui, |ui| {
+                    for entry in timings.worst(20) {
+                        let text = format!("{:.2} ms", 1e3 * entry.seconds);
+                        if budget.map_or(false, |budget| budget < entry.seconds) {
+                            ui.colored_label(ui.visuals().error_fg_color, text);
+                        } else {
+                            ui.label(text);
+                        }
+                        let response = ui.label(&entry.name);
+                        if response.hovered() && entry.rect.is_positive() {
+                            ui.ctx()
+                                .debug_painter()
+                                .debug_rect(entry.rect, Color32::RED, &entry.name);
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+
+        CollapsingHeader::new("📊 Frame statistics")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.frame_stats().ui(ui);
+            });
+
         CollapsingHeader::new("🔠 Font texture")
             .default_open(false)
             .show(ui, |ui| {
@@ -2987,6 +3519,57 @@ impl Context {
         }
     }
 
+    /// Let the image loaders clean up their caches at the end of the frame.
+    fn loaders_end_frame(&self) {
+        use load::BytesLoader as _;
+
+        crate::profile_function!();
+
+        let frame_nr = self.frame_nr() as usize;
+        let loaders = self.loaders();
+
+        loaders.include.end_frame(frame_nr);
+        for loader in loaders.bytes.lock().iter() {
+            loader.end_frame(frame_nr);
+        }
+        for loader in loaders.image.lock().iter() {
+            loader.end_frame(frame_nr);
+        }
+        for loader in loaders.texture.lock().iter() {
+            loader.end_frame(frame_nr);
+        }
+    }
+
+    /// Evict the least recently used image textures until the texture loaders use at most `max_bytes`.
+    ///
+    /// This is called at the end of each frame if [`crate::Options::texture_budget`] is set.
+    /// Evicted images are forgotten by all loaders, so they will be reloaded from scratch if shown again.
+    pub fn evict_textures_to_budget(&self, max_bytes: usize) {
+        use load::BytesLoader as _;
+
+        crate::profile_function!();
+
+        let frame_nr = self.frame_nr() as usize;
+        let loaders = self.loaders();
+
+        let evicted: Vec<String> = loaders
+            .texture
+            .lock()
+            .iter()
+            .flat_map(|loader| loader.evict_to_budget(max_bytes, frame_nr))
+            .collect();
+
+        for uri in &evicted {
+            loaders.include.forget(uri);
+            for loader in loaders.bytes.lock().iter() {
+                loader.forget(uri);
+            }
+            for loader in loaders.image.lock().iter() {
+                loader.forget(uri);
+            }
+        }
+    }
+
     /// Release all memory and textures related to images used in [`Ui::image`] or [`Image`].
     ///
     /// If you attempt to load any images again, they will be reloaded from scratch.
@@ -3131,6 +3714,168 @@ impl Context {
     }
 }
 
+/// ## Icons
+#[cfg(feature = "icons")]
+impl Context {
+    /// The icons available to [`crate::Icon`].
+    ///
+    /// Use [`crate::IconRegistry::insert`] to add your own.
+    pub fn icons(&self) -> Arc<crate::IconRegistry> {
+        self.read(|this| this.icons.clone())
+    }
+}
+
+/// ## Async tasks
+impl Context {
+    /// Set the executor used by [`Self::spawn`].
+    ///
+    /// `eframe` sets this to `wasm_bindgen_futures::spawn_local` on the web.
+    /// On native, tasks run on their own thread by default,
+    /// which is fine for plain futures, but not for ones that need a runtime.
+    /// If you use `tokio`, do this once at startup (inside the runtime):
+    ///
+    /// ```ignore
+    /// let handle = tokio::runtime::Handle::current();
+    /// ctx.set_spawner(move |future| {
+    ///     handle.spawn(future);
+    /// });
+    /// ```
+    pub fn set_spawner(&self, spawner: impl Fn(crate::task::BoxFuture) + Send + Sync + 'static) {
+        self.write(|ctx| ctx.spawner = Some(Arc::new(spawner)));
+    }
+
+    /// Run a future in the background, and get a handle to its result.
+    ///
+    /// When the future completes, a repaint is requested,
+    /// so you can check [`crate::TaskHandle::ready`] each frame without busy-repainting.
+    ///
+    /// See [`Self::set_spawner`] for how the future is run.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let handle = ui.ctx().spawn(async { 6 * 7 });
+    /// // Later, e.g. in the next frame:
+    /// match handle.ready() {
+    ///     Some(answer) => ui.label(format!("The answer is {answer}")),
+    ///     None => ui.spinner(),
+    /// };
+    /// # });
+    /// ```
+    pub fn spawn<T>(
+        &self,
+        future: impl std::future::Future<Output = T> + crate::task::MaybeSend + 'static,
+    ) -> crate::TaskHandle<T>
+    where
+        T: crate::task::MaybeSend + Sync + 'static,
+    {
+        let (handle, result) = crate::TaskHandle::new();
+        let ctx = self.clone();
+        let future: crate::task::BoxFuture = Box::pin(async move {
+            let value = future.await;
+            result.set(value).ok();
+            ctx.request_repaint();
+        });
+
+        match self.read(|ctx| ctx.spawner.clone()) {
+            Some(spawner) => spawner(future),
+
+            #[cfg(not(target_arch = "wasm32"))]
+            None => crate::task::spawn_on_thread(future),
+
+            #[cfg(target_arch = "wasm32")]
+            None => panic!(
+                "No spawner set. Call `Context::set_spawner` with e.g. `wasm_bindgen_futures::spawn_local`"
+            ),
+        }
+
+        handle
+    }
+
+    /// Can [`Self::spawn`] run futures?
+    ///
+    /// Only `false` on the web, when no spawner has been set.
+    pub(crate) fn can_spawn(&self) -> bool {
+        cfg!(not(target_arch = "wasm32")) || self.read(|ctx| ctx.spawner.is_some())
+    }
+}
+
+/// ## Screen color picking
+impl Context {
+    /// Set how to pick a color anywhere on the screen, e.g. with a native eyedropper.
+    ///
+    /// The picker is given a callback to call with the picked color,
+    /// or with `None` if the user cancelled.
+    /// It may call it right away, or later from any thread.
+    ///
+    /// `eframe` sets this on the platforms where it is supported, with its `eyedropper` feature.
+    /// When set, the color picker popups show an eyedropper button.
+    pub fn set_screen_color_picker(
+        &self,
+        picker: impl Fn(Box<dyn FnOnce(Option<Color32>) + Send>) + Send + Sync + 'static,
+    ) {
+        self.write(|ctx| ctx.screen_color_picker = Some(Arc::new(picker)));
+    }
+
+    /// Has a picker been set with [`Self::set_screen_color_picker`]?
+    pub fn can_pick_screen_color(&self) -> bool {
+        self.read(|ctx| ctx.screen_color_picker.is_some())
+    }
+
+    /// Let the user pick a color anywhere on the screen.
+    ///
+    /// The result is `None` if the user cancelled.
+    /// A repaint is requested when the user has picked a color,
+    /// so you can check [`crate::TaskHandle::ready`] each frame.
+    ///
+    /// Returns `None` if no picker has been set with [`Self::set_screen_color_picker`].
+    pub fn pick_screen_color(&self) -> Option<crate::TaskHandle<Option<Color32>>> {
+        let picker = self.read(|ctx| ctx.screen_color_picker.clone())?;
+        let (handle, result) = crate::TaskHandle::new();
+        let ctx = self.clone();
+        picker(Box::new(move |color| {
+            result.set(color).ok();
+            ctx.request_repaint();
+        }));
+        Some(handle)
+    }
+}
+
+/// ## Dialogs
+impl Context {
+    /// Show a modal [`crate::Dialog`], and get a handle to its result.
+    ///
+    /// Only one dialog is shown at a time.
+    /// If a dialog is already open, this one is queued and shown when the others are closed.
+    ///
+    /// When the dialog is closed, a repaint is requested,
+    /// so you can check [`crate::DialogHandle::ready`] each frame.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut handle: Option<egui::DialogHandle<String>> = None;
+    /// if ui.button("Rename…").clicked() {
+    ///     handle = Some(ui.ctx().open_dialog(egui::Dialog::prompt("Rename", "New name:", "untitled")));
+    /// }
+    /// if let Some(egui::DialogResult::Confirmed(name)) = handle.as_ref().and_then(|h| h.ready()) {
+    ///     ui.label(format!("Renamed to {name}"));
+    /// }
+    /// # });
+    /// ```
+    pub fn open_dialog<T: Send + Sync + 'static>(
+        &self,
+        dialog: crate::Dialog<T>,
+    ) -> crate::DialogHandle<T> {
+        crate::dialog::open(self, dialog)
+    }
+
+    /// Is a dialog open (or waiting to be shown)?
+    ///
+    /// See [`Self::open_dialog`].
+    pub fn is_dialog_open(&self) -> bool {
+        crate::dialog::is_open(self)
+    }
+}
+
 /// ## Viewports
 impl Context {
     /// Return the `ViewportId` of the current viewport.
@@ -3223,6 +3968,135 @@ impl Context {
         self.write(|ctx| ctx.viewport_for(id).commands.push(command));
     }
 
+    /// Postpone closing the current viewport, e.g. to ask the user whether to save their changes first.
+    ///
+    /// Call this when [`crate::ViewportInfo::close_requested`] is set.
+    /// This cancels the close (see [`ViewportCommand::CancelClose`]),
+    /// and [`Self::is_close_deferred`] will return `true` until you call either
+    /// [`Self::confirm_close`] or [`Self::abort_close`].
+    /// That can be many frames later, e.g. after an async save has finished.
+    ///
+    /// After [`Self::confirm_close`] the viewport is asked to close again,
+    /// and then this does nothing and returns `false`, so the close goes through.
+    ///
+    /// Returns `true` if the close was deferred.
+    ///
+    /// When the user logs out or shuts down the computer, the OS only waits for apps that
+    /// have said so beforehand, see [`Self::set_session_end_blocker`].
+    /// Other closes can't be deferred, e.g. when the user quits a macOS app with Cmd-Q.
+    /// Save your state in `eframe::App::save` to handle those.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # let has_unsaved_changes = true;
+    /// # let save_finished = false;
+    /// if ctx.input(|i| i.viewport().close_requested()) && has_unsaved_changes {
+    ///     ctx.defer_close();
+    /// }
+    ///
+    /// if ctx.is_close_deferred() {
+    ///     egui::Window::new("Save changes?").show(&ctx, |ui| {
+    ///         ui.horizontal(|ui| {
+    ///             if ui.button("Save").clicked() {
+    ///                 // start saving in the background, and call `confirm_close` when done
+    ///             }
+    ///             if ui.button("Don't save").clicked() {
+    ///                 ui.ctx().confirm_close();
+    ///             }
+    ///             if ui.button("Cancel").clicked() {
+    ///                 ui.ctx().abort_close();
+    ///             }
+    ///         });
+    ///     });
+    ///     if save_finished {
+    ///         ctx.confirm_close();
+    ///     }
+    /// }
+    /// ```
+    pub fn defer_close(&self) -> bool {
+        let id = self.viewport_id();
+        let deferred = self.write(|ctx| {
+            let viewport = ctx.viewport_for(id);
+            if viewport.close_state == CloseState::Confirmed {
+                false
+            } else {
+                viewport.close_state = CloseState::Deferred;
+                true
+            }
+        });
+        if deferred {
+            self.send_viewport_cmd_to(id, ViewportCommand::CancelClose);
+        }
+        deferred
+    }
+
+    /// Is the current viewport waiting for [`Self::confirm_close`] or [`Self::abort_close`]?
+    ///
+    /// See [`Self::defer_close`].
+    pub fn is_close_deferred(&self) -> bool {
+        self.is_close_deferred_of(self.viewport_id())
+    }
+
+    /// Is the given viewport waiting for [`Self::confirm_close_of`] or [`Self::abort_close_of`]?
+    pub fn is_close_deferred_of(&self, id: ViewportId) -> bool {
+        self.read(|ctx| {
+            ctx.viewports.get(&id).map_or(false, |viewport| {
+                viewport.close_state == CloseState::Deferred
+            })
+        })
+    }
+
+    /// Go ahead with closing the current viewport, after [`Self::defer_close`].
+    ///
+    /// See [`Self::defer_close`].
+    pub fn confirm_close(&self) {
+        self.confirm_close_of(self.viewport_id());
+    }
+
+    /// Go ahead with closing the given viewport, after [`Self::defer_close`].
+    ///
+    /// Can be called from any thread, e.g. when a background save has finished.
+    pub fn confirm_close_of(&self, id: ViewportId) {
+        self.write(|ctx| ctx.viewport_for(id).close_state = CloseState::Confirmed);
+        self.send_viewport_cmd_to(id, ViewportCommand::Close);
+    }
+
+    /// Keep the current viewport open after all, after [`Self::defer_close`].
+    pub fn abort_close(&self) {
+        self.abort_close_of(self.viewport_id());
+    }
+
+    /// Keep the given viewport open after all, after [`Self::defer_close`].
+    pub fn abort_close_of(&self, id: ViewportId) {
+        self.write(|ctx| ctx.viewport_for(id).close_state = CloseState::Open);
+        self.request_repaint_of(id);
+    }
+
+    /// Ask the OS to wait when the user logs out or shuts down the computer, e.g. while there are unsaved changes.
+    ///
+    /// While this is `Some`, logging out or shutting down sends the root viewport
+    /// a normal close request instead, which you can [`Self::defer_close`].
+    /// Until you call [`Self::confirm_close`] or [`Self::abort_close`],
+    /// the OS shows the given reason and lets the user choose whether to wait for the app.
+    ///
+    /// Set it back to `None` once there is nothing to lose, so that the OS doesn't have to ask.
+    ///
+    /// This is up to the integration. `eframe` supports it on Windows, with its `session_end_blocker` feature.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # let has_unsaved_changes = true;
+    /// ctx.set_session_end_blocker(has_unsaved_changes.then(|| "Unsaved changes".to_owned()));
+    /// ```
+    pub fn set_session_end_blocker(&self, reason: Option<String>) {
+        self.write(|ctx| ctx.session_end_blocker = reason);
+    }
+
+    /// See [`Self::set_session_end_blocker`].
+    pub fn session_end_blocker(&self) -> Option<String> {
+        self.read(|ctx| ctx.session_end_blocker.clone())
+    }
+
     /// Show a deferred viewport, creating a new native window, if possible.
     ///
     /// The given id must be unique for each viewport.
@@ -3428,6 +4302,29 @@ impl Context {
         });
     }
 
+    /// Capture the pointer for the widget with the given id, until all pointer buttons are released.
+    ///
+    /// While captured, only this widget is hovered, and it keeps getting the pointer position
+    /// even when the pointer leaves the widget or the window.
+    /// Integrations keep tracking the pointer outside the window where the platform allows it
+    /// (`eframe` does on the web, and on native while a button is held).
+    ///
+    /// This is usually done when a drag starts, see [`crate::Response::capture_pointer`].
+    /// If no pointer button is down, the capture is released the next frame.
+    pub fn capture_pointer(&self, id: Id) {
+        self.memory_mut(|mem| mem.interaction_mut().pointer_capture = Some(id));
+    }
+
+    /// Release the pointer capture early, see [`Self::capture_pointer`].
+    pub fn release_pointer_capture(&self) {
+        self.memory_mut(|mem| mem.interaction_mut().pointer_capture = None);
+    }
+
+    /// The widget that has captured the pointer, if any. See [`Self::capture_pointer`].
+    pub fn pointer_capture_id(&self) -> Option<Id> {
+        self.memory(|mem| mem.interaction().pointer_capture)
+    }
+
     /// Is something else being dragged?
     ///
     /// Returns true if we are dragging something, but not the given widget.
@@ -3443,3 +4340,143 @@ fn context_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Context>();
 }
+
+#[cfg(test)]
+fn close_requested_input() -> RawInput {
+    let mut raw_input = RawInput::default();
+    raw_input
+        .viewports
+        .entry(ViewportId::ROOT)
+        .or_default()
+        .events
+        .push(crate::ViewportEvent::Close);
+    raw_input
+}
+
+#[test]
+fn defer_close_then_confirm() {
+    let ctx = Context::default();
+
+    let output = ctx.run(close_requested_input(), |ctx| {
+        assert!(ctx.defer_close());
+    });
+    assert!(ctx.is_close_deferred());
+    let commands = &output.viewport_output[&ViewportId::ROOT].commands;
+    assert!(commands.contains(&ViewportCommand::CancelClose));
+
+    // Stays deferred until the app decides, e.g. after an async save:
+    let output = ctx.run(RawInput::default(), |_| {});
+    assert!(ctx.is_close_deferred());
+    assert!(output.viewport_output[&ViewportId::ROOT]
+        .commands
+        .is_empty());
+
+    ctx.confirm_close();
+    assert!(!ctx.is_close_deferred());
+    let output = ctx.run(RawInput::default(), |_| {});
+    let commands = &output.viewport_output[&ViewportId::ROOT].commands;
+    assert!(commands.contains(&ViewportCommand::Close));
+
+    // The close request that follows goes through:
+    let output = ctx.run(close_requested_input(), |ctx| {
+        assert!(!ctx.defer_close());
+    });
+    let commands = &output.viewport_output[&ViewportId::ROOT].commands;
+    assert!(!commands.contains(&ViewportCommand::CancelClose));
+
+    // If the viewport stays open anyway, later close requests can be deferred again:
+    let _ = ctx.run(close_requested_input(), |ctx| {
+        assert!(ctx.defer_close());
+    });
+    assert!(ctx.is_close_deferred());
+}
+
+#[test]
+fn defer_close_then_abort() {
+    let ctx = Context::default();
+
+    let _ = ctx.run(close_requested_input(), |ctx| {
+        assert!(ctx.defer_close());
+    });
+    assert!(ctx.is_close_deferred());
+
+    let _ = ctx.run(RawInput::default(), |ctx| ctx.abort_close());
+    assert!(!ctx.is_close_deferred());
+    let output = ctx.run(RawInput::default(), |_| {});
+    let commands = &output.viewport_output[&ViewportId::ROOT].commands;
+    assert!(!commands.contains(&ViewportCommand::Close));
+
+    let _ = ctx.run(close_requested_input(), |ctx| {
+        assert!(ctx.defer_close());
+    });
+    assert!(ctx.is_close_deferred());
+}
+
+#[test]
+fn subpixel_glyphs_get_a_texture_of_their_own() {
+    use epaint::text::{GlyphRasterization, SubpixelOrder};
+
+    let is_subpixel = |delta: &ImageDelta| match &delta.image {
+        ImageData::Font(image) => image.is_subpixel(),
+        ImageData::Color(_) => false,
+    };
+
+    let ctx = Context::default();
+    ctx.options_mut(|o| {
+        o.glyph_rasterization = GlyphRasterization::Subpixel(SubpixelOrder::Rgb);
+    });
+    let output = ctx.run(RawInput::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| ui.label("Hello"));
+    });
+
+    let (glyph_texture, glyph_delta) = output
+        .textures_delta
+        .set
+        .iter()
+        .find(|(id, _)| *id != TextureId::default())
+        .expect("the glyphs should be uploaded to a texture of their own");
+    assert!(is_subpixel(glyph_delta));
+    assert!(output
+        .textures_delta
+        .set
+        .iter()
+        .filter(|(id, _)| *id == TextureId::default())
+        .all(|(_, delta)| !is_subpixel(delta)));
+
+    let primitives = ctx.tessellate(output.shapes, output.pixels_per_point);
+    assert!(primitives.iter().any(|primitive| matches!(
+        &primitive.primitive,
+        epaint::Primitive::Mesh(mesh) if mesh.texture_id == *glyph_texture
+    )));
+
+    // Turning it off frees the texture:
+    let glyph_texture = *glyph_texture;
+    ctx.options_mut(|o| o.glyph_rasterization = GlyphRasterization::Coverage);
+    let output = ctx.run(RawInput::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| ui.label("Hello"));
+    });
+    assert!(output.textures_delta.free.contains(&glyph_texture));
+}
+
+#[test]
+fn immediate_viewport_first_frame() {
+    Context::set_immediate_viewport_renderer(|ctx, viewport| {
+        let input = RawInput {
+            viewport_id: viewport.ids.this,
+            viewports: std::iter::once((viewport.ids.this, Default::default())).collect(),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, viewport.viewport_ui_cb);
+    });
+
+    let ctx = Context::default();
+    ctx.set_embed_viewports(false);
+    let mut shown = false;
+    let _ = ctx.run(RawInput::default(), |ctx| {
+        let id = ViewportId::from_hash_of("immediate");
+        shown = ctx.show_viewport_immediate(id, ViewportBuilder::default(), |ctx, class| {
+            ctx.viewport_id() == id && class == ViewportClass::Immediate
+        });
+    });
+    assert!(shown);
+}
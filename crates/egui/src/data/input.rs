@@ -195,6 +195,14 @@ pub struct ViewportInfo {
     /// Current monitor size in egui points.
     pub monitor_size: Option<Vec2>,
 
+    /// Identifies the monitor the viewport is on, if known.
+    ///
+    /// Identical monitors often have the same name, so this should also include
+    /// something that tells them apart, like their position.
+    ///
+    /// Used to remember the zoom factor of each monitor, see [`crate::Options::zoom_per_monitor`].
+    pub monitor_id: Option<String>,
+
     /// The inner rectangle of the native window, in monitor space and ui points scale.
     ///
     /// This is the content rectangle of the viewport.
@@ -235,6 +243,14 @@ impl ViewportInfo {
             .any(|&event| event == ViewportEvent::Close)
     }
 
+    /// The size of [`Self::inner_rect`] in physical pixels, if known.
+    ///
+    /// `pixels_per_point` is usually [`crate::Context::pixels_per_point`].
+    pub fn inner_size_in_pixels(&self, pixels_per_point: f32) -> Option<IVec2> {
+        self.inner_rect
+            .map(|rect| IVec2::from_points(rect.size(), pixels_per_point))
+    }
+
     pub fn ui(&self, ui: &mut crate::Ui) {
         let Self {
             parent,
@@ -242,6 +258,7 @@ impl ViewportInfo {
             events,
             native_pixels_per_point,
             monitor_size,
+            monitor_id,
             inner_rect,
             outer_rect,
             minimized,
@@ -271,6 +288,10 @@ impl ViewportInfo {
             ui.label(opt_as_str(monitor_size));
             ui.end_row();
 
+            ui.label("Monitor id:");
+            ui.label(opt_as_str(monitor_id));
+            ui.end_row();
+
             ui.label("Inner rect:");
             ui.label(opt_rect_as_string(inner_rect));
             ui.end_row();
@@ -0,0 +1,506 @@
+//! Modal dialogs, see [`crate::Context::open_dialog`].
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, OnceLock},
+};
+
+use crate::{mutex::Mutex, *};
+
+/// How a [`Dialog`] was closed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DialogResult<T> {
+    /// The user clicked the confirm button or pressed enter.
+    /// Contains the value of the dialog, e.g. the text of [`Dialog::prompt`].
+    Confirmed(T),
+
+    /// The user clicked the cancel button or pressed escape.
+    Cancelled,
+}
+
+impl<T> DialogResult<T> {
+    /// Was the dialog confirmed?
+    #[inline]
+    pub fn is_confirmed(&self) -> bool {
+        matches!(self, Self::Confirmed(_))
+    }
+
+    /// The value, if the dialog was confirmed.
+    #[inline]
+    pub fn confirmed(self) -> Option<T> {
+        match self {
+            Self::Confirmed(value) => Some(value),
+            Self::Cancelled => None,
+        }
+    }
+}
+
+/// The result of a dialog opened with [`crate::Context::open_dialog`].
+///
+/// Check it each frame with [`Self::ready`] or [`Self::try_take`].
+pub struct DialogHandle<T> {
+    result: Arc<OnceLock<DialogResult<T>>>,
+}
+
+impl<T> DialogHandle<T> {
+    /// Has the dialog been closed?
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.result.get().is_some()
+    }
+
+    /// The result of the dialog, if it has been closed.
+    #[inline]
+    pub fn ready(&self) -> Option<&DialogResult<T>> {
+        self.result.get()
+    }
+
+    /// Take the result of the dialog if it has been closed.
+    ///
+    /// # Errors
+    /// Gives back the handle if the dialog is still open (or waiting to be shown).
+    pub fn try_take(self) -> Result<DialogResult<T>, Self> {
+        if !self.is_ready() {
+            return Err(self);
+        }
+        match Arc::try_unwrap(self.result) {
+            Ok(result) => Ok(result.into_inner().expect("checked by is_ready")),
+            Err(result) => Err(Self { result }),
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for DialogHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DialogHandle")
+            .field("result", &self.result.get())
+            .finish()
+    }
+}
+
+/// Type of the function used by [`Dialog::new`].
+type AddContents<T> = Box<dyn FnMut(&mut Ui, &mut T) + Send>;
+
+/// A modal dialog, shown with [`crate::Context::open_dialog`].
+///
+/// It is shown in the viewport it was opened in.
+/// While it is open, the rest of the UI is dimmed and can't be clicked,
+/// and the keyboard focus stays in the dialog.
+/// Enter confirms the dialog (unless a text edit in it has the focus), and escape cancels it.
+///
+/// The value of type `T` can be edited by the contents of the dialog,
+/// and is given back in [`DialogResult::Confirmed`].
+#[must_use = "You should call ctx.open_dialog(dialog)"]
+pub struct Dialog<T> {
+    title: WidgetText,
+    value: T,
+    add_contents: AddContents<T>,
+    confirm_text: WidgetText,
+    cancel_text: Option<WidgetText>,
+}
+
+impl<T> Dialog<T> {
+    /// A dialog with custom contents, which can edit `value`.
+    pub fn new(
+        title: impl Into<WidgetText>,
+        value: T,
+        add_contents: impl FnMut(&mut Ui, &mut T) + Send + 'static,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            value,
+            add_contents: Box::new(add_contents),
+            confirm_text: "OK".into(),
+            cancel_text: Some("Cancel".into()),
+        }
+    }
+
+    /// The text of the confirm button. Default: "OK".
+    #[inline]
+    pub fn confirm_text(mut self, confirm_text: impl Into<WidgetText>) -> Self {
+        self.confirm_text = confirm_text.into();
+        self
+    }
+
+    /// The text of the cancel button. Default: "Cancel".
+    ///
+    /// With `None` there is no cancel button, but escape still cancels the dialog.
+    #[inline]
+    pub fn cancel_text(mut self, cancel_text: Option<impl Into<WidgetText>>) -> Self {
+        self.cancel_text = cancel_text.map(Into::into);
+        self
+    }
+}
+
+impl Dialog<()> {
+    /// Ask the user to confirm something.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// if ui.button("Delete").clicked() {
+    ///     let handle = ui.ctx().open_dialog(
+    ///         egui::Dialog::confirm("Delete file", "Are you sure?").confirm_text("Delete"),
+    ///     );
+    ///     // Store the handle, and check `handle.ready()` in later frames.
+    /// }
+    /// # });
+    /// ```
+    pub fn confirm(title: impl Into<WidgetText>, message: impl Into<WidgetText>) -> Self {
+        let message = message.into();
+        Self::new(title, (), move |ui, ()| {
+            ui.label(message.clone());
+        })
+    }
+}
+
+impl Dialog<String> {
+    /// Ask the user to enter a line of text, starting with `text`.
+    pub fn prompt(
+        title: impl Into<WidgetText>,
+        message: impl Into<WidgetText>,
+        text: impl Into<String>,
+    ) -> Self {
+        let message = message.into();
+        let mut is_first_frame = true;
+        Self::new(title, text.into(), move |ui, text| {
+            ui.label(message.clone());
+            let response = ui.text_edit_singleline(text);
+            if std::mem::take(&mut is_first_frame) {
+                response.request_focus();
+            }
+        })
+    }
+}
+
+/// A [`Dialog`] in the queue, with the type of its value erased.
+trait QueuedDialog: Send {
+    /// The viewport the dialog was opened in, which it is shown in.
+    fn viewport_id(&self) -> ViewportId;
+
+    /// Show the dialog in the root viewport instead, e.g. because its own viewport was closed.
+    fn move_to_root(&mut self);
+
+    /// Show the dialog. Returns the result once it is closed.
+    fn show(&mut self, ctx: &Context) -> Option<bool>;
+
+    /// Give the result to the [`DialogHandle`].
+    fn finish(self: Box<Self>, confirmed: bool);
+}
+
+struct DialogEntry<T> {
+    id: Id,
+    viewport_id: ViewportId,
+    dialog: Dialog<T>,
+    result: Arc<OnceLock<DialogResult<T>>>,
+    is_first_frame: bool,
+}
+
+impl<T: Send + Sync + 'static> QueuedDialog for DialogEntry<T> {
+    fn viewport_id(&self) -> ViewportId {
+        self.viewport_id
+    }
+
+    fn move_to_root(&mut self) {
+        self.viewport_id = ViewportId::ROOT;
+    }
+
+    fn show(&mut self, ctx: &Context) -> Option<bool> {
+        let Self {
+            id,
+            viewport_id: _,
+            dialog,
+            result: _,
+            is_first_frame,
+        } = self;
+        let is_first_frame = std::mem::take(is_first_frame);
+
+        // Dim everything behind the dialog, and block clicks on it:
+        let screen_rect = ctx.screen_rect();
+        Area::new(id.with("backdrop"))
+            .order(Order::Foreground)
+            .fixed_pos(screen_rect.min)
+            .show(ctx, |ui| {
+                ui.painter()
+                    .rect_filled(screen_rect, 0.0, ctx.style().visuals.window_shadow.color);
+                ui.allocate_rect(screen_rect, Sense::click_and_drag());
+            });
+
+        let mut closed = None;
+        let window = Window::new(dialog.title.clone())
+            .id(*id)
+            .order(Order::Foreground)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+            .show(ctx, |ui| {
+                (dialog.add_contents)(ui, &mut dialog.value);
+                ui.add_space(ui.spacing().item_spacing.y);
+
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    let confirm = ui.button(dialog.confirm_text.clone());
+                    if confirm.clicked() {
+                        closed = Some(true);
+                    }
+                    if let Some(cancel_text) = &dialog.cancel_text {
+                        if ui.button(cancel_text.clone()).clicked() {
+                            closed = Some(false);
+                        }
+                    }
+                    confirm.id
+                })
+                .inner
+            });
+
+        let Some(window) = window else {
+            return closed;
+        };
+        let layer_id = window.response.layer_id;
+        ctx.move_to_top(layer_id);
+
+        // Keep the keyboard focus inside the dialog:
+        if let Some(confirm_id) = window.inner {
+            let focused = ctx.memory(|mem| mem.focused());
+            let is_focus_inside = focused
+                .and_then(|focused| ctx.read_response(focused))
+                .map_or(false, |response| response.layer_id == layer_id);
+            if !is_focus_inside && (is_first_frame || focused.is_some()) {
+                ctx.memory_mut(|mem| mem.request_focus(confirm_id));
+            }
+        }
+
+        // A text edit that still has the focus at this point uses enter itself, e.g. for a new line.
+        // A single line text edit gives up the focus when enter is pressed.
+        let is_editing_text = ctx.memory(|mem| mem.focused()).map_or(false, |focused| {
+            text_edit::TextEditState::load(ctx, focused).is_some()
+        });
+
+        // Ignore the keys of the frame the dialog was opened in,
+        // since they may have been what opened it.
+        if closed.is_none() && !is_first_frame {
+            if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+                closed = Some(false);
+            } else if !is_editing_text
+                && ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Enter))
+            {
+                closed = Some(true);
+            }
+        }
+
+        closed
+    }
+
+    fn finish(self: Box<Self>, confirmed: bool) {
+        let result = if confirmed {
+            DialogResult::Confirmed(self.dialog.value)
+        } else {
+            DialogResult::Cancelled
+        };
+        self.result.set(result).ok();
+    }
+}
+
+#[derive(Default)]
+struct DialogQueueInner {
+    dialogs: VecDeque<Box<dyn QueuedDialog>>,
+
+    /// Used to give each dialog its own [`Id`].
+    count: u64,
+}
+
+/// The dialogs waiting to be shown, one at a time.
+///
+/// This is a built-in plugin in egui.
+#[derive(Clone, Default)]
+struct DialogQueue(Arc<Mutex<DialogQueueInner>>);
+
+impl DialogQueue {
+    fn get(ctx: &Context) -> Self {
+        ctx.data_mut(|d| d.get_temp_mut_or_default::<Self>(Id::NULL).clone())
+    }
+
+    fn end_frame(ctx: &Context) {
+        let queue = Self::get(ctx);
+        let viewport_id = ctx.viewport_id();
+
+        // Don't hold the lock while showing the dialog, since it may open another one:
+        let mut dialog = {
+            let mut inner = queue.0.lock();
+            let Some(dialog) = inner.dialogs.front_mut() else {
+                return;
+            };
+            if dialog.viewport_id() != viewport_id {
+                let is_viewport_gone = viewport_id == ViewportId::ROOT
+                    && ctx.input(|i| !i.raw.viewports.contains_key(&dialog.viewport_id()));
+                if !is_viewport_gone {
+                    return; // Shown in the frames of its own viewport
+                }
+                dialog.move_to_root();
+            }
+            inner.dialogs.pop_front().expect("checked by front_mut")
+        };
+
+        if let Some(confirmed) = dialog.show(ctx) {
+            dialog.finish(confirmed);
+            ctx.request_repaint();
+        } else {
+            queue.0.lock().dialogs.push_front(dialog);
+        }
+    }
+}
+
+/// Register this plugin on the given egui context,
+/// so that it will be called every frame.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_end_frame("dialog", Arc::new(DialogQueue::end_frame));
+}
+
+pub(crate) fn open<T: Send + Sync + 'static>(ctx: &Context, dialog: Dialog<T>) -> DialogHandle<T> {
+    let result = Arc::new(OnceLock::new());
+    let queue = DialogQueue::get(ctx);
+    let mut inner = queue.0.lock();
+    inner.count += 1;
+    let entry = DialogEntry {
+        id: Id::new("egui_dialog").with(inner.count),
+        viewport_id: ctx.viewport_id(),
+        dialog,
+        result: result.clone(),
+        is_first_frame: true,
+    };
+    inner.dialogs.push_back(Box::new(entry));
+    ctx.request_repaint();
+
+    DialogHandle { result }
+}
+
+pub(crate) fn is_open(ctx: &Context) -> bool {
+    !DialogQueue::get(ctx).0.lock().dialogs.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::press;
+
+    #[test]
+    fn test_dialog_handle() {
+        let result = Arc::new(OnceLock::new());
+        let handle = DialogHandle {
+            result: result.clone(),
+        };
+        assert!(!handle.is_ready());
+        let handle = handle.try_take().unwrap_err();
+
+        result.set(DialogResult::Confirmed(42)).ok();
+        assert_eq!(handle.ready(), Some(&DialogResult::Confirmed(42)));
+
+        // The dialog still holds on to the result:
+        let handle = handle.try_take().unwrap_err();
+        drop(result);
+        assert_eq!(handle.try_take().ok(), Some(DialogResult::Confirmed(42)));
+    }
+
+    /// Input for a frame of the root viewport, or of its child viewport.
+    fn input(viewport_id: ViewportId, events: Vec<Event>) -> RawInput {
+        let viewports = [ViewportId::ROOT, ViewportId::from_hash_of("child")]
+            .into_iter()
+            .map(|id| (id, ViewportInfo::default()))
+            .collect();
+        RawInput {
+            viewport_id,
+            viewports,
+            events,
+            ..Default::default()
+        }
+    }
+
+    fn run_in(ctx: &Context, viewport_id: ViewportId, events: Vec<Event>) {
+        let _ = ctx.run(input(viewport_id, events), |ctx| {
+            CentralPanel::default().show(ctx, |_ui| {});
+        });
+    }
+
+    fn run(ctx: &Context, events: Vec<Event>) {
+        run_in(ctx, ViewportId::ROOT, events);
+    }
+
+    #[test]
+    fn test_one_dialog_at_a_time() {
+        let ctx = Context::default();
+        let first = ctx.open_dialog(Dialog::confirm("First", "Are you sure?"));
+        let second = ctx.open_dialog(Dialog::prompt("Second", "Name:", "untitled"));
+        assert!(ctx.is_dialog_open());
+
+        // The keys of the first frame may have opened the dialog, so they are ignored:
+        run(&ctx, vec![press(Key::Escape)]);
+        assert!(!first.is_ready());
+
+        run(&ctx, vec![press(Key::Escape)]);
+        assert_eq!(first.ready(), Some(&DialogResult::Cancelled));
+        assert!(!second.is_ready(), "Only the first dialog was shown");
+
+        // The prompt focuses its text edit:
+        run(&ctx, vec![]);
+        run(&ctx, vec![]);
+        run(&ctx, vec![Event::Text("s".to_owned()), press(Key::Enter)]);
+        assert_eq!(
+            second.try_take().ok(),
+            Some(DialogResult::Confirmed("untitleds".to_owned()))
+        );
+        assert!(!ctx.is_dialog_open());
+    }
+
+    #[test]
+    fn test_enter_in_multiline_text_edit() {
+        let ctx = Context::default();
+        let notes = Arc::new(Mutex::new(String::new()));
+        let handle = ctx.open_dialog(Dialog::new("Notes", String::new(), {
+            let notes = notes.clone();
+            move |ui, text| {
+                ui.text_edit_multiline(text).request_focus();
+                notes.lock().clone_from(text);
+            }
+        }));
+
+        run(&ctx, vec![]);
+        run(&ctx, vec![]);
+        run(&ctx, vec![press(Key::Enter)]);
+        assert!(
+            !handle.is_ready(),
+            "Enter is a new line, and doesn't confirm"
+        );
+        run(&ctx, vec![]);
+        assert_eq!(*notes.lock(), "\n");
+
+        run(&ctx, vec![press(Key::Escape)]);
+        assert_eq!(handle.ready(), Some(&DialogResult::Cancelled));
+    }
+
+    #[test]
+    fn test_dialog_in_its_viewport() {
+        let ctx = Context::default();
+        let child = ViewportId::from_hash_of("child");
+
+        let mut handle = None;
+        let _ = ctx.run(input(child, vec![]), |ctx| {
+            handle = Some(ctx.open_dialog(Dialog::confirm("Child", "Are you sure?")));
+        });
+        let handle = handle.unwrap();
+
+        run(&ctx, vec![press(Key::Enter)]);
+        run(&ctx, vec![press(Key::Enter)]);
+        assert!(!handle.is_ready(), "Not shown in the root viewport");
+
+        run_in(&ctx, child, vec![press(Key::Enter)]);
+        assert_eq!(handle.ready(), Some(&DialogResult::Confirmed(())));
+
+        // When the viewport is closed, the dialog moves to the root viewport:
+        let handle = ctx.open_dialog(Dialog::confirm("Orphan", "Are you sure?"));
+        let input = RawInput {
+            events: vec![press(Key::Escape)],
+            ..Default::default()
+        };
+        let _ = ctx.run(input.clone(), |_ctx| {});
+        let _ = ctx.run(input, |_ctx| {});
+        assert_eq!(handle.ready(), Some(&DialogResult::Cancelled));
+    }
+}
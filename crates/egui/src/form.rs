@@ -0,0 +1,322 @@
+use crate::{id::IdSet, *};
+
+/// What a [`Form`] remembers between frames.
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// Fields that have been edited, and so should show their validation errors.
+    touched: IdSet,
+
+    /// Show all validation errors, because the user tried to submit.
+    submit_attempted: bool,
+
+    /// Has anything changed since the form was shown first, or last submitted?
+    dirty: bool,
+}
+
+/// A validation error of one field in a [`Form`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormError {
+    /// The label of the field.
+    pub label: String,
+
+    /// What is wrong with it.
+    pub message: String,
+}
+
+/// Returned by [`Form::show`].
+#[derive(Clone, Debug)]
+pub struct FormResponse {
+    /// The response of the whole form.
+    pub response: Response,
+
+    /// The form was submitted this frame, and all fields were valid.
+    pub submitted: bool,
+
+    /// Has any field changed since the form was first shown, or last submitted?
+    pub dirty: bool,
+
+    /// The validation errors of all fields, in order.
+    pub errors: Vec<FormError>,
+}
+
+impl FormResponse {
+    /// Did all fields pass validation?
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Labelled input fields, with validation and submit handling.
+///
+/// The labels and inputs are laid out in a [`Grid`], so they line up.
+///
+/// Validation errors are shown under a field once it has been edited,
+/// or once the user has tried to submit the form.
+/// A form with errors can't be submitted.
+///
+/// Pressing enter in a field moves the focus to the next field,
+/// and pressing enter in the last field submits the form.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut name = String::new();
+/// # let mut age = 42;
+/// let response = egui::Form::new("person").show(ui, |form| {
+///     form.field_with_validation(
+///         "Name",
+///         &mut name,
+///         |ui, name| ui.text_edit_singleline(name),
+///         |name| if name.is_empty() { Err("Name is required".to_owned()) } else { Ok(()) },
+///     );
+///     form.field("Age", |ui| ui.add(egui::DragValue::new(&mut age)));
+///     form.submit_button("Save");
+/// });
+///
+/// if response.submitted {
+///     // save `name` and `age`
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Form {
+    id_source: Id,
+    spacing: Option<Vec2>,
+    focus_next_on_enter: bool,
+    submit_on_enter: bool,
+}
+
+impl Form {
+    /// Create a new [`Form`] with a locally unique identifier.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            spacing: None,
+            focus_next_on_enter: true,
+            submit_on_enter: true,
+        }
+    }
+
+    /// Set spacing between the labels and fields, and between rows.
+    ///
+    /// Default: [`crate::style::Spacing::item_spacing`].
+    #[inline]
+    pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+
+    /// Pressing enter in a field moves the focus to the next field.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn focus_next_on_enter(mut self, focus_next_on_enter: bool) -> Self {
+        self.focus_next_on_enter = focus_next_on_enter;
+        self
+    }
+
+    /// Pressing enter in the last field (or in any field, if [`Self::focus_next_on_enter`] is off)
+    /// submits the form.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn submit_on_enter(mut self, submit_on_enter: bool) -> Self {
+        self.submit_on_enter = submit_on_enter;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui, add_contents: impl FnOnce(&mut FormUi<'_>)) -> FormResponse {
+        let Self {
+            id_source,
+            spacing,
+            focus_next_on_enter,
+            submit_on_enter,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let state = ui.data_mut(|d| d.get_temp::<State>(id)).unwrap_or_default();
+
+        let mut grid = Grid::new(id.with("grid")).num_columns(2);
+        if let Some(spacing) = spacing {
+            grid = grid.spacing(spacing);
+        }
+
+        let InnerResponse { inner, response } = grid.show(ui, |ui| {
+            let mut form = FormUi {
+                ui,
+                id,
+                state,
+                focus_next_on_enter,
+                submit_on_enter,
+                errors: vec![],
+                focus_next: false,
+                submit_requested: false,
+            };
+            add_contents(&mut form);
+            if form.focus_next && form.submit_on_enter {
+                // Enter was pressed in the last field.
+                form.submit_requested = true;
+            }
+            (form.state, form.errors, form.submit_requested)
+        });
+        let (mut state, errors, submit_requested) = inner;
+
+        let submitted = submit_requested && errors.is_empty();
+        if submitted {
+            state = State::default();
+        } else if submit_requested && !state.submit_attempted {
+            // Show all the errors:
+            state.submit_attempted = true;
+            ui.ctx().request_repaint();
+        }
+
+        let dirty = state.dirty;
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        FormResponse {
+            response,
+            submitted,
+            dirty,
+            errors,
+        }
+    }
+}
+
+/// Adds fields to a [`Form`].
+pub struct FormUi<'a> {
+    ui: &'a mut Ui,
+    id: Id,
+    state: State,
+    focus_next_on_enter: bool,
+    submit_on_enter: bool,
+    errors: Vec<FormError>,
+
+    /// Enter was pressed in the previous field.
+    focus_next: bool,
+
+    submit_requested: bool,
+}
+
+impl<'a> FormUi<'a> {
+    /// The [`Ui`] of the underlying [`Grid`], for adding custom rows.
+    ///
+    /// Remember to call [`Ui::end_row`] after each row.
+    #[inline]
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+
+    /// Add a labelled input field.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut enabled = true;
+    /// egui::Form::new("settings").show(ui, |form| {
+    ///     form.field("Enabled", |ui| ui.checkbox(&mut enabled, ""));
+    /// });
+    /// # });
+    /// ```
+    pub fn field(
+        &mut self,
+        label: impl Into<WidgetText>,
+        add_input: impl FnOnce(&mut Ui) -> Response,
+    ) -> Response {
+        self.add_field(label.into(), |ui| (add_input(ui), None))
+    }
+
+    /// Add a labelled input field for `value`, which is checked by `validate` after editing.
+    ///
+    /// If `validate` returns an error, it is shown under the field, and the form can't be submitted.
+    pub fn field_with_validation<T: ?Sized>(
+        &mut self,
+        label: impl Into<WidgetText>,
+        value: &mut T,
+        add_input: impl FnOnce(&mut Ui, &mut T) -> Response,
+        validate: impl FnOnce(&T) -> Result<(), String>,
+    ) -> Response {
+        self.add_field(label.into(), |ui| {
+            let response = add_input(ui, value);
+            (response, validate(value).err())
+        })
+    }
+
+    fn add_field(
+        &mut self,
+        label: WidgetText,
+        add_input: impl FnOnce(&mut Ui) -> (Response, Option<String>),
+    ) -> Response {
+        let label_text = label.text().to_owned();
+        let field_id = self.id.with(&label_text);
+        let label_response = self.ui.label(label);
+
+        let state = &mut self.state;
+        let (response, error) = self
+            .ui
+            .vertical(|ui| {
+                let (response, error) = add_input(ui);
+                if response.changed() {
+                    state.touched.insert(field_id);
+                    state.dirty = true;
+                }
+                if let Some(error) = &error {
+                    if state.submit_attempted || state.touched.contains(&field_id) {
+                        let error = ValidationState::error(error.clone());
+                        ui.label(error.rich_text(ui.visuals()).small());
+                    }
+                }
+                (response, error)
+            })
+            .inner;
+        self.ui.end_row();
+
+        let response = response.labelled_by(label_response.id);
+
+        if self.focus_next {
+            response.request_focus();
+            self.focus_next = false;
+        }
+        if response.lost_focus() && self.ui.input(|i| i.key_pressed(Key::Enter)) {
+            if self.focus_next_on_enter {
+                self.focus_next = true;
+            } else if self.submit_on_enter {
+                self.submit_requested = true;
+            }
+        }
+
+        if let Some(message) = error {
+            self.errors.push(FormError {
+                label: label_text,
+                message,
+            });
+        }
+
+        response
+    }
+
+    /// Add a button that submits the form, in the input column.
+    ///
+    /// Put this after all the fields, so that their validation is taken into account.
+    pub fn submit_button(&mut self, text: impl Into<WidgetText>) -> Response {
+        self.ui.label("");
+        let response = self.ui.button(text);
+        self.ui.end_row();
+        if response.clicked() {
+            self.submit();
+        }
+        response
+    }
+
+    /// Submit the form, as if the submit button was clicked.
+    ///
+    /// Put this after all the fields, so that their validation is taken into account.
+    #[inline]
+    pub fn submit(&mut self) {
+        self.submit_requested = true;
+    }
+
+    /// Reset [`FormResponse::dirty`], e.g. after the values were saved some other way.
+    #[inline]
+    pub fn mark_clean(&mut self) {
+        self.state.dirty = false;
+    }
+}
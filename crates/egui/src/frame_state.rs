@@ -74,6 +74,12 @@ pub struct FrameState {
     /// Highlight these widgets the next frame. Write to this.
     pub highlight_next_frame: IdSet,
 
+    /// Measured areas and panels, if [`crate::Options::measure_timings`] is on.
+    pub timings: Vec<crate::TimingEntry>,
+
+    /// Registered with [`crate::Context::register_shortcut`].
+    pub shortcuts: Vec<crate::RegisteredShortcut>,
+
     #[cfg(debug_assertions)]
     pub has_debug_viewed_this_frame: bool,
 }
@@ -92,6 +98,8 @@ impl Default for FrameState {
             accesskit_state: None,
             highlight_this_frame: Default::default(),
             highlight_next_frame: Default::default(),
+            timings: Default::default(),
+            shortcuts: Default::default(),
 
             #[cfg(debug_assertions)]
             has_debug_viewed_this_frame: false,
@@ -114,6 +122,8 @@ impl FrameState {
             accesskit_state,
             highlight_this_frame,
             highlight_next_frame,
+            timings,
+            shortcuts,
 
             #[cfg(debug_assertions)]
             has_debug_viewed_this_frame,
@@ -126,6 +136,8 @@ impl FrameState {
         tooltip_state.clear();
         *scroll_target = [None, None];
         *scroll_delta = Vec2::default();
+        timings.clear();
+        shortcuts.clear();
 
         #[cfg(debug_assertions)]
         {
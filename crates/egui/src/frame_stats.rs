@@ -0,0 +1,232 @@
+//! Statistics about what was painted during a frame.
+//!
+//! Read them with [`crate::Context::frame_stats`], or in the "📊 Frame statistics" section of [`crate::Context::inspection_ui`].
+
+use epaint::{textures::TexturesDelta, ClippedPrimitive, ClippedShape, Primitive, Shape};
+
+/// How many shapes of each type were painted.
+///
+/// Shapes nested in [`Shape::Vec`] are counted individually.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ShapeCounts {
+    /// [`Shape::Rect`].
+    pub rects: usize,
+
+    /// [`Shape::Circle`] and [`Shape::Ellipse`].
+    pub circles: usize,
+
+    /// [`Shape::Arc`] and [`Shape::Pie`].
+    pub arcs: usize,
+
+    /// [`Shape::LineSegment`].
+    pub line_segments: usize,
+
+    /// [`Shape::Path`] and [`Shape::SvgPath`].
+    pub paths: usize,
+
+    /// [`Shape::CubicBezier`], [`Shape::QuadraticBezier`] and [`Shape::CatmullRom`].
+    pub curves: usize,
+
+    /// [`Shape::Text`].
+    pub texts: usize,
+
+    /// [`Shape::Mesh`] and [`Shape::PrecomputedMesh`].
+    pub meshes: usize,
+
+    /// [`Shape::Callback`].
+    pub callbacks: usize,
+}
+
+impl ShapeCounts {
+    pub fn from_shapes(shapes: &[ClippedShape]) -> Self {
+        let mut counts = Self::default();
+        for ClippedShape { shape, .. } in shapes {
+            counts.add(shape);
+        }
+        counts
+    }
+
+    /// Total number of shapes, not counting [`Shape::Noop`] and [`Shape::Vec`].
+    pub fn total(&self) -> usize {
+        let Self {
+            rects,
+            circles,
+            arcs,
+            line_segments,
+            paths,
+            curves,
+            texts,
+            meshes,
+            callbacks,
+        } = *self;
+        rects + circles + arcs + line_segments + paths + curves + texts + meshes + callbacks
+    }
+
+    fn add(&mut self, shape: &Shape) {
+        match shape {
+            Shape::Noop => {}
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    self.add(shape);
+                }
+            }
+            Shape::Rect(_) => self.rects += 1,
+            Shape::Circle(_) | Shape::Ellipse(_) => self.circles += 1,
+            Shape::Arc(_) | Shape::Pie(_) => self.arcs += 1,
+            Shape::LineSegment { .. } => self.line_segments += 1,
+            Shape::Path(_) | Shape::SvgPath(_) => self.paths += 1,
+            Shape::CubicBezier(_) | Shape::QuadraticBezier(_) | Shape::CatmullRom(_) => {
+                self.curves += 1;
+            }
+            Shape::Text(_) => self.texts += 1,
+            Shape::Mesh(_) | Shape::PrecomputedMesh(..) => self.meshes += 1,
+            Shape::Callback(_) => self.callbacks += 1,
+        }
+    }
+}
+
+/// Statistics about the last complete frame of a viewport.
+///
+/// Useful for performance dashboards, and for asserting budgets in tests.
+///
+/// See [`crate::Context::frame_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    /// The frame these statistics are for, see [`crate::Context::frame_nr`].
+    pub frame_nr: u64,
+
+    /// Number of layers that painted anything.
+    pub num_layers: usize,
+
+    /// Number of visible [`crate::Area`]s, including windows, tooltips and popups.
+    pub num_areas: usize,
+
+    /// The painted shapes, by type.
+    pub shapes: ShapeCounts,
+
+    /// Number of textures created or updated, including the font atlas.
+    pub num_texture_uploads: usize,
+
+    /// Total size of the uploaded texture data, in bytes.
+    pub texture_upload_bytes: usize,
+
+    /// Number of textures freed.
+    pub num_texture_frees: usize,
+
+    /// Number of meshes and callbacks produced by tessellation.
+    ///
+    /// Zero until [`crate::Context::tessellate`] has been called for the frame.
+    pub num_clipped_primitives: usize,
+
+    /// Number of vertices produced by tessellation.
+    ///
+    /// Zero until [`crate::Context::tessellate`] has been called for the frame.
+    pub num_vertices: usize,
+
+    /// Number of triangle indices produced by tessellation.
+    ///
+    /// Zero until [`crate::Context::tessellate`] has been called for the frame.
+    pub num_indices: usize,
+}
+
+impl FrameStats {
+    pub(crate) fn add_textures_delta(&mut self, textures_delta: &TexturesDelta) {
+        for (_, delta) in &textures_delta.set {
+            let image = &delta.image;
+            self.num_texture_uploads += 1;
+            self.texture_upload_bytes += image.width() * image.height() * image.bytes_per_pixel();
+        }
+        self.num_texture_frees += textures_delta.free.len();
+    }
+
+    pub(crate) fn set_clipped_primitives(&mut self, clipped_primitives: &[ClippedPrimitive]) {
+        self.num_clipped_primitives = clipped_primitives.len();
+        self.num_vertices = 0;
+        self.num_indices = 0;
+        for clipped_primitive in clipped_primitives {
+            if let Primitive::Mesh(mesh) = &clipped_primitive.primitive {
+                self.num_vertices += mesh.vertices.len();
+                self.num_indices += mesh.indices.len();
+            }
+        }
+    }
+
+    /// Show the statistics in a grid.
+    pub fn ui(&self, ui: &mut crate::Ui) {
+        let Self {
+            frame_nr,
+            num_layers,
+            num_areas,
+            shapes,
+            num_texture_uploads,
+            texture_upload_bytes,
+            num_texture_frees,
+            num_clipped_primitives,
+            num_vertices,
+            num_indices,
+        } = *self;
+
+        crate::Grid::new("frame_stats")
+            .striped(true)
+            .show(ui, |ui| {
+                let mut row = |name: &str, value: String| {
+                    ui.label(name);
+                    ui.label(value);
+                    ui.end_row();
+                };
+                row("Frame", frame_nr.to_string());
+                row("Layers", num_layers.to_string());
+                row("Areas", num_areas.to_string());
+                row("Shapes", shapes.total().to_string());
+                row("  rects", shapes.rects.to_string());
+                row("  circles", shapes.circles.to_string());
+                row("  arcs", shapes.arcs.to_string());
+                row("  line segments", shapes.line_segments.to_string());
+                row("  paths", shapes.paths.to_string());
+                row("  curves", shapes.curves.to_string());
+                row("  texts", shapes.texts.to_string());
+                row("  meshes", shapes.meshes.to_string());
+                row("  callbacks", shapes.callbacks.to_string());
+                row(
+                    "Texture uploads",
+                    format!(
+                        "{num_texture_uploads} ({:.2} MB)",
+                        texture_upload_bytes as f64 / 1e6
+                    ),
+                );
+                row("Texture frees", num_texture_frees.to_string());
+                row("Clipped primitives", num_clipped_primitives.to_string());
+                row("Vertices", num_vertices.to_string());
+                row("Indices", num_indices.to_string());
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shape_counts() {
+        let clip_rect = emath::Rect::EVERYTHING;
+        let shapes = [
+            ClippedShape {
+                clip_rect,
+                shape: Shape::circle_filled(emath::Pos2::ZERO, 1.0, epaint::Color32::RED),
+            },
+            ClippedShape {
+                clip_rect,
+                shape: Shape::Vec(vec![
+                    Shape::Noop,
+                    Shape::rect_filled(emath::Rect::ZERO, 0.0, epaint::Color32::RED),
+                    Shape::line_segment([emath::Pos2::ZERO; 2], (1.0, epaint::Color32::RED)),
+                ]),
+            },
+        ];
+        let counts = ShapeCounts::from_shapes(&shapes);
+        assert_eq!(counts.circles, 1);
+        assert_eq!(counts.rects, 1);
+        assert_eq!(counts.line_segments, 1);
+        assert_eq!(counts.total(), 3);
+    }
+}
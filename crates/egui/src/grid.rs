@@ -71,10 +71,16 @@ pub(crate) struct GridLayout {
     min_cell_size: Vec2,
     max_cell_size: Vec2,
     color_picker: Option<ColorPickerFn>,
+    row_sense: Option<Sense>,
+    highlight_hovered_row: bool,
+    enabled: bool,
 
     // Cursor:
     col: usize,
     row: usize,
+
+    /// One response per row, if [`Self::row_sense`] is set.
+    row_responses: Vec<(usize, Response)>,
 }
 
 impl GridLayout {
@@ -106,9 +112,13 @@ impl GridLayout {
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
             color_picker: None,
+            row_sense: None,
+            highlight_hovered_row: false,
+            enabled: ui.is_enabled(),
 
             col: 0,
             row: 0,
+            row_responses: Vec::new(),
         }
     }
 }
@@ -222,13 +232,9 @@ impl GridLayout {
     }
 
     fn paint_row(&mut self, cursor: &Rect, painter: &Painter) {
-        // handle row color painting based on color-picker function
-        let Some(color_picker) = self.color_picker.as_ref() else {
-            return;
-        };
-        let Some(row_color) = color_picker(self.row, &self.style) else {
+        if self.color_picker.is_none() && self.row_sense.is_none() {
             return;
-        };
+        }
         let Some(height) = self.prev_state.row_height(self.row) else {
             return;
         };
@@ -238,7 +244,32 @@ impl GridLayout {
         let rect = rect.expand2(0.5 * self.spacing.y * Vec2::Y);
         let rect = rect.expand2(2.0 * Vec2::X); // HACK: just looks better with some spacing on the sides
 
-        painter.rect_filled(rect, 2.0, row_color);
+        // handle row color painting based on color-picker function
+        if let Some(row_color) = self
+            .color_picker
+            .as_ref()
+            .and_then(|color_picker| color_picker(self.row, &self.style))
+        {
+            painter.rect_filled(rect, 2.0, row_color);
+        }
+
+        // The row is registered before its cells, so that the cells end up on top of it:
+        if let Some(sense) = self.row_sense {
+            let response = self.ctx.create_widget(WidgetRect {
+                id: self.id.with(self.row),
+                layer_id: painter.layer_id(),
+                rect,
+                interact_rect: painter.clip_rect().intersect(rect),
+                sense,
+                enabled: self.enabled,
+            });
+
+            if self.highlight_hovered_row && response.hovered() {
+                painter.rect_filled(rect, 2.0, self.style.visuals.widgets.hovered.bg_fill);
+            }
+
+            self.row_responses.push((self.row, response));
+        }
     }
 
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
@@ -301,6 +332,8 @@ pub struct Grid {
     spacing: Option<Vec2>,
     start_row: usize,
     color_picker: Option<ColorPickerFn>,
+    sense: Option<Sense>,
+    highlight_hovered_row: bool,
 }
 
 impl Grid {
@@ -315,6 +348,8 @@ impl Grid {
             spacing: None,
             start_row: 0,
             color_picker: None,
+            sense: None,
+            highlight_hovered_row: false,
         }
     }
 
@@ -381,6 +416,30 @@ impl Grid {
         self
     }
 
+    /// What should the rows of the grid sense for?
+    ///
+    /// Each row is covered by an invisible widget behind its cells,
+    /// so clicking e.g. a [`Button`] in a row will only click the button, not the row.
+    ///
+    /// Use [`Self::show_interactive`] to find out which row was hovered or clicked.
+    ///
+    /// Default: the rows don't sense anything.
+    #[inline]
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.sense = Some(sense);
+        self
+    }
+
+    /// If `true`, highlight the row under the mouse pointer
+    /// using [`crate::style::WidgetVisuals::bg_fill`] of [`crate::style::Widgets::hovered`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn highlight_hovered_row(mut self, highlight_hovered_row: bool) -> Self {
+        self.highlight_hovered_row = highlight_hovered_row;
+        self
+    }
+
     /// Change which row number the grid starts on.
     /// This can be useful when you have a large [`Grid`] inside of [`ScrollArea::show_rows`].
     #[inline]
@@ -392,6 +451,41 @@ impl Grid {
 
 impl Grid {
     pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let GridResponse {
+            inner, response, ..
+        } = self.show_dyn(ui, Box::new(add_contents));
+        InnerResponse { inner, response }
+    }
+
+    /// Like [`Self::show`], but also tells you which row was hovered or clicked.
+    ///
+    /// Use [`Self::sense`] to decide what the rows should sense for
+    /// (default here: [`Sense::click`]).
+    ///
+    /// The row responses are based on the row heights of the previous frame,
+    /// so there are none the first frame a grid is shown.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = egui::Grid::new("clickable_rows")
+    ///     .highlight_hovered_row(true)
+    ///     .show_interactive(ui, |ui| {
+    ///         for name in ["Alice", "Bob"] {
+    ///             ui.label(name);
+    ///             ui.end_row();
+    ///         }
+    ///     });
+    /// if let Some(row) = response.clicked_row() {
+    ///     println!("Clicked row {row}");
+    /// }
+    /// # });
+    /// ```
+    pub fn show_interactive<R>(
+        mut self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> GridResponse<R> {
+        self.sense = Some(self.sense.unwrap_or_else(Sense::click));
         self.show_dyn(ui, Box::new(add_contents))
     }
 
@@ -399,7 +493,7 @@ impl Grid {
         self,
         ui: &mut Ui,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
-    ) -> InnerResponse<R> {
+    ) -> GridResponse<R> {
         let Self {
             id_source,
             num_columns,
@@ -409,6 +503,8 @@ impl Grid {
             spacing,
             start_row,
             mut color_picker,
+            sense,
+            highlight_hovered_row,
         } = self;
         let min_col_width = min_col_width.unwrap_or_else(|| ui.spacing().interact_size.x);
         let min_row_height = min_row_height.unwrap_or_else(|| ui.spacing().interact_size.y);
@@ -416,6 +512,7 @@ impl Grid {
         if color_picker.is_none() && ui.visuals().striped {
             color_picker = Some(Box::new(striped_row_color));
         }
+        let row_sense = sense.or_else(|| highlight_hovered_row.then(Sense::hover));
 
         let id = ui.make_persistent_id(id_source);
         let prev_state = State::load(ui.ctx(), id);
@@ -425,16 +522,20 @@ impl Grid {
         // then we should pick a default layout that matches that alignment,
         // which we do here:
         let max_rect = ui.cursor().intersect(ui.max_rect());
-        ui.allocate_ui_at_rect(max_rect, |ui| {
+        let InnerResponse {
+            inner: (inner, row_responses),
+            response,
+        } = ui.allocate_ui_at_rect(max_rect, |ui| {
             if prev_state.is_none() {
                 // Hide the ui this frame, and make things as narrow as possible.
                 ui.set_sizing_pass();
             }
             ui.horizontal(|ui| {
-                let is_color = color_picker.is_some();
                 let mut grid = GridLayout {
                     num_columns,
                     color_picker,
+                    row_sense,
+                    highlight_hovered_row,
                     min_cell_size: vec2(min_col_width, min_row_height),
                     max_cell_size,
                     spacing,
@@ -443,19 +544,67 @@ impl Grid {
                 };
 
                 // paint first incoming row
-                if is_color {
-                    let cursor = ui.cursor();
-                    let painter = ui.painter();
-                    grid.paint_row(&cursor, painter);
-                }
+                let cursor = ui.cursor();
+                let painter = ui.painter();
+                grid.paint_row(&cursor, painter);
 
                 ui.set_grid(grid);
                 let r = add_contents(ui);
                 ui.save_grid();
-                r
+                let row_responses = ui
+                    .placer()
+                    .grid()
+                    .map(|grid| grid.row_responses.clone())
+                    .unwrap_or_default();
+                (r, row_responses)
             })
             .inner
-        })
+        });
+
+        GridResponse {
+            inner,
+            response,
+            row_responses,
+        }
+    }
+}
+
+/// Returned by [`Grid::show_interactive`].
+#[derive(Debug)]
+pub struct GridResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the whole grid.
+    pub response: Response,
+
+    /// Row index and response of each row, in order.
+    row_responses: Vec<(usize, Response)>,
+}
+
+impl<R> GridResponse<R> {
+    /// The response of the given row, if it was shown and [`Grid::sense`] was set.
+    pub fn row_response(&self, row: usize) -> Option<&Response> {
+        self.row_responses
+            .iter()
+            .find(|(index, _)| *index == row)
+            .map(|(_, response)| response)
+    }
+
+    /// The row under the mouse pointer, if any.
+    pub fn hovered_row(&self) -> Option<usize> {
+        self.row_responses
+            .iter()
+            .find(|(_, response)| response.hovered())
+            .map(|(row, _)| *row)
+    }
+
+    /// The row that was clicked this frame, if any.
+    pub fn clicked_row(&self) -> Option<usize> {
+        self.row_responses
+            .iter()
+            .find(|(_, response)| response.clicked())
+            .map(|(row, _)| *row)
     }
 }
 
@@ -130,6 +130,10 @@ pub(crate) fn interact(
         }
     }
 
+    if !input.pointer.any_down() {
+        interaction.pointer_capture = None;
+    }
+
     let mut clicked = None;
     let mut dragged = prev_snapshot.dragged;
     let mut long_touched = None;
@@ -232,15 +236,22 @@ pub(crate) fn interact(
     //     );
     // }
 
-    let contains_pointer: IdSet = hits
+    let mut contains_pointer: IdSet = hits
         .contains_pointer
         .iter()
         .chain(&hits.click)
         .chain(&hits.drag)
         .map(|w| w.id)
         .collect();
+    contains_pointer.extend(interaction.pointer_capture);
 
-    let hovered = if clicked.is_some() || dragged.is_some() || long_touched.is_some() {
+    let hovered = if let Some(captured) = interaction.pointer_capture {
+        // Only the widget with the pointer capture is hovered.
+        std::iter::once(captured)
+            .chain(clicked)
+            .chain(dragged)
+            .collect()
+    } else if clicked.is_some() || dragged.is_some() || long_touched.is_some() {
         // If currently clicking or dragging, only that and nothing else is hovered.
         clicked
             .iter()
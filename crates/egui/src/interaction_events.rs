@@ -0,0 +1,194 @@
+//! A stream of user interactions with widgets, for analytics, usage heat-maps, tutorial systems etc.
+//!
+//! Subscribe with [`crate::Context::on_interaction`].
+//!
+//! Unlike [`crate::output::OutputEvent`], which is meant for screen readers and reports at most one
+//! event per widget and frame, this reports every kind of interaction that happened,
+//! together with where and when it happened.
+
+use std::sync::Arc;
+
+use crate::{Id, LayerId, Pos2, Rect, Response, ViewportId, WidgetInfo};
+
+/// What the user did with a widget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum InteractionKind {
+    /// Clicked with the primary mouse button, or activated with the keyboard.
+    Clicked,
+
+    /// Clicked with the secondary mouse button.
+    SecondaryClicked,
+
+    /// Clicked with the middle mouse button.
+    MiddleClicked,
+
+    DoubleClicked,
+
+    TripleClicked,
+
+    /// Started dragging the widget.
+    DragStarted,
+
+    /// Stopped dragging the widget.
+    DragStopped,
+
+    /// The widget gained keyboard focus.
+    FocusGained,
+
+    /// The widget lost keyboard focus.
+    FocusLost,
+
+    /// The value of the widget changed, e.g. a slider was moved or text was typed.
+    ValueChanged,
+}
+
+impl InteractionKind {
+    /// All the interactions that happened with the widget of the response this frame.
+    pub fn from_response(response: &Response) -> Vec<Self> {
+        [
+            (response.clicked(), Self::Clicked),
+            (response.secondary_clicked(), Self::SecondaryClicked),
+            (response.middle_clicked(), Self::MiddleClicked),
+            (response.double_clicked(), Self::DoubleClicked),
+            (response.triple_clicked(), Self::TripleClicked),
+            (response.drag_started(), Self::DragStarted),
+            (response.drag_stopped(), Self::DragStopped),
+            (response.gained_focus(), Self::FocusGained),
+            (response.lost_focus(), Self::FocusLost),
+            (response.changed(), Self::ValueChanged),
+        ]
+        .into_iter()
+        .filter_map(|(happened, kind)| happened.then_some(kind))
+        .collect()
+    }
+}
+
+/// The user interacted with a widget.
+///
+/// See [`crate::Context::on_interaction`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InteractionEvent {
+    /// What happened.
+    pub kind: InteractionKind,
+
+    /// What kind of widget it was, its label, value etc.
+    ///
+    /// The widget type is in [`WidgetInfo::typ`].
+    pub info: WidgetInfo,
+
+    /// The id of the widget.
+    ///
+    /// This is only stable between runs if the widget id is derived from stable data (e.g. a label),
+    /// so prefer [`WidgetInfo::label`] for grouping events.
+    pub id: Id,
+
+    /// The layer the widget is in.
+    pub layer_id: LayerId,
+
+    /// Where the widget is, in points.
+    pub rect: Rect,
+
+    /// Where the pointer was, if anywhere.
+    pub pointer_pos: Option<Pos2>,
+
+    /// The viewport the widget is in.
+    pub viewport_id: ViewportId,
+
+    /// See [`crate::Context::frame_nr`].
+    pub frame_nr: u64,
+}
+
+/// Called with each [`InteractionEvent`].
+///
+/// See [`crate::Context::on_interaction`].
+pub type InteractionCallback = Arc<dyn Fn(&InteractionEvent) + Send + Sync>;
+
+#[derive(Clone)]
+pub(crate) struct NamedInteractionCallback {
+    pub debug_name: &'static str,
+    pub callback: InteractionCallback,
+}
+
+/// Report the interactions with the widget of `response` to all subscribers.
+///
+/// `make_info` is only called if there is anything to report.
+pub(crate) fn report(
+    callbacks: &[NamedInteractionCallback],
+    response: &Response,
+    make_info: impl Fn() -> WidgetInfo,
+) {
+    if callbacks.is_empty() {
+        return;
+    }
+
+    let kinds = InteractionKind::from_response(response);
+    if kinds.is_empty() {
+        return;
+    }
+
+    crate::profile_function!();
+
+    let ctx = &response.ctx;
+    let info = make_info();
+    let pointer_pos = ctx.input(|i| i.pointer.interact_pos());
+    let viewport_id = ctx.viewport_id();
+    let frame_nr = ctx.frame_nr();
+
+    for kind in kinds {
+        let event = InteractionEvent {
+            kind,
+            info: info.clone(),
+            id: response.id,
+            layer_id: response.layer_id,
+            rect: response.rect,
+            pointer_pos,
+            viewport_id,
+            frame_nr,
+        };
+        for NamedInteractionCallback {
+            debug_name: _name,
+            callback,
+        } in callbacks
+        {
+            crate::profile_scope!("interaction callback", _name);
+            (callback)(&event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutex::Mutex;
+    use crate::test_utils::{click, run};
+    use crate::{Context, WidgetType};
+
+    #[test]
+    fn test_click_is_reported() {
+        let ctx = Context::default();
+        let events = Arc::new(Mutex::new(vec![]));
+        ctx.on_interaction("test", {
+            let events = events.clone();
+            Arc::new(move |event: &InteractionEvent| events.lock().push(event.clone()))
+        });
+
+        let button = run(&ctx, vec![], |ui| ui.button("Click me"));
+        assert!(events.lock().is_empty());
+
+        run(&ctx, click(button.rect.center()), |ui| {
+            ui.button("Click me")
+        });
+        let events = events.lock();
+        let clicks: Vec<_> = events
+            .iter()
+            .filter(|event| event.kind == InteractionKind::Clicked)
+            .collect();
+        assert_eq!(clicks.len(), 1);
+        assert_eq!(clicks[0].id, button.id);
+        assert_eq!(clicks[0].info.typ, WidgetType::Button);
+        assert_eq!(clicks[0].info.label.as_deref(), Some("Click me"));
+        assert_eq!(clicks[0].pointer_pos, Some(button.rect.center()));
+    }
+}
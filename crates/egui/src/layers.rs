@@ -200,8 +200,24 @@ impl GraphicLayers {
         transforms: &ahash::HashMap<LayerId, TSTransform>,
     ) -> Vec<ClippedShape> {
         crate::profile_function!();
+        let mut all_shapes = Vec::new();
+        for (_, mut shapes) in self.drain_layers(area_order, transforms) {
+            all_shapes.append(&mut shapes);
+        }
+        all_shapes
+    }
 
-        let mut all_shapes: Vec<_> = Default::default();
+    /// Like [`Self::drain`], but keeps the shapes of each layer separate.
+    ///
+    /// The layers are returned in paint order, back to front.
+    pub fn drain_layers(
+        &mut self,
+        area_order: &[LayerId],
+        transforms: &ahash::HashMap<LayerId, TSTransform>,
+    ) -> Vec<(LayerId, Vec<ClippedShape>)> {
+        crate::profile_function!();
+
+        let mut all_layers: Vec<_> = Default::default();
 
         for &order in &Order::ALL {
             let order_map = &mut self.0[order as usize];
@@ -221,7 +237,7 @@ impl GraphicLayers {
                                 clipped_shape.shape.transform(*transform);
                             }
                         }
-                        all_shapes.append(&mut list.0);
+                        all_layers.push((*layer_id, std::mem::take(&mut list.0)));
                     }
                 }
             }
@@ -237,10 +253,12 @@ impl GraphicLayers {
                     }
                 }
 
-                all_shapes.append(&mut list.0);
+                if !list.0.is_empty() {
+                    all_layers.push((layer_id, std::mem::take(&mut list.0)));
+                }
             }
         }
 
-        all_shapes
+        all_layers
     }
 }
@@ -140,6 +140,11 @@ pub struct Layout {
     /// For vertical layouts justify mean all widgets get maximum width.
     /// For horizontal layouts justify mean all widgets get maximum height.
     pub cross_justify: bool,
+
+    /// Align widgets with text on the baseline of their text, instead of using [`Self::cross_align`].
+    ///
+    /// Only used by horizontal layouts.
+    pub baseline_align: bool,
 }
 
 impl Default for Layout {
@@ -163,6 +168,7 @@ impl Layout {
             main_justify: false,
             cross_align: valign,
             cross_justify: false,
+            baseline_align: false,
         }
     }
 
@@ -178,6 +184,7 @@ impl Layout {
             main_justify: false,
             cross_align: valign,
             cross_justify: false,
+            baseline_align: false,
         }
     }
 
@@ -193,6 +200,7 @@ impl Layout {
             main_justify: false,
             cross_align: halign,
             cross_justify: false,
+            baseline_align: false,
         }
     }
 
@@ -214,6 +222,7 @@ impl Layout {
             main_justify: false,
             cross_align: halign,
             cross_justify: false,
+            baseline_align: false,
         }
     }
 
@@ -226,6 +235,7 @@ impl Layout {
             main_justify: false,
             cross_align,
             cross_justify: false,
+            baseline_align: false,
         }
     }
 
@@ -242,6 +252,7 @@ impl Layout {
             main_justify: true,
             cross_align: Align::Center,
             cross_justify: true,
+            baseline_align: false,
         }
     }
 
@@ -254,6 +265,21 @@ impl Layout {
         Self { main_wrap, ..self }
     }
 
+    /// Align widgets with text (e.g. labels, buttons and [`crate::DragValue`]s)
+    /// so that their text sits on a common baseline.
+    ///
+    /// Widgets without text still use [`Self::cross_align`].
+    /// Only used by horizontal layouts.
+    ///
+    /// See also [`crate::Ui::horizontal_baseline`].
+    #[inline(always)]
+    pub fn with_baseline_align(self, baseline_align: bool) -> Self {
+        Self {
+            baseline_align,
+            ..self
+        }
+    }
+
     /// The alignment to use on the main axis.
     #[inline(always)]
     pub fn with_main_align(self, main_align: Align) -> Self {
@@ -320,6 +346,11 @@ impl Layout {
         self.cross_justify
     }
 
+    #[inline(always)]
+    pub fn baseline_align(&self) -> bool {
+        self.baseline_align
+    }
+
     #[inline(always)]
     pub fn is_horizontal(&self) -> bool {
         self.main_dir().is_horizontal()
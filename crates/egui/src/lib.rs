@@ -378,14 +378,18 @@ pub mod containers;
 mod context;
 mod data;
 pub mod debug_text;
+mod dialog;
 mod drag_and_drop;
+mod form;
 mod frame_state;
+mod frame_stats;
 pub(crate) mod grid;
 pub mod gui_zoom;
 mod hit_test;
 mod id;
 mod input_state;
 mod interaction;
+mod interaction_events;
 pub mod introspection;
 pub mod layers;
 mod layout;
@@ -397,11 +401,18 @@ mod painter;
 pub(crate) mod placer;
 mod response;
 mod sense;
+mod shortcuts;
 pub mod style;
+pub mod task;
+mod tessellation_cache;
+#[cfg(any(test, feature = "test_utils"))]
+pub mod test_utils;
 pub mod text_selection;
+mod timings;
 mod ui;
 mod ui_stack;
 pub mod util;
+mod validation;
 pub mod viewport;
 mod widget_rect;
 pub mod widget_text;
@@ -422,23 +433,24 @@ pub use epaint::emath;
 
 #[cfg(feature = "color-hex")]
 pub use ecolor::hex_color;
-pub use ecolor::{Color32, Rgba};
+pub use ecolor::{Color32, ColorSpace, Rgba, ToneMapping};
 pub use emath::{
-    lerp, pos2, remap, remap_clamp, vec2, Align, Align2, NumExt, Pos2, Rangef, Rect, Vec2, Vec2b,
+    lerp, pos2, remap, remap_clamp, vec2, Align, Align2, NumExt, NumberFormat, Pos2, Rangef, Rect,
+    Vec2, Vec2b,
 };
 pub use epaint::{
     mutex,
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TextureOptions, TextureWrapMode, TexturesDelta},
     ClippedPrimitive, ColorImage, FontImage, ImageData, Margin, Mesh, PaintCallback,
-    PaintCallbackInfo, Rounding, Shadow, Shape, Stroke, TextureHandle, TextureId,
+    PaintCallbackInfo, Rounding, Shadow, ShadowFalloff, Shape, Stroke, TextureHandle, TextureId,
 };
 
 pub mod text {
     pub use crate::text_selection::{CCursorRange, CursorRange};
     pub use epaint::text::{
-        cursor::CCursor, FontData, FontDefinitions, FontFamily, Fonts, Galley, LayoutJob,
-        LayoutSection, TextFormat, TextWrapping, TAB_SIZE,
+        cursor::CCursor, FontData, FontDefinitions, FontFamily, FontFeatures, Fonts, Galley,
+        LayoutJob, LayoutSection, TextFormat, TextWrapping, TAB_SIZE,
     };
 }
 
@@ -452,11 +464,15 @@ pub use {
         },
         Key,
     },
+    dialog::{Dialog, DialogHandle, DialogResult},
     drag_and_drop::DragAndDrop,
     epaint::text::TextWrapMode,
-    grid::Grid,
+    form::{Form, FormError, FormResponse, FormUi},
+    frame_stats::{FrameStats, ShapeCounts},
+    grid::{Grid, GridResponse},
     id::{Id, IdMap},
     input_state::{InputState, MultiTouchInfo, PointerState},
+    interaction_events::{InteractionCallback, InteractionEvent, InteractionKind},
     layers::{LayerId, Order},
     layout::*,
     load::SizeHint,
@@ -464,10 +480,14 @@ pub use {
     painter::Painter,
     response::{InnerResponse, Response},
     sense::Sense,
-    style::{FontSelection, Style, TextStyle, Visuals},
+    shortcuts::{RegisteredShortcut, ShortcutCheatSheet},
+    style::{AreaAnimation, FontSelection, Style, TextStyle, Visuals},
+    task::TaskHandle,
     text::{Galley, TextFormat},
+    timings::{TimingEntry, Timings},
     ui::Ui,
     ui_stack::*,
+    validation::{ValidationMessagePlacement, ValidationSeverity, ValidationState},
     viewport::*,
     widget_rect::{WidgetRect, WidgetRects},
     widget_text::{RichText, WidgetText},
@@ -512,6 +512,20 @@ pub trait TextureLoader {
 
     /// If the loader caches any data, this should return the size of that cache.
     fn byte_size(&self) -> usize;
+
+    /// Evict the least recently used textures until the cache uses at most `max_bytes`.
+    ///
+    /// Textures that were loaded or used during `frame_index` should be kept,
+    /// even if that means staying above the budget.
+    ///
+    /// Returns the URIs of the evicted textures, so that egui can forget them in the other loaders too.
+    ///
+    /// This is called at the end of each frame if [`crate::Options::texture_budget`] is set.
+    /// The default implementation does nothing.
+    fn evict_to_budget(&self, max_bytes: usize, frame_index: usize) -> Vec<String> {
+        let _ = (max_bytes, frame_index);
+        Vec::new()
+    }
 }
 
 type BytesLoaderImpl = Arc<dyn BytesLoader + Send + Sync + 'static>;
@@ -527,6 +541,17 @@ pub struct Loaders {
     pub texture: Mutex<Vec<TextureLoaderImpl>>,
 }
 
+impl Loaders {
+    /// How much memory the caches of the loaders are using.
+    pub fn stats(&self) -> LoadersStats {
+        LoadersStats {
+            bytes: self.bytes.lock().iter().map(|l| l.byte_size()).sum(),
+            images: self.image.lock().iter().map(|l| l.byte_size()).sum(),
+            textures: self.texture.lock().iter().map(|l| l.byte_size()).sum(),
+        }
+    }
+}
+
 impl Default for Loaders {
     fn default() -> Self {
         let include = Arc::new(DefaultBytesLoader::default());
@@ -539,3 +564,27 @@ impl Default for Loaders {
         }
     }
 }
+
+/// How much memory the caches of the [`Loaders`] are using, in bytes.
+///
+/// See [`Loaders::stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LoadersStats {
+    /// Raw bytes cached by the [`BytesLoader`]s, e.g. the contents of image files.
+    pub bytes: usize,
+
+    /// Decoded images cached by the [`ImageLoader`]s.
+    pub images: usize,
+
+    /// Textures cached by the [`TextureLoader`]s, i.e. GPU memory.
+    ///
+    /// This is what [`crate::Options::texture_budget`] limits.
+    pub textures: usize,
+}
+
+impl LoadersStats {
+    /// Total bytes used by all loaders.
+    pub fn total(&self) -> usize {
+        self.bytes + self.images + self.textures
+    }
+}
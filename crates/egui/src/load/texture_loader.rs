@@ -1,8 +1,26 @@
+use std::sync::Weak;
+
 use super::*;
 
+struct CachedTexture {
+    handle: TextureHandle,
+
+    /// The image the texture was created from.
+    ///
+    /// Image loaders that ignore the [`SizeHint`] return the same image for every size,
+    /// and then we can share the texture instead of uploading it again.
+    image: Weak<ColorImage>,
+
+    /// The frame number the texture was last used, for LRU eviction.
+    last_used: usize,
+}
+
+/// The textures are cached per [`SizeHint`], so that e.g. an svg is rasterized again when zooming.
+/// Sizes of an image that were not used during a frame where another size was,
+/// are evicted at the end of that frame.
 #[derive(Default)]
 pub struct DefaultTextureLoader {
-    cache: Mutex<HashMap<(String, TextureOptions), TextureHandle>>,
+    cache: Mutex<HashMap<(String, TextureOptions, SizeHint), CachedTexture>>,
 }
 
 impl TextureLoader for DefaultTextureLoader {
@@ -17,17 +35,34 @@ impl TextureLoader for DefaultTextureLoader {
         texture_options: TextureOptions,
         size_hint: SizeHint,
     ) -> TextureLoadResult {
+        let frame_nr = ctx.frame_nr() as usize;
         let mut cache = self.cache.lock();
-        if let Some(handle) = cache.get(&(uri.into(), texture_options)) {
-            let texture = SizedTexture::from_handle(handle);
+        if let Some(cached) = cache.get_mut(&(uri.into(), texture_options, size_hint)) {
+            cached.last_used = frame_nr;
+            let texture = SizedTexture::from_handle(&cached.handle);
             Ok(TexturePoll::Ready { texture })
         } else {
             match ctx.try_load_image(uri, size_hint)? {
                 ImagePoll::Pending { size } => Ok(TexturePoll::Pending { size }),
                 ImagePoll::Ready { image } => {
-                    let handle = ctx.load_texture(uri, image, texture_options);
+                    let weak_image = Arc::downgrade(&image);
+                    let same_image = cache.iter().find_map(|((u, o, _), cached)| {
+                        (u == uri
+                            && *o == texture_options
+                            && Weak::ptr_eq(&cached.image, &weak_image))
+                        .then(|| cached.handle.clone())
+                    });
+                    let handle =
+                        same_image.unwrap_or_else(|| ctx.load_texture(uri, image, texture_options));
                     let texture = SizedTexture::from_handle(&handle);
-                    cache.insert((uri.into(), texture_options), handle);
+                    cache.insert(
+                        (uri.into(), texture_options, size_hint),
+                        CachedTexture {
+                            handle,
+                            image: weak_image,
+                            last_used: frame_nr,
+                        },
+                    );
                     let reduce_texture_memory = ctx.options(|o| o.reduce_texture_memory);
                     if reduce_texture_memory {
                         let loaders = ctx.loaders();
@@ -49,7 +84,7 @@ impl TextureLoader for DefaultTextureLoader {
         #[cfg(feature = "log")]
         log::trace!("forget {uri:?}");
 
-        self.cache.lock().retain(|(u, _), _| u != uri);
+        self.cache.lock().retain(|(u, _, _), _| u != uri);
     }
 
     fn forget_all(&self) {
@@ -59,13 +94,55 @@ impl TextureLoader for DefaultTextureLoader {
         self.cache.lock().clear();
     }
 
-    fn end_frame(&self, _: usize) {}
+    fn end_frame(&self, frame_index: usize) {
+        let mut cache = self.cache.lock();
+        let used: ahash::HashSet<(String, TextureOptions)> = cache
+            .iter()
+            .filter(|(_, cached)| cached.last_used == frame_index)
+            .map(|((uri, options, _), _)| (uri.clone(), *options))
+            .collect();
+        cache.retain(|(uri, options, _), cached| {
+            cached.last_used == frame_index || !used.contains(&(uri.clone(), *options))
+        });
+    }
 
     fn byte_size(&self) -> usize {
-        self.cache
-            .lock()
+        let cache = self.cache.lock();
+        let mut seen = ahash::HashSet::default();
+        cache
             .values()
-            .map(|texture| texture.byte_size())
+            .filter(|cached| seen.insert(cached.handle.id()))
+            .map(|cached| cached.handle.byte_size())
             .sum()
     }
+
+    fn evict_to_budget(&self, max_bytes: usize, frame_index: usize) -> Vec<String> {
+        let mut cache = self.cache.lock();
+        let mut bytes: usize = cache.values().map(|cached| cached.handle.byte_size()).sum();
+        if bytes <= max_bytes {
+            return Vec::new();
+        }
+
+        let mut candidates: Vec<_> = cache
+            .iter()
+            .filter(|(_, cached)| cached.last_used < frame_index)
+            .map(|(key, cached)| (cached.last_used, key.clone()))
+            .collect();
+        candidates.sort_by_key(|(last_used, _)| *last_used);
+
+        let mut evicted = Vec::new();
+        for (_, key) in candidates {
+            if bytes <= max_bytes {
+                break;
+            }
+            if let Some(cached) = cache.remove(&key) {
+                #[cfg(feature = "log")]
+                log::trace!("evict {:?}", key.0);
+
+                bytes -= cached.handle.byte_size();
+                evicted.push(key.0);
+            }
+        }
+        evicted
+    }
 }
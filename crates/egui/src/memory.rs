@@ -89,6 +89,15 @@ pub struct Memory {
     /// Transforms per layer
     pub layer_transforms: HashMap<LayerId, TSTransform>,
 
+    /// The zoom factor last used on each monitor, keyed by [`crate::ViewportInfo::monitor_id`].
+    ///
+    /// See [`Options::zoom_per_monitor`].
+    zoom_factor_per_monitor: HashMap<String, f32>,
+
+    /// The monitor the root viewport was on last frame.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    current_monitor: Option<String>,
+
     // -------------------------------------------------
     // Per-viewport:
     areas: ViewportIdMap<Areas>,
@@ -112,6 +121,8 @@ impl Default for Memory {
             viewport_id: Default::default(),
             areas: Default::default(),
             layer_transforms: Default::default(),
+            zoom_factor_per_monitor: Default::default(),
+            current_monitor: Default::default(),
             popup: Default::default(),
             everything_is_visible: Default::default(),
         };
@@ -181,6 +192,18 @@ pub struct Options {
     /// instead of modifying this directly!
     pub zoom_factor: f32,
 
+    /// Remember the zoom factor of each monitor, and switch to it when
+    /// the root viewport moves to that monitor.
+    ///
+    /// This is useful when monitors have different scale factors,
+    /// and the user prefers a different zoom on each of them.
+    /// With the `persistence` feature, this is remembered between runs.
+    ///
+    /// Requires the integration to set [`crate::ViewportInfo::monitor_id`].
+    ///
+    /// This is `false` by default.
+    pub zoom_per_monitor: bool,
+
     /// If `true`, egui will change the scale of the ui ([`crate::Context::zoom_factor`]) when the user
     /// presses Cmd+Plus, Cmd+Minus or Cmd+0, just like in a browser.
     ///
@@ -197,6 +220,16 @@ pub struct Options {
     /// Controls the tessellator.
     pub tessellation_options: epaint::TessellationOptions,
 
+    /// Reuse the tessellation of the previous frame for layers whose shapes haven't changed.
+    ///
+    /// This helps when most of the ui is static, e.g. side panels that rarely change,
+    /// but comparing the shapes has a cost, so it is slower when everything changes every frame.
+    ///
+    /// Only used by [`crate::Context::tessellate`] when it is given the shapes of the latest frame.
+    ///
+    /// Default is `false`.
+    pub cache_tessellation: bool,
+
     /// If any widget moves or changes id, repaint everything.
     ///
     /// It is recommended you keep this OFF, because
@@ -223,6 +256,14 @@ pub struct Options {
     /// if you are changing [`Style::text_styles`], of have a lot of text styles.
     pub preload_font_glyphs: bool,
 
+    /// How glyphs are rasterized into the font atlas.
+    ///
+    /// Anything but [`epaint::text::GlyphRasterization::Coverage`] needs support from the renderer,
+    /// which `egui_glow` and `egui-wgpu` have.
+    ///
+    /// Changing this reloads all fonts.
+    pub glyph_rasterization: epaint::text::GlyphRasterization,
+
     /// Check reusing of [`Id`]s, and show a visual warning on screen when one is found.
     ///
     /// By default this is `true` in debug builds.
@@ -248,6 +289,54 @@ pub struct Options {
     ///
     /// Default is `false`.
     pub reduce_texture_memory: bool,
+
+    /// If set, the least recently used image textures are evicted at the end of each frame
+    /// until the texture loaders use at most this many bytes.
+    ///
+    /// Evicted images are also forgotten by the image and bytes loaders,
+    /// and are reloaded from scratch if they are shown again.
+    /// Textures shown during the current frame are never evicted.
+    ///
+    /// See [`crate::load::Loaders::stats`] for how much memory is currently used.
+    ///
+    /// Default is `None` (no limit).
+    pub texture_budget: Option<usize>,
+
+    /// If set, at most this many bytes of image data are sent to the renderer each frame.
+    ///
+    /// Larger images are uploaded a few rows at a time over several frames,
+    /// so that loading a huge image doesn't freeze the ui.
+    /// [`crate::Image`] shows the upload progress meanwhile.
+    ///
+    /// See [`epaint::textures::TextureManager::set_upload_budget`].
+    ///
+    /// Default is `None` (no limit).
+    pub texture_upload_budget: Option<usize>,
+
+    /// Measure how long each [`crate::Area`] (windows, popups, …) and panel takes to run each frame.
+    ///
+    /// The results are available from [`crate::Context::timings`] and in [`crate::Context::inspection_ui`].
+    /// Use [`crate::Ui::measure_time`] to also measure your own parts of the UI.
+    ///
+    /// Does nothing on the web.
+    ///
+    /// Default is `false`.
+    pub measure_timings: bool,
+
+    /// If set (and [`Self::measure_timings`] is on), outline areas and panels that took longer
+    /// than this many seconds in a frame, so you can see what is slow.
+    ///
+    /// Default is `None`.
+    pub timing_budget: Option<f32>,
+
+    /// If set, show every frame as someone with this kind of color blindness would see it,
+    /// so you can check that your UI doesn't rely on colors they can't tell apart.
+    ///
+    /// This transforms the vertex colors of the tessellated frame.
+    /// Images and [`crate::PaintCallback`]s are not affected.
+    ///
+    /// Default is `None`.
+    pub color_vision_simulation: Option<crate::ecolor::ColorVisionDeficiency>,
 }
 
 impl Default for Options {
@@ -263,17 +352,25 @@ impl Default for Options {
         Self {
             style: Default::default(),
             zoom_factor: 1.0,
+            zoom_per_monitor: false,
             zoom_with_keyboard: true,
             tessellation_options: Default::default(),
+            cache_tessellation: false,
             repaint_on_widget_change: false,
             screen_reader: false,
             preload_font_glyphs: true,
+            glyph_rasterization: Default::default(),
             warn_on_id_clash: cfg!(debug_assertions),
 
             // Input:
             line_scroll_speed,
             scroll_zoom_speed: 1.0 / 200.0,
             reduce_texture_memory: false,
+            texture_budget: None,
+            texture_upload_budget: None,
+            measure_timings: false,
+            timing_budget: None,
+            color_vision_simulation: None,
         }
     }
 }
@@ -284,16 +381,24 @@ impl Options {
         let Self {
             style,          // covered above
             zoom_factor: _, // TODO(emilk)
+            zoom_per_monitor,
             zoom_with_keyboard,
             tessellation_options,
+            cache_tessellation,
             repaint_on_widget_change,
             screen_reader: _, // needs to come from the integration
             preload_font_glyphs: _,
+            glyph_rasterization: _, // needs to be supported by the integration
             warn_on_id_clash,
 
             line_scroll_speed,
             scroll_zoom_speed,
             reduce_texture_memory,
+            texture_budget,
+            texture_upload_budget,
+            measure_timings,
+            timing_budget,
+            color_vision_simulation,
         } = self;
 
         use crate::Widget as _;
@@ -311,9 +416,75 @@ impl Options {
                     "Zoom with keyboard (Cmd +, Cmd -, Cmd 0)",
                 );
 
+                ui.checkbox(zoom_per_monitor, "Remember zoom for each monitor");
+
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
 
                 ui.checkbox(reduce_texture_memory, "Reduce texture memory");
+
+                ui.horizontal(|ui| {
+                    let mut has_budget = texture_budget.is_some();
+                    ui.checkbox(&mut has_budget, "Texture budget");
+                    if has_budget {
+                        let budget = texture_budget.get_or_insert(256_000_000);
+                        let mut megabytes = *budget as f64 * 1e-6;
+                        crate::DragValue::new(&mut megabytes)
+                            .clamp_range(1.0..=f64::INFINITY)
+                            .suffix(" MB")
+                            .ui(ui);
+                        *budget = (megabytes * 1e6) as usize;
+                    } else {
+                        *texture_budget = None;
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    let mut has_budget = texture_upload_budget.is_some();
+                    ui.checkbox(&mut has_budget, "Texture upload budget");
+                    if has_budget {
+                        let budget = texture_upload_budget.get_or_insert(16_000_000);
+                        let mut megabytes = *budget as f64 * 1e-6;
+                        crate::DragValue::new(&mut megabytes)
+                            .clamp_range(0.1..=f64::INFINITY)
+                            .suffix(" MB/frame")
+                            .ui(ui);
+                        *budget = (megabytes * 1e6) as usize;
+                    } else {
+                        *texture_upload_budget = None;
+                    }
+                });
+
+                ui.checkbox(measure_timings, "Measure timings of areas and panels");
+                if *measure_timings {
+                    ui.horizontal(|ui| {
+                        let mut has_budget = timing_budget.is_some();
+                        ui.checkbox(&mut has_budget, "Outline if slower than");
+                        if has_budget {
+                            let budget = timing_budget.get_or_insert(0.004);
+                            let mut milliseconds = *budget * 1e3;
+                            crate::DragValue::new(&mut milliseconds)
+                                .clamp_range(0.0..=f32::INFINITY)
+                                .speed(0.1)
+                                .suffix(" ms")
+                                .ui(ui);
+                            *budget = milliseconds * 1e-3;
+                        } else {
+                            *timing_budget = None;
+                        }
+                    });
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("Simulate color blindness:");
+                    crate::ComboBox::from_id_source("color_vision_simulation")
+                        .selected_text(color_vision_simulation.map_or("None", |cvd| cvd.name()))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(color_vision_simulation, None, "None");
+                            for cvd in crate::ecolor::ColorVisionDeficiency::ALL {
+                                ui.selectable_value(color_vision_simulation, Some(cvd), cvd.name());
+                            }
+                        });
+                });
             });
 
         use crate::containers::*;
@@ -327,6 +498,7 @@ impl Options {
             .default_open(false)
             .show(ui, |ui| {
                 tessellation_options.ui(ui);
+                ui.checkbox(cache_tessellation, "Reuse tessellation of unchanged layers");
                 ui.vertical_centered(|ui| {
                     crate::reset_button(ui, tessellation_options, "Reset paint settings");
                 });
@@ -381,6 +553,9 @@ pub(crate) struct InteractionState {
     /// as that can only happen after the mouse has moved a bit
     /// (at least if the widget is interesated in both clicks and drags).
     pub potential_drag_id: Option<Id>,
+
+    /// The widget that has captured the pointer, see [`crate::Context::capture_pointer`].
+    pub pointer_capture: Option<Id>,
 }
 
 /// Keeps tracks of what widget has keyboard focus
@@ -431,7 +606,9 @@ impl FocusWidget {
 impl InteractionState {
     /// Are we currently clicking or dragging an egui widget?
     pub fn is_using_pointer(&self) -> bool {
-        self.potential_click_id.is_some() || self.potential_drag_id.is_some()
+        self.potential_click_id.is_some()
+            || self.potential_drag_id.is_some()
+            || self.pointer_capture.is_some()
     }
 }
 
@@ -677,6 +854,23 @@ impl Memory {
         self.focus_mut().end_frame(used_ids);
     }
 
+    /// A copy of the parts of the memory that are persisted, e.g. to serialize them on another thread.
+    ///
+    /// This is much cheaper than [`Clone::clone`], because it skips the caches and the temporary data,
+    /// see [`crate::util::IdTypeMap::clone_persisted`].
+    #[cfg(feature = "persistence")]
+    pub fn clone_persisted(&self) -> Self {
+        crate::profile_function!();
+        Self {
+            options: self.options.clone(),
+            data: self.data.clone_persisted(),
+            layer_transforms: self.layer_transforms.clone(),
+            zoom_factor_per_monitor: self.zoom_factor_per_monitor.clone(),
+            areas: self.areas.clone(),
+            ..Default::default()
+        }
+    }
+
     pub(crate) fn set_viewport_id(&mut self, viewport_id: ViewportId) {
         self.viewport_id = viewport_id;
     }
@@ -852,6 +1046,24 @@ impl Memory {
         self.areas().get(id.into()).map(|state| state.rect())
     }
 
+    /// Remember the current zoom factor for the monitor of the root viewport.
+    ///
+    /// If the root viewport moved to another monitor, returns the zoom factor last used there.
+    pub(crate) fn zoom_factor_for_monitor(&mut self, monitor_id: Option<&String>) -> Option<f32> {
+        if let Some(current_monitor) = &self.current_monitor {
+            self.zoom_factor_per_monitor
+                .insert(current_monitor.clone(), self.options.zoom_factor);
+        }
+
+        if self.current_monitor.as_ref() == monitor_id {
+            return None;
+        }
+
+        self.current_monitor = monitor_id.cloned();
+        let monitor_id = monitor_id?;
+        self.zoom_factor_per_monitor.get(monitor_id).copied()
+    }
+
     pub(crate) fn interaction(&self) -> &InteractionState {
         self.interactions
             .get(&self.viewport_id)
@@ -92,6 +92,198 @@ pub fn bar<R>(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResp
     })
 }
 
+/// A menu bar that moves the items that don't fit into a trailing "»" menu.
+///
+/// Use this instead of [`bar`] if the bar may be shown in a narrow window,
+/// where a normal menu bar would clip the items that don't fit.
+/// This works for toolbars too: an item can contain any widgets.
+///
+/// Items with a low priority are moved to the overflow menu first.
+/// Items with the same priority are moved starting with the last one.
+///
+/// `add_items` is called once for the bar, and once more for the overflow menu if it is open.
+/// Each call only shows the items that belong there.
+/// Which items fit is based on their widths in earlier frames.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::menu::bar_with_overflow(ui, |bar| {
+///     bar.item(|ui| {
+///         ui.menu_button("File", |ui| {
+///             if ui.button("Open").clicked() {
+///                 ui.close_menu();
+///             }
+///         });
+///     });
+///     bar.item_with_priority(-1, |ui| {
+///         ui.menu_button("Help", |ui| {
+///             ui.label("Rarely used, so hidden first");
+///         });
+///     });
+/// });
+/// # });
+/// ```
+pub fn bar_with_overflow(
+    ui: &mut Ui,
+    mut add_items: impl FnMut(&mut OverflowBar<'_>),
+) -> InnerResponse<()> {
+    bar(ui, |ui| {
+        let id = ui.id().with("__overflow");
+        let mut state: OverflowBarState = ui.data(|d| d.get_temp(id).unwrap_or_default());
+        let available_width = ui.available_width();
+        let hidden = state.hidden_items(available_width);
+
+        let mut overflow_bar = OverflowBar {
+            ui,
+            hidden: &hidden,
+            in_overflow_menu: false,
+            index: 0,
+            widths: vec![],
+            priorities: vec![],
+        };
+        add_items(&mut overflow_bar);
+        let OverflowBar {
+            ui,
+            widths,
+            priorities,
+            ..
+        } = overflow_bar;
+
+        if hidden.iter().any(|&is_hidden| is_hidden) {
+            let cursor_before = ui.cursor().min.x;
+            ui.menu_button("»", |ui| {
+                add_items(&mut OverflowBar {
+                    ui,
+                    hidden: &hidden,
+                    in_overflow_menu: true,
+                    index: 0,
+                    widths: vec![],
+                    priorities: vec![],
+                });
+            })
+            .response
+            .on_hover_text("More");
+            state.overflow_button_width = ui.cursor().min.x - cursor_before;
+        }
+
+        // Hidden items keep the width they had when they were last shown:
+        let widths = widths
+            .into_iter()
+            .enumerate()
+            .map(|(i, width)| {
+                width
+                    .or_else(|| state.widths.get(i).copied())
+                    .unwrap_or(0.0)
+            })
+            .collect();
+        let new_state = OverflowBarState {
+            widths,
+            priorities,
+            overflow_button_width: state.overflow_button_width,
+        };
+        if new_state.hidden_items(available_width) != hidden {
+            ui.ctx().request_repaint(); // Items were added, removed or resized
+        }
+        ui.data_mut(|d| d.insert_temp(id, new_state));
+    })
+}
+
+/// What [`bar_with_overflow`] remembers between frames.
+#[derive(Clone, Default)]
+struct OverflowBarState {
+    /// Width of each item, including the spacing after it.
+    widths: Vec<f32>,
+
+    /// See [`OverflowBar::item_with_priority`].
+    priorities: Vec<i32>,
+
+    /// Including the spacing after it.
+    overflow_button_width: f32,
+}
+
+impl OverflowBarState {
+    /// Which items should go into the overflow menu?
+    fn hidden_items(&self, available_width: f32) -> Vec<bool> {
+        let mut hidden = vec![false; self.widths.len()];
+        let mut total_width: f32 = self.widths.iter().sum();
+        if total_width <= available_width {
+            return hidden;
+        }
+
+        // Least important first:
+        let mut order: Vec<usize> = (0..self.widths.len()).collect();
+        order.sort_by_key(|&i| (self.priorities.get(i).copied().unwrap_or(0), usize::MAX - i));
+
+        let available_width = available_width - self.overflow_button_width;
+        for i in order {
+            if total_width <= available_width {
+                break;
+            }
+            hidden[i] = true;
+            total_width -= self.widths[i];
+        }
+        hidden
+    }
+}
+
+/// Add items to a [`bar_with_overflow`].
+pub struct OverflowBar<'u> {
+    ui: &'u mut Ui,
+    hidden: &'u [bool],
+    in_overflow_menu: bool,
+    index: usize,
+
+    /// Measured widths of the items shown in the bar this frame.
+    widths: Vec<Option<f32>>,
+    priorities: Vec<i32>,
+}
+
+impl<'u> OverflowBar<'u> {
+    /// Add an item with the default priority (zero).
+    ///
+    /// Returns `None` if the item was not shown in this pass.
+    pub fn item<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> Option<R> {
+        self.item_with_priority(0, add_contents)
+    }
+
+    /// Add an item with the given priority.
+    ///
+    /// Items with a lower priority are moved to the overflow menu before items with a higher priority.
+    ///
+    /// Returns `None` if the item was not shown in this pass.
+    pub fn item_with_priority<R>(
+        &mut self,
+        priority: i32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<R> {
+        let index = self.index;
+        self.index += 1;
+        self.priorities.push(priority);
+
+        let is_hidden = self.hidden.get(index).copied().unwrap_or(false);
+        if self.in_overflow_menu {
+            is_hidden.then(|| add_contents(self.ui))
+        } else if is_hidden {
+            self.widths.push(None);
+            None
+        } else {
+            let cursor_before = self.ui.cursor().min.x;
+            let inner = add_contents(self.ui);
+            self.widths
+                .push(Some(self.ui.cursor().min.x - cursor_before));
+            Some(inner)
+        }
+    }
+
+    /// Is this pass for the overflow menu?
+    ///
+    /// Use this to show an item differently in the menu, e.g. with a text label instead of just an icon.
+    #[inline]
+    pub fn is_in_overflow_menu(&self) -> bool {
+        self.in_overflow_menu
+    }
+}
+
 /// Construct a top level menu in a menu bar. This would be e.g. "File", "Edit" etc.
 ///
 /// Responds to primary clicks.
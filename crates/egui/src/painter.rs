@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
-    emath::{Align2, Pos2, Rangef, Rect, Vec2},
+    emath::{Align2, Pos2, Rangef, Rect, RotatedRect, TSTransform, Vec2},
     layers::{LayerId, PaintList, ShapeIdx},
     Color32, Context, FontId,
 };
@@ -12,7 +12,8 @@ use epaint::{
 
 /// Helper to paint shapes and text to a specific region on a specific layer.
 ///
-/// All coordinates are screen coordinates in the unit points (one point can consist of many physical pixels).
+/// All coordinates are screen coordinates in the unit points (one point can consist of many physical pixels),
+/// unless you use [`Self::push_transform`] to paint in another coordinate space.
 #[derive(Clone)]
 pub struct Painter {
     /// Source of fonts and destination of shapes
@@ -33,6 +34,15 @@ pub struct Painter {
     /// this value as the factor.
     /// This is used to make interfaces semi-transparent.
     opacity_factor: f32,
+
+    /// Applied to all shapes added to this painter,
+    /// transforming them from the current coordinate space to screen space.
+    ///
+    /// See [`Self::push_transform`].
+    transform: TSTransform,
+
+    /// The transforms that were active before each call to [`Self::push_transform`].
+    transform_stack: Vec<TSTransform>,
 }
 
 impl Painter {
@@ -44,6 +54,8 @@ impl Painter {
             clip_rect,
             fade_to_color: None,
             opacity_factor: 1.0,
+            transform: TSTransform::IDENTITY,
+            transform_stack: Vec::new(),
         }
     }
 
@@ -56,6 +68,8 @@ impl Painter {
             clip_rect: self.clip_rect,
             fade_to_color: None,
             opacity_factor: 1.0,
+            transform: self.transform,
+            transform_stack: self.transform_stack,
         }
     }
 
@@ -70,6 +84,8 @@ impl Painter {
             clip_rect: rect.intersect(self.clip_rect),
             fade_to_color: self.fade_to_color,
             opacity_factor: self.opacity_factor,
+            transform: self.transform,
+            transform_stack: self.transform_stack.clone(),
         }
     }
 
@@ -112,6 +128,52 @@ impl Painter {
         self.opacity_factor
     }
 
+    /// Transform everything painted from now on by `transform`,
+    /// until the matching call to [`Self::pop_transform`].
+    ///
+    /// The transform goes from the new (inner) coordinate space to the previous (outer) one,
+    /// so nested calls compose: the innermost transform is applied first.
+    ///
+    /// This affects the coordinates of all shapes added to the painter,
+    /// and the `round_*_to_pixel*` methods, but not the clip rectangle,
+    /// which is always in screen space.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{emath::TSTransform, pos2, vec2, Color32};
+    /// let mut painter = ui.painter().clone();
+    /// painter.push_transform(TSTransform::new(vec2(100.0, 100.0), 2.0));
+    /// // Painted at (110, 110) with a radius of 10 in screen space:
+    /// painter.circle_filled(pos2(5.0, 5.0), 5.0, Color32::RED);
+    /// painter.pop_transform();
+    /// # });
+    /// ```
+    pub fn push_transform(&mut self, transform: TSTransform) {
+        self.transform_stack.push(self.transform);
+        self.transform = self.transform * transform;
+    }
+
+    /// Restore the transform to what it was before the last call to [`Self::push_transform`].
+    ///
+    /// Does nothing (except for a debug assertion) if there is no matching [`Self::push_transform`].
+    pub fn pop_transform(&mut self) {
+        debug_assert!(
+            !self.transform_stack.is_empty(),
+            "Painter::pop_transform called without a matching push_transform"
+        );
+        if let Some(transform) = self.transform_stack.pop() {
+            self.transform = transform;
+        }
+    }
+
+    /// The transform from the current coordinate space to screen space.
+    ///
+    /// This is the composition of all the transforms given to [`Self::push_transform`].
+    #[inline]
+    pub fn transform(&self) -> TSTransform {
+        self.transform
+    }
+
     pub(crate) fn is_visible(&self) -> bool {
         self.fade_to_color != Some(Color32::TRANSPARENT)
     }
@@ -159,27 +221,41 @@ impl Painter {
     }
 
     /// Useful for pixel-perfect rendering.
+    ///
+    /// The value is treated as a length, so only the scaling of the
+    /// current [`Self::transform`] is taken into account.
+    /// Use [`Self::round_pos_to_pixels`] for positions.
     #[inline]
     pub fn round_to_pixel(&self, point: f32) -> f32 {
-        self.ctx().round_to_pixel(point)
+        let scaling = self.transform.scaling;
+        self.ctx().round_to_pixel(point * scaling) / scaling
     }
 
     /// Useful for pixel-perfect rendering.
+    ///
+    /// The vector is rounded after scaling it with the current [`Self::transform`].
     #[inline]
     pub fn round_vec_to_pixels(&self, vec: Vec2) -> Vec2 {
-        self.ctx().round_vec_to_pixels(vec)
+        let scaling = self.transform.scaling;
+        self.ctx().round_vec_to_pixels(vec * scaling) / scaling
     }
 
     /// Useful for pixel-perfect rendering.
+    ///
+    /// The position is rounded in screen space, after applying the current [`Self::transform`].
     #[inline]
     pub fn round_pos_to_pixels(&self, pos: Pos2) -> Pos2 {
-        self.ctx().round_pos_to_pixels(pos)
+        let screen_pos = self.ctx().round_pos_to_pixels(self.transform * pos);
+        self.transform.inverse() * screen_pos
     }
 
     /// Useful for pixel-perfect rendering.
+    ///
+    /// The rectangle is rounded in screen space, after applying the current [`Self::transform`].
     #[inline]
     pub fn round_rect_to_pixels(&self, rect: Rect) -> Rect {
-        self.ctx().round_rect_to_pixels(rect)
+        let screen_rect = self.ctx().round_rect_to_pixels(self.transform * rect);
+        self.transform.inverse() * screen_rect
     }
 }
 
@@ -191,6 +267,9 @@ impl Painter {
     }
 
     fn transform_shape(&self, shape: &mut Shape) {
+        if self.transform != TSTransform::IDENTITY {
+            shape.transform(self.transform);
+        }
         if let Some(fade_to_color) = self.fade_to_color {
             tint_shape_towards(shape, fade_to_color);
         }
@@ -201,7 +280,7 @@ impl Painter {
 
     /// It is up to the caller to make sure there is room for this.
     /// Can be used for free painting.
-    /// NOTE: all coordinates are screen coordinates!
+    /// NOTE: all coordinates are screen coordinates, unless you have called [`Self::push_transform`]!
     pub fn add(&self, shape: impl Into<Shape>) -> ShapeIdx {
         if self.fade_to_color == Some(Color32::TRANSPARENT) || self.opacity_factor == 0.0 {
             self.paint_list(|l| l.add(self.clip_rect, Shape::Noop))
@@ -219,7 +298,10 @@ impl Painter {
         if self.fade_to_color == Some(Color32::TRANSPARENT) || self.opacity_factor == 0.0 {
             return;
         }
-        if self.fade_to_color.is_some() || self.opacity_factor < 1.0 {
+        if self.fade_to_color.is_some()
+            || self.opacity_factor < 1.0
+            || self.transform != TSTransform::IDENTITY
+        {
             let shapes = shapes.into_iter().map(|mut shape| {
                 self.transform_shape(&mut shape);
                 shape
@@ -310,6 +392,23 @@ impl Painter {
         })
     }
 
+    /// Paints a line through many points, first removing points that would not be visible.
+    ///
+    /// Any point that deviates less than `tolerance` (in points) from the simplified line is dropped.
+    /// Use this for dense data, such as signal traces with millions of samples.
+    ///
+    /// A `tolerance` of half a physical pixel (`0.5 / pixels_per_point`) is visually lossless.
+    ///
+    /// See also [`Shape::line_simplified`].
+    pub fn line_simplified(
+        &self,
+        points: &[Pos2],
+        tolerance: f32,
+        stroke: impl Into<PathStroke>,
+    ) -> ShapeIdx {
+        self.add(Shape::line_simplified(points, tolerance, stroke))
+    }
+
     /// Paints a horizontal line.
     pub fn hline(&self, x: impl Into<Rangef>, y: f32, stroke: impl Into<PathStroke>) -> ShapeIdx {
         self.add(Shape::hline(x, y, stroke.into()))
@@ -386,6 +485,18 @@ impl Painter {
         self.add(RectShape::stroke(rect, rounding, stroke))
     }
 
+    /// A rectangle rotated around its center.
+    ///
+    /// Use [`RotatedRect::contains`] for hit-testing it.
+    pub fn rotated_rect(
+        &self,
+        rect: RotatedRect,
+        fill_color: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> ShapeIdx {
+        self.add(Shape::rotated_rect(rect, fill_color, stroke))
+    }
+
     /// Show an arrow starting at `origin` and going in the direction of `vec`, with the length `vec.length()`.
     pub fn arrow(&self, origin: Pos2, vec: Vec2, stroke: impl Into<Stroke>) {
         use crate::emath::*;
@@ -5,6 +5,28 @@ pub(crate) struct Placer {
     grid: Option<grid::GridLayout>,
     layout: Layout,
     region: Region,
+
+    /// The current row of a baseline aligned layout.
+    baseline_row: Option<BaselineRow>,
+}
+
+/// The text baseline of one row in a baseline aligned [`Layout`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BaselineRow {
+    /// The top of the row, used to detect when we move on to the next row.
+    pub top: f32,
+
+    /// Which row this is, counting from zero.
+    pub index: usize,
+
+    /// Where to put the baseline, relative to [`Self::top`].
+    pub baseline: f32,
+
+    /// The largest baseline of the widgets in this row so far, this frame.
+    pub max_baseline: f32,
+
+    /// The value last stored in memory for the next frame.
+    pub stored: Option<f32>,
 }
 
 impl Placer {
@@ -14,6 +36,7 @@ impl Placer {
             grid: None,
             layout,
             region,
+            baseline_row: None,
         }
     }
 
@@ -192,6 +215,27 @@ impl Placer {
     pub(crate) fn set_row_height(&mut self, height: f32) {
         self.layout.set_row_height(&mut self.region, height);
     }
+
+    /// The baseline state of the row starting at `row_top`.
+    ///
+    /// Returns `true` if this is a new row.
+    pub(crate) fn baseline_row(&mut self, row_top: f32) -> (&mut BaselineRow, bool) {
+        let index = match self.baseline_row {
+            Some(row) if row.top == row_top => {
+                return (self.baseline_row.as_mut().expect("checked above"), false);
+            }
+            Some(row) => row.index + 1,
+            None => 0,
+        };
+        let row = self.baseline_row.insert(BaselineRow {
+            top: row_top,
+            index,
+            baseline: 0.0,
+            max_baseline: 0.0,
+            stored: None,
+        });
+        (row, true)
+    }
 }
 
 impl Placer {
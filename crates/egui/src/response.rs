@@ -41,6 +41,11 @@ pub struct Response {
     /// (e.g. when inside a scroll area).
     pub interact_rect: Rect,
 
+    /// The y coordinate of the baseline of the (first row of) text in the widget, if any.
+    ///
+    /// Used to align text of different sizes, see [`crate::Layout::with_baseline_align`].
+    pub baseline: Option<f32>,
+
     /// The senses (click and/or drag) that the widget was interested in (if any).
     ///
     /// Note: if [`Self::enabled`] is `false`, then
@@ -292,6 +297,19 @@ impl Response {
         self.ctx.memory(|mem| mem.lost_focus(self.id))
     }
 
+    /// Capture the pointer until all pointer buttons are released,
+    /// so that e.g. a drag keeps working when the pointer leaves the window.
+    ///
+    /// See [`Context::capture_pointer`].
+    pub fn capture_pointer(&self) {
+        self.ctx.capture_pointer(self.id);
+    }
+
+    /// Has this widget captured the pointer? See [`Self::capture_pointer`].
+    pub fn has_pointer_capture(&self) -> bool {
+        self.ctx.pointer_capture_id() == Some(self.id)
+    }
+
     /// Request that this widget get keyboard focus.
     pub fn request_focus(&self) {
         self.ctx.memory_mut(|mem| mem.request_focus(self.id));
@@ -542,6 +560,76 @@ impl Response {
         self
     }
 
+    /// Like [`Self::on_hover_ui`], but for tooltips that are expensive to compute,
+    /// e.g. previews that need to query a database.
+    ///
+    /// `compute` is only called once the pointer has rested on the widget for `delay` seconds,
+    /// and then only once for as long as the tooltip stays open: the result is cached,
+    /// and passed to `add_contents` each frame.
+    /// Until the result is ready, a [`crate::Spinner`] is shown in the tooltip.
+    ///
+    /// The cached result is dropped when the tooltip closes.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let query_database = || "lots of rows".to_owned();
+    /// ui.label("Hover me").on_hover_ui_deferred(0.5, query_database, |ui, preview| {
+    ///     ui.label(preview);
+    /// });
+    /// # });
+    /// ```
+    #[doc(alias = "tooltip")]
+    pub fn on_hover_ui_deferred<T: 'static + Clone + Send + Sync>(
+        self,
+        delay: f32,
+        compute: impl FnOnce() -> T,
+        add_contents: impl FnOnce(&mut Ui, &T),
+    ) -> Self {
+        let cache_id = self.id.with("__deferred_hover_ui");
+        let rest_id = cache_id.with("rest");
+
+        if !(self.enabled && self.should_show_hover_ui()) {
+            self.ctx.data_mut(|d| {
+                d.remove::<T>(cache_id);
+                d.remove::<(Option<Pos2>, f64)>(rest_id);
+            });
+            return self;
+        }
+
+        let mut value = self.ctx.data(|d| d.get_temp::<T>(cache_id));
+        if value.is_none() {
+            // When did the pointer come to rest?
+            let (pointer_pos, now) = self.ctx.input(|i| (i.pointer.hover_pos(), i.time));
+            let rest_start = self.ctx.data_mut(|d| {
+                let (pos, since) = d.get_temp_mut_or_insert_with(rest_id, || (pointer_pos, now));
+                if *pos != pointer_pos {
+                    *pos = pointer_pos;
+                    *since = now;
+                }
+                *since
+            });
+
+            let time_til_compute = delay - (now - rest_start) as f32;
+            if time_til_compute <= 0.0 {
+                let computed = compute();
+                self.ctx
+                    .data_mut(|d| d.insert_temp(cache_id, computed.clone()));
+                value = Some(computed);
+            } else if let Ok(duration) = std::time::Duration::try_from_secs_f32(time_til_compute) {
+                self.ctx.request_repaint_after(duration);
+            }
+        }
+
+        self.show_tooltip_ui(|ui| {
+            if let Some(value) = &value {
+                add_contents(ui, value);
+            } else {
+                ui.spinner();
+            }
+        });
+        self
+    }
+
     /// Show this UI when hovering if the widget is disabled.
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         if !self.enabled && self.should_show_hover_ui() {
@@ -840,6 +928,8 @@ impl Response {
     pub fn widget_info(&self, make_info: impl Fn() -> crate::WidgetInfo) {
         use crate::output::OutputEvent;
 
+        self.ctx.report_interaction(self, &make_info);
+
         let event = if self.clicked() {
             Some(OutputEvent::Clicked(make_info()))
         } else if self.double_clicked() {
@@ -1040,6 +1130,7 @@ impl Response {
             id: self.id,
             rect: self.rect.union(other.rect),
             interact_rect: self.interact_rect.union(other.interact_rect),
+            baseline: self.baseline.or(other.baseline),
             sense: self.sense.union(other.sense),
             enabled: self.enabled || other.enabled,
             contains_pointer: self.contains_pointer || other.contains_pointer,
@@ -0,0 +1,230 @@
+//! Make keyboard shortcuts discoverable.
+//!
+//! Register the shortcuts of your app each frame with [`crate::Context::register_shortcut`],
+//! and show them to the user with a [`ShortcutCheatSheet`].
+
+use crate::{
+    Align2, Context, Grid, Id, Key, KeyboardShortcut, Modifiers, ScrollArea, TextEdit, Vec2, Window,
+};
+
+/// A keyboard shortcut registered with [`crate::Context::register_shortcut`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RegisteredShortcut {
+    pub shortcut: KeyboardShortcut,
+
+    /// Used for grouping, e.g. "File" or "Navigation".
+    pub category: String,
+
+    /// What the shortcut does, e.g. "Save".
+    pub description: String,
+}
+
+/// What [`ShortcutCheatSheet`] remembers between frames.
+#[derive(Clone, Default)]
+struct CheatSheetState {
+    open: bool,
+    search: String,
+}
+
+/// An overlay listing all keyboard shortcuts registered with [`crate::Context::register_shortcut`].
+///
+/// The shortcuts are grouped by category, and can be searched.
+/// Shortcuts that are used for more than one thing are marked as conflicts.
+///
+/// By default it is toggled by pressing `?` (when no text field has keyboard focus),
+/// and closed by pressing Escape.
+///
+/// Call [`Self::show`] once per frame, after registering your shortcuts.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// let save = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S);
+/// ctx.register_shortcut("File", "Save", save);
+/// if ctx.input_mut(|i| i.consume_shortcut(&save)) {
+///     // save
+/// }
+///
+/// egui::ShortcutCheatSheet::default().show(ctx);
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct ShortcutCheatSheet {
+    trigger: KeyboardShortcut,
+}
+
+impl Default for ShortcutCheatSheet {
+    fn default() -> Self {
+        Self {
+            trigger: KeyboardShortcut::new(Modifiers::NONE, Key::Questionmark),
+        }
+    }
+}
+
+impl ShortcutCheatSheet {
+    /// The shortcut that opens and closes the cheat sheet. Default: `?`.
+    #[inline]
+    pub fn trigger(mut self, trigger: KeyboardShortcut) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Open the cheat sheet, e.g. from a "Help" menu.
+    pub fn open(ctx: &Context) {
+        ctx.data_mut(|d| {
+            d.get_temp_mut_or_default::<CheatSheetState>(Self::id())
+                .open = true;
+        });
+    }
+
+    /// Is the cheat sheet currently shown?
+    pub fn is_open(ctx: &Context) -> bool {
+        ctx.data(|d| d.get_temp::<CheatSheetState>(Self::id()))
+            .map_or(false, |state| state.open)
+    }
+
+    fn id() -> Id {
+        Id::new("egui_shortcut_cheat_sheet")
+    }
+
+    pub fn show(self, ctx: &Context) {
+        let Self { trigger } = self;
+        let id = Self::id();
+
+        ctx.register_shortcut("Help", "Show keyboard shortcuts", trigger);
+
+        let mut state: CheatSheetState = ctx.data(|d| d.get_temp(id).unwrap_or_default());
+        let was_open = state.open;
+
+        if !ctx.wants_keyboard_input() && ctx.input_mut(|i| i.consume_shortcut(&trigger)) {
+            state.open = !state.open;
+        }
+        if ctx.input(|i| i.key_pressed(Key::Escape)) {
+            state.open = false;
+        }
+
+        let shortcuts = unique_shortcuts(ctx.registered_shortcuts());
+
+        let mut open = state.open;
+        Window::new("⌨ Keyboard shortcuts")
+            .id(id)
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+            .show(ctx, |ui| {
+                let search_response = ui.add(
+                    TextEdit::singleline(&mut state.search)
+                        .hint_text("Search")
+                        .desired_width(f32::INFINITY),
+                );
+                if !was_open {
+                    search_response.request_focus();
+                }
+
+                let search = state.search.to_lowercase();
+                let matches = |shortcut: &RegisteredShortcut| {
+                    search.is_empty()
+                        || shortcut.category.to_lowercase().contains(&search)
+                        || shortcut.description.to_lowercase().contains(&search)
+                        || ctx
+                            .format_shortcut(&shortcut.shortcut)
+                            .to_lowercase()
+                            .contains(&search)
+                };
+
+                ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                    let mut categories: Vec<&str> = vec![];
+                    for shortcut in &shortcuts {
+                        if !categories.contains(&shortcut.category.as_str()) {
+                            categories.push(&shortcut.category);
+                        }
+                    }
+
+                    for category in categories {
+                        let in_category: Vec<&RegisteredShortcut> = shortcuts
+                            .iter()
+                            .filter(|shortcut| shortcut.category == category && matches(shortcut))
+                            .collect();
+                        if in_category.is_empty() {
+                            continue;
+                        }
+
+                        ui.strong(category);
+                        Grid::new(id.with(category)).striped(true).show(ui, |ui| {
+                            for shortcut in in_category {
+                                ui.label(&shortcut.description);
+
+                                let text = ctx.format_shortcut(&shortcut.shortcut);
+                                let conflicts: Vec<String> = conflicts(&shortcuts, shortcut)
+                                    .map(|other| {
+                                        format!("{}: {}", other.category, other.description)
+                                    })
+                                    .collect();
+                                if conflicts.is_empty() {
+                                    ui.monospace(text);
+                                } else {
+                                    ui.colored_label(
+                                        ui.visuals().warn_fg_color,
+                                        format!("⚠ {text}"),
+                                    )
+                                    .on_hover_text(format!(
+                                        "Also used for:\n{}",
+                                        conflicts.join("\n")
+                                    ));
+                                }
+                                ui.end_row();
+                            }
+                        });
+                        ui.add_space(4.0);
+                    }
+                });
+            });
+        state.open &= open;
+
+        ctx.data_mut(|d| d.insert_temp(id, state));
+    }
+}
+
+/// Remove duplicates, e.g. from the same shortcut being registered by many list items.
+fn unique_shortcuts(shortcuts: Vec<RegisteredShortcut>) -> Vec<RegisteredShortcut> {
+    let mut unique: Vec<RegisteredShortcut> = Vec::with_capacity(shortcuts.len());
+    for shortcut in shortcuts {
+        if !unique.contains(&shortcut) {
+            unique.push(shortcut);
+        }
+    }
+    unique
+}
+
+/// Other shortcuts that are triggered by the same key combination.
+fn conflicts<'a>(
+    shortcuts: &'a [RegisteredShortcut],
+    shortcut: &'a RegisteredShortcut,
+) -> impl Iterator<Item = &'a RegisteredShortcut> {
+    shortcuts
+        .iter()
+        .filter(move |other| other.shortcut == shortcut.shortcut && *other != shortcut)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conflicts() {
+        let shortcut = |key, description: &str| RegisteredShortcut {
+            shortcut: KeyboardShortcut::new(Modifiers::COMMAND, key),
+            category: "File".to_owned(),
+            description: description.to_owned(),
+        };
+        let shortcuts = unique_shortcuts(vec![
+            shortcut(Key::S, "Save"),
+            shortcut(Key::S, "Save"),
+            shortcut(Key::S, "Search"),
+            shortcut(Key::O, "Open"),
+        ]);
+        assert_eq!(shortcuts.len(), 3);
+        assert_eq!(conflicts(&shortcuts, &shortcuts[0]).count(), 1);
+        assert_eq!(conflicts(&shortcuts, &shortcuts[2]).count(), 0);
+    }
+}
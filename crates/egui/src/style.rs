@@ -4,7 +4,7 @@
 
 use std::collections::BTreeMap;
 
-use epaint::{Rounding, Shadow, Stroke};
+use epaint::{Rounding, Shadow, ShadowFalloff, Stroke};
 
 use crate::{
     ecolor::*, emath::*, ComboBox, CursorIcon, FontFamily, FontId, Grid, Margin, Response,
@@ -182,6 +182,11 @@ pub struct Style {
     /// The style to use for [`DragValue`] text.
     pub drag_value_text_style: TextStyle,
 
+    /// How [`DragValue`], [`crate::Slider`] etc show numbers, e.g. with `,` as the decimal separator.
+    ///
+    /// Default: [`NumberFormat::PLAIN`], i.e. `1234.56`.
+    pub number_format: NumberFormat,
+
     /// If set, labels, buttons, etc. will use this to determine whether to wrap the text at the
     /// right edge of the [`Ui`] they are in. By default, this is `None`.
     ///
@@ -213,6 +218,12 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// How [`crate::Window`]s animate when they open and close.
+    pub window_animation: AreaAnimation,
+
+    /// How popups, menus and tooltips animate when they open and close.
+    pub popup_animation: AreaAnimation,
+
     /// Options to help debug why egui behaves strangely.
     ///
     /// Only available in debug builds.
@@ -766,6 +777,9 @@ pub struct Visuals {
     /// A good color for error text (e.g. red).
     pub error_fg_color: Color32,
 
+    /// A good color for text confirming that something is right (e.g. green).
+    pub success_fg_color: Color32,
+
     pub window_rounding: Rounding,
     pub window_shadow: Shadow,
     pub window_fill: Color32,
@@ -847,6 +861,27 @@ impl Visuals {
         self.widgets.active.text_color()
     }
 
+    /// A readable color for text on top of `fill`, e.g. a button or tag with a custom fill color.
+    ///
+    /// This is `text_color` if it has enough contrast to `fill`
+    /// (see [`crate::ecolor::MIN_TEXT_CONTRAST`]), and otherwise a darker or lighter version of it.
+    /// A translucent `fill` is assumed to be on top of [`Self::panel_fill`].
+    pub fn text_color_on(&self, fill: Color32, text_color: Color32) -> Color32 {
+        if fill.a() == 0 {
+            return text_color;
+        }
+        let background = if fill.is_opaque() {
+            fill
+        } else {
+            // Premultiplied alpha blending, like the painter does:
+            let alpha = fill.a() as f32 / 255.0;
+            let [r, g, b, _] = self.panel_fill.to_array();
+            let over = |fill: u8, bg: u8| fill.saturating_add((bg as f32 * (1.0 - alpha)) as u8);
+            Color32::from_rgb(over(fill.r(), r), over(fill.g(), g), over(fill.b(), b))
+        };
+        crate::ecolor::readable_text_color(background, text_color, crate::ecolor::MIN_TEXT_CONTRAST)
+    }
+
     /// Window background color.
     #[inline(always)]
     pub fn window_fill(&self) -> Color32 {
@@ -895,6 +930,89 @@ pub enum HandleShape {
     },
 }
 
+/// How an [`crate::Area`] (and therefore a [`crate::Window`] or popup) animates when it opens and closes.
+///
+/// The animation lasts [`Style::animation_time`] seconds.
+/// While closing, the area does not react to any input.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum AreaAnimation {
+    /// Appear and disappear instantly.
+    None,
+
+    /// Fade in and out.
+    Fade,
+
+    /// Fade, and grow from (or shrink towards) the pivot of the area.
+    ///
+    /// For popups the pivot is where they are attached to their widget.
+    Scale {
+        /// The scale when fully closed, e.g. `0.9`.
+        from: f32,
+    },
+
+    /// Fade, and slide in from (or out to) the given offset, in points.
+    Slide {
+        /// The offset when fully closed.
+        from: Vec2,
+    },
+}
+
+impl AreaAnimation {
+    /// The opacity at the given point in the animation, where `t = 0` is closed and `t = 1` is open.
+    pub fn opacity(&self, t: f32) -> f32 {
+        match self {
+            Self::None => 1.0,
+            Self::Fade | Self::Scale { .. } | Self::Slide { .. } => t,
+        }
+    }
+
+    /// The transform at the given point in the animation, where `t = 0` is closed and `t = 1` is open.
+    ///
+    /// `pivot` is the point that stays put while scaling.
+    pub fn transform(&self, t: f32, pivot: Pos2) -> emath::TSTransform {
+        match *self {
+            Self::None | Self::Fade => emath::TSTransform::IDENTITY,
+            Self::Scale { from } => {
+                let scale = lerp(from..=1.0, t);
+                emath::TSTransform::new((1.0 - scale) * pivot.to_vec2(), scale)
+            }
+            Self::Slide { from } => emath::TSTransform::from_translation((1.0 - t) * from),
+        }
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.radio_value(self, Self::None, "None");
+            ui.radio_value(self, Self::Fade, "Fade");
+            if ui
+                .radio(matches!(self, Self::Scale { .. }), "Scale")
+                .clicked()
+            {
+                *self = Self::Scale { from: 0.9 };
+            }
+            if ui
+                .radio(matches!(self, Self::Slide { .. }), "Slide")
+                .clicked()
+            {
+                *self = Self::Slide {
+                    from: vec2(0.0, -8.0),
+                };
+            }
+            match self {
+                Self::None | Self::Fade => {}
+                Self::Scale { from } => {
+                    ui.add(Slider::new(from, 0.0..=1.0).text("From"));
+                }
+                Self::Slide { from } => {
+                    ui.add(DragValue::new(&mut from.x).prefix("x: "));
+                    ui.add(DragValue::new(&mut from.y).prefix("y: "));
+                }
+            }
+        });
+    }
+}
+
 /// The visuals of widgets for different states of interaction.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -1060,12 +1178,15 @@ impl Default for Style {
             override_text_style: None,
             text_styles: default_text_styles(),
             drag_value_text_style: TextStyle::Button,
+            number_format: Default::default(),
             wrap: None,
             wrap_mode: None,
             spacing: Spacing::default(),
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 1.0 / 12.0,
+            window_animation: AreaAnimation::Fade,
+            popup_animation: AreaAnimation::Fade,
             #[cfg(debug_assertions)]
             debug: Default::default(),
             explanation_tooltips: false,
@@ -1131,6 +1252,7 @@ impl Visuals {
             code_bg_color: Color32::from_gray(64),
             warn_fg_color: Color32::from_rgb(255, 143, 0), // orange
             error_fg_color: Color32::from_rgb(255, 0, 0),  // red
+            success_fg_color: Color32::from_rgb(0, 200, 80), // green
 
             window_rounding: Rounding::same(6.0),
             window_shadow: Shadow {
@@ -1138,6 +1260,7 @@ impl Visuals {
                 blur: 15.0,
                 spread: 0.0,
                 color: Color32::from_black_alpha(96),
+                falloff: ShadowFalloff::Linear,
             },
             window_fill: Color32::from_gray(27),
             window_stroke: Stroke::new(1.0, Color32::from_gray(60)),
@@ -1152,6 +1275,7 @@ impl Visuals {
                 blur: 8.0,
                 spread: 0.0,
                 color: Color32::from_black_alpha(96),
+                falloff: ShadowFalloff::Linear,
             },
 
             resize_corner_size: 12.0,
@@ -1188,12 +1312,14 @@ impl Visuals {
             code_bg_color: Color32::from_gray(230),
             warn_fg_color: Color32::from_rgb(255, 100, 0), // slightly orange red. it's difficult to find a warning color that pops on bright background.
             error_fg_color: Color32::from_rgb(255, 0, 0),  // red
+            success_fg_color: Color32::from_rgb(0, 140, 40), // darker green, to be readable on bright background
 
             window_shadow: Shadow {
                 offset: vec2(10.0, 20.0),
                 blur: 15.0,
                 spread: 0.0,
                 color: Color32::from_black_alpha(25),
+                falloff: ShadowFalloff::Linear,
             },
             window_fill: Color32::from_gray(248),
             window_stroke: Stroke::new(1.0, Color32::from_gray(190)),
@@ -1205,6 +1331,7 @@ impl Visuals {
                 blur: 8.0,
                 spread: 0.0,
                 color: Color32::from_black_alpha(25),
+                falloff: ShadowFalloff::Linear,
             },
 
             text_cursor: TextCursorStyle {
@@ -1355,12 +1482,15 @@ impl Style {
             override_text_style,
             text_styles,
             drag_value_text_style,
+            number_format,
             wrap: _,
             wrap_mode: _,
             spacing,
             interaction,
             visuals,
             animation_time,
+            window_animation,
+            popup_animation,
             #[cfg(debug_assertions)]
             debug,
             explanation_tooltips,
@@ -1415,6 +1545,22 @@ impl Style {
                 });
             ui.end_row();
 
+            ui.label("Number format");
+            crate::ComboBox::from_id_source("number_format")
+                .selected_text(number_format.localize("1234.5"))
+                .show_ui(ui, |ui| {
+                    for format in [
+                        NumberFormat::PLAIN,
+                        NumberFormat::ENGLISH,
+                        NumberFormat::EUROPEAN,
+                        NumberFormat::GERMAN,
+                        NumberFormat::ARABIC,
+                    ] {
+                        ui.selectable_value(number_format, format, format.localize("1234.5"));
+                    }
+                });
+            ui.end_row();
+
             ui.label("Animation duration");
             ui.add(
                 DragValue::new(animation_time)
@@ -1423,6 +1569,14 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Window animation");
+            window_animation.ui(ui);
+            ui.end_row();
+
+            ui.label("Popup animation");
+            popup_animation.ui(ui);
+            ui.end_row();
         });
 
         ui.collapsing("🔠 Text Styles", |ui| text_styles_ui(ui, text_styles));
@@ -1806,6 +1960,7 @@ impl Visuals {
             code_bg_color,
             warn_fg_color,
             error_fg_color,
+            success_fg_color,
 
             window_rounding,
             window_shadow,
@@ -1862,6 +2017,7 @@ impl Visuals {
 
             ui_text_color(ui, warn_fg_color, RichText::new("Warnings"));
             ui_text_color(ui, error_fg_color, RichText::new("Errors"));
+            ui_text_color(ui, success_fg_color, RichText::new("Success"));
 
             ui_text_color(ui, hyperlink_color, "hyperlink_color");
 
@@ -2259,6 +2415,7 @@ impl Widget for &mut Shadow {
             blur,
             spread,
             color,
+            falloff,
         } = self;
 
         ui.vertical(|ui| {
@@ -2290,6 +2447,12 @@ impl Widget for &mut Shadow {
                         .clamp_range(0.0..=100.0)
                         .prefix("spread: "),
                 );
+                ui.end_row();
+
+                ui.selectable_value(falloff, ShadowFalloff::Linear, "Linear");
+                ui.selectable_value(falloff, ShadowFalloff::Gaussian, "Gaussian")
+                    .on_hover_text("Smoother, but uses more triangles");
+                ui.end_row();
             });
             ui.color_edit_button_srgba(color);
         })
@@ -0,0 +1,157 @@
+//! Running async tasks from the UI, see [`crate::Context::spawn`].
+
+use std::{future::Future, pin::Pin, sync::Arc, sync::OnceLock};
+
+/// `Send` on native, and nothing on the web (where everything runs on one thread).
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send> MaybeSend for T {}
+
+/// `Send` on native, and nothing on the web (where everything runs on one thread).
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T {}
+
+/// A type-erased future, as handed to a [`Spawner`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// A type-erased future, as handed to a [`Spawner`].
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + 'static>>;
+
+/// Runs futures to completion on some executor.
+///
+/// See [`crate::Context::set_spawner`].
+pub type Spawner = Arc<dyn Fn(BoxFuture) + Send + Sync>;
+
+// ----------------------------------------------------------------------------
+
+/// The result of a task started with [`crate::Context::spawn`].
+///
+/// Poll it each frame with [`Self::ready`].
+/// A repaint is requested when the task finishes, so you don't need to repaint while waiting.
+///
+/// Dropping the handle does not cancel the task.
+pub struct TaskHandle<T> {
+    result: Arc<OnceLock<T>>,
+}
+
+impl<T> TaskHandle<T> {
+    pub(crate) fn new() -> (Self, Arc<OnceLock<T>>) {
+        let result = Arc::new(OnceLock::new());
+        (
+            Self {
+                result: result.clone(),
+            },
+            result,
+        )
+    }
+
+    /// Has the task finished?
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.result.get().is_some()
+    }
+
+    /// The result of the task, if it has finished.
+    #[inline]
+    pub fn ready(&self) -> Option<&T> {
+        self.result.get()
+    }
+
+    /// The result of the task, if it has finished.
+    #[inline]
+    pub fn ready_mut(&mut self) -> Option<&mut T> {
+        Arc::get_mut(&mut self.result).and_then(OnceLock::get_mut)
+    }
+
+    /// Take the result of the task if it has finished.
+    ///
+    /// # Errors
+    /// Gives back the handle if the task is still running.
+    pub fn try_take(self) -> Result<T, Self> {
+        if !self.is_ready() {
+            return Err(self);
+        }
+        match Arc::try_unwrap(self.result) {
+            Ok(result) => Ok(result.into_inner().expect("checked by is_ready")),
+            // The task has stored its result, but not yet let go of it.
+            Err(result) => Err(Self { result }),
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for TaskHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskHandle")
+            .field("result", &self.result.get())
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Used when no [`Spawner`] has been set: run each task on its own thread.
+///
+/// This knows nothing about any particular runtime,
+/// so futures that need e.g. a `tokio` reactor won't work here.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn spawn_on_thread(future: BoxFuture) {
+    std::thread::Builder::new()
+        .name("egui_task".to_owned())
+        .spawn(move || block_on(future))
+        .expect("Failed to spawn a thread for an egui task");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn block_on(mut future: BoxFuture) {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = std::task::Context::from_waker(&waker);
+    while future.as_mut().poll(&mut cx).is_pending() {
+        std::thread::park();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawn_without_spawner() {
+        let ctx = crate::Context::default();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        ctx.set_request_repaint_callback(move |_| {
+            sender.send(()).ok();
+        });
+        let handle = ctx.spawn(async { 40 + 2 });
+
+        // Wait for the repaint that the task requests after setting its result:
+        while !(handle.is_ready() && ctx.has_requested_repaint()) {
+            receiver
+                .recv_timeout(std::time::Duration::from_secs(10))
+                .expect("Task never finished");
+        }
+        assert_eq!(handle.ready(), Some(&42));
+    }
+
+    #[test]
+    fn test_custom_spawner() {
+        let ctx = crate::Context::default();
+        ctx.set_spawner(block_on);
+        let handle = ctx.spawn(async { "done" });
+        assert_eq!(handle.try_take().ok(), Some("done"));
+    }
+}
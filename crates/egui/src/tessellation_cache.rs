@@ -0,0 +1,280 @@
+use epaint::{
+    tessellator::Tessellator, ClippedPrimitive, ClippedShape, TessellationOptions, TextureId,
+};
+
+use crate::{LayerId, ViewportId};
+
+/// The tessellation of one layer in the previous frame.
+struct CachedLayer {
+    shapes: Vec<ClippedShape>,
+    primitives: Vec<ClippedPrimitive>,
+}
+
+/// Everything that affects the tessellation, apart from the shapes themselves.
+#[derive(Clone, Copy, PartialEq)]
+struct TessellationKey {
+    pixels_per_point: f32,
+    options: TessellationOptions,
+    font_tex_size: [usize; 2],
+    subpixel_glyph_texture: Option<TextureId>,
+}
+
+/// Reuses last frame's tessellation of layers whose shapes haven't changed.
+///
+/// Enabled with [`crate::Options::cache_tessellation`].
+#[derive(Default)]
+pub(crate) struct TessellationCache {
+    /// The layers of the latest ended frame, in paint order, with their number of shapes.
+    layers: Vec<(LayerId, usize)>,
+
+    /// The viewport that [`Self::layers`] belong to.
+    viewport_id: ViewportId,
+
+    key: Option<TessellationKey>,
+
+    cached: ahash::HashMap<(ViewportId, LayerId), CachedLayer>,
+}
+
+impl TessellationCache {
+    /// Remember how the shapes of the frame that just ended are split into layers.
+    pub fn set_layers(&mut self, viewport_id: ViewportId, layers: Vec<(LayerId, usize)>) {
+        self.viewport_id = viewport_id;
+        self.layers = layers;
+    }
+
+    /// Forget the cached tessellation of this layer.
+    pub fn invalidate_layer(&mut self, layer_id: LayerId) {
+        self.cached
+            .retain(|(_, cached_layer_id), _| *cached_layer_id != layer_id);
+    }
+
+    /// Forget everything.
+    pub fn clear(&mut self) {
+        self.cached.clear();
+    }
+
+    pub fn tessellate(
+        &mut self,
+        tessellator: &mut Tessellator,
+        shapes: Vec<ClippedShape>,
+        pixels_per_point: f32,
+        options: TessellationOptions,
+        font_tex_size: [usize; 2],
+        subpixel_glyph_texture: Option<TextureId>,
+    ) -> Vec<ClippedPrimitive> {
+        let key = TessellationKey {
+            pixels_per_point,
+            options,
+            font_tex_size,
+            subpixel_glyph_texture,
+        };
+        if self.key != Some(key) {
+            self.key = Some(key);
+            self.cached.clear();
+        }
+
+        let num_shapes: usize = self.layers.iter().map(|(_, count)| count).sum();
+        if num_shapes != shapes.len() {
+            // These shapes are not from the latest frame, so we don't know the layers.
+            return tessellator.tessellate_shapes(shapes);
+        }
+
+        let viewport_id = self.viewport_id;
+        let mut shapes = shapes.into_iter();
+        let mut primitives = Vec::new();
+
+        for &(layer_id, count) in &self.layers {
+            let layer_shapes: Vec<ClippedShape> = shapes.by_ref().take(count).collect();
+
+            if contains_precomputed_mesh(&layer_shapes) {
+                // The meshes can change without the shapes changing, so never cache these.
+                primitives.extend(tessellator.tessellate_shapes(layer_shapes));
+                continue;
+            }
+
+            let cache_key = (viewport_id, layer_id);
+            let is_unchanged = self
+                .cached
+                .get(&cache_key)
+                .map_or(false, |cached| cached.shapes == layer_shapes);
+            if !is_unchanged {
+                let layer_primitives = tessellator.tessellate_shapes(layer_shapes.clone());
+                self.cached.insert(
+                    cache_key,
+                    CachedLayer {
+                        shapes: layer_shapes,
+                        primitives: layer_primitives,
+                    },
+                );
+            }
+            primitives.extend_from_slice(&self.cached[&cache_key].primitives);
+        }
+
+        // Forget layers that are no longer painted:
+        let layer_ids: ahash::HashSet<LayerId> =
+            self.layers.iter().map(|(layer_id, _)| *layer_id).collect();
+        self.cached.retain(|(cached_viewport_id, layer_id), _| {
+            *cached_viewport_id != viewport_id || layer_ids.contains(layer_id)
+        });
+
+        primitives
+    }
+}
+
+fn contains_precomputed_mesh(shapes: &[ClippedShape]) -> bool {
+    fn contains(shape: &epaint::Shape) -> bool {
+        match shape {
+            epaint::Shape::PrecomputedMesh(..) => true,
+            epaint::Shape::Vec(shapes) => shapes.iter().any(contains),
+            _ => false,
+        }
+    }
+    shapes.iter().any(|clipped| contains(&clipped.shape))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn meshes(ctx: &Context, text: &str) -> Vec<Mesh> {
+        let output = ctx.run(RawInput::default(), |ctx| {
+            SidePanel::left("static").show(ctx, |ui| {
+                ui.label("This panel never changes");
+            });
+            CentralPanel::default().show(ctx, |ui| {
+                ui.label(text);
+            });
+        });
+        ctx.tessellate(output.shapes, output.pixels_per_point)
+            .into_iter()
+            .filter_map(|clipped| match clipped.primitive {
+                epaint::Primitive::Mesh(mesh) => Some(mesh),
+                epaint::Primitive::PrecomputedMesh(_) | epaint::Primitive::Callback(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cached_tessellation_matches_uncached() {
+        let cached = Context::default();
+        cached.options_mut(|o| o.cache_tessellation = true);
+        let uncached = Context::default();
+
+        for text in ["Hello", "Hello", "World", "World"] {
+            assert_eq!(meshes(&cached, text), meshes(&uncached, text));
+        }
+    }
+
+    #[test]
+    fn test_cached_tessellation_of_several_layers() {
+        /// The triangles that are painted.
+        ///
+        /// Meshes of different layers are not merged when caching, so we can't compare the meshes.
+        fn triangles(
+            ctx: &Context,
+            window_text: &str,
+            tooltip_text: &str,
+        ) -> Vec<[epaint::Vertex; 3]> {
+            let output = ctx.run(RawInput::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.label("Background");
+                });
+                Window::new("Window").show(ctx, |ui| {
+                    ui.label(window_text);
+                });
+                Area::new("tooltip".into())
+                    .order(Order::Tooltip)
+                    .fixed_pos(pos2(100.0, 100.0))
+                    .show(ctx, |ui| {
+                        ui.label(tooltip_text);
+                    });
+            });
+            ctx.tessellate(output.shapes, output.pixels_per_point)
+                .into_iter()
+                .filter_map(|clipped| match clipped.primitive {
+                    epaint::Primitive::Mesh(mesh) => Some(mesh),
+                    epaint::Primitive::PrecomputedMesh(_) | epaint::Primitive::Callback(_) => None,
+                })
+                .flat_map(|mesh| {
+                    mesh.indices
+                        .chunks_exact(3)
+                        .map(|triangle| [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize]))
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        }
+
+        let cached = Context::default();
+        cached.options_mut(|o| o.cache_tessellation = true);
+        let uncached = Context::default();
+
+        // Change one layer at a time:
+        for (window_text, tooltip_text) in [
+            ("Hello", "Tip"),
+            ("Hello", "Tip"),
+            ("World", "Tip"),
+            ("World", "Other tip"),
+            ("Hello", "Tip"),
+        ] {
+            assert_eq!(
+                triangles(&cached, window_text, tooltip_text),
+                triangles(&uncached, window_text, tooltip_text)
+            );
+        }
+    }
+
+    #[test]
+    fn test_only_changed_layers_are_tessellated() {
+        use super::TessellationCache;
+        use epaint::{
+            tessellator::Tessellator, ClippedPrimitive, ClippedShape, Shape, TessellationOptions,
+        };
+
+        let background = LayerId::background();
+        let foreground = LayerId::new(Order::Foreground, Id::new("foreground"));
+        let circle = |radius: f32| ClippedShape {
+            clip_rect: Rect::EVERYTHING,
+            shape: Shape::circle_filled(pos2(50.0, 50.0), radius, Color32::RED),
+        };
+        let tessellate = |cache: &mut TessellationCache, feathering: bool, shapes| {
+            // The cache doesn't know about the feathering of the tessellator,
+            // so we can tell which layers it tessellated again:
+            let options = TessellationOptions::default();
+            let mut tessellator = Tessellator::new(
+                1.0,
+                TessellationOptions {
+                    feathering,
+                    ..options
+                },
+                [1, 1],
+                vec![],
+            );
+            cache.tessellate(&mut tessellator, shapes, 1.0, options, [1, 1], None)
+        };
+
+        let mesh = |primitive: &ClippedPrimitive| match &primitive.primitive {
+            epaint::Primitive::Mesh(mesh) => mesh.clone(),
+            _ => panic!("Expected a mesh"),
+        };
+
+        let mut cache = TessellationCache::default();
+        cache.set_layers(ViewportId::ROOT, vec![(background, 1), (foreground, 1)]);
+        let first = tessellate(&mut cache, true, vec![circle(10.0), circle(20.0)]);
+        assert_eq!(first.len(), 2);
+
+        // Only the foreground changed:
+        let second = tessellate(&mut cache, false, vec![circle(10.0), circle(30.0)]);
+        assert_eq!(
+            mesh(&second[0]),
+            mesh(&first[0]),
+            "The background should be reused"
+        );
+        let uncached = tessellate(&mut TessellationCache::default(), false, vec![circle(30.0)]);
+        assert_eq!(mesh(&second[1]), mesh(&uncached[0]));
+
+        // A layer that is no longer painted is forgotten:
+        cache.set_layers(ViewportId::ROOT, vec![(foreground, 1)]);
+        tessellate(&mut cache, false, vec![circle(30.0)]);
+        assert_eq!(cache.cached.len(), 1);
+    }
+}
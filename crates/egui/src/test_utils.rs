@@ -0,0 +1,51 @@
+//! Helpers for testing widgets by feeding a [`Context`] input events, one frame at a time.
+//!
+//! Only compiled for the tests of egui, or with the `test_utils` feature.
+
+use crate::{CentralPanel, Context, Event, Key, PointerButton, Pos2, RawInput, Ui};
+
+/// Run one frame with the given `events`, show `add_contents` in a [`CentralPanel`],
+/// and return what it returned.
+pub fn run<R>(ctx: &Context, events: Vec<Event>, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+    let input = RawInput {
+        events,
+        ..Default::default()
+    };
+    let mut output = None;
+    let _ = ctx.run(input, |ctx| {
+        output = Some(CentralPanel::default().show(ctx, add_contents).inner);
+    });
+    output.expect("the central panel is always shown")
+}
+
+/// A press of `key`, without modifiers.
+pub fn press(key: Key) -> Event {
+    Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: Default::default(),
+    }
+}
+
+/// Move the pointer to `pos`, and press or release the primary button there if `pressed` is set.
+pub fn pointer(pos: Pos2, pressed: Option<bool>) -> Vec<Event> {
+    let mut events = vec![Event::PointerMoved(pos)];
+    if let Some(pressed) = pressed {
+        events.push(Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        });
+    }
+    events
+}
+
+/// Click the primary button at `pos`.
+pub fn click(pos: Pos2) -> Vec<Event> {
+    let mut events = pointer(pos, Some(true));
+    events.extend(pointer(pos, Some(false)));
+    events
+}
@@ -1,10 +1,16 @@
+use std::{ops::Range, sync::Arc};
+
+use epaint::text::LayoutJob;
+
 use crate::{
     layers::ShapeIdx, text::CCursor, text_selection::CCursorRange, Context, CursorIcon, Event,
     Galley, Id, LayerId, Pos2, Rect, Response, Ui,
 };
 
 use super::{
-    text_cursor_state::cursor_rect, visuals::paint_text_selection, CursorRange, TextCursorState,
+    text_cursor_state::{byte_index_from_char_index, cursor_rect},
+    visuals::paint_text_selection,
+    CursorRange, TextCursorState,
 };
 
 /// Turn on to help debug this
@@ -96,9 +102,29 @@ struct CurrentSelection {
     pub secondary: WidgetTextCursor,
 }
 
+/// The selected part of the text of one label.
+///
+/// The parts are only joined into one text when it is needed, e.g. when copying.
+#[derive(Clone, Debug)]
+struct SelectedPart {
+    /// Has the text of the label, shared with its [`Galley`].
+    job: Arc<LayoutJob>,
+    byte_range: Range<usize>,
+    galley_rect: Rect,
+    row_height: f32,
+}
+
+impl SelectedPart {
+    fn text(&self) -> &str {
+        &self.job.text[self.byte_range.clone()]
+    }
+}
+
 /// Handles text selection in labels (NOT in [`crate::TextEdit`])s.
 ///
-/// One state for all labels, because we only support text selection in one label at a time.
+/// One state for all labels. With [`crate::style::Interaction::multi_widget_text_select`],
+/// a selection can span several labels in the same layer, in the order they are shown,
+/// and copying it copies the text of all of them.
 #[derive(Clone, Debug)]
 pub struct LabelSelectionState {
     /// The current selection, if any.
@@ -119,9 +145,11 @@ pub struct LabelSelectionState {
     /// Have we reached the widget containing the secondary selection?
     has_reached_secondary: bool,
 
-    /// Accumulated text to copy.
-    text_to_copy: String,
-    last_copied_galley_rect: Option<Rect>,
+    /// The selected text of all labels shown so far this frame, in the order they were shown.
+    selected_parts: Vec<SelectedPart>,
+
+    /// All the [`Self::selected_parts`] of the previous frame.
+    selected_parts_last_frame: Vec<SelectedPart>,
 
     /// Painted selections this frame.
     painted_shape_idx: Vec<ShapeIdx>,
@@ -137,8 +165,8 @@ impl Default for LabelSelectionState {
             is_dragging: Default::default(),
             has_reached_primary: Default::default(),
             has_reached_secondary: Default::default(),
-            text_to_copy: Default::default(),
-            last_copied_galley_rect: Default::default(),
+            selected_parts: Default::default(),
+            selected_parts_last_frame: Default::default(),
             painted_shape_idx: Default::default(),
         }
     }
@@ -178,8 +206,7 @@ impl LabelSelectionState {
         state.any_hovered = false;
         state.has_reached_primary = false;
         state.has_reached_secondary = false;
-        state.text_to_copy.clear();
-        state.last_copied_galley_rect = None;
+        state.selected_parts.clear();
         state.painted_shape_idx.clear();
 
         state.store(ctx);
@@ -223,10 +250,14 @@ impl LabelSelectionState {
             state.is_dragging = false;
         }
 
-        let text_to_copy = std::mem::take(&mut state.text_to_copy);
-        if !text_to_copy.is_empty() {
-            ctx.copy_text(text_to_copy);
+        let mut selected_parts = std::mem::take(&mut state.selected_parts);
+        if state.selection.is_none() {
+            selected_parts.clear();
+        }
+        if !selected_parts.is_empty() && got_copy_event(ctx) {
+            ctx.copy_text(join_selected_parts(&selected_parts));
         }
+        state.selected_parts_last_frame = selected_parts;
 
         state.store(ctx);
     }
@@ -237,53 +268,46 @@ impl LabelSelectionState {
 
     pub fn clear_selection(&mut self) {
         self.selection = None;
+        self.selected_parts_last_frame.clear();
     }
 
-    fn copy_text(&mut self, galley_pos: Pos2, galley: &Galley, cursor_range: &CursorRange) {
-        let new_galley_rect = Rect::from_min_size(galley_pos, galley.size());
-        let new_text = selected_text(galley, cursor_range);
-        if new_text.is_empty() {
-            return;
-        }
+    /// The selected text of all labels, in the order they were shown, as of the end of last frame.
+    ///
+    /// This is what a copy command copies (e.g. <kbd>Ctrl</kbd>+<kbd>C</kbd>).
+    pub fn selected_text(&self) -> String {
+        join_selected_parts(&self.selected_parts_last_frame)
+    }
 
-        if self.text_to_copy.is_empty() {
-            self.text_to_copy = new_text;
-            self.last_copied_galley_rect = Some(new_galley_rect);
-            return;
+    /// Copy the selected text of all labels to the clipboard,
+    /// e.g. from a "Copy" button in a context menu.
+    ///
+    /// Returns `false` if there was nothing to copy.
+    pub fn copy_selected_text(ctx: &Context) -> bool {
+        let selected_text = Self::load(ctx).selected_text();
+        if selected_text.is_empty() {
+            false
+        } else {
+            ctx.copy_text(selected_text);
+            true
         }
+    }
 
-        let Some(last_copied_galley_rect) = self.last_copied_galley_rect else {
-            self.text_to_copy = new_text;
-            self.last_copied_galley_rect = Some(new_galley_rect);
+    fn append_selected_text(
+        &mut self,
+        galley_pos: Pos2,
+        galley: &Galley,
+        cursor_range: &CursorRange,
+    ) {
+        let byte_range = selected_byte_range(galley, cursor_range);
+        if byte_range.is_empty() {
             return;
-        };
-
-        // We need to append or prepend the new text to the already copied text.
-        // We need to do so intelligently.
-
-        if last_copied_galley_rect.bottom() <= new_galley_rect.top() {
-            self.text_to_copy.push('\n');
-            let vertical_distance = new_galley_rect.top() - last_copied_galley_rect.bottom();
-            if estimate_row_height(galley) * 0.5 < vertical_distance {
-                self.text_to_copy.push('\n');
-            }
-        } else {
-            let existing_ends_with_space =
-                self.text_to_copy.chars().last().map(|c| c.is_whitespace());
-
-            let new_text_starts_with_space_or_punctuation = new_text
-                .chars()
-                .next()
-                .map_or(false, |c| c.is_whitespace() || c.is_ascii_punctuation());
-
-            if existing_ends_with_space == Some(false) && !new_text_starts_with_space_or_punctuation
-            {
-                self.text_to_copy.push(' ');
-            }
         }
-
-        self.text_to_copy.push_str(&new_text);
-        self.last_copied_galley_rect = Some(new_galley_rect);
+        self.selected_parts.push(SelectedPart {
+            job: galley.job.clone(),
+            byte_range,
+            galley_rect: Rect::from_min_size(galley_pos, galley.size()),
+            row_height: estimate_row_height(galley),
+        });
     }
 
     /// Handle text selection state for a label or similar widget.
@@ -505,9 +529,7 @@ impl LabelSelectionState {
                 }
             }
 
-            if got_copy_event(ui.ctx()) {
-                self.copy_text(galley_pos, galley, &cursor_range);
-            }
+            self.append_selected_text(galley_pos, galley, &cursor_range);
 
             cursor_state.set_range(Some(cursor_range));
         }
@@ -615,20 +637,63 @@ fn process_selection_key_events(
     changed
 }
 
-fn selected_text(galley: &Galley, cursor_range: &CursorRange) -> String {
+/// The selected bytes of [`Galley::text`].
+fn selected_byte_range(galley: &Galley, cursor_range: &CursorRange) -> Range<usize> {
     // This logic means we can select everything in an ellided label (including the `…`)
     // and still copy the entire un-ellided text!
     let everything_is_selected = cursor_range.contains(&CursorRange::select_all(galley));
 
     let copy_everything = cursor_range.is_empty() || everything_is_selected;
 
+    let text = galley.text();
     if copy_everything {
-        galley.text().to_owned()
+        0..text.len()
     } else {
-        cursor_range.slice_str(galley).to_owned()
+        let char_range = cursor_range.as_sorted_char_range();
+        byte_index_from_char_index(text, char_range.start)
+            ..byte_index_from_char_index(text, char_range.end)
     }
 }
 
+/// Join the selected text of several labels, with newlines between the rows
+/// and spaces between labels on the same row.
+fn join_selected_parts(parts: &[SelectedPart]) -> String {
+    let mut text = String::new();
+    let mut last_galley_rect: Option<Rect> = None;
+
+    for part in parts {
+        let new_text = part.text();
+
+        if let Some(last_galley_rect) = last_galley_rect {
+            if last_galley_rect.bottom() <= part.galley_rect.top() {
+                text.push('\n');
+                let vertical_distance = part.galley_rect.top() - last_galley_rect.bottom();
+                if part.row_height * 0.5 < vertical_distance {
+                    text.push('\n');
+                }
+            } else {
+                let existing_ends_with_space = text.chars().last().map(|c| c.is_whitespace());
+
+                let new_text_starts_with_space_or_punctuation = new_text
+                    .chars()
+                    .next()
+                    .map_or(false, |c| c.is_whitespace() || c.is_ascii_punctuation());
+
+                if existing_ends_with_space == Some(false)
+                    && !new_text_starts_with_space_or_punctuation
+                {
+                    text.push(' ');
+                }
+            }
+        }
+
+        text.push_str(new_text);
+        last_galley_rect = Some(part.galley_rect);
+    }
+
+    text
+}
+
 fn estimate_row_height(galley: &Galley) -> f32 {
     if let Some(row) = galley.rows.first() {
         row.rect.height()
@@ -636,3 +701,40 @@ fn estimate_row_height(galley: &Galley) -> f32 {
         galley.size().y
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(text: &str, galley_rect: Rect) -> SelectedPart {
+        SelectedPart {
+            job: Arc::new(LayoutJob::single_section(
+                text.to_owned(),
+                Default::default(),
+            )),
+            byte_range: 0..text.len(),
+            galley_rect,
+            row_height: 10.0,
+        }
+    }
+
+    #[test]
+    fn test_join_selected_parts() {
+        let row = |y: f32, x: f32| Rect::from_min_size(Pos2::new(x, y), crate::vec2(40.0, 10.0));
+
+        let parts = [
+            part("hello", row(0.0, 0.0)),
+            part("world", row(0.0, 50.0)),
+            part("next row", row(10.0, 0.0)),
+            part("after a gap", row(40.0, 0.0)),
+        ];
+        assert_eq!(
+            join_selected_parts(&parts),
+            "hello world\nnext row\n\nafter a gap"
+        );
+
+        // No space in front of punctuation:
+        let parts = [part("hello", row(0.0, 0.0)), part("!", row(0.0, 50.0))];
+        assert_eq!(join_selected_parts(&parts), "hello!");
+    }
+}
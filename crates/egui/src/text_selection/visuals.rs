@@ -24,30 +24,74 @@ pub fn paint_text_selection(
 
     for ri in min.row..=max.row {
         let row = &galley.rows[ri];
-        let left = if ri == min.row {
-            row.x_offset(min.column)
+        let newline_size = if ri != max.row && row.ends_with_newline {
+            row.height() / 2.0 // visualize that we select the newline
         } else {
-            row.rect.left()
+            0.0
         };
-        let right = if ri == max.row {
-            row.x_offset(max.column)
+
+        let x_ranges = if row.has_rtl() {
+            // The selected characters may be spread out over several visual runs:
+            let start = if ri == min.row { min.column } else { 0 };
+            let end = if ri == max.row {
+                max.column
+            } else {
+                row.char_count_excluding_newline()
+            };
+            let mut x_ranges = selected_x_ranges(row, start..end);
+            if 0.0 < newline_size {
+                x_ranges.push(Rangef::new(
+                    row.rect.right(),
+                    row.rect.right() + newline_size,
+                ));
+            }
+            x_ranges
         } else {
-            let newline_size = if row.ends_with_newline {
-                row.height() / 2.0 // visualize that we select the newline
+            let left = if ri == min.row {
+                row.x_offset(min.column)
             } else {
-                0.0
+                row.rect.left()
             };
-            row.rect.right() + newline_size
+            let right = if ri == max.row {
+                row.x_offset(max.column)
+            } else {
+                row.rect.right() + newline_size
+            };
+            vec![Rangef::new(left, right)]
         };
-        let rect = Rect::from_min_max(
-            galley_pos + vec2(left, row.min_y()),
-            galley_pos + vec2(right, row.max_y()),
-        );
-        let shape_idx = painter.rect_filled(rect, 0.0, color);
-        if let Some(out_shaped_idx) = &mut out_shaped_idx {
-            out_shaped_idx.push(shape_idx);
+
+        for x_range in x_ranges {
+            let rect = Rect::from_min_max(
+                galley_pos + vec2(x_range.min, row.min_y()),
+                galley_pos + vec2(x_range.max, row.max_y()),
+            );
+            let shape_idx = painter.rect_filled(rect, 0.0, color);
+            if let Some(out_shaped_idx) = &mut out_shaped_idx {
+                out_shaped_idx.push(shape_idx);
+            }
+        }
+    }
+}
+
+/// The horizontal extent of the given glyphs of a row, merged into as few ranges as possible.
+///
+/// In a row with mixed directions, consecutive characters are not necessarily next to each other on screen.
+fn selected_x_ranges(row: &epaint::text::Row, columns: std::ops::Range<usize>) -> Vec<Rangef> {
+    let columns = columns.start.min(row.glyphs.len())..columns.end.min(row.glyphs.len());
+    let mut x_ranges: Vec<Rangef> = row.glyphs[columns]
+        .iter()
+        .map(|glyph| Rangef::new(glyph.pos.x, glyph.pos.x + glyph.size.x))
+        .collect();
+    x_ranges.sort_by(|a, b| a.min.total_cmp(&b.min));
+
+    let mut merged: Vec<Rangef> = vec![];
+    for x_range in x_ranges {
+        match merged.last_mut() {
+            Some(last) if x_range.min <= last.max + 1.0 => last.max = last.max.max(x_range.max),
+            _ => merged.push(x_range),
         }
     }
+    merged
 }
 
 /// Paint one end of the selection, e.g. the primary cursor.
@@ -0,0 +1,163 @@
+//! Opt-in measurements of how long each part of the UI takes to run.
+//!
+//! Turn it on with [`crate::Options::measure_timings`], and read the results with [`crate::Context::timings`]
+//! or in the "⏱ Timings" section of [`crate::Context::inspection_ui`].
+
+use crate::{Context, Id, Rect, UiKind};
+
+/// How long one part of the UI took to run during a frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingEntry {
+    /// The [`Id`] of the area, panel or [`crate::Ui::measure_time`] scope.
+    pub id: Id,
+
+    /// What was measured, if it was a container.
+    ///
+    /// `None` for [`crate::Ui::measure_time`].
+    pub kind: Option<UiKind>,
+
+    /// Human-readable description.
+    pub name: String,
+
+    /// Where on screen it was shown.
+    pub rect: Rect,
+
+    /// How long it took, in seconds.
+    ///
+    /// This includes the time of everything inside of it, including nested measurements.
+    pub seconds: f32,
+}
+
+/// The timings of the last complete frame, slowest first.
+///
+/// See [`crate::Options::measure_timings`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timings {
+    /// Sorted slowest first.
+    pub entries: Vec<TimingEntry>,
+}
+
+impl Timings {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The `n` slowest entries.
+    pub fn worst(&self, n: usize) -> &[TimingEntry] {
+        &self.entries[..n.min(self.entries.len())]
+    }
+
+    /// All entries that took longer than the given number of seconds.
+    pub fn over_budget(&self, budget: f32) -> impl Iterator<Item = &TimingEntry> {
+        self.entries.iter().take_while(move |e| budget < e.seconds)
+    }
+
+    pub(crate) fn from_entries(mut entries: Vec<TimingEntry>) -> Self {
+        entries.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+        Self { entries }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A measurement in progress.
+///
+/// Does nothing unless [`crate::Options::measure_timings`] is on.
+/// Also does nothing on the web, where there is no high-resolution clock in `std`.
+pub(crate) struct TimingScope {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: Option<std::time::Instant>,
+}
+
+impl TimingScope {
+    pub fn start(ctx: &Context) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let start = ctx
+                .options(|o| o.measure_timings)
+                .then(std::time::Instant::now);
+            Self { start }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = ctx;
+            Self {}
+        }
+    }
+
+    /// Are we measuring anything?
+    pub fn is_active(&self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.start.is_some()
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            false
+        }
+    }
+
+    /// Record the measurement. `name` is only called if we are measuring.
+    pub fn finish(
+        self,
+        ctx: &Context,
+        id: Id,
+        kind: Option<UiKind>,
+        name: impl FnOnce() -> String,
+        rect: Rect,
+    ) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(start) = self.start {
+            let entry = TimingEntry {
+                id,
+                kind,
+                name: name(),
+                rect,
+                seconds: start.elapsed().as_secs_f32(),
+            };
+            ctx.frame_state_mut(|fs| fs.timings.push(entry));
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = (ctx, id, kind, name, rect);
+        }
+    }
+}
+
+/// A name for a container without a title.
+pub(crate) fn container_name(kind: UiKind, id: Id) -> String {
+    format!("{kind:?} {}", id.short_debug_format())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timings_sorted_slowest_first() {
+        let entry = |name: &str, seconds: f32| TimingEntry {
+            id: Id::new(name),
+            kind: None,
+            name: name.to_owned(),
+            rect: Rect::NOTHING,
+            seconds,
+        };
+        let timings = Timings::from_entries(vec![
+            entry("fast", 0.001),
+            entry("slow", 0.010),
+            entry("medium", 0.005),
+        ]);
+
+        let names = |entries: &[TimingEntry]| -> Vec<String> {
+            entries.iter().map(|e| e.name.clone()).collect()
+        };
+        assert_eq!(names(&timings.entries), ["slow", "medium", "fast"]);
+        assert_eq!(names(timings.worst(2)), ["slow", "medium"]);
+        assert_eq!(names(timings.worst(10)).len(), 3);
+        assert_eq!(timings.over_budget(0.004).count(), 2);
+    }
+}
@@ -310,6 +310,35 @@ impl Ui {
         &self.stack
     }
 
+    /// The scroll position of the closest [`crate::ScrollArea`] this [`Ui`] is in, if any.
+    ///
+    /// This is the offset the contents are laid out with this frame,
+    /// so use this (and not the output of the previous frame) for scroll-linked effects.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     // A background that scrolls at half the speed of the contents:
+    ///     let offset = ui.scroll_area_viewport().map_or(0.0, |scroll| scroll.offset.y);
+    ///     let rect = egui::Rect::from_min_size(
+    ///         ui.max_rect().min + egui::vec2(0.0, 0.5 * offset),
+    ///         egui::vec2(ui.available_width(), 100.0),
+    ///     );
+    ///     ui.painter().rect_filled(rect, 0.0, egui::Color32::DARK_BLUE);
+    ///
+    ///     // Add a lot of widgets here.
+    /// });
+    /// # });
+    /// ```
+    pub fn scroll_area_viewport(
+        &self,
+    ) -> Option<crate::containers::scroll_area::ScrollAreaViewport> {
+        self.stack
+            .iter()
+            .find(|frame| frame.kind() == Some(UiKind::ScrollArea))
+            .and_then(|frame| self.ctx().data(|data| data.get_temp(frame.id)))
+    }
+
     /// Get a reference to the parent [`Context`].
     #[inline]
     pub fn ctx(&self) -> &Context {
@@ -359,6 +388,13 @@ impl Ui {
         }
     }
 
+    /// Deny all future interaction, without changing how the widgets look.
+    ///
+    /// Used for containers that are animating closed.
+    pub(crate) fn disable_interaction(&mut self) {
+        self.enabled = false;
+    }
+
     /// Calling `set_enabled(false)` will cause the [`Ui`] to deny all future interaction
     /// and all the widgets will draw with a gray look.
     ///
@@ -964,6 +1000,41 @@ impl Ui {
         (response.rect, response)
     }
 
+    /// Like [`Self::allocate_exact_size`], but for a widget containing text.
+    ///
+    /// `baseline` is the distance from the top of the widget to the baseline of its (first row of) text.
+    /// In a baseline aligned layout (see [`Layout::with_baseline_align`]) the widget is moved
+    /// up or down so that its baseline lines up with the rest of the row.
+    ///
+    /// [`Response::baseline`] is set from the returned [`Rect`].
+    pub fn allocate_exact_size_with_baseline(
+        &mut self,
+        desired_size: Vec2,
+        baseline: f32,
+        sense: Sense,
+    ) -> (Rect, Response) {
+        let (id, rect) = self.allocate_space_with_baseline(desired_size, baseline);
+        let mut response = self.interact(rect, id, sense);
+        let rect = self.placer.align_size_within_rect(desired_size, rect);
+        response.baseline = Some(rect.top() + baseline);
+        (rect, response)
+    }
+
+    /// Like [`Self::allocate_at_least`], but for a widget containing text.
+    ///
+    /// See [`Self::allocate_exact_size_with_baseline`].
+    pub fn allocate_at_least_with_baseline(
+        &mut self,
+        desired_size: Vec2,
+        baseline: f32,
+        sense: Sense,
+    ) -> (Rect, Response) {
+        let (id, rect) = self.allocate_space_with_baseline(desired_size, baseline);
+        let mut response = self.interact(rect, id, sense);
+        response.baseline = Some(rect.top() + baseline);
+        (response.rect, response)
+    }
+
     /// Reserve this much space and move the cursor.
     /// Returns where to put the widget.
     ///
@@ -1046,6 +1117,71 @@ impl Ui {
         widget_rect
     }
 
+    /// Like [`Self::allocate_space`], but for a widget containing text.
+    ///
+    /// See [`Self::allocate_exact_size_with_baseline`].
+    pub(crate) fn allocate_space_with_baseline(
+        &mut self,
+        desired_size: Vec2,
+        baseline: f32,
+    ) -> (Id, Rect) {
+        let layout = self.placer.layout();
+        if !layout.baseline_align() || !layout.is_horizontal() || self.placer.is_grid() {
+            return self.allocate_space(desired_size);
+        }
+
+        let item_spacing = self.spacing().item_spacing;
+        let frame_rect = self.placer.next_space(desired_size, item_spacing);
+        debug_assert!(!frame_rect.any_nan());
+        let widget_rect = self.placer.justify_and_align(frame_rect, desired_size);
+
+        let row_baseline = self.row_baseline(frame_rect.top(), baseline);
+        let widget_rect = Rect::from_min_size(
+            pos2(
+                widget_rect.left(),
+                frame_rect.top() + row_baseline - baseline,
+            ),
+            vec2(widget_rect.width(), desired_size.y),
+        );
+
+        self.placer
+            .advance_after_rects(frame_rect.union(widget_rect), widget_rect, item_spacing);
+
+        register_rect(self, widget_rect);
+
+        let id = Id::new(self.next_auto_id_source);
+        self.next_auto_id_source = self.next_auto_id_source.wrapping_add(1);
+        (id, widget_rect)
+    }
+
+    /// Where to put the baseline in the row starting at `row_top`, relative to the top of the row.
+    ///
+    /// The row baseline is the largest baseline of its widgets.
+    /// We remember it from the previous frame, so that earlier widgets in the row can be aligned with later ones.
+    fn row_baseline(&mut self, row_top: f32, baseline: f32) -> f32 {
+        let ctx = self.ctx().clone();
+        let memory_id = self.id.with("baseline");
+        let (row, is_new_row) = self.placer.baseline_row(row_top);
+        let memory_id = memory_id.with(row.index);
+        if is_new_row {
+            row.baseline = ctx.data(|d| d.get_temp::<f32>(memory_id)).unwrap_or(0.0);
+        }
+
+        let widgets_before_are_misaligned = !is_new_row && row.baseline < baseline;
+        row.max_baseline = row.max_baseline.max(baseline);
+        row.baseline = row.baseline.max(baseline);
+
+        if row.stored != Some(row.max_baseline) {
+            row.stored = Some(row.max_baseline);
+            ctx.data_mut(|d| d.insert_temp(memory_id, row.max_baseline));
+        }
+        if widgets_before_are_misaligned {
+            ctx.request_repaint();
+        }
+
+        row.baseline
+    }
+
     /// Allocate a specific part of the [`Ui`].
     ///
     /// Ignore the layout of the [`Ui`]: just put my widget here!
@@ -1660,17 +1796,27 @@ impl Ui {
 
     /// Show a checkbox.
     ///
-    /// See also [`Self::toggle_value`].
+    /// See also [`Self::toggle_value`] and [`Self::toggle_switch`].
     #[inline]
     pub fn checkbox(&mut self, checked: &mut bool, text: impl Into<WidgetText>) -> Response {
         Checkbox::new(checked, text).ui(self)
     }
 
+    /// Acts like a checkbox, but looks like an on/off switch.
+    ///
+    /// Shortcut for `add(ToggleSwitch::new(on, text))`
+    ///
+    /// See also [`Self::checkbox`].
+    #[inline]
+    pub fn toggle_switch(&mut self, on: &mut bool, text: impl Into<WidgetText>) -> Response {
+        ToggleSwitch::new(on, text).ui(self)
+    }
+
     /// Acts like a checkbox, but looks like a [`SelectableLabel`].
     ///
     /// Click to toggle to bool.
     ///
-    /// See also [`Self::checkbox`].
+    /// See also [`Self::checkbox`] and [`Self::toggle_switch`].
     pub fn toggle_value(&mut self, selected: &mut bool, text: impl Into<WidgetText>) -> Response {
         let mut response = self.selectable_label(*selected, text);
         if response.clicked() {
@@ -1833,6 +1979,15 @@ impl Ui {
     pub fn image<'a>(&mut self, source: impl Into<ImageSource<'a>>) -> Response {
         Image::new(source).ui(self)
     }
+
+    /// Show an icon from the [`crate::IconRegistry`], sized to match the text.
+    ///
+    /// Shortcut for `ui.add(Icon::new(name))`. See [`Icon`] for more options.
+    #[cfg(feature = "icons")]
+    #[inline]
+    pub fn icon(&mut self, name: impl Into<String>) -> Response {
+        Icon::new(name).ui(self)
+    }
 }
 
 /// # Colors
@@ -1991,6 +2146,29 @@ impl Ui {
         self.scope_dyn(Box::new(add_contents), Id::new("child"), None)
     }
 
+    /// Like [`Self::scope`], but also measures how long the contents take to run,
+    /// if [`crate::Options::measure_timings`] is on.
+    ///
+    /// The result shows up in [`Context::timings`] under the given name.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.measure_time("Expensive table", |ui| {
+    ///     // …
+    /// });
+    /// # });
+    /// ```
+    pub fn measure_time<R>(
+        &mut self,
+        name: impl Into<String>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let timing = crate::timings::TimingScope::start(self.ctx());
+        let InnerResponse { inner, response } = self.scope(add_contents);
+        timing.finish(self.ctx(), response.id, None, || name.into(), response.rect);
+        InnerResponse { inner, response }
+    }
+
     fn scope_dyn<'c, R>(
         &mut self,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
@@ -2176,6 +2354,41 @@ impl Ui {
         self.horizontal_with_main_wrap_dyn(true, Box::new(add_contents))
     }
 
+    /// Like [`Self::horizontal`], but aligns text on its baseline.
+    ///
+    /// Use this when mixing text of different sizes with e.g. buttons or [`crate::DragValue`]s,
+    /// so that all the text sits on the same line.
+    /// Widgets without text are centered vertically, like in [`Self::horizontal`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut value = 42.0;
+    /// ui.horizontal_baseline(|ui| {
+    ///     ui.heading("Value:");
+    ///     ui.add(egui::DragValue::new(&mut value));
+    ///     ui.small("units");
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See also [`Layout::with_baseline_align`].
+    pub fn horizontal_baseline<R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let initial_size = vec2(
+            self.available_size_before_wrap().x,
+            self.spacing().interact_size.y,
+        );
+        let layout = if self.placer.prefer_right_to_left() {
+            Layout::right_to_left(Align::Center)
+        } else {
+            Layout::left_to_right(Align::Center)
+        }
+        .with_baseline_align(true);
+        self.allocate_ui_with_layout_dyn(initial_size, layout, Box::new(add_contents))
+    }
+
     fn horizontal_with_main_wrap_dyn<'c, R>(
         &mut self,
         main_wrap: bool,
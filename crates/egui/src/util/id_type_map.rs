@@ -524,6 +524,28 @@ impl IdTypeMap {
             .count()
     }
 
+    /// A copy with only the values that are persisted, e.g. to serialize them on another thread.
+    ///
+    /// This is much cheaper than [`Clone::clone`] when there is a lot of temporary data,
+    /// and the values that were never deserialized share their serialized form.
+    #[cfg(feature = "persistence")]
+    pub fn clone_persisted(&self) -> Self {
+        crate::profile_function!();
+        let map = self
+            .map
+            .iter()
+            .filter(|(_, element)| match element {
+                Element::Value { serialize_fn, .. } => serialize_fn.is_some(),
+                Element::Serialized(_) => true,
+            })
+            .map(|(hash, element)| (*hash, element.clone()))
+            .collect();
+        Self {
+            map,
+            max_bytes_per_type: self.max_bytes_per_type,
+        }
+    }
+
     /// Count the number of values are stored with the given type.
     pub fn count<T: 'static>(&self) -> usize {
         let key = TypeId::of::<T>();
@@ -859,6 +881,29 @@ fn test_mix_serialize() {
     assert_eq!(map.get_temp::<Serializable>(id), Some(Serializable(555)));
 }
 
+#[cfg(feature = "persistence")]
+#[test]
+fn test_clone_persisted() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Serializable(i32);
+
+    let id = Id::new("a");
+
+    let mut map: IdTypeMap = Default::default();
+    map.insert_persisted(id, Serializable(555));
+    map.insert_temp(id, 1.0_f32);
+
+    let mut snapshot = map.clone_persisted();
+    assert_eq!(snapshot.len(), 1);
+    assert_eq!(snapshot.get_temp::<f32>(id), None);
+    assert_eq!(
+        snapshot.get_persisted::<Serializable>(id),
+        Some(Serializable(555))
+    );
+}
+
 #[cfg(feature = "persistence")]
 #[test]
 fn test_serialize_generations() {
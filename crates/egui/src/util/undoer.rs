@@ -112,6 +112,39 @@ where
         }
     }
 
+    /// How often undo points are created, and how many are kept.
+    #[inline]
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Change the [`Settings`], e.g. to undo in smaller steps.
+    ///
+    /// If there are more undo points than the new [`Settings::max_undos`], the oldest are removed.
+    pub fn set_settings(&mut self, settings: Settings) {
+        self.settings = settings;
+        self.truncate();
+    }
+
+    /// Forget all undo and redo points.
+    pub fn clear(&mut self) {
+        self.undos.clear();
+        self.redos.clear();
+        self.flux = None;
+    }
+
+    /// How many undo points there are, including the latest one (which is often the current state).
+    #[inline]
+    pub fn num_undos(&self) -> usize {
+        self.undos.len()
+    }
+
+    /// How many states can be redone, right after some undos.
+    #[inline]
+    pub fn num_redos(&self) -> usize {
+        self.redos.len()
+    }
+
     /// Do we have an undo point different from the given state?
     pub fn has_undo(&self, current_state: &State) -> bool {
         match self.undos.len() {
@@ -165,10 +198,14 @@ where
         if self.undos.back() != Some(current_state) {
             self.undos.push_back(current_state.clone());
         }
+        self.truncate();
+        self.flux = None;
+    }
+
+    fn truncate(&mut self) {
         while self.undos.len() > self.settings.max_undos {
             self.undos.pop_front();
         }
-        self.flux = None;
     }
 
     /// Call this as often as you want (e.g. every frame)
@@ -219,3 +256,30 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_settings_and_clear() {
+        let mut undoer = Undoer::default();
+        for state in 0..5 {
+            undoer.add_undo(&state);
+        }
+        undoer.undo(&5);
+        assert_eq!((undoer.num_undos(), undoer.num_redos()), (5, 1));
+
+        undoer.set_settings(Settings {
+            max_undos: 3,
+            ..Default::default()
+        });
+        assert_eq!(undoer.settings().max_undos, 3);
+        assert_eq!(undoer.num_undos(), 3, "The oldest undo points are removed");
+        assert_eq!(undoer.undo(&4), Some(&3));
+
+        undoer.clear();
+        assert_eq!((undoer.num_undos(), undoer.num_redos()), (0, 0));
+        assert!(!undoer.has_undo(&3));
+    }
+}
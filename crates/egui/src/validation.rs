@@ -0,0 +1,229 @@
+//! Consistent styling of the validation state of input widgets.
+//!
+//! See [`ValidationState`].
+
+use crate::{epaint, Color32, Rect, Response, RichText, Stroke, Ui, Visuals};
+
+/// How serious a [`ValidationState`] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ValidationSeverity {
+    /// The value is valid, and the user should know it.
+    Success,
+
+    /// The value is allowed, but probably not what the user wants.
+    Warning,
+
+    /// The value is invalid.
+    Error,
+}
+
+impl ValidationSeverity {
+    /// The color to use for the border and message, from
+    /// [`Visuals::success_fg_color`], [`Visuals::warn_fg_color`] or [`Visuals::error_fg_color`].
+    pub fn color(self, visuals: &Visuals) -> Color32 {
+        match self {
+            Self::Success => visuals.success_fg_color,
+            Self::Warning => visuals.warn_fg_color,
+            Self::Error => visuals.error_fg_color,
+        }
+    }
+
+    /// An icon shown in front of the message.
+    pub fn icon(self) -> &'static str {
+        match self {
+            Self::Success => "✔",
+            Self::Warning => "⚠",
+            Self::Error => "❌",
+        }
+    }
+}
+
+/// Where the message of a [`ValidationState`] is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ValidationMessagePlacement {
+    /// In a small label added right after the widget,
+    /// which puts it below the widget in a vertical layout.
+    Below,
+
+    /// In a tooltip when hovering the widget.
+    #[default]
+    Tooltip,
+}
+
+/// The result of validating the value of an input widget, e.g. a [`crate::TextEdit`].
+///
+/// Give it to a widget with e.g. [`crate::TextEdit::validation`], [`crate::DragValue::validation`],
+/// [`crate::ComboBox::validation`] or [`crate::Checkbox::validation`],
+/// and it will be shown with a colored border and a message.
+/// An [`ValidationSeverity::Error`] also marks the widget as invalid for screen readers.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut name = String::new();
+/// let validation = if name.is_empty() {
+///     Some(egui::ValidationState::error("Name is required").message_below())
+/// } else {
+///     None
+/// };
+/// ui.add(egui::TextEdit::singleline(&mut name).validation(validation));
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ValidationState {
+    pub severity: ValidationSeverity,
+
+    /// Explains the problem to the user. Not shown if empty.
+    pub message: String,
+
+    /// Default: [`ValidationMessagePlacement::Tooltip`].
+    pub placement: ValidationMessagePlacement,
+}
+
+impl ValidationState {
+    pub fn new(severity: ValidationSeverity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            placement: Default::default(),
+        }
+    }
+
+    /// See [`ValidationSeverity::Success`].
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(ValidationSeverity::Success, message)
+    }
+
+    /// See [`ValidationSeverity::Warning`].
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(ValidationSeverity::Warning, message)
+    }
+
+    /// See [`ValidationSeverity::Error`].
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(ValidationSeverity::Error, message)
+    }
+
+    /// Show the message below the widget instead of in a tooltip.
+    #[inline]
+    pub fn message_below(mut self) -> Self {
+        self.placement = ValidationMessagePlacement::Below;
+        self
+    }
+
+    /// Is this [`ValidationSeverity::Error`]?
+    #[inline]
+    pub fn is_error(&self) -> bool {
+        self.severity == ValidationSeverity::Error
+    }
+
+    /// The stroke to use for the border of a widget in this state.
+    pub fn stroke(&self, visuals: &Visuals) -> Stroke {
+        let width = visuals.widgets.inactive.bg_stroke.width.max(1.0);
+        Stroke::new(width, self.severity.color(visuals))
+    }
+
+    /// The message, with an icon in front, colored by severity.
+    pub fn rich_text(&self, visuals: &Visuals) -> RichText {
+        RichText::new(format!("{} {}", self.severity.icon(), self.message))
+            .color(self.severity.color(visuals))
+    }
+
+    /// Paint a colored border around `frame_rect`, and show the message.
+    ///
+    /// Called by widgets at the end of their `ui` function.
+    pub(crate) fn show(&self, ui: &mut Ui, response: Response, frame_rect: Rect) -> Response {
+        let visuals = ui.visuals();
+        let rounding = ui.style().interact(&response).rounding;
+        ui.painter().add(epaint::RectShape::stroke(
+            frame_rect,
+            rounding,
+            self.stroke(visuals),
+        ));
+
+        #[cfg(feature = "accesskit")]
+        ui.ctx().accesskit_node_builder(response.id, |builder| {
+            if self.is_error() {
+                builder.set_invalid(accesskit::Invalid::True);
+            }
+            if !self.message.is_empty() {
+                builder.set_description(self.message.clone());
+            }
+        });
+
+        if self.message.is_empty() {
+            return response;
+        }
+
+        match self.placement {
+            ValidationMessagePlacement::Below => {
+                ui.label(self.rich_text(ui.visuals()).small());
+                response
+            }
+            ValidationMessagePlacement::Tooltip => {
+                let text = self.rich_text(ui.visuals());
+                response.on_hover_text(text)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_severity_styling() {
+        let visuals = Visuals::dark();
+        let error = ValidationState::error("Too long");
+        assert!(error.is_error());
+        assert_eq!(error.stroke(&visuals).color, visuals.error_fg_color);
+        assert_eq!(error.rich_text(&visuals).text(), "❌ Too long");
+
+        let success = ValidationState::success("");
+        assert!(!success.is_error());
+        assert_eq!(success.stroke(&visuals).color, visuals.success_fg_color);
+        assert_eq!(
+            ValidationState::warning("").stroke(&visuals).color,
+            visuals.warn_fg_color
+        );
+    }
+
+    /// Show a [`DragValue`] with `validation` followed by a label,
+    /// and return the shapes and the top of the label.
+    fn run(validation: Option<ValidationState>) -> (Vec<epaint::ClippedShape>, f32) {
+        let ctx = Context::default();
+        let mut label_top = 0.0;
+        let output = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let mut value = 1.0;
+                ui.add(DragValue::new(&mut value).validation(validation));
+                label_top = ui.label("Next").rect.top();
+            });
+        });
+        (output.shapes, label_top)
+    }
+
+    #[test]
+    fn test_border_and_message() {
+        let error_color = Visuals::dark().error_fg_color;
+        let has_error_border = |shapes: &[epaint::ClippedShape]| {
+            shapes.iter().any(|clipped| {
+                matches!(&clipped.shape, Shape::Rect(rect) if rect.stroke.color == error_color)
+            })
+        };
+
+        let (shapes, no_validation_top) = run(None);
+        assert!(!has_error_border(&shapes));
+
+        let (shapes, tooltip_top) = run(Some(ValidationState::error("Too big")));
+        assert!(has_error_border(&shapes));
+        assert_eq!(tooltip_top, no_validation_top, "The tooltip takes no space");
+
+        let (shapes, below_top) = run(Some(ValidationState::error("Too big").message_below()));
+        assert!(has_error_border(&shapes));
+        assert!(below_top > no_validation_top, "The message is shown below");
+    }
+}
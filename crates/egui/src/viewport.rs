@@ -307,11 +307,44 @@ pub struct ViewportBuilder {
 
     pub mouse_passthrough: Option<bool>,
 
+    /// See [`Self::with_owned_by_parent`].
+    pub owned_by_parent: Option<bool>,
+
+    /// See [`Self::with_focusable`].
+    pub focusable: Option<bool>,
+
+    /// See [`Self::with_has_shadow`].
+    pub has_shadow: Option<bool>,
+
     // X11
     pub window_type: Option<X11WindowType>,
 }
 
 impl ViewportBuilder {
+    /// A preset for a floating tool palette, as used by creative apps.
+    ///
+    /// The window has no decorations, and is not shown in the taskbar.
+    /// It stays on top of its parent viewport (but not of other apps),
+    /// never takes keyboard focus away from its parent,
+    /// and has a drop shadow on platforms that support it.
+    ///
+    /// Since there is no title bar, you need to move the window yourself,
+    /// e.g. with [`ViewportCommand::StartDrag`].
+    /// A [`crate::Window`] with [`crate::Window::tool_palette`] does this for you.
+    pub fn tool_palette() -> Self {
+        Self::default()
+            .with_decorations(false)
+            .with_transparent(true)
+            .with_resizable(false)
+            .with_taskbar(false)
+            .with_minimize_button(false)
+            .with_maximize_button(false)
+            .with_owned_by_parent(true)
+            .with_focusable(false)
+            .with_has_shadow(true)
+            .with_window_type(X11WindowType::Utility)
+    }
+
     /// Sets the initial title of the window in the title bar.
     ///
     /// Look at winit for more details
@@ -600,6 +633,40 @@ impl ViewportBuilder {
         self
     }
 
+    /// Keep the window above its parent viewport, but not above other apps,
+    /// and minimize it together with its parent.
+    ///
+    /// This is called an "owned window" on Windows, and a "child window" on macOS.
+    /// On other platforms the window is made always-on-top instead,
+    /// unless you set [`Self::with_window_level`].
+    #[inline]
+    pub fn with_owned_by_parent(mut self, value: bool) -> Self {
+        self.owned_by_parent = Some(value);
+        self
+    }
+
+    /// If `false`, the window never keeps keyboard focus:
+    /// when it is clicked, focus is given back to its parent viewport.
+    ///
+    /// Mouse input still works as usual, but keyboard input goes to the parent viewport.
+    /// This also implies `with_active(false)` unless [`Self::with_active`] is set.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn with_focusable(mut self, value: bool) -> Self {
+        self.focusable = Some(value);
+        self
+    }
+
+    /// Show a drop shadow around the window, even if it has no decorations.
+    ///
+    /// Only supported on Windows and macOS.
+    #[inline]
+    pub fn with_has_shadow(mut self, value: bool) -> Self {
+        self.has_shadow = Some(value);
+        self
+    }
+
     /// ### On X11
     /// This sets the window type.
     /// Maps directly to [`_NET_WM_WINDOW_TYPE`](https://specifications.freedesktop.org/wm-spec/wm-spec-1.5.html).
@@ -639,6 +706,9 @@ impl ViewportBuilder {
             maximize_button: new_maximize_button,
             window_level: new_window_level,
             mouse_passthrough: new_mouse_passthrough,
+            owned_by_parent: new_owned_by_parent,
+            focusable: new_focusable,
+            has_shadow: new_has_shadow,
             taskbar: new_taskbar,
             window_type: new_window_type,
         } = new_vp_builder;
@@ -826,6 +896,21 @@ impl ViewportBuilder {
             recreate_window = true;
         }
 
+        if new_owned_by_parent.is_some() && self.owned_by_parent != new_owned_by_parent {
+            self.owned_by_parent = new_owned_by_parent;
+            recreate_window = true;
+        }
+
+        if new_has_shadow.is_some() && self.has_shadow != new_has_shadow {
+            self.has_shadow = new_has_shadow;
+            recreate_window = true;
+        }
+
+        if new_focusable.is_some() {
+            // Only checked by the backend when the window gains focus.
+            self.focusable = new_focusable;
+        }
+
         (commands, recreate_window)
     }
 }
@@ -1170,3 +1255,35 @@ pub struct ImmediateViewport<'a> {
     /// The user-code that shows the GUI.
     pub viewport_ui_cb: Box<dyn FnOnce(&Context) + 'a>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patch_tool_palette_options() {
+        let mut builder = ViewportBuilder::tool_palette();
+        assert_eq!(builder.owned_by_parent, Some(true));
+        assert_eq!(builder.focusable, Some(false));
+        assert_eq!(builder.decorations, Some(false));
+
+        // Changing the focusability is picked up without a new window:
+        let (_, recreate_window) =
+            builder.patch(ViewportBuilder::tool_palette().with_focusable(true));
+        assert!(!recreate_window);
+        assert_eq!(builder.focusable, Some(true));
+
+        let (_, recreate_window) =
+            builder.patch(ViewportBuilder::tool_palette().with_owned_by_parent(false));
+        assert!(recreate_window);
+        assert_eq!(builder.owned_by_parent, Some(false));
+
+        let (_, recreate_window) =
+            builder.patch(ViewportBuilder::tool_palette().with_has_shadow(false));
+        assert!(recreate_window);
+
+        // Unset options leave the builder as it is:
+        let (_, recreate_window) = builder.clone().patch(ViewportBuilder::default());
+        assert!(!recreate_window);
+    }
+}
@@ -37,6 +37,7 @@ pub struct RichText {
     underline: bool,
     italics: bool,
     raised: bool,
+    font_features: crate::text::FontFeatures,
 }
 
 impl From<&str> for RichText {
@@ -239,6 +240,32 @@ impl RichText {
         self
     }
 
+    /// Use digits that all have the same width, so that numbers line up in columns
+    /// and don't jitter when they change.
+    ///
+    /// See [`crate::text::FontFeatures::tabular_figures`].
+    #[inline]
+    pub fn tabular_figures(mut self) -> Self {
+        self.font_features.tabular_figures = true;
+        self
+    }
+
+    /// Show lowercase letters as small capitals, if the font supports it.
+    ///
+    /// See [`crate::text::FontFeatures::small_caps`].
+    #[inline]
+    pub fn small_caps(mut self) -> Self {
+        self.font_features.small_caps = true;
+        self
+    }
+
+    /// Select which OpenType features to use.
+    #[inline]
+    pub fn font_features(mut self, font_features: crate::text::FontFeatures) -> Self {
+        self.font_features = font_features;
+        self
+    }
+
     /// Fill-color behind the text.
     #[inline]
     pub fn background_color(mut self, background_color: impl Into<Color32>) -> Self {
@@ -347,6 +374,7 @@ impl RichText {
             underline,
             italics,
             raised,
+            font_features,
         } = self;
 
         let line_color = text_color.unwrap_or_else(|| style.visuals.text_color());
@@ -401,6 +429,7 @@ impl RichText {
                 underline,
                 strikethrough,
                 valign,
+                font_features,
             },
         )
     }
@@ -728,3 +757,34 @@ impl From<Arc<Galley>> for WidgetText {
         Self::Galley(galley)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::FontFeatures;
+
+    #[test]
+    fn test_rich_text_font_features() {
+        let style = Style::default();
+        let format_of = |text: RichText| {
+            let mut job = LayoutJob::default();
+            text.append_to(&mut job, &style, FontSelection::Default, Align::Center);
+            job.sections[0].format.font_features
+        };
+
+        assert_eq!(format_of(RichText::new("1")), FontFeatures::NONE);
+        assert_eq!(
+            format_of(RichText::new("1").tabular_figures()),
+            FontFeatures::TABULAR_FIGURES
+        );
+
+        let features = format_of(RichText::new("a").tabular_figures().small_caps());
+        assert!(features.tabular_figures && features.small_caps);
+
+        let custom = FontFeatures {
+            small_caps: true,
+            ..Default::default()
+        };
+        assert_eq!(format_of(RichText::new("a").font_features(custom)), custom);
+    }
+}
@@ -27,6 +27,7 @@ pub struct Button<'a> {
 
     /// None means default for interact
     fill: Option<Color32>,
+    readable_text: bool,
     stroke: Option<Stroke>,
     sense: Sense,
     small: bool,
@@ -60,6 +61,7 @@ impl<'a> Button<'a> {
             shortcut_text: Default::default(),
             wrap_mode: None,
             fill: None,
+            readable_text: false,
             stroke: None,
             sense: Sense::click(),
             small: false,
@@ -98,6 +100,8 @@ impl<'a> Button<'a> {
 
     /// Override background fill color. Note that this will override any on-hover effects.
     /// Calling this will also turn on the frame.
+    ///
+    /// See also [`Self::readable_text`].
     #[inline]
     pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
         self.fill = Some(fill.into());
@@ -105,6 +109,16 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// If `true`, the text color is darkened or lightened until it is readable on the
+    /// [`Self::fill`] color, see [`crate::Visuals::text_color_on`]. (Default: false)
+    ///
+    /// Has no effect without a custom fill.
+    #[inline]
+    pub fn readable_text(mut self, readable_text: bool) -> Self {
+        self.readable_text = readable_text;
+        self
+    }
+
     /// Override button stroke. Note that this will override any on-hover effects.
     /// Calling this will also turn on the frame.
     #[inline]
@@ -180,6 +194,7 @@ impl Widget for Button<'_> {
             shortcut_text,
             wrap_mode,
             fill,
+            readable_text,
             stroke,
             sense,
             small,
@@ -262,7 +277,29 @@ impl Widget for Button<'_> {
         }
         desired_size = desired_size.at_least(min_size);
 
-        let (rect, mut response) = ui.allocate_at_least(desired_size, sense);
+        let layout = *ui.layout();
+        let text_beside_other_content = image.is_some() || shortcut_galley.is_some();
+        let text_pos_within = |galley: &Galley, rect: Rect| {
+            if text_beside_other_content {
+                pos2(rect.min.x, rect.center().y - 0.5 * galley.size().y)
+            } else {
+                // Make sure button text is centered if within a centered layout
+                layout
+                    .align_size_within_rect(galley.size(), rect.shrink2(button_padding))
+                    .min
+            }
+        };
+
+        let (rect, mut response) = if let Some(galley) = &galley {
+            let baseline = text_pos_within(galley, Rect::from_min_size(Pos2::ZERO, desired_size)).y
+                + galley.first_row_baseline();
+            ui.allocate_at_least_with_baseline(desired_size, baseline, sense)
+        } else {
+            ui.allocate_at_least(desired_size, sense)
+        };
+        if let Some(galley) = &galley {
+            response.baseline = Some(text_pos_within(galley, rect).y + galley.first_row_baseline());
+        }
         response.widget_info(|| {
             if let Some(galley) = &galley {
                 WidgetInfo::labeled(WidgetType::Button, galley.text())
@@ -306,6 +343,7 @@ impl Widget for Button<'_> {
             let mut cursor_x = rect.min.x + button_padding.x;
 
             if let Some(image) = &image {
+                let image = image.clone().resolve_current_color(visuals.text_color());
                 let image_rect = Rect::from_min_size(
                     pos2(cursor_x, rect.center().y - 0.5 - (image_size.y / 2.0)),
                     image_size,
@@ -331,15 +369,16 @@ impl Widget for Button<'_> {
             }
 
             if let Some(galley) = galley {
-                let text_pos = if image.is_some() || shortcut_galley.is_some() {
-                    pos2(cursor_x, rect.center().y - 0.5 * galley.size().y)
+                let mut text_pos = text_pos_within(&galley, rect);
+                if text_beside_other_content {
+                    text_pos.x = cursor_x;
+                }
+                let text_color = if fill.is_some() && readable_text {
+                    ui.visuals().text_color_on(frame_fill, visuals.text_color())
                 } else {
-                    // Make sure button text is centered if within a centered layout
-                    ui.layout()
-                        .align_size_within_rect(galley.size(), rect.shrink2(button_padding))
-                        .min
+                    visuals.text_color()
                 };
-                ui.painter().galley(text_pos, galley, visuals.text_color());
+                ui.painter().galley(text_pos, galley, text_color);
             }
 
             if let Some(shortcut_galley) = shortcut_galley {
@@ -364,3 +403,47 @@ impl Widget for Button<'_> {
         response
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The color of the text of the button, when its style is light mode.
+    fn text_color_of(button: Button<'_>) -> Color32 {
+        let ctx = Context::default();
+        ctx.set_visuals(Visuals::light());
+        let mut button = Some(button);
+        let output = ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add(button.take().unwrap());
+            });
+        });
+        output
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.shape {
+                Shape::Text(text) => Some(text.fallback_color),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_readable_text() {
+        let text_color = Visuals::light().widgets.inactive.text_color();
+        let fill = Color32::from_rgb(20, 20, 80);
+        assert!(ecolor::contrast_ratio(fill, text_color) < ecolor::MIN_TEXT_CONTRAST);
+
+        // The fill alone doesn't change the text color:
+        assert_eq!(text_color_of(Button::new("Text").fill(fill)), text_color);
+
+        let readable = text_color_of(Button::new("Text").fill(fill).readable_text(true));
+        assert!(ecolor::contrast_ratio(fill, readable) >= ecolor::MIN_TEXT_CONTRAST);
+
+        // Without a fill, there is nothing to adjust to:
+        assert_eq!(
+            text_color_of(Button::new("Text").readable_text(true)),
+            text_color
+        );
+    }
+}
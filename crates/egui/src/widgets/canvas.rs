@@ -0,0 +1,358 @@
+use epaint::CatmullRomShape;
+
+use crate::{util::undoer::Undoer, *};
+
+/// One freehand stroke drawn on a [`Canvas`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CanvasStroke {
+    /// Relative to the top left corner of the canvas.
+    pub points: Vec<Pos2>,
+
+    /// The pressure (`0.0..=1.0`) at each point.
+    ///
+    /// Empty if the input device doesn't report pressure, e.g. a mouse.
+    pub pressure: Vec<f32>,
+
+    /// The color and width (at full pressure).
+    pub stroke: Stroke,
+}
+
+impl CanvasStroke {
+    /// The points of the stroke, with their pressures, after smoothing.
+    ///
+    /// The points are relative to the top left corner of the canvas.
+    pub fn smoothed(&self, tolerance: f32) -> Vec<(Pos2, f32)> {
+        let pressure = |i: usize| self.pressure.get(i).copied().unwrap_or(1.0);
+
+        if self.points.len() < 3 {
+            return self.with_pressure();
+        }
+
+        let spline = CatmullRomShape::line(self.points.clone(), 0.0, Stroke::NONE);
+        let mut result = vec![(self.points[0], pressure(0))];
+        for (i, bezier) in spline.to_cubic_beziers().iter().enumerate() {
+            let (from, to) = (pressure(i), pressure(i + 1));
+            bezier.for_each_flattened_with_t(tolerance, &mut |p, t| {
+                result.push((p, lerp(from..=to, t)));
+            });
+        }
+        result
+    }
+
+    /// The points of the stroke, with their pressures.
+    pub fn with_pressure(&self) -> Vec<(Pos2, f32)> {
+        let pressure = |i: usize| self.pressure.get(i).copied().unwrap_or(1.0);
+        (0..self.points.len())
+            .map(|i| (self.points[i], pressure(i)))
+            .collect()
+    }
+}
+
+/// The drawing of a [`Canvas`]: the strokes and the undo history.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CanvasData {
+    /// All finished strokes, oldest first.
+    ///
+    /// If you change these yourself, call [`Self::add_undo_point`] before and after.
+    pub strokes: Vec<CanvasStroke>,
+
+    undoer: Undoer<Vec<CanvasStroke>>,
+
+    /// The stroke that is currently being drawn.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    in_progress: Option<CanvasStroke>,
+}
+
+impl CanvasData {
+    pub fn new(strokes: Vec<CanvasStroke>) -> Self {
+        Self {
+            strokes,
+            ..Default::default()
+        }
+    }
+
+    /// The stroke that is currently being drawn, if any.
+    pub fn stroke_in_progress(&self) -> Option<&CanvasStroke> {
+        self.in_progress.as_ref()
+    }
+
+    /// Remember the current strokes, so that [`Self::undo`] can go back to them.
+    pub fn add_undo_point(&mut self) {
+        self.undoer.add_undo(&self.strokes);
+    }
+
+    pub fn has_undo(&self) -> bool {
+        self.undoer.has_undo(&self.strokes)
+    }
+
+    pub fn has_redo(&self) -> bool {
+        self.undoer.has_redo(&self.strokes)
+    }
+
+    /// Go back to before the latest change. Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        if let Some(strokes) = self.undoer.undo(&self.strokes) {
+            self.strokes = strokes.clone();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Redo the latest undone change. Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        if let Some(strokes) = self.undoer.redo(&self.strokes) {
+            self.strokes = strokes.clone();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Remove all strokes. This can be undone.
+    pub fn clear(&mut self) {
+        self.add_undo_point();
+        self.strokes.clear();
+        self.add_undo_point();
+    }
+
+    fn finish_stroke(&mut self) -> bool {
+        let Some(stroke) = self.in_progress.take() else {
+            return false;
+        };
+        if stroke.points.is_empty() {
+            return false;
+        }
+        self.add_undo_point();
+        self.strokes.push(stroke);
+        self.add_undo_point();
+        true
+    }
+}
+
+/// An area to draw freehand strokes on with the mouse, a pen, or a finger.
+///
+/// The strokes are smoothed with a Catmull-Rom spline,
+/// and pen pressure is used for the stroke width when available.
+///
+/// When the canvas has keyboard focus, <kbd>Cmd/Ctrl+Z</kbd> undoes
+/// and <kbd>Cmd/Ctrl+Shift+Z</kbd> or <kbd>Cmd/Ctrl+Y</kbd> redoes.
+///
+/// The [`Response`] is marked as changed when a stroke is finished, undone, or redone.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut drawing = egui::CanvasData::default();
+/// ui.horizontal(|ui| {
+///     if ui.add_enabled(drawing.has_undo(), egui::Button::new("Undo")).clicked() {
+///         drawing.undo();
+///     }
+///     if ui.button("Clear").clicked() {
+///         drawing.clear();
+///     }
+/// });
+/// ui.add(egui::Canvas::new(&mut drawing).desired_size(egui::vec2(300.0, 200.0)));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Canvas<'a> {
+    data: &'a mut CanvasData,
+    stroke: Option<Stroke>,
+    desired_size: Option<Vec2>,
+    smoothing: bool,
+}
+
+impl<'a> Canvas<'a> {
+    pub fn new(data: &'a mut CanvasData) -> Self {
+        Self {
+            data,
+            stroke: None,
+            desired_size: None,
+            smoothing: true,
+        }
+    }
+
+    /// The color and width of new strokes.
+    ///
+    /// Default: 2 points wide, in the text color.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Default: all the available space.
+    #[inline]
+    pub fn desired_size(mut self, desired_size: Vec2) -> Self {
+        self.desired_size = Some(desired_size);
+        self
+    }
+
+    /// Smooth the strokes with a Catmull-Rom spline.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn smoothing(mut self, smoothing: bool) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+}
+
+impl<'a> Widget for Canvas<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            data,
+            stroke,
+            desired_size,
+            smoothing,
+        } = self;
+
+        let stroke = stroke.unwrap_or_else(|| Stroke::new(2.0, ui.visuals().text_color()));
+        let desired_size = desired_size.unwrap_or_else(|| ui.available_size());
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
+
+        if response.drag_started() || response.clicked() {
+            response.request_focus();
+        }
+
+        if response.dragged() || response.drag_stopped() || response.clicked() {
+            if let Some(pointer_pos) = response.interact_pointer_pos() {
+                let (press_origin, force) = ui.input(|i| {
+                    // Pen pressure, if any:
+                    let force = i.events.iter().rev().find_map(|event| match event {
+                        Event::Touch { force, .. } => *force,
+                        _ => None,
+                    });
+                    (i.pointer.press_origin(), force)
+                });
+
+                let current = data.in_progress.get_or_insert_with(|| CanvasStroke {
+                    // Don't lose the part before the drag was detected:
+                    points: press_origin
+                        .map(|p| p - rect.min.to_vec2())
+                        .into_iter()
+                        .collect(),
+                    pressure: vec![],
+                    stroke,
+                });
+                let pos = pointer_pos - rect.min.to_vec2();
+                let is_new_point = current
+                    .points
+                    .last()
+                    .map_or(true, |last| 1.0 <= last.distance(pos));
+                if is_new_point {
+                    current.points.push(pos);
+                }
+                if let Some(force) = force {
+                    current.pressure.resize(current.points.len() - 1, 1.0);
+                    current.pressure.push(force.clamp(0.0, 1.0));
+                } else if !current.pressure.is_empty() {
+                    let last = current.pressure.last().copied().unwrap_or(1.0);
+                    current.pressure.resize(current.points.len(), last);
+                }
+            }
+        }
+        if !response.dragged() && data.finish_stroke() {
+            response.mark_changed();
+        }
+
+        if response.has_focus() {
+            let (undo, redo) = ui.input_mut(|i| {
+                let redo = i.consume_key(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z)
+                    || i.consume_key(Modifiers::COMMAND, Key::Y);
+                let undo = i.consume_key(Modifiers::COMMAND, Key::Z);
+                (undo, redo)
+            });
+            if (undo && data.undo()) || (redo && data.redo()) {
+                response.mark_changed();
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui
+                .style()
+                .interact_selectable(&response, response.has_focus());
+            ui.painter().rect(
+                rect,
+                visuals.rounding,
+                ui.visuals().extreme_bg_color,
+                visuals.bg_stroke,
+            );
+
+            let painter = ui.painter_at(rect);
+            for canvas_stroke in data.strokes.iter().chain(&data.in_progress) {
+                paint_stroke(&painter, rect.min, canvas_stroke, smoothing);
+            }
+        }
+
+        response.widget_info(|| WidgetInfo::new(WidgetType::Other));
+        response
+    }
+}
+
+fn paint_stroke(painter: &Painter, origin: Pos2, canvas_stroke: &CanvasStroke, smoothing: bool) {
+    let stroke = canvas_stroke.stroke;
+    let to_screen = |p: Pos2| origin + p.to_vec2();
+
+    let points = if smoothing {
+        canvas_stroke.smoothed(0.1)
+    } else {
+        canvas_stroke.with_pressure()
+    };
+
+    match points.as_slice() {
+        [] => {}
+        [(p, pressure)] => {
+            painter.circle_filled(to_screen(*p), 0.5 * stroke.width * pressure, stroke.color);
+        }
+        _ if canvas_stroke.pressure.is_empty() => {
+            let points = points.iter().map(|(p, _)| to_screen(*p)).collect();
+            painter.add(Shape::line(points, stroke));
+        }
+        _ => {
+            // Variable width: round joints between segments of different widths.
+            for pair in points.windows(2) {
+                let [(a, pa), (b, pb)] = [pair[0], pair[1]];
+                let width = stroke.width * 0.5 * (pa + pb);
+                painter.line_segment([to_screen(a), to_screen(b)], (width, stroke.color));
+                painter.circle_filled(to_screen(b), 0.5 * width, stroke.color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canvas_undo_redo() {
+        let stroke = |x: f32| CanvasStroke {
+            points: vec![pos2(x, 0.0), pos2(x, 10.0)],
+            ..Default::default()
+        };
+
+        let mut data = CanvasData::default();
+        for x in [1.0, 2.0] {
+            data.in_progress = Some(stroke(x));
+            assert!(data.finish_stroke());
+        }
+        assert_eq!(data.strokes, [stroke(1.0), stroke(2.0)]);
+
+        assert!(data.undo());
+        assert_eq!(data.strokes, [stroke(1.0)]);
+        assert!(data.undo());
+        assert!(data.strokes.is_empty());
+        assert!(!data.undo());
+
+        assert!(data.redo());
+        assert_eq!(data.strokes, [stroke(1.0)]);
+
+        data.clear();
+        assert!(data.strokes.is_empty());
+        assert!(data.undo());
+        assert_eq!(data.strokes, [stroke(1.0)]);
+    }
+}
@@ -18,6 +18,7 @@ pub struct Checkbox<'a> {
     checked: &'a mut bool,
     text: WidgetText,
     indeterminate: bool,
+    validation: Option<ValidationState>,
 }
 
 impl<'a> Checkbox<'a> {
@@ -26,6 +27,7 @@ impl<'a> Checkbox<'a> {
             checked,
             text: text.into(),
             indeterminate: false,
+            validation: None,
         }
     }
 
@@ -42,6 +44,15 @@ impl<'a> Checkbox<'a> {
         self.indeterminate = indeterminate;
         self
     }
+
+    /// Show the result of validating the value, with a colored border around the box and a message.
+    ///
+    /// See [`ValidationState`].
+    #[inline]
+    pub fn validation(mut self, validation: impl Into<Option<ValidationState>>) -> Self {
+        self.validation = validation.into();
+        self
+    }
 }
 
 impl<'a> Widget for Checkbox<'a> {
@@ -50,6 +61,7 @@ impl<'a> Widget for Checkbox<'a> {
             checked,
             text,
             indeterminate,
+            validation,
         } = self;
 
         let spacing = &ui.spacing();
@@ -131,6 +143,12 @@ impl<'a> Widget for Checkbox<'a> {
             }
         }
 
+        if let Some(validation) = validation {
+            let (_, big_icon_rect) = ui.spacing().icon_rectangles(rect);
+            let expansion = ui.style().interact(&response).expansion;
+            response = validation.show(ui, response, big_icon_rect.expand(expansion));
+        }
+
         response
     }
 }
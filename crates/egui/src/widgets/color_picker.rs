@@ -1,9 +1,18 @@
 //! Color picker widgets.
 
+use std::sync::Arc;
+
 use crate::util::fixed_cache::FixedCache;
 use crate::*;
 use epaint::{ecolor::*, *};
 
+/// Lets the user pick a color anywhere on the screen, e.g. with a native eyedropper.
+///
+/// Calls the callback with the picked color, or with `None` if the user cancelled.
+///
+/// See [`Context::set_screen_color_picker`].
+pub type ScreenColorPicker = Arc<dyn Fn(Box<dyn FnOnce(Option<Color32>) + Send>) + Send + Sync>;
+
 fn contrast_color(color: impl Into<Rgba>) -> Color32 {
     if color.into().intensity() < 0.5 {
         Color32::WHITE
@@ -106,6 +115,9 @@ fn color_slider_1d(ui: &mut Ui, value: &mut f32, color_at: impl Fn(f32) -> Color
 
     let desired_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
     let (rect, response) = ui.allocate_at_least(desired_size, Sense::click_and_drag());
+    if response.drag_started() {
+        response.capture_pointer();
+    }
 
     if let Some(mpos) = response.interact_pointer_pos() {
         *value = remap_clamp(mpos.x, rect.left()..=rect.right(), 0.0..=1.0);
@@ -169,6 +181,9 @@ fn color_slider_2d(
 ) -> Response {
     let desired_size = Vec2::splat(ui.spacing().slider_width);
     let (rect, response) = ui.allocate_at_least(desired_size, Sense::click_and_drag());
+    if response.drag_started() {
+        response.capture_pointer();
+    }
 
     if let Some(mpos) = response.interact_pointer_pos() {
         *x_value = remap_clamp(mpos.x, rect.left()..=rect.right(), 0.0..=1.0);
@@ -296,8 +311,18 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
         }
     }
 
+    if !is_additive_alpha(hsvag.a) {
+        ui.horizontal(|ui| {
+            hex_edit_ui(ui, hsvag, alpha);
+            hsv_edit_ui(ui, hsvag);
+        });
+    }
+
     let current_color_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
-    show_color(ui, *hsvag, current_color_size).on_hover_text("Selected color");
+    ui.horizontal(|ui| {
+        show_color(ui, *hsvag, current_color_size).on_hover_text("Selected color");
+        eyedropper_ui(ui, hsvag);
+    });
 
     if alpha == Alpha::BlendOrAdditive {
         let a = &mut hsvag.a;
@@ -360,6 +385,170 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
     }
 }
 
+/// A button to pick a color anywhere on the screen, if the integration supports it.
+///
+/// The picked color replaces the color, but not the alpha.
+fn eyedropper_ui(ui: &mut Ui, hsvag: &mut HsvaGamma) {
+    type PickHandle = Arc<TaskHandle<Option<Color32>>>;
+
+    if !ui.ctx().can_pick_screen_color() {
+        return;
+    }
+
+    let id = ui.next_auto_id().with("eyedropper");
+    let mut is_picking = false;
+    if let Some(handle) = ui.data(|d| d.get_temp::<PickHandle>(id)) {
+        if let Some(&picked) = handle.ready() {
+            ui.data_mut(|d| d.remove::<PickHandle>(id));
+            if let Some(color) = picked {
+                let alpha = hsvag.a;
+                *hsvag = HsvaGamma::from(color);
+                hsvag.a = alpha;
+            }
+        } else {
+            is_picking = true;
+        }
+    }
+
+    let response = ui
+        .add(Button::new("💉").selected(is_picking))
+        .on_hover_text("Pick a color from the screen");
+    if response.clicked() && !is_picking {
+        if let Some(handle) = ui.ctx().pick_screen_color() {
+            ui.data_mut(|d| d.insert_temp::<PickHandle>(id, Arc::new(handle)));
+        }
+    }
+}
+
+/// A text field with the color as hex, e.g. `#ff8000`, which also accepts `rgb(255, 128, 0)`.
+///
+/// The alpha is shown and edited unless [`Alpha::Opaque`].
+fn hex_edit_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
+    let [r, g, b, a] = Hsva::from(*hsvag).to_srgba_unmultiplied();
+    let hex = if alpha == Alpha::Opaque {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    };
+
+    // Keep what the user typed while editing, even if it doesn't parse (yet):
+    let id = ui.next_auto_id().with("hex");
+    let mut text = ui
+        .data(|d| d.get_temp::<String>(id))
+        .filter(|_| ui.memory(|mem| mem.has_focus(id)))
+        .unwrap_or(hex);
+
+    let mut response = TextEdit::singleline(&mut text)
+        .id(id)
+        .font(TextStyle::Monospace)
+        .desired_width(9.0 * ui.spacing().interact_size.y)
+        .show(ui)
+        .response;
+    let parsed = parse_color(&text);
+    if response.changed() {
+        if let Some([r, g, b, parsed_a]) = parsed {
+            let a = if alpha == Alpha::Opaque {
+                255
+            } else {
+                parsed_a
+            };
+            *hsvag = HsvaGamma::from(Hsva::from_srgba_unmultiplied([r, g, b, a]));
+        }
+    }
+
+    if response.has_focus() {
+        if parsed.is_none() {
+            let rect = response.rect;
+            response = ValidationState::error("Expected e.g. #ff8000 or rgb(255, 128, 0)")
+                .show(ui, response, rect);
+        }
+        ui.data_mut(|d| d.insert_temp(id, text));
+    } else {
+        ui.data_mut(|d| d.remove::<String>(id));
+    }
+    response.on_hover_text("Hex color, or rgb(…)");
+}
+
+/// Parse a color typed by the user, as unmultiplied sRGBA.
+///
+/// Accepts `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa` (the `#` is optional),
+/// and `rgb(r, g, b)` and `rgba(r, g, b, a)`, with channels in 0-255 or percent
+/// and alpha in 0-1 or percent.
+fn parse_color(text: &str) -> Option<[u8; 4]> {
+    fn channel(value: &str, max: f32) -> Option<u8> {
+        let value = match value.strip_suffix('%') {
+            Some(percent) => percent.parse::<f32>().ok()? / 100.0,
+            None => value.parse::<f32>().ok()? / max,
+        };
+        (0.0..=1.0)
+            .contains(&value)
+            .then(|| (value * 255.0).round() as u8)
+    }
+
+    let text = text.trim();
+    let function_args = text
+        .strip_prefix("rgba(")
+        .or_else(|| text.strip_prefix("rgb("))
+        .and_then(|args| args.strip_suffix(')'));
+    if let Some(args) = function_args {
+        let args: Vec<&str> = args
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|arg| !arg.is_empty())
+            .collect();
+        return match args[..] {
+            [r, g, b] => Some([
+                channel(r, 255.0)?,
+                channel(g, 255.0)?,
+                channel(b, 255.0)?,
+                255,
+            ]),
+            [r, g, b, a] => Some([
+                channel(r, 255.0)?,
+                channel(g, 255.0)?,
+                channel(b, 255.0)?,
+                channel(a, 1.0)?,
+            ]),
+            _ => None,
+        };
+    }
+
+    let hex = text.strip_prefix('#').unwrap_or(text);
+    let digits: Vec<u8> = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|digit| digit as u8))
+        .collect::<Option<_>>()?;
+    match digits[..] {
+        [r, g, b] => Some([r * 17, g * 17, b * 17, 255]),
+        [r, g, b, a] => Some([r * 17, g * 17, b * 17, a * 17]),
+        [r1, r2, g1, g2, b1, b2] => Some([r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2, 255]),
+        [r1, r2, g1, g2, b1, b2, a1, a2] => {
+            Some([r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2, a1 * 16 + a2])
+        }
+        _ => None,
+    }
+}
+
+/// Hue in degrees, and saturation and value in percent.
+fn hsv_edit_ui(ui: &mut Ui, hsvag: &mut HsvaGamma) {
+    fn drag_value(ui: &mut Ui, value: &mut f32, scale: f32, prefix: &str, suffix: &str) {
+        let mut scaled = *value * scale;
+        let response = DragValue::new(&mut scaled)
+            .speed(0.5)
+            .clamp_range(0.0..=scale)
+            .max_decimals(0)
+            .prefix(prefix)
+            .suffix(suffix)
+            .ui(ui);
+        if response.changed() {
+            *value = scaled / scale;
+        }
+    }
+
+    drag_value(ui, &mut hsvag.h, 360.0, "H ", "°");
+    drag_value(ui, &mut hsvag.s, 100.0, "S ", "%");
+    drag_value(ui, &mut hsvag.v, 100.0, "V ", "%");
+}
+
 fn input_type_button_ui(ui: &mut Ui) {
     let mut input_type = ui.ctx().style().visuals.numeric_color_space;
     if input_type.toggle_button_ui(ui).changed() {
@@ -472,6 +661,169 @@ pub fn color_picker_color32(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> b
     changed
 }
 
+/// Swatches shown in the popups of the `color_edit_button_*` functions,
+/// below the color picker.
+///
+/// Nothing is shown unless the app sets a [`Self::palette`] or [`Self::max_recent`].
+/// This is persisted in [`crate::Memory`], so changes made by the user are remembered.
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// let mut swatches = egui::color_picker::ColorSwatches::load(&ctx);
+/// if swatches.palette.is_empty() {
+///     swatches.palette = vec![egui::Color32::from_rgb(0xe0, 0x40, 0x30), egui::Color32::BLACK];
+/// }
+/// swatches.max_recent = 8;
+/// swatches.store(&ctx);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ColorSwatches {
+    /// Colors chosen by the app, e.g. brand colors.
+    ///
+    /// The user can add the current color with the "+" button,
+    /// reorder the swatches by dragging them, and remove them with a right-click.
+    pub palette: Vec<Color32>,
+
+    /// The most recently picked colors, most recent first.
+    ///
+    /// A color is added when a color picker popup is closed after changing it.
+    pub recent: Vec<Color32>,
+
+    /// How many colors to keep in [`Self::recent`]. Default: 0, i.e. no recent colors.
+    pub max_recent: usize,
+}
+
+impl ColorSwatches {
+    fn id() -> Id {
+        Id::new("color_swatches")
+    }
+
+    pub fn load(ctx: &Context) -> Self {
+        ctx.data_mut(|d| d.get_persisted(Self::id()))
+            .unwrap_or_default()
+    }
+
+    pub fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_persisted(Self::id(), self));
+    }
+
+    /// Put `color` first in [`Self::recent`], keeping at most [`Self::max_recent`] colors.
+    pub fn add_recent(&mut self, color: Color32) {
+        self.recent.retain(|&recent| recent != color);
+        self.recent.insert(0, color);
+        self.recent.truncate(self.max_recent);
+    }
+
+    /// Move the palette swatch at index `from` to index `to`.
+    fn move_swatch(&mut self, from: usize, to: usize) {
+        if from < self.palette.len() {
+            let color = self.palette.remove(from);
+            self.palette.insert(to.min(self.palette.len()), color);
+        }
+    }
+}
+
+/// A clickable and draggable color.
+fn swatch_ui(ui: &mut Ui, color: Color32, sense: Sense) -> Response {
+    let size = Vec2::splat(ui.spacing().interact_size.y);
+    let (rect, response) = ui.allocate_exact_size(size, sense);
+    response.widget_info(|| WidgetInfo::new(WidgetType::ColorButton));
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        let rect = rect.expand(visuals.expansion);
+        show_color_at(ui.painter(), color, rect);
+        let rounding = visuals.rounding.at_most(2.0);
+        ui.painter()
+            .rect_stroke(rect, rounding, (2.0, visuals.bg_fill));
+    }
+
+    response
+}
+
+/// The [`ColorSwatches`], if the app has set any.
+///
+/// Returns `true` if the user picked one of them.
+fn swatches_ui(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> bool {
+    /// Drag-and-drop payload when reordering the palette.
+    struct PaletteIndex(usize);
+
+    let mut swatches = ColorSwatches::load(ui.ctx());
+    if swatches.palette.is_empty() && swatches.max_recent == 0 {
+        return false;
+    }
+    let before = swatches.clone();
+    let current = Color32::from(*hsva);
+    let mut picked = None;
+
+    ui.separator();
+    ui.horizontal_wrapped(|ui| {
+        let mut moved = None;
+        let mut removed = None;
+        for (index, &color) in swatches.palette.iter().enumerate() {
+            let response = swatch_ui(ui, color, Sense::click_and_drag())
+                .on_hover_text("Click to use, drag to reorder, right-click to remove");
+            response.dnd_set_drag_payload(PaletteIndex(index));
+            if let Some(from) = response.dnd_release_payload::<PaletteIndex>() {
+                moved = Some((from.0, index));
+            } else if response.dnd_hover_payload::<PaletteIndex>().is_some() {
+                ui.painter()
+                    .rect_stroke(response.rect, 0.0, ui.visuals().selection.stroke);
+            }
+            if response.clicked() {
+                picked = Some(color);
+            }
+            if response.secondary_clicked() {
+                removed = Some(index);
+            }
+        }
+
+        if let Some((from, to)) = moved {
+            swatches.move_swatch(from, to);
+        } else if let Some(index) = removed {
+            swatches.palette.remove(index);
+        }
+
+        let add_button = ui
+            .add_enabled(!swatches.palette.contains(&current), Button::new("+"))
+            .on_hover_text("Add the current color to the palette");
+        if add_button.clicked() {
+            swatches.palette.push(current);
+        }
+    });
+
+    if !swatches.recent.is_empty() {
+        ui.horizontal_wrapped(|ui| {
+            for &color in &swatches.recent {
+                if swatch_ui(ui, color, Sense::click())
+                    .on_hover_text("Recent color")
+                    .clicked()
+                {
+                    picked = Some(color);
+                }
+            }
+        });
+    }
+
+    if swatches != before {
+        swatches.store(ui.ctx());
+    }
+
+    if let Some(color) = picked {
+        let color = if alpha == Alpha::Opaque {
+            color.to_opaque()
+        } else {
+            color
+        };
+        *hsva = Hsva::from(color);
+        true
+    } else {
+        false
+    }
+}
+
 pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Response {
     let popup_id = ui.auto_id_with("popup");
     let open = ui.memory(|mem| mem.is_popup_open(popup_id));
@@ -498,6 +850,9 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
                     if color_picker_hsva_2d(ui, hsva, alpha) {
                         button_response.mark_changed();
                     }
+                    if swatches_ui(ui, hsva, alpha) {
+                        button_response.mark_changed();
+                    }
                 });
             })
             .response;
@@ -509,6 +864,26 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
         }
     }
 
+    // Remember the color when the popup opens, to add it to the recent colors if it changed:
+    let initial_id = popup_id.with("initial_color");
+    let is_open = ui.memory(|mem| mem.is_popup_open(popup_id));
+    let initial = ui.data(|d| d.get_temp::<Color32>(initial_id));
+    match (is_open, initial) {
+        (true, None) => ui.data_mut(|d| d.insert_temp(initial_id, Color32::from(*hsva))),
+        (false, Some(initial)) => {
+            ui.data_mut(|d| d.remove::<Color32>(initial_id));
+            let color = Color32::from(*hsva);
+            if color != initial {
+                let mut swatches = ColorSwatches::load(ui.ctx());
+                if 0 < swatches.max_recent {
+                    swatches.add_recent(color);
+                    swatches.store(ui.ctx());
+                }
+            }
+        }
+        _ => {}
+    }
+
     button_response
 }
 
@@ -571,3 +946,52 @@ fn color_cache_set(ctx: &Context, rgba: impl Into<Rgba>, hsva: Hsva) {
 fn use_color_cache<R>(ctx: &Context, f: impl FnOnce(&mut FixedCache<Rgba, Hsva>) -> R) -> R {
     ctx.data_mut(|d| f(d.get_temp_mut_or_default(Id::NULL)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#ff8000"), Some([255, 128, 0, 255]));
+        assert_eq!(parse_color(" FF800080 "), Some([255, 128, 0, 128]));
+        assert_eq!(parse_color("#f80"), Some([255, 136, 0, 255]));
+        assert_eq!(parse_color("#f808"), Some([255, 136, 0, 136]));
+        assert_eq!(parse_color("rgb(255, 128, 0)"), Some([255, 128, 0, 255]));
+        assert_eq!(parse_color("rgba(255,128,0,0.5)"), Some([255, 128, 0, 128]));
+        assert_eq!(
+            parse_color("rgb(100% 50% 0% / 50%)"),
+            Some([255, 128, 0, 128])
+        );
+
+        assert_eq!(parse_color(""), None);
+        assert_eq!(parse_color("#ff80"), Some([255, 255, 136, 0]));
+        assert_eq!(parse_color("#ff800"), None);
+        assert_eq!(parse_color("#gg8000"), None);
+        assert_eq!(parse_color("rgb(256, 0, 0)"), None);
+        assert_eq!(parse_color("rgba(0, 0, 0, 2)"), None);
+        assert_eq!(parse_color("rgb(0, 0)"), None);
+    }
+
+    #[test]
+    fn test_color_swatches() {
+        let [a, b, c, d] = [Color32::RED, Color32::GREEN, Color32::BLUE, Color32::BLACK];
+        let mut swatches = ColorSwatches {
+            palette: vec![a, b, c, d],
+            recent: vec![],
+            max_recent: 3,
+        };
+
+        swatches.move_swatch(0, 2);
+        assert_eq!(swatches.palette, vec![b, c, a, d]);
+        swatches.move_swatch(3, 0);
+        assert_eq!(swatches.palette, vec![d, b, c, a]);
+        swatches.move_swatch(1, 10);
+        assert_eq!(swatches.palette, vec![d, c, a, b]);
+
+        for color in [a, b, c, b, d] {
+            swatches.add_recent(color);
+        }
+        assert_eq!(swatches.recent, vec![d, b, c]);
+    }
+}
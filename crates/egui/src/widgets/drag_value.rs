@@ -42,7 +42,12 @@ pub struct DragValue<'a> {
     max_decimals: Option<usize>,
     custom_formatter: Option<NumFormatter<'a>>,
     custom_parser: Option<NumParser<'a>>,
+    units: Vec<(String, f64)>,
+    number_format: Option<NumberFormat>,
     update_while_editing: bool,
+    infinite_drag: bool,
+    with_buttons: bool,
+    validation: Option<ValidationState>,
 }
 
 impl<'a> DragValue<'a> {
@@ -74,7 +79,12 @@ impl<'a> DragValue<'a> {
             max_decimals: None,
             custom_formatter: None,
             custom_parser: None,
+            units: Vec::new(),
+            number_format: None,
             update_while_editing: true,
+            infinite_drag: false,
+            with_buttons: false,
+            validation: None,
         }
     }
 
@@ -195,6 +205,9 @@ impl<'a> DragValue<'a> {
     /// A custom parser takes an `&str` to parse into a number and returns a `f64` if it was successfully parsed
     /// or `None` otherwise.
     ///
+    /// Without a custom parser, the user can enter math expressions like `1920/2`,
+    /// see [`emath::expression`] and [`Self::unit`].
+    ///
     /// See also: [`DragValue::custom_formatter`]
     ///
     /// ```
@@ -232,6 +245,41 @@ impl<'a> DragValue<'a> {
         self
     }
 
+    /// Let the user enter values in another unit, e.g. centimeters in a field that is in meters.
+    ///
+    /// When the user types `25cm`, the value is set to `25.0 * factor`.
+    /// The suffix (e.g. `" m"`) is always understood as a unit with a factor of one.
+    /// Units can be mixed in math expressions, like `1m + 25cm`.
+    ///
+    /// This is ignored if you set a [`Self::custom_parser`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut meters: f64 = 1.0;
+    /// ui.add(egui::DragValue::new(&mut meters)
+    ///     .suffix(" m")
+    ///     .unit("cm", 0.01)
+    ///     .unit("mm", 0.001));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn unit(mut self, unit: impl ToString, factor: f64) -> Self {
+        self.units.push((unit.to_string(), factor));
+        self
+    }
+
+    /// How to show the number, e.g. with `,` as the decimal separator.
+    ///
+    /// The user can then also enter numbers in this format.
+    /// This is ignored for the parts you set a [`Self::custom_formatter`] or [`Self::custom_parser`] for.
+    ///
+    /// Default: [`crate::Style::number_format`].
+    #[inline]
+    pub fn number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = Some(number_format);
+        self
+    }
+
     /// Set `custom_formatter` and `custom_parser` to display and parse numbers as binary integers. Floating point
     /// numbers are *not* supported.
     ///
@@ -354,10 +402,52 @@ impl<'a> DragValue<'a> {
         self.update_while_editing = update;
         self
     }
+
+    /// Blender-style scrubbing: drag horizontally as far as you like, without hitting the edge of the screen.
+    ///
+    /// While dragging, the cursor is hidden and kept close to where the drag started,
+    /// and only horizontal movement changes the value.
+    /// When the drag ends, the cursor reappears where the drag started.
+    ///
+    /// This relies on the integration supporting [`ViewportCommand::CursorPosition`].
+    /// If it doesn't, the cursor is shown again and the drag is limited by the screen edges as usual.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn infinite_drag(mut self, infinite_drag: bool) -> Self {
+        self.infinite_drag = infinite_drag;
+        self
+    }
+
+    /// Show small up/down buttons next to the value, which change it by [`Self::speed`].
+    ///
+    /// Pressing and holding a button keeps changing the value.
+    /// This is easier to discover than dragging, especially on touch screens.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn with_buttons(mut self, with_buttons: bool) -> Self {
+        self.with_buttons = with_buttons;
+        self
+    }
+
+    /// Show the result of validating the value, with a colored frame and a message.
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn validation(mut self, validation: impl Into<Option<ValidationState>>) -> Self {
+        self.validation = validation.into();
+        self
+    }
 }
 
 impl<'a> Widget for DragValue<'a> {
     fn ui(self, ui: &mut Ui) -> Response {
+        if self.with_buttons && !ui.layout().is_horizontal() {
+            // Keep the buttons next to the value:
+            return ui.horizontal(|ui| self.ui(ui)).inner;
+        }
+
         let Self {
             mut get_set_value,
             speed,
@@ -368,13 +458,21 @@ impl<'a> Widget for DragValue<'a> {
             max_decimals,
             custom_formatter,
             custom_parser,
+            units,
+            number_format,
             update_while_editing,
+            infinite_drag,
+            with_buttons,
+            validation,
         } = self;
 
-        let shift = ui.input(|i| i.modifiers.shift_only());
+        let (shift, command) = ui.input(|i| (i.modifiers.shift_only(), i.modifiers.command_only()));
+        let number_format = number_format.unwrap_or(ui.style().number_format);
         // The widget has the same ID whether it's in edit or button mode.
         let id = ui.next_auto_id();
-        let is_slow_speed = shift && ui.ctx().is_being_dragged(id);
+        let is_being_dragged = ui.ctx().is_being_dragged(id);
+        let is_slow_speed = shift && is_being_dragged;
+        let is_fast_speed = command && is_being_dragged;
 
         // The following ensures that when a `DragValue` receives focus,
         // it is immediately rendered in edit mode, rather than being rendered
@@ -452,7 +550,10 @@ impl<'a> Widget for DragValue<'a> {
 
         let value_text = match custom_formatter {
             Some(custom_formatter) => custom_formatter(value, auto_decimals..=max_decimals),
-            None => emath::format_with_decimals_in_range(value, auto_decimals..=max_decimals),
+            None => number_format.localize(&emath::format_with_decimals_in_range(
+                value,
+                auto_decimals..=max_decimals,
+            )),
         };
 
         let text_style = ui.style().drag_value_text_style.clone();
@@ -464,7 +565,12 @@ impl<'a> Widget for DragValue<'a> {
                 // Make sure we applied the last text value:
                 let parsed_value = match &custom_parser {
                     Some(parser) => parser(&value_text),
-                    None => value_text.parse().ok(),
+                    None => parse_expression(
+                        &number_format.delocalize(&value_text),
+                        &prefix,
+                        &suffix,
+                        &units,
+                    ),
                 };
                 if let Some(parsed_value) = parsed_value {
                     let parsed_value = clamp_to_range(parsed_value, clamp_range.clone());
@@ -501,7 +607,12 @@ impl<'a> Widget for DragValue<'a> {
             if update {
                 let parsed_value = match &custom_parser {
                     Some(parser) => parser(&value_text),
-                    None => value_text.parse().ok(),
+                    None => parse_expression(
+                        &number_format.delocalize(&value_text),
+                        &prefix,
+                        &suffix,
+                        &units,
+                    ),
                 };
                 if let Some(parsed_value) = parsed_value {
                     let parsed_value = clamp_to_range(parsed_value, clamp_range.clone());
@@ -532,10 +643,14 @@ impl<'a> Widget for DragValue<'a> {
 
             if ui.style().explanation_tooltips {
                 response = response.on_hover_text(format!(
-                    "{}{}{}\nDrag to edit or click to enter a value.\nPress 'Shift' while dragging for better control.",
+                    "{}{}{}\nDrag to edit or click to enter a value.\nPress 'Shift' while dragging for better control, or '{}' to go faster.",
                     prefix,
-                    value as f32, // Show full precision value on-hover. TODO(emilk): figure out f64 vs f32
-                    suffix
+                    number_format.localize(&(value as f32).to_string()), // Show full precision value on-hover. TODO(emilk): figure out f64 vs f32
+                    suffix,
+                    ModifierNames::NAMES.format(
+                        &Modifiers::COMMAND,
+                        matches!(ui.ctx().os(), os::OperatingSystem::Mac | os::OperatingSystem::IOS)
+                    ),
                 ));
             }
 
@@ -554,12 +669,27 @@ impl<'a> Widget for DragValue<'a> {
                 )));
                 state.store(ui.ctx(), response.id);
             } else if response.dragged() {
-                ui.ctx().set_cursor_icon(cursor_icon);
-
-                let mdelta = response.drag_delta();
-                let delta_points = mdelta.x - mdelta.y; // Increase to the right and up
+                let delta_points = if infinite_drag {
+                    let (delta, cursor_is_kept_in_place) = infinite_drag_delta(ui, id, &response);
+                    ui.ctx().set_cursor_icon(if cursor_is_kept_in_place {
+                        CursorIcon::None
+                    } else {
+                        cursor_icon
+                    });
+                    delta.x // Only horizontal, so the pointer can drift vertically without effect
+                } else {
+                    ui.ctx().set_cursor_icon(cursor_icon);
+                    let mdelta = response.drag_delta();
+                    mdelta.x - mdelta.y // Increase to the right and up
+                };
 
-                let speed = if is_slow_speed { speed / 10.0 } else { speed };
+                let speed = if is_slow_speed {
+                    speed / 10.0
+                } else if is_fast_speed {
+                    speed * 10.0
+                } else {
+                    speed
+                };
 
                 let delta_value = delta_points as f64 * speed;
 
@@ -581,12 +711,36 @@ impl<'a> Widget for DragValue<'a> {
 
                     ui.data_mut(|data| data.insert_temp::<f64>(id, precise_value));
                 }
+            } else if response.drag_stopped() && infinite_drag {
+                end_infinite_drag(ui, id);
             }
 
             response
         };
 
-        response.changed = get(&mut get_set_value) != old_value;
+        if with_buttons {
+            let value = get(&mut get_set_value);
+            let (stepper_response, steps) = stepper_buttons_ui(
+                ui,
+                id,
+                response.rect.height(),
+                value > *clamp_range.start(),
+                value < *clamp_range.end(),
+            );
+            if steps != 0.0 {
+                let new_value = emath::round_to_decimals(value + speed * steps, auto_decimals);
+                set(
+                    &mut get_set_value,
+                    clamp_to_range(new_value, clamp_range.clone()),
+                );
+                ui.data_mut(|data| data.remove::<String>(id));
+            }
+            response = response.union(stepper_response);
+        }
+
+        // The value may have been dragged or stepped:
+        let value = get(&mut get_set_value);
+        response.changed = value != old_value;
 
         response.widget_info(|| WidgetInfo::drag_value(value));
 
@@ -637,10 +791,247 @@ impl<'a> Widget for DragValue<'a> {
             }
         });
 
+        if let Some(validation) = validation {
+            let frame_rect = response
+                .rect
+                .expand(ui.style().interact(&response).expansion);
+            response = validation.show(ui, response, frame_rect);
+        }
+
         response
     }
 }
 
+// ----------------------------------------------------------------------------
+
+/// The default parser: a plain number, or a math expression with units (see [`DragValue::unit`]).
+fn parse_expression(
+    text: &str,
+    prefix: &str,
+    suffix: &str,
+    units: &[(String, f64)],
+) -> Option<f64> {
+    if let Ok(value) = text.parse() {
+        return Some(value);
+    }
+
+    let text = text.trim();
+    let text = text.strip_prefix(prefix.trim()).unwrap_or(text);
+    let suffix = suffix.trim();
+    let unit = |name: &str| {
+        units
+            .iter()
+            .find(|(unit, _)| unit == name)
+            .map(|(_, factor)| *factor)
+            .or_else(|| (name == suffix).then_some(1.0))
+    };
+
+    // The suffix may not be a valid unit (e.g. `px/s`), so also try without it:
+    text.strip_suffix(suffix)
+        .filter(|_| !suffix.is_empty())
+        .and_then(|text| emath::expression::eval_with_units(text, unit))
+        .or_else(|| emath::expression::eval_with_units(text, unit))
+}
+
+// ----------------------------------------------------------------------------
+
+/// Press and hold a [`DragValue::with_buttons`] button this long before it starts repeating.
+const STEPPER_REPEAT_DELAY: f64 = 0.5;
+
+/// How often a held [`DragValue::with_buttons`] button repeats, in seconds.
+const STEPPER_REPEAT_INTERVAL: f64 = 0.05;
+
+/// The up/down buttons of [`DragValue::with_buttons`], as tall as the value.
+///
+/// Returns the response of the buttons, and how many steps to change the value by.
+fn stepper_buttons_ui(
+    ui: &mut Ui,
+    id: Id,
+    height: f32,
+    can_decrement: bool,
+    can_increment: bool,
+) -> (Response, f64) {
+    let size = vec2(ui.spacing().icon_width, height);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+    // Only the value itself is focusable; keyboard users have the arrow keys.
+    // Sensing drags keeps the button pressed after it is held too long to be a click.
+    let sense = Sense {
+        click: true,
+        drag: true,
+        focusable: false,
+    };
+    let (top, bottom) = rect.split_top_bottom_at_fraction(0.5);
+    let increment = ui.interact(top, id.with("increment"), sense);
+    let decrement = ui.interact(bottom, id.with("decrement"), sense);
+
+    // Step once when pressed, then repeat after a delay for as long as the button is held:
+    let state_id = id.with("stepper_repeat");
+    let is_held =
+        |button: &Response| button.is_pointer_button_down_on() && button.contains_pointer();
+    let direction = if is_held(&increment) {
+        1.0
+    } else if is_held(&decrement) {
+        -1.0
+    } else {
+        0.0
+    };
+    let mut steps = 0.0;
+    if direction == 0.0 {
+        ui.data_mut(|data| data.remove::<(f64, u32)>(state_id));
+    } else {
+        let now = ui.input(|i| i.time);
+        let (press_time, steps_done) = ui
+            .data(|data| data.get_temp::<(f64, u32)>(state_id))
+            .unwrap_or((now, 0));
+        let held_for = now - press_time;
+        let steps_due = if held_for < STEPPER_REPEAT_DELAY {
+            1
+        } else {
+            2 + ((held_for - STEPPER_REPEAT_DELAY) / STEPPER_REPEAT_INTERVAL) as u32
+        };
+        steps = direction * steps_due.saturating_sub(steps_done) as f64;
+        ui.data_mut(|data| data.insert_temp(state_id, (press_time, steps_due)));
+        ui.ctx()
+            .request_repaint_after(std::time::Duration::from_secs_f64(STEPPER_REPEAT_INTERVAL));
+    }
+
+    if ui.is_rect_visible(rect) {
+        let rounding = ui.visuals().widgets.inactive.rounding;
+        for (button, enabled, is_increment) in [
+            (&increment, can_increment, true),
+            (&decrement, can_decrement, false),
+        ] {
+            let visuals = ui.style().interact(button);
+            let button_rounding = if is_increment {
+                Rounding {
+                    nw: rounding.nw,
+                    ne: rounding.ne,
+                    ..Rounding::ZERO
+                }
+            } else {
+                Rounding {
+                    sw: rounding.sw,
+                    se: rounding.se,
+                    ..Rounding::ZERO
+                }
+            };
+            ui.painter().rect(
+                button.rect,
+                button_rounding,
+                visuals.weak_bg_fill,
+                visuals.bg_stroke,
+            );
+
+            let color = if enabled {
+                visuals.fg_stroke.color
+            } else {
+                ui.visuals().weak_text_color()
+            };
+            let icon = Rect::from_center_size(
+                button.rect.center(),
+                vec2(button.rect.width() * 0.5, button.rect.height() * 0.3),
+            );
+            let points = if is_increment {
+                vec![icon.left_bottom(), icon.right_bottom(), icon.center_top()]
+            } else {
+                vec![icon.left_top(), icon.right_top(), icon.center_bottom()]
+            };
+            ui.painter()
+                .add(Shape::convex_polygon(points, color, Stroke::NONE));
+        }
+    }
+
+    (response | increment | decrement, steps)
+}
+
+// ----------------------------------------------------------------------------
+
+/// How far the hidden cursor may wander from where an [`DragValue::infinite_drag`] started,
+/// before we move it back.
+const INFINITE_DRAG_RADIUS: f32 = 64.0;
+
+/// Stored in temp data during a [`DragValue::infinite_drag`].
+#[derive(Clone, Copy, Debug)]
+struct InfiniteDragState {
+    /// Where the drag started, and where we keep moving the cursor back to.
+    origin: Pos2,
+
+    /// Where the pointer was last frame, or where we moved it to.
+    last_pos: Pos2,
+
+    /// We asked the integration to move the cursor to `origin`, and are waiting for it to happen.
+    warp_pending: bool,
+
+    /// The integration doesn't move the cursor for us, so we have to make do with a normal drag.
+    warp_unsupported: bool,
+}
+
+/// How much the pointer moved this frame during an infinite drag,
+/// and whether or not the cursor is being kept in place (and so should be hidden).
+fn infinite_drag_delta(ui: &Ui, id: Id, response: &Response) -> (Vec2, bool) {
+    let state_id = id.with("infinite_drag");
+    let Some(pos) = ui.input(|i| i.pointer.latest_pos()) else {
+        return (Vec2::ZERO, false);
+    };
+
+    let mut state = ui
+        .data(|data| data.get_temp::<InfiniteDragState>(state_id))
+        .unwrap_or_else(|| {
+            let last_pos = pos - response.drag_delta();
+            InfiniteDragState {
+                origin: ui.input(|i| i.pointer.press_origin()).unwrap_or(last_pos),
+                last_pos,
+                warp_pending: false,
+                warp_unsupported: false,
+            }
+        });
+
+    let mut delta = pos - state.last_pos;
+
+    if state.warp_pending && pos != state.last_pos {
+        // The first movement after asking for a warp tells us if the cursor was actually moved.
+        // The user can't move the pointer far in a single frame, so if we are still far from
+        // the origin, the integration ignored us:
+        state.warp_pending = false;
+        if pos.distance(state.origin) < pos.distance(state.last_pos) {
+            delta = pos - state.origin;
+        } else {
+            state.warp_unsupported = true;
+        }
+    }
+    state.last_pos = pos;
+
+    if !state.warp_unsupported
+        && !state.warp_pending
+        && INFINITE_DRAG_RADIUS < pos.distance(state.origin)
+    {
+        ui.ctx()
+            .send_viewport_cmd(ViewportCommand::CursorPosition(state.origin));
+        state.warp_pending = true;
+    }
+
+    ui.data_mut(|data| data.insert_temp(state_id, state));
+
+    (delta, !state.warp_unsupported)
+}
+
+/// Put the cursor back where the infinite drag started.
+fn end_infinite_drag(ui: &Ui, id: Id) {
+    let state_id = id.with("infinite_drag");
+    let state = ui.data_mut(|data| {
+        let state = data.get_temp::<InfiniteDragState>(state_id);
+        data.remove::<InfiniteDragState>(state_id);
+        state
+    });
+    if let Some(state) = state {
+        if !state.warp_unsupported {
+            ui.ctx()
+                .send_viewport_cmd(ViewportCommand::CursorPosition(state.origin));
+        }
+    }
+}
+
 fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
     let (mut min, mut max) = (*range.start(), *range.end());
 
@@ -659,7 +1050,9 @@ fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
 
 #[cfg(test)]
 mod tests {
-    use super::clamp_to_range;
+    use super::{
+        clamp_to_range, parse_expression, DragValue, STEPPER_REPEAT_DELAY, STEPPER_REPEAT_INTERVAL,
+    };
 
     macro_rules! total_assert_eq {
         ($a:expr, $b:expr) => {
@@ -672,6 +1065,165 @@ mod tests {
         };
     }
 
+    /// Show a [`DragValue::with_buttons`] for `value` in a frame at `time`.
+    fn run_stepper(
+        ctx: &crate::Context,
+        value: &mut f64,
+        time: f64,
+        events: Vec<crate::Event>,
+    ) -> (crate::Response, crate::FullOutput) {
+        let input = crate::RawInput {
+            time: Some(time),
+            events,
+            ..Default::default()
+        };
+        let mut response = None;
+        let output = ctx.run(input, |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                response = Some(
+                    ui.add(
+                        DragValue::new(value)
+                            .speed(1.0)
+                            .clamp_range(0.0..=10.0)
+                            .with_buttons(true),
+                    ),
+                );
+            });
+        });
+        (response.unwrap(), output)
+    }
+
+    fn pointer_button(pos: crate::Pos2, pressed: bool) -> crate::Event {
+        crate::Event::PointerButton {
+            pos,
+            button: crate::PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        }
+    }
+
+    /// The center of the increment (upper) or decrement (lower) button.
+    fn stepper_button_pos(
+        ctx: &crate::Context,
+        response: &crate::Response,
+        up: bool,
+    ) -> crate::Pos2 {
+        let width = ctx.style().spacing.icon_width;
+        let rect = response.rect;
+        let y = if up {
+            rect.top() + rect.height() / 4.0
+        } else {
+            rect.bottom() - rect.height() / 4.0
+        };
+        crate::pos2(rect.right() - width / 2.0, y)
+    }
+
+    #[test]
+    fn test_stepper_buttons() {
+        let ctx = crate::Context::default();
+        let mut value = 5.0;
+        let (response, _) = run_stepper(&ctx, &mut value, 0.0, vec![]);
+        let up = stepper_button_pos(&ctx, &response, true);
+        let down = stepper_button_pos(&ctx, &response, false);
+
+        let (response, output) = run_stepper(
+            &ctx,
+            &mut value,
+            0.1,
+            vec![crate::Event::PointerMoved(up), pointer_button(up, true)],
+        );
+        assert_eq!(value, 6.0, "Steps once when pressed");
+        assert!(response.changed());
+        let reported = output
+            .platform_output
+            .events
+            .iter()
+            .find_map(|event| match event {
+                crate::output::OutputEvent::ValueChanged(info) => info.value,
+                _ => None,
+            });
+        assert_eq!(reported, Some(6.0), "The new value is reported");
+
+        let _ = run_stepper(&ctx, &mut value, 0.2, vec![pointer_button(up, false)]);
+        assert_eq!(value, 6.0);
+
+        let _ = run_stepper(
+            &ctx,
+            &mut value,
+            0.3,
+            vec![crate::Event::PointerMoved(down), pointer_button(down, true)],
+        );
+        assert_eq!(value, 5.0);
+
+        // Holding the button repeats after a delay:
+        let _ = run_stepper(&ctx, &mut value, 0.6, vec![]);
+        assert_eq!(value, 5.0);
+        let _ = run_stepper(
+            &ctx,
+            &mut value,
+            0.3 + STEPPER_REPEAT_DELAY + 2.5 * STEPPER_REPEAT_INTERVAL,
+            vec![],
+        );
+        assert_eq!(
+            value, 2.0,
+            "One step when pressed, one after the delay, and one per interval after that"
+        );
+
+        // Clamped to the range:
+        let _ = run_stepper(&ctx, &mut value, 2.0, vec![]);
+        assert_eq!(value, 0.0);
+    }
+
+    #[test]
+    fn test_stepper_buttons_beside_the_value_in_vertical_layout() {
+        let ctx = crate::Context::default();
+        let mut value = 5.0;
+        let _ = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let response = ui.add(DragValue::new(&mut value).with_buttons(true));
+                let width = ui.spacing().icon_width;
+                assert!(width < response.rect.width());
+                assert!(response.rect.height() < 2.0 * ui.spacing().interact_size.y);
+            });
+        });
+    }
+
+    #[test]
+    fn test_baseline_stays_when_editing() {
+        let ctx = crate::Context::default();
+        let mut value = 5.0;
+        let mut run = |events: Vec<crate::Event>| {
+            let input = crate::RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut baselines = None;
+            let _ = ctx.run(input, |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    ui.horizontal_baseline(|ui| {
+                        let heading = ui.heading("Value:");
+                        let drag_value = ui.add(DragValue::new(&mut value));
+                        baselines = Some((heading.baseline, drag_value));
+                    });
+                });
+            });
+            baselines.unwrap()
+        };
+
+        run(vec![]);
+        let (heading_baseline, response) = run(vec![]);
+        assert!(heading_baseline.is_some());
+        assert_eq!(response.baseline, heading_baseline);
+
+        // Clicking starts editing with the keyboard:
+        let pos = response.rect.center();
+        run(vec![pointer_button(pos, true)]);
+        run(vec![pointer_button(pos, false)]);
+        let (_, response) = run(vec![]);
+        assert!(response.has_focus());
+        assert_eq!(response.baseline, heading_baseline);
+    }
+
     #[test]
     fn test_total_cmp_clamp_to_range() {
         total_assert_eq!(0.0_f64, clamp_to_range(-0.0, 0.0..=f64::MAX));
@@ -685,4 +1237,16 @@ mod tests {
         total_assert_eq!(5.0_f64, clamp_to_range(15.0, 5.0..=1.0));
         total_assert_eq!(1.0_f64, clamp_to_range(-5.0, 5.0..=1.0));
     }
+
+    #[test]
+    fn test_parse_expression() {
+        let units = [("cm".to_owned(), 0.01)];
+        assert_eq!(parse_expression("inf", "", "", &[]), Some(f64::INFINITY));
+        assert_eq!(parse_expression("1920/2", "", "", &[]), Some(960.0));
+        assert_eq!(parse_expression("x: 3*1.5+2", "x: ", "", &[]), Some(6.5));
+        assert_eq!(parse_expression("50%", "", "%", &[]), Some(50.0));
+        assert_eq!(parse_expression("2 m + 50cm", "", " m", &units), Some(2.5));
+        assert_eq!(parse_expression("10 px/s", "", " px/s", &[]), Some(10.0));
+        assert_eq!(parse_expression("10 m", "", "", &[]), None);
+    }
 }
@@ -0,0 +1,300 @@
+use crate::*;
+
+use self::text_selection::CCursorRange;
+use epaint::text::cursor::CCursor;
+
+type Validator<'t> = Box<dyn Fn(&str) -> Result<(), String> + 't>;
+
+/// What we remember about an [`EditableLabel`] while it is being edited.
+#[derive(Clone, Default)]
+struct EditableLabelState {
+    /// The text being edited. `None` on the first frame of editing.
+    buffer: Option<String>,
+}
+
+/// A label that can be renamed in place.
+///
+/// Shows as a label until the user double-clicks it, or presses F2 while it has keyboard focus.
+/// It then turns into a single-line [`TextEdit`] with all text selected.
+///
+/// * Enter, or clicking elsewhere, commits the new text.
+/// * Escape cancels, restoring the old text.
+///
+/// [`Response::changed`] is only set when a new text is committed,
+/// not for every keystroke while editing.
+///
+/// The label and the [`TextEdit`] share the same [`Id`],
+/// so keyboard focus is kept when switching between them.
+/// In lists where items can be added, removed or reordered (file trees, tab strips, …)
+/// you should set a stable [`Self::id_source`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut name = "untitled.txt".to_owned();
+/// let response = ui.add(
+///     egui::EditableLabel::new(&mut name)
+///         .id_source("file_name")
+///         .validator(|name| {
+///             if name.trim().is_empty() {
+///                 Err("The name can't be empty".to_owned())
+///             } else {
+///                 Ok(())
+///             }
+///         }),
+/// );
+/// if response.changed() {
+///     // rename the file
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct EditableLabel<'t> {
+    text: &'t mut String,
+    id_source: Option<Id>,
+    validator: Option<Validator<'t>>,
+}
+
+impl<'t> EditableLabel<'t> {
+    pub fn new(text: &'t mut String) -> Self {
+        Self {
+            text,
+            id_source: None,
+            validator: None,
+        }
+    }
+
+    /// Use this to keep the editing state stable when the label moves around,
+    /// e.g. when items are added or removed above it.
+    ///
+    /// By default the [`Id`] is based on the position in the [`Ui`].
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Check the edited text before it is committed.
+    ///
+    /// Return an error message to reject the text.
+    /// The error is shown while editing, and pressing Enter won't commit the text.
+    /// Clicking elsewhere with an invalid text cancels the edit.
+    #[inline]
+    pub fn validator(mut self, validator: impl Fn(&str) -> Result<(), String> + 't) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Start editing the label with the given [`Id`], e.g. from a "Rename" context menu.
+    ///
+    /// The id is [`Response::id`] of the label.
+    pub fn start_editing(ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, EditableLabelState::default()));
+        ctx.memory_mut(|mem| mem.request_focus(id));
+        ctx.request_repaint();
+    }
+
+    /// Stop editing the label with the given [`Id`], discarding any edits.
+    pub fn stop_editing(ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.remove::<EditableLabelState>(id));
+    }
+
+    /// Is the label with the given [`Id`] currently being edited?
+    pub fn is_editing(ctx: &Context, id: Id) -> bool {
+        ctx.data(|d| d.get_temp::<EditableLabelState>(id).is_some())
+    }
+}
+
+impl<'t> Widget for EditableLabel<'t> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            text,
+            id_source,
+            validator,
+        } = self;
+
+        let id = id_source.map_or_else(
+            || ui.next_auto_id(),
+            |id_source| ui.make_persistent_id(id_source),
+        );
+
+        // Same margin as the `TextEdit`, so that the text doesn't move when we start editing:
+        let margin = Margin::symmetric(4.0, 2.0);
+
+        let Some(mut state) = ui.data(|d| d.get_temp::<EditableLabelState>(id)) else {
+            return label_ui(ui, id, text, margin);
+        };
+
+        let just_started = state.buffer.is_none();
+        let mut buffer = state.buffer.take().unwrap_or_else(|| text.clone());
+
+        let mut output = TextEdit::singleline(&mut buffer)
+            .id(id)
+            .margin(margin)
+            .clip_text(false)
+            .desired_width(0.0)
+            .show(ui);
+
+        if just_started {
+            let select_all =
+                CCursorRange::two(CCursor::new(0), CCursor::new(buffer.chars().count()));
+            output.state.cursor.set_char_range(Some(select_all));
+            output.state.clone().store(ui.ctx(), id);
+            output.response.request_focus();
+        }
+
+        let mut response = output.response;
+        response.changed = false; // Only when committed, see below
+
+        // Validate what was typed this frame, so that we never commit an invalid text:
+        let error = validator
+            .as_ref()
+            .and_then(|validator| validator(&buffer).err());
+
+        let (enter, escape) = ui.input(|i| (i.key_pressed(Key::Enter), i.key_pressed(Key::Escape)));
+        let lost_focus = response.lost_focus() || (!just_started && !response.has_focus());
+
+        if lost_focus && escape {
+            EditableLabel::stop_editing(ui.ctx(), id);
+            response.request_focus(); // give the focus back to the label
+        } else if lost_focus && enter && error.is_some() {
+            // Keep editing until the text is valid:
+            response.request_focus();
+            state.buffer = Some(buffer);
+            ui.data_mut(|d| d.insert_temp(id, state));
+        } else if lost_focus {
+            if error.is_none() && *text != buffer {
+                *text = buffer;
+                response.mark_changed();
+            }
+            EditableLabel::stop_editing(ui.ctx(), id);
+            if enter {
+                response.request_focus(); // give the focus back to the label
+            }
+        } else {
+            state.buffer = Some(buffer);
+            ui.data_mut(|d| d.insert_temp(id, state));
+        }
+
+        if let Some(error) = error.filter(|_| EditableLabel::is_editing(ui.ctx(), id)) {
+            let error_color = ui.visuals().error_fg_color;
+            ui.painter().rect_stroke(
+                response.rect,
+                ui.visuals().widgets.inactive.rounding,
+                (1.0, error_color),
+            );
+            show_tooltip_for(ui.ctx(), id, &response.rect, |ui| {
+                ui.colored_label(error_color, error);
+            });
+        }
+
+        response
+    }
+}
+
+fn label_ui(ui: &mut Ui, id: Id, text: &str, margin: Margin) -> Response {
+    let galley = WidgetText::from(text).into_galley(
+        ui,
+        Some(TextWrapMode::Extend),
+        f32::INFINITY,
+        TextStyle::Body,
+    );
+
+    let mut desired_size = galley.size() + margin.sum();
+    desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
+    let (_, rect) = ui.allocate_space(desired_size);
+    let response = ui.interact(rect, id, Sense::click());
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, galley.text()));
+
+    if response.double_clicked() || (response.has_focus() && ui.input(|i| i.key_pressed(Key::F2))) {
+        EditableLabel::start_editing(ui.ctx(), id);
+    }
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        if response.hovered() || response.has_focus() {
+            ui.painter().rect(
+                rect.expand(visuals.expansion),
+                visuals.rounding,
+                visuals.weak_bg_fill,
+                visuals.bg_stroke,
+            );
+        }
+
+        let text_pos = ui
+            .layout()
+            .align_size_within_rect(galley.size(), rect - margin)
+            .min;
+        ui.painter().galley(text_pos, galley, visuals.text_color());
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_press(key: Key) -> Event {
+        Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    /// Runs a frame with the given events, and returns the response of the label.
+    fn run_frame(ctx: &Context, name: &mut String, events: Vec<Event>) -> Response {
+        let raw_input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut response = None;
+        let _ = ctx.run(raw_input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let label = EditableLabel::new(name)
+                    .id_source("name")
+                    .validator(|name| {
+                        if name.is_empty() {
+                            Err("Empty".to_owned())
+                        } else {
+                            Ok(())
+                        }
+                    });
+                response = Some(ui.add(label));
+            });
+        });
+        response.unwrap()
+    }
+
+    #[test]
+    fn test_edit_and_commit() {
+        let ctx = Context::default();
+        let mut name = "name".to_owned();
+        let id = run_frame(&ctx, &mut name, vec![]).id;
+
+        EditableLabel::start_editing(&ctx, id);
+        run_frame(&ctx, &mut name, vec![]); // selects all the text
+        let events = vec![Event::Text("new".to_owned()), key_press(Key::Enter)];
+        assert!(run_frame(&ctx, &mut name, events).changed());
+        assert_eq!(name, "new");
+        assert!(!EditableLabel::is_editing(&ctx, id));
+    }
+
+    #[test]
+    fn test_enter_validates_the_edited_text() {
+        let ctx = Context::default();
+        let mut name = "name".to_owned();
+        let id = run_frame(&ctx, &mut name, vec![]).id;
+
+        EditableLabel::start_editing(&ctx, id);
+        run_frame(&ctx, &mut name, vec![]); // selects all the text
+
+        // Erasing the text and pressing Enter in the same frame must not commit the empty text:
+        let events = vec![key_press(Key::Backspace), key_press(Key::Enter)];
+        assert!(!run_frame(&ctx, &mut name, events).changed());
+        assert_eq!(name, "name");
+        assert!(EditableLabel::is_editing(&ctx, id));
+    }
+}
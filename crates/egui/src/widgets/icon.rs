@@ -0,0 +1,367 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use epaint::{mutex::Mutex, Color32, FontFamily, FontId, Pos2, Rect, Shape, Stroke, Vec2};
+
+use crate::{
+    vec2, Align2, Painter, Response, Sense, TextStyle, Ui, Widget, WidgetInfo, WidgetType,
+};
+
+/// A function that paints an icon into the given square, in the given color.
+pub type IconPaintFn = dyn Fn(&Painter, Rect, Color32) + Send + Sync;
+
+/// How to paint an icon registered in the [`IconRegistry`].
+#[derive(Clone)]
+pub enum IconSource {
+    /// A single character of a font, e.g. from an icon font you have added to [`crate::FontDefinitions`].
+    Glyph { character: char, family: FontFamily },
+
+    /// Painted with shapes, so it is crisp at any size.
+    Painted(Arc<IconPaintFn>),
+}
+
+impl IconSource {
+    /// A character of the given font family.
+    pub fn glyph(character: char, family: FontFamily) -> Self {
+        Self::Glyph { character, family }
+    }
+
+    /// Painted by the given function.
+    pub fn painted(paint: impl Fn(&Painter, Rect, Color32) + Send + Sync + 'static) -> Self {
+        Self::Painted(Arc::new(paint))
+    }
+
+    /// Paint the icon into the given square.
+    pub fn paint(&self, painter: &Painter, rect: Rect, color: Color32) {
+        match self {
+            Self::Glyph { character, family } => {
+                painter.text(
+                    rect.center(),
+                    Align2::CENTER_CENTER,
+                    character,
+                    FontId::new(rect.height(), family.clone()),
+                    color,
+                );
+            }
+            Self::Painted(paint) => paint(painter, rect, color),
+        }
+    }
+}
+
+impl std::fmt::Debug for IconSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Glyph { character, family } => f
+                .debug_struct("Glyph")
+                .field("character", character)
+                .field("family", family)
+                .finish(),
+            Self::Painted(_) => f.write_str("Painted"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The icons available to [`Icon`], by name.
+///
+/// Comes with a small built-in set of symbolic icons:
+/// `add`, `remove`, `check`, `close`, `chevron_left`, `chevron_right`, `chevron_up`, `chevron_down`,
+/// `menu`, `search`, `info`, `warning` and `refresh`.
+///
+/// Apps can add their own icons (or replace the built-in ones) with [`Self::insert`]:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.ctx().icons().insert("save", egui::IconSource::glyph('💾', egui::FontFamily::Proportional));
+/// ui.add(egui::Icon::new("save"));
+/// # });
+/// ```
+pub struct IconRegistry {
+    icons: Mutex<BTreeMap<String, IconSource>>,
+}
+
+impl Default for IconRegistry {
+    fn default() -> Self {
+        let icons = BUILTIN_ICONS
+            .iter()
+            .map(|&(name, paint)| (name.to_owned(), IconSource::painted(paint)))
+            .collect();
+        Self {
+            icons: Mutex::new(icons),
+        }
+    }
+}
+
+impl IconRegistry {
+    /// Add an icon, replacing any previous icon with the same name.
+    pub fn insert(&self, name: impl Into<String>, icon: IconSource) {
+        self.icons.lock().insert(name.into(), icon);
+    }
+
+    /// Remove an icon, returning it if it was registered.
+    pub fn remove(&self, name: &str) -> Option<IconSource> {
+        self.icons.lock().remove(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<IconSource> {
+        self.icons.lock().get(name).cloned()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.icons.lock().contains_key(name)
+    }
+
+    /// The names of all registered icons, in alphabetical order.
+    pub fn names(&self) -> Vec<String> {
+        self.icons.lock().keys().cloned().collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// An icon from the [`IconRegistry`], sized to match text and colored like other widgets.
+///
+/// By default the icon is as large as the [`TextStyle::Body`] font (or [`crate::Style::override_text_style`]),
+/// and occupies a full row height so that it lines up with labels in a horizontal layout.
+///
+/// If the icon is given a [`Sense`], it is colored according to its interaction state
+/// (hovered, clicked, …), just like the text of a button.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.horizontal(|ui| {
+///     ui.add(egui::Icon::new("info"));
+///     ui.label("Some information");
+/// });
+///
+/// if ui.add(egui::Icon::new("close").sense(egui::Sense::click())).clicked() {
+///     // …
+/// }
+/// # });
+/// ```
+///
+/// Unknown icon names are painted as a box in [`crate::Visuals::error_fg_color`].
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Icon {
+    name: String,
+    text_style: Option<TextStyle>,
+    size: Option<f32>,
+    color: Option<Color32>,
+    sense: Sense,
+}
+
+impl Icon {
+    /// The icon registered under this name in the [`IconRegistry`].
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            text_style: None,
+            size: None,
+            color: None,
+            sense: Sense::hover(),
+        }
+    }
+
+    /// Size the icon to match this text style.
+    #[inline]
+    pub fn text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = Some(text_style);
+        self
+    }
+
+    /// Set an exact size (width and height) of the icon, overriding the text style.
+    #[inline]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Override the color, which otherwise depends on the interaction state.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Make the icon respond to clicks and/or drags.
+    ///
+    /// By default, icons only sense hovers.
+    #[inline]
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.sense = sense;
+        self
+    }
+}
+
+impl Widget for Icon {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            name,
+            text_style,
+            size,
+            color,
+            sense,
+        } = self;
+
+        let text_style = text_style
+            .or_else(|| ui.style().override_text_style.clone())
+            .unwrap_or(TextStyle::Body);
+        let font_id = text_style.resolve(ui.style());
+        let size = size.unwrap_or(font_id.size);
+        let row_height = ui.fonts(|f| f.row_height(&font_id)).max(size);
+
+        let (rect, response) = ui.allocate_exact_size(vec2(size, row_height), sense);
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Other, &name));
+
+        if ui.is_rect_visible(rect) {
+            let color = color.unwrap_or_else(|| {
+                if sense.interactive() {
+                    ui.style().interact(&response).fg_stroke.color
+                } else {
+                    ui.visuals().text_color()
+                }
+            });
+            let icon_rect = Rect::from_center_size(rect.center(), Vec2::splat(size));
+
+            if let Some(icon) = ui.ctx().icons().get(&name) {
+                icon.paint(ui.painter(), icon_rect, color);
+            } else {
+                let stroke = Stroke::new(1.0, ui.visuals().error_fg_color);
+                ui.painter().rect_stroke(icon_rect.shrink(1.0), 0.0, stroke);
+            }
+        }
+
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+// The built-in icons are drawn in a unit square, with some padding.
+
+type BuiltinIcon = fn(&Painter, Rect, Color32);
+
+const BUILTIN_ICONS: &[(&str, BuiltinIcon)] = &[
+    ("add", paint_add),
+    ("remove", paint_remove),
+    ("check", paint_check),
+    ("close", paint_close),
+    ("chevron_left", |p, r, c| {
+        paint_chevron(p, r, c, vec2(-1.0, 0.0));
+    }),
+    ("chevron_right", |p, r, c| {
+        paint_chevron(p, r, c, vec2(1.0, 0.0));
+    }),
+    ("chevron_up", |p, r, c| {
+        paint_chevron(p, r, c, vec2(0.0, -1.0));
+    }),
+    ("chevron_down", |p, r, c| {
+        paint_chevron(p, r, c, vec2(0.0, 1.0));
+    }),
+    ("menu", paint_menu),
+    ("search", paint_search),
+    ("info", paint_info),
+    ("warning", paint_warning),
+    ("refresh", paint_refresh),
+];
+
+/// Maps the unit square (with padding) to `rect`, and picks a stroke width that scales with it.
+fn icon_frame(rect: Rect, color: Color32) -> (impl Fn(f32, f32) -> Pos2, Stroke) {
+    let inner = rect.shrink(0.125 * rect.width());
+    let stroke = Stroke::new((0.1 * rect.width()).max(1.0), color);
+    (move |x, y| inner.lerp_inside(vec2(x, y)), stroke)
+}
+
+fn paint_add(painter: &Painter, rect: Rect, color: Color32) {
+    let (p, stroke) = icon_frame(rect, color);
+    painter.line_segment([p(0.5, 0.0), p(0.5, 1.0)], stroke);
+    painter.line_segment([p(0.0, 0.5), p(1.0, 0.5)], stroke);
+}
+
+fn paint_remove(painter: &Painter, rect: Rect, color: Color32) {
+    let (p, stroke) = icon_frame(rect, color);
+    painter.line_segment([p(0.0, 0.5), p(1.0, 0.5)], stroke);
+}
+
+fn paint_check(painter: &Painter, rect: Rect, color: Color32) {
+    let (p, stroke) = icon_frame(rect, color);
+    painter.add(Shape::line(
+        vec![p(0.0, 0.55), p(0.35, 0.9), p(1.0, 0.15)],
+        stroke,
+    ));
+}
+
+fn paint_close(painter: &Painter, rect: Rect, color: Color32) {
+    let (p, stroke) = icon_frame(rect, color);
+    painter.line_segment([p(0.1, 0.1), p(0.9, 0.9)], stroke);
+    painter.line_segment([p(0.9, 0.1), p(0.1, 0.9)], stroke);
+}
+
+fn paint_chevron(painter: &Painter, rect: Rect, color: Color32, dir: Vec2) {
+    let (p, stroke) = icon_frame(rect, color);
+    let at = |along: f32, across: f32| {
+        let v = 0.5 * Vec2::splat(1.0) + along * dir + across * dir.rot90();
+        p(v.x, v.y)
+    };
+    painter.add(Shape::line(
+        vec![at(-0.2, -0.4), at(0.2, 0.0), at(-0.2, 0.4)],
+        stroke,
+    ));
+}
+
+fn paint_menu(painter: &Painter, rect: Rect, color: Color32) {
+    let (p, stroke) = icon_frame(rect, color);
+    for y in [0.15, 0.5, 0.85] {
+        painter.line_segment([p(0.0, y), p(1.0, y)], stroke);
+    }
+}
+
+fn paint_search(painter: &Painter, rect: Rect, color: Color32) {
+    let (p, stroke) = icon_frame(rect, color);
+    let center = p(0.4, 0.4);
+    let radius = center.distance(p(0.4, 0.0));
+    painter.circle_stroke(center, radius, stroke);
+    painter.line_segment([p(0.68, 0.68), p(1.0, 1.0)], stroke);
+}
+
+fn paint_info(painter: &Painter, rect: Rect, color: Color32) {
+    let (p, stroke) = icon_frame(rect, color);
+    let radius = 0.5 * p(0.0, 0.0).distance(p(1.0, 0.0));
+    painter.circle_stroke(p(0.5, 0.5), radius, stroke);
+    painter.circle_filled(p(0.5, 0.27), stroke.width * 0.6, color);
+    painter.line_segment([p(0.5, 0.45), p(0.5, 0.78)], stroke);
+}
+
+fn paint_warning(painter: &Painter, rect: Rect, color: Color32) {
+    let (p, stroke) = icon_frame(rect, color);
+    painter.add(Shape::closed_line(
+        vec![p(0.5, 0.0), p(1.0, 0.95), p(0.0, 0.95)],
+        stroke,
+    ));
+    painter.line_segment([p(0.5, 0.35), p(0.5, 0.62)], stroke);
+    painter.circle_filled(p(0.5, 0.78), stroke.width * 0.6, color);
+}
+
+fn paint_refresh(painter: &Painter, rect: Rect, color: Color32) {
+    use std::f32::consts::TAU;
+
+    let (p, stroke) = icon_frame(rect, color);
+    let center = p(0.5, 0.5);
+    let radius = 0.45 * p(0.0, 0.0).distance(p(1.0, 0.0));
+    let end_angle = 0.95 * TAU;
+    painter.add(Shape::arc(center, radius, 0.1 * TAU, end_angle, stroke));
+
+    // Arrow head at the end of the arc, pointing along it:
+    let tip = center + radius * Vec2::angled(end_angle);
+    let forward = Vec2::angled(end_angle).rot90();
+    let outward = Vec2::angled(end_angle);
+    let head = 0.25 * radius;
+    painter.add(Shape::convex_polygon(
+        vec![
+            tip + head * forward,
+            tip - head * outward,
+            tip + head * outward,
+        ],
+        color,
+        Stroke::NONE,
+    ));
+}
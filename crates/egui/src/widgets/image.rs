@@ -50,6 +50,7 @@ pub struct Image<'a> {
     sense: Sense,
     size: ImageSize,
     pub(crate) show_loading_spinner: Option<bool>,
+    pub(crate) current_color: Option<Color32>,
 }
 
 impl<'a> Image<'a> {
@@ -75,6 +76,7 @@ impl<'a> Image<'a> {
                 sense: Sense::hover(),
                 size,
                 show_loading_spinner: None,
+                current_color: None,
             }
         }
 
@@ -215,6 +217,21 @@ impl<'a> Image<'a> {
         self
     }
 
+    /// Replace `currentColor` in an SVG image with this color.
+    ///
+    /// Use [`Color32::PLACEHOLDER`] to follow the text color of the widget,
+    /// so that e.g. monochrome icons work with both light and dark mode.
+    ///
+    /// Unlike [`Self::tint`], this only recolors the parts of the image that use `currentColor`.
+    /// Each color is rasterized separately.
+    /// This requires an image loader with support for it, like the svg loader in `egui_extras`,
+    /// and is ignored for images that are not SVG:s.
+    #[inline]
+    pub fn current_color(mut self, color: impl Into<Color32>) -> Self {
+        self.current_color = Some(color.into());
+        self
+    }
+
     /// Rotate the image about an origin by some angle
     ///
     /// Positive angle is clockwise.
@@ -236,12 +253,47 @@ impl<'a> Image<'a> {
     /// The default is no rounding ([`Rounding::ZERO`]).
     ///
     /// Due to limitations in the current implementation,
-    /// this will turn off any rotation of the image.
+    /// this will turn off any rotation and nine-slicing of the image.
     #[inline]
     pub fn rounding(mut self, rounding: impl Into<Rounding>) -> Self {
         self.image_options.rounding = rounding.into();
         if self.image_options.rounding != Rounding::ZERO {
             self.image_options.rotation = None; // incompatible with rounding
+            self.image_options.nine_slice = None; // incompatible with rounding
+        }
+        self
+    }
+
+    /// Scale the image "nine-slice" style: the borders keep their size and only the middle is stretched.
+    ///
+    /// The `insets` are the sizes of the borders in the source image, in the same units as its size
+    /// (usually pixels), and the borders are painted at that many points.
+    /// This is the usual way to paint textured buttons, frames and speech bubbles at any size.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let texture = egui::load::SizedTexture::new(egui::TextureId::default(), [32.0, 32.0]);
+    /// ui.add(
+    ///     egui::Image::new(texture)
+    ///         .nine_slice(egui::Margin::same(8.0))
+    ///         .fit_to_exact_size(egui::vec2(200.0, 64.0)),
+    /// );
+    /// # });
+    /// ```
+    ///
+    /// Due to limitations in the current implementation,
+    /// this will turn off rounding of the image.
+    #[inline]
+    pub fn nine_slice(mut self, insets: impl Into<Margin>) -> Self {
+        self.image_options.nine_slice = Some(insets.into());
+        self.image_options.rounding = Rounding::ZERO; // incompatible with nine-slice
+        self
+    }
+
+    /// Replace [`Color32::PLACEHOLDER`] in [`Self::current_color`] with the given text color.
+    pub(crate) fn resolve_current_color(mut self, text_color: Color32) -> Self {
+        if self.current_color == Some(Color32::PLACEHOLDER) {
+            self.current_color = Some(text_color);
         }
         self
     }
@@ -290,7 +342,28 @@ impl<'a> Image<'a> {
 
     #[inline]
     pub fn source(&'a self, ctx: &Context) -> ImageSource<'a> {
+        let current_color = self.current_color.map(|color| {
+            if color == Color32::PLACEHOLDER {
+                ctx.style().visuals.text_color()
+            } else {
+                color
+            }
+        });
+
         match &self.source {
+            ImageSource::Uri(uri) if is_svg_uri(uri) => match current_color {
+                Some(color) => ImageSource::Uri(Cow::Owned(encode_current_color_uri(uri, color))),
+                None => self.source.clone(),
+            },
+
+            ImageSource::Bytes { uri, bytes } if is_svg_uri(uri) => match current_color {
+                Some(color) => {
+                    ctx.include_bytes(uri.clone(), bytes.clone());
+                    ImageSource::Uri(Cow::Owned(encode_current_color_uri(uri, color)))
+                }
+                None => self.source.clone(),
+            },
+
             ImageSource::Uri(uri) if is_gif_uri(uri) => {
                 let frame_uri = encode_gif_uri(uri, gif_frame_index(ctx, uri));
                 ImageSource::Uri(Cow::Owned(frame_uri))
@@ -312,10 +385,39 @@ impl<'a> Image<'a> {
     /// # Errors
     /// May fail if they underlying [`Context::try_load_texture`] call fails.
     pub fn load_for_size(&self, ctx: &Context, available_size: Vec2) -> TextureLoadResult {
-        let size_hint = self.size.hint(available_size);
-        self.source(ctx)
+        let pixels_per_point = match self.size.fit {
+            // Other images have the same size for every `SizeHint::Scale`,
+            // and that size is their size in points.
+            ImageFit::Original { .. } if !self.is_svg() => 1.0,
+            _ => ctx.pixels_per_point(),
+        };
+        let size_hint = self
+            .size
+            .hint_for_pixels_per_point(available_size, pixels_per_point);
+        let tlr = self
+            .source(ctx)
             .clone()
-            .load(ctx, self.texture_options, size_hint)
+            .load(ctx, self.texture_options, size_hint);
+
+        if !matches!(self.size.fit, ImageFit::Original { .. }) {
+            return tlr;
+        }
+        // The original size was scaled to physical pixels, so scale it back to points:
+        tlr.map(|poll| match poll {
+            TexturePoll::Pending { size } => TexturePoll::Pending {
+                size: size.map(|size| size / pixels_per_point),
+            },
+            TexturePoll::Ready { texture } => TexturePoll::Ready {
+                texture: SizedTexture::new(texture.id, texture.size / pixels_per_point),
+            },
+        })
+    }
+
+    fn is_svg(&self) -> bool {
+        match &self.source {
+            ImageSource::Uri(uri) | ImageSource::Bytes { uri, .. } => is_svg_uri(uri),
+            ImageSource::Texture(_) => false,
+        }
     }
 
     /// Paint the image in the given rectangle.
@@ -331,9 +433,12 @@ impl<'a> Image<'a> {
     /// ```
     #[inline]
     pub fn paint_at(&self, ui: &Ui, rect: Rect) {
+        let image = self
+            .clone()
+            .resolve_current_color(ui.visuals().text_color());
         paint_texture_load_result(
             ui,
-            &self.load_for_size(ui.ctx(), rect.size()),
+            &image.load_for_size(ui.ctx(), rect.size()),
             rect,
             self.show_loading_spinner,
             &self.image_options,
@@ -343,21 +448,22 @@ impl<'a> Image<'a> {
 
 impl<'a> Widget for Image<'a> {
     fn ui(self, ui: &mut Ui) -> Response {
-        let tlr = self.load_for_size(ui.ctx(), ui.available_size());
+        let image = self.resolve_current_color(ui.visuals().text_color());
+        let tlr = image.load_for_size(ui.ctx(), ui.available_size());
         let original_image_size = tlr.as_ref().ok().and_then(|t| t.size());
-        let ui_size = self.calc_size(ui.available_size(), original_image_size);
+        let ui_size = image.calc_size(ui.available_size(), original_image_size);
 
-        let (rect, response) = ui.allocate_exact_size(ui_size, self.sense);
+        let (rect, response) = ui.allocate_exact_size(ui_size, image.sense);
         if ui.is_rect_visible(rect) {
             paint_texture_load_result(
                 ui,
                 &tlr,
                 rect,
-                self.show_loading_spinner,
-                &self.image_options,
+                image.show_loading_spinner,
+                &image.image_options,
             );
         }
-        texture_load_result_response(&self.source(ui.ctx()), &tlr, response)
+        texture_load_result_response(&image.source(ui.ctx()), &tlr, response)
     }
 }
 
@@ -416,17 +522,30 @@ impl ImageFit {
 }
 
 impl ImageSize {
-    /// Size hint for e.g. rasterizing an svg.
+    /// Size hint for e.g. rasterizing an svg, in points.
     pub fn hint(&self, available_size: Vec2) -> SizeHint {
+        self.hint_for_pixels_per_point(available_size, 1.0)
+    }
+
+    /// Size hint for e.g. rasterizing an svg, in physical pixels,
+    /// so that the image is sharp at any zoom level.
+    ///
+    /// For [`ImageFit::Original`] the scale is multiplied by `pixels_per_point`,
+    /// so the loaded image is `pixels_per_point` times its original size.
+    pub fn hint_for_pixels_per_point(
+        &self,
+        available_size: Vec2,
+        pixels_per_point: f32,
+    ) -> SizeHint {
         let size = match self.fit {
-            ImageFit::Original { scale } => return SizeHint::Scale(scale.ord()),
+            ImageFit::Original { scale } => {
+                return SizeHint::Scale((scale * pixels_per_point).ord())
+            }
             ImageFit::Fraction(fract) => available_size * fract,
             ImageFit::Exact(size) => size,
         };
 
-        let size = size.min(self.max_size);
-
-        // TODO(emilk): take pixels_per_point into account here!
+        let size = size.min(self.max_size) * pixels_per_point;
 
         // `inf` on an axis means "any value"
         match (size.x.is_finite(), size.y.is_finite()) {
@@ -591,6 +710,25 @@ pub fn paint_texture_load_result(
     match tlr {
         Ok(TexturePoll::Ready { texture }) => {
             paint_texture_at(ui.painter(), rect, options, texture);
+
+            let show_loading_spinner =
+                show_loading_spinner.unwrap_or(ui.visuals().image_loading_spinners);
+            if show_loading_spinner {
+                if let Some(progress) = texture_upload_progress(ui.ctx(), texture.id) {
+                    // The texture is being uploaded in chunks (see `Options::texture_upload_budget`):
+                    let bar = Rect::from_min_max(
+                        rect.left_bottom() - vec2(0.0, 4.0),
+                        rect.right_bottom(),
+                    );
+                    let visuals = ui.visuals();
+                    ui.painter().rect_filled(bar, 0.0, visuals.extreme_bg_color);
+                    ui.painter().rect_filled(
+                        bar.with_max_x(lerp(bar.x_range(), progress)),
+                        0.0,
+                        visuals.selection.bg_fill,
+                    );
+                }
+            }
         }
         Ok(TexturePoll::Pending { .. }) => {
             let show_loading_spinner =
@@ -612,6 +750,13 @@ pub fn paint_texture_load_result(
     }
 }
 
+/// How much of a texture that is being uploaded in chunks has been uploaded, in `[0, 1)`.
+///
+/// See [`crate::Options::texture_upload_budget`].
+fn texture_upload_progress(ctx: &crate::Context, texture_id: TextureId) -> Option<f32> {
+    ctx.tex_manager().read().upload_progress(texture_id)
+}
+
 /// Attach tooltips like "Loading…" or "Failed loading: …".
 pub fn texture_load_result_response(
     source: &ImageSource<'_>,
@@ -619,7 +764,15 @@ pub fn texture_load_result_response(
     response: Response,
 ) -> Response {
     match tlr {
-        Ok(TexturePoll::Ready { .. }) => response,
+        Ok(TexturePoll::Ready { texture }) => {
+            if let Some(progress) = texture_upload_progress(&response.ctx, texture.id) {
+                let uri = source.uri().unwrap_or("image");
+                let percent = (100.0 * progress) as u32;
+                response.on_hover_text(format!("Uploading {uri}… {percent}%"))
+            } else {
+                response
+            }
+        }
         Ok(TexturePoll::Pending { .. }) => {
             let uri = source.uri().unwrap_or("image");
             response.on_hover_text(format!("Loading {uri}…"))
@@ -729,8 +882,16 @@ pub struct ImageOptions {
     /// The default is no rounding ([`Rounding::ZERO`]).
     ///
     /// Due to limitations in the current implementation,
-    /// this will turn off any rotation of the image.
+    /// this will turn off any rotation and nine-slicing of the image.
     pub rounding: Rounding,
+
+    /// Keep the borders of the image at this size, and only stretch the middle.
+    ///
+    /// The insets are in the same units as the size of the texture (usually pixels),
+    /// and the borders are painted at that many points.
+    ///
+    /// See [`Image::nine_slice`].
+    pub nine_slice: Option<Margin>,
 }
 
 impl Default for ImageOptions {
@@ -741,6 +902,7 @@ impl Default for ImageOptions {
             tint: Color32::WHITE,
             rotation: None,
             rounding: Rounding::ZERO,
+            nine_slice: None,
         }
     }
 }
@@ -755,6 +917,36 @@ pub fn paint_texture_at(
         painter.add(RectShape::filled(rect, options.rounding, options.bg_fill));
     }
 
+    if let Some(insets) = options.nine_slice {
+        debug_assert!(
+            options.rounding == Rounding::ZERO,
+            "Image had both rounding and nine-slice. Please pick only one"
+        );
+
+        let to_uv = |inset: f32, texture_size: f32, uv_size: f32| {
+            if 0.0 < texture_size {
+                inset / texture_size * uv_size
+            } else {
+                0.0
+            }
+        };
+        let (texture_size, uv_size) = (texture.size, options.uv.size());
+        let uv_insets = Margin {
+            left: to_uv(insets.left, texture_size.x, uv_size.x),
+            right: to_uv(insets.right, texture_size.x, uv_size.x),
+            top: to_uv(insets.top, texture_size.y, uv_size.y),
+            bottom: to_uv(insets.bottom, texture_size.y, uv_size.y),
+        };
+
+        let mut mesh = Mesh::with_texture(texture.id);
+        mesh.add_nine_slice_with_uv(rect, insets, options.uv, uv_insets, options.tint);
+        if let Some((rot, origin)) = options.rotation {
+            mesh.rotate(rot, rect.min + origin * rect.size());
+        }
+        painter.add(Shape::mesh(mesh));
+        return;
+    }
+
     match options.rotation {
         Some((rot, origin)) => {
             // TODO(emilk): implement this using `PathShape` (add texture support to it).
@@ -778,6 +970,7 @@ pub fn paint_texture_at(
                 blur_width: 0.0,
                 fill_texture_id: texture.id,
                 uv: options.uv,
+                angle: 0.0,
             });
         }
     }
@@ -801,6 +994,31 @@ pub fn decode_gif_uri(uri: &str) -> Result<(&str, usize), String> {
     Ok((uri, index))
 }
 
+/// svg uris with a `currentColor` contain the uri & the color
+fn encode_current_color_uri(uri: &str, color: Color32) -> String {
+    format!("{uri}#currentColor={}", color.to_hex())
+}
+
+/// Extracts the uri and the color to use for `currentColor`, if any.
+///
+/// # Errors
+/// Will return `Err` if the color in `{uri}#currentColor={color}` is not a valid hex color.
+pub fn decode_current_color_uri(uri: &str) -> Result<(&str, Option<Color32>), String> {
+    match uri.split_once("#currentColor=") {
+        Some((uri, color)) => {
+            let color = Color32::from_hex(color)
+                .map_err(|err| format!("Failed to parse currentColor {color:?}: {err:?}"))?;
+            Ok((uri, Some(color)))
+        }
+        None => Ok((uri, None)),
+    }
+}
+
+/// checks if uri is an svg file
+fn is_svg_uri(uri: &str) -> bool {
+    uri.ends_with(".svg") || uri.contains(".svg#")
+}
+
 /// checks if uri is a gif file
 fn is_gif_uri(uri: &str) -> bool {
     uri.ends_with(".gif") || uri.contains(".gif#")
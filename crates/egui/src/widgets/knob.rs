@@ -0,0 +1,666 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::{f32::consts::TAU, ops::RangeInclusive};
+
+use crate::*;
+
+use super::slider::{
+    clamp_to_range, get, normalized_from_value, round_to_step, set, value_from_normalized,
+    GetSetValue, NumFormatter, NumParser, SliderSpec,
+};
+
+// ----------------------------------------------------------------------------
+
+/// How the user drags a [`Knob`] to change its value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KnobDragMode {
+    /// Drag up or right to increase the value, and down or left to decrease it.
+    ///
+    /// This is what most audio software does, and it allows fine control.
+    #[default]
+    Vertical,
+
+    /// Point at the value on the dial: the knob turns to face the pointer.
+    Circular,
+}
+
+/// The angle of the minimum value, measured clockwise from the right (so this is down-left).
+const START_ANGLE: f32 = 0.375 * TAU;
+
+/// How far the knob turns from its minimum to its maximum value.
+const SWEEP: f32 = 0.75 * TAU;
+
+/// Detent marks are only painted if there are at most this many steps.
+const MAX_DETENT_MARKS: f64 = 48.0;
+
+/// A rotary knob (or dial) to control a number, like on audio equipment.
+///
+/// The knob has the same formatting options as a [`Slider`], and like a slider it always
+/// stays within its range.
+/// The user changes the value by dragging (see [`KnobDragMode`]),
+/// scrolling over it, or with the arrow keys.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut gain_db: f32 = 0.0;
+/// ui.add(egui::Knob::new(&mut gain_db, -24.0..=24.0).suffix(" dB").text("Gain"));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Knob<'a> {
+    get_set_value: GetSetValue<'a>,
+    range: RangeInclusive<f64>,
+    spec: SliderSpec,
+    drag_mode: KnobDragMode,
+    diameter: Option<f32>,
+    show_value: bool,
+    prefix: String,
+    suffix: String,
+    text: WidgetText,
+
+    /// Sets the minimal step of the widget value
+    step: Option<f64>,
+
+    min_decimals: usize,
+    max_decimals: Option<usize>,
+    custom_formatter: Option<NumFormatter<'a>>,
+    custom_parser: Option<NumParser<'a>>,
+}
+
+impl<'a> Knob<'a> {
+    pub fn new<Num: emath::Numeric>(value: &'a mut Num, range: RangeInclusive<Num>) -> Self {
+        let range_f64 = range.start().to_f64()..=range.end().to_f64();
+        let slf = Self::from_get_set(range_f64, move |v: Option<f64>| {
+            if let Some(v) = v {
+                *value = Num::from_f64(v);
+            }
+            value.to_f64()
+        });
+
+        if Num::INTEGRAL {
+            slf.integer()
+        } else {
+            slf
+        }
+    }
+
+    pub fn from_get_set(
+        range: RangeInclusive<f64>,
+        get_set_value: impl 'a + FnMut(Option<f64>) -> f64,
+    ) -> Self {
+        Self {
+            get_set_value: Box::new(get_set_value),
+            range,
+            spec: SliderSpec {
+                logarithmic: false,
+                smallest_positive: 1e-6,
+                largest_finite: f64::INFINITY,
+            },
+            drag_mode: KnobDragMode::default(),
+            diameter: None,
+            show_value: true,
+            prefix: Default::default(),
+            suffix: Default::default(),
+            text: Default::default(),
+            step: None,
+            min_decimals: 0,
+            max_decimals: None,
+            custom_formatter: None,
+            custom_parser: None,
+        }
+    }
+
+    /// How the user drags the knob. Default: [`KnobDragMode::Vertical`].
+    #[inline]
+    pub fn drag_mode(mut self, drag_mode: KnobDragMode) -> Self {
+        self.drag_mode = drag_mode;
+        self
+    }
+
+    /// The size of the knob.
+    ///
+    /// Default: twice the height of [`crate::style::Spacing::interact_size`].
+    #[inline]
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// Control whether or not the knob shows the current value next to it.
+    /// Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Show a prefix before the number, e.g. "x: "
+    #[inline]
+    pub fn prefix(mut self, prefix: impl ToString) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Add a suffix to the number, this can be e.g. a unit ("°" or " m")
+    #[inline]
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Show a text next to the knob (e.g. explaining what the knob controls).
+    #[inline]
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Make this a logarithmic knob, like [`Slider::logarithmic`].
+    ///
+    /// This is great for frequencies and gains, where the interesting values span many orders of magnitude.
+    #[inline]
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.spec.logarithmic = logarithmic;
+        self
+    }
+
+    /// For logarithmic knobs that includes zero:
+    /// what is the smallest positive value you want to be able to select?
+    /// The default is `1` for integer knobs and `1e-6` for real knobs.
+    #[inline]
+    pub fn smallest_positive(mut self, smallest_positive: f64) -> Self {
+        self.spec.smallest_positive = smallest_positive;
+        self
+    }
+
+    /// For logarithmic knobs, the largest positive value we are interested in
+    /// before the knob switches to `INFINITY`, if that is the higher end.
+    /// Default: INFINITY.
+    #[inline]
+    pub fn largest_finite(mut self, largest_finite: f64) -> Self {
+        self.spec.largest_finite = largest_finite;
+        self
+    }
+
+    /// Snap the value to multiples of `step`, counted from the start of the range.
+    ///
+    /// The knob then has detents: each arrow key press moves it one step,
+    /// and if there are few enough steps they are marked around the dial.
+    ///
+    /// Default: `0.0` (disabled).
+    #[inline]
+    pub fn step_by(mut self, step: f64) -> Self {
+        self.step = if step != 0.0 { Some(step) } else { None };
+        self
+    }
+
+    /// Set a minimum number of decimals to display.
+    ///
+    /// Normally you don't need to pick a precision, as the knob will intelligently pick a precision for you.
+    #[inline]
+    pub fn min_decimals(mut self, min_decimals: usize) -> Self {
+        self.min_decimals = min_decimals;
+        self
+    }
+
+    /// Set a maximum number of decimals to display.
+    ///
+    /// Values will also be rounded to this number of decimals.
+    /// Normally you don't need to pick a precision, as the knob will intelligently pick a precision for you.
+    #[inline]
+    pub fn max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    /// Set an exact number of decimals to display.
+    ///
+    /// Values will also be rounded to this number of decimals.
+    /// Normally you don't need to pick a precision, as the knob will intelligently pick a precision for you.
+    #[inline]
+    pub fn fixed_decimals(mut self, num_decimals: usize) -> Self {
+        self.min_decimals = num_decimals;
+        self.max_decimals = Some(num_decimals);
+        self
+    }
+
+    /// Set custom formatter defining how numbers are converted into text,
+    /// like [`Slider::custom_formatter`].
+    pub fn custom_formatter(
+        mut self,
+        formatter: impl 'a + Fn(f64, RangeInclusive<usize>) -> String,
+    ) -> Self {
+        self.custom_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Set custom parser defining how the text input is parsed into a number,
+    /// like [`Slider::custom_parser`].
+    #[inline]
+    pub fn custom_parser(mut self, parser: impl 'a + Fn(&str) -> Option<f64>) -> Self {
+        self.custom_parser = Some(Box::new(parser));
+        self
+    }
+
+    /// Helper: equivalent to `self.fixed_decimals(0).smallest_positive(1.0).step_by(1.0)`.
+    /// If you use one of the integer constructors this is called for you,
+    /// but if you want to have a knob for picking integer values of an `f64`, use this.
+    pub fn integer(self) -> Self {
+        self.fixed_decimals(0).smallest_positive(1.0).step_by(1.0)
+    }
+
+    fn get_value(&mut self) -> f64 {
+        clamp_to_range(get(&mut self.get_set_value), &self.range)
+    }
+
+    fn set_value(&mut self, value: f64) {
+        let value = clamp_to_range(value, &self.range);
+        let value = round_to_step(value, &self.range, self.max_decimals, self.step);
+        set(&mut self.get_set_value, value);
+    }
+
+    fn value_from_normalized(&self, normalized: f64) -> f64 {
+        value_from_normalized(normalized, self.range.clone(), &self.spec)
+    }
+
+    fn normalized_from_value(&self, value: f64) -> f64 {
+        normalized_from_value(value, self.range.clone(), &self.spec)
+    }
+
+    /// How many points the user needs to drag or scroll to go through the whole range.
+    fn drag_distance(ui: &Ui) -> f32 {
+        ui.spacing().slider_width
+    }
+
+    /// Move the value by `delta` in normalized `[0, 1]` range.
+    ///
+    /// We remember the unrounded position, so that slow drags and scrolls add up
+    /// even if each frame moves less than a step.
+    fn move_normalized(&mut self, ui: &Ui, id: Id, delta: f64) {
+        let value = self.get_value();
+        let normalized = ui
+            .data(|data| data.get_temp::<(f64, f64)>(id))
+            .filter(|&(_, last_value)| last_value == value)
+            .map_or_else(|| self.normalized_from_value(value), |(precise, _)| precise);
+        let normalized = (normalized + delta).clamp(0.0, 1.0);
+        self.set_value(self.value_from_normalized(normalized));
+        let value = self.get_value();
+        ui.data_mut(|data| data.insert_temp(id, (normalized, value)));
+    }
+
+    /// Just the knob, no text
+    fn knob_ui(&mut self, ui: &Ui, response: &Response) {
+        let rect = response.rect;
+        let precise_id = response.id.with("precise_normalized");
+
+        if response.dragged() {
+            match self.drag_mode {
+                KnobDragMode::Vertical => {
+                    let delta = response.drag_delta();
+                    // Hold shift for fine control:
+                    let shift = ui.input(|i| i.modifiers.shift_only());
+                    let slow_down = if shift { 10.0 } else { 1.0 };
+                    let delta_points = delta.x - delta.y; // Increase to the right and up
+                    if delta_points != 0.0 {
+                        let delta = delta_points / Self::drag_distance(ui) / slow_down;
+                        self.move_normalized(ui, precise_id, delta as f64);
+                    }
+                }
+                KnobDragMode::Circular => {
+                    if let Some(pointer_pos) = response.interact_pointer_pos() {
+                        let offset = pointer_pos - rect.center();
+                        if offset.length() > 2.0 {
+                            let angle = (offset.angle() - START_ANGLE).rem_euclid(TAU);
+                            let normalized = if angle <= SWEEP {
+                                angle / SWEEP
+                            } else if angle < SWEEP + 0.5 * (TAU - SWEEP) {
+                                1.0 // in the gap at the bottom, closer to the maximum
+                            } else {
+                                0.0
+                            };
+                            self.set_value(self.value_from_normalized(normalized as f64));
+                        }
+                    }
+                }
+            }
+        }
+
+        if response.hovered() {
+            let scroll = ui.input(|i| i.smooth_scroll_delta);
+            let scroll_points = scroll.x + scroll.y;
+            if scroll_points != 0.0 {
+                // The knob uses up the scroll, so that it doesn't also scroll a surrounding `ScrollArea`:
+                ui.input_mut(|i| i.smooth_scroll_delta = Vec2::ZERO);
+                let delta = scroll_points / Self::drag_distance(ui);
+                self.move_normalized(ui, precise_id, delta as f64);
+            }
+        }
+
+        let mut decrement = 0usize;
+        let mut increment = 0usize;
+
+        if response.has_focus() {
+            ui.ctx().memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    response.id,
+                    EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+
+            ui.input(|input| {
+                decrement += input.num_presses(Key::ArrowDown) + input.num_presses(Key::ArrowLeft);
+                increment += input.num_presses(Key::ArrowUp) + input.num_presses(Key::ArrowRight);
+            });
+        }
+
+        #[cfg(feature = "accesskit")]
+        {
+            use accesskit::Action;
+            ui.input(|input| {
+                decrement += input.num_accesskit_action_requests(response.id, Action::Decrement);
+                increment += input.num_accesskit_action_requests(response.id, Action::Increment);
+            });
+        }
+
+        let kb_step = increment as f64 - decrement as f64;
+
+        if kb_step != 0.0 {
+            let prev_value = self.get_value();
+            let new_value = if let Some(step) = self.step {
+                prev_value + kb_step * step
+            } else {
+                let normalized = self.normalized_from_value(prev_value) + 0.01 * kb_step;
+                self.value_from_normalized(normalized.clamp(0.0, 1.0))
+            };
+            self.set_value(new_value);
+        }
+
+        #[cfg(feature = "accesskit")]
+        {
+            use accesskit::{Action, ActionData};
+            ui.input(|input| {
+                for request in input.accesskit_action_requests(response.id, Action::SetValue) {
+                    if let Some(ActionData::NumericValue(new_value)) = request.data {
+                        self.set_value(new_value);
+                    }
+                }
+            });
+        }
+
+        if !response.dragged() && !response.hovered() {
+            ui.data_mut(|data| data.remove::<(f64, f64)>(precise_id));
+        }
+
+        // Paint it:
+        if ui.is_rect_visible(rect) {
+            self.paint(ui, response);
+        }
+    }
+
+    fn paint(&mut self, ui: &Ui, response: &Response) {
+        let value = self.get_value();
+        let normalized = self.normalized_from_value(value) as f32;
+
+        let visuals = ui.style().interact(response);
+        let widget_visuals = &ui.visuals().widgets;
+        let painter = ui.painter();
+
+        let center = response.rect.center();
+        let arc_width = (0.1 * response.rect.width()).at_least(2.0);
+        let arc_radius = 0.5 * (response.rect.width() - arc_width);
+        let body_radius = arc_radius - arc_width;
+
+        // The track, and the part of it up to the current value:
+        painter.add(Shape::arc(
+            center,
+            arc_radius,
+            START_ANGLE,
+            START_ANGLE + SWEEP,
+            Stroke::new(arc_width, widget_visuals.inactive.bg_fill),
+        ));
+        if normalized > 0.0 {
+            painter.add(Shape::arc(
+                center,
+                arc_radius,
+                START_ANGLE,
+                START_ANGLE + normalized * SWEEP,
+                Stroke::new(arc_width, ui.visuals().selection.bg_fill),
+            ));
+        }
+
+        // Detents:
+        if let Some(step) = self.step {
+            let (start, end) = (*self.range.start(), *self.range.end());
+            let num_steps = ((end - start) / step).abs().floor();
+            if num_steps <= MAX_DETENT_MARKS {
+                for i in 0..=num_steps as usize {
+                    let detent = start + i as f64 * step.abs() * (end - start).signum();
+                    let angle = START_ANGLE + self.normalized_from_value(detent) as f32 * SWEEP;
+                    let dir = Vec2::angled(angle);
+                    painter.line_segment(
+                        [
+                            center + dir * (body_radius - 0.25 * arc_width),
+                            center + dir * (body_radius - 0.75 * arc_width),
+                        ],
+                        widget_visuals.noninteractive.fg_stroke,
+                    );
+                }
+            }
+        }
+
+        // The knob itself, with a line pointing at the value:
+        let body_radius = body_radius - if self.step.is_some() { arc_width } else { 0.0 };
+        painter.add(epaint::CircleShape {
+            center,
+            radius: body_radius + visuals.expansion,
+            fill: visuals.bg_fill,
+            stroke: visuals.bg_stroke,
+        });
+        let dir = Vec2::angled(START_ANGLE + normalized * SWEEP);
+        painter.line_segment(
+            [center + dir * 0.3 * body_radius, center + dir * body_radius],
+            visuals.fg_stroke,
+        );
+    }
+
+    fn value_ui(&mut self, ui: &mut Ui) -> Response {
+        let value = self.get_value();
+        let normalized = self.normalized_from_value(value);
+        let half_point = 0.5 / Self::drag_distance(ui) as f64;
+        let speed = match self.step {
+            Some(step) => step,
+            None => {
+                self.value_from_normalized((normalized + half_point).min(1.0))
+                    - self.value_from_normalized((normalized - half_point).max(0.0))
+            }
+        };
+
+        let mut value = value;
+        let response = ui.add({
+            let mut dv = DragValue::new(&mut value)
+                .speed(speed)
+                .clamp_range(self.range.clone())
+                .min_decimals(self.min_decimals)
+                .max_decimals_opt(self.max_decimals)
+                .suffix(self.suffix.clone())
+                .prefix(self.prefix.clone());
+            if let Some(fmt) = &self.custom_formatter {
+                dv = dv.custom_formatter(fmt);
+            };
+            if let Some(parser) = &self.custom_parser {
+                dv = dv.custom_parser(parser);
+            }
+            dv
+        });
+        if value != self.get_value() {
+            self.set_value(value);
+        }
+        response
+    }
+
+    fn add_contents(&mut self, ui: &mut Ui) -> Response {
+        let old_value = self.get_value();
+
+        let diameter = self
+            .diameter
+            .unwrap_or_else(|| 2.0 * ui.spacing().interact_size.y);
+        let (_, mut response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::drag());
+        self.knob_ui(ui, &response);
+
+        let value = self.get_value();
+        response.changed = value != old_value;
+        response.widget_info(|| WidgetInfo::slider(value, self.text.text()));
+
+        #[cfg(feature = "accesskit")]
+        ui.ctx().accesskit_node_builder(response.id, |builder| {
+            use accesskit::Action;
+            builder.set_min_numeric_value(*self.range.start());
+            builder.set_max_numeric_value(*self.range.end());
+            if let Some(step) = self.step {
+                builder.set_numeric_value_step(step);
+            }
+            builder.add_action(Action::SetValue);
+            let (start, end) = (*self.range.start(), *self.range.end());
+            if value < start.max(end) {
+                builder.add_action(Action::Increment);
+            }
+            if value > start.min(end) {
+                builder.add_action(Action::Decrement);
+            }
+        });
+
+        let knob_response = response.clone();
+
+        let value_response = if self.show_value {
+            let value_response = self.value_ui(ui);
+            if value_response.gained_focus()
+                || value_response.has_focus()
+                || value_response.lost_focus()
+            {
+                // Use the [`DragValue`] id as the id of the whole widget,
+                // so that the focus events work as expected.
+                response = value_response.union(response);
+            } else {
+                // Use the knob id as the id for the whole widget
+                response = response.union(value_response.clone());
+            }
+            Some(value_response)
+        } else {
+            None
+        };
+
+        if !self.text.is_empty() {
+            let label_response =
+                ui.add(Label::new(self.text.clone()).wrap_mode(TextWrapMode::Extend));
+            knob_response.labelled_by(label_response.id);
+            if let Some(value_response) = value_response {
+                value_response.labelled_by(label_response.id);
+            }
+        }
+
+        response
+    }
+}
+
+impl<'a> Widget for Knob<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let inner_response = ui.horizontal(|ui| self.add_contents(ui));
+        inner_response.inner | inner_response.response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{pointer, press, run};
+    use crate::*;
+
+    /// A knob for `value` in `0..=10`.
+    fn knob(value: &mut i32, drag_mode: KnobDragMode) -> Knob<'_> {
+        Knob::new(value, 0..=10)
+            .drag_mode(drag_mode)
+            .show_value(false)
+    }
+
+    #[test]
+    fn test_vertical_drag_stays_in_range() {
+        let ctx = Context::default();
+        let mut value = 0;
+        let center = run(&ctx, vec![], |ui| {
+            ui.add(knob(&mut value, KnobDragMode::Vertical))
+        })
+        .rect
+        .center();
+        let drag_distance = ctx.style().spacing.slider_width;
+        let mut drag = |events| {
+            run(&ctx, events, |ui| {
+                ui.add(knob(&mut value, KnobDragMode::Vertical))
+            });
+            value
+        };
+
+        drag(pointer(center, Some(true)));
+
+        // Dragging up through half the distance goes through half the range:
+        let up = vec2(0.0, -0.5 * drag_distance);
+        assert_eq!(drag(pointer(center + up, None)), 5);
+
+        // Dragging further doesn't leave the range:
+        let up = vec2(0.0, -2.0 * drag_distance);
+        assert_eq!(drag(pointer(center + up, None)), 10);
+        assert_eq!(drag(pointer(center + up, Some(false))), 10);
+    }
+
+    #[test]
+    fn test_circular_drag() {
+        let ctx = Context::default();
+        let mut value = 0;
+        let rect = run(&ctx, vec![], |ui| {
+            ui.add(knob(&mut value, KnobDragMode::Circular))
+        })
+        .rect;
+        let mut drag = |events| {
+            run(&ctx, events, |ui| {
+                ui.add(knob(&mut value, KnobDragMode::Circular))
+            });
+            value
+        };
+
+        // Straight up is the middle of the range:
+        let top = rect.center_top() + vec2(0.0, 1.0);
+        drag(pointer(top, Some(true)));
+        assert_eq!(drag(pointer(top, None)), 5);
+
+        // Right of the bottom is in the gap, closer to the maximum:
+        let bottom_right = rect.center_bottom() + vec2(1.0, -1.0);
+        assert_eq!(drag(pointer(bottom_right, None)), 10);
+
+        drag(pointer(bottom_right, Some(false)));
+    }
+
+    #[test]
+    fn test_arrow_keys_step_within_range() {
+        let ctx = Context::default();
+        let mut value = 8;
+        let id = run(&ctx, vec![], |ui| {
+            ui.add(knob(&mut value, KnobDragMode::Vertical))
+        })
+        .id;
+        ctx.memory_mut(|mem| mem.request_focus(id));
+        let mut press_keys = |keys: &[Key]| {
+            let events = keys.iter().map(|&key| press(key)).collect();
+            run(&ctx, events, |ui| {
+                ui.add(knob(&mut value, KnobDragMode::Vertical))
+            });
+            value
+        };
+
+        press_keys(&[]);
+        assert_eq!(press_keys(&[Key::ArrowUp]), 9);
+        assert_eq!(press_keys(&[Key::ArrowUp, Key::ArrowRight]), 10);
+        assert_eq!(press_keys(&[Key::ArrowDown]), 9);
+    }
+}
@@ -133,7 +133,11 @@ impl Label {
 
         if let WidgetText::Galley(galley) = self.text {
             // If the user said "use this specific galley", then just use it:
-            let (rect, response) = ui.allocate_exact_size(galley.size(), sense);
+            let (rect, response) = ui.allocate_exact_size_with_baseline(
+                galley.size(),
+                galley.first_row_baseline(),
+                sense,
+            );
             let pos = match galley.job.halign {
                 Align::LEFT => rect.left_top(),
                 Align::Center => rect.center_top(),
@@ -180,6 +184,7 @@ impl Label {
                 let rect = row.rect.translate(vec2(pos.x, pos.y));
                 response |= ui.allocate_rect(rect, sense);
             }
+            response.baseline = Some(pos.y + galley.first_row_baseline());
             (pos, galley, response)
         } else {
             // Apply wrap_mode, but don't overwrite anything important
@@ -208,7 +213,11 @@ impl Label {
             };
 
             let galley = ui.fonts(|fonts| fonts.layout_job(layout_job));
-            let (rect, response) = ui.allocate_exact_size(galley.size(), sense);
+            let (rect, response) = ui.allocate_exact_size_with_baseline(
+                galley.size(),
+                galley.first_row_baseline(),
+                sense,
+            );
             let galley_pos = match galley.job.halign {
                 Align::LEFT => rect.left_top(),
                 Align::Center => rect.center_top(),
@@ -7,41 +7,60 @@
 use crate::*;
 
 mod button;
+mod canvas;
 mod checkbox;
 pub mod color_picker;
 pub(crate) mod drag_value;
+mod editable_label;
 mod hyperlink;
+#[cfg(feature = "icons")]
+mod icon;
 mod image;
 mod image_button;
+mod knob;
 mod label;
 mod progress_bar;
+mod progress_circle;
 mod radio_button;
+mod segmented_control;
 mod selected_label;
 mod separator;
+mod shortcut_edit;
 mod slider;
 mod spinner;
 pub mod text_edit;
+mod toggle_switch;
 
 pub use self::{
     button::Button,
+    canvas::{Canvas, CanvasData, CanvasStroke},
     checkbox::Checkbox,
     drag_value::DragValue,
+    editable_label::EditableLabel,
     hyperlink::{Hyperlink, Link},
     image::{
-        decode_gif_uri, has_gif_magic_header, paint_texture_at, GifFrameDurations, Image, ImageFit,
-        ImageOptions, ImageSize, ImageSource,
+        decode_current_color_uri, decode_gif_uri, has_gif_magic_header, paint_texture_at,
+        GifFrameDurations, Image, ImageFit, ImageOptions, ImageSize, ImageSource,
     },
     image_button::ImageButton,
+    knob::{Knob, KnobDragMode},
     label::Label,
     progress_bar::ProgressBar,
+    progress_circle::ProgressCircle,
     radio_button::RadioButton,
+    segmented_control::SegmentedControl,
     selected_label::SelectableLabel,
     separator::Separator,
+    shortcut_edit::ShortcutEdit,
     slider::{Slider, SliderOrientation},
     spinner::Spinner,
-    text_edit::{TextBuffer, TextEdit},
+    text_edit::{InputMask, TextBuffer, TextEdit},
+    toggle_switch::ToggleSwitch,
 };
 
+#[cfg(feature = "icons")]
+pub use self::icon::{Icon, IconPaintFn, IconRegistry, IconSource};
+
 // ----------------------------------------------------------------------------
 
 /// Anything implementing Widget can be added to a [`Ui`] with [`Ui::add`].
@@ -0,0 +1,180 @@
+use std::f32::consts::TAU;
+
+use crate::*;
+
+enum ProgressCircleText {
+    Custom(WidgetText),
+    Percentage,
+}
+
+/// A circular progress indicator, like a round [`crate::ProgressBar`].
+///
+/// Shows how much is done as an arc, optionally with a text in the middle.
+/// When the progress is unknown, use [`Self::indeterminate`] for a spinning arc.
+///
+/// See also: [`crate::Spinner`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(egui::ProgressCircle::new(0.25).show_percentage());
+/// ui.add(egui::ProgressCircle::indeterminate().diameter(16.0));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ProgressCircle {
+    /// `None` when indeterminate.
+    progress: Option<f32>,
+    diameter: Option<f32>,
+    thickness: Option<f32>,
+    text: Option<ProgressCircleText>,
+    fill: Option<Color32>,
+}
+
+impl ProgressCircle {
+    /// Progress in the `[0, 1]` range, where `1` means "completed".
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress: Some(progress.clamp(0.0, 1.0)),
+            diameter: None,
+            thickness: None,
+            text: None,
+            fill: None,
+        }
+    }
+
+    /// A spinning arc, for when it is unknown how much is done.
+    ///
+    /// Note that this will cause the UI to be redrawn.
+    pub fn indeterminate() -> Self {
+        Self {
+            progress: None,
+            ..Self::new(0.0)
+        }
+    }
+
+    /// The diameter of the circle. Default: twice the height of [`crate::style::Spacing::interact_size`].
+    #[inline]
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// The width of the arc. Default: an eighth of the diameter.
+    #[inline]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    /// The color of the arc.
+    #[inline]
+    pub fn fill(mut self, color: Color32) -> Self {
+        self.fill = Some(color);
+        self
+    }
+
+    /// A custom text to display in the middle of the circle.
+    #[inline]
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = Some(ProgressCircleText::Custom(text.into()));
+        self
+    }
+
+    /// Show the progress in percent in the middle of the circle.
+    ///
+    /// Nothing is shown when indeterminate.
+    #[inline]
+    pub fn show_percentage(mut self) -> Self {
+        self.text = Some(ProgressCircleText::Percentage);
+        self
+    }
+}
+
+impl Widget for ProgressCircle {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            progress,
+            diameter,
+            thickness,
+            text,
+            fill,
+        } = self;
+
+        let diameter = diameter.unwrap_or(2.0 * ui.spacing().interact_size.y);
+        let thickness = thickness
+            .unwrap_or(diameter / 8.0)
+            .clamp(1.0, 0.5 * diameter);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::hover());
+
+        response.widget_info(|| {
+            let mut info = if let Some(ProgressCircleText::Custom(text)) = &text {
+                WidgetInfo::labeled(WidgetType::ProgressIndicator, text.text())
+            } else {
+                WidgetInfo::new(WidgetType::ProgressIndicator)
+            };
+            info.value = progress.map(|progress| (progress as f64 * 100.0).floor());
+
+            info
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let center = rect.center();
+            let radius = 0.5 * (diameter - thickness);
+            let fill = fill.unwrap_or(visuals.selection.bg_fill);
+
+            // The track, and the arc on top of it, starting at the top:
+            ui.painter().circle_stroke(
+                center,
+                radius,
+                Stroke::new(thickness, visuals.extreme_bg_color),
+            );
+            let start_angle = -TAU / 4.0;
+            if let Some(progress) = progress {
+                if 0.0 < progress {
+                    ui.painter().add(Shape::arc(
+                        center,
+                        radius,
+                        start_angle,
+                        start_angle + progress * TAU,
+                        Stroke::new(thickness, fill),
+                    ));
+                }
+            } else {
+                ui.ctx().request_repaint(); // because it is animated
+
+                let time = ui.input(|i| i.time);
+                let start_angle = (time * std::f64::consts::TAU) as f32;
+                let sweep = lerp(0.1 * TAU..=0.6 * TAU, (0.5 * time.sin() + 0.5) as f32);
+                ui.painter().add(Shape::arc(
+                    center,
+                    radius,
+                    start_angle,
+                    start_angle + sweep,
+                    Stroke::new(thickness, fill),
+                ));
+            }
+
+            let text = match (text, progress) {
+                (Some(ProgressCircleText::Custom(text)), _) => Some(text),
+                (Some(ProgressCircleText::Percentage), Some(progress)) => {
+                    Some(format!("{}%", (progress * 100.0) as usize).into())
+                }
+                _ => None,
+            };
+            if let Some(text) = text {
+                let galley = text.into_galley(
+                    ui,
+                    Some(TextWrapMode::Truncate),
+                    diameter - 2.0 * thickness,
+                    TextStyle::Small,
+                );
+                let text_pos = center - 0.5 * galley.size();
+                ui.painter()
+                    .galley(text_pos, galley, ui.visuals().text_color());
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,298 @@
+use crate::*;
+
+/// A row of joined buttons where exactly one segment is selected, e.g. to pick an enum value.
+///
+/// This is a more compact alternative to a group of [`RadioButton`]s,
+/// and looks better than a row of [`Ui::selectable_value`]s.
+///
+/// When a segment has keyboard focus, the left and right arrow keys select the previous and next segment.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// #[derive(Clone, PartialEq)]
+/// enum Alignment { Left, Center, Right }
+/// let mut alignment = Alignment::Left;
+///
+/// ui.add(
+///     egui::SegmentedControl::new(&mut alignment)
+///         .segment(Alignment::Left, "Left")
+///         .segment(Alignment::Center, "Center")
+///         .segment(Alignment::Right, "Right"),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct SegmentedControl<'a, Value> {
+    current_value: &'a mut Value,
+    segments: Vec<(Value, WidgetText)>,
+    equal_width: bool,
+}
+
+impl<'a, Value: Clone + PartialEq> SegmentedControl<'a, Value> {
+    /// The segment whose value is equal to `current_value` is shown as selected.
+    pub fn new(current_value: &'a mut Value) -> Self {
+        Self {
+            current_value,
+            segments: Vec::new(),
+            equal_width: false,
+        }
+    }
+
+    /// Add a segment that sets the value to `value` when clicked.
+    #[inline]
+    pub fn segment(mut self, value: Value, text: impl Into<WidgetText>) -> Self {
+        self.segments.push((value, text.into()));
+        self
+    }
+
+    /// Add several segments at once.
+    pub fn segments(
+        mut self,
+        segments: impl IntoIterator<Item = (Value, impl Into<WidgetText>)>,
+    ) -> Self {
+        self.segments.extend(
+            segments
+                .into_iter()
+                .map(|(value, text)| (value, text.into())),
+        );
+        self
+    }
+
+    /// Give all segments the width of the widest one.
+    ///
+    /// Default: `false` (each segment is as wide as its text).
+    #[inline]
+    pub fn equal_width(mut self, equal_width: bool) -> Self {
+        self.equal_width = equal_width;
+        self
+    }
+}
+
+impl<'a, Value: Clone + PartialEq> Widget for SegmentedControl<'a, Value> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            current_value,
+            segments,
+            equal_width,
+        } = self;
+
+        let button_padding = ui.spacing().button_padding;
+        let galleys: Vec<_> = segments
+            .iter()
+            .map(|(_, text)| {
+                text.clone().into_galley(
+                    ui,
+                    Some(TextWrapMode::Extend),
+                    f32::INFINITY,
+                    TextStyle::Button,
+                )
+            })
+            .collect();
+
+        let mut widths: Vec<f32> = galleys
+            .iter()
+            .map(|galley| galley.size().x + 2.0 * button_padding.x)
+            .collect();
+        if equal_width {
+            let max_width = widths.iter().copied().fold(0.0, f32::max);
+            widths.fill(max_width);
+        }
+        let height = galleys
+            .iter()
+            .fold(0.0_f32, |height, galley| height.max(galley.size().y))
+            + 2.0 * button_padding.y;
+        let height = height.at_least(ui.spacing().interact_size.y);
+
+        let desired_size = vec2(widths.iter().sum(), height);
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        // Lay out and interact with each segment:
+        let mut left = rect.left();
+        let mut segment_responses = Vec::with_capacity(segments.len());
+        for (i, width) in widths.iter().enumerate() {
+            let segment_rect = Rect::from_min_size(pos2(left, rect.top()), vec2(*width, height));
+            left += width;
+            segment_responses.push(ui.interact(segment_rect, response.id.with(i), Sense::click()));
+        }
+
+        let mut new_index = segment_responses
+            .iter()
+            .position(|segment_response| segment_response.clicked());
+
+        // Look for the focused segment before moving the focus,
+        // so that the next segment doesn't handle the same key presses:
+        let focused = segment_responses
+            .iter()
+            .position(|segment_response| segment_response.has_focus());
+        if let Some(i) = focused {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    segment_responses[i].id,
+                    EventFilter {
+                        // Left and right select the neighboring segment
+                        // rather than moving focus to the next widget:
+                        horizontal_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+            let (left, right) = ui.input(|i| {
+                (
+                    i.num_presses(Key::ArrowLeft),
+                    i.num_presses(Key::ArrowRight),
+                )
+            });
+            let target = (i + right).saturating_sub(left).min(segments.len() - 1);
+            if target != i {
+                new_index = Some(target);
+                segment_responses[target].request_focus();
+            }
+        }
+
+        if let Some((value, _)) = new_index.and_then(|index| segments.get(index)) {
+            if *current_value != *value {
+                *current_value = value.clone();
+                response.mark_changed();
+            }
+        }
+
+        for (i, segment_response) in segment_responses.iter().enumerate() {
+            let selected = segments[i].0 == *current_value;
+            segment_response.widget_info(|| {
+                WidgetInfo::selected(WidgetType::RadioButton, selected, galleys[i].text())
+            });
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let rounding = visuals.widgets.inactive.rounding;
+            let last = segments.len().saturating_sub(1);
+
+            ui.painter().rect(
+                rect,
+                rounding,
+                visuals.widgets.inactive.weak_bg_fill,
+                visuals.widgets.inactive.bg_stroke,
+            );
+
+            for (i, (segment_response, galley)) in segment_responses.iter().zip(galleys).enumerate()
+            {
+                let segment_rect = segment_response.rect;
+                let selected = segments[i].0 == *current_value;
+
+                // Only round the outer corners, so the segments join up:
+                let segment_rounding = Rounding {
+                    nw: if i == 0 { rounding.nw } else { 0.0 },
+                    sw: if i == 0 { rounding.sw } else { 0.0 },
+                    ne: if i == last { rounding.ne } else { 0.0 },
+                    se: if i == last { rounding.se } else { 0.0 },
+                };
+
+                let text_color = if selected {
+                    ui.painter().rect(
+                        segment_rect,
+                        segment_rounding,
+                        visuals.selection.bg_fill,
+                        visuals.selection.stroke,
+                    );
+                    visuals.selection.stroke.color
+                } else {
+                    let segment_visuals = ui.style().interact(segment_response);
+                    if segment_response.hovered() || segment_response.has_focus() {
+                        ui.painter().rect(
+                            segment_rect,
+                            segment_rounding,
+                            segment_visuals.weak_bg_fill,
+                            segment_visuals.bg_stroke,
+                        );
+                    }
+                    segment_visuals.text_color()
+                };
+
+                // Separate neighboring segments, unless one of them is selected:
+                let next_selected = segments
+                    .get(i + 1)
+                    .map_or(true, |(value, _)| *value == *current_value);
+                if !selected && !next_selected {
+                    ui.painter().vline(
+                        segment_rect.right(),
+                        segment_rect.y_range().shrink(button_padding.y),
+                        visuals.widgets.noninteractive.bg_stroke,
+                    );
+                }
+
+                let text_pos = segment_rect.center() - 0.5 * galley.size();
+                ui.painter().galley(text_pos, galley, text_color);
+            }
+        }
+
+        for segment_response in segment_responses {
+            response |= segment_response;
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{click, press, run};
+    use crate::*;
+
+    /// A control with the segments "A", "Bbbb" and "C" for `value`.
+    fn control(value: &mut usize, equal_width: bool) -> SegmentedControl<'_, usize> {
+        SegmentedControl::new(value)
+            .segments([(0, "A"), (1, "Bbbb"), (2, "C")])
+            .equal_width(equal_width)
+    }
+
+    #[test]
+    fn test_click_selects_segment() {
+        let ctx = Context::default();
+        let mut value = 0;
+        let rect = run(&ctx, vec![], |ui| ui.add(control(&mut value, true))).rect;
+
+        // With equal widths, the last third is the last segment:
+        let pos = rect.lerp_inside(vec2(5.0 / 6.0, 0.5));
+        let response = run(&ctx, click(pos), |ui| ui.add(control(&mut value, true)));
+        assert_eq!(value, 2);
+        assert!(response.changed());
+
+        let response = run(&ctx, vec![], |ui| ui.add(control(&mut value, true)));
+        assert!(!response.changed());
+    }
+
+    #[test]
+    fn test_arrow_keys_select_neighbors() {
+        let ctx = Context::default();
+        let mut value = 0;
+        let id = run(&ctx, vec![], |ui| ui.add(control(&mut value, false))).id;
+        ctx.memory_mut(|mem| mem.request_focus(id.with(0)));
+
+        // A segment that just got the focus only keeps the arrow keys from the next frame on,
+        // so we run a frame without events before each key press:
+        let mut press_keys = |keys: &[Key]| {
+            run(&ctx, vec![], |ui| ui.add(control(&mut value, false)));
+            let events = keys.iter().map(|&key| press(key)).collect();
+            run(&ctx, events, |ui| ui.add(control(&mut value, false)));
+            value
+        };
+        assert_eq!(press_keys(&[Key::ArrowRight]), 1);
+        assert_eq!(
+            press_keys(&[Key::ArrowRight, Key::ArrowRight]),
+            2,
+            "Stays at the last segment"
+        );
+        press_keys(&[Key::ArrowLeft]);
+        assert_eq!(value, 1);
+        assert!(ctx.memory(|mem| mem.has_focus(id.with(1))));
+    }
+
+    #[test]
+    fn test_equal_width() {
+        let ctx = Context::default();
+        let mut value = 0;
+        let natural = run(&ctx, vec![], |ui| ui.add(control(&mut value, false)));
+        let equal = run(&ctx, vec![], |ui| ui.add(control(&mut value, true)));
+        assert!(equal.rect.width() > natural.rect.width());
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{os::OperatingSystem, *};
+
+/// Type of the function used by [`ShortcutEdit::validate`].
+type Validator<'a> = Box<dyn FnOnce(&KeyboardShortcut) -> Option<ValidationState> + 'a>;
+
+/// Stored in the temporary data of a [`ShortcutEdit`] while it is recording.
+#[derive(Clone, Copy)]
+struct RecordingMarker;
+
+/// What the user did while a [`ShortcutEdit`] was recording.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Recorded {
+    Shortcut(KeyboardShortcut),
+    Cancel,
+}
+
+/// Edit a [`KeyboardShortcut`], e.g. in the keybinding settings of an app.
+///
+/// Click it (or focus it and press enter) to start recording,
+/// then press the new key together with any modifiers.
+/// Escape, or clicking somewhere else, cancels the recording.
+/// While recording, all keyboard events are consumed, so that the chord doesn't trigger anything else.
+///
+/// The shortcut can be cleared with the "🗙" button,
+/// and reset to [`Self::default_shortcut`] with the "⟲" button.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut save_shortcut = Some(egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S));
+/// # let open_shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::O);
+/// ui.add(
+///     egui::ShortcutEdit::new(&mut save_shortcut).validate(|shortcut| {
+///         (*shortcut == open_shortcut)
+///             .then(|| egui::ValidationState::warning("Also used for Open"))
+///     }),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ShortcutEdit<'a> {
+    shortcut: &'a mut Option<KeyboardShortcut>,
+    default_shortcut: Option<KeyboardShortcut>,
+    validate: Option<Validator<'a>>,
+    desired_width: Option<f32>,
+}
+
+impl<'a> ShortcutEdit<'a> {
+    pub fn new(shortcut: &'a mut Option<KeyboardShortcut>) -> Self {
+        Self {
+            shortcut,
+            default_shortcut: None,
+            validate: None,
+            desired_width: None,
+        }
+    }
+
+    /// Show a button to reset the shortcut to this, when it is different.
+    #[inline]
+    pub fn default_shortcut(
+        mut self,
+        default_shortcut: impl Into<Option<KeyboardShortcut>>,
+    ) -> Self {
+        self.default_shortcut = default_shortcut.into();
+        self
+    }
+
+    /// Check the shortcut, e.g. for conflicts with [`Context::registered_shortcuts`].
+    ///
+    /// The returned [`ValidationState`] is shown with a colored border and a message.
+    #[inline]
+    pub fn validate(
+        mut self,
+        validate: impl FnOnce(&KeyboardShortcut) -> Option<ValidationState> + 'a,
+    ) -> Self {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// The width of the part showing the shortcut.
+    /// Default: [`crate::style::Spacing::interact_size`] `.x` times two.
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    /// Is any [`ShortcutEdit`] recording a shortcut?
+    ///
+    /// Use this to not trigger your own shortcuts while the user is choosing a new one,
+    /// if you check them before the [`ShortcutEdit`] is shown.
+    pub fn is_recording(ctx: &Context) -> bool {
+        ctx.memory(|mem| mem.focused()).map_or(false, |id| {
+            ctx.data(|d| d.get_temp::<RecordingMarker>(id).is_some())
+        })
+    }
+}
+
+impl<'a> Widget for ShortcutEdit<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            shortcut,
+            default_shortcut,
+            validate,
+            desired_width,
+        } = self;
+
+        ui.horizontal(|ui| {
+            let id = ui.next_auto_id();
+            let mut changed = false;
+            let mut is_recording = ui.memory(|mem| mem.has_focus(id))
+                && ui.data(|d| d.get_temp::<RecordingMarker>(id).is_some());
+            let was_recording = is_recording;
+            if is_recording {
+                ui.memory_mut(|mem| {
+                    mem.set_focus_lock_filter(
+                        id,
+                        EventFilter {
+                            tab: true,
+                            horizontal_arrows: true,
+                            vertical_arrows: true,
+                            escape: true,
+                        },
+                    );
+                });
+                let recorded = ui.input_mut(|i| {
+                    let recorded = recorded_shortcut(&i.events);
+                    i.events.retain(|event| {
+                        !matches!(
+                            event,
+                            Event::Key { .. }
+                                | Event::Text(_)
+                                | Event::Copy
+                                | Event::Cut
+                                | Event::Paste(_)
+                        )
+                    });
+                    recorded
+                });
+                match recorded {
+                    Some(Recorded::Shortcut(recorded)) => {
+                        changed = *shortcut != Some(recorded);
+                        *shortcut = Some(recorded);
+                        is_recording = false;
+                    }
+                    Some(Recorded::Cancel) => is_recording = false,
+                    None => {}
+                }
+            }
+
+            let text = if is_recording {
+                ui.ctx().request_repaint(); // to show the modifiers as they are pressed
+                let modifiers = ui.input(|i| i.modifiers);
+                let is_mac = matches!(ui.ctx().os(), OperatingSystem::Mac | OperatingSystem::IOS);
+                let modifiers = ModifierNames::NAMES.format(&modifiers, is_mac);
+                if modifiers.is_empty() {
+                    RichText::new("Press a shortcut…").italics()
+                } else {
+                    RichText::new(format!("{modifiers}+…")).italics()
+                }
+            } else if let Some(shortcut) = shortcut.as_ref() {
+                RichText::new(ui.ctx().format_shortcut(shortcut))
+            } else {
+                RichText::new("None").weak()
+            };
+
+            let desired_width = desired_width.unwrap_or(2.0 * ui.spacing().interact_size.x);
+            let galley = WidgetText::from(text).into_galley(
+                ui,
+                Some(TextWrapMode::Truncate),
+                desired_width - 2.0 * ui.spacing().button_padding.x,
+                TextStyle::Button,
+            );
+            let desired_size = vec2(desired_width, ui.spacing().interact_size.y)
+                .at_least(galley.size() + 2.0 * ui.spacing().button_padding);
+            let (_, rect) = ui.allocate_space(desired_size);
+            let mut response = ui.interact(rect, id, Sense::click());
+
+            if response.clicked() && !was_recording {
+                is_recording = true;
+                ui.memory_mut(|mem| mem.request_focus(id));
+            }
+            ui.data_mut(|d| {
+                if is_recording {
+                    d.insert_temp(id, RecordingMarker);
+                } else {
+                    d.remove::<RecordingMarker>(id);
+                }
+            });
+            response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, galley.text()));
+
+            if ui.is_rect_visible(rect) {
+                let visuals = ui.style().interact_selectable(&response, is_recording);
+                let stroke = if is_recording {
+                    ui.visuals().selection.stroke
+                } else {
+                    visuals.bg_stroke
+                };
+                ui.painter().rect(
+                    rect.expand(visuals.expansion),
+                    visuals.rounding,
+                    ui.visuals().extreme_bg_color,
+                    stroke,
+                );
+                let text_pos = rect.center() - 0.5 * galley.size();
+                ui.painter().galley(text_pos, galley, visuals.text_color());
+            }
+
+            if let Some(validation) = shortcut.as_ref().zip(validate).and_then(|(s, f)| f(s)) {
+                let expansion = ui.style().interact(&response).expansion;
+                response = validation.show(ui, response, rect.expand(expansion));
+            }
+
+            let small_button = |text: &str| Button::new(RichText::new(text).small()).frame(false);
+            if shortcut.is_some()
+                && ui
+                    .add(small_button("🗙"))
+                    .on_hover_text("Clear shortcut")
+                    .clicked()
+            {
+                *shortcut = None;
+                changed = true;
+            }
+            if default_shortcut != *shortcut
+                && default_shortcut.is_some()
+                && ui
+                    .add(small_button("⟲"))
+                    .on_hover_text("Reset to default")
+                    .clicked()
+            {
+                *shortcut = default_shortcut;
+                changed = true;
+            }
+
+            if changed {
+                response.mark_changed();
+            }
+            response
+        })
+        .inner
+    }
+}
+
+/// The first key pressed in `events`, with the modifiers held at the time.
+fn recorded_shortcut(events: &[Event]) -> Option<Recorded> {
+    events.iter().find_map(|event| match event {
+        Event::Key {
+            key,
+            pressed: true,
+            repeat: false,
+            modifiers,
+            ..
+        } => Some(if *key == Key::Escape && modifiers.is_none() {
+            Recorded::Cancel
+        } else {
+            Recorded::Shortcut(KeyboardShortcut::new(*modifiers, *key))
+        }),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_event(key: Key, modifiers: Modifiers, pressed: bool) -> Event {
+        Event::Key {
+            key,
+            physical_key: None,
+            pressed,
+            repeat: false,
+            modifiers,
+        }
+    }
+
+    #[test]
+    fn test_recorded_shortcut() {
+        assert_eq!(recorded_shortcut(&[]), None);
+        assert_eq!(
+            recorded_shortcut(&[
+                Event::Text("k".to_owned()),
+                key_event(Key::K, Modifiers::CTRL, true),
+                key_event(Key::K, Modifiers::CTRL, false),
+            ]),
+            Some(Recorded::Shortcut(KeyboardShortcut::new(
+                Modifiers::CTRL,
+                Key::K
+            )))
+        );
+        assert_eq!(
+            recorded_shortcut(&[key_event(Key::F2, Modifiers::NONE, false)]),
+            None
+        );
+        assert_eq!(
+            recorded_shortcut(&[key_event(Key::Escape, Modifiers::NONE, true)]),
+            Some(Recorded::Cancel)
+        );
+        assert_eq!(
+            recorded_shortcut(&[key_event(Key::Escape, Modifiers::SHIFT, true)]),
+            Some(Recorded::Shortcut(KeyboardShortcut::new(
+                Modifiers::SHIFT,
+                Key::Escape
+            )))
+        );
+    }
+}
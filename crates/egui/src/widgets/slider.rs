@@ -1,42 +1,65 @@
 #![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
 
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use crate::{style::HandleShape, *};
 
 // ----------------------------------------------------------------------------
 
-type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
-type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
+pub(crate) type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
+pub(crate) type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
 
 // ----------------------------------------------------------------------------
 
 /// Combined into one function (rather than two) to make it easier
 /// for the borrow checker.
-type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
+pub(crate) type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
 
-fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
+pub(crate) fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
     (get_set_value)(None)
 }
 
-fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
+pub(crate) fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
     (get_set_value)(Some(value));
 }
 
+/// Clamp `value` to `range`, which can go from high to low.
+pub(crate) fn clamp_to_range(value: f64, range: &RangeInclusive<f64>) -> f64 {
+    let (start, end) = (*range.start(), *range.end());
+    value.clamp(start.min(end), start.max(end))
+}
+
+/// Round `value` to `max_decimals`, and snap it to multiples of `step` counted from the start of `range`.
+pub(crate) fn round_to_step(
+    mut value: f64,
+    range: &RangeInclusive<f64>,
+    max_decimals: Option<usize>,
+    step: Option<f64>,
+) -> f64 {
+    if let Some(max_decimals) = max_decimals {
+        value = emath::round_to_decimals(value, max_decimals);
+    }
+    if let Some(step) = step {
+        let start = *range.start();
+        value = start + ((value - start) / step).round() * step;
+    }
+    value
+}
+
 // ----------------------------------------------------------------------------
 
 #[derive(Clone)]
-struct SliderSpec {
-    logarithmic: bool,
+pub(crate) struct SliderSpec {
+    pub(crate) logarithmic: bool,
 
     /// For logarithmic sliders, the smallest positive value we are interested in.
     /// 1 for integer sliders, maybe 1e-6 for others.
-    smallest_positive: f64,
+    pub(crate) smallest_positive: f64,
 
     /// For logarithmic sliders, the largest positive value we are interested in
     /// before the slider switches to `INFINITY`, if that is the higher end.
     /// Default: INFINITY.
-    largest_finite: f64,
+    pub(crate) largest_finite: f64,
 }
 
 /// Specifies the orientation of a [`Slider`].
@@ -86,8 +109,18 @@ pub struct Slider<'a> {
     max_decimals: Option<usize>,
     custom_formatter: Option<NumFormatter<'a>>,
     custom_parser: Option<NumParser<'a>>,
+    units: Vec<(String, f64)>,
+    number_format: Option<NumberFormat>,
     trailing_fill: Option<bool>,
     handle_shape: Option<HandleShape>,
+
+    /// Put a tick mark every this often, counted from the start of the range.
+    tick_step: Option<f64>,
+
+    /// Extra tick marks, with optional custom labels.
+    tick_positions: Vec<(f64, Option<WidgetText>)>,
+    show_tick_labels: bool,
+    snap_to_ticks: bool,
 }
 
 impl<'a> Slider<'a> {
@@ -133,8 +166,14 @@ impl<'a> Slider<'a> {
             max_decimals: None,
             custom_formatter: None,
             custom_parser: None,
+            units: Vec::new(),
+            number_format: None,
             trailing_fill: None,
             handle_shape: None,
+            tick_step: None,
+            tick_positions: Vec::new(),
+            show_tick_labels: false,
+            snap_to_ticks: false,
         }
     }
 
@@ -314,6 +353,79 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Put a tick mark on the rail every `step`, counted from the start of the range.
+    ///
+    /// See also [`Self::tick_positions`], [`Self::show_tick_labels`] and [`Self::snap_to_ticks`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut percent: f32 = 0.0;
+    /// ui.add(egui::Slider::new(&mut percent, 0.0..=100.0)
+    ///     .suffix("%")
+    ///     .ticks(25.0)
+    ///     .show_tick_labels(true));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn ticks(mut self, step: f64) -> Self {
+        self.tick_step = if step != 0.0 { Some(step) } else { None };
+        self
+    }
+
+    /// Put tick marks on the rail at these values.
+    ///
+    /// Can be combined with [`Self::ticks`]. Values outside of the range are ignored.
+    pub fn tick_positions(mut self, values: impl IntoIterator<Item = f64>) -> Self {
+        self.tick_positions
+            .extend(values.into_iter().map(|value| (value, None)));
+        self
+    }
+
+    /// Put tick marks on the rail at these values, each with its own label.
+    ///
+    /// The labels are shown even if [`Self::show_tick_labels`] is off.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut quality: u8 = 1;
+    /// ui.add(egui::Slider::new(&mut quality, 0..=2)
+    ///     .labeled_ticks([(0.0, "Low"), (1.0, "Medium"), (2.0, "High")])
+    ///     .snap_to_ticks(true)
+    ///     .show_value(false));
+    /// # });
+    /// ```
+    pub fn labeled_ticks(
+        mut self,
+        ticks: impl IntoIterator<Item = (f64, impl Into<WidgetText>)>,
+    ) -> Self {
+        self.tick_positions.extend(
+            ticks
+                .into_iter()
+                .map(|(value, label)| (value, Some(label.into()))),
+        );
+        self
+    }
+
+    /// Show the value of each tick mark below the rail (or next to it, for vertical sliders).
+    ///
+    /// The values are formatted like the value of the slider, including prefix and suffix.
+    /// Default: `false`.
+    #[inline]
+    pub fn show_tick_labels(mut self, show_tick_labels: bool) -> Self {
+        self.show_tick_labels = show_tick_labels;
+        self
+    }
+
+    /// Only allow the values of the tick marks.
+    ///
+    /// Dragging picks the closest tick, and the arrow keys move from tick to tick.
+    /// Default: `false`.
+    #[inline]
+    pub fn snap_to_ticks(mut self, snap_to_ticks: bool) -> Self {
+        self.snap_to_ticks = snap_to_ticks;
+        self
+    }
+
     /// Set custom formatter defining how numbers are converted into text.
     ///
     /// A custom formatter takes a `f64` for the numeric value and a `RangeInclusive<usize>` representing
@@ -398,6 +510,26 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Let the user enter values in another unit when editing the value as text,
+    /// like [`DragValue::unit`].
+    ///
+    /// Math expressions like `1920/2` can be entered even without any units.
+    #[inline]
+    pub fn unit(mut self, unit: impl ToString, factor: f64) -> Self {
+        self.units.push((unit.to_string(), factor));
+        self
+    }
+
+    /// How to show the value and the tick labels, e.g. with `,` as the decimal separator.
+    ///
+    /// Default: [`crate::Style::number_format`].
+    /// See [`DragValue::number_format`].
+    #[inline]
+    pub fn number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = Some(number_format);
+        self
+    }
+
     /// Set `custom_formatter` and `custom_parser` to display and parse numbers as binary integers. Floating point
     /// numbers are *not* supported.
     ///
@@ -521,9 +653,7 @@ impl<'a> Slider<'a> {
     fn get_value(&mut self) -> f64 {
         let value = get(&mut self.get_set_value);
         if self.clamp_to_range {
-            let start = *self.range.start();
-            let end = *self.range.end();
-            value.clamp(start.min(end), start.max(end))
+            clamp_to_range(value, &self.range)
         } else {
             value
         }
@@ -531,20 +661,105 @@ impl<'a> Slider<'a> {
 
     fn set_value(&mut self, mut value: f64) {
         if self.clamp_to_range {
-            let start = *self.range.start();
-            let end = *self.range.end();
-            value = value.clamp(start.min(end), start.max(end));
-        }
-        if let Some(max_decimals) = self.max_decimals {
-            value = emath::round_to_decimals(value, max_decimals);
+            value = clamp_to_range(value, &self.range);
         }
-        if let Some(step) = self.step {
-            let start = *self.range.start();
-            value = start + ((value - start) / step).round() * step;
+        value = round_to_step(value, &self.range, self.max_decimals, self.step);
+        if self.snap_to_ticks {
+            value = self.closest_tick(value).unwrap_or(value);
         }
         set(&mut self.get_set_value, value);
     }
 
+    /// The values of all tick marks within the range, sorted and without duplicates.
+    fn tick_values(&self) -> Vec<f64> {
+        /// Don't hang on a tiny tick step.
+        const MAX_TICKS: f64 = 1000.0;
+
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let (min, max) = (start.min(end), start.max(end));
+
+        let mut values: Vec<f64> = self
+            .tick_positions
+            .iter()
+            .map(|(value, _)| *value)
+            .filter(|value| (min..=max).contains(value))
+            .collect();
+        if let Some(step) = self.tick_step {
+            let num_steps = ((end - start) / step).abs().floor();
+            if num_steps.is_finite() && num_steps <= MAX_TICKS {
+                let step = step.abs() * (end - start).signum();
+                values.extend((0..=num_steps as usize).map(|i| start + i as f64 * step));
+            }
+        }
+        values.sort_by(f64::total_cmp);
+        values.dedup();
+        values
+    }
+
+    fn closest_tick(&self, value: f64) -> Option<f64> {
+        let normalized = normalized_from_value(value, self.range(), &self.spec);
+        let distance =
+            |tick: f64| (normalized_from_value(tick, self.range(), &self.spec) - normalized).abs();
+        self.tick_values()
+            .into_iter()
+            .min_by(|&a, &b| distance(a).total_cmp(&distance(b)))
+    }
+
+    /// The tick mark after (`forward`) or before the current value, along the slider.
+    fn next_tick(&mut self, forward: bool) -> Option<f64> {
+        let normalized = normalized_from_value(self.get_value(), self.range(), &self.spec);
+        let ticks = self
+            .tick_values()
+            .into_iter()
+            .map(|tick| (normalized_from_value(tick, self.range(), &self.spec), tick));
+        if forward {
+            ticks
+                .filter(|&(tick_normalized, _)| tick_normalized > normalized)
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+        } else {
+            ticks
+                .filter(|&(tick_normalized, _)| tick_normalized < normalized)
+                .max_by(|a, b| a.0.total_cmp(&b.0))
+        }
+        .map(|(_, tick)| tick)
+    }
+
+    /// The tick labels to show, laid out.
+    fn tick_label_galleys(&self, ui: &Ui) -> Vec<(f64, Arc<Galley>)> {
+        let number_format = self.number_format.unwrap_or(ui.style().number_format);
+        self.tick_values()
+            .into_iter()
+            .filter_map(|value| {
+                let custom_label = self
+                    .tick_positions
+                    .iter()
+                    .find(|(tick, label)| *tick == value && label.is_some())
+                    .and_then(|(_, label)| label.clone());
+                let label = custom_label.or_else(|| {
+                    self.show_tick_labels
+                        .then(|| self.format_tick_value(value, &number_format).into())
+                })?;
+                let galley = label.into_galley(
+                    ui,
+                    Some(TextWrapMode::Extend),
+                    f32::INFINITY,
+                    TextStyle::Small,
+                );
+                Some((value, galley))
+            })
+            .collect()
+    }
+
+    fn format_tick_value(&self, value: f64, number_format: &NumberFormat) -> String {
+        let max_decimals = self.max_decimals.unwrap_or(6).at_least(self.min_decimals);
+        let decimals = self.min_decimals..=max_decimals;
+        let text = match &self.custom_formatter {
+            Some(custom_formatter) => custom_formatter(value, decimals),
+            None => number_format.localize(&emath::format_with_decimals_in_range(value, decimals)),
+        };
+        format!("{}{text}{}", self.prefix, self.suffix)
+    }
+
     fn clamp_range(&self) -> RangeInclusive<f64> {
         if self.clamp_to_range {
             self.range()
@@ -570,18 +785,35 @@ impl<'a> Slider<'a> {
 }
 
 impl<'a> Slider<'a> {
-    /// Just the slider, no text
-    fn allocate_slider_space(&self, ui: &mut Ui, thickness: f32) -> Response {
+    /// Just the slider, no text.
+    ///
+    /// Also returns the part of the response rectangle with the rail, without the tick labels.
+    fn allocate_slider_space(
+        &self,
+        ui: &mut Ui,
+        thickness: f32,
+        tick_labels: &[(f64, Arc<Galley>)],
+    ) -> (Response, Rect) {
+        let label_gap = ui.spacing().item_spacing.y;
+        let labels_size = tick_labels
+            .iter()
+            .fold(Vec2::ZERO, |size, (_, galley)| size.max(galley.size()));
         let desired_size = match self.orientation {
             SliderOrientation::Horizontal => vec2(ui.spacing().slider_width, thickness),
             SliderOrientation::Vertical => vec2(thickness, ui.spacing().slider_width),
         };
-        ui.allocate_response(desired_size, Sense::drag())
+        let labels_space = match (tick_labels.is_empty(), &self.orientation) {
+            (true, _) => Vec2::ZERO,
+            (false, SliderOrientation::Horizontal) => vec2(0.0, label_gap + labels_size.y),
+            (false, SliderOrientation::Vertical) => vec2(label_gap + labels_size.x, 0.0),
+        };
+        let response = ui.allocate_response(desired_size + labels_space, Sense::drag());
+        let slider_rect = Rect::from_min_size(response.rect.min, desired_size);
+        (response, slider_rect)
     }
 
     /// Just the slider, no text
-    fn slider_ui(&mut self, ui: &Ui, response: &Response) {
-        let rect = &response.rect;
+    fn slider_ui(&mut self, ui: &Ui, response: &Response, rect: &Rect) {
         let handle_shape = self
             .handle_shape
             .unwrap_or_else(|| ui.style().visuals.handle_shape);
@@ -645,7 +877,17 @@ impl<'a> Slider<'a> {
 
         let kb_step = increment as f32 - decrement as f32;
 
-        if kb_step != 0.0 {
+        if kb_step != 0.0 && self.snap_to_ticks {
+            // The slider position increases towards the end of the range,
+            // except for vertical sliders where it is flipped:
+            let forward =
+                (kb_step > 0.0) == matches!(self.orientation, SliderOrientation::Horizontal);
+            for _ in 0..(increment.abs_diff(decrement)) {
+                if let Some(tick) = self.next_tick(forward) {
+                    self.set_value(tick);
+                }
+            }
+        } else if kb_step != 0.0 {
             let ui_point_per_step = 1.0; // move this many ui points for each kb_step
             let prev_value = self.get_value();
             let prev_position = self.position_from_value(prev_value, position_range);
@@ -720,6 +962,20 @@ impl<'a> Slider<'a> {
                 );
             }
 
+            // Paint tick marks across the rail.
+            let tick_stroke = Stroke::new(1.0, widget_visuals.noninteractive.fg_stroke.color);
+            let tick_half_length = rail_radius + 2.0;
+            for tick in self.tick_values() {
+                let tick_center =
+                    self.marker_center(self.position_from_value(tick, position_range), &rail_rect);
+                let across = match self.orientation {
+                    SliderOrientation::Horizontal => vec2(0.0, tick_half_length),
+                    SliderOrientation::Vertical => vec2(tick_half_length, 0.0),
+                };
+                ui.painter()
+                    .line_segment([tick_center - across, tick_center + across], tick_stroke);
+            }
+
             let radius = self.handle_radius(rect);
 
             let handle_shape = self
@@ -797,6 +1053,43 @@ impl<'a> Slider<'a> {
         limit / 2.5
     }
 
+    /// Paint the tick labels below the rail (or to the right of it, for vertical sliders),
+    /// centered on their tick marks but kept within `rect`.
+    fn paint_tick_labels(
+        &self,
+        ui: &Ui,
+        rect: Rect,
+        slider_rect: &Rect,
+        tick_labels: Vec<(f64, Arc<Galley>)>,
+    ) {
+        let handle_shape = self
+            .handle_shape
+            .unwrap_or_else(|| ui.style().visuals.handle_shape);
+        let position_range = self.position_range(slider_rect, &handle_shape);
+        let label_gap = ui.spacing().item_spacing.y;
+        let text_color = ui.visuals().text_color();
+
+        for (value, galley) in tick_labels {
+            let position = self.position_from_value(value, position_range);
+            let size = galley.size();
+            let pos = match self.orientation {
+                SliderOrientation::Horizontal => pos2(
+                    (position - 0.5 * size.x)
+                        .at_most(rect.right() - size.x)
+                        .at_least(rect.left()),
+                    slider_rect.bottom() + label_gap,
+                ),
+                SliderOrientation::Vertical => pos2(
+                    slider_rect.right() + label_gap,
+                    (position - 0.5 * size.y)
+                        .at_most(rect.bottom() - size.y)
+                        .at_least(rect.top()),
+                ),
+            };
+            ui.painter().galley(pos, galley, text_color);
+        }
+    }
+
     fn value_ui(&mut self, ui: &mut Ui, position_range: Rangef) -> Response {
         // If [`DragValue`] is controlled from the keyboard and `step` is defined, set speed to `step`
         let change = ui.input(|input| {
@@ -829,6 +1122,12 @@ impl<'a> Slider<'a> {
             if let Some(parser) = &self.custom_parser {
                 dv = dv.custom_parser(parser);
             }
+            for (unit, factor) in &self.units {
+                dv = dv.unit(unit, *factor);
+            }
+            if let Some(number_format) = self.number_format {
+                dv = dv.number_format(number_format);
+            }
             dv
         });
         if value != self.get_value() {
@@ -854,8 +1153,12 @@ impl<'a> Slider<'a> {
         let thickness = ui
             .text_style_height(&TextStyle::Body)
             .at_least(ui.spacing().interact_size.y);
-        let mut response = self.allocate_slider_space(ui, thickness);
-        self.slider_ui(ui, &response);
+        let tick_labels = self.tick_label_galleys(ui);
+        let (mut response, slider_rect) = self.allocate_slider_space(ui, thickness, &tick_labels);
+        self.slider_ui(ui, &response, &slider_rect);
+        if ui.is_rect_visible(response.rect) {
+            self.paint_tick_labels(ui, response.rect, &slider_rect, tick_labels);
+        }
 
         let value = self.get_value();
         response.changed = value != old_value;
@@ -885,7 +1188,7 @@ impl<'a> Slider<'a> {
             let handle_shape = self
                 .handle_shape
                 .unwrap_or_else(|| ui.style().visuals.handle_shape);
-            let position_range = self.position_range(&response.rect, &handle_shape);
+            let position_range = self.position_range(&slider_rect, &handle_shape);
             let value_response = self.value_ui(ui, position_range);
             if value_response.gained_focus()
                 || value_response.has_focus()
@@ -943,7 +1246,11 @@ use std::f64::INFINITY;
 /// give a scale that this many orders of magnitude in size.
 const INF_RANGE_MAGNITUDE: f64 = 10.0;
 
-fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &SliderSpec) -> f64 {
+pub(crate) fn value_from_normalized(
+    normalized: f64,
+    range: RangeInclusive<f64>,
+    spec: &SliderSpec,
+) -> f64 {
     let (min, max) = (*range.start(), *range.end());
 
     if min.is_nan() || max.is_nan() {
@@ -992,7 +1299,11 @@ fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &Sli
     }
 }
 
-fn normalized_from_value(value: f64, range: RangeInclusive<f64>, spec: &SliderSpec) -> f64 {
+pub(crate) fn normalized_from_value(
+    value: f64,
+    range: RangeInclusive<f64>,
+    spec: &SliderSpec,
+) -> f64 {
     let (min, max) = (*range.start(), *range.end());
 
     if min.is_nan() || max.is_nan() {
@@ -1084,3 +1395,97 @@ fn logarithmic_zero_cutoff(min: f64, max: f64) -> f64 {
     debug_assert!(0.0 <= cutoff && cutoff <= 1.0);
     cutoff
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{pointer, press, run};
+    use crate::*;
+
+    /// A snapping slider for `value` in `0..=100` with a tick every 25.
+    fn slider(value: &mut f64) -> Slider<'_> {
+        Slider::new(value, 0.0..=100.0)
+            .ticks(25.0)
+            .snap_to_ticks(true)
+            .show_value(false)
+    }
+
+    #[test]
+    fn test_tick_values() {
+        let mut value = 0.0;
+        let tick_values = |slider: Slider<'_>| slider.tick_values();
+        assert_eq!(
+            tick_values(
+                Slider::new(&mut value, 0.0..=100.0)
+                    .ticks(25.0)
+                    .tick_positions([10.0, 25.0, 200.0])
+            ),
+            [0.0, 10.0, 25.0, 50.0, 75.0, 100.0]
+        );
+        assert_eq!(
+            tick_values(Slider::new(&mut value, 10.0..=0.0).ticks(4.0)),
+            [2.0, 6.0, 10.0]
+        );
+        assert!(
+            tick_values(Slider::new(&mut value, 0.0..=1.0).ticks(1e-9)).is_empty(),
+            "Too many ticks"
+        );
+    }
+
+    #[test]
+    fn test_drag_snaps_to_ticks() {
+        let ctx = Context::default();
+        let mut value = 0.0;
+        let rect = run(&ctx, vec![], |ui| ui.add(slider(&mut value))).rect;
+
+        let pos = rect.lerp_inside(vec2(0.4, 0.5));
+        run(&ctx, pointer(pos, Some(true)), |ui| {
+            ui.add(slider(&mut value))
+        });
+        assert_eq!(value, 50.0);
+    }
+
+    #[test]
+    fn test_arrow_keys_move_between_ticks() {
+        let ctx = Context::default();
+        let mut value = 30.0;
+        let id = run(&ctx, vec![], |ui| ui.add(slider(&mut value))).id;
+        ctx.memory_mut(|mem| mem.request_focus(id));
+        let mut press_keys = |keys: &[Key]| {
+            let events = keys.iter().map(|&key| press(key)).collect();
+            run(&ctx, events, |ui| ui.add(slider(&mut value)));
+            value
+        };
+        press_keys(&[]);
+
+        assert_eq!(press_keys(&[Key::ArrowRight]), 50.0);
+        assert_eq!(press_keys(&[Key::ArrowRight, Key::ArrowRight]), 100.0);
+        assert_eq!(
+            press_keys(&[Key::ArrowRight]),
+            100.0,
+            "Stays at the last tick"
+        );
+        assert_eq!(press_keys(&[Key::ArrowLeft]), 75.0);
+    }
+
+    #[test]
+    fn test_tick_labels_take_space() {
+        let ctx = Context::default();
+        let mut heights = vec![];
+        run(&ctx, vec![], |ui| {
+            let mut value = 0.0;
+            let slider = Slider::new(&mut value, 0.0..=100.0).ticks(25.0);
+            heights.push(ui.add(slider).rect.height());
+        });
+        run(&ctx, vec![], |ui| {
+            let mut value = 0.0;
+            let slider = Slider::new(&mut value, 0.0..=100.0)
+                .ticks(25.0)
+                .show_tick_labels(true);
+            heights.push(ui.add(slider).rect.height());
+            let slider = Slider::new(&mut value, 0.0..=2.0).labeled_ticks([(1.0, "One")]);
+            heights.push(ui.add(slider).rect.height());
+        });
+        assert!(heights[1] > heights[0]);
+        assert_eq!(heights[1], heights[2], "Custom labels are always shown");
+    }
+}
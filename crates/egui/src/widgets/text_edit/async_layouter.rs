@@ -0,0 +1,207 @@
+use std::sync::Arc;
+
+use epaint::text::{LayoutJob, LayoutSection};
+
+use crate::{Galley, Id, TaskHandle, Ui};
+
+/// See [`crate::TextEdit::async_layouter`].
+pub(crate) type AsyncLayouter = Arc<dyn Fn(&str, f32) -> LayoutJob + Send + Sync>;
+
+/// Stored in temporary memory for each [`crate::TextEdit`] with an [`AsyncLayouter`].
+#[derive(Clone, Default)]
+struct AsyncLayoutState {
+    /// The latest finished layout, and the wrap width it was made for.
+    finished: Option<(Arc<Galley>, f32)>,
+
+    /// The layout that is currently running in the background.
+    pending: Option<Arc<TaskHandle<(Arc<Galley>, f32)>>>,
+}
+
+/// Lay out `text` with the [`AsyncLayouter`], without waiting for it.
+///
+/// If the latest finished layout is for the same text, it is returned directly.
+/// Otherwise a new layout is started in the background (unless one is already running),
+/// and the text is laid out with the styling of the latest finished layout
+/// as a placeholder, see [`patch_layout_job`].
+///
+/// If [`crate::Context::spawn`] can't be used, the text is laid out right away instead.
+///
+/// Returns `None` if there is no finished layout yet.
+pub(crate) fn layout_async(
+    ui: &Ui,
+    id: Id,
+    layouter: &AsyncLayouter,
+    text: &str,
+    wrap_width: f32,
+) -> Option<Arc<Galley>> {
+    let ctx = ui.ctx();
+    if !ctx.can_spawn() {
+        let job = layouter(text, wrap_width);
+        return Some(ui.fonts(|f| f.layout_job(job)));
+    }
+
+    let mut state: AsyncLayoutState = ctx.data(|data| data.get_temp(id)).unwrap_or_default();
+
+    if let Some(result) = state.pending.as_ref().and_then(|pending| pending.ready()) {
+        state.finished = Some(result.clone());
+        state.pending = None;
+    }
+
+    let is_up_to_date = state
+        .finished
+        .as_ref()
+        .is_some_and(|(galley, finished_wrap)| {
+            galley.job.text == text
+                && *finished_wrap == wrap_width
+                && galley.pixels_per_point == ctx.pixels_per_point()
+        });
+
+    if !is_up_to_date && state.pending.is_none() {
+        let layouter = layouter.clone();
+        let text = text.to_owned();
+        // Don't lock the context while laying out:
+        let fonts = ctx.fonts(|f| f.clone());
+        state.pending = Some(Arc::new(ctx.spawn(async move {
+            let job = layouter(&text, wrap_width);
+            (fonts.layout_job_unblocking(job), wrap_width)
+        })));
+    }
+
+    let galley = state.finished.as_ref().map(|(galley, _)| {
+        if is_up_to_date {
+            galley.clone()
+        } else {
+            let job = patch_layout_job(&galley.job, text);
+            ui.fonts(|f| f.layout_job(job))
+        }
+    });
+
+    ctx.data_mut(|data| data.insert_temp(id, state));
+
+    galley
+}
+
+/// Adapt a [`LayoutJob`] made for another text to `new_text`,
+/// keeping the styling of the unchanged start and end of the text.
+///
+/// Edited text gets the style of the text in front of it.
+pub(crate) fn patch_layout_job(job: &LayoutJob, new_text: &str) -> LayoutJob {
+    let old_text = job.text.as_str();
+
+    let prefix = old_text
+        .char_indices()
+        .zip(new_text.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(old_text.len().min(new_text.len()), |((i, _), _)| i);
+
+    let max_suffix = old_text.len().min(new_text.len()) - prefix;
+    let suffix = old_text
+        .chars()
+        .rev()
+        .zip(new_text.chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .scan(0, |len, c_len| {
+            *len += c_len;
+            Some(*len)
+        })
+        .take_while(|len| *len <= max_suffix)
+        .last()
+        .unwrap_or(0);
+
+    let old_end = old_text.len() - suffix;
+    let new_end = new_text.len() - suffix;
+    let map = |i: usize| {
+        if i < prefix {
+            i
+        } else if old_end <= i {
+            i - old_end + new_end
+        } else {
+            new_end
+        }
+    };
+
+    let mut sections: Vec<LayoutSection> = job
+        .sections
+        .iter()
+        .map(|section| LayoutSection {
+            byte_range: map(section.byte_range.start)..map(section.byte_range.end),
+            ..section.clone()
+        })
+        .collect();
+
+    // Make sure all of the text is covered, without gaps or overlaps:
+    let mut start = 0;
+    for section in &mut sections {
+        section.byte_range.start = start;
+        section.byte_range.end = section.byte_range.end.max(start);
+        start = section.byte_range.end;
+    }
+    if let Some(last) = sections.last_mut() {
+        last.byte_range.end = new_text.len();
+    }
+    if sections
+        .iter()
+        .any(|section| !section.byte_range.is_empty())
+    {
+        sections.retain(|section| !section.byte_range.is_empty());
+    } else {
+        sections.truncate(1);
+    }
+
+    LayoutJob {
+        text: new_text.to_owned(),
+        sections,
+        ..job.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color32, FontId};
+
+    fn job(sections: &[(&str, Color32)]) -> LayoutJob {
+        let mut job = LayoutJob::default();
+        for (text, color) in sections {
+            job.append(
+                text,
+                0.0,
+                epaint::text::TextFormat::simple(FontId::default(), *color),
+            );
+        }
+        job
+    }
+
+    fn colors(job: &LayoutJob) -> Vec<(&str, Color32)> {
+        job.sections
+            .iter()
+            .map(|s| (&job.text[s.byte_range.clone()], s.format.color))
+            .collect()
+    }
+
+    #[test]
+    fn test_patch_layout_job() {
+        let (red, blue) = (Color32::RED, Color32::BLUE);
+        let old = job(&[("let ", red), ("x", blue), (" = 1;", red)]);
+
+        // Typing at the end of a word extends it:
+        let new = patch_layout_job(&old, "let xy = 1;");
+        assert_eq!(colors(&new), [("let ", red), ("xy", blue), (" = 1;", red)]);
+
+        // Replacing text across sections:
+        let new = patch_layout_job(&old, "let ö1;");
+        assert_eq!(colors(&new), [("let ö", red), ("1;", red)]);
+
+        // Deleting everything:
+        let new = patch_layout_job(&old, "");
+        assert_eq!(colors(&new), [("", red)]);
+
+        // Inserting at the start:
+        let new = patch_layout_job(&old, "// let x = 1;");
+        assert_eq!(
+            colors(&new),
+            [("// let ", red), ("x", blue), (" = 1;", red)]
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use epaint::text::cursor::CCursor;
+
+use crate::{
+    text_selection::CursorRange, Area, Context, Frame, Id, Key, LayerId, Modifiers, Order, Rect,
+    ScrollArea, TextBuffer, Ui, UiKind,
+};
+
+use super::builder::InputFilter;
+
+/// See [`crate::TextEdit::autocomplete`].
+pub(crate) type Suggester<'t> = Box<dyn FnMut(&str) -> Vec<String> + 't>;
+
+/// Stored in temporary memory for each [`crate::TextEdit`] with autocomplete.
+#[derive(Clone, Default)]
+struct AutocompleteState {
+    /// The word the suggestions are for.
+    word: String,
+
+    /// What the app suggested for [`Self::word`].
+    suggestions: Vec<String>,
+
+    /// Index into [`Self::suggestions`] of the highlighted suggestion.
+    selected: usize,
+
+    /// The user pressed escape, so don't show the suggestions until the word changes.
+    dismissed: bool,
+}
+
+/// The autocomplete popup of a [`crate::TextEdit`].
+pub(crate) struct Autocomplete<'t> {
+    id: Id,
+    suggest: Suggester<'t>,
+    state: AutocompleteState,
+
+    /// Char index of the start of [`AutocompleteState::word`].
+    word_start: usize,
+
+    /// Scroll the highlighted suggestion into view, because it was changed with the keyboard.
+    scroll_to_selected: bool,
+}
+
+impl<'t> Autocomplete<'t> {
+    pub(crate) fn load(ctx: &Context, id: Id, suggest: Suggester<'t>) -> Self {
+        Self {
+            id,
+            suggest,
+            state: ctx.data(|data| data.get_temp(id)).unwrap_or_default(),
+            word_start: 0,
+            scroll_to_selected: false,
+        }
+    }
+
+    pub(crate) fn store(self, ctx: &Context) {
+        ctx.data_mut(|data| data.insert_temp(self.id, self.state));
+    }
+
+    /// Find the word in front of the cursor, and ask the app for suggestions if it changed.
+    ///
+    /// There is no word if `cursor_range` is `None` or has a selection.
+    pub(crate) fn update(&mut self, text: &str, cursor_range: Option<CursorRange>) {
+        let (word_start, word) = match cursor_range {
+            Some(cursor_range) if cursor_range.is_empty() => {
+                word_before_cursor(text, cursor_range.primary.ccursor.index)
+            }
+            _ => (0, ""),
+        };
+        self.word_start = word_start;
+
+        if word != self.state.word {
+            let suggestions = if word.is_empty() {
+                vec![]
+            } else {
+                (self.suggest)(word)
+                    .into_iter()
+                    .filter(|suggestion| suggestion != word)
+                    .collect()
+            };
+            self.state = AutocompleteState {
+                word: word.to_owned(),
+                suggestions,
+                selected: 0,
+                dismissed: false,
+            };
+        }
+    }
+
+    /// Are the suggestions shown?
+    pub(crate) fn is_open(&self) -> bool {
+        !self.state.dismissed && !self.state.suggestions.is_empty()
+    }
+
+    /// Is the pointer being pressed on the popup that was shown last frame?
+    ///
+    /// The [`crate::TextEdit`] should keep the focus when this happens.
+    pub(crate) fn is_popup_pressed(&self, ctx: &Context) -> bool {
+        let layer_id = LayerId::new(Order::Foreground, self.id);
+        let was_visible = ctx.memory(|mem| mem.areas().visible_last_frame(&layer_id));
+        let popup_rect = ctx.memory(|mem| mem.area_rect(self.id));
+        was_visible
+            && ctx.input(|i| {
+                i.pointer.any_pressed()
+                    && i.pointer
+                        .interact_pos()
+                        .zip(popup_rect)
+                        .is_some_and(|(pos, rect)| rect.contains(pos))
+            })
+    }
+
+    /// Move the highlight with the up and down arrows, accept with tab or enter, and dismiss with escape.
+    ///
+    /// The keys are consumed while the suggestions are shown.
+    /// Returns the new cursor if a suggestion was accepted.
+    pub(crate) fn handle_keys(
+        &mut self,
+        ui: &Ui,
+        text: &mut dyn TextBuffer,
+        input_filter: &InputFilter<'_>,
+    ) -> Option<CCursor> {
+        if !self.is_open() {
+            return None;
+        }
+
+        let (down, up, accept, escape) = ui.input_mut(|i| {
+            (
+                i.count_and_consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.count_and_consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::Tab)
+                    || i.consume_key(Modifiers::NONE, Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+
+        if escape {
+            self.state.dismissed = true;
+            return None;
+        }
+
+        let num_suggestions = self.state.suggestions.len();
+        if down + up > 0 {
+            self.state.selected = (self.state.selected + down + num_suggestions
+                - up % num_suggestions)
+                % num_suggestions;
+            self.scroll_to_selected = true;
+        }
+
+        if accept {
+            self.accept(text, self.state.selected, input_filter)
+        } else {
+            None
+        }
+    }
+
+    /// Show the suggestions below `cursor_rect`, which is in the coordinates of `ui`.
+    ///
+    /// Returns the new cursor if a suggestion was clicked.
+    pub(crate) fn show_popup(
+        &mut self,
+        ui: &Ui,
+        text: &mut dyn TextBuffer,
+        cursor_rect: Rect,
+        input_filter: &InputFilter<'_>,
+    ) -> Option<CCursor> {
+        if !self.is_open() {
+            return None;
+        }
+
+        let mut pos = cursor_rect.left_bottom();
+        if let Some(transform) = ui
+            .ctx()
+            .memory(|m| m.layer_transforms.get(&ui.layer_id()).copied())
+        {
+            pos = transform * pos;
+        }
+
+        let clicked = Area::new(self.id)
+            .kind(UiKind::Popup)
+            .order(Order::Foreground)
+            .fixed_pos(pos)
+            .show(ui.ctx(), |ui| {
+                Frame::popup(ui.style())
+                    .show(ui, |ui| {
+                        ScrollArea::vertical()
+                            .max_height(ui.spacing().combo_height)
+                            .show(ui, |ui| {
+                                let mut clicked = None;
+                                for (i, suggestion) in self.state.suggestions.iter().enumerate() {
+                                    let selected = i == self.state.selected;
+                                    let response = ui.selectable_label(selected, suggestion);
+                                    if selected && self.scroll_to_selected {
+                                        response.scroll_to_me(None);
+                                    }
+                                    if response.clicked() {
+                                        clicked = Some(i);
+                                    }
+                                }
+                                clicked
+                            })
+                            .inner
+                    })
+                    .inner
+            })
+            .inner;
+
+        clicked.and_then(|index| self.accept(text, index, input_filter))
+    }
+
+    /// Replace the word in front of the cursor with the suggestion at `index`.
+    ///
+    /// Like typed text, the suggestion goes through `input_filter`.
+    /// Returns `None` (and leaves `text` unchanged) if nothing of it is allowed.
+    fn accept(
+        &mut self,
+        text: &mut dyn TextBuffer,
+        index: usize,
+        input_filter: &InputFilter<'_>,
+    ) -> Option<CCursor> {
+        let num_replaced = self.state.word.chars().count();
+        let suggestion = input_filter
+            .filter_replacement(text, num_replaced, &self.state.suggestions[index])?
+            .into_owned();
+        let word_end = self.word_start + num_replaced;
+        text.delete_char_range(self.word_start..word_end);
+        let inserted = text.insert_text(&suggestion, self.word_start);
+
+        // The word now matches the suggestion, so don't suggest it again:
+        self.state = AutocompleteState {
+            word: suggestion,
+            dismissed: true,
+            ..Default::default()
+        };
+
+        Some(CCursor::new(self.word_start + inserted))
+    }
+}
+
+/// The char index where the word ending at `cursor` starts, and the word itself.
+///
+/// A word is made of alphanumeric characters and `_`.
+fn word_before_cursor(text: &str, cursor: usize) -> (usize, &str) {
+    let end = text
+        .char_indices()
+        .nth(cursor)
+        .map_or(text.len(), |(byte, _)| byte);
+    let start = text[..end]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+        .last()
+        .map_or(end, |(byte, _)| byte);
+    (text[..start].chars().count(), &text[start..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_before_cursor() {
+        assert_eq!(word_before_cursor("", 0), (0, ""));
+        assert_eq!(word_before_cursor("hello", 5), (0, "hello"));
+        assert_eq!(word_before_cursor("hello", 3), (0, "hel"));
+        assert_eq!(word_before_cursor("let my_var", 10), (4, "my_var"));
+        assert_eq!(word_before_cursor("foo(bar", 7), (4, "bar"));
+        assert_eq!(word_before_cursor("foo ", 4), (4, ""));
+        assert_eq!(word_before_cursor("åäö öä", 6), (4, "öä"));
+    }
+}
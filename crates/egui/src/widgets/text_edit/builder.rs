@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
 use epaint::text::{cursor::*, Galley, LayoutJob};
 
@@ -11,7 +11,11 @@ use crate::{
     *,
 };
 
-use super::{TextEditOutput, TextEditState};
+use super::{
+    async_layouter::{layout_async, AsyncLayouter},
+    autocomplete::{Autocomplete, Suggester},
+    InputMask, TextEditOutput, TextEditState,
+};
 
 /// A text region that the user can edit the contents of.
 ///
@@ -66,6 +70,7 @@ pub struct TextEdit<'t> {
     font_selection: FontSelection,
     text_color: Option<Color32>,
     layouter: Option<&'t mut dyn FnMut(&Ui, &str, f32) -> Arc<Galley>>,
+    async_layouter: Option<AsyncLayouter>,
     password: bool,
     frame: bool,
     margin: Margin,
@@ -79,7 +84,12 @@ pub struct TextEdit<'t> {
     align: Align2,
     clip_text: bool,
     char_limit: usize,
+    char_limit_hard: usize,
+    char_filter: Option<Box<dyn Fn(char) -> bool + 't>>,
     return_key: Option<KeyboardShortcut>,
+    validation: Option<ValidationState>,
+    autocomplete: Option<Suggester<'t>>,
+    input_mask: Option<InputMask>,
 }
 
 impl<'t> WidgetWithState for TextEdit<'t> {
@@ -118,6 +128,7 @@ impl<'t> TextEdit<'t> {
             font_selection: Default::default(),
             text_color: None,
             layouter: None,
+            async_layouter: None,
             password: false,
             frame: true,
             margin: Margin::symmetric(4.0, 2.0),
@@ -137,7 +148,12 @@ impl<'t> TextEdit<'t> {
             align: Align2::LEFT_TOP,
             clip_text: false,
             char_limit: usize::MAX,
+            char_limit_hard: usize::MAX,
+            char_filter: None,
             return_key: Some(KeyboardShortcut::new(Modifiers::NONE, Key::Enter)),
+            validation: None,
+            autocomplete: None,
+            input_mask: None,
         }
     }
 
@@ -250,6 +266,40 @@ impl<'t> TextEdit<'t> {
     #[inline]
     pub fn layouter(mut self, layouter: &'t mut dyn FnMut(&Ui, &str, f32) -> Arc<Galley>) -> Self {
         self.layouter = Some(layouter);
+        self.async_layouter = None;
+
+        self
+    }
+
+    /// Like [`Self::layouter`], but runs in the background, so that a slow layouter
+    /// (e.g. a syntax highlighter for a large file) doesn't add to the input latency.
+    ///
+    /// The function is given the text and the wrap width, and returns the [`LayoutJob`] to lay out.
+    /// It is run with [`Context::spawn`], at most once at a time for each [`TextEdit`].
+    ///
+    /// While a new layout is being computed, the text is shown with the styling of the latest finished layout,
+    /// adjusted to the edited text, and with plain styling until the first layout is done.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_code = String::new();
+    /// # fn my_slow_highlighter(s: &str) -> egui::text::LayoutJob { Default::default() }
+    /// ui.add(
+    ///     egui::TextEdit::multiline(&mut my_code).async_layouter(|string, wrap_width| {
+    ///         let mut layout_job = my_slow_highlighter(string);
+    ///         layout_job.wrap.max_width = wrap_width;
+    ///         layout_job
+    ///     }),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn async_layouter(
+        mut self,
+        layouter: impl Fn(&str, f32) -> LayoutJob + Send + Sync + 'static,
+    ) -> Self {
+        self.async_layouter = Some(Arc::new(layouter));
+        self.layouter = None;
 
         self
     }
@@ -337,6 +387,44 @@ impl<'t> TextEdit<'t> {
         self
     }
 
+    /// Never let the text have more than this many characters.
+    ///
+    /// Unlike [`Self::char_limit`], which cuts off pasted text that doesn't fit,
+    /// an edit that would make the text too long is rejected as a whole.
+    /// This includes typing, pasting and IME input.
+    ///
+    /// Text set by your code is not checked.
+    #[inline]
+    pub fn char_limit_hard(mut self, limit: usize) -> Self {
+        self.char_limit_hard = limit;
+        self
+    }
+
+    /// Only let the user enter characters for which `char_filter` returns `true`.
+    ///
+    /// Other characters are removed from the input before it is inserted,
+    /// whether it is typed, pasted or from an IME,
+    /// so the text never contains them (unless your code puts them there).
+    /// This includes newlines and tabs in a multiline [`TextEdit`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut amount = String::new();
+    /// # let mut identifier = String::new();
+    /// ui.add(egui::TextEdit::singleline(&mut amount).char_filter(|c| c.is_ascii_digit()));
+    /// ui.add(
+    ///     egui::TextEdit::singleline(&mut identifier)
+    ///         .char_filter(|c| c.is_ascii_alphanumeric() || c == '_')
+    ///         .char_limit_hard(32),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn char_filter(mut self, char_filter: impl Fn(char) -> bool + 't) -> Self {
+        self.char_filter = Some(Box::new(char_filter));
+        self
+    }
+
     /// Set the horizontal align of the inner text.
     #[inline]
     pub fn horizontal_align(mut self, align: Align) -> Self {
@@ -369,6 +457,64 @@ impl<'t> TextEdit<'t> {
         self.return_key = return_key.into();
         self
     }
+
+    /// Show the result of validating the text, with a colored frame and a message.
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn validation(mut self, validation: impl Into<Option<ValidationState>>) -> Self {
+        self.validation = validation.into();
+        self
+    }
+
+    /// Suggest completions for the word being typed.
+    ///
+    /// `suggest` is given the word in front of the cursor (made of alphanumeric characters and `_`),
+    /// and returns the completions to show, best first.
+    /// It is only called when that word changes.
+    ///
+    /// The suggestions are shown in a popup below the cursor.
+    /// The arrow keys move the highlight, tab or enter replaces the word with the highlighted suggestion,
+    /// and escape hides the popup.
+    /// A suggestion can also be clicked.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_code = String::new();
+    /// let keywords = ["break", "const", "continue", "else", "enum", "extern"];
+    /// ui.add(egui::TextEdit::multiline(&mut my_code).autocomplete(|word| {
+    ///     keywords
+    ///         .iter()
+    ///         .filter(|keyword| keyword.starts_with(word))
+    ///         .map(|keyword| (*keyword).to_owned())
+    ///         .collect()
+    /// }));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn autocomplete(mut self, suggest: impl FnMut(&str) -> Vec<String> + 't) -> Self {
+        self.autocomplete = Some(Box::new(suggest));
+        self
+    }
+
+    /// Only allow text in a fixed format, like a date or phone number.
+    ///
+    /// Literals of the mask are inserted automatically as the user types.
+    /// If there is no [`Self::hint_text`], the mask is shown as hint.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut phone_number = String::new();
+    /// ui.add(egui::TextEdit::singleline(&mut phone_number).input_mask("+1 (###) ###-####"));
+    /// # });
+    /// ```
+    ///
+    /// See [`InputMask`] for more.
+    #[inline]
+    pub fn input_mask(mut self, input_mask: impl Into<InputMask>) -> Self {
+        self.input_mask = Some(input_mask.into());
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -401,6 +547,7 @@ impl<'t> TextEdit<'t> {
         let where_to_put_background = ui.painter().add(Shape::Noop);
 
         let margin = self.margin;
+        let validation = self.validation.clone();
         let mut output = self.show_content(ui);
 
         // TODO(emilk): return full outer_rect in `TextEditOutput`.
@@ -440,6 +587,11 @@ impl<'t> TextEdit<'t> {
             ui.painter().set(where_to_put_background, shape);
         }
 
+        if let Some(validation) = validation {
+            let frame_rect = outer_rect.expand(ui.style().interact(&output.response).expansion);
+            output.response = validation.show(ui, output.response, frame_rect);
+        }
+
         output
     }
 
@@ -453,6 +605,7 @@ impl<'t> TextEdit<'t> {
             font_selection,
             text_color,
             layouter,
+            async_layouter,
             password,
             frame: _,
             margin,
@@ -466,9 +619,19 @@ impl<'t> TextEdit<'t> {
             align,
             clip_text,
             char_limit,
+            char_limit_hard,
+            char_filter,
             return_key,
+            validation: _,
+            autocomplete,
+            input_mask,
         } = self;
 
+        let hint_text = match &input_mask {
+            Some(input_mask) if hint_text.is_empty() => input_mask.placeholder().into(),
+            _ => hint_text,
+        };
+
         let text_color = text_color
             .or(ui.visuals().override_text_color)
             // .unwrap_or_else(|| ui.style().interact(&response).text_color()); // too bright
@@ -488,7 +651,7 @@ impl<'t> TextEdit<'t> {
         };
 
         let font_id_clone = font_id.clone();
-        let mut default_layouter = move |ui: &Ui, text: &str, wrap_width: f32| {
+        let default_layouter = move |ui: &Ui, text: &str, wrap_width: f32| {
             let text = mask_if_password(password, text);
             let layout_job = if multiline {
                 LayoutJob::simple(text, font_id_clone.clone(), text_color, wrap_width)
@@ -498,7 +661,35 @@ impl<'t> TextEdit<'t> {
             ui.fonts(|f| f.layout_job(layout_job))
         };
 
-        let layouter = layouter.unwrap_or(&mut default_layouter);
+        let default_layouter = &default_layouter;
+        let mut plain_layouter =
+            |ui: &Ui, text: &str, wrap_width: f32| default_layouter(ui, text, wrap_width);
+
+        // Same as the `id` below, which we don't know yet:
+        let async_layout_id = id
+            .or_else(|| id_source.map(|id_source| ui.make_persistent_id(id_source)))
+            .unwrap_or_else(|| ui.next_auto_id())
+            .with("async_layouter");
+        let mut async_layouter = async_layouter.map(|async_layouter| {
+            move |ui: &Ui, text: &str, wrap_width: f32| {
+                let masked_text = mask_if_password(password, text);
+                layout_async(
+                    ui,
+                    async_layout_id,
+                    &async_layouter,
+                    &masked_text,
+                    wrap_width,
+                )
+                .unwrap_or_else(|| default_layouter(ui, text, wrap_width))
+            }
+        });
+
+        let layouter: &mut dyn FnMut(&Ui, &str, f32) -> Arc<Galley> =
+            match (layouter, &mut async_layouter) {
+                (Some(layouter), _) => layouter,
+                (None, Some(async_layouter)) => async_layouter,
+                (None, None) => &mut plain_layouter,
+            };
 
         let mut galley = layouter(ui, text.as_str(), wrap_width);
 
@@ -510,7 +701,16 @@ impl<'t> TextEdit<'t> {
         let desired_height = (desired_height_rows.at_least(1) as f32) * row_height;
         let desired_inner_size = vec2(desired_width, galley.size().y.max(desired_height));
         let desired_outer_size = (desired_inner_size + margin.sum()).at_least(min_size);
-        let (auto_id, outer_rect) = ui.allocate_space(desired_outer_size);
+        // Where the first row of text ends up, so that we line up with labels in a baseline aligned layout:
+        let baseline = {
+            let inner_rect = Rect::from_min_size(Pos2::ZERO, desired_outer_size) - margin;
+            let galley_top = align
+                .align_size_within_rect(galley.size(), inner_rect)
+                .intersect(inner_rect)
+                .top();
+            galley_top + galley.first_row_baseline()
+        };
+        let (auto_id, outer_rect) = ui.allocate_space_with_baseline(desired_outer_size, baseline);
         let rect = outer_rect - margin; // inner rect (excluding frame/margin).
 
         let id = id.unwrap_or_else(|| {
@@ -521,6 +721,9 @@ impl<'t> TextEdit<'t> {
             }
         });
         let mut state = TextEditState::load(ui.ctx(), id).unwrap_or_default();
+        let mut autocomplete = autocomplete
+            .filter(|_| interactive && text.is_mutable())
+            .map(|suggest| Autocomplete::load(ui.ctx(), id.with("autocomplete"), suggest));
 
         // On touch screens (e.g. mobile in `eframe` web), should
         // dragging select text, or scroll the enclosing [`ScrollArea`] (if any)?
@@ -539,6 +742,16 @@ impl<'t> TextEdit<'t> {
             Sense::hover()
         };
         let mut response = ui.interact(outer_rect, id, sense);
+
+        if autocomplete
+            .as_ref()
+            .is_some_and(|autocomplete| autocomplete.is_popup_pressed(ui.ctx()))
+            && ui.memory(|mem| mem.had_focus_last_frame(id))
+        {
+            // Keep the focus when clicking a suggestion:
+            ui.memory_mut(|mem| mem.request_focus(id));
+        }
+
         let text_clip_rect = rect;
         let painter = ui.painter_at(text_clip_rect.expand(1.0)); // expand to avoid clipping cursor
 
@@ -583,6 +796,12 @@ impl<'t> TextEdit<'t> {
             ui.ctx().set_cursor_icon(CursorIcon::Text);
         }
 
+        let input_filter = InputFilter {
+            char_filter: char_filter.as_deref(),
+            char_limit,
+            char_limit_hard,
+        };
+
         let mut cursor_range = None;
         let prev_cursor_range = state.cursor.range(&galley);
         if interactive && ui.memory(|mem| mem.has_focus(id)) {
@@ -594,6 +813,17 @@ impl<'t> TextEdit<'t> {
                 CursorRange::default()
             };
 
+            if let Some(autocomplete) = &mut autocomplete {
+                autocomplete.update(text.as_str(), state.cursor.range(&galley));
+                if let Some(ccursor) = autocomplete.handle_keys(ui, text, &input_filter) {
+                    galley = layouter(ui, text.as_str(), wrap_width);
+                    state
+                        .cursor
+                        .set_char_range(Some(CCursorRange::one(ccursor)));
+                    response.mark_changed();
+                }
+            }
+
             let (changed, new_cursor_range) = events(
                 ui,
                 &mut state,
@@ -605,15 +835,34 @@ impl<'t> TextEdit<'t> {
                 multiline,
                 password,
                 default_cursor_range,
-                char_limit,
+                &input_filter,
                 event_filter,
                 return_key,
+                input_mask.as_ref(),
             );
 
             if changed {
                 response.mark_changed();
             }
             cursor_range = Some(new_cursor_range);
+
+            if let Some(autocomplete) = &mut autocomplete {
+                autocomplete.update(text.as_str(), cursor_range);
+                if autocomplete.is_open() {
+                    // Let the suggestion popup have the keys next frame:
+                    ui.memory_mut(|mem| {
+                        mem.set_focus_lock_filter(
+                            id,
+                            EventFilter {
+                                tab: true,
+                                vertical_arrows: true,
+                                escape: true,
+                                ..event_filter
+                            },
+                        );
+                    });
+                }
+            }
         }
 
         let mut galley_pos = align
@@ -621,6 +870,7 @@ impl<'t> TextEdit<'t> {
             .intersect(rect) // limit pos to the response rect area
             .min;
         let align_offset = rect.left() - galley_pos.x;
+        response.baseline = Some(galley_pos.y + galley.first_row_baseline());
 
         // Visual clipping for singleline text editor with text larger than width
         if clip_text && align_offset == 0.0 {
@@ -739,6 +989,23 @@ impl<'t> TextEdit<'t> {
             }
         }
 
+        if let Some(mut autocomplete) = autocomplete {
+            if let Some(primary) = cursor_range.map(|cursor_range| cursor_range.primary) {
+                let primary_cursor_rect = cursor_rect(galley_pos, &galley, &primary, row_height);
+                if let Some(ccursor) =
+                    autocomplete.show_popup(ui, text, primary_cursor_rect, &input_filter)
+                {
+                    galley = layouter(ui, text.as_str(), wrap_width);
+                    let new_cursor_range = CursorRange::one(galley.from_ccursor(ccursor));
+                    state.cursor.set_range(Some(new_cursor_range));
+                    cursor_range = Some(new_cursor_range);
+                    response.mark_changed();
+                    ui.ctx().request_repaint();
+                }
+            }
+            autocomplete.store(ui.ctx());
+        }
+
         state.clone().store(ui.ctx(), id);
 
         if response.changed {
@@ -826,9 +1093,10 @@ fn events(
     multiline: bool,
     password: bool,
     default_cursor_range: CursorRange,
-    char_limit: usize,
+    input_filter: &InputFilter<'_>,
     event_filter: EventFilter,
     return_key: Option<KeyboardShortcut>,
+    input_mask: Option<&InputMask>,
 ) -> (bool, CursorRange) {
     let os = ui.ctx().os();
 
@@ -851,6 +1119,9 @@ fn events(
 
     let events = ui.input(|i| i.filtered_events(&event_filter));
     for event in &events {
+        // The mask may add literals, which must also pass the input filter:
+        let text_before_event = input_mask.map(|_| text.as_str().to_owned());
+
         let did_mutate_text = match event {
             // First handle events that only changes the selection cursor, not the text:
             event if cursor_range.on_event(os, event, galley, id) => None,
@@ -872,25 +1143,15 @@ fn events(
                     Some(CCursorRange::one(text.delete_selected(&cursor_range)))
                 }
             }
-            Event::Paste(text_to_insert) => {
-                if !text_to_insert.is_empty() {
-                    let mut ccursor = text.delete_selected(&cursor_range);
-
-                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
-
-                    Some(CCursorRange::one(ccursor))
-                } else {
-                    None
-                }
-            }
+            Event::Paste(text_to_insert) => input_filter
+                .replace_selection(text, &cursor_range, text_to_insert)
+                .map(CCursorRange::one),
             Event::Text(text_to_insert) => {
                 // Newlines are handled by `Key::Enter`.
-                if !text_to_insert.is_empty() && text_to_insert != "\n" && text_to_insert != "\r" {
-                    let mut ccursor = text.delete_selected(&cursor_range);
-
-                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
-
-                    Some(CCursorRange::one(ccursor))
+                if text_to_insert != "\n" && text_to_insert != "\r" {
+                    input_filter
+                        .replace_selection(text, &cursor_range, text_to_insert)
+                        .map(CCursorRange::one)
                 } else {
                     None
                 }
@@ -901,14 +1162,16 @@ fn events(
                 modifiers,
                 ..
             } if multiline => {
-                let mut ccursor = text.delete_selected(&cursor_range);
                 if modifiers.shift {
+                    let mut ccursor = text.delete_selected(&cursor_range);
                     // TODO(emilk): support removing indentation over a selection?
                     text.decrease_indentation(&mut ccursor);
+                    Some(CCursorRange::one(ccursor))
                 } else {
-                    text.insert_text_at(&mut ccursor, "\t", char_limit);
+                    input_filter
+                        .replace_selection(text, &cursor_range, "\t")
+                        .map(CCursorRange::one)
                 }
-                Some(CCursorRange::one(ccursor))
             }
             Event::Key {
                 key,
@@ -920,10 +1183,10 @@ fn events(
             }) =>
             {
                 if multiline {
-                    let mut ccursor = text.delete_selected(&cursor_range);
-                    text.insert_text_at(&mut ccursor, "\n", char_limit);
                     // TODO(emilk): if code editor, auto-indent by same leading tabs, + one if the lines end on an opening bracket
-                    Some(CCursorRange::one(ccursor))
+                    input_filter
+                        .replace_selection(text, &cursor_range, "\n")
+                        .map(CCursorRange::one)
                 } else {
                     ui.memory_mut(|mem| mem.surrender_focus(id)); // End input with enter
                     break;
@@ -967,6 +1230,24 @@ fn events(
                 }
             }
 
+            Event::Key {
+                key: key @ (Key::Backspace | Key::Delete),
+                pressed: true,
+                modifiers,
+                ..
+            } if modifiers.is_none() && cursor_range.is_empty() && input_mask.is_some() => {
+                input_mask.map(|input_mask| {
+                    // Skip over the literals of the mask:
+                    let (masked, index) = input_mask.delete(
+                        text.as_str(),
+                        cursor_range.primary.ccursor.index,
+                        *key == Key::Delete,
+                    );
+                    text.replace_with(&masked);
+                    CCursorRange::one(CCursor::new(index))
+                })
+            }
+
             Event::Key {
                 modifiers,
                 key,
@@ -986,10 +1267,11 @@ fn events(
                     } else {
                         // Empty prediction can be produced when user press backspace
                         // or escape during IME, so we clear current text.
+                        let text_mark = input_filter.filter(text, &cursor_range, text_mark);
                         let mut ccursor = text.delete_selected(&cursor_range);
                         let start_cursor = ccursor;
-                        if !text_mark.is_empty() {
-                            text.insert_text_at(&mut ccursor, text_mark, char_limit);
+                        if let Some(text_mark) = text_mark {
+                            text.insert_text_at(&mut ccursor, &text_mark, input_filter.char_limit);
                         }
                         state.ime_cursor_range = cursor_range;
                         Some(CCursorRange::two(start_cursor, ccursor))
@@ -1005,8 +1287,10 @@ fn events(
                             && cursor_range.secondary.ccursor.index
                                 == state.ime_cursor_range.secondary.ccursor.index
                         {
-                            let mut ccursor = text.delete_selected(&cursor_range);
-                            text.insert_text_at(&mut ccursor, prediction, char_limit);
+                            let ccursor = input_filter
+                                .replace_selection(text, &cursor_range, prediction)
+                                // Remove the preedit text even if the prediction is rejected:
+                                .unwrap_or_else(|| text.delete_selected(&cursor_range));
                             Some(CCursorRange::one(ccursor))
                         } else {
                             let ccursor = cursor_range.primary.ccursor;
@@ -1023,7 +1307,20 @@ fn events(
             _ => None,
         };
 
-        if let Some(new_ccursor_range) = did_mutate_text {
+        if let Some(mut new_ccursor_range) = did_mutate_text {
+            if let (Some(input_mask), Some(text_before_event)) = (input_mask, &text_before_event) {
+                let (masked, index) =
+                    input_mask.apply(text.as_str(), new_ccursor_range.primary.index);
+                if !input_filter.allows_change(text_before_event, &masked) {
+                    text.replace_with(text_before_event);
+                    continue;
+                }
+                if masked != text.as_str() {
+                    text.replace_with(&masked);
+                }
+                new_ccursor_range = CCursorRange::one(CCursor::new(index));
+            }
+
             any_change = true;
 
             // Layout again to avoid frame delay, and to keep `text` and `galley` in sync.
@@ -1049,6 +1346,89 @@ fn events(
 
 // ----------------------------------------------------------------------------
 
+/// What the user is allowed to enter,
+/// see [`TextEdit::char_filter`], [`TextEdit::char_limit`] and [`TextEdit::char_limit_hard`].
+pub(super) struct InputFilter<'a> {
+    char_filter: Option<&'a dyn Fn(char) -> bool>,
+    char_limit: usize,
+    char_limit_hard: usize,
+}
+
+impl InputFilter<'_> {
+    /// Remove the characters that are not allowed from `text_to_insert`.
+    ///
+    /// Returns `None` if there is nothing left, or if replacing the selection with it
+    /// would make the text longer than [`Self::char_limit_hard`].
+    fn filter<'s>(
+        &self,
+        text: &dyn TextBuffer,
+        cursor_range: &CursorRange,
+        text_to_insert: &'s str,
+    ) -> Option<Cow<'s, str>> {
+        self.filter_replacement(
+            text,
+            cursor_range.as_sorted_char_range().len(),
+            text_to_insert,
+        )
+    }
+
+    /// Like [`Self::filter`], for replacing `num_replaced` characters of `text` with `text_to_insert`.
+    pub(super) fn filter_replacement<'s>(
+        &self,
+        text: &dyn TextBuffer,
+        num_replaced: usize,
+        text_to_insert: &'s str,
+    ) -> Option<Cow<'s, str>> {
+        let text_to_insert = match self.char_filter {
+            Some(char_filter) if !text_to_insert.chars().all(char_filter) => {
+                Cow::Owned(text_to_insert.chars().filter(|c| char_filter(*c)).collect())
+            }
+            _ => Cow::Borrowed(text_to_insert),
+        };
+        if text_to_insert.is_empty() {
+            return None;
+        }
+
+        let new_len = text.as_str().chars().count() - num_replaced + text_to_insert.chars().count();
+        (new_len <= self.char_limit_hard).then_some(text_to_insert)
+    }
+
+    /// May `old_text` be changed into `new_text`, e.g. when an [`InputMask`] adds its literals?
+    ///
+    /// The change may not add characters rejected by the char filter,
+    /// nor make the text grow past [`Self::char_limit_hard`].
+    fn allows_change(&self, old_text: &str, new_text: &str) -> bool {
+        let (old_len, new_len) = (old_text.chars().count(), new_text.chars().count());
+        if old_len < new_len && self.char_limit_hard < new_len {
+            return false;
+        }
+        match self.char_filter {
+            Some(char_filter) => {
+                let num_rejected = |text: &str| text.chars().filter(|c| !char_filter(*c)).count();
+                num_rejected(new_text) <= num_rejected(old_text)
+            }
+            None => true,
+        }
+    }
+
+    /// Replace the selection with the allowed characters of `text_to_insert`.
+    ///
+    /// Returns `None` (and leaves `text` unchanged) if nothing is allowed, see [`Self::filter`].
+    fn replace_selection(
+        &self,
+        text: &mut dyn TextBuffer,
+        cursor_range: &CursorRange,
+        text_to_insert: &str,
+    ) -> Option<CCursor> {
+        let text_to_insert = self.filter(text, cursor_range, text_to_insert)?;
+        let mut ccursor = text.delete_selected(cursor_range);
+        text.insert_text_at(&mut ccursor, &text_to_insert, self.char_limit);
+        Some(ccursor)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Returns `Some(new_cursor)` if we did mutate `text`.
 fn check_for_mutating_key_press(
     os: OperatingSystem,
@@ -1122,3 +1502,114 @@ fn check_for_mutating_key_press(
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CentralPanel, Context, Event, Modifiers, RawInput};
+
+    /// Runs a frame with a focused [`TextEdit`] made by `text_edit`.
+    fn run_frame(
+        ctx: &Context,
+        text: &mut String,
+        events: Vec<Event>,
+        text_edit: fn(&mut String) -> TextEdit<'_>,
+    ) {
+        let raw_input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(raw_input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let response = ui.add(text_edit(text));
+                if !response.has_focus() {
+                    response.request_focus();
+                }
+            });
+        });
+    }
+
+    /// Types `chars` one at a time into the [`TextEdit`] made by `text_edit`.
+    fn type_chars(text: &mut String, chars: &str, text_edit: fn(&mut String) -> TextEdit<'_>) {
+        let ctx = Context::default();
+        run_frame(&ctx, text, vec![], text_edit); // gives the focus
+        for c in chars.chars() {
+            run_frame(&ctx, text, vec![Event::Text(c.to_string())], text_edit);
+        }
+    }
+
+    #[test]
+    fn test_input_mask_adds_literals() {
+        let mut text = String::new();
+        type_chars(&mut text, "1234", |text| {
+            TextEdit::singleline(text).input_mask("##-##")
+        });
+        assert_eq!(text, "12-34");
+    }
+
+    #[test]
+    fn test_input_mask_respects_char_limit_hard() {
+        // The literal added by the mask counts towards the limit:
+        let mut text = String::new();
+        type_chars(&mut text, "1234", |text| {
+            TextEdit::singleline(text)
+                .input_mask("##-##")
+                .char_limit_hard(3)
+        });
+        assert_eq!(text, "12");
+    }
+
+    #[test]
+    fn test_input_mask_respects_char_filter() {
+        // The filter doesn't allow the literal of the mask:
+        let mut text = String::new();
+        type_chars(&mut text, "1234", |text| {
+            TextEdit::singleline(text)
+                .input_mask("##-##")
+                .char_filter(|c| c.is_ascii_digit())
+        });
+        assert_eq!(text, "12");
+    }
+
+    fn accept_suggestion(text: &mut String, text_edit: fn(&mut String) -> TextEdit<'_>) {
+        let ctx = Context::default();
+        run_frame(&ctx, text, vec![], text_edit); // gives the focus
+        run_frame(&ctx, text, vec![Event::Text("ap".to_owned())], text_edit);
+        let tab = Event::Key {
+            key: Key::Tab,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        };
+        run_frame(&ctx, text, vec![tab], text_edit);
+    }
+
+    #[test]
+    fn test_autocomplete() {
+        let mut text = String::new();
+        accept_suggestion(&mut text, |text| {
+            TextEdit::singleline(text).autocomplete(|_| vec!["apple".to_owned()])
+        });
+        assert_eq!(text, "apple");
+    }
+
+    #[test]
+    fn test_autocomplete_respects_input_filter() {
+        let mut text = String::new();
+        accept_suggestion(&mut text, |text| {
+            TextEdit::singleline(text)
+                .autocomplete(|_| vec!["apple".to_owned()])
+                .char_limit_hard(4)
+        });
+        assert_eq!(text, "ap");
+
+        let mut text = String::new();
+        accept_suggestion(&mut text, |text| {
+            TextEdit::singleline(text)
+                .autocomplete(|_| vec!["a_pple".to_owned()])
+                .char_filter(|c| c != '_')
+        });
+        assert_eq!(text, "apple");
+    }
+}
@@ -0,0 +1,280 @@
+/// One character of an [`InputMask`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+enum MaskChar {
+    /// `#`: a digit typed by the user.
+    Digit,
+
+    /// `A`: a letter typed by the user.
+    Letter,
+
+    /// `*`: a letter or digit typed by the user.
+    Alphanumeric,
+
+    /// Inserted automatically.
+    Literal(char),
+}
+
+impl MaskChar {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Alphanumeric => c.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+}
+
+/// Restricts what can be typed into a [`crate::TextEdit`] to a fixed format,
+/// like a date, phone number or credit card number.
+///
+/// The mask is a pattern where
+/// * `#` is a digit,
+/// * `A` is a letter,
+/// * `*` is a letter or digit,
+/// * `\` makes the next character a literal (e.g. `\#`),
+/// * anything else is a literal, which is inserted automatically.
+///
+/// Characters that don't fit are ignored, both when typing and pasting,
+/// and the text cursor is kept next to the characters the user typed.
+/// Backspace and delete skip over the literals.
+///
+/// The text of the [`crate::TextEdit`] includes the literals.
+/// Use [`Self::unmask`] to get only the characters the user typed.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut date = String::new();
+/// let mask = egui::InputMask::new("##.##.####");
+/// ui.add(egui::TextEdit::singleline(&mut date).input_mask(mask.clone()));
+/// if mask.is_complete(&date) {
+///     let digits = mask.unmask(&date);
+///     // …
+/// }
+/// # });
+/// ```
+///
+/// Some examples:
+/// * Phone number: `+1 (###) ###-####`
+/// * IPv4 address: `###.###.###.###`
+/// * Credit card number: `#### #### #### ####`
+/// * Postal code: `A#A #A#`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InputMask {
+    chars: Vec<MaskChar>,
+}
+
+impl InputMask {
+    /// See [`InputMask`] for the syntax of the pattern.
+    pub fn new(pattern: &str) -> Self {
+        let mut chars = Vec::with_capacity(pattern.len());
+        let mut pattern_chars = pattern.chars();
+        while let Some(c) = pattern_chars.next() {
+            chars.push(match c {
+                '#' => MaskChar::Digit,
+                'A' => MaskChar::Letter,
+                '*' => MaskChar::Alphanumeric,
+                '\\' => MaskChar::Literal(pattern_chars.next().unwrap_or('\\')),
+                _ => MaskChar::Literal(c),
+            });
+        }
+        Self { chars }
+    }
+
+    /// The mask with `_` for each character the user should type, e.g. `__.__.____`.
+    ///
+    /// This is used as the hint text of a [`crate::TextEdit`] that doesn't have one.
+    pub fn placeholder(&self) -> String {
+        self.chars
+            .iter()
+            .map(|mask_char| match mask_char {
+                MaskChar::Literal(c) => *c,
+                _ => '_',
+            })
+            .collect()
+    }
+
+    /// Only the characters typed by the user, without the literals.
+    ///
+    /// ```
+    /// let mask = egui::InputMask::new("+1 (###) ###-####");
+    /// assert_eq!(mask.unmask("+1 (555) 123-4567"), "5551234567");
+    /// ```
+    pub fn unmask(&self, text: &str) -> String {
+        self.split(text, 0).0.into_iter().collect()
+    }
+
+    /// Fit `text` to the mask, e.g. `"24122024"` to `"24.12.2024"`.
+    ///
+    /// Characters that don't fit are removed.
+    /// Literals are only added between typed characters, so there is no literal at the end.
+    pub fn format(&self, text: &str) -> String {
+        self.apply(text, 0).0
+    }
+
+    /// Has the user typed all the characters of the mask?
+    pub fn is_complete(&self, text: &str) -> bool {
+        let num_editable = self
+            .chars
+            .iter()
+            .filter(|mask_char| !matches!(mask_char, MaskChar::Literal(_)))
+            .count();
+        self.split(text, 0).0.len() == num_editable
+    }
+
+    /// Fit `text` to the mask.
+    ///
+    /// Returns the new text, and where the char index `cursor` ends up in it.
+    pub(crate) fn apply(&self, text: &str, cursor: usize) -> (String, usize) {
+        let (typed, typed_cursor) = self.split(text, cursor);
+        self.join(&typed, typed_cursor)
+    }
+
+    /// Delete the typed character in front of (or, if `forward`, after) the char index `cursor`,
+    /// skipping over literals.
+    ///
+    /// Returns the new text and cursor.
+    pub(crate) fn delete(&self, text: &str, cursor: usize, forward: bool) -> (String, usize) {
+        let (mut typed, mut typed_cursor) = self.split(text, cursor);
+        if forward {
+            if typed_cursor < typed.len() {
+                typed.remove(typed_cursor);
+            }
+        } else if 0 < typed_cursor {
+            typed_cursor -= 1;
+            typed.remove(typed_cursor);
+        }
+        self.join(&typed, typed_cursor)
+    }
+
+    /// Find the characters typed by the user in `text`, by walking through it together with the mask.
+    ///
+    /// Returns the typed characters, and how many of them are before the char index `cursor`.
+    fn split(&self, text: &str, cursor: usize) -> (Vec<char>, usize) {
+        let mut typed = Vec::new();
+        let mut typed_cursor = None;
+        let mut mask_index = 0;
+
+        for (i, c) in text.chars().enumerate() {
+            if i == cursor {
+                typed_cursor = Some(typed.len());
+            }
+
+            if self.chars.get(mask_index) == Some(&MaskChar::Literal(c)) {
+                mask_index += 1;
+                continue;
+            }
+
+            let next_editable = self.chars[mask_index..]
+                .iter()
+                .position(|mask_char| !matches!(mask_char, MaskChar::Literal(_)))
+                .map(|offset| mask_index + offset);
+            if let Some(next_editable) = next_editable {
+                if self.chars[next_editable].accepts(c) {
+                    typed.push(c);
+                    mask_index = next_editable + 1;
+                }
+            }
+        }
+
+        let typed_cursor = typed_cursor.unwrap_or(typed.len());
+        (typed, typed_cursor)
+    }
+
+    /// Put the typed characters into the mask, adding literals between them.
+    ///
+    /// Returns the text, and where the text cursor in front of `typed[typed_cursor]` ends up.
+    fn join(&self, typed: &[char], typed_cursor: usize) -> (String, usize) {
+        let mut text = String::new();
+        let mut len = 0;
+        let mut end = 0; // Where the last typed character ended.
+        let mut cursor = None;
+        let mut typed = typed.iter().copied().enumerate().peekable();
+
+        for mask_char in &self.chars {
+            if typed.peek().is_none() {
+                break;
+            }
+            if let MaskChar::Literal(c) = mask_char {
+                text.push(*c);
+                len += 1;
+                continue;
+            }
+
+            // Characters that were moved to where they don't fit (e.g. a digit to a letter) are dropped:
+            for (i, c) in typed.by_ref() {
+                if i == typed_cursor {
+                    cursor.get_or_insert(len);
+                }
+                if mask_char.accepts(c) {
+                    text.push(c);
+                    len += 1;
+                    end = len;
+                    break;
+                }
+            }
+        }
+
+        // Remove literals after the last typed character:
+        let text = text.chars().take(end).collect();
+        (text, cursor.unwrap_or(end).min(end))
+    }
+}
+
+impl From<&str> for InputMask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_mask_format() {
+        let date = InputMask::new("##.##.####");
+        assert_eq!(date.placeholder(), "__.__.____");
+        assert_eq!(date.format(""), "");
+        assert_eq!(date.format("1"), "1");
+        assert_eq!(date.format("12"), "12");
+        assert_eq!(date.format("123"), "12.3");
+        assert_eq!(date.format("24122024"), "24.12.2024");
+        assert_eq!(date.format("24.12.2024"), "24.12.2024");
+        assert_eq!(date.format("24/12/2024"), "24.12.2024");
+        assert_eq!(date.format("24.12.20245"), "24.12.2024");
+        assert_eq!(date.format("2a4"), "24");
+        assert!(date.is_complete("24.12.2024"));
+        assert!(!date.is_complete("24.12.202"));
+
+        let phone = InputMask::new("+1 (###) ###-####");
+        assert_eq!(phone.format("5551234567"), "+1 (555) 123-4567");
+        assert_eq!(phone.format("+1 (555) 123-4567"), "+1 (555) 123-4567");
+        assert_eq!(phone.unmask("+1 (555) 123-4567"), "5551234567");
+
+        let escaped = InputMask::new(r"\##");
+        assert_eq!(escaped.format("7"), "#7");
+    }
+
+    #[test]
+    fn test_input_mask_cursor() {
+        let date = InputMask::new("##.##.####");
+
+        // Typing at the end moves past the literal:
+        assert_eq!(date.apply("120", 3), ("12.0".to_owned(), 4));
+
+        // Typing in the middle:
+        assert_eq!(date.apply("12.503", 4), ("12.50.3".to_owned(), 4));
+
+        // A character that doesn't fit is ignored, and the cursor stays:
+        assert_eq!(date.apply("12.x03", 4), ("12.03".to_owned(), 3));
+
+        // Backspace and delete skip over literals:
+        assert_eq!(date.delete("12.03", 3, false), ("10.3".to_owned(), 1));
+        assert_eq!(date.delete("12.03", 2, true), ("12.3".to_owned(), 3));
+        assert_eq!(date.delete("12.0", 4, false), ("12".to_owned(), 2));
+        assert_eq!(date.delete("12", 0, false), ("12".to_owned(), 0));
+    }
+}
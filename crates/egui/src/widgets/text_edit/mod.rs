@@ -1,9 +1,12 @@
+mod async_layouter;
+mod autocomplete;
 mod builder;
+mod input_mask;
 mod output;
 mod state;
 mod text_buffer;
 
 pub use {
-    crate::text_selection::TextCursorState, builder::TextEdit, output::TextEditOutput,
-    state::TextEditState, text_buffer::TextBuffer,
+    crate::text_selection::TextCursorState, builder::TextEdit, input_mask::InputMask,
+    output::TextEditOutput, state::TextEditState, text_buffer::TextBuffer,
 };
@@ -6,6 +6,8 @@ use crate::*;
 
 use self::text_selection::{CCursorRange, CursorRange, TextCursorState};
 
+use crate::util::undoer::Settings as UndoSettings;
+
 pub type TextEditUndoer = crate::util::undoer::Undoer<(CCursorRange, String)>;
 
 /// The text edit state stored between frames.
@@ -96,8 +98,153 @@ impl TextEditState {
         self.set_undoer(TextEditUndoer::default());
     }
 
+    /// How often undo points are created, and how many are kept.
+    pub fn undo_settings(&self) -> UndoSettings {
+        self.undoer.lock().settings().clone()
+    }
+
+    /// Change how often undo points are created, and how many are kept.
+    ///
+    /// For instance, set [`UndoSettings::stable_time`] lower to undo in smaller steps.
+    pub fn set_undo_settings(&self, settings: UndoSettings) {
+        self.undoer.lock().set_settings(settings);
+    }
+
+    /// Remember the current `text` and selection, so that [`Self::undo`] can go back to them.
+    ///
+    /// Use this to mark the boundary between edits,
+    /// e.g. before and after changing the text from your own code.
+    pub fn add_undo_point(&self, text: &str) {
+        let undo_state = self.undo_state(text);
+        self.undoer.lock().add_undo(&undo_state);
+    }
+
+    /// Is there anything to undo, compared to the current `text`?
+    pub fn has_undo(&self, text: &str) -> bool {
+        self.undoer.lock().has_undo(&self.undo_state(text))
+    }
+
+    /// Is there anything to redo, compared to the current `text`?
+    pub fn has_redo(&self, text: &str) -> bool {
+        self.undoer.lock().has_redo(&self.undo_state(text))
+    }
+
+    /// Go back to before the latest change of `text`, like pressing Ctrl+Z.
+    ///
+    /// This also restores the selection.
+    /// Returns `false` if there was nothing to undo.
+    ///
+    /// Remember to [`Self::store`] the state afterwards:
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut text = String::new();
+    /// let output = egui::TextEdit::multiline(&mut text).show(ui);
+    /// let mut state = output.state;
+    /// if ui.add_enabled(state.has_undo(&text), egui::Button::new("Undo")).clicked() {
+    ///     state.undo(&mut text);
+    ///     state.store(ui.ctx(), output.response.id);
+    /// }
+    /// # });
+    /// ```
+    pub fn undo(&mut self, text: &mut dyn TextBuffer) -> bool {
+        let undo_state = self.undo_state(text.as_str());
+        let undone = self.undoer.lock().undo(&undo_state).cloned();
+        self.restore(text, undone)
+    }
+
+    /// Redo the latest undone change of `text`, like pressing Ctrl+Y.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    /// Remember to [`Self::store`] the state afterwards.
+    pub fn redo(&mut self, text: &mut dyn TextBuffer) -> bool {
+        let undo_state = self.undo_state(text.as_str());
+        let redone = self.undoer.lock().redo(&undo_state).cloned();
+        self.restore(text, redone)
+    }
+
+    /// What the undoer remembers: the selection and the text.
+    fn undo_state(&self, text: &str) -> (CCursorRange, String) {
+        (
+            self.cursor.char_range().unwrap_or_default(),
+            text.to_owned(),
+        )
+    }
+
+    fn restore(
+        &mut self,
+        text: &mut dyn TextBuffer,
+        state: Option<(CCursorRange, String)>,
+    ) -> bool {
+        if let Some((cursor_range, new_text)) = state {
+            text.replace_with(&new_text);
+            self.cursor.set_char_range(Some(cursor_range));
+            true
+        } else {
+            false
+        }
+    }
+
     #[deprecated = "Use `self.cursor.range` instead"]
     pub fn cursor_range(&mut self, galley: &Galley) -> Option<CursorRange> {
         self.cursor.range(galley)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::CCursor;
+
+    #[test]
+    fn test_undo_and_redo() {
+        let mut state = TextEditState::default();
+        let mut text = String::from("hello");
+        assert!(!state.has_undo(&text));
+
+        state.add_undo_point(&text);
+        text.push_str(" world");
+        state
+            .cursor
+            .set_char_range(Some(CCursorRange::one(CCursor::new(11))));
+        assert!(state.has_undo(&text));
+        assert!(!state.has_redo(&text));
+
+        assert!(state.undo(&mut text));
+        assert_eq!(text, "hello");
+        assert_eq!(state.cursor.char_range(), Some(CCursorRange::default()));
+        assert!(state.has_redo(&text));
+
+        assert!(state.redo(&mut text));
+        assert_eq!(text, "hello world");
+        assert_eq!(
+            state.cursor.char_range(),
+            Some(CCursorRange::one(CCursor::new(11)))
+        );
+        assert!(!state.redo(&mut text));
+    }
+
+    #[test]
+    fn test_undo_settings() {
+        let state = TextEditState::default();
+        for text in ["a", "ab", "abc"] {
+            state.add_undo_point(text);
+        }
+        assert_eq!(state.undoer().num_undos(), 3);
+
+        state.set_undo_settings(UndoSettings {
+            max_undos: 2,
+            ..Default::default()
+        });
+        assert_eq!(state.undo_settings().max_undos, 2);
+        assert_eq!(
+            state.undoer().num_undos(),
+            2,
+            "The oldest undo point is dropped"
+        );
+
+        // Clones share the undoer:
+        let mut clone = state.clone();
+        clone.clear_undoer();
+        assert!(!state.has_undo("abcd"));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::*;
+
+/// An on/off switch with an optional text label, like on a phone.
+///
+/// It works like a [`Checkbox`], but is better suited for settings that take effect immediately.
+/// The thumb slides over when toggled, and the track takes the selection color from [`Visuals`] when on.
+///
+/// Usually you'd use [`Ui::toggle_switch`] instead.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut dark_mode = true;
+/// // These are equivalent:
+/// ui.toggle_switch(&mut dark_mode, "Dark mode");
+/// ui.add(egui::ToggleSwitch::new(&mut dark_mode, "Dark mode"));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ToggleSwitch<'a> {
+    on: &'a mut bool,
+    text: WidgetText,
+}
+
+impl<'a> ToggleSwitch<'a> {
+    pub fn new(on: &'a mut bool, text: impl Into<WidgetText>) -> Self {
+        Self {
+            on,
+            text: text.into(),
+        }
+    }
+
+    pub fn without_text(on: &'a mut bool) -> Self {
+        Self::new(on, WidgetText::default())
+    }
+}
+
+impl<'a> Widget for ToggleSwitch<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let ToggleSwitch { on, text } = self;
+
+        let spacing = &ui.spacing();
+        let switch_size = spacing.interact_size.y * vec2(2.0, 1.0);
+        let icon_spacing = spacing.icon_spacing;
+
+        let (galley, desired_size) = if text.is_empty() {
+            (None, switch_size)
+        } else {
+            let total_extra = vec2(switch_size.x + icon_spacing, 0.0);
+
+            let wrap_width = ui.available_width() - total_extra.x;
+            let galley = text.into_galley(ui, None, wrap_width, TextStyle::Button);
+
+            let desired_size = (total_extra + galley.size()).at_least(switch_size);
+
+            (Some(galley), desired_size)
+        };
+
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        if response.clicked() {
+            *on = !*on;
+            response.mark_changed();
+        }
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::Checkbox,
+                *on,
+                galley.as_ref().map_or("", |x| x.text()),
+            )
+        });
+
+        if ui.is_rect_visible(rect) {
+            let how_on = ui.ctx().animate_bool_responsive(response.id, *on);
+            let visuals = ui.style().interact_selectable(&response, *on);
+
+            let switch_rect = Rect::from_min_size(
+                pos2(rect.left(), rect.center().y - 0.5 * switch_size.y),
+                switch_size,
+            )
+            .expand(visuals.expansion);
+            let radius = 0.5 * switch_rect.height();
+            ui.painter()
+                .rect(switch_rect, radius, visuals.bg_fill, visuals.bg_stroke);
+
+            let thumb_x = lerp(
+                (switch_rect.left() + radius)..=(switch_rect.right() - radius),
+                how_on,
+            );
+            let thumb_center = pos2(thumb_x, switch_rect.center().y);
+            ui.painter().circle(
+                thumb_center,
+                0.75 * radius,
+                visuals.bg_fill,
+                visuals.fg_stroke,
+            );
+
+            if let Some(galley) = galley {
+                let text_pos = pos2(
+                    rect.min.x + switch_size.x + icon_spacing,
+                    rect.center().y - 0.5 * galley.size().y,
+                );
+                ui.painter().galley(text_pos, galley, visuals.text_color());
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{click, run};
+    use crate::*;
+
+    #[test]
+    fn test_click_toggles() {
+        let ctx = Context::default();
+        let mut on = false;
+        let rect = run(&ctx, vec![], |ui| ui.toggle_switch(&mut on, "Switch")).rect;
+
+        let response = run(&ctx, click(rect.center()), |ui| {
+            ui.toggle_switch(&mut on, "Switch")
+        });
+        assert!(on);
+        assert!(response.changed());
+
+        let response = run(&ctx, click(rect.center()), |ui| {
+            ui.toggle_switch(&mut on, "Switch")
+        });
+        assert!(!on);
+        assert!(response.changed());
+
+        let response = run(&ctx, vec![], |ui| ui.toggle_switch(&mut on, "Switch"));
+        assert!(!response.changed());
+    }
+
+    #[test]
+    fn test_size() {
+        let ctx = Context::default();
+        let mut on = false;
+        let switch_size = ctx.style().spacing.interact_size.y * vec2(2.0, 1.0);
+        let without_text = run(&ctx, vec![], |ui| ui.toggle_switch(&mut on, "")).rect;
+        assert_eq!(without_text.size(), switch_size);
+
+        let with_text = run(&ctx, vec![], |ui| ui.toggle_switch(&mut on, "Switch")).rect;
+        assert!(with_text.width() > switch_size.x + ctx.style().spacing.icon_spacing);
+        assert_eq!(with_text.height(), switch_size.y);
+    }
+}
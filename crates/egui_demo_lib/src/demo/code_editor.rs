@@ -5,6 +5,7 @@
 pub struct CodeEditor {
     language: String,
     code: String,
+    breakpoints: std::collections::BTreeSet<usize>,
 }
 
 impl Default for CodeEditor {
@@ -17,6 +18,7 @@ fn main() {\n\
 }\n\
 "
             .into(),
+            breakpoints: Default::default(),
         }
     }
 }
@@ -37,11 +39,15 @@ impl crate::Demo for CodeEditor {
 
 impl crate::View for CodeEditor {
     fn ui(&mut self, ui: &mut egui::Ui) {
-        let Self { language, code } = self;
+        let Self {
+            language,
+            code,
+            breakpoints,
+        } = self;
 
         ui.horizontal(|ui| {
             ui.set_height(0.0);
-            ui.label("An example of syntax highlighting in a TextEdit.");
+            ui.label("An example of a code editor with syntax highlighting. Click a line number to toggle a breakpoint.");
             ui.add(crate::egui_github_link_file!());
         });
 
@@ -75,23 +81,23 @@ impl crate::View for CodeEditor {
             });
         });
 
-        let mut layouter = |ui: &egui::Ui, string: &str, wrap_width: f32| {
-            let mut layout_job =
-                egui_extras::syntax_highlighting::highlight(ui.ctx(), &theme, string, language);
-            layout_job.wrap.max_width = wrap_width;
-            ui.fonts(|f| f.layout_job(layout_job))
-        };
-
         egui::ScrollArea::vertical().show(ui, |ui| {
-            ui.add(
-                egui::TextEdit::multiline(code)
-                    .font(egui::TextStyle::Monospace) // for cursor height
-                    .code_editor()
-                    .desired_rows(10)
-                    .lock_focus(true)
-                    .desired_width(f32::INFINITY)
-                    .layouter(&mut layouter),
-            );
+            let folds = egui_extras::indentation_folds(code);
+            let output = egui_extras::CodeEditor::new(code)
+                .language(language.as_str())
+                .theme(theme)
+                .folds(folds)
+                .markers(|line| {
+                    breakpoints
+                        .contains(&line)
+                        .then_some(egui_extras::GutterMarker::Breakpoint)
+                })
+                .show(ui);
+            if let Some(line) = output.gutter_clicked {
+                if !breakpoints.remove(&line) {
+                    breakpoints.insert(line);
+                }
+            }
         });
     }
 }
@@ -114,6 +114,7 @@ impl Default for Tests {
             Box::<super::tests::ManualLayoutTest>::default(),
             Box::<super::tests::TableTest>::default(),
             Box::<super::tests::WindowResizeTest>::default(),
+            Box::<crate::WidgetStateSheet>::default(),
         ])
     }
 }
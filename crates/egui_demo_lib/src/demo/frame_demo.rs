@@ -16,6 +16,7 @@ impl Default for FrameDemo {
                     blur: 16.0,
                     spread: 0.0,
                     color: egui::Color32::from_black_alpha(180),
+                    falloff: egui::ShadowFalloff::Gaussian,
                 },
                 fill: egui::Color32::from_rgba_unmultiplied(97, 0, 255, 128),
                 stroke: egui::Stroke::new(1.0, egui::Color32::GRAY),
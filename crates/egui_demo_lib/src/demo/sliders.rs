@@ -18,6 +18,8 @@ pub struct Sliders {
     pub value: f64,
     pub trailing_fill: bool,
     pub handle_shape: HandleShape,
+    pub percent: f64,
+    pub snap_to_ticks: bool,
 }
 
 impl Default for Sliders {
@@ -35,6 +37,8 @@ impl Default for Sliders {
             value: 10.0,
             trailing_fill: false,
             handle_shape: HandleShape::Circle,
+            percent: 50.0,
+            snap_to_ticks: false,
         }
     }
 }
@@ -70,6 +74,8 @@ impl crate::View for Sliders {
             value,
             trailing_fill,
             handle_shape,
+            percent,
+            snap_to_ticks,
         } = self;
 
         ui.label("You can click a slider value to edit it with the keyboard.");
@@ -168,6 +174,21 @@ impl crate::View for Sliders {
 
         ui.separator();
 
+        ui.add(
+            Slider::new(percent, 0.0..=100.0)
+                .suffix("%")
+                .text("with tick marks")
+                .ticks(25.0)
+                .show_tick_labels(true)
+                .snap_to_ticks(*snap_to_ticks)
+                .trailing_fill(*trailing_fill)
+                .handle_shape(*handle_shape),
+        );
+        ui.checkbox(snap_to_ticks, "Snap to ticks");
+        ui.label("When enabled, the slider only stops at its tick marks.");
+
+        ui.separator();
+
         ui.horizontal(|ui| {
             ui.label("Slider type:");
             ui.radio_value(integer, true, "i32");
@@ -47,6 +47,7 @@ impl crate::View for TextEditDemo {
 
         let output = egui::TextEdit::multiline(text)
             .hint_text("Type something!")
+            .autocomplete(suggest_words)
             .show(ui);
 
         ui.horizontal(|ui| {
@@ -112,3 +113,29 @@ impl crate::View for TextEditDemo {
         });
     }
 }
+
+/// Suggest words for the autocomplete of the [`egui::TextEdit`].
+fn suggest_words(word: &str) -> Vec<String> {
+    const WORDS: &[&str] = &[
+        "about",
+        "again",
+        "because",
+        "before",
+        "button",
+        "cursor",
+        "egui",
+        "example",
+        "keyboard",
+        "label",
+        "something",
+        "suggestion",
+        "text",
+        "window",
+    ];
+    let word = word.to_lowercase();
+    WORDS
+        .iter()
+        .filter(|candidate| candidate.starts_with(&word))
+        .map(|candidate| (*candidate).to_owned())
+        .collect()
+}
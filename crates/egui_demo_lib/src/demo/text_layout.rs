@@ -3,6 +3,7 @@
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct TextLayoutDemo {
     break_anywhere: bool,
+    unicode_line_breaks: bool,
     max_rows: usize,
     overflow_character: Option<char>,
     extra_letter_spacing_pixels: i32,
@@ -15,6 +16,7 @@ impl Default for TextLayoutDemo {
         Self {
             max_rows: 6,
             break_anywhere: true,
+            unicode_line_breaks: true,
             overflow_character: Some('…'),
             extra_letter_spacing_pixels: 0,
             line_height_pixels: 0,
@@ -43,6 +45,7 @@ impl crate::View for TextLayoutDemo {
     fn ui(&mut self, ui: &mut egui::Ui) {
         let Self {
             break_anywhere,
+            unicode_line_breaks,
             max_rows,
             overflow_character,
             extra_letter_spacing_pixels,
@@ -75,6 +78,13 @@ impl crate::View for TextLayoutDemo {
                 });
                 ui.end_row();
 
+                ui.label("Line-break rules:");
+                ui.add_enabled(
+                    !*break_anywhere,
+                    egui::Checkbox::new(unicode_line_breaks, "Unicode (UAX #14)"),
+                );
+                ui.end_row();
+
                 ui.label("Overflow character:");
                 ui.horizontal(|ui| {
                     ui.selectable_value(overflow_character, None, "None");
@@ -141,6 +151,7 @@ impl crate::View for TextLayoutDemo {
                 job.wrap = egui::text::TextWrapping {
                     max_rows: *max_rows,
                     break_anywhere: *break_anywhere,
+                    unicode_line_breaks: *unicode_line_breaks,
                     overflow_character: *overflow_character,
                     ..Default::default()
                 };
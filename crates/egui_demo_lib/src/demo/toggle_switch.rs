@@ -1,5 +1,8 @@
 //! Source code example of how to create your own widget.
 //! This is meant to be read as a tutorial, hence the plethora of comments.
+//!
+//! egui already comes with a toggle switch, [`egui::ToggleSwitch`].
+//! This is how you could write it yourself.
 
 /// iOS-style toggle switch:
 ///
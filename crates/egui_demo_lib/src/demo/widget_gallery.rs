@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 enum Enum {
     First,
@@ -153,6 +153,10 @@ impl WidgetGallery {
         ui.checkbox(boolean, "Checkbox");
         ui.end_row();
 
+        ui.add(doc_link_label("ToggleSwitch", "toggle_switch"));
+        ui.toggle_switch(boolean, "Toggle switch");
+        ui.end_row();
+
         ui.add(doc_link_label("RadioButton", "radio"));
         ui.horizontal(|ui| {
             ui.radio_value(radio, Enum::First, "First");
@@ -169,6 +173,15 @@ impl WidgetGallery {
         });
         ui.end_row();
 
+        ui.add(doc_link_label("SegmentedControl", "SegmentedControl"));
+        ui.add(
+            egui::SegmentedControl::new(radio)
+                .segment(Enum::First, "First")
+                .segment(Enum::Second, "Second")
+                .segment(Enum::Third, "Third"),
+        );
+        ui.end_row();
+
         ui.add(doc_link_label("ComboBox", "ComboBox"));
 
         egui::ComboBox::from_label("Take your pick")
@@ -188,6 +201,10 @@ impl WidgetGallery {
         ui.add(egui::DragValue::new(scalar).speed(1.0));
         ui.end_row();
 
+        ui.add(doc_link_label("Knob", "Knob"));
+        ui.add(egui::Knob::new(scalar, 0.0..=360.0).suffix("°"));
+        ui.end_row();
+
         ui.add(doc_link_label("ProgressBar", "ProgressBar"));
         let progress = *scalar / 360.0;
         let progress_bar = egui::ProgressBar::new(progress)
@@ -199,6 +216,10 @@ impl WidgetGallery {
             .hovered();
         ui.end_row();
 
+        ui.add(doc_link_label("ProgressCircle", "ProgressCircle"));
+        ui.add(egui::ProgressCircle::new(progress).show_percentage());
+        ui.end_row();
+
         ui.add(doc_link_label("Color picker", "color_edit"));
         ui.color_edit_button_srgba(color);
         ui.end_row();
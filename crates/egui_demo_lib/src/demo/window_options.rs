@@ -9,6 +9,7 @@ pub struct WindowOptions {
     collapsible: bool,
     resizable: bool,
     constrain: bool,
+    detachable: bool,
     scroll2: Vec2b,
     disabled_time: f64,
 
@@ -26,6 +27,7 @@ impl Default for WindowOptions {
             collapsible: true,
             resizable: true,
             constrain: true,
+            detachable: false,
             scroll2: Vec2b::TRUE,
             disabled_time: f64::NEG_INFINITY,
             anchored: false,
@@ -48,6 +50,7 @@ impl crate::Demo for WindowOptions {
             collapsible,
             resizable,
             constrain,
+            detachable,
             scroll2,
             disabled_time,
             anchored,
@@ -65,6 +68,7 @@ impl crate::Demo for WindowOptions {
             .id(egui::Id::new("demo_window_options")) // required since we change the title
             .resizable(resizable)
             .constrain(constrain)
+            .detachable(detachable)
             .collapsible(collapsible)
             .title_bar(title_bar)
             .scroll(scroll2)
@@ -88,6 +92,7 @@ impl crate::View for WindowOptions {
             collapsible,
             resizable,
             constrain,
+            detachable,
             scroll2,
             disabled_time: _,
             anchored,
@@ -108,6 +113,9 @@ impl crate::View for WindowOptions {
                     ui.checkbox(resizable, "resizable");
                     ui.checkbox(constrain, "constrain")
                         .on_hover_text("Constrain window to the screen");
+                    ui.checkbox(detachable, "detachable").on_hover_text(
+                        "Drag the window out of the native window to turn it into its own native window",
+                    );
                     ui.checkbox(&mut scroll2[0], "hscroll");
                     ui.checkbox(&mut scroll2[1], "vscroll");
                 });
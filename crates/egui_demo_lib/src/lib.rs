@@ -14,9 +14,13 @@
 mod demo;
 pub mod easy_mark;
 mod rendering_test;
+#[cfg(test)]
+mod software_renderer;
+mod widget_state_sheet;
 
 pub use demo::{Demo, DemoWindows, View, WidgetGallery};
 pub use rendering_test::ColorTest;
+pub use widget_state_sheet::{WidgetState, WidgetStateSheet};
 
 /// View some Rust code with syntax highlighting and selection.
 pub(crate) fn rust_view_ui(ui: &mut egui::Ui, code: &str) {
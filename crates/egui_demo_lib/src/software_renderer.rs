@@ -0,0 +1,197 @@
+//! A slow but simple renderer for egui on the CPU, used to take screenshots in tests.
+
+use egui::{
+    ahash::HashMap,
+    epaint::{ClippedPrimitive, ImageData, ImageDelta, Mesh, Primitive, Vertex},
+    Color32, ColorImage, Pos2, Rect, TextureId, TexturesDelta,
+};
+
+/// Keeps track of the textures, and paints meshes into a [`ColorImage`].
+///
+/// Like egui's GPU renderers with a gamma framebuffer, colors are blended in gamma space.
+#[derive(Default)]
+pub(crate) struct SoftwareRenderer {
+    textures: HashMap<TextureId, ColorImage>,
+}
+
+impl SoftwareRenderer {
+    pub fn update_textures(&mut self, textures_delta: &TexturesDelta) {
+        for &(id, size, _) in &textures_delta.allocate {
+            self.textures
+                .insert(id, ColorImage::new(size, Color32::TRANSPARENT));
+        }
+        for (id, delta) in &textures_delta.set {
+            self.set_texture(*id, delta);
+        }
+        for id in &textures_delta.free {
+            self.textures.remove(id);
+        }
+    }
+
+    fn set_texture(&mut self, id: TextureId, delta: &ImageDelta) {
+        let image = match &delta.image {
+            ImageData::Color(image) => ColorImage::clone(image),
+            ImageData::Font(image) => {
+                ColorImage::from_pixels(image.size, image.srgba_pixels(None).collect())
+            }
+        };
+
+        match delta.pos {
+            None => {
+                self.textures.insert(id, image);
+            }
+            Some(pos) => {
+                if let Some(texture) = self.textures.get_mut(&id) {
+                    texture.blit(&image, pos);
+                }
+            }
+        }
+    }
+
+    /// Paint the primitives onto `target`, which covers the screen in physical pixels.
+    ///
+    /// Paint callbacks are ignored.
+    pub fn paint(
+        &self,
+        target: &mut ColorImage,
+        pixels_per_point: f32,
+        clipped_primitives: &[ClippedPrimitive],
+    ) {
+        for ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } in clipped_primitives
+        {
+            let clip_rect = Rect::from_min_max(
+                (pixels_per_point * clip_rect.min).round(),
+                (pixels_per_point * clip_rect.max).round(),
+            );
+            match primitive {
+                Primitive::Mesh(mesh) => {
+                    self.paint_mesh(target, pixels_per_point, clip_rect, mesh);
+                }
+                Primitive::PrecomputedMesh(primitive) => {
+                    let mut mesh = Mesh::clone(&primitive.mesh);
+                    mesh.transform(primitive.transform);
+                    self.paint_mesh(target, pixels_per_point, clip_rect, &mesh);
+                }
+                Primitive::Callback(_) => {}
+            }
+        }
+    }
+
+    fn paint_mesh(
+        &self,
+        target: &mut ColorImage,
+        pixels_per_point: f32,
+        clip_rect: Rect,
+        mesh: &Mesh,
+    ) {
+        let Some(texture) = self.textures.get(&mesh.texture_id) else {
+            log::warn!("Missing texture: {:?}", mesh.texture_id);
+            return;
+        };
+        let clip_rect = clip_rect.intersect(Rect::from_min_size(
+            Pos2::ZERO,
+            egui::vec2(target.width() as f32, target.height() as f32),
+        ));
+
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| {
+                let vertex = mesh.vertices[triangle[i] as usize];
+                Vertex {
+                    pos: pixels_per_point * vertex.pos,
+                    ..vertex
+                }
+            });
+            paint_triangle(target, texture, clip_rect, [a, b, c]);
+        }
+    }
+}
+
+fn paint_triangle(
+    target: &mut ColorImage,
+    texture: &ColorImage,
+    clip_rect: Rect,
+    [a, b, c]: [Vertex; 3],
+) {
+    let area = edge(a.pos, b.pos, c.pos);
+    if area == 0.0 {
+        return;
+    }
+
+    let bounds = Rect::from_points(&[a.pos, b.pos, c.pos]).intersect(clip_rect);
+    if !bounds.is_positive() {
+        return;
+    }
+
+    let width = target.width();
+    for y in bounds.min.y.floor() as usize..bounds.max.y.ceil() as usize {
+        for x in bounds.min.x.floor() as usize..bounds.max.x.ceil() as usize {
+            // Sample at the pixel center, like the GPU does:
+            let p = egui::pos2(x as f32 + 0.5, y as f32 + 0.5);
+            if !clip_rect.contains(p) {
+                continue;
+            }
+            let weights = [
+                edge(b.pos, c.pos, p) / area,
+                edge(c.pos, a.pos, p) / area,
+                edge(a.pos, b.pos, p) / area,
+            ];
+            if weights.iter().any(|&w| w < 0.0) {
+                continue;
+            }
+
+            let uv = egui::pos2(
+                weights[0] * a.uv.x + weights[1] * b.uv.x + weights[2] * c.uv.x,
+                weights[0] * a.uv.y + weights[1] * b.uv.y + weights[2] * c.uv.y,
+            );
+            let color = [0, 1, 2, 3].map(|i| {
+                weights[0] * a.color[i] as f32
+                    + weights[1] * b.color[i] as f32
+                    + weights[2] * c.color[i] as f32
+            });
+            let texel = sample_bilinear(texture, uv);
+
+            // Both are premultiplied:
+            let src = [0, 1, 2, 3].map(|i| color[i] * texel[i] / 255.0);
+            let dst = &mut target.pixels[y * width + x];
+            let src_alpha = src[3] / 255.0;
+            *dst = Color32::from_rgba_premultiplied(
+                blend(src[0], dst.r(), src_alpha),
+                blend(src[1], dst.g(), src_alpha),
+                blend(src[2], dst.b(), src_alpha),
+                blend(src[3], dst.a(), src_alpha),
+            );
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a, b, p`.
+fn edge(a: Pos2, b: Pos2, p: Pos2) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+fn blend(src: f32, dst: u8, src_alpha: f32) -> u8 {
+    (src + dst as f32 * (1.0 - src_alpha))
+        .round()
+        .clamp(0.0, 255.0) as u8
+}
+
+/// Sample the premultiplied texture with linear filtering, clamped to the edges.
+fn sample_bilinear(texture: &ColorImage, uv: Pos2) -> [f32; 4] {
+    let [w, h] = texture.size;
+    let x = (uv.x * w as f32 - 0.5).clamp(0.0, (w - 1) as f32);
+    let y = (uv.y * h as f32 - 0.5).clamp(0.0, (h - 1) as f32);
+    let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+    let (x1, y1) = ((x0 + 1).min(w - 1), (y0 + 1).min(h - 1));
+    let (tx, ty) = (x.fract(), y.fract());
+
+    let texel = |x: usize, y: usize| texture.pixels[y * w + x].to_array().map(f32::from);
+    let [c00, c10, c01, c11] = [texel(x0, y0), texel(x1, y0), texel(x0, y1), texel(x1, y1)];
+    [0, 1, 2, 3].map(|i| {
+        let top = egui::lerp(c00[i]..=c10[i], tx);
+        let bottom = egui::lerp(c01[i]..=c11[i], tx);
+        egui::lerp(top..=bottom, ty)
+    })
+}
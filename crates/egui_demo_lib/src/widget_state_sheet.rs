@@ -0,0 +1,302 @@
+use egui::{Ui, Visuals};
+
+/// The interaction states shown for each widget by [`WidgetStateSheet`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WidgetState {
+    Inactive,
+    Hovered,
+    Active,
+    Focused,
+    Disabled,
+}
+
+impl WidgetState {
+    pub const ALL: [Self; 5] = [
+        Self::Inactive,
+        Self::Hovered,
+        Self::Active,
+        Self::Focused,
+        Self::Disabled,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Inactive => "Inactive",
+            Self::Hovered => "Hovered",
+            Self::Active => "Active",
+            Self::Focused => "Focused",
+            Self::Disabled => "Disabled",
+        }
+    }
+}
+
+/// Every built-in widget in every [`WidgetState`], side by side, for one or more themes.
+///
+/// Use it to review a theme. The tests also render it
+/// and compare the image to a golden screenshot to catch visual regressions
+/// across the whole widget set.
+///
+/// Only one widget can really be hovered or focused at a time,
+/// so the states are simulated by giving each widget the [`egui::style::WidgetVisuals`]
+/// egui would use for it in that state. This means the whole sheet is deterministic,
+/// and can be painted in a single frame.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WidgetStateSheet {
+    boolean: bool,
+    scalar: f32,
+    string: String,
+}
+
+impl Default for WidgetStateSheet {
+    fn default() -> Self {
+        Self {
+            boolean: true,
+            scalar: 42.0,
+            string: "Text".to_owned(),
+        }
+    }
+}
+
+impl crate::Demo for WidgetStateSheet {
+    fn name(&self) -> &'static str {
+        "Widget States"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                use crate::View as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl crate::View for WidgetStateSheet {
+    fn ui(&mut self, ui: &mut Ui) {
+        self.sheet_ui(ui, "widget_state_sheet");
+        ui.vertical_centered(|ui| {
+            ui.add(crate::egui_github_link_file!());
+        });
+    }
+}
+
+impl WidgetStateSheet {
+    /// Show the sheet once for each theme, side by side: a contact sheet.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut sheet = egui_demo_lib::WidgetStateSheet::default();
+    /// sheet.contact_sheet_ui(
+    ///     ui,
+    ///     &[("Dark", egui::Visuals::dark()), ("Light", egui::Visuals::light())],
+    /// );
+    /// # });
+    /// ```
+    pub fn contact_sheet_ui(&mut self, ui: &mut Ui, themes: &[(&str, Visuals)]) {
+        ui.horizontal_top(|ui| {
+            for (name, visuals) in themes {
+                ui.push_id(name, |ui| {
+                    ui.style_mut().visuals = visuals.clone();
+                    egui::Frame::central_panel(ui.style()).show(ui, |ui| {
+                        ui.vertical(|ui| {
+                            ui.heading(*name);
+                            self.sheet_ui(ui, name);
+                        });
+                    });
+                });
+            }
+        });
+    }
+
+    /// Render [`Self::contact_sheet_ui`] to an image, cropped to the sheet.
+    ///
+    /// This uses a simple software renderer, so no GPU is needed.
+    /// Paint callbacks are not rendered.
+    #[cfg(test)]
+    fn contact_sheet_image(
+        &mut self,
+        themes: &[(&str, Visuals)],
+        pixels_per_point: f32,
+    ) -> egui::ColorImage {
+        let ctx = egui::Context::default();
+        ctx.set_pixels_per_point(pixels_per_point);
+        let screen_size = egui::vec2(400.0 + 800.0 * themes.len() as f32, 1000.0);
+        let raw_input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, screen_size)),
+            ..Default::default()
+        };
+
+        let mut renderer = crate::software_renderer::SoftwareRenderer::default();
+        let mut sheet_rect = egui::Rect::NOTHING;
+        let mut full_output = Default::default();
+        // The grid needs a few frames to settle on its column widths:
+        for _ in 0..3 {
+            full_output = ctx.run(raw_input.clone(), |ctx| {
+                sheet_rect = egui::Area::new(egui::Id::new("contact_sheet"))
+                    .fixed_pos(egui::Pos2::ZERO)
+                    .show(ctx, |ui| self.contact_sheet_ui(ui, themes))
+                    .response
+                    .rect;
+            });
+            renderer.update_textures(&full_output.textures_delta);
+        }
+
+        let pixels_per_point = full_output.pixels_per_point;
+        let clipped_primitives = ctx.tessellate(full_output.shapes, pixels_per_point);
+        let physical_size = (pixels_per_point * screen_size).round();
+        let [width, height] = [physical_size.x, physical_size.y];
+        let mut image = egui::ColorImage::new(
+            [width as usize, height as usize],
+            egui::Color32::TRANSPARENT,
+        );
+        renderer.paint(&mut image, pixels_per_point, &clipped_primitives);
+
+        let sheet_size = (pixels_per_point * sheet_rect.size())
+            .ceil()
+            .min(egui::vec2(width, height));
+        image.crop([0, 0], [sheet_size.x as usize, sheet_size.y as usize])
+    }
+
+    /// Show the sheet using the current theme of `ui`.
+    fn sheet_ui(&mut self, ui: &mut Ui, id_source: &str) {
+        egui::Grid::new(id_source)
+            .num_columns(1 + WidgetState::ALL.len())
+            .spacing([16.0, 8.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("");
+                for state in WidgetState::ALL {
+                    ui.strong(state.name());
+                }
+                ui.end_row();
+
+                self.row(ui, "Button", |_, ui| {
+                    let _ = ui.button("Button");
+                });
+                self.row(ui, "Selected button", |_, ui| {
+                    ui.add(egui::Button::new("Selected").selected(true));
+                });
+                self.row(ui, "Checkbox", |sheet, ui| {
+                    ui.checkbox(&mut sheet.boolean, "Checkbox");
+                });
+                self.row(ui, "Radio button", |sheet, ui| {
+                    let _ = ui.radio(sheet.boolean, "Radio");
+                });
+                self.row(ui, "Selectable label", |_, ui| {
+                    let _ = ui.selectable_label(false, "Selectable");
+                });
+                self.row(ui, "Hyperlink", |_, ui| {
+                    ui.hyperlink_to("Hyperlink", "https://www.egui.rs/");
+                });
+                self.row(ui, "Slider", |sheet, ui| {
+                    ui.add(egui::Slider::new(&mut sheet.scalar, 0.0..=100.0));
+                });
+                self.row(ui, "Drag value", |sheet, ui| {
+                    ui.add(egui::DragValue::new(&mut sheet.scalar));
+                });
+                self.row(ui, "Text edit", |sheet, ui| {
+                    ui.add(egui::TextEdit::singleline(&mut sheet.string).desired_width(80.0));
+                });
+                self.row(ui, "Combo box", |_, ui| {
+                    egui::ComboBox::from_id_source("combo_box")
+                        .selected_text("Combo box")
+                        .show_ui(ui, |_ui| {});
+                });
+                self.row(ui, "Collapsing header", |_, ui| {
+                    ui.collapsing("Collapsing", |_ui| {});
+                });
+                self.row(ui, "Progress bar", |_, ui| {
+                    ui.add(egui::ProgressBar::new(0.5).desired_width(80.0));
+                });
+            });
+    }
+
+    fn row(&mut self, ui: &mut Ui, name: &str, mut add_widget: impl FnMut(&mut Self, &mut Ui)) {
+        ui.label(name);
+        for state in WidgetState::ALL {
+            ui.push_id((name, state), |ui| {
+                simulate_state(ui, state);
+                add_widget(self, ui);
+            });
+        }
+        ui.end_row();
+    }
+}
+
+/// Make the widgets added to `ui` look like they are in the given state.
+fn simulate_state(ui: &mut Ui, state: WidgetState) {
+    let visuals = ui.visuals_mut();
+    match state {
+        WidgetState::Inactive => {}
+        WidgetState::Hovered => {
+            visuals.widgets.inactive = visuals.widgets.hovered;
+        }
+        WidgetState::Active => {
+            visuals.widgets.inactive = visuals.widgets.active;
+        }
+        WidgetState::Focused => {
+            // Focused widgets use the active visuals, and many outline themselves with the selection:
+            visuals.widgets.inactive = visuals.widgets.active;
+            visuals.widgets.inactive.bg_stroke = visuals.selection.stroke;
+        }
+        WidgetState::Disabled => {
+            ui.disable();
+        }
+    }
+}
+
+/// Compares the contact sheet with the golden screenshot in `tests/snapshots`.
+///
+/// Run with `UPDATE_SNAPSHOTS=1` to accept a changed look.
+#[test]
+fn test_widget_state_sheet_matches_golden() {
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/snapshots/widget_state_sheet.png"
+    );
+
+    let sheet = WidgetStateSheet::default().contact_sheet_image(
+        &[("Dark", Visuals::dark()), ("Light", Visuals::light())],
+        1.0,
+    );
+    let [width, height] = sheet.size;
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(std::path::Path::new(path).parent().unwrap()).unwrap();
+        image::save_buffer(
+            path,
+            &sheet
+                .pixels
+                .iter()
+                .flat_map(|pixel| pixel.to_array())
+                .collect::<Vec<u8>>(),
+            width as u32,
+            height as u32,
+            image::ExtendedColorType::Rgba8,
+        )
+        .unwrap();
+        return;
+    }
+
+    let golden = image::open(path)
+        .unwrap_or_else(|err| panic!("Failed to load {path}: {err}. Run with UPDATE_SNAPSHOTS=1"))
+        .into_rgba8();
+    assert_eq!(
+        [golden.width() as usize, golden.height() as usize],
+        sheet.size,
+        "The size of the widget state sheet changed. Run with UPDATE_SNAPSHOTS=1 to accept it"
+    );
+
+    // Allow for small differences in rounding:
+    let num_different = golden
+        .pixels()
+        .zip(sheet.pixels.iter())
+        .filter(|(golden, pixel)| (0..4).any(|i| golden.0[i].abs_diff(pixel.to_array()[i]) > 2))
+        .count();
+    assert!(
+        num_different <= width * height / 1000,
+        "{num_different} pixels differ from {path}. Run with UPDATE_SNAPSHOTS=1 to accept the change"
+    );
+}
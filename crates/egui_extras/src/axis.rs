@@ -0,0 +1,356 @@
+//! Mapping between data values and screen coordinates, for painting your own charts.
+
+use std::ops::RangeInclusive;
+
+use egui::{Context, Id, Rangef, Rect};
+
+/// A linear mapping between a range of data values and a range of screen coordinates (in points),
+/// for one axis of a chart you paint yourself.
+///
+/// It also finds "nice" values to put tick marks and grid lines at, and formats labels for them.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::LinearMap;
+///
+/// let (response, painter) = ui.allocate_painter(egui::vec2(200.0, 100.0), egui::Sense::hover());
+/// let rect = response.rect;
+/// let x_axis = LinearMap::x_axis(0.0..=10.0, rect);
+/// let y_axis = LinearMap::y_axis(-1.0..=1.0, rect);
+///
+/// for tick in x_axis.ticks(50.0) {
+///     painter.vline(tick.screen_pos, rect.y_range(), ui.visuals().widgets.noninteractive.bg_stroke);
+/// }
+///
+/// let points: Vec<egui::Pos2> = (0..=100)
+///     .map(|i| {
+///         let x = i as f64 / 10.0;
+///         egui::pos2(x_axis.screen_from_data(x), y_axis.screen_from_data(x.sin()))
+///     })
+///     .collect();
+/// painter.add(egui::Shape::line(points, (1.5, ui.visuals().text_color())));
+/// # });
+/// ```
+///
+/// Use [`Self::load`] and [`Self::store`] to have several charts share
+/// (and pan and zoom) the same data range.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LinearMap {
+    data: RangeInclusive<f64>,
+    screen: Rangef,
+}
+
+impl LinearMap {
+    /// Map `data` onto `screen`.
+    ///
+    /// The start of `data` is mapped to `screen.min`, and the end to `screen.max`.
+    /// `screen.min` may be larger than `screen.max`, e.g. for a y axis pointing up.
+    pub fn new(data: RangeInclusive<f64>, screen: impl Into<Rangef>) -> Self {
+        Self {
+            data,
+            screen: screen.into(),
+        }
+    }
+
+    /// Map `data` onto the width of `rect`, from left to right.
+    pub fn x_axis(data: RangeInclusive<f64>, rect: Rect) -> Self {
+        Self::new(data, rect.x_range())
+    }
+
+    /// Map `data` onto the height of `rect`, from the bottom up.
+    pub fn y_axis(data: RangeInclusive<f64>, rect: Rect) -> Self {
+        Self::new(data, Rangef::new(rect.bottom(), rect.top()))
+    }
+
+    /// The data values at the start and end of the screen range.
+    #[inline]
+    pub fn data(&self) -> RangeInclusive<f64> {
+        self.data.clone()
+    }
+
+    /// The screen coordinates (in points) of the start and end of the data range.
+    #[inline]
+    pub fn screen(&self) -> Rangef {
+        self.screen
+    }
+
+    /// Change the data range, keeping the screen range.
+    #[inline]
+    pub fn set_data(&mut self, data: RangeInclusive<f64>) {
+        self.data = data;
+    }
+
+    /// Change the screen range, keeping the data range.
+    #[inline]
+    pub fn set_screen(&mut self, screen: impl Into<Rangef>) {
+        self.screen = screen.into();
+    }
+
+    /// How much the data value changes per screen point. Always positive.
+    pub fn data_per_point(&self) -> f64 {
+        let data_span = self.data.end() - self.data.start();
+        let screen_span = (self.screen.max - self.screen.min) as f64;
+        if screen_span == 0.0 {
+            0.0
+        } else {
+            (data_span / screen_span).abs()
+        }
+    }
+
+    /// The screen coordinate (in points) of a data value.
+    pub fn screen_from_data(&self, value: f64) -> f32 {
+        let data_span = self.data.end() - self.data.start();
+        if data_span == 0.0 {
+            return self.screen.center();
+        }
+        let t = (value - self.data.start()) / data_span;
+        (self.screen.min as f64 + t * (self.screen.max - self.screen.min) as f64) as f32
+    }
+
+    /// The data value at a screen coordinate (in points).
+    pub fn data_from_screen(&self, pos: f32) -> f64 {
+        let screen_span = (self.screen.max - self.screen.min) as f64;
+        if screen_span == 0.0 {
+            return (self.data.start() + self.data.end()) / 2.0;
+        }
+        let t = (pos - self.screen.min) as f64 / screen_span;
+        self.data.start() + t * (self.data.end() - self.data.start())
+    }
+
+    /// Move the data range so that the data follows a drag of `delta` screen points.
+    pub fn pan(&mut self, delta: f32) {
+        let data_delta =
+            self.data_from_screen(self.screen.min) - self.data_from_screen(self.screen.min + delta);
+        self.data = self.data.start() + data_delta..=self.data.end() + data_delta;
+    }
+
+    /// Zoom in (`factor > 1`) or out (`factor < 1`) around the screen coordinate `center`,
+    /// which keeps showing the same data value.
+    pub fn zoom(&mut self, factor: f32, center: f32) {
+        if factor <= 0.0 || !factor.is_finite() {
+            return;
+        }
+        let center = self.data_from_screen(center);
+        let factor = factor as f64;
+        self.data = center + (self.data.start() - center) / factor
+            ..=center + (self.data.end() - center) / factor;
+    }
+
+    /// Nice values for tick marks within the data range, at least `min_spacing` points apart.
+    ///
+    /// The step between ticks is 1, 2 or 5 times a power of ten.
+    /// Ticks at multiples of ten times the step have [`AxisTick::is_major`] set.
+    pub fn ticks(&self, min_spacing: f32) -> Vec<AxisTick> {
+        let Some(step_size) = nice_step_size(self.data_per_point() * min_spacing.max(0.0) as f64)
+        else {
+            return vec![];
+        };
+
+        // In `f64`, since these can be far outside the range of integers:
+        let (min, max) = min_max(*self.data.start(), *self.data.end());
+        let first = (min / step_size).ceil();
+        let last = (max / step_size).floor();
+        if !(first <= last && last - first <= 10_000.0) {
+            return vec![]; // Nothing to show, or a degenerate range
+        }
+
+        (0..=(last - first) as usize)
+            .map(|i| {
+                let multiple = first + i as f64;
+                let value = multiple * step_size;
+                AxisTick {
+                    value,
+                    screen_pos: self.screen_from_data(value),
+                    step_size,
+                    is_major: multiple.rem_euclid(10.0) == 0.0,
+                }
+            })
+            .collect()
+    }
+
+    /// Load the data range shared by the link group `id`,
+    /// or use `default_data` if nothing has been stored for it yet.
+    ///
+    /// Call [`Self::store`] after panning or zooming, so that all axes in the group follow along.
+    /// Each axis keeps its own screen range.
+    pub fn load(
+        ctx: &Context,
+        id: Id,
+        default_data: RangeInclusive<f64>,
+        screen: impl Into<Rangef>,
+    ) -> Self {
+        let data = ctx
+            .data(|data| data.get_temp::<LinkedAxisData>(id))
+            .map_or(default_data, |linked| linked.0);
+        Self::new(data, screen)
+    }
+
+    /// Share the data range of this axis with the link group `id`.
+    ///
+    /// See [`Self::load`].
+    pub fn store(&self, ctx: &Context, id: Id) {
+        ctx.data_mut(|data| data.insert_temp(id, LinkedAxisData(self.data())));
+    }
+}
+
+/// The data range stored for a link group of [`LinearMap`]s.
+#[derive(Clone)]
+struct LinkedAxisData(RangeInclusive<f64>);
+
+/// A tick mark on an axis, as returned by [`LinearMap::ticks`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AxisTick {
+    /// The data value of the tick.
+    pub value: f64,
+
+    /// The screen coordinate (in points) of the tick.
+    pub screen_pos: f32,
+
+    /// The data distance to the next tick.
+    pub step_size: f64,
+
+    /// Is this tick at a multiple of ten steps?
+    ///
+    /// You may want to paint these with a stronger line, or only label these.
+    pub is_major: bool,
+}
+
+impl AxisTick {
+    /// A label for the tick, with just enough decimals to tell it apart from its neighbors.
+    pub fn label(&self) -> String {
+        format_tick_value(self.value, self.step_size)
+    }
+}
+
+/// Format a tick value with just enough decimals to tell apart values `step_size` apart.
+///
+/// ```
+/// use egui_extras::format_tick_value;
+/// assert_eq!(format_tick_value(20.0, 5.0), "20");
+/// assert_eq!(format_tick_value(0.25, 0.05), "0.25");
+/// assert_eq!(format_tick_value(-0.0, 0.1), "0.0");
+/// ```
+pub fn format_tick_value(value: f64, step_size: f64) -> String {
+    let num_decimals = if 0.0 < step_size && step_size < 1.0 {
+        (-step_size.log10().floor()) as usize
+    } else {
+        0
+    };
+    // Snap away rounding errors, and avoid showing "-0":
+    let value = if value.abs() < step_size * 1e-6 {
+        0.0
+    } else {
+        value
+    };
+    format!("{value:.num_decimals$}")
+}
+
+/// The smallest step of 1, 2 or 5 times a power of ten that is at least `min_step`.
+fn nice_step_size(min_step: f64) -> Option<f64> {
+    if !min_step.is_finite() || min_step <= 0.0 {
+        return None;
+    }
+    let power_of_ten = 10.0_f64.powf(min_step.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|multiple| multiple * power_of_ten)
+        .find(|&step| min_step <= step)
+}
+
+fn min_max(a: f64, b: f64) -> (f64, f64) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_screen_from_data() {
+        let rect = Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(100.0, 100.0));
+
+        let x_axis = LinearMap::x_axis(0.0..=10.0, rect);
+        assert_eq!(x_axis.screen_from_data(0.0), 0.0);
+        assert_eq!(x_axis.screen_from_data(5.0), 50.0);
+        assert_eq!(x_axis.screen_from_data(-10.0), -100.0);
+        assert_eq!(x_axis.data_from_screen(50.0), 5.0);
+        assert_eq!(x_axis.data_per_point(), 0.1);
+
+        // Pointing up:
+        let y_axis = LinearMap::y_axis(0.0..=10.0, rect);
+        assert_eq!(y_axis.screen_from_data(0.0), 100.0);
+        assert_eq!(y_axis.screen_from_data(10.0), 0.0);
+        assert_eq!(y_axis.data_from_screen(0.0), 10.0);
+        assert_eq!(y_axis.data_per_point(), 0.1);
+
+        // Empty ranges map to the middle:
+        let empty_data = LinearMap::new(3.0..=3.0, Rangef::new(0.0, 100.0));
+        assert_eq!(empty_data.screen_from_data(3.0), 50.0);
+        let empty_screen = LinearMap::new(0.0..=10.0, Rangef::new(20.0, 20.0));
+        assert_eq!(empty_screen.data_from_screen(20.0), 5.0);
+        assert_eq!(empty_screen.data_per_point(), 0.0);
+    }
+
+    #[test]
+    fn test_ticks() {
+        let axis = LinearMap::new(0.0..=10.0, Rangef::new(0.0, 100.0));
+        let ticks = axis.ticks(15.0);
+        let values: Vec<f64> = ticks.iter().map(|tick| tick.value).collect();
+        assert_eq!(values, vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(ticks[1].screen_pos, 20.0);
+        assert_eq!(ticks[1].step_size, 2.0);
+        assert_eq!(ticks[1].label(), "2");
+        let major: Vec<f64> = ticks
+            .iter()
+            .filter(|tick| tick.is_major)
+            .map(|tick| tick.value)
+            .collect();
+        assert_eq!(major, vec![0.0]);
+
+        let axis = LinearMap::new(-0.5..=0.5, Rangef::new(0.0, 100.0));
+        let values: Vec<f64> = axis.ticks(15.0).iter().map(|tick| tick.value).collect();
+        assert_eq!(values, vec![-0.4, -0.2, 0.0, 0.2, 0.4]);
+
+        // Degenerate spacings and ranges:
+        let axis = LinearMap::new(-1.0..=1.0, Rangef::new(0.0, 100.0));
+        assert!(axis.ticks(0.0).is_empty());
+        assert!(axis.ticks(1e-30).is_empty());
+        assert!(axis.ticks(f32::INFINITY).is_empty());
+        let huge = LinearMap::new(-1e300..=1e300, Rangef::new(0.0, 100.0));
+        assert!(!huge.ticks(10.0).is_empty());
+        let empty = LinearMap::new(1.0..=1.0, Rangef::new(0.0, 100.0));
+        assert!(empty.ticks(10.0).is_empty());
+    }
+
+    #[test]
+    fn test_pan() {
+        let mut x_axis = LinearMap::new(0.0..=10.0, Rangef::new(0.0, 100.0));
+        x_axis.pan(10.0); // Drag to the right
+        assert_eq!(x_axis.data(), -1.0..=9.0);
+
+        let mut y_axis = LinearMap::new(0.0..=10.0, Rangef::new(100.0, 0.0));
+        y_axis.pan(10.0); // Drag down
+        assert_eq!(y_axis.data(), 1.0..=11.0);
+    }
+
+    #[test]
+    fn test_zoom() {
+        let mut axis = LinearMap::new(0.0..=10.0, Rangef::new(0.0, 100.0));
+        axis.zoom(2.0, 50.0);
+        assert_eq!(axis.data(), 2.5..=7.5);
+        axis.zoom(0.5, 50.0);
+        assert_eq!(axis.data(), 0.0..=10.0);
+        axis.zoom(2.0, 0.0);
+        assert_eq!(axis.data(), 0.0..=5.0);
+
+        for factor in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            axis.zoom(factor, 50.0);
+            assert_eq!(axis.data(), 0.0..=5.0);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::ops::Range;
+
+use egui::{Button, Response, RichText, TextStyle, TextWrapMode, Ui, WidgetText};
+
+/// The response from [`Breadcrumbs::show`].
+pub struct BreadcrumbsResponse {
+    /// The response of the whole row.
+    pub response: Response,
+
+    /// The index of the segment the user clicked, if any.
+    ///
+    /// This can also be a segment that was clicked in the overflow menu.
+    pub clicked: Option<usize>,
+}
+
+/// A path of clickable segments, e.g. the folders leading to the current one.
+///
+/// When the segments don't fit, the ones in the middle are replaced by "…",
+/// which opens a menu with the hidden segments.
+/// The first and the last segment are always shown, and the last one is truncated if needed.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut path = vec!["home", "user", "documents"];
+/// let response = egui_extras::Breadcrumbs::new(path.iter().copied()).show(ui);
+/// if let Some(index) = response.clicked {
+///     path.truncate(index + 1);
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Breadcrumbs {
+    segments: Vec<WidgetText>,
+    separator: WidgetText,
+    max_width: Option<f32>,
+}
+
+impl Breadcrumbs {
+    pub fn new(segments: impl IntoIterator<Item = impl Into<WidgetText>>) -> Self {
+        Self {
+            segments: segments.into_iter().map(Into::into).collect(),
+            separator: RichText::new("›").weak().into(),
+            max_width: None,
+        }
+    }
+
+    /// Shown between the segments. Default: a weak "›".
+    #[inline]
+    pub fn separator(mut self, separator: impl Into<WidgetText>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Hide segments if they don't fit in this width. Default: the available width.
+    #[inline]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> BreadcrumbsResponse {
+        let Self {
+            segments,
+            separator,
+            max_width,
+        } = self;
+
+        let max_width = max_width.unwrap_or_else(|| ui.available_width());
+        let spacing = ui.spacing().item_spacing.x;
+        let padding = ui.spacing().button_padding.x;
+        let text_width = |ui: &Ui, text: &WidgetText| {
+            text.clone()
+                .into_galley(
+                    ui,
+                    Some(TextWrapMode::Extend),
+                    f32::INFINITY,
+                    TextStyle::Button,
+                )
+                .size()
+                .x
+        };
+        let widths: Vec<f32> = segments
+            .iter()
+            .map(|segment| text_width(ui, segment) + 2.0 * padding + spacing)
+            .collect();
+        let separator_width = text_width(ui, &separator) + spacing;
+        let ellipsis_width = text_width(ui, &"…".into()) + 2.0 * padding + spacing;
+        let hidden = hidden_segments(&widths, separator_width, ellipsis_width, max_width);
+
+        let mut clicked = None;
+        let response = ui
+            .horizontal(|ui| {
+                ui.set_max_width(max_width);
+
+                for (index, segment) in segments.iter().enumerate() {
+                    if hidden.contains(&index) {
+                        if index == hidden.start {
+                            ui.menu_button("…", |ui| {
+                                for index in hidden.clone() {
+                                    if ui.button(segments[index].clone()).clicked() {
+                                        clicked = Some(index);
+                                        ui.close_menu();
+                                    }
+                                }
+                            })
+                            .response
+                            .on_hover_text("Show hidden segments");
+                            ui.label(separator.clone());
+                        }
+                        continue;
+                    }
+
+                    let is_last = index + 1 == segments.len();
+                    let mut button = Button::new(segment.clone()).frame(false);
+                    if is_last {
+                        button = button.truncate();
+                    }
+                    if ui.add(button).clicked() {
+                        clicked = Some(index);
+                    }
+                    if !is_last {
+                        ui.label(separator.clone());
+                    }
+                }
+            })
+            .response;
+
+        BreadcrumbsResponse { response, clicked }
+    }
+}
+
+/// Which segments to hide, so that the rest fits in `available` width.
+///
+/// The widths include the spacing after each segment and separator.
+/// Keeps the first and last segment, and as many segments before the last one as fit.
+fn hidden_segments(widths: &[f32], separator: f32, ellipsis: f32, available: f32) -> Range<usize> {
+    let total: f32 = widths.iter().sum::<f32>() + separator * widths.len().saturating_sub(1) as f32;
+    if total <= available || widths.len() <= 2 {
+        return 1..1;
+    }
+
+    let last = widths.len() - 1;
+    let mut remaining = available - widths[0] - ellipsis - 2.0 * separator - widths[last];
+    let mut first_shown = last;
+    while 1 < first_shown && widths[first_shown - 1] + separator <= remaining {
+        first_shown -= 1;
+        remaining -= widths[first_shown] + separator;
+    }
+    1..first_shown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hidden_segments() {
+        let widths = [10.0, 10.0, 10.0, 10.0, 10.0];
+
+        // Everything fits:
+        assert!(hidden_segments(&widths, 2.0, 5.0, 100.0).is_empty());
+        assert!(hidden_segments(&widths, 2.0, 5.0, 58.0).is_empty());
+
+        // first + … + last = 10 + 5 + 10 + 2 * 2, then 12 for each extra segment:
+        assert_eq!(hidden_segments(&widths, 2.0, 5.0, 57.0), 1..2);
+        assert_eq!(hidden_segments(&widths, 2.0, 5.0, 41.0), 1..3);
+        assert_eq!(hidden_segments(&widths, 2.0, 5.0, 40.0), 1..4);
+
+        // Nothing fits, so hide all the middle:
+        assert_eq!(hidden_segments(&widths, 2.0, 5.0, 10.0), 1..4);
+
+        // Never hide the first or last:
+        assert!(hidden_segments(&[50.0, 50.0], 2.0, 5.0, 10.0).is_empty());
+    }
+}
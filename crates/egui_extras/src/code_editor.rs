@@ -0,0 +1,831 @@
+use std::{collections::BTreeSet, ops::Range};
+
+use egui::{
+    text::LayoutJob, text_edit::TextEditState, Align2, Color32, Frame, Id, Rect, Response, Sense,
+    Shape, TextEdit, TextStyle, Ui, ValidationSeverity, Widget,
+};
+
+use crate::syntax_highlighting::{highlight, CodeTheme};
+
+/// An icon shown in the gutter of a [`CodeEditor`], next to the line number.
+///
+/// See [`CodeEditor::markers`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GutterMarker {
+    /// A red dot.
+    Breakpoint,
+
+    /// An error or warning, with a message shown when hovering the icon.
+    Diagnostic {
+        severity: ValidationSeverity,
+        message: String,
+    },
+
+    /// Any icon, with an optional message shown when hovering it.
+    Custom {
+        icon: String,
+        color: Color32,
+        message: String,
+    },
+}
+
+impl GutterMarker {
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::Diagnostic {
+            severity: ValidationSeverity::Error,
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::Diagnostic {
+            severity: ValidationSeverity::Warning,
+            message: message.into(),
+        }
+    }
+
+    fn icon(&self) -> &str {
+        match self {
+            Self::Breakpoint => "⏺",
+            Self::Diagnostic { severity, .. } => severity.icon(),
+            Self::Custom { icon, .. } => icon,
+        }
+    }
+
+    fn color(&self, visuals: &egui::Visuals) -> Color32 {
+        match self {
+            Self::Breakpoint => visuals.error_fg_color,
+            Self::Diagnostic { severity, .. } => severity.color(visuals),
+            Self::Custom { color, .. } => *color,
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            Self::Breakpoint => "",
+            Self::Diagnostic { message, .. } | Self::Custom { message, .. } => message,
+        }
+    }
+}
+
+/// The output from [`CodeEditor::show`].
+pub struct CodeEditorOutput {
+    /// The response of the [`TextEdit`].
+    pub response: Response,
+
+    /// The line (starting at zero) the text cursor is on, if the editor has focus.
+    pub cursor_line: Option<usize>,
+
+    /// The line (starting at zero) whose line number or marker was clicked,
+    /// e.g. to toggle a breakpoint.
+    pub gutter_clicked: Option<usize>,
+}
+
+/// Which folds are closed. Stored in temporary memory.
+#[derive(Clone, Default)]
+struct CodeEditorState {
+    /// The first line of each closed fold.
+    folded: BTreeSet<usize>,
+
+    /// The lines that were hidden last frame, moved along with the edits made since.
+    ///
+    /// The undo history of the [`TextEdit`] is of the visible text,
+    /// so it is cleared when other lines are hidden.
+    hidden: Vec<Range<usize>>,
+}
+
+/// A [`TextEdit`] for code, with line numbers, a highlighted current line,
+/// markers like breakpoints and errors, and folding.
+///
+/// Folding regions are ranges of lines, where the first line stays visible
+/// and the rest are hidden when the region is folded.
+/// Click the triangle in the gutter to fold or unfold.
+/// You can compute the regions with [`indentation_folds`], or with your own parser.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut code = String::new();
+/// # let mut breakpoints = std::collections::BTreeSet::<usize>::new();
+/// let folds = egui_extras::indentation_folds(&code);
+/// let output = egui_extras::CodeEditor::new(&mut code)
+///     .language("rs")
+///     .folds(folds)
+///     .markers(|line| {
+///         breakpoints
+///             .contains(&line)
+///             .then_some(egui_extras::GutterMarker::Breakpoint)
+///     })
+///     .show(ui);
+/// if let Some(line) = output.gutter_clicked {
+///     if !breakpoints.remove(&line) {
+///         breakpoints.insert(line);
+///     }
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct CodeEditor<'a> {
+    code: &'a mut String,
+    id_source: Option<Id>,
+    language: Option<String>,
+    theme: Option<CodeTheme>,
+    desired_rows: usize,
+    line_numbers: bool,
+    highlight_current_line: bool,
+    markers: Option<Box<dyn FnMut(usize) -> Option<GutterMarker> + 'a>>,
+    folds: Vec<Range<usize>>,
+}
+
+impl<'a> CodeEditor<'a> {
+    pub fn new(code: &'a mut String) -> Self {
+        Self {
+            code,
+            id_source: None,
+            language: None,
+            theme: None,
+            desired_rows: 10,
+            line_numbers: true,
+            highlight_current_line: true,
+            markers: None,
+            folds: Vec::new(),
+        }
+    }
+
+    /// Must be set if multiple [`CodeEditor`]s are in the same [`Ui`].
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Turn on syntax highlighting for this language, e.g. `"rs"`.
+    ///
+    /// See [`highlight`] for which languages are supported.
+    #[inline]
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// The theme of the syntax highlighting. Default: [`CodeTheme::from_memory`].
+    #[inline]
+    pub fn theme(mut self, theme: CodeTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Default: 10.
+    #[inline]
+    pub fn desired_rows(mut self, desired_rows: usize) -> Self {
+        self.desired_rows = desired_rows;
+        self
+    }
+
+    /// Show line numbers in the gutter. Default: `true`.
+    #[inline]
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Highlight the line with the text cursor. Default: `true`.
+    #[inline]
+    pub fn highlight_current_line(mut self, highlight_current_line: bool) -> Self {
+        self.highlight_current_line = highlight_current_line;
+        self
+    }
+
+    /// Show markers like breakpoints or errors in the gutter.
+    ///
+    /// The function is called with each visible line (starting at zero).
+    /// Use [`CodeEditorOutput::gutter_clicked`] to find out if the user clicked next to a line.
+    #[inline]
+    pub fn markers(mut self, markers: impl FnMut(usize) -> Option<GutterMarker> + 'a) -> Self {
+        self.markers = Some(Box::new(markers));
+        self
+    }
+
+    /// The regions of lines that can be folded, e.g. from [`indentation_folds`].
+    ///
+    /// The first line of a region stays visible when it is folded.
+    /// Regions with less than two lines are ignored.
+    #[inline]
+    pub fn folds(mut self, folds: impl IntoIterator<Item = Range<usize>>) -> Self {
+        self.folds = folds
+            .into_iter()
+            .filter(|fold| fold.start + 1 < fold.end)
+            .collect();
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> CodeEditorOutput {
+        let Self {
+            code,
+            id_source,
+            language,
+            theme,
+            desired_rows,
+            line_numbers,
+            highlight_current_line,
+            mut markers,
+            folds,
+        } = self;
+
+        let id = id_source.map_or_else(
+            || ui.next_auto_id().with("code_editor"),
+            |id_source| ui.make_persistent_id(id_source),
+        );
+        let mut state: CodeEditorState = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+        state
+            .folded
+            .retain(|line| folds.iter().any(|fold| fold.start == *line));
+
+        let folded_text = FoldedText::new(code, &folds, &state.folded);
+        let mut text = folded_text.visible.clone();
+
+        if folded_text.hidden != state.hidden {
+            // Undoing would bring back a visible text of other folds, and so delete hidden lines:
+            if let Some(mut text_edit_state) = TextEditState::load(ui.ctx(), id) {
+                let settings = text_edit_state.undo_settings();
+                text_edit_state.clear_undoer();
+                text_edit_state.set_undo_settings(settings);
+                text_edit_state.store(ui.ctx(), id);
+            }
+        }
+
+        let font_id = TextStyle::Monospace.resolve(ui.style());
+        let theme = theme.unwrap_or_else(|| CodeTheme::from_memory(ui.ctx()));
+        let mut layouter = |ui: &Ui, string: &str, wrap_width: f32| {
+            let mut job = if let Some(language) = &language {
+                highlight(ui.ctx(), &theme, string, language)
+            } else {
+                LayoutJob::simple(
+                    string.to_owned(),
+                    font_id.clone(),
+                    ui.visuals().widgets.inactive.text_color(),
+                    wrap_width,
+                )
+            };
+            job.wrap.max_width = wrap_width;
+            ui.fonts(|f| f.layout_job(job))
+        };
+
+        let (row_height, digit_width) =
+            ui.fonts(|f| (f.row_height(&font_id), f.glyph_width(&font_id, '0')));
+        let max_line_number = code.lines().count().max(1);
+        let numbers_width = if line_numbers {
+            max_line_number.to_string().len() as f32 * digit_width
+        } else {
+            0.0
+        };
+        let markers_width = if markers.is_some() { row_height } else { 0.0 };
+        let folds_width = if folds.is_empty() { 0.0 } else { row_height };
+        let gap = ui.spacing().item_spacing.x;
+        let gutter_width = markers_width + numbers_width + folds_width + gap;
+
+        let mut gutter_clicked = None;
+        let mut toggled_fold = None;
+
+        let output = Frame::canvas(ui.style())
+            .show(ui, |ui| {
+                let current_line_idx = ui.painter().add(Shape::Noop);
+
+                ui.horizontal_top(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    let (gutter_rect, _) =
+                        ui.allocate_exact_size(egui::vec2(gutter_width, 0.0), Sense::hover());
+
+                    let output = TextEdit::multiline(&mut text)
+                        .id(id)
+                        .font(TextStyle::Monospace) // for cursor height
+                        .code_editor()
+                        .frame(false)
+                        .desired_rows(desired_rows)
+                        .desired_width(f32::INFINITY)
+                        .lock_focus(true)
+                        .layouter(&mut layouter)
+                        .show(ui);
+
+                    let galley_offset = output.galley_pos.to_vec2();
+                    let gutter_rect = gutter_rect.with_max_y(output.response.rect.bottom());
+                    let cursor_line = output.cursor_range.map(|cursor_range| {
+                        folded_text.line_of(cursor_range.primary.ccursor.index)
+                    });
+
+                    if let (true, Some(cursor_range)) =
+                        (highlight_current_line, output.cursor_range)
+                    {
+                        let cursor_rect = output
+                            .galley
+                            .pos_from_cursor(&cursor_range.primary)
+                            .translate(galley_offset);
+                        let line_rect =
+                            Rect::from_x_y_ranges(ui.min_rect().x_range(), cursor_rect.y_range());
+                        let color = ui.visuals().selection.bg_fill.gamma_multiply(0.25);
+                        ui.painter()
+                            .set(current_line_idx, Shape::rect_filled(line_rect, 0.0, color));
+                    }
+
+                    ui.painter().vline(
+                        gutter_rect.right() - 0.5 * gap,
+                        gutter_rect.y_range(),
+                        ui.visuals().widgets.noninteractive.bg_stroke,
+                    );
+
+                    let mut visible_line = 0;
+                    let mut starts_line = true;
+                    for row in &output.galley.rows {
+                        let row_starts_line = starts_line;
+                        starts_line = row.ends_with_newline;
+                        if !row_starts_line {
+                            continue;
+                        }
+                        let Some(&line) = folded_text.lines.get(visible_line) else {
+                            break;
+                        };
+                        visible_line += 1;
+
+                        let y_range = row.rect.translate(galley_offset).y_range();
+                        let left = gutter_rect.left();
+                        let markers_rect = Rect::from_x_y_ranges(
+                            left..=left + markers_width + numbers_width,
+                            y_range,
+                        );
+                        let response =
+                            ui.interact(markers_rect, id.with(("gutter", line)), Sense::click());
+                        if response.clicked() {
+                            gutter_clicked = Some(line);
+                        }
+
+                        if let Some(marker) = markers.as_mut().and_then(|markers| markers(line)) {
+                            ui.painter().text(
+                                egui::pos2(left + 0.5 * markers_width, markers_rect.center().y),
+                                Align2::CENTER_CENTER,
+                                marker.icon(),
+                                font_id.clone(),
+                                marker.color(ui.visuals()),
+                            );
+                            if !marker.message().is_empty() {
+                                response.on_hover_text(marker.message());
+                            }
+                        }
+
+                        if line_numbers {
+                            let color = if cursor_line == Some(line) {
+                                ui.visuals().strong_text_color()
+                            } else {
+                                ui.visuals().weak_text_color()
+                            };
+                            ui.painter().text(
+                                egui::pos2(markers_rect.right(), markers_rect.center().y),
+                                Align2::RIGHT_CENTER,
+                                (line + 1).to_string(),
+                                font_id.clone(),
+                                color,
+                            );
+                        }
+
+                        if folds.iter().any(|fold| fold.start == line) {
+                            let fold_rect = Rect::from_x_y_ranges(
+                                markers_rect.right()..=markers_rect.right() + folds_width,
+                                y_range,
+                            )
+                            .shrink(0.2 * folds_width);
+                            let response =
+                                ui.interact(fold_rect, id.with(("fold", line)), Sense::click());
+                            if response.clicked() {
+                                toggled_fold = Some(line);
+                            }
+                            let is_folded = state.folded.contains(&line);
+                            let openness = if is_folded { 0.0 } else { 1.0 };
+                            egui::collapsing_header::paint_default_icon(ui, openness, &response);
+
+                            if is_folded {
+                                let row_rect = row.rect.translate(galley_offset);
+                                ui.painter().text(
+                                    egui::pos2(row_rect.right() + digit_width, row_rect.center().y),
+                                    Align2::LEFT_CENTER,
+                                    "⋯",
+                                    font_id.clone(),
+                                    ui.visuals().weak_text_color(),
+                                );
+                            }
+                        }
+                    }
+
+                    (output.response, cursor_line)
+                })
+                .inner
+            })
+            .inner;
+
+        let (mut response, cursor_line) = output;
+
+        if let Some(line) = toggled_fold {
+            if !state.folded.remove(&line) {
+                state.folded.insert(line);
+            }
+        }
+
+        state.hidden = folded_text.hidden.clone();
+        if let Some(line_edit) = folded_text.apply_edit(code, &text) {
+            response.mark_changed();
+
+            // Keep the folds on the same lines of code:
+            state.folded = state
+                .folded
+                .iter()
+                .filter_map(|&line| line_edit.moved_line(line))
+                .collect();
+            state.hidden = state
+                .hidden
+                .iter()
+                .filter_map(|lines| {
+                    let start = line_edit.moved_line(lines.start)?;
+                    let last = line_edit.moved_line(lines.end - 1)?;
+                    Some(start..last + 1)
+                })
+                .collect();
+        }
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        CodeEditorOutput {
+            response,
+            cursor_line,
+            gutter_clicked,
+        }
+    }
+}
+
+impl<'a> Widget for CodeEditor<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// Find folding regions from the indentation of the code, like in Python.
+///
+/// Each line followed by more indented lines starts a region, which ends at the last of those lines.
+/// Empty lines don't end a region.
+///
+/// ```
+/// let code = "fn main() {\n    println!(\"Hello\");\n}\n";
+/// assert_eq!(egui_extras::indentation_folds(code), vec![0..2]);
+/// ```
+pub fn indentation_folds(code: &str) -> Vec<Range<usize>> {
+    let mut folds = Vec::new();
+
+    // The indentation and first line of the regions we are in:
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+    let mut last_non_empty = 0;
+
+    for (line, text) in code.lines().enumerate() {
+        let trimmed = text.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indentation = text.len() - trimmed.len();
+        while let Some(&(outer, start)) = stack.last() {
+            if outer < indentation {
+                break;
+            }
+            stack.pop();
+            if start < last_non_empty {
+                folds.push(start..last_non_empty + 1);
+            }
+        }
+        stack.push((indentation, line));
+        last_non_empty = line;
+    }
+
+    for (_, start) in stack {
+        if start < last_non_empty {
+            folds.push(start..last_non_empty + 1);
+        }
+    }
+
+    folds.sort_by_key(|fold| fold.start);
+    folds
+}
+
+// ----------------------------------------------------------------------------
+
+/// The code with the hidden lines of folded regions removed, as shown in the [`TextEdit`].
+///
+/// All indices are in characters.
+struct FoldedText {
+    visible: String,
+
+    /// The hidden lines of the code, in order.
+    hidden: Vec<Range<usize>>,
+
+    /// The line in the code of each line in [`Self::visible`].
+    lines: Vec<usize>,
+
+    /// The parts of the code that are visible, in order.
+    segments: Vec<Segment>,
+}
+
+struct Segment {
+    /// Where the segment is in [`FoldedText::visible`].
+    visible: Range<usize>,
+
+    /// Where the segment starts in the code.
+    code_start: usize,
+}
+
+impl FoldedText {
+    /// Hide all but the first line of the `folds` that start at a line in `folded`.
+    fn new(code: &str, folds: &[Range<usize>], folded: &BTreeSet<usize>) -> Self {
+        // Char index of the newline at the end of each line, or the end of the code for the last line:
+        let mut line_ends: Vec<usize> = code
+            .chars()
+            .enumerate()
+            .filter_map(|(i, c)| (c == '\n').then_some(i))
+            .collect();
+        line_ends.push(code.chars().count());
+        let num_lines = line_ends.len();
+
+        let mut hidden: Vec<Range<usize>> = folds
+            .iter()
+            .filter(|fold| folded.contains(&fold.start))
+            .map(|fold| fold.start + 1..fold.end.min(num_lines))
+            .filter(|lines| !lines.is_empty())
+            .collect();
+        hidden.sort_by_key(|lines| lines.start);
+        hidden.dedup_by(|next, previous| {
+            if next.start <= previous.end {
+                previous.end = previous.end.max(next.end);
+                true
+            } else {
+                false
+            }
+        });
+
+        let mut lines = Vec::with_capacity(num_lines);
+        let mut segments = Vec::with_capacity(hidden.len() + 1);
+        let mut visible_len = 0;
+        let mut code_start = 0;
+        let mut next_line = 0;
+        for hidden_lines in &hidden {
+            lines.extend(next_line..hidden_lines.start);
+            next_line = hidden_lines.end;
+
+            // Hide from the newline in front of the hidden lines, to the newline after them:
+            let hidden_start = line_ends[hidden_lines.start - 1];
+            let len = hidden_start - code_start;
+            segments.push(Segment {
+                visible: visible_len..visible_len + len,
+                code_start,
+            });
+            visible_len += len;
+            code_start = line_ends[hidden_lines.end - 1];
+        }
+        lines.extend(next_line..num_lines);
+        let len = line_ends[num_lines - 1] - code_start;
+        segments.push(Segment {
+            visible: visible_len..visible_len + len,
+            code_start,
+        });
+
+        let visible = segments
+            .iter()
+            .flat_map(|segment| {
+                code.chars()
+                    .skip(segment.code_start)
+                    .take(segment.visible.len())
+            })
+            .collect();
+
+        Self {
+            visible,
+            hidden,
+            lines,
+            segments,
+        }
+    }
+
+    /// The line in the code of the char index `visible_index` in [`Self::visible`].
+    fn line_of(&self, visible_index: usize) -> usize {
+        let visible_line = self
+            .visible
+            .chars()
+            .take(visible_index)
+            .filter(|&c| c == '\n')
+            .count();
+        self.lines.get(visible_line).copied().unwrap_or_default()
+    }
+
+    /// The char index in the code of `visible_index`.
+    ///
+    /// Where lines are hidden, this is in front of them unless `after_hidden` is set.
+    fn code_index(&self, visible_index: usize, after_hidden: bool) -> usize {
+        let last = self.segments.len() - 1;
+        for (i, segment) in self.segments.iter().enumerate() {
+            if visible_index < segment.visible.end
+                || (visible_index == segment.visible.end && (!after_hidden || i == last))
+            {
+                return segment.code_start + visible_index - segment.visible.start;
+            }
+        }
+        self.segments[last].code_start + self.segments[last].visible.len()
+    }
+
+    /// Apply an edit of the visible text to the code.
+    ///
+    /// Hidden lines inside of the edited text are removed,
+    /// and text inserted at the end of a folded region's first line is inserted in front of the hidden lines.
+    /// Returns how the lines of the code moved, or `None` if the code was not changed.
+    fn apply_edit(&self, code: &mut String, new_visible: &str) -> Option<LineEdit> {
+        if self.visible == new_visible {
+            return None;
+        }
+
+        let old_len = self.visible.chars().count();
+        let new_len = new_visible.chars().count();
+        let prefix = self
+            .visible
+            .chars()
+            .zip(new_visible.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let suffix = self
+            .visible
+            .chars()
+            .rev()
+            .zip(new_visible.chars().rev())
+            .take(old_len.min(new_len) - prefix)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let start = self.code_index(prefix, false);
+        let end = if prefix == old_len - suffix {
+            start
+        } else {
+            self.code_index(old_len - suffix, true)
+        };
+        let byte_index = |char_index| {
+            code.char_indices()
+                .nth(char_index)
+                .map_or(code.len(), |(byte_index, _)| byte_index)
+        };
+        let byte_range = byte_index(start)..byte_index(end);
+        let inserted: String = new_visible
+            .chars()
+            .skip(prefix)
+            .take(new_len - suffix - prefix)
+            .collect();
+
+        let before = &code[..byte_range.start];
+        let line = before.matches('\n').count();
+        let removed = &code[byte_range.clone()];
+        let line_edit = LineEdit {
+            // Inserting at the start of a line moves it down:
+            first_moved: if removed.is_empty() && (before.is_empty() || before.ends_with('\n')) {
+                line
+            } else {
+                line + 1
+            },
+            removed: removed.matches('\n').count(),
+            added: inserted.matches('\n').count(),
+        };
+
+        code.replace_range(byte_range, &inserted);
+        Some(line_edit)
+    }
+}
+
+/// How the lines of the code moved in an edit.
+#[derive(Debug, PartialEq, Eq)]
+struct LineEdit {
+    /// The lines in front of this one stay where they are.
+    first_moved: usize,
+
+    /// The number of lines from `first_moved` on that were removed.
+    removed: usize,
+
+    /// The number of lines that were added in front of `first_moved`.
+    added: usize,
+}
+
+impl LineEdit {
+    /// Where `line` is after the edit, or `None` if it was removed.
+    fn moved_line(&self, line: usize) -> Option<usize> {
+        if line < self.first_moved {
+            Some(line)
+        } else if line < self.first_moved + self.removed {
+            None
+        } else {
+            Some(line - self.removed + self.added)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODE: &str = "fn main() {\n    if x {\n        y();\n    }\n}\nrest";
+
+    #[test]
+    fn test_indentation_folds() {
+        assert_eq!(indentation_folds(CODE), vec![0..4, 1..3]);
+        assert_eq!(indentation_folds("a\n\n  b\n\n  c\n\nd"), vec![0..5]);
+        assert!(indentation_folds("").is_empty());
+    }
+
+    #[test]
+    fn test_folded_text() {
+        let folds = indentation_folds(CODE);
+        let folded = FoldedText::new(CODE, &folds, &BTreeSet::from([1]));
+        assert_eq!(folded.visible, "fn main() {\n    if x {\n    }\n}\nrest");
+        assert_eq!(folded.lines, vec![0, 1, 3, 4, 5]);
+        assert_eq!(folded.line_of(13), 1);
+        assert_eq!(folded.line_of(24), 3);
+
+        let folded = FoldedText::new(CODE, &folds, &BTreeSet::from([0, 1]));
+        assert_eq!(folded.visible, "fn main() {\n}\nrest");
+        assert_eq!(folded.lines, vec![0, 4, 5]);
+
+        let folded = FoldedText::new(CODE, &folds, &BTreeSet::new());
+        assert_eq!(folded.visible, CODE);
+    }
+
+    #[test]
+    fn test_folded_edit() {
+        let folds = indentation_folds(CODE);
+        let folded = FoldedText::new(CODE, &folds, &BTreeSet::from([1]));
+
+        // Typing at the end of the first line of a fold:
+        let mut code = CODE.to_owned();
+        assert!(folded
+            .apply_edit(&mut code, "fn main() {\n    if x { // yes\n    }\n}\nrest")
+            .is_some());
+        assert_eq!(
+            code,
+            "fn main() {\n    if x { // yes\n        y();\n    }\n}\nrest"
+        );
+
+        // Typing after the fold:
+        let mut code = CODE.to_owned();
+        assert!(folded
+            .apply_edit(&mut code, "fn main() {\n    if x {\n    } // no\n}\nrest")
+            .is_some());
+        assert_eq!(
+            code,
+            "fn main() {\n    if x {\n        y();\n    } // no\n}\nrest"
+        );
+
+        // Deleting across the fold removes the hidden lines:
+        let mut code = CODE.to_owned();
+        let line_edit = folded.apply_edit(&mut code, "fn main() {\n    if x }\n}\nrest");
+        assert_eq!(code, "fn main() {\n    if x }\n}\nrest");
+        let line_edit = line_edit.unwrap();
+        assert_eq!(line_edit.moved_line(1), Some(1));
+        assert_eq!(line_edit.moved_line(2), None);
+        assert_eq!(line_edit.moved_line(3), None);
+        assert_eq!(line_edit.moved_line(4), Some(2));
+
+        let mut code = CODE.to_owned();
+        assert!(folded
+            .apply_edit(&mut code, &folded.visible.clone())
+            .is_none());
+        assert_eq!(code, CODE);
+    }
+
+    #[test]
+    fn test_folded_edit_moves_lines() {
+        let folds = indentation_folds(CODE);
+        let folded = FoldedText::new(CODE, &folds, &BTreeSet::from([1]));
+        assert_eq!(folded.hidden, vec![2..3]);
+
+        // A new line in front of the fold moves it down:
+        let mut code = CODE.to_owned();
+        let line_edit = folded
+            .apply_edit(
+                &mut code,
+                "// main\nfn main() {\n    if x {\n    }\n}\nrest",
+            )
+            .unwrap();
+        assert_eq!(
+            line_edit,
+            LineEdit {
+                first_moved: 0,
+                removed: 0,
+                added: 1
+            }
+        );
+        assert_eq!(line_edit.moved_line(1), Some(2));
+        let moved = FoldedText::new(&code, &indentation_folds(&code), &BTreeSet::from([2]));
+        assert_eq!(moved.hidden, vec![3..4]);
+
+        // Splitting the first line of the fold keeps the fold on that line:
+        let mut code = CODE.to_owned();
+        let line_edit = folded
+            .apply_edit(&mut code, "fn main() {\n    if\n x {\n    }\n}\nrest")
+            .unwrap();
+        assert_eq!(line_edit.moved_line(0), Some(0));
+        assert_eq!(line_edit.moved_line(1), Some(1));
+        assert_eq!(line_edit.moved_line(3), Some(4));
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use super::popup::DatePickerPopup;
 use chrono::NaiveDate;
 use egui::{Area, Button, Frame, InnerResponse, Key, Order, RichText, Ui, Widget};
@@ -9,8 +11,12 @@ pub(crate) struct DatePickerButtonState {
 }
 
 /// Shows a date, and will open a date picker popup when clicked.
+///
+/// With [`Self::range`], it shows and edits a range of dates instead.
 pub struct DatePickerButton<'a> {
     selection: &'a mut NaiveDate,
+    range_end: Option<&'a mut NaiveDate>,
+    range_presets: Option<Vec<(String, RangeInclusive<NaiveDate>)>>,
     id_source: Option<&'a str>,
     combo_boxes: bool,
     arrows: bool,
@@ -25,6 +31,8 @@ impl<'a> DatePickerButton<'a> {
     pub fn new(selection: &'a mut NaiveDate) -> Self {
         Self {
             selection,
+            range_end: None,
+            range_presets: None,
             id_source: None,
             combo_boxes: true,
             arrows: true,
@@ -36,6 +44,44 @@ impl<'a> DatePickerButton<'a> {
         }
     }
 
+    /// Select a range of dates from `start` to `end`, both inclusive.
+    ///
+    /// In the calendar, click the first and then the last day of the range.
+    /// While choosing the last day, the range up to the hovered day is highlighted.
+    /// The popup also has presets like "Last 7 days", see [`Self::range_presets`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut start = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// # let mut end = chrono::NaiveDate::from_ymd_opt(2024, 1, 7).unwrap();
+    /// ui.add(egui_extras::DatePickerButton::range(&mut start, &mut end));
+    /// # });
+    /// ```
+    pub fn range(start: &'a mut NaiveDate, end: &'a mut NaiveDate) -> Self {
+        Self {
+            range_end: Some(end),
+            ..Self::new(start)
+        }
+    }
+
+    /// Replace the presets of a [`Self::range`] picker.
+    /// (Default: Today, Last 7 days, Last 30 days, This month, Last month and This year)
+    ///
+    /// An empty list hides the presets.
+    #[inline]
+    pub fn range_presets(
+        mut self,
+        presets: impl IntoIterator<Item = (impl Into<String>, RangeInclusive<NaiveDate>)>,
+    ) -> Self {
+        self.range_presets = Some(
+            presets
+                .into_iter()
+                .map(|(label, range)| (label.into(), range))
+                .collect(),
+        );
+        self
+    }
+
     /// Add id source.
     /// Must be set if multiple date picker buttons are in the same Ui.
     #[inline]
@@ -102,11 +148,14 @@ impl<'a> Widget for DatePickerButton<'a> {
             .data_mut(|data| data.get_persisted::<DatePickerButtonState>(id))
             .unwrap_or_default();
 
-        let mut text = if self.show_icon {
-            RichText::new(format!("{} 📆", self.selection.format(&self.format)))
-        } else {
-            RichText::new(format!("{}", self.selection.format(&self.format)))
-        };
+        let mut text = self.selection.format(&self.format).to_string();
+        if let Some(end) = &self.range_end {
+            text = format!("{text} – {}", end.format(&self.format));
+        }
+        if self.show_icon {
+            text.push_str(" 📆");
+        }
+        let mut text = RichText::new(text);
         let visuals = ui.visuals().widgets.open;
         if button_state.picker_visible {
             text = text.color(visuals.text_color());
@@ -151,8 +200,15 @@ impl<'a> Widget for DatePickerButton<'a> {
                             ui.set_min_width(width);
                             ui.set_max_width(width);
 
+                            let range_presets = self.range_presets.unwrap_or_else(|| {
+                                super::default_range_presets(
+                                    chrono::offset::Utc::now().date_naive(),
+                                )
+                            });
                             DatePickerPopup {
                                 selection: self.selection,
+                                range_end: self.range_end,
+                                range_presets,
                                 button_id: id,
                                 combo_boxes: self.combo_boxes,
                                 arrows: self.arrows,
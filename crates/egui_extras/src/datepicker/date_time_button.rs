@@ -0,0 +1,124 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone};
+use egui::{InnerResponse, Response, Ui, Widget};
+
+use super::{button::DatePickerButton, time_button::TimePickerButton};
+
+/// Shows a date and a time, which open a date picker and a time picker popup when clicked.
+///
+/// The date and time are edited in the time zone of the [`DateTime`],
+/// e.g. [`chrono::Local`] or [`chrono::Utc`], which is shown next to them.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut date_time = chrono::Utc::now();
+/// ui.add(egui_extras::DateTimePickerButton::new(&mut date_time));
+/// # });
+/// ```
+pub struct DateTimePickerButton<'a, Tz: TimeZone> {
+    date_time: &'a mut DateTime<Tz>,
+    id_source: Option<&'a str>,
+    seconds: bool,
+    twelve_hour: bool,
+    show_timezone: bool,
+}
+
+impl<'a, Tz: TimeZone> DateTimePickerButton<'a, Tz>
+where
+    Tz::Offset: Display,
+{
+    pub fn new(date_time: &'a mut DateTime<Tz>) -> Self {
+        Self {
+            date_time,
+            id_source: None,
+            seconds: false,
+            twelve_hour: false,
+            show_timezone: true,
+        }
+    }
+
+    /// Add id source.
+    /// Must be set if multiple date-time picker buttons are in the same Ui.
+    #[inline]
+    pub fn id_source(mut self, id_source: &'a str) -> Self {
+        self.id_source = Some(id_source);
+        self
+    }
+
+    /// Show and edit seconds. (Default: false)
+    #[inline]
+    pub fn seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Use the 12-hour clock with AM and PM, instead of the 24-hour clock. (Default: false)
+    #[inline]
+    pub fn twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.twelve_hour = twelve_hour;
+        self
+    }
+
+    /// Show the time zone after the time, e.g. `UTC` or `+02:00`. (Default: true)
+    #[inline]
+    pub fn show_timezone(mut self, show_timezone: bool) -> Self {
+        self.show_timezone = show_timezone;
+        self
+    }
+}
+
+impl<'a, Tz: TimeZone> Widget for DateTimePickerButton<'a, Tz>
+where
+    Tz::Offset: Display,
+{
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            date_time,
+            id_source,
+            seconds,
+            twelve_hour,
+            show_timezone,
+        } = self;
+
+        let naive = date_time.naive_local();
+        let (mut date, mut time) = (naive.date(), naive.time());
+
+        let InnerResponse {
+            inner: changed,
+            mut response,
+        } = ui.horizontal(|ui| {
+            let mut date_button = DatePickerButton::new(&mut date);
+            let mut time_button = TimePickerButton::new(&mut time)
+                .seconds(seconds)
+                .twelve_hour(twelve_hour);
+            if let Some(id_source) = id_source {
+                date_button = date_button.id_source(id_source);
+                time_button = time_button.id_source(id_source);
+            }
+            let changed = ui.add(date_button).changed() | ui.add(time_button).changed();
+            if show_timezone {
+                ui.weak(date_time.format("%Z").to_string());
+            }
+            changed
+        });
+
+        if changed {
+            let naive = NaiveDateTime::new(date, time);
+            let timezone = date_time.timezone();
+            // A local time can be ambiguous or skipped when the clocks change.
+            // In the gap of a skipped hour, move forward by that hour.
+            let new_date_time = timezone.from_local_datetime(&naive).earliest().or_else(|| {
+                timezone
+                    .from_local_datetime(&(naive + Duration::hours(1)))
+                    .earliest()
+            });
+            if let Some(new_date_time) = new_date_time {
+                *date_time = new_date_time;
+                response.mark_changed();
+            }
+        }
+
+        response
+    }
+}
@@ -1,8 +1,14 @@
 mod button;
+mod date_time_button;
 mod popup;
+mod time_button;
+
+use std::ops::RangeInclusive;
 
 pub use button::DatePickerButton;
 use chrono::{Datelike, Duration, NaiveDate, Weekday};
+pub use date_time_button::DateTimePickerButton;
+pub use time_button::TimePickerButton;
 
 #[derive(Debug)]
 struct Week {
@@ -32,3 +38,68 @@ fn month_data(year: i32, month: u32) -> Vec<Week> {
 
     weeks
 }
+
+/// The presets of [`DatePickerButton::range`], unless replaced with [`DatePickerButton::range_presets`].
+fn default_range_presets(today: NaiveDate) -> Vec<(String, RangeInclusive<NaiveDate>)> {
+    let first_of_month = today.with_day(1).expect("Every month has a first day");
+    let first_of_next_month = if today.month() == 12 {
+        NaiveDate::from_ymd_opt(today.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(today.year(), today.month() + 1, 1)
+    }
+    .expect("Could not create NaiveDate");
+    let last_of_month = first_of_next_month.pred_opt().unwrap();
+    let last_of_previous_month = first_of_month.pred_opt().unwrap();
+    let first_of_previous_month = last_of_previous_month
+        .with_day(1)
+        .expect("Every month has a first day");
+    let first_of_year =
+        NaiveDate::from_ymd_opt(today.year(), 1, 1).expect("Could not create NaiveDate");
+    let last_of_year =
+        NaiveDate::from_ymd_opt(today.year(), 12, 31).expect("Could not create NaiveDate");
+
+    vec![
+        ("Today".to_owned(), today..=today),
+        ("Last 7 days".to_owned(), today - Duration::days(6)..=today),
+        (
+            "Last 30 days".to_owned(),
+            today - Duration::days(29)..=today,
+        ),
+        ("This month".to_owned(), first_of_month..=last_of_month),
+        (
+            "Last month".to_owned(),
+            first_of_previous_month..=last_of_previous_month,
+        ),
+        ("This year".to_owned(), first_of_year..=last_of_year),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_range_presets() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let presets = default_range_presets(date(2024, 3, 15));
+        let range = |label: &str| {
+            presets
+                .iter()
+                .find(|(preset, _)| preset == label)
+                .map(|(_, range)| range.clone())
+                .unwrap()
+        };
+
+        assert_eq!(range("Today"), date(2024, 3, 15)..=date(2024, 3, 15));
+        assert_eq!(range("Last 7 days"), date(2024, 3, 9)..=date(2024, 3, 15));
+        assert_eq!(range("Last 30 days"), date(2024, 2, 15)..=date(2024, 3, 15));
+        assert_eq!(range("This month"), date(2024, 3, 1)..=date(2024, 3, 31));
+        assert_eq!(range("Last month"), date(2024, 2, 1)..=date(2024, 2, 29));
+        assert_eq!(range("This year"), date(2024, 1, 1)..=date(2024, 12, 31));
+
+        let presets = default_range_presets(date(2023, 12, 5));
+        assert_eq!(presets[3].1, date(2023, 12, 1)..=date(2023, 12, 31));
+        let presets = default_range_presets(date(2024, 1, 5));
+        assert_eq!(presets[4].1, date(2023, 12, 1)..=date(2023, 12, 31));
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use chrono::{Datelike, NaiveDate, Weekday};
 
 use egui::{Align, Button, Color32, ComboBox, Direction, Id, Layout, RichText, Ui, Vec2};
@@ -13,9 +15,95 @@ struct DatePickerPopupState {
     month: u32,
     day: u32,
     setup: bool,
+
+    /// When selecting a range, the start of it, as days since the Common Era.
+    ///
+    /// The year, month and day above are then only the month being shown.
+    range_start: Option<i32>,
+
+    /// When selecting a range, the end of it, as days since the Common Era,
+    /// or `None` while the user is picking it.
+    range_end: Option<i32>,
+
+    /// The day hovered last frame, as days since the Common Era,
+    /// to preview the range while the user is picking its end.
+    range_hover: Option<i32>,
 }
 
 impl DatePickerPopupState {
+    fn date(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+            .expect("Could not create NaiveDate")
+    }
+
+    fn set_date(&mut self, date: NaiveDate) {
+        self.year = date.year();
+        self.month = date.month();
+        self.day = date.day();
+    }
+
+    /// Show the month `months` before or after the current one.
+    fn add_months(&mut self, months: i32) {
+        let month_index = self.year * 12 + self.month as i32 - 1 + months;
+        self.year = month_index.div_euclid(12);
+        self.month = month_index.rem_euclid(12) as u32 + 1;
+        self.day = self.day.min(self.last_day_of_month());
+    }
+
+    fn range_start(&self) -> NaiveDate {
+        self.range_start
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .unwrap_or_else(|| self.date())
+    }
+
+    /// The selected range, or the one being picked, in order.
+    fn range(&self) -> RangeInclusive<NaiveDate> {
+        let start = self.range_start();
+        let end = self
+            .range_end
+            .or(self.range_hover)
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .unwrap_or(start);
+        start.min(end)..=start.max(end)
+    }
+
+    /// The range to save: just the start if the user hasn't picked the end yet.
+    fn selected_range(&self) -> RangeInclusive<NaiveDate> {
+        let start = self.range_start();
+        let end = self
+            .range_end
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .unwrap_or(start);
+        start.min(end)..=start.max(end)
+    }
+
+    fn set_range(&mut self, range: &RangeInclusive<NaiveDate>) {
+        self.set_date(*range.start());
+        self.range_start = Some(range.start().num_days_from_ce());
+        self.range_end = Some(range.end().num_days_from_ce());
+    }
+
+    /// The user clicked `day` in the calendar.
+    fn click_day(&mut self, day: NaiveDate, is_range: bool) {
+        self.set_date(day);
+        if !is_range {
+            return;
+        }
+        match self.range_start {
+            Some(start) if self.range_end.is_none() => {
+                // Second click: the end of the range.
+                let day = day.num_days_from_ce();
+                self.range_start = Some(start.min(day));
+                self.range_end = Some(start.max(day));
+            }
+            _ => {
+                // First click: the start of the range.
+                self.range_start = Some(day.num_days_from_ce());
+                self.range_end = None;
+            }
+        }
+    }
+
     fn last_day_of_month(&self) -> u32 {
         let date: NaiveDate =
             NaiveDate::from_ymd_opt(self.year, self.month, 1).expect("Could not create NaiveDate");
@@ -29,6 +117,8 @@ impl DatePickerPopupState {
 
 pub(crate) struct DatePickerPopup<'a> {
     pub selection: &'a mut NaiveDate,
+    pub range_end: Option<&'a mut NaiveDate>,
+    pub range_presets: Vec<(String, RangeInclusive<NaiveDate>)>,
     pub button_id: Id,
     pub combo_boxes: bool,
     pub arrows: bool,
@@ -45,16 +135,19 @@ impl<'a> DatePickerPopup<'a> {
         let mut popup_state = ui
             .data_mut(|data| data.get_persisted::<DatePickerPopupState>(id))
             .unwrap_or_default();
+        let is_range = self.range_end.is_some();
         if !popup_state.setup {
-            popup_state.year = self.selection.year();
-            popup_state.month = self.selection.month();
-            popup_state.day = self.selection.day();
+            popup_state.set_date(*self.selection);
+            popup_state.range_start = is_range.then(|| self.selection.num_days_from_ce());
+            popup_state.range_end = self.range_end.as_ref().map(|end| end.num_days_from_ce());
+            popup_state.range_hover = None;
             popup_state.setup = true;
             ui.data_mut(|data| data.insert_persisted(id, popup_state.clone()));
         }
 
         let weeks = month_data(popup_state.year, popup_state.month);
         let (mut close, mut saved) = (false, false);
+        let mut hovered_day = None;
         let height = 20.0;
         let spacing = 2.0;
         ui.spacing_mut().item_spacing = Vec2::splat(spacing);
@@ -68,6 +161,10 @@ impl<'a> DatePickerPopup<'a> {
                     (false, false) => 0,
                 },
             )
+            .sizes(
+                Size::exact(height),
+                (is_range && !self.range_presets.is_empty()) as usize,
+            )
             .sizes(
                 Size::exact((spacing + height) * (weeks.len() + 1) as f32),
                 self.calendar as usize,
@@ -176,13 +273,7 @@ impl<'a> DatePickerPopup<'a> {
                                         .on_hover_text("subtract one month")
                                         .clicked()
                                     {
-                                        popup_state.month -= 1;
-                                        if popup_state.month == 0 {
-                                            popup_state.month = 12;
-                                            popup_state.year -= 1;
-                                        }
-                                        popup_state.day =
-                                            popup_state.day.min(popup_state.last_day_of_month());
+                                        popup_state.add_months(-1);
                                         ui.data_mut(|data| {
                                             data.insert_persisted(id, popup_state.clone());
                                         });
@@ -228,13 +319,7 @@ impl<'a> DatePickerPopup<'a> {
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                     if ui.button(">>").on_hover_text("add one month").clicked() {
-                                        popup_state.month += 1;
-                                        if popup_state.month > 12 {
-                                            popup_state.month = 1;
-                                            popup_state.year += 1;
-                                        }
-                                        popup_state.day =
-                                            popup_state.day.min(popup_state.last_day_of_month());
+                                        popup_state.add_months(1);
                                         ui.data_mut(|data| {
                                             data.insert_persisted(id, popup_state.clone());
                                         });
@@ -257,7 +342,25 @@ impl<'a> DatePickerPopup<'a> {
                     });
                 }
 
+                if is_range && !self.range_presets.is_empty() {
+                    strip.cell(|ui| {
+                        ComboBox::from_id_source("date_picker_range_preset")
+                            .selected_text("Presets")
+                            .show_ui(ui, |ui| {
+                                for (label, range) in &self.range_presets {
+                                    if ui.selectable_label(false, label).clicked() {
+                                        popup_state.set_range(range);
+                                        ui.data_mut(|data| {
+                                            data.insert_persisted(id, popup_state.clone());
+                                        });
+                                    }
+                                }
+                            });
+                    });
+                }
+
                 if self.calendar {
+                    let selected_range = popup_state.range();
                     strip.cell(|ui| {
                         ui.spacing_mut().item_spacing = Vec2::new(1.0, 2.0);
                         TableBuilder::new(ui)
@@ -300,12 +403,23 @@ impl<'a> DatePickerPopup<'a> {
                                                 ui.with_layout(
                                                     Layout::top_down_justified(Align::Center),
                                                     |ui| {
-                                                        let fill_color = if popup_state.year
-                                                            == day.year()
-                                                            && popup_state.month == day.month()
-                                                            && popup_state.day == day.day()
-                                                        {
+                                                        let is_selected = if is_range {
+                                                            day == *selected_range.start()
+                                                                || day == *selected_range.end()
+                                                        } else {
+                                                            popup_state.year == day.year()
+                                                                && popup_state.month == day.month()
+                                                                && popup_state.day == day.day()
+                                                        };
+                                                        let fill_color = if is_selected {
                                                             ui.visuals().selection.bg_fill
+                                                        } else if is_range
+                                                            && selected_range.contains(&day)
+                                                        {
+                                                            ui.visuals()
+                                                                .selection
+                                                                .bg_fill
+                                                                .linear_multiply(0.4)
                                                         } else if (day.weekday() == Weekday::Sat
                                                             || day.weekday() == Weekday::Sun)
                                                             && self.highlight_weekends
@@ -354,10 +468,13 @@ impl<'a> DatePickerPopup<'a> {
                                                             );
                                                         }
 
+                                                        if button_response.hovered() {
+                                                            hovered_day =
+                                                                Some(day.num_days_from_ce());
+                                                        }
+
                                                         if button_response.clicked() {
-                                                            popup_state.year = day.year();
-                                                            popup_state.month = day.month();
-                                                            popup_state.day = day.day();
+                                                            popup_state.click_day(day, is_range);
                                                             ui.data_mut(|data| {
                                                                 data.insert_persisted(
                                                                     id,
@@ -388,12 +505,13 @@ impl<'a> DatePickerPopup<'a> {
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                 if ui.button("Save").clicked() {
-                                    *self.selection = NaiveDate::from_ymd_opt(
-                                        popup_state.year,
-                                        popup_state.month,
-                                        popup_state.day,
-                                    )
-                                    .expect("Could not create NaiveDate");
+                                    if let Some(range_end) = self.range_end.as_deref_mut() {
+                                        let range = popup_state.selected_range();
+                                        *self.selection = *range.start();
+                                        *range_end = *range.end();
+                                    } else {
+                                        *self.selection = popup_state.date();
+                                    }
                                     saved = true;
                                     close = true;
                                 }
@@ -403,6 +521,11 @@ impl<'a> DatePickerPopup<'a> {
                 });
             });
 
+        if is_range && popup_state.range_hover != hovered_day {
+            popup_state.range_hover = hovered_day;
+            ui.data_mut(|data| data.insert_persisted(id, popup_state.clone()));
+        }
+
         if close {
             popup_state.setup = false;
             ui.data_mut(|data| {
@@ -433,3 +556,48 @@ fn month_name(i: u32) -> &'static str {
         _ => panic!("Unknown month: {i}"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_range_across_months() {
+        let mut state = DatePickerPopupState::default();
+        state.set_range(&(date(2024, 1, 10)..=date(2024, 1, 10)));
+
+        state.click_day(date(2024, 1, 28), true);
+        state.add_months(1);
+        assert_eq!((state.year, state.month, state.day), (2024, 2, 28));
+        assert_eq!(state.range(), date(2024, 1, 28)..=date(2024, 1, 28));
+
+        state.click_day(date(2024, 2, 3), true);
+        assert_eq!(state.selected_range(), date(2024, 1, 28)..=date(2024, 2, 3));
+
+        // Browsing doesn't change the range:
+        state.add_months(-13);
+        assert_eq!((state.year, state.month), (2023, 1));
+        assert_eq!(state.selected_range(), date(2024, 1, 28)..=date(2024, 2, 3));
+
+        // A third click starts a new range:
+        state.click_day(date(2023, 1, 5), true);
+        assert_eq!(state.selected_range(), date(2023, 1, 5)..=date(2023, 1, 5));
+    }
+
+    #[test]
+    fn test_range_preset_then_browse() {
+        let mut state = DatePickerPopupState::default();
+        state.set_range(&(date(2024, 2, 15)..=date(2024, 3, 15)));
+        assert_eq!((state.year, state.month), (2024, 2));
+
+        state.add_months(1);
+        assert_eq!(
+            state.selected_range(),
+            date(2024, 2, 15)..=date(2024, 3, 15)
+        );
+    }
+}
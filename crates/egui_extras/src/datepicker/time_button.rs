@@ -0,0 +1,354 @@
+use chrono::{NaiveTime, Timelike};
+use egui::{
+    Area, Button, DragValue, Frame, InnerResponse, Key, Order, Response, RichText, TextEdit, Ui,
+    Widget,
+};
+
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub(crate) struct TimePickerButtonState {
+    pub picker_visible: bool,
+}
+
+/// The time being edited in the popup of a [`TimePickerButton`], until it is saved.
+#[derive(Clone)]
+struct TimePickerPopupState {
+    time: NaiveTime,
+
+    /// What is in the text field, which may not be a valid time (yet).
+    text: String,
+}
+
+/// Shows a time, and will open a time picker popup when clicked.
+///
+/// In the popup, the time can be typed, e.g. `14:30` or `2:30 pm`,
+/// or changed by dragging the hours and minutes.
+///
+/// See also [`crate::DateTimePickerButton`].
+pub struct TimePickerButton<'a> {
+    time: &'a mut NaiveTime,
+    id_source: Option<&'a str>,
+    seconds: bool,
+    twelve_hour: bool,
+    show_icon: bool,
+}
+
+impl<'a> TimePickerButton<'a> {
+    pub fn new(time: &'a mut NaiveTime) -> Self {
+        Self {
+            time,
+            id_source: None,
+            seconds: false,
+            twelve_hour: false,
+            show_icon: true,
+        }
+    }
+
+    /// Add id source.
+    /// Must be set if multiple time picker buttons are in the same Ui.
+    #[inline]
+    pub fn id_source(mut self, id_source: &'a str) -> Self {
+        self.id_source = Some(id_source);
+        self
+    }
+
+    /// Show and edit seconds. (Default: false)
+    ///
+    /// Without seconds, they are set to zero when the time is changed.
+    #[inline]
+    pub fn seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Use the 12-hour clock with AM and PM, instead of the 24-hour clock. (Default: false)
+    #[inline]
+    pub fn twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.twelve_hour = twelve_hour;
+        self
+    }
+
+    /// Show the clock icon on the button. (Default: true)
+    #[inline]
+    pub fn show_icon(mut self, show_icon: bool) -> Self {
+        self.show_icon = show_icon;
+        self
+    }
+
+    fn format(&self, time: NaiveTime) -> String {
+        let format = match (self.twelve_hour, self.seconds) {
+            (false, false) => "%H:%M",
+            (false, true) => "%H:%M:%S",
+            (true, false) => "%-I:%M %p",
+            (true, true) => "%-I:%M:%S %p",
+        };
+        time.format(format).to_string()
+    }
+}
+
+impl<'a> Widget for TimePickerButton<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let id = ui.make_persistent_id(("time_picker", self.id_source));
+        let mut button_state = ui
+            .data_mut(|data| data.get_persisted::<TimePickerButtonState>(id))
+            .unwrap_or_default();
+
+        let mut text = self.format(*self.time);
+        if self.show_icon {
+            text.push_str(" 🕓");
+        }
+        let mut text = RichText::new(text);
+        let visuals = ui.visuals().widgets.open;
+        if button_state.picker_visible {
+            text = text.color(visuals.text_color());
+        }
+        let mut button = Button::new(text);
+        if button_state.picker_visible {
+            button = button.fill(visuals.weak_bg_fill).stroke(visuals.bg_stroke);
+        }
+        let mut button_response = ui.add(button);
+        if button_response.clicked() && !button_state.picker_visible {
+            button_state.picker_visible = true;
+            ui.data_mut(|data| data.insert_persisted(id, button_state.clone()));
+        }
+
+        if button_state.picker_visible {
+            let popup_id = id.with("popup");
+            let mut is_first_frame = false;
+            let mut popup_state = ui
+                .data(|data| data.get_temp::<TimePickerPopupState>(popup_id))
+                .unwrap_or_else(|| {
+                    is_first_frame = true;
+                    TimePickerPopupState {
+                        time: *self.time,
+                        text: self.format(*self.time),
+                    }
+                });
+
+            let mut close = false;
+            let InnerResponse {
+                inner: saved,
+                response: area_response,
+            } = Area::new(popup_id)
+                .kind(egui::UiKind::Picker)
+                .order(Order::Foreground)
+                .fixed_pos(button_response.rect.left_bottom())
+                .show(ui.ctx(), |ui| {
+                    Frame::popup(ui.style())
+                        .show(ui, |ui| {
+                            self.popup_ui(ui, &mut popup_state, is_first_frame, &mut close)
+                        })
+                        .inner
+                });
+
+            if saved {
+                *self.time = popup_state.time;
+                button_response.mark_changed();
+            }
+
+            if !button_response.clicked()
+                && (ui.input(|i| i.key_pressed(Key::Escape)) || area_response.clicked_elsewhere())
+            {
+                close = true;
+            }
+
+            if close {
+                button_state.picker_visible = false;
+                ui.data_mut(|data| {
+                    data.insert_persisted(id, button_state);
+                    data.remove::<TimePickerPopupState>(popup_id);
+                });
+            } else {
+                ui.data_mut(|data| data.insert_temp(popup_id, popup_state));
+            }
+        }
+
+        button_response
+    }
+}
+
+impl<'a> TimePickerButton<'a> {
+    /// Returns `true` if the user saved the time.
+    fn popup_ui(
+        &self,
+        ui: &mut Ui,
+        state: &mut TimePickerPopupState,
+        is_first_frame: bool,
+        close: &mut bool,
+    ) -> bool {
+        let mut saved = false;
+
+        let text_response = TextEdit::singleline(&mut state.text)
+            .id(ui.id().with("time_text"))
+            .hint_text(if self.twelve_hour { "2:30 pm" } else { "14:30" })
+            .desired_width(ui.spacing().slider_width)
+            .show(ui)
+            .response;
+        if is_first_frame {
+            text_response.request_focus();
+        }
+        let parsed = parse_time(&state.text);
+        if text_response.changed() {
+            if let Some(time) = parsed {
+                state.time = time;
+            }
+        }
+        if text_response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+            if let Some(time) = parsed {
+                state.time = time;
+                saved = true;
+                *close = true;
+            }
+        }
+        if parsed.is_none() && !state.text.is_empty() {
+            ui.colored_label(ui.visuals().error_fg_color, "Not a valid time");
+        }
+
+        // Edit the hour, minute and second separately:
+        let (mut hour, mut minute, mut second) =
+            (state.time.hour(), state.time.minute(), state.time.second());
+        let (mut is_pm, mut hour12) = state.time.hour12();
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            let two_digits = |n: f64, _| format!("{:02}", n as u32);
+            if self.twelve_hour {
+                changed |= ui
+                    .add(DragValue::new(&mut hour12).speed(0.1).clamp_range(1..=12))
+                    .changed();
+            } else {
+                changed |= ui
+                    .add(
+                        DragValue::new(&mut hour)
+                            .speed(0.1)
+                            .clamp_range(0..=23)
+                            .custom_formatter(two_digits),
+                    )
+                    .changed();
+            }
+            ui.label(":");
+            changed |= ui
+                .add(
+                    DragValue::new(&mut minute)
+                        .speed(0.2)
+                        .clamp_range(0..=59)
+                        .custom_formatter(two_digits),
+                )
+                .changed();
+            if self.seconds {
+                ui.label(":");
+                changed |= ui
+                    .add(
+                        DragValue::new(&mut second)
+                            .speed(0.2)
+                            .clamp_range(0..=59)
+                            .custom_formatter(two_digits),
+                    )
+                    .changed();
+            }
+            if self.twelve_hour {
+                changed |= ui.selectable_value(&mut is_pm, false, "AM").changed();
+                changed |= ui.selectable_value(&mut is_pm, true, "PM").changed();
+            }
+        });
+        if changed {
+            if self.twelve_hour {
+                hour = hour12 % 12 + if is_pm { 12 } else { 0 };
+            }
+            if !self.seconds {
+                second = 0;
+            }
+            if let Some(time) = NaiveTime::from_hms_opt(hour, minute, second) {
+                state.time = time;
+                state.text = self.format(time);
+            }
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("Cancel").clicked() {
+                *close = true;
+            }
+            if ui.button("Save").clicked() {
+                saved = true;
+                *close = true;
+            }
+        });
+
+        saved
+    }
+}
+
+/// Parse a time typed by the user, e.g. `14:30`, `14:30:15`, `1430`, `2:30 pm` or `2pm`.
+fn parse_time(text: &str) -> Option<NaiveTime> {
+    let (hour, minute, second) = parse_hms(text)?;
+    NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+/// The hour (0-23), minute and second of a time typed by the user, see [`parse_time`].
+fn parse_hms(text: &str) -> Option<(u32, u32, u32)> {
+    let text = text.trim().to_ascii_lowercase();
+    let (text, is_pm) =
+        if let Some(text) = text.strip_suffix("am").or_else(|| text.strip_suffix('a')) {
+            (text.trim_end(), Some(false))
+        } else if let Some(text) = text.strip_suffix("pm").or_else(|| text.strip_suffix('p')) {
+            (text.trim_end(), Some(true))
+        } else {
+            (text.as_str(), None)
+        };
+
+    let parts: Vec<&str> = text.split(':').collect();
+    let number = |part: &str| {
+        if !part.is_empty() && part.len() <= 2 && part.bytes().all(|b| b.is_ascii_digit()) {
+            part.parse::<u32>().ok()
+        } else {
+            None
+        }
+    };
+    let (hour, minute, second) = match parts[..] {
+        // "14", "1430" or "143015":
+        [digits] if digits.len() <= 2 => (number(digits)?, 0, 0),
+        [digits] if digits.len() == 4 || digits.len() == 6 => (
+            number(digits.get(0..2)?)?,
+            number(digits.get(2..4)?)?,
+            digits.get(4..6).map_or(Some(0), number)?,
+        ),
+        [hour, minute] => (number(hour)?, number(minute)?, 0),
+        [hour, minute, second] => (number(hour)?, number(minute)?, number(second)?),
+        _ => return None,
+    };
+
+    let hour = match is_pm {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some(is_pm) => hour % 12 + if is_pm { 12 } else { 0 },
+        None => hour,
+    };
+    (hour < 24 && minute < 60 && second < 60).then_some((hour, minute, second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hms() {
+        assert_eq!(parse_hms("14:30"), Some((14, 30, 0)));
+        assert_eq!(parse_hms(" 9:05:07 "), Some((9, 5, 7)));
+        assert_eq!(parse_hms("1430"), Some((14, 30, 0)));
+        assert_eq!(parse_hms("143015"), Some((14, 30, 15)));
+        assert_eq!(parse_hms("7"), Some((7, 0, 0)));
+
+        assert_eq!(parse_hms("2:30 pm"), Some((14, 30, 0)));
+        assert_eq!(parse_hms("2:30PM"), Some((14, 30, 0)));
+        assert_eq!(parse_hms("12:15 am"), Some((0, 15, 0)));
+        assert_eq!(parse_hms("12 pm"), Some((12, 0, 0)));
+        assert_eq!(parse_hms("2p"), Some((14, 0, 0)));
+
+        assert_eq!(parse_hms(""), None);
+        assert_eq!(parse_hms("24:00"), None);
+        assert_eq!(parse_hms("12:60"), None);
+        assert_eq!(parse_hms("13:00 pm"), None);
+        assert_eq!(parse_hms("0 am"), None);
+        assert_eq!(parse_hms("14:3a0"), None);
+        assert_eq!(parse_hms("143"), None);
+        assert_eq!(parse_hms("1:2:3:4"), None);
+    }
+}
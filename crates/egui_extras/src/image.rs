@@ -103,10 +103,7 @@ impl RetainedImage {
     /// # use egui_extras::RetainedImage;
     /// # use egui::{Color32, epaint::{ColorImage, textures::TextureOptions}};
     /// # let pixels = vec![Color32::BLACK];
-    /// # let color_image = ColorImage {
-    /// #   size: [1, 1],
-    /// #   pixels,
-    /// # };
+    /// # let color_image = ColorImage::from_pixels([1, 1], pixels);
     /// #
     /// // Upload a pixel art image without it getting blurry when resized
     /// let image = RetainedImage::from_color_image("my_image", color_image)
@@ -197,19 +194,40 @@ use egui::ColorImage;
 /// Requires the "image" feature. You must also opt-in to the image formats you need
 /// with e.g. `image = { version = "0.25", features = ["jpeg", "png"] }`.
 ///
+/// Images with an embedded ICC profile in a color space other than sRGB
+/// (e.g. Display P3 photos from phones) are converted to sRGB.
+///
 /// # Errors
 /// On invalid image or unsupported image format.
 #[cfg(feature = "image")]
 pub fn load_image_bytes(image_bytes: &[u8]) -> Result<egui::ColorImage, String> {
+    use image::ImageDecoder as _;
+
     crate::profile_function!();
-    let image = image::load_from_memory(image_bytes).map_err(|err| err.to_string())?;
+    let mut decoder = image::io::Reader::new(std::io::Cursor::new(image_bytes))
+        .with_guessed_format()
+        .map_err(|err| err.to_string())?
+        .into_decoder()
+        .map_err(|err| err.to_string())?;
+    let icc_profile = decoder.icc_profile().ok().flatten();
+    let image = image::DynamicImage::from_decoder(decoder).map_err(|err| err.to_string())?;
+
     let size = [image.width() as _, image.height() as _];
     let image_buffer = image.to_rgba8();
     let pixels = image_buffer.as_flat_samples();
-    Ok(egui::ColorImage::from_rgba_unmultiplied(
-        size,
-        pixels.as_slice(),
-    ))
+    let mut color_image = egui::ColorImage::from_rgba_unmultiplied(size, pixels.as_slice());
+
+    if let Some(icc_profile) = icc_profile {
+        if let Some(color_space) = egui::ColorSpace::from_icc_profile(&icc_profile) {
+            crate::profile_scope!("convert_to_srgb");
+            color_image = color_image.with_color_space(color_space);
+            color_image.convert_to(egui::ColorSpace::Srgb);
+        } else {
+            log::debug!("Unsupported ICC profile; assuming the image is in sRGB");
+        }
+    }
+
+    Ok(color_image)
 }
 
 /// Load an SVG and rasterize it into an egui image.
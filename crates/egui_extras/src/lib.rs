@@ -14,24 +14,46 @@ mod datepicker;
 
 pub mod syntax_highlighting;
 
+mod axis;
+mod breadcrumbs;
+mod code_editor;
 #[doc(hidden)]
 pub mod image;
 mod layout;
 mod loaders;
+mod reorderable_list;
+mod rich_text_edit;
 mod sizing;
 mod strip;
 mod table;
+mod tabs;
+mod tag_input;
+mod tree_view;
+mod virtual_keyboard;
 
 #[cfg(feature = "chrono")]
-pub use crate::datepicker::DatePickerButton;
+pub use crate::datepicker::{DatePickerButton, DateTimePickerButton, TimePickerButton};
+
+pub use crate::axis::{format_tick_value, AxisTick, LinearMap};
+pub use crate::breadcrumbs::{Breadcrumbs, BreadcrumbsResponse};
+pub use crate::code_editor::{indentation_folds, CodeEditor, CodeEditorOutput, GutterMarker};
 
 #[doc(hidden)]
 #[allow(deprecated)]
 pub use crate::image::RetainedImage;
 pub(crate) use crate::layout::StripLayout;
+pub use crate::reorderable_list::{ListMove, ReorderableList, ReorderableListResponse};
+pub use crate::rich_text_edit::{RichTextDocument, RichTextEdit, RichTextSpan, RichTextStyle};
 pub use crate::sizing::Size;
 pub use crate::strip::*;
 pub use crate::table::*;
+pub use crate::tabs::{Tabs, TabsBuilder, TabsResponse};
+pub use crate::tag_input::TagInput;
+pub use crate::tree_view::{
+    DropPosition, TreeNodeId, TreeView, TreeViewBuilder, TreeViewDrop, TreeViewResponse,
+    TreeViewState,
+};
+pub use crate::virtual_keyboard::{KeyboardLayout, VirtualKeyboard};
 
 pub use loaders::install_image_loaders;
 
@@ -1,15 +1,22 @@
-use std::{mem::size_of, path::Path, sync::Arc};
+use std::{borrow::Cow, mem::size_of, path::Path, sync::Arc};
 
 use ahash::HashMap;
 
 use egui::{
+    decode_current_color_uri,
     load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
     mutex::Mutex,
-    ColorImage,
+    Color32, ColorImage,
 };
 
 type Entry = Result<Arc<ColorImage>, String>;
 
+/// Rasterizes SVG:s at the requested [`SizeHint`].
+///
+/// Uris created by [`egui::Image::current_color`] (`{uri}#currentColor={color}`)
+/// are rasterized with `currentColor` replaced by that color.
+///
+/// Only the latest size of each uri is kept, since the textures are cached anyway.
 #[derive(Default)]
 pub struct SvgLoader {
     cache: Mutex<HashMap<(String, SizeHint), Entry>>,
@@ -20,6 +27,9 @@ impl SvgLoader {
 }
 
 fn is_supported(uri: &str) -> bool {
+    let Ok((uri, _)) = decode_current_color_uri(uri) else {
+        return false;
+    };
     let Some(ext) = Path::new(uri).extension().and_then(|ext| ext.to_str()) else {
         return false;
     };
@@ -47,12 +57,21 @@ impl ImageLoader for SvgLoader {
                 Err(err) => Err(LoadError::Loading(err)),
             }
         } else {
-            match ctx.try_load_bytes(&uri) {
+            let (bytes_uri, current_color) =
+                decode_current_color_uri(&uri).map_err(LoadError::Loading)?;
+            match ctx.try_load_bytes(bytes_uri) {
                 Ok(BytesPoll::Ready { bytes, .. }) => {
                     log::trace!("started loading {uri:?}");
+                    let bytes = match current_color {
+                        Some(color) => replace_current_color(&bytes, color),
+                        None => Cow::Borrowed(&*bytes),
+                    };
                     let result = crate::image::load_svg_bytes_with_size(&bytes, Some(size_hint))
                         .map(Arc::new);
                     log::trace!("finished loading {uri:?}");
+
+                    // The other sizes are most likely from before a zoom or resize:
+                    cache.retain(|(u, _), _| u != &uri);
                     cache.insert((uri, size_hint), result.clone());
                     match result {
                         Ok(image) => Ok(ImagePoll::Ready { image }),
@@ -66,7 +85,9 @@ impl ImageLoader for SvgLoader {
     }
 
     fn forget(&self, uri: &str) {
-        self.cache.lock().retain(|(u, _), _| u != uri);
+        self.cache.lock().retain(|(u, _), _| {
+            u != uri && decode_current_color_uri(u).map_or(true, |(u, _)| u != uri)
+        });
     }
 
     fn forget_all(&self) {
@@ -85,6 +106,23 @@ impl ImageLoader for SvgLoader {
     }
 }
 
+/// Replace all `currentColor` keywords in the svg with the given color.
+fn replace_current_color(svg_bytes: &[u8], color: Color32) -> Cow<'_, [u8]> {
+    let Ok(svg) = std::str::from_utf8(svg_bytes) else {
+        return Cow::Borrowed(svg_bytes);
+    };
+    if !svg.contains("currentColor") && !svg.contains("currentcolor") {
+        return Cow::Borrowed(svg_bytes);
+    }
+
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    let color = format!("rgba({r}, {g}, {b}, {})", f32::from(a) / 255.0);
+    let svg = svg
+        .replace("currentColor", &color)
+        .replace("currentcolor", &color);
+    Cow::Owned(svg.into_bytes())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,5 +136,20 @@ mod tests {
         assert!(!is_supported("test.webp"));
         assert!(!is_supported("file://test"));
         assert!(is_supported("test.svg"));
+        assert!(is_supported("test.svg#currentColor=#ff0000ff"));
+        assert!(!is_supported("test.svg#currentColor=red"));
+    }
+
+    #[test]
+    fn current_color() {
+        let svg = br#"<svg><path fill="currentColor" stroke="red"/></svg>"#;
+        assert_eq!(
+            &*replace_current_color(svg, Color32::from_rgb(255, 128, 0)),
+            br#"<svg><path fill="rgba(255, 128, 0, 1)" stroke="red"/></svg>"#
+        );
+        assert!(matches!(
+            replace_current_color(b"<svg/>", Color32::RED),
+            Cow::Borrowed(_)
+        ));
     }
 }
@@ -0,0 +1,413 @@
+use std::hash::Hash;
+
+use egui::{
+    emath::TSTransform, CursorIcon, Id, Key, LayerId, Modifiers, NumExt as _, Order, Rect,
+    Response, Sense, Ui, UiStackInfo, Vec2,
+};
+
+/// An item of a [`ReorderableList`] was moved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListMove {
+    /// The index of the item before the move.
+    pub from: usize,
+
+    /// The index of the item after the move.
+    pub to: usize,
+}
+
+impl ListMove {
+    /// Move the item in `items`.
+    pub fn apply<T>(self, items: &mut Vec<T>) {
+        if self.from < items.len() && self.to < items.len() {
+            let item = items.remove(self.from);
+            items.insert(self.to, item);
+        }
+    }
+}
+
+/// The response from [`ReorderableList::show`].
+pub struct ReorderableListResponse {
+    /// The response of the whole list.
+    pub response: Response,
+
+    /// An item was moved by the user this frame.
+    pub moved: Option<ListMove>,
+}
+
+/// Stored in temporary memory for each [`ReorderableList`].
+#[derive(Clone, Default)]
+struct ReorderState {
+    /// The height of each item, last time it was shown.
+    heights: Vec<f32>,
+
+    /// The index of the item being dragged.
+    dragged: Option<usize>,
+
+    /// Where the item was grabbed, relative to its top left corner.
+    grab_offset: Vec2,
+
+    /// Where the dragged item ends up if it is dropped now.
+    target: usize,
+
+    /// Counts the drags, so that each drag gets new animations of the gaps.
+    drag_count: u64,
+}
+
+/// A vertical list of items that the user can reorder by dragging them.
+///
+/// While an item is dragged, it follows the mouse, and a gap opens where it will be dropped.
+/// Dragging near the top or bottom of a surrounding [`egui::ScrollArea`] scrolls it.
+///
+/// An item is only picked up once the pointer has moved a bit while pressed on it,
+/// so clicking widgets inside the items works as usual.
+///
+/// Items can also be moved with the keyboard: focus one with tab, and press alt and the up or down arrow.
+///
+/// The list doesn't move anything itself; use [`ReorderableListResponse::moved`],
+/// or [`Self::show_vec`] which does it for you.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut items = vec!["Alpha", "Beta", "Gamma"];
+/// egui_extras::ReorderableList::new("my_list").show_vec(ui, &mut items, |ui, item| {
+///     ui.label(*item);
+/// });
+/// # });
+/// ```
+pub struct ReorderableList {
+    id_source: Id,
+}
+
+impl ReorderableList {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+        }
+    }
+
+    /// Show the items of a [`Vec`], and move them around when the user drags them.
+    pub fn show_vec<T>(
+        self,
+        ui: &mut Ui,
+        items: &mut Vec<T>,
+        mut add_item: impl FnMut(&mut Ui, &mut T),
+    ) -> ReorderableListResponse {
+        let response = self.show(ui, items.len(), |ui, index| add_item(ui, &mut items[index]));
+        if let Some(moved) = response.moved {
+            moved.apply(items);
+        }
+        response
+    }
+
+    /// Show `num_items` items, using `add_item` to show the item at each index.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        num_items: usize,
+        mut add_item: impl FnMut(&mut Ui, usize),
+    ) -> ReorderableListResponse {
+        let id = ui.make_persistent_id(self.id_source);
+        let item_id = |index: usize| id.with(("item", index));
+
+        let mut state: ReorderState = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+        state.heights.resize(num_items, 0.0);
+        let mut moved = None;
+
+        if let Some(from) = state.dragged {
+            if num_items <= from || !ui.ctx().is_being_dragged(item_id(from)) {
+                // Dropped (or aborted with escape):
+                let dropped = ui.input(|i| i.pointer.any_released());
+                if dropped && from < num_items && state.target != from {
+                    moved = Some(ListMove {
+                        from,
+                        to: state.target,
+                    });
+                }
+                state.dragged = None;
+            }
+        }
+
+        let pointer_pos = ui.ctx().pointer_interact_pos();
+        let animation_time = ui.style().animation_time;
+        let spacing = ui.spacing().item_spacing.y;
+
+        let response = ui
+            .vertical(|ui| {
+                if let (Some(dragged), Some(pointer_pos)) = (state.dragged, pointer_pos) {
+                    let center_y = pointer_pos.y - state.grab_offset.y - ui.cursor().top()
+                        + 0.5 * state.heights[dragged];
+                    state.target = insertion_index(&state.heights, dragged, center_y, spacing);
+                    auto_scroll(ui, pointer_pos.y);
+                }
+
+                // Where the next item is, not counting the dragged one:
+                let mut position = 0;
+
+                for index in 0..num_items {
+                    if state.dragged == Some(index) {
+                        // Paint the item on top of everything, where the mouse is:
+                        let layer_id = LayerId::new(Order::Tooltip, item_id(index));
+                        let mut item_ui = new_item_ui(ui, layer_id, item_id(index), ui.max_rect());
+                        add_item(&mut item_ui, index);
+                        let rect = item_ui.min_rect();
+                        if let Some(pointer_pos) = pointer_pos {
+                            let delta = pointer_pos - state.grab_offset - rect.min;
+                            ui.ctx().transform_layer_shapes(
+                                layer_id,
+                                TSTransform::from_translation(delta),
+                            );
+                        }
+                        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+                        continue;
+                    }
+
+                    add_gap(ui, id, &state, position, animation_time);
+
+                    // The same `Ui` id as when dragged, so the ids of the contents don't change:
+                    let mut item_ui = new_item_ui(
+                        ui,
+                        ui.layer_id(),
+                        item_id(index),
+                        ui.available_rect_before_wrap(),
+                    );
+                    add_item(&mut item_ui, index);
+                    // Behind the contents, so that they can still be clicked:
+                    let response = item_ui
+                        .interact_bg(Sense::drag() | Sense::focusable_noninteractive())
+                        .on_hover_cursor(CursorIcon::Grab);
+                    let rect = ui.allocate_rect(response.rect, Sense::hover()).rect;
+                    state.heights[index] = rect.height();
+
+                    // Only pick up the item once the pointer has moved a bit,
+                    // so that a press on a widget inside it doesn't start a drag:
+                    let is_decidedly_dragging =
+                        response.dragged() && ui.input(|i| i.pointer.is_decidedly_dragging());
+                    if is_decidedly_dragging && state.dragged.is_none() {
+                        if let Some(press_origin) = ui.input(|i| i.pointer.press_origin()) {
+                            state.dragged = Some(index);
+                            state.grab_offset = press_origin - rect.min;
+                            state.target = index;
+                            state.drag_count += 1;
+                        }
+                    }
+
+                    if response.has_focus() {
+                        ui.painter().rect_stroke(
+                            rect,
+                            ui.visuals().widgets.noninteractive.rounding,
+                            ui.visuals().selection.stroke,
+                        );
+
+                        let (up, down) = ui.input_mut(|i| {
+                            (
+                                i.consume_key(Modifiers::ALT, Key::ArrowUp),
+                                i.consume_key(Modifiers::ALT, Key::ArrowDown),
+                            )
+                        });
+                        let to = if up {
+                            index.checked_sub(1)
+                        } else if down {
+                            Some(index + 1).filter(|&to| to < num_items)
+                        } else {
+                            None
+                        };
+                        if let Some(to) = to {
+                            moved = Some(ListMove { from: index, to });
+                            ui.memory_mut(|mem| mem.request_focus(item_id(to)));
+                        }
+                    }
+
+                    position += 1;
+                }
+
+                add_gap(ui, id, &state, position, animation_time);
+            })
+            .response;
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        ReorderableListResponse { response, moved }
+    }
+}
+
+/// The [`Ui`] for the contents of an item, with the same id whether it is dragged or not.
+///
+/// Its id is also used to drag and focus the item.
+fn new_item_ui(ui: &Ui, layer_id: LayerId, id: Id, max_rect: Rect) -> Ui {
+    let mut item_ui = Ui::new(
+        ui.ctx().clone(),
+        layer_id,
+        id,
+        max_rect,
+        ui.clip_rect(),
+        UiStackInfo::default(),
+    );
+    item_ui.set_style(ui.style().clone());
+    if !ui.is_enabled() {
+        item_ui.disable();
+    }
+    item_ui
+}
+
+/// While dragging, add space where the dragged item would be dropped,
+/// if that is in front of the item at `position` (not counting the dragged item).
+fn add_gap(ui: &mut Ui, id: Id, state: &ReorderState, position: usize, animation_time: f32) {
+    let Some(dragged) = state.dragged else {
+        return;
+    };
+
+    let target_height = if state.target == position {
+        state.heights[dragged] + ui.spacing().item_spacing.y
+    } else {
+        0.0
+    };
+    let height = ui.ctx().animate_value_with_time(
+        id.with(("gap", position, state.drag_count)),
+        target_height,
+        animation_time,
+    );
+    if 0.0 < height {
+        ui.add_space(height);
+    }
+}
+
+/// Where an item ends up when dropped with its center at `center_y`,
+/// relative to the top of the list.
+fn insertion_index(heights: &[f32], dragged: usize, center_y: f32, spacing: f32) -> usize {
+    let mut top = 0.0;
+    let mut index = 0;
+    for (i, height) in heights.iter().enumerate() {
+        if i == dragged {
+            continue;
+        }
+        if top + 0.5 * height < center_y {
+            index += 1;
+        }
+        top += height + spacing;
+    }
+    index
+}
+
+/// Scroll the surrounding [`egui::ScrollArea`] when dragging close to its top or bottom.
+fn auto_scroll(ui: &Ui, pointer_y: f32) {
+    /// In points per second, when at the very edge.
+    const MAX_SPEED: f32 = 800.0;
+
+    let clip_rect = ui.clip_rect();
+    let margin = 2.0 * ui.spacing().interact_size.y;
+    let speed = if pointer_y < clip_rect.top() + margin {
+        (clip_rect.top() + margin - pointer_y) / margin
+    } else if clip_rect.bottom() - margin < pointer_y {
+        (clip_rect.bottom() - margin - pointer_y) / margin
+    } else {
+        return;
+    };
+
+    let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+    ui.scroll_with_delta(Vec2::new(0.0, speed.clamp(-1.0, 1.0) * MAX_SPEED * dt));
+    ui.ctx().request_repaint();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insertion_index() {
+        let heights = [10.0, 20.0, 10.0];
+
+        // Dragging the first item:
+        assert_eq!(insertion_index(&heights, 0, 0.0, 0.0), 0);
+        assert_eq!(insertion_index(&heights, 0, 9.0, 0.0), 0);
+        assert_eq!(insertion_index(&heights, 0, 11.0, 0.0), 1);
+        assert_eq!(insertion_index(&heights, 0, 26.0, 0.0), 2);
+
+        // Dragging the last item, with spacing:
+        assert_eq!(insertion_index(&heights, 2, 100.0, 5.0), 2);
+        assert_eq!(insertion_index(&heights, 2, 19.0, 5.0), 1);
+        assert_eq!(insertion_index(&heights, 2, -5.0, 5.0), 0);
+    }
+
+    #[test]
+    fn test_drag_item_with_button() {
+        use egui::{Event, PointerButton, Pos2, RawInput};
+
+        let ctx = egui::Context::default();
+        let mut items = vec!['a', 'b', 'c'];
+        let mut rects = vec![Rect::NOTHING; items.len()];
+        let mut clicked = None;
+        let mut moved = None;
+
+        let run = |events: Vec<Event>,
+                   items: &mut Vec<char>,
+                   rects: &mut Vec<Rect>,
+                   clicked: &mut Option<char>,
+                   moved: &mut Option<ListMove>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let response = ReorderableList::new("list").show(ui, items.len(), |ui, i| {
+                        let response = ui.button(items[i].to_string());
+                        if response.clicked() {
+                            *clicked = Some(items[i]);
+                        }
+                        rects[i] = response.rect;
+                    });
+                    if let Some(list_move) = response.moved {
+                        *moved = Some(list_move);
+                        list_move.apply(items);
+                    }
+                });
+            });
+        };
+        let pointer = |pos: Pos2, pressed: Option<bool>| {
+            let mut events = vec![Event::PointerMoved(pos)];
+            if let Some(pressed) = pressed {
+                events.push(Event::PointerButton {
+                    pos,
+                    button: PointerButton::Primary,
+                    pressed,
+                    modifiers: Default::default(),
+                });
+            }
+            events
+        };
+
+        run(vec![], &mut items, &mut rects, &mut clicked, &mut moved);
+        let a = rects[0].center();
+        let below_c = rects[2].center() + Vec2::new(0.0, rects[2].height());
+
+        // Clicking the button doesn't move the item:
+        for events in [pointer(a, Some(true)), pointer(a, Some(false))] {
+            run(events, &mut items, &mut rects, &mut clicked, &mut moved);
+        }
+        assert_eq!(clicked, Some('a'));
+        assert_eq!(moved, None);
+
+        // Dragging it does:
+        clicked = None;
+        for events in [
+            pointer(a, Some(true)),
+            pointer(a + Vec2::new(0.0, 10.0), None),
+            pointer(below_c, None),
+            pointer(below_c, Some(false)),
+        ] {
+            run(events, &mut items, &mut rects, &mut clicked, &mut moved);
+        }
+        assert_eq!(clicked, None);
+        assert_eq!(moved, Some(ListMove { from: 0, to: 2 }));
+        assert_eq!(items, ['b', 'c', 'a']);
+    }
+
+    #[test]
+    fn test_list_move() {
+        let mut items = vec!['a', 'b', 'c', 'd'];
+        ListMove { from: 0, to: 2 }.apply(&mut items);
+        assert_eq!(items, ['b', 'c', 'a', 'd']);
+        ListMove { from: 3, to: 0 }.apply(&mut items);
+        assert_eq!(items, ['d', 'b', 'c', 'a']);
+    }
+}
@@ -0,0 +1,852 @@
+use std::ops::Range;
+
+use egui::{
+    text::{LayoutJob, TextFormat},
+    Color32, FontFamily, FontId, FontSelection, Id, Key, KeyboardShortcut, Modifiers, Response,
+    Stroke, TextEdit, Ui, Widget, WidgetText,
+};
+
+/// The formatting of a [`RichTextSpan`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RichTextStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+
+    /// `None` means the default text color.
+    pub color: Option<Color32>,
+
+    /// If set, the text is a link to this url.
+    pub link: Option<String>,
+}
+
+/// A piece of text with the same [`RichTextStyle`] throughout.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RichTextSpan {
+    pub text: String,
+    pub style: RichTextStyle,
+}
+
+impl RichTextSpan {
+    pub fn new(text: impl Into<String>, style: RichTextStyle) -> Self {
+        Self {
+            text: text.into(),
+            style,
+        }
+    }
+}
+
+/// Styled text edited by [`RichTextEdit`]: a list of [`RichTextSpan`]s.
+///
+/// Neighboring spans always have different styles, and no span is empty.
+///
+/// The formatting commands (like [`Self::toggle_bold`]) work on the selection,
+/// which is updated by the [`RichTextEdit`], so you can call them from your own toolbar.
+/// With nothing selected, they change the style of the text typed next.
+///
+/// All indices are in characters, not bytes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RichTextDocument {
+    spans: Vec<RichTextSpan>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    selection: Range<usize>,
+
+    /// Set when formatting is changed with nothing selected.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    typing_style: Option<RichTextStyle>,
+}
+
+impl RichTextDocument {
+    /// A document with unformatted text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self::from_spans([RichTextSpan::new(text, RichTextStyle::default())])
+    }
+
+    pub fn from_spans(spans: impl IntoIterator<Item = RichTextSpan>) -> Self {
+        let mut document = Self {
+            spans: spans.into_iter().collect(),
+            ..Default::default()
+        };
+        document.normalize();
+        document
+    }
+
+    #[inline]
+    pub fn spans(&self) -> &[RichTextSpan] {
+        &self.spans
+    }
+
+    /// The text without formatting.
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Number of characters in the document.
+    pub fn char_count(&self) -> usize {
+        self.spans
+            .iter()
+            .map(|span| span.text.chars().count())
+            .sum()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// The selected range of characters, or the cursor position if the range is empty.
+    #[inline]
+    pub fn selection(&self) -> Range<usize> {
+        self.selection.clone()
+    }
+
+    /// This is called by [`RichTextEdit`], but you can also use it to format text from code.
+    pub fn set_selection(&mut self, selection: Range<usize>) {
+        if selection != self.selection {
+            self.typing_style = None;
+        }
+        self.selection = selection;
+    }
+
+    /// The style of the character at `char_index`.
+    ///
+    /// Past the end, this is the style of the last character.
+    pub fn style_at(&self, char_index: usize) -> RichTextStyle {
+        let mut start = 0;
+        for span in &self.spans {
+            start += span.text.chars().count();
+            if char_index < start {
+                return span.style.clone();
+            }
+        }
+        self.spans
+            .last()
+            .map(|span| span.style.clone())
+            .unwrap_or_default()
+    }
+
+    /// The style of the selection, or of the text typed next if nothing is selected.
+    ///
+    /// Use this to show which formatting buttons of a toolbar are active.
+    pub fn current_style(&self) -> RichTextStyle {
+        if let Some(style) = &self.typing_style {
+            style.clone()
+        } else if self.selection.is_empty() {
+            self.inherited_style(self.selection.start)
+        } else {
+            self.style_at(self.selection.start)
+        }
+    }
+
+    /// Change the style of a range of characters.
+    pub fn edit_style(&mut self, range: Range<usize>, edit: impl Fn(&mut RichTextStyle)) {
+        let range = range.start.min(range.end)..range.end;
+        if range.is_empty() {
+            return;
+        }
+        let first = self.split_at(range.start);
+        let last = self.split_at(range.end);
+        for span in &mut self.spans[first..last] {
+            edit(&mut span.style);
+        }
+        self.normalize();
+    }
+
+    /// Make the selection bold, or not bold if it already is.
+    pub fn toggle_bold(&mut self) {
+        self.toggle(|style| &mut style.bold);
+    }
+
+    /// Make the selection italic, or not italic if it already is.
+    pub fn toggle_italic(&mut self) {
+        self.toggle(|style| &mut style.italic);
+    }
+
+    /// Underline the selection, or remove the underline if it already has one.
+    pub fn toggle_underline(&mut self) {
+        self.toggle(|style| &mut style.underline);
+    }
+
+    /// Strike through the selection, or remove the strikethrough if it already has one.
+    pub fn toggle_strikethrough(&mut self) {
+        self.toggle(|style| &mut style.strikethrough);
+    }
+
+    /// Set the color of the selection. `None` means the default text color.
+    pub fn set_color(&mut self, color: Option<Color32>) {
+        self.edit_selection_style(|style| style.color = color);
+    }
+
+    /// Turn the selection into a link, or remove the link with `None`.
+    pub fn set_link(&mut self, url: Option<&str>) {
+        self.edit_selection_style(|style| style.link = url.map(ToOwned::to_owned));
+    }
+
+    fn toggle(&mut self, flag: fn(&mut RichTextStyle) -> &mut bool) {
+        let all_set = if self.selection.is_empty() {
+            *flag(&mut self.current_style())
+        } else {
+            self.spans_in(self.selection.clone())
+                .all(|span| *flag(&mut span.style.clone()))
+        };
+        self.edit_selection_style(|style| *flag(style) = !all_set);
+    }
+
+    fn edit_selection_style(&mut self, edit: impl Fn(&mut RichTextStyle)) {
+        if self.selection.is_empty() {
+            let mut style = self.current_style();
+            edit(&mut style);
+            self.typing_style = Some(style);
+        } else {
+            self.edit_style(self.selection.clone(), edit);
+        }
+    }
+
+    /// The spans that overlap the given range of characters.
+    fn spans_in(&self, range: Range<usize>) -> impl Iterator<Item = &RichTextSpan> {
+        let mut start = 0;
+        self.spans.iter().filter(move |span| {
+            let span_start = start;
+            start += span.text.chars().count();
+            span_start < range.end && range.start < start
+        })
+    }
+
+    /// The style of text inserted at `char_index`: that of the character in front of it,
+    /// but not continuing a link past its end.
+    fn inherited_style(&self, char_index: usize) -> RichTextStyle {
+        let mut style = self.style_at(char_index.saturating_sub(1));
+        let inside_link = 0 < char_index
+            && char_index < self.char_count()
+            && self.style_at(char_index).link == style.link;
+        if !inside_link {
+            style.link = None;
+        }
+        style
+    }
+
+    /// Update the document after its text was edited to `new_text`.
+    ///
+    /// `cursor` is the character index of the cursor in `new_text` after the edit,
+    /// e.g. from [`egui::text_edit::TextEditOutput::cursor_range`].
+    /// It tells where the edit was when that is ambiguous,
+    /// e.g. which letter was typed when typing a letter next to the same letter.
+    /// Without it, the edit is guessed from the selection before the edit (see [`Self::set_selection`]).
+    ///
+    /// Deleted text takes its formatting with it,
+    /// and inserted text gets the formatting of the text it replaced or was typed after.
+    pub fn apply_edit(&mut self, new_text: &str, cursor: Option<usize>) {
+        let Some(Edit {
+            deleted,
+            inserted,
+            style,
+        }) = self.find_edit(new_text, cursor)
+        else {
+            return;
+        };
+
+        self.typing_style = None;
+        let first = self.split_at(deleted.start);
+        let last = self.split_at(deleted.end);
+        self.spans
+            .splice(first..last, [RichTextSpan::new(inserted, style)]);
+        self.normalize();
+    }
+
+    /// What changed from the current text to `new_text`, or `None` if nothing did.
+    ///
+    /// Found by comparing the start and end of the old and new text.
+    /// See [`Self::apply_edit`] for `cursor`.
+    fn find_edit<'t>(&self, new_text: &'t str, cursor: Option<usize>) -> Option<Edit<'t>> {
+        let old_chars = || self.spans.iter().flat_map(|span| span.text.chars());
+        let old_len = self.char_count();
+        let new_len = new_text.chars().count();
+
+        let common_prefix = old_chars()
+            .zip(new_text.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        if common_prefix == old_len && common_prefix == new_len {
+            return None;
+        }
+        let common_suffix = |prefix: usize| {
+            old_chars()
+                .rev()
+                .zip(new_text.chars().rev())
+                .take(old_len.min(new_len) - prefix)
+                .take_while(|(a, b)| a == b)
+                .count()
+        };
+
+        // The same edit can often be in several places, e.g. when typing a letter next to the same letter.
+        // Prefer the place where the user typed:
+        let smallest_suffix = common_suffix(common_prefix);
+        let num_deleted = old_len - common_prefix - smallest_suffix;
+        let num_inserted = new_len - common_prefix - smallest_suffix;
+        let edit_start = if let Some(cursor) = cursor {
+            // The cursor ends up after the inserted text:
+            cursor.saturating_sub(num_inserted)
+        } else if num_inserted == 0 && self.selection.is_empty() {
+            // Probably backspace:
+            self.selection.start.saturating_sub(num_deleted)
+        } else {
+            self.selection.start
+        };
+        let mut prefix = common_prefix.min(edit_start);
+        let mut suffix = common_suffix(prefix);
+        if prefix + suffix < common_prefix + smallest_suffix {
+            // The edit can't be there, e.g. after an undo.
+            prefix = common_prefix;
+            suffix = smallest_suffix;
+        }
+
+        let deleted = prefix..old_len - suffix;
+        let style = self.typing_style.clone().unwrap_or_else(|| {
+            if deleted.is_empty() {
+                self.inherited_style(prefix)
+            } else {
+                self.style_at(prefix)
+            }
+        });
+
+        Some(Edit {
+            deleted,
+            inserted: char_slice(new_text, prefix..new_len - suffix),
+            style,
+        })
+    }
+
+    /// The parts of the spans inside the given range of characters.
+    fn slices(&self, range: Range<usize>) -> impl Iterator<Item = (&str, &RichTextStyle)> {
+        let mut start = 0;
+        self.spans.iter().filter_map(move |span| {
+            let span_range = start..start + span.text.chars().count();
+            start = span_range.end;
+            let from = range.start.clamp(span_range.start, span_range.end) - span_range.start;
+            let to = range.end.clamp(span_range.start, span_range.end) - span_range.start;
+            (from < to).then(|| (char_slice(&span.text, from..to), &span.style))
+        })
+    }
+
+    /// Make sure a span starts at `char_index`, and return its index.
+    fn split_at(&mut self, char_index: usize) -> usize {
+        let mut start = 0;
+        for i in 0..self.spans.len() {
+            if char_index <= start {
+                return i;
+            }
+            let len = self.spans[i].text.chars().count();
+            if char_index < start + len {
+                let span = &mut self.spans[i];
+                let byte_index = span
+                    .text
+                    .char_indices()
+                    .nth(char_index - start)
+                    .map_or(span.text.len(), |(byte_index, _)| byte_index);
+                let tail = RichTextSpan::new(span.text.split_off(byte_index), span.style.clone());
+                self.spans.insert(i + 1, tail);
+                return i + 1;
+            }
+            start += len;
+        }
+        self.spans.len()
+    }
+
+    /// Remove empty spans, and merge neighbors with the same style.
+    fn normalize(&mut self) {
+        self.spans.retain(|span| !span.text.is_empty());
+        self.spans.dedup_by(|next, previous| {
+            if next.style == previous.style {
+                previous.text.push_str(&next.text);
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    /// How to show the document, with the given edit applied.
+    ///
+    /// Bold text uses `bold_family` if set, and [`egui::Visuals::strong_text_color`] otherwise.
+    fn layout_job(
+        &self,
+        edit: Option<&Edit<'_>>,
+        ui: &Ui,
+        font_id: &FontId,
+        bold_family: Option<&FontFamily>,
+        wrap_width: f32,
+    ) -> LayoutJob {
+        let visuals = ui.visuals();
+        let mut job = LayoutJob::default();
+        job.wrap.max_width = wrap_width;
+
+        let sections: Vec<(&str, &RichTextStyle)> = if let Some(edit) = edit {
+            (self.slices(0..edit.deleted.start))
+                .chain(std::iter::once((edit.inserted, &edit.style)))
+                .chain(self.slices(edit.deleted.end..usize::MAX))
+                .collect()
+        } else {
+            (self.spans.iter())
+                .map(|span| (span.text.as_str(), &span.style))
+                .collect()
+        };
+
+        for (text, style) in sections {
+            let color = if let Some(color) = style.color {
+                color
+            } else if style.link.is_some() {
+                visuals.hyperlink_color
+            } else if style.bold && bold_family.is_none() {
+                visuals.strong_text_color()
+            } else {
+                visuals.text_color()
+            };
+
+            let mut font_id = font_id.clone();
+            if let (true, Some(bold_family)) = (style.bold, bold_family) {
+                font_id.family = bold_family.clone();
+            }
+
+            let line = Stroke::new(1.0, color);
+            let format = TextFormat {
+                font_id,
+                color,
+                italics: style.italic,
+                underline: if style.underline || style.link.is_some() {
+                    line
+                } else {
+                    Stroke::NONE
+                },
+                strikethrough: if style.strikethrough {
+                    line
+                } else {
+                    Stroke::NONE
+                },
+                ..Default::default()
+            };
+            job.append(text, 0.0, format);
+        }
+
+        job
+    }
+}
+
+/// A change to the text of a [`RichTextDocument`].
+struct Edit<'t> {
+    /// The replaced characters of the old text.
+    deleted: Range<usize>,
+
+    inserted: &'t str,
+
+    /// The style of the inserted text.
+    style: RichTextStyle,
+}
+
+/// The given range of characters of `text`.
+fn char_slice(text: &str, range: Range<usize>) -> &str {
+    let byte_index = |char_index| {
+        text.char_indices()
+            .nth(char_index)
+            .map_or(text.len(), |(byte_index, _)| byte_index)
+    };
+    &text[byte_index(range.start)..byte_index(range.end)]
+}
+
+// ----------------------------------------------------------------------------
+
+/// A multiline text editor for a [`RichTextDocument`], with bold, italic, underline, strikethrough,
+/// colors and links.
+///
+/// Keyboard shortcuts: `Cmd+B` bold, `Cmd+I` italic, `Cmd+U` underline.
+/// `Cmd`-click a link to open it.
+///
+/// With [`Self::toolbar`] there are buttons for the formatting above the text.
+/// To make your own toolbar, call e.g. [`RichTextDocument::toggle_bold`] when a button is clicked.
+///
+/// The built-in undo (`Cmd+Z`) only remembers the text, not the formatting.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut document = egui_extras::RichTextDocument::new("Hello world!");
+/// ui.add(egui_extras::RichTextEdit::new(&mut document).toolbar(true));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct RichTextEdit<'a> {
+    document: &'a mut RichTextDocument,
+    id_source: Option<Id>,
+    font: FontSelection,
+    bold_family: Option<FontFamily>,
+    hint_text: WidgetText,
+    desired_width: Option<f32>,
+    desired_rows: usize,
+    toolbar: bool,
+}
+
+impl<'a> RichTextEdit<'a> {
+    pub fn new(document: &'a mut RichTextDocument) -> Self {
+        Self {
+            document,
+            id_source: None,
+            font: FontSelection::default(),
+            bold_family: None,
+            hint_text: WidgetText::default(),
+            desired_width: None,
+            desired_rows: 4,
+            toolbar: false,
+        }
+    }
+
+    /// Must be set if multiple [`RichTextEdit`]s are in the same [`Ui`].
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// The font of the text. Default: [`egui::TextStyle::Body`].
+    #[inline]
+    pub fn font(mut self, font: impl Into<FontSelection>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Show bold text with this font family.
+    ///
+    /// By default egui has no bold font, so bold text is shown with a stronger color instead.
+    #[inline]
+    pub fn bold_family(mut self, bold_family: FontFamily) -> Self {
+        self.bold_family = Some(bold_family);
+        self
+    }
+
+    /// Show a faint hint text when the document is empty.
+    #[inline]
+    pub fn hint_text(mut self, hint_text: impl Into<WidgetText>) -> Self {
+        self.hint_text = hint_text.into();
+        self
+    }
+
+    /// See [`TextEdit::desired_width`].
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    /// See [`TextEdit::desired_rows`]. Default: 4.
+    #[inline]
+    pub fn desired_rows(mut self, desired_rows: usize) -> Self {
+        self.desired_rows = desired_rows;
+        self
+    }
+
+    /// Show buttons for the formatting above the text.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn toolbar(mut self, toolbar: bool) -> Self {
+        self.toolbar = toolbar;
+        self
+    }
+}
+
+impl<'a> Widget for RichTextEdit<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            document,
+            id_source,
+            font,
+            bold_family,
+            hint_text,
+            desired_width,
+            desired_rows,
+            toolbar,
+        } = self;
+
+        let id = id_source.map_or_else(
+            || ui.next_auto_id().with("rich_text_edit"),
+            |id_source| ui.make_persistent_id(id_source),
+        );
+        let has_focus = ui.memory(|mem| mem.has_focus(id));
+
+        if toolbar && toolbar_ui(ui, document) {
+            // Give the focus back, so the selection is kept:
+            ui.memory_mut(|mem| mem.request_focus(id));
+        }
+
+        if has_focus {
+            // Before the `TextEdit` sees them, since `Ctrl+U` also deletes text in a `TextEdit`.
+            let shortcut = |key| KeyboardShortcut::new(Modifiers::COMMAND, key);
+            let (bold, italic, underline) = ui.input_mut(|i| {
+                (
+                    i.consume_shortcut(&shortcut(Key::B)),
+                    i.consume_shortcut(&shortcut(Key::I)),
+                    i.consume_shortcut(&shortcut(Key::U)),
+                )
+            });
+            if bold {
+                document.toggle_bold();
+            }
+            if italic {
+                document.toggle_italic();
+            }
+            if underline {
+                document.toggle_underline();
+            }
+        }
+
+        let font_id = font.resolve(ui.style());
+        let mut text = document.text();
+        let output = {
+            let document = &*document;
+            let mut layouter = |ui: &Ui, string: &str, wrap_width: f32| {
+                // While the text is being edited, show what the document will look like:
+                let edit = document.find_edit(string, None);
+                let job = document.layout_job(
+                    edit.as_ref(),
+                    ui,
+                    &font_id,
+                    bold_family.as_ref(),
+                    wrap_width,
+                );
+                ui.fonts(|f| f.layout_job(job))
+            };
+
+            let mut text_edit = TextEdit::multiline(&mut text)
+                .id(id)
+                .font(font_id.clone())
+                .hint_text(hint_text)
+                .desired_rows(desired_rows)
+                .layouter(&mut layouter);
+            if let Some(desired_width) = desired_width {
+                text_edit = text_edit.desired_width(desired_width);
+            }
+            text_edit.show(ui)
+        };
+
+        let cursor = (output.cursor_range).map(|cursor_range| cursor_range.primary.ccursor.index);
+        document.apply_edit(&text, cursor);
+        if let Some(cursor_range) = output.cursor_range {
+            document.set_selection(cursor_range.as_sorted_char_range());
+        }
+
+        if output.response.clicked() && ui.input(|i| i.modifiers.command) {
+            if let Some(pointer_pos) = output.response.interact_pointer_pos() {
+                let cursor = output
+                    .galley
+                    .cursor_from_pos(pointer_pos - output.galley_pos);
+                if let Some(url) = document.style_at(cursor.ccursor.index).link {
+                    ui.ctx().open_url(egui::OpenUrl::new_tab(url));
+                }
+            }
+        }
+
+        output.response
+    }
+}
+
+/// Returns `true` if a button was clicked.
+fn toolbar_ui(ui: &mut Ui, document: &mut RichTextDocument) -> bool {
+    let style = document.current_style();
+    let mut color = style.color.unwrap_or_else(|| ui.visuals().text_color());
+
+    ui.horizontal(|ui| {
+        let mut clicked = false;
+        let toggle = |ui: &mut Ui, on: bool, text: egui::RichText, hover: &str| {
+            ui.selectable_label(on, text).on_hover_text(hover).clicked()
+        };
+
+        if toggle(ui, style.bold, egui::RichText::new("B").strong(), "Bold") {
+            document.toggle_bold();
+            clicked = true;
+        }
+        if toggle(
+            ui,
+            style.italic,
+            egui::RichText::new("I").italics(),
+            "Italic",
+        ) {
+            document.toggle_italic();
+            clicked = true;
+        }
+        if toggle(
+            ui,
+            style.underline,
+            egui::RichText::new("U").underline(),
+            "Underline",
+        ) {
+            document.toggle_underline();
+            clicked = true;
+        }
+        if toggle(
+            ui,
+            style.strikethrough,
+            egui::RichText::new("S").strikethrough(),
+            "Strikethrough",
+        ) {
+            document.toggle_strikethrough();
+            clicked = true;
+        }
+
+        if ui
+            .color_edit_button_srgba(&mut color)
+            .on_hover_text("Text color")
+            .changed()
+        {
+            document.set_color(Some(color));
+            clicked = true;
+        }
+        if style.color.is_some() && ui.button("Default color").clicked() {
+            document.set_color(None);
+            clicked = true;
+        }
+
+        clicked
+    })
+    .inner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold() -> RichTextStyle {
+        RichTextStyle {
+            bold: true,
+            ..Default::default()
+        }
+    }
+
+    fn spans(document: &RichTextDocument) -> Vec<(&str, bool)> {
+        document
+            .spans()
+            .iter()
+            .map(|span| (span.text.as_str(), span.style.bold))
+            .collect()
+    }
+
+    #[test]
+    fn test_edit_style() {
+        let mut document = RichTextDocument::new("Hello world");
+        document.edit_style(6..11, |style| style.bold = true);
+        assert_eq!(spans(&document), [("Hello ", false), ("world", true)]);
+
+        document.edit_style(0..6, |style| style.bold = true);
+        assert_eq!(spans(&document), [("Hello world", true)]);
+
+        document.edit_style(2..4, |style| style.bold = false);
+        assert_eq!(
+            spans(&document),
+            [("He", true), ("ll", false), ("o world", true)]
+        );
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mut document = RichTextDocument::from_spans([
+            RichTextSpan::new("ab", bold()),
+            RichTextSpan::new("cd", RichTextStyle::default()),
+        ]);
+
+        // Partly bold becomes all bold:
+        document.set_selection(1..3);
+        document.toggle_bold();
+        assert_eq!(spans(&document), [("abc", true), ("d", false)]);
+
+        // All bold becomes not bold:
+        document.toggle_bold();
+        assert_eq!(spans(&document), [("a", true), ("bcd", false)]);
+    }
+
+    #[test]
+    fn test_apply_edit() {
+        let mut document = RichTextDocument::from_spans([
+            RichTextSpan::new("Hello ", RichTextStyle::default()),
+            RichTextSpan::new("wörld", bold()),
+        ]);
+
+        // Typing at the end of a span continues its style:
+        document.apply_edit("Hello wörld!", None);
+        assert_eq!(spans(&document), [("Hello ", false), ("wörld!", true)]);
+
+        // Deleting across spans:
+        document.apply_edit("Hellö!", None);
+        assert_eq!(spans(&document), [("Hellö", false), ("!", true)]);
+
+        // Replacing gets the style of the replaced text:
+        document.apply_edit("Hi!", None);
+        assert_eq!(spans(&document), [("Hi", false), ("!", true)]);
+    }
+
+    #[test]
+    fn test_apply_edit_at_cursor() {
+        // Typing a bold 'a' in front of "abc":
+        let mut document = RichTextDocument::new("abc");
+        document.set_selection(0..0);
+        document.toggle_bold();
+        document.apply_edit("aabc", Some(1));
+        assert_eq!(spans(&document), [("a", true), ("abc", false)]);
+
+        // Backspace or delete between two equal letters:
+        let aa = RichTextDocument::from_spans([
+            RichTextSpan::new("a", RichTextStyle::default()),
+            RichTextSpan::new("a", bold()),
+        ]);
+        let mut document = aa.clone();
+        document.apply_edit("a", Some(0));
+        assert_eq!(spans(&document), [("a", true)]);
+        let mut document = aa;
+        document.apply_edit("a", Some(1));
+        assert_eq!(spans(&document), [("a", false)]);
+
+        // Without a cursor, the selection from before the edit is used:
+        let mut document = RichTextDocument::new("abc");
+        document.set_selection(0..0);
+        document.toggle_bold();
+        document.apply_edit("aabc", None);
+        assert_eq!(spans(&document), [("a", true), ("abc", false)]);
+
+        // A cursor where the edit can't be doesn't make the edit bigger:
+        let mut document = RichTextDocument::from_spans([
+            RichTextSpan::new("ab", bold()),
+            RichTextSpan::new("c", RichTextStyle::default()),
+        ]);
+        document.apply_edit("abcd", Some(0));
+        assert_eq!(spans(&document), [("ab", true), ("cd", false)]);
+    }
+
+    #[test]
+    fn test_typing_style() {
+        let mut document = RichTextDocument::new("ab");
+        document.set_selection(2..2);
+        document.toggle_bold();
+        assert!(document.current_style().bold);
+        document.apply_edit("abc", None);
+        assert_eq!(spans(&document), [("ab", false), ("c", true)]);
+
+        // Moving the cursor forgets the typing style:
+        document.set_selection(1..1);
+        document.toggle_italic();
+        document.set_selection(0..0);
+        document.apply_edit("_abc", None);
+        assert!(!document.style_at(0).italic);
+    }
+
+    #[test]
+    fn test_links_do_not_grow() {
+        let link = RichTextStyle {
+            link: Some("https://www.egui.rs".to_owned()),
+            ..Default::default()
+        };
+        let mut document = RichTextDocument::from_spans([RichTextSpan::new("egui", link)]);
+        document.apply_edit("egui rocks", None);
+        assert_eq!(document.spans().len(), 2);
+        assert_eq!(document.spans()[1].style.link, None);
+    }
+}
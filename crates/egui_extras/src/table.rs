@@ -165,6 +165,114 @@ fn to_sizing(columns: &[Column]) -> crate::sizing::Sizing {
 
 // -----------------------------------------------------------------=----------
 
+/// See [`TableBuilder::reorderable_rows`].
+type OnRowReorder<'a> = Box<dyn FnOnce(usize, usize) + 'a>;
+
+/// What to show in front of the first cell of a row.
+#[derive(Clone, Copy)]
+enum RowHandle {
+    /// Rows can't be reordered.
+    None,
+
+    /// Empty space, to line up the header with the rows.
+    Blank,
+
+    /// A handle for dragging the row, storing a [`RowDragState`] under this id.
+    Grip(egui::Id),
+}
+
+/// A row that is being dragged, stored in temporary memory.
+#[derive(Clone, Copy, Debug)]
+struct RowDragState {
+    /// The index of the row being dragged.
+    from: usize,
+
+    /// Insert the row before this row index when dropped.
+    target: Option<usize>,
+}
+
+fn row_handle_width(ui: &Ui) -> f32 {
+    ui.spacing().icon_width
+}
+
+fn row_handle_ui(ui: &Ui, row_drag_id: egui::Id, row_index: usize) {
+    let rect = ui.max_rect();
+    let response = ui.interact(rect, row_drag_id.with(row_index), egui::Sense::drag());
+    if response.drag_started() {
+        ui.data_mut(|data| {
+            data.insert_temp(
+                row_drag_id,
+                RowDragState {
+                    from: row_index,
+                    target: None,
+                },
+            );
+        });
+    }
+    if response.hovered() {
+        ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+    }
+
+    // Three short lines:
+    let stroke = ui.style().interact(&response).fg_stroke;
+    let half_width = 0.3 * rect.width();
+    for dy in [-3.0, 0.0, 3.0] {
+        let y = rect.center().y + dy;
+        ui.painter().hline(
+            Rangef::new(rect.center().x - half_width, rect.center().x + half_width),
+            y,
+            stroke,
+        );
+    }
+}
+
+/// Auto-scroll while a row is dragged near the edges of the visible part of the table,
+/// and return `(from, to)` when it is dropped.
+fn update_row_drag(ui: &Ui, row_drag_id: egui::Id, clip_rect: Rect) -> Option<(usize, usize)> {
+    let drag = ui.data(|data| data.get_temp::<RowDragState>(row_drag_id))?;
+
+    let (pointer, released, dt) = ui.input(|i| {
+        (
+            i.pointer.latest_pos(),
+            !i.pointer.primary_down(),
+            i.stable_dt.min(0.1),
+        )
+    });
+
+    if released {
+        ui.data_mut(|data| data.remove::<RowDragState>(row_drag_id));
+        let target = drag.target?;
+        let to = if drag.from < target {
+            target - 1
+        } else {
+            target
+        };
+        return (to != drag.from).then_some((drag.from, to));
+    }
+
+    ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+
+    if let Some(pointer) = pointer {
+        let edge = 2.0 * ui.spacing().interact_size.y;
+        let max_speed = 1000.0; // points per second
+        let above = (clip_rect.top() + edge - pointer.y) / edge;
+        let below = (pointer.y - (clip_rect.bottom() - edge)) / edge;
+        let speed = if 0.0 < above {
+            max_speed * above.min(1.0)
+        } else if 0.0 < below {
+            -max_speed * below.min(1.0)
+        } else {
+            0.0
+        };
+        if speed != 0.0 {
+            ui.scroll_with_delta(egui::vec2(0.0, speed * dt));
+            ui.ctx().request_repaint();
+        }
+    }
+
+    None
+}
+
 struct TableScrollOptions {
     vscroll: bool,
     drag_to_scroll: bool,
@@ -231,12 +339,14 @@ impl Default for TableScrollOptions {
 /// ```
 pub struct TableBuilder<'a> {
     ui: &'a mut Ui,
+    id_source: Option<egui::Id>,
     columns: Vec<Column>,
     striped: Option<bool>,
     resizable: bool,
     cell_layout: egui::Layout,
     scroll_options: TableScrollOptions,
     sense: egui::Sense,
+    on_row_reorder: Option<OnRowReorder<'a>>,
 }
 
 impl<'a> TableBuilder<'a> {
@@ -244,15 +354,27 @@ impl<'a> TableBuilder<'a> {
         let cell_layout = *ui.layout();
         Self {
             ui,
+            id_source: None,
             columns: Default::default(),
             striped: None,
             resizable: false,
             cell_layout,
             scroll_options: Default::default(),
             sense: egui::Sense::hover(),
+            on_row_reorder: None,
         }
     }
 
+    /// Must be set if multiple tables are in the same [`Ui`].
+    ///
+    /// The column widths are stored under this id,
+    /// and the drag handles of [`Self::reorderable_rows`] are told apart by it.
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(egui::Id::new(id_source));
+        self
+    }
+
     /// Enable striped row background for improved readability.
     ///
     /// Default is whatever is in [`egui::Visuals::striped`].
@@ -376,6 +498,43 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
+    /// Let the user reorder the rows by dragging them by a handle in front of each row.
+    ///
+    /// While dragging, a line shows where the row will be inserted,
+    /// and the table scrolls when the pointer is near its top or bottom edge.
+    ///
+    /// When the row is dropped, `on_reorder(from, to)` is called with the index of the dragged row,
+    /// and the index it should have afterwards.
+    /// This works with [`TableBody::rows`] and [`TableBody::heterogeneous_rows`] too.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// let mut items = vec!["first", "second", "third"];
+    /// let mut reorder = None;
+    /// TableBuilder::new(ui)
+    ///     .column(Column::remainder())
+    ///     .reorderable_rows(|from, to| reorder = Some((from, to)))
+    ///     .body(|body| {
+    ///         body.rows(18.0, items.len(), |mut row| {
+    ///             let item = items[row.index()];
+    ///             row.col(|ui| {
+    ///                 ui.label(item);
+    ///             });
+    ///         });
+    ///     });
+    /// if let Some((from, to)) = reorder {
+    ///     let item = items.remove(from);
+    ///     items.insert(to, item);
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn reorderable_rows(mut self, on_reorder: impl FnOnce(usize, usize) + 'a) -> Self {
+        self.on_row_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
     /// What layout should we use for the individual cells?
     #[inline]
     pub fn cell_layout(mut self, cell_layout: egui::Layout) -> Self {
@@ -408,6 +567,11 @@ impl<'a> TableBuilder<'a> {
             } else {
                 0.0
             }
+            - if self.on_row_reorder.is_some() {
+                row_handle_width(self.ui) + self.ui.spacing().item_spacing.x
+            } else {
+                0.0
+            }
     }
 
     /// Create a header row which always stays visible and at the top
@@ -416,17 +580,19 @@ impl<'a> TableBuilder<'a> {
 
         let Self {
             ui,
+            id_source,
             columns,
             striped,
             resizable,
             cell_layout,
             scroll_options,
             sense,
+            on_row_reorder,
         } = self;
 
         let striped = striped.unwrap_or(ui.visuals().striped);
 
-        let state_id = ui.id().with("__table_state");
+        let (state_id, scroll_area_id) = table_ids(ui, id_source);
 
         let initial_widths =
             to_sizing(&columns).to_lengths(available_width, ui.spacing().item_spacing.x);
@@ -455,6 +621,11 @@ impl<'a> TableBuilder<'a> {
                 striped: false,
                 hovered: false,
                 selected: false,
+                handle: if on_row_reorder.is_some() {
+                    RowHandle::Blank
+                } else {
+                    RowHandle::None
+                },
                 response: &mut response,
             });
             layout.allocate_rect();
@@ -464,6 +635,7 @@ impl<'a> TableBuilder<'a> {
             ui,
             table_top,
             state_id,
+            scroll_area_id,
             columns,
             available_width,
             state,
@@ -474,6 +646,7 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            on_row_reorder,
         }
     }
 
@@ -486,17 +659,19 @@ impl<'a> TableBuilder<'a> {
 
         let Self {
             ui,
+            id_source,
             columns,
             striped,
             resizable,
             cell_layout,
             scroll_options,
             sense,
+            on_row_reorder,
         } = self;
 
         let striped = striped.unwrap_or(ui.visuals().striped);
 
-        let state_id = ui.id().with("__table_state");
+        let (state_id, scroll_area_id) = table_ids(ui, id_source);
 
         let initial_widths =
             to_sizing(&columns).to_lengths(available_width, ui.spacing().item_spacing.x);
@@ -511,6 +686,7 @@ impl<'a> TableBuilder<'a> {
             ui,
             table_top,
             state_id,
+            scroll_area_id,
             columns,
             available_width,
             state,
@@ -521,6 +697,7 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            on_row_reorder,
         }
         .body(add_body_contents);
     }
@@ -562,6 +739,19 @@ impl TableState {
 
 // ----------------------------------------------------------------------------
 
+/// The id of the [`TableState`], and of the [`ScrollArea`] unless it has the default one.
+///
+/// Without [`TableBuilder::id_source`] these are the ids of tables from before it existed,
+/// so that the stored column widths and scroll offsets are kept.
+fn table_ids(ui: &Ui, id_source: Option<egui::Id>) -> (egui::Id, Option<egui::Id>) {
+    if let Some(id_source) = id_source {
+        let state_id = ui.id().with(id_source);
+        (state_id, Some(state_id.with("__scroll_area")))
+    } else {
+        (ui.id().with("__table_state"), None)
+    }
+}
+
 /// Table struct which can construct a [`TableBody`].
 ///
 /// Is created by [`TableBuilder`] by either calling [`TableBuilder::body`] or after creating a header row with [`TableBuilder::header`].
@@ -569,6 +759,10 @@ pub struct Table<'a> {
     ui: &'a mut Ui,
     table_top: f32,
     state_id: egui::Id,
+
+    /// `None` for the default id of the [`ScrollArea`].
+    scroll_area_id: Option<egui::Id>,
+
     columns: Vec<Column>,
     available_width: f32,
     state: TableState,
@@ -584,6 +778,8 @@ pub struct Table<'a> {
     scroll_options: TableScrollOptions,
 
     sense: egui::Sense,
+
+    on_row_reorder: Option<OnRowReorder<'a>>,
 }
 
 impl<'a> Table<'a> {
@@ -603,6 +799,7 @@ impl<'a> Table<'a> {
             ui,
             table_top,
             state_id,
+            scroll_area_id,
             columns,
             resizable,
             mut available_width,
@@ -613,6 +810,7 @@ impl<'a> Table<'a> {
             cell_layout,
             scroll_options,
             sense,
+            on_row_reorder,
         } = self;
 
         let TableScrollOptions {
@@ -638,6 +836,9 @@ impl<'a> Table<'a> {
             .auto_shrink(auto_shrink)
             .scroll_bar_visibility(scroll_bar_visibility);
 
+        if let Some(scroll_area_id) = scroll_area_id {
+            scroll_area = scroll_area.id_source(scroll_area_id);
+        }
         if let Some(scroll_offset_y) = scroll_offset_y {
             scroll_area = scroll_area.vertical_scroll_offset(scroll_offset_y);
         }
@@ -646,6 +847,11 @@ impl<'a> Table<'a> {
         let widths_ref = &state.column_widths;
         let max_used_widths_ref = &mut max_used_widths;
 
+        let reorderable = on_row_reorder.is_some();
+        let row_drag_id = state_id.with("__table_row_drag");
+        let mut reordered_row = None;
+        let reordered_row_ref = &mut reordered_row;
+
         scroll_area.show(ui, move |ui| {
             let mut scroll_to_y_range = None;
 
@@ -676,6 +882,7 @@ impl<'a> Table<'a> {
                     scroll_to_y_range: &mut scroll_to_y_range,
                     hovered_row_index,
                     hovered_row_index_id,
+                    row_drag_id: reorderable.then_some(row_drag_id),
                 });
 
                 if scroll_to_row.is_some() && scroll_to_y_range.is_none() {
@@ -690,12 +897,23 @@ impl<'a> Table<'a> {
                 let align = scroll_to_row.and_then(|(_, a)| a);
                 ui.scroll_to_rect(rect, align);
             }
+
+            if reorderable {
+                *reordered_row_ref = update_row_drag(ui, row_drag_id, clip_rect);
+            }
         });
 
+        if let (Some((from, to)), Some(on_row_reorder)) = (reordered_row, on_row_reorder) {
+            on_row_reorder(from, to);
+        }
+
         let bottom = ui.min_rect().bottom();
 
         let spacing_x = ui.spacing().item_spacing.x;
         let mut x = cursor_position.x - spacing_x * 0.5;
+        if reorderable {
+            x += row_handle_width(ui) + spacing_x;
+        }
         for (i, column_width) in state.column_widths.iter_mut().enumerate() {
             let column = &columns[i];
             let column_is_resizable = column.resizable.unwrap_or(resizable);
@@ -821,6 +1039,9 @@ pub struct TableBody<'a> {
 
     /// Used to store the hovered row index between frames.
     hovered_row_index_id: egui::Id,
+
+    /// Where the [`RowDragState`] is stored, if rows can be reordered.
+    row_drag_id: Option<egui::Id>,
 }
 
 impl<'a> TableBody<'a> {
@@ -869,9 +1090,11 @@ impl<'a> TableBody<'a> {
             striped: self.striped && self.row_index % 2 == 0,
             hovered: self.hovered_row_index == Some(self.row_index),
             selected: false,
+            handle: self.row_drag_id.map_or(RowHandle::None, RowHandle::Grip),
             response: &mut response,
         });
         self.capture_hover_state(&response, self.row_index);
+        self.update_drop_target(self.row_index, top_y);
         let bottom_y = self.layout.cursor.y;
 
         if Some(self.row_index) == self.scroll_to_row {
@@ -938,6 +1161,7 @@ impl<'a> TableBody<'a> {
         let max_row = max_row.min(total_rows);
 
         for row_index in min_row..max_row {
+            let top_y = self.layout.cursor.y;
             let mut response: Option<Response> = None;
             add_row_content(TableRow {
                 layout: &mut self.layout,
@@ -950,9 +1174,11 @@ impl<'a> TableBody<'a> {
                 striped: self.striped && (row_index + self.row_index) % 2 == 0,
                 hovered: self.hovered_row_index == Some(row_index),
                 selected: false,
+                handle: self.row_drag_id.map_or(RowHandle::None, RowHandle::Grip),
                 response: &mut response,
             });
             self.capture_hover_state(&response, row_index);
+            self.update_drop_target(row_index, top_y);
         }
 
         if total_rows - max_row > 0 {
@@ -1019,6 +1245,7 @@ impl<'a> TableBody<'a> {
             if cursor_y >= scroll_offset_y {
                 // This row is visible:
                 self.add_buffer(old_cursor_y as f32); // skip all the invisible rows
+                let top_y = self.layout.cursor.y;
                 let mut response: Option<Response> = None;
                 add_row_content(TableRow {
                     layout: &mut self.layout,
@@ -1031,9 +1258,11 @@ impl<'a> TableBody<'a> {
                     striped: self.striped && (row_index + self.row_index) % 2 == 0,
                     hovered: self.hovered_row_index == Some(row_index),
                     selected: false,
+                    handle: self.row_drag_id.map_or(RowHandle::None, RowHandle::Grip),
                     response: &mut response,
                 });
                 self.capture_hover_state(&response, row_index);
+                self.update_drop_target(row_index, top_y);
                 break;
             }
         }
@@ -1041,6 +1270,7 @@ impl<'a> TableBody<'a> {
         // populate visible rows:
         for (row_index, row_height) in &mut enumerated_heights {
             let top_y = cursor_y;
+            let screen_top_y = self.layout.cursor.y;
             let mut response: Option<Response> = None;
             add_row_content(TableRow {
                 layout: &mut self.layout,
@@ -1053,9 +1283,11 @@ impl<'a> TableBody<'a> {
                 striped: self.striped && (row_index + self.row_index) % 2 == 0,
                 hovered: self.hovered_row_index == Some(row_index),
                 selected: false,
+                handle: self.row_drag_id.map_or(RowHandle::None, RowHandle::Grip),
                 response: &mut response,
             });
             self.capture_hover_state(&response, row_index);
+            self.update_drop_target(row_index, screen_top_y);
             cursor_y += (row_height + spacing.y) as f64;
 
             if Some(row_index) == self.scroll_to_row {
@@ -1104,6 +1336,39 @@ impl<'a> TableBody<'a> {
         self.layout.skip_space(egui::vec2(0.0, height));
     }
 
+    // While a row is being dragged, check if it would be dropped next to the row that was just
+    // added, between `top_y` and the cursor, and if so show where.
+    fn update_drop_target(&mut self, row_index: usize, top_y: f32) {
+        let Some(row_drag_id) = self.row_drag_id else {
+            return;
+        };
+        let ui = &mut *self.layout.ui;
+        let Some(mut drag) = ui.data(|data| data.get_temp::<RowDragState>(row_drag_id)) else {
+            return;
+        };
+        let Some(pointer) = ui.ctx().pointer_latest_pos() else {
+            return;
+        };
+
+        let half_spacing = 0.5 * ui.spacing().item_spacing.y;
+        let (top, bottom) = (top_y - half_spacing, self.layout.cursor.y - half_spacing);
+        let is_first_row = row_index == 0;
+        if (pointer.y < top && !is_first_row) || bottom <= pointer.y {
+            return;
+        }
+
+        let (target, y) = if pointer.y < 0.5 * (top + bottom) {
+            (row_index, top)
+        } else {
+            (row_index + 1, bottom)
+        };
+        drag.target = Some(target);
+        ui.data_mut(|data| data.insert_temp(row_drag_id, drag));
+
+        let stroke = egui::Stroke::new(2.0, ui.visuals().selection.stroke.color);
+        ui.painter().hline(self.layout.rect.x_range(), y, stroke);
+    }
+
     // Capture the hover information for the just created row. This is used in the next render
     // to ensure that the entire row is highlighted.
     fn capture_hover_state(&mut self, response: &Option<Response>, row_index: usize) {
@@ -1140,6 +1405,9 @@ pub struct TableRow<'a, 'b> {
     hovered: bool,
     selected: bool,
 
+    /// In front of the first cell.
+    handle: RowHandle,
+
     response: &'b mut Option<Response>,
 }
 
@@ -1174,6 +1442,10 @@ impl<'a, 'b> TableRow<'a, 'b> {
             selected: self.selected,
         };
 
+        if col_index == 0 {
+            self.add_handle(flags);
+        }
+
         let (used_rect, response) = self.layout.add(
             flags,
             width,
@@ -1195,6 +1467,28 @@ impl<'a, 'b> TableRow<'a, 'b> {
         (used_rect, response)
     }
 
+    fn add_handle(&mut self, flags: StripLayoutFlags) {
+        let width = CellSize::Absolute(row_handle_width(self.layout.ui));
+        let height = CellSize::Absolute(self.height);
+        match self.handle {
+            RowHandle::None => {}
+            RowHandle::Blank => self.layout.empty(width, height),
+            RowHandle::Grip(row_drag_id) => {
+                let row_index = self.row_index;
+                self.layout.add(
+                    StripLayoutFlags {
+                        clip: false,
+                        ..flags
+                    },
+                    width,
+                    height,
+                    egui::Id::new((row_index, "__row_handle")),
+                    |ui| row_handle_ui(ui, row_drag_id, row_index),
+                );
+            }
+        }
+    }
+
     /// Set the selection highlight state for cells added after a call to this function.
     #[inline]
     pub fn set_selected(&mut self, selected: bool) {
@@ -1229,3 +1523,116 @@ impl<'a, 'b> Drop for TableRow<'a, 'b> {
         self.layout.end_line();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::test_utils::pointer;
+    use egui::{Event, Pos2, Vec2};
+
+    /// Two tables of three rows in the same [`Ui`], returning the reorders of each.
+    fn run(
+        ctx: &egui::Context,
+        events: Vec<Event>,
+        handles: &mut [[Pos2; 3]; 2],
+    ) -> [Option<(usize, usize)>; 2] {
+        let mut reorders = [None; 2];
+        egui::test_utils::run(ctx, events, |ui| {
+            for (table, reorder) in reorders.iter_mut().enumerate() {
+                let handles = &mut handles[table];
+                TableBuilder::new(ui)
+                    .id_source(table)
+                    .vscroll(false)
+                    .column(Column::exact(100.0))
+                    .reorderable_rows(|from, to| *reorder = Some((from, to)))
+                    .body(|body| {
+                        body.rows(20.0, 3, |mut row| {
+                            let index = row.index();
+                            row.col(|ui| {
+                                ui.label(format!("Row {index}"));
+                                let rect = ui.max_rect();
+                                let handle_x = rect.left() - 20.0;
+                                handles[index] = egui::pos2(handle_x, rect.center().y);
+                            });
+                        });
+                    });
+            }
+        });
+        reorders
+    }
+
+    /// Drag from `from` to `to`, and return the reorders of the last frame.
+    fn drag(
+        ctx: &egui::Context,
+        handles: &mut [[Pos2; 3]; 2],
+        from: Pos2,
+        to: Pos2,
+    ) -> [Option<(usize, usize)>; 2] {
+        for events in [
+            pointer(from, Some(true)),
+            pointer(from + Vec2::new(0.0, 5.0), None),
+            pointer(to, None),
+        ] {
+            assert_eq!(run(ctx, events, handles), [None, None]);
+        }
+        run(ctx, pointer(to, Some(false)), handles)
+    }
+
+    #[test]
+    fn test_reorder_rows() {
+        let ctx = egui::Context::default();
+        let mut handles = [[Pos2::ZERO; 3]; 2];
+        run(&ctx, vec![], &mut handles);
+        run(&ctx, vec![], &mut handles);
+        let [first, _, last] = handles[0];
+
+        // Below the middle of the last row:
+        let below_last = last + Vec2::new(0.0, 5.0);
+        let reorders = drag(&ctx, &mut handles, first, below_last);
+        assert_eq!(reorders, [Some((0, 2)), None]);
+
+        // Above the middle of the first row:
+        let above_first = first - Vec2::new(0.0, 5.0);
+        let reorders = drag(&ctx, &mut handles, last, above_first);
+        assert_eq!(reorders, [Some((2, 0)), None]);
+
+        // Dropping a row where it already is does nothing:
+        let reorders = drag(&ctx, &mut handles, first, first);
+        assert_eq!(reorders, [None, None]);
+    }
+
+    #[test]
+    fn test_reorder_rows_of_second_table() {
+        let ctx = egui::Context::default();
+        let mut handles = [[Pos2::ZERO; 3]; 2];
+        run(&ctx, vec![], &mut handles);
+        run(&ctx, vec![], &mut handles);
+        let [first, _, last] = handles[1];
+        assert!(handles[0][2].y < first.y, "The tables should be stacked");
+
+        let reorders = drag(&ctx, &mut handles, first, last + Vec2::new(0.0, 5.0));
+        assert_eq!(reorders, [None, Some((0, 2))]);
+    }
+
+    #[test]
+    fn test_default_state_id() {
+        let ctx = egui::Context::default();
+        let ui_id = egui::test_utils::run(&ctx, vec![], |ui| {
+            TableBuilder::new(ui)
+                .column(Column::exact(100.0))
+                .body(|body| {
+                    body.rows(20.0, 3, |mut row| {
+                        row.col(|ui| {
+                            ui.label("Row");
+                        });
+                    });
+                });
+            ui.id()
+        });
+
+        // The id from before `TableBuilder::id_source`, so the column widths are kept:
+        let state_id = ui_id.with("__table_state");
+        let state = ctx.data_mut(|d| d.get_persisted::<TableState>(state_id));
+        assert_eq!(state.map(|state| state.column_widths), Some(vec![100.0]));
+    }
+}
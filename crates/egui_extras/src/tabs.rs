@@ -0,0 +1,452 @@
+use std::hash::Hash;
+
+use egui::{
+    scroll_area::ScrollBarVisibility, EventFilter, Id, Key, Modifiers, Rect, Response, Rounding,
+    ScrollArea, Sense, Stroke, TextStyle, TextWrapMode, Ui, WidgetText,
+};
+
+use crate::ListMove;
+
+/// The response from [`Tabs::show`].
+pub struct TabsResponse<T> {
+    /// The response of the tab bar.
+    pub response: Response,
+
+    /// Did the user select another tab this frame?
+    pub selection_changed: bool,
+
+    /// The close button of this tab was clicked. See [`Tabs::closable`].
+    ///
+    /// It is up to you to remove the tab.
+    pub closed: Option<T>,
+
+    /// A tab was dragged to a new place. See [`Tabs::reorderable`].
+    ///
+    /// It is up to you to move the tab.
+    pub moved: Option<ListMove>,
+}
+
+/// What the user can do with the tabs, see [`Tabs::closable`] and [`Tabs::reorderable`].
+#[derive(Clone, Copy)]
+struct TabOptions {
+    closable: bool,
+    reorderable: bool,
+}
+
+/// Stored in temporary memory for each [`Tabs`].
+#[derive(Clone, Copy, Default)]
+struct TabsState {
+    /// The tabs didn't fit last frame, so show the menu with all tabs.
+    overflowing: bool,
+
+    /// The selection was changed, so scroll the selected tab into view.
+    scroll_to_selected: bool,
+}
+
+struct Tab<'c, T> {
+    value: T,
+    label: WidgetText,
+    content: Box<dyn FnOnce(&mut Ui) + 'c>,
+}
+
+/// Adds the tabs of [`Tabs`]. See [`Tabs::show`].
+pub struct TabsBuilder<'c, T> {
+    tabs: Vec<Tab<'c, T>>,
+}
+
+impl<'c, T> TabsBuilder<'c, T> {
+    /// Add a tab. `content` is only called when the tab is selected.
+    pub fn tab(
+        &mut self,
+        value: T,
+        label: impl Into<WidgetText>,
+        content: impl FnOnce(&mut Ui) + 'c,
+    ) {
+        self.tabs.push(Tab {
+            value,
+            label: label.into(),
+            content: Box::new(content),
+        });
+    }
+}
+
+/// A row of tabs, with the contents of the selected tab below.
+///
+/// The selected tab is a value of any type, e.g. an enum or an index.
+/// The value also identifies the tab, so the tabs keep their focus and state when they are reordered.
+///
+/// When the tabs don't fit, the tab bar can be scrolled, and a menu button with all the tabs is shown.
+/// When a tab has keyboard focus, the left and right arrows, home and end select other tabs.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// #[derive(Clone, PartialEq, Hash)]
+/// enum Page {
+///     General,
+///     Advanced,
+/// }
+///
+/// # let mut page = Page::General;
+/// egui_extras::Tabs::new("settings", &mut page).show(ui, |tabs| {
+///     tabs.tab(Page::General, "General", |ui| {
+///         ui.label("General settings");
+///     });
+///     tabs.tab(Page::Advanced, "Advanced", |ui| {
+///         ui.label("Advanced settings");
+///     });
+/// });
+/// # });
+/// ```
+pub struct Tabs<'a, T> {
+    id_source: Id,
+    selected: &'a mut T,
+    closable: bool,
+    reorderable: bool,
+}
+
+impl<'a, T: Clone + PartialEq + Hash> Tabs<'a, T> {
+    pub fn new(id_source: impl Hash, selected: &'a mut T) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            selected,
+            closable: false,
+            reorderable: false,
+        }
+    }
+
+    /// Show a close button on each tab. Default: `false`.
+    ///
+    /// Clicking it, or clicking the tab with the middle mouse button,
+    /// reports the tab in [`TabsResponse::closed`].
+    #[inline]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Allow dragging the tabs to reorder them. Default: `false`.
+    ///
+    /// Use [`TabsResponse::moved`] to move the tabs.
+    #[inline]
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    pub fn show<'c>(
+        self,
+        ui: &mut Ui,
+        add_tabs: impl FnOnce(&mut TabsBuilder<'c, T>),
+    ) -> TabsResponse<T> {
+        let Self {
+            id_source,
+            selected,
+            closable,
+            reorderable,
+        } = self;
+
+        let options = TabOptions {
+            closable,
+            reorderable,
+        };
+        let id = ui.make_persistent_id(id_source);
+        let mut state: TabsState = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+
+        let mut builder = TabsBuilder { tabs: Vec::new() };
+        add_tabs(&mut builder);
+        let mut tabs = builder.tabs;
+
+        let mut selected_index = tabs.iter().position(|tab| &tab.value == selected);
+        let mut closed = None;
+        let mut moved = None;
+
+        let bar_response = ui
+            .horizontal(|ui| {
+                let menu_width = if state.overflowing {
+                    ui.spacing().interact_size.y + ui.spacing().item_spacing.x
+                } else {
+                    0.0
+                };
+
+                let scroll_output = ScrollArea::horizontal()
+                    .id_source(id)
+                    .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                    .max_width(ui.available_width() - menu_width)
+                    .show(ui, |ui| {
+                        ui.spacing_mut().item_spacing.x = 0.0;
+
+                        let mut rects = Vec::with_capacity(tabs.len());
+                        let mut dragged = None;
+                        let mut focused = None;
+                        for (index, tab) in tabs.iter().enumerate() {
+                            let tab_id = id.with(&tab.value);
+                            let is_selected = selected_index == Some(index);
+                            let (response, close_clicked) =
+                                tab_ui(ui, tab_id, tab.label.clone(), is_selected, options);
+
+                            if response.clicked() {
+                                selected_index = Some(index);
+                            }
+                            if close_clicked || (closable && response.middle_clicked()) {
+                                closed = Some(index);
+                            }
+                            if response.dragged() || response.drag_stopped() {
+                                dragged = Some((index, response.drag_stopped()));
+                            }
+                            if response.has_focus() {
+                                focused = Some(index);
+                            }
+                            if is_selected && state.scroll_to_selected {
+                                response.scroll_to_me(None);
+                                state.scroll_to_selected = false;
+                            }
+                            rects.push(response.rect);
+                        }
+
+                        if let (Some((from, stopped)), Some(pointer_pos)) =
+                            (dragged, ui.ctx().pointer_interact_pos())
+                        {
+                            let centers: Vec<f32> =
+                                rects.iter().map(|rect| rect.center().x).collect();
+                            let to = drop_index(&centers, from, pointer_pos.x);
+                            if stopped {
+                                if to != from {
+                                    moved = Some(ListMove { from, to });
+                                }
+                            } else {
+                                let x = insertion_x(&rects, from, to);
+                                ui.painter().vline(
+                                    x,
+                                    rects[from].y_range(),
+                                    Stroke::new(2.0, ui.visuals().selection.bg_fill),
+                                );
+                            }
+                        }
+
+                        if let Some(index) = focused {
+                            let tab_id = id.with(&tabs[index].value);
+                            ui.memory_mut(|mem| {
+                                mem.set_focus_lock_filter(
+                                    tab_id,
+                                    EventFilter {
+                                        horizontal_arrows: true,
+                                        ..Default::default()
+                                    },
+                                );
+                            });
+                            let new_index = ui.input_mut(|i| {
+                                if i.consume_key(Modifiers::NONE, Key::ArrowLeft) {
+                                    index.checked_sub(1)
+                                } else if i.consume_key(Modifiers::NONE, Key::ArrowRight) {
+                                    Some(index + 1).filter(|&next| next < tabs.len())
+                                } else if i.consume_key(Modifiers::NONE, Key::Home) {
+                                    Some(0)
+                                } else if i.consume_key(Modifiers::NONE, Key::End) {
+                                    tabs.len().checked_sub(1)
+                                } else {
+                                    None
+                                }
+                            });
+                            if let Some(new_index) = new_index {
+                                selected_index = Some(new_index);
+                                let new_tab_id = id.with(&tabs[new_index].value);
+                                ui.memory_mut(|mem| mem.request_focus(new_tab_id));
+                            }
+                        }
+                    });
+
+                let overflowing = scroll_output.inner_rect.width() < scroll_output.content_size.x;
+                if overflowing != state.overflowing {
+                    state.overflowing = overflowing;
+                    ui.ctx().request_repaint();
+                }
+
+                if state.overflowing {
+                    ui.menu_button("⏷", |ui| {
+                        for (index, tab) in tabs.iter().enumerate() {
+                            let is_selected = selected_index == Some(index);
+                            if ui
+                                .selectable_label(is_selected, tab.label.clone())
+                                .clicked()
+                            {
+                                selected_index = Some(index);
+                                ui.close_menu();
+                            }
+                        }
+                    })
+                    .response
+                    .on_hover_text("All tabs");
+                }
+            })
+            .response;
+
+        if let Some(index) = closed {
+            // Select a neighbor of the closed tab:
+            if selected_index == Some(index) {
+                selected_index = if index + 1 < tabs.len() {
+                    Some(index + 1)
+                } else {
+                    index.checked_sub(1)
+                };
+            }
+        }
+        if selected_index.is_none() && !tabs.is_empty() {
+            selected_index = Some(0);
+        }
+
+        let mut selection_changed = false;
+        if let Some(index) = selected_index {
+            if tabs[index].value != *selected {
+                *selected = tabs[index].value.clone();
+                selection_changed = true;
+                state.scroll_to_selected = true;
+            }
+        }
+
+        ui.separator();
+        let closed = closed.map(|index| tabs[index].value.clone());
+        if let Some(index) = selected_index {
+            let tab = tabs.swap_remove(index);
+            ui.push_id(id.with("content"), |ui| (tab.content)(ui));
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        TabsResponse {
+            response: bar_response,
+            selection_changed,
+            closed,
+            moved,
+        }
+    }
+}
+
+/// Returns the response of the tab, and if its close button was clicked.
+fn tab_ui(
+    ui: &mut Ui,
+    id: Id,
+    label: WidgetText,
+    selected: bool,
+    options: TabOptions,
+) -> (Response, bool) {
+    let TabOptions {
+        closable,
+        reorderable,
+    } = options;
+    let padding = ui.spacing().button_padding;
+    let galley = label.into_galley(
+        ui,
+        Some(TextWrapMode::Extend),
+        f32::INFINITY,
+        TextStyle::Button,
+    );
+    let close_size = if closable {
+        ui.spacing().icon_width
+    } else {
+        0.0
+    };
+    let width = 2.0 * padding.x + galley.size().x + close_size;
+    let height = ui
+        .spacing()
+        .interact_size
+        .y
+        .max(galley.size().y + 2.0 * padding.y);
+    let (_, rect) = ui.allocate_space(egui::vec2(width, height));
+
+    let sense = if reorderable {
+        Sense::click_and_drag()
+    } else {
+        Sense::click()
+    };
+    let response = ui.interact(rect, id, sense);
+    let visuals = ui.style().interact_selectable(&response, selected);
+
+    if selected || response.hovered() || response.has_focus() {
+        let rounding = Rounding {
+            nw: visuals.rounding.nw,
+            ne: visuals.rounding.ne,
+            ..Rounding::ZERO
+        };
+        ui.painter()
+            .rect(rect, rounding, visuals.weak_bg_fill, visuals.bg_stroke);
+    }
+    if selected {
+        ui.painter().hline(
+            rect.x_range(),
+            rect.bottom() - 1.0,
+            Stroke::new(2.0, ui.visuals().selection.bg_fill),
+        );
+    }
+
+    let text_pos = egui::pos2(
+        rect.left() + padding.x,
+        rect.center().y - 0.5 * galley.size().y,
+    );
+    let text_color = if selected {
+        ui.visuals().strong_text_color()
+    } else {
+        visuals.text_color()
+    };
+    ui.painter().galley(text_pos, galley, text_color);
+
+    let mut close_clicked = false;
+    if closable {
+        let close_rect = Rect::from_center_size(
+            egui::pos2(rect.right() - padding.x - 0.5 * close_size, rect.center().y),
+            egui::Vec2::splat(0.6 * close_size),
+        );
+        let close_response = ui
+            .interact(close_rect, id.with("close"), Sense::click())
+            .on_hover_text("Close");
+        close_clicked = close_response.clicked();
+
+        let stroke = ui.style().interact(&close_response).fg_stroke;
+        let cross = close_rect.shrink(0.15 * close_size);
+        ui.painter()
+            .line_segment([cross.left_top(), cross.right_bottom()], stroke);
+        ui.painter()
+            .line_segment([cross.right_top(), cross.left_bottom()], stroke);
+    }
+
+    (response, close_clicked)
+}
+
+/// Where a tab dragged from `from` ends up when dropped at `x`, given the centers of all tabs.
+fn drop_index(centers: &[f32], from: usize, x: f32) -> usize {
+    centers
+        .iter()
+        .enumerate()
+        .filter(|&(index, &center)| index != from && center < x)
+        .count()
+}
+
+/// The x coordinate of the gap between the tabs where a dragged tab would be dropped.
+fn insertion_x(rects: &[Rect], from: usize, to: usize) -> f32 {
+    let others: Vec<&Rect> = rects
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != from)
+        .map(|(_, rect)| rect)
+        .collect();
+    match others.get(to) {
+        Some(rect) => rect.left(),
+        None => others
+            .last()
+            .map_or(rects[from].left(), |rect| rect.right()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_index() {
+        let centers = [10.0, 30.0, 50.0];
+        assert_eq!(drop_index(&centers, 0, 5.0), 0);
+        assert_eq!(drop_index(&centers, 0, 35.0), 1);
+        assert_eq!(drop_index(&centers, 0, 100.0), 2);
+        assert_eq!(drop_index(&centers, 2, 0.0), 0);
+        assert_eq!(drop_index(&centers, 2, 20.0), 1);
+    }
+}
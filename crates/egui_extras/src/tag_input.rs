@@ -0,0 +1,277 @@
+use egui::{
+    Button, Frame, Id, Key, Margin, Modifiers, NumExt as _, Response, RichText, TextEdit, Ui,
+    Widget, WidgetText,
+};
+
+/// Type of the function used by [`TagInput::suggestions`].
+type Suggester<'a> = Box<dyn FnMut(&str) -> Vec<String> + 'a>;
+
+/// Edit a list of tags, like labels, keywords or email addresses.
+///
+/// Each tag is shown as a chip with a button to remove it.
+/// Text typed after the chips becomes a new tag when pressing enter or typing a comma,
+/// and backspace in the empty text removes the last tag.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut tags = vec!["rust".to_owned(), "gui".to_owned()];
+/// ui.add(egui_extras::TagInput::new(&mut tags).hint_text("Add a tag"));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct TagInput<'a> {
+    tags: &'a mut Vec<String>,
+    id_source: Option<Id>,
+    hint_text: WidgetText,
+    separators: Vec<char>,
+    allow_duplicates: bool,
+    max_tags: usize,
+    desired_width: Option<f32>,
+    suggest: Option<Suggester<'a>>,
+}
+
+impl<'a> TagInput<'a> {
+    pub fn new(tags: &'a mut Vec<String>) -> Self {
+        Self {
+            tags,
+            id_source: None,
+            hint_text: WidgetText::default(),
+            separators: vec![','],
+            allow_duplicates: false,
+            max_tags: usize::MAX,
+            desired_width: None,
+            suggest: None,
+        }
+    }
+
+    /// Must be set if multiple [`TagInput`]s are in the same [`Ui`].
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Show a faint hint text when there are no tags and no text.
+    #[inline]
+    pub fn hint_text(mut self, hint_text: impl Into<WidgetText>) -> Self {
+        self.hint_text = hint_text.into();
+        self
+    }
+
+    /// Typing one of these characters turns the text into a tag. Default: `[',']`.
+    ///
+    /// Enter always does.
+    #[inline]
+    pub fn separators(mut self, separators: impl Into<Vec<char>>) -> Self {
+        self.separators = separators.into();
+        self
+    }
+
+    /// Allow adding the same tag more than once. Default: `false`.
+    #[inline]
+    pub fn allow_duplicates(mut self, allow_duplicates: bool) -> Self {
+        self.allow_duplicates = allow_duplicates;
+        self
+    }
+
+    /// Don't add more tags than this. Default: no limit.
+    #[inline]
+    pub fn max_tags(mut self, max_tags: usize) -> Self {
+        self.max_tags = max_tags;
+        self
+    }
+
+    /// Default: [`egui::style::Spacing::text_edit_width`].
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    /// Suggest tags in a popup while typing, like [`TextEdit::autocomplete`].
+    ///
+    /// The function is given the word being typed. Tags that were already added are not suggested.
+    #[inline]
+    pub fn suggestions(mut self, suggest: impl FnMut(&str) -> Vec<String> + 'a) -> Self {
+        self.suggest = Some(Box::new(suggest));
+        self
+    }
+}
+
+impl<'a> Widget for TagInput<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            tags,
+            id_source,
+            hint_text,
+            separators,
+            allow_duplicates,
+            max_tags,
+            desired_width,
+            mut suggest,
+        } = self;
+
+        let id = id_source.map_or_else(
+            || ui.next_auto_id().with("tag_input"),
+            |id_source| ui.make_persistent_id(id_source),
+        );
+        let text_id = id.with("text");
+        let mut text: String = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+        let mut changed = false;
+
+        let has_focus = ui.memory(|mem| mem.has_focus(text_id));
+        if has_focus
+            && text.is_empty()
+            && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Backspace))
+        {
+            changed |= tags.pop().is_some();
+        }
+
+        let visuals = ui.visuals();
+        let frame = Frame::none()
+            .fill(visuals.extreme_bg_color)
+            .stroke(if has_focus {
+                visuals.selection.stroke
+            } else {
+                visuals.widgets.inactive.bg_stroke
+            })
+            .rounding(visuals.widgets.inactive.rounding)
+            .inner_margin(ui.spacing().button_padding);
+        let desired_width = desired_width.unwrap_or(ui.spacing().text_edit_width);
+
+        let inner = frame.show(ui, |ui| {
+            ui.set_width(desired_width);
+            ui.horizontal_wrapped(|ui| {
+                let mut removed = None;
+                for (index, tag) in tags.iter().enumerate() {
+                    if chip_ui(ui, tag).clicked() {
+                        removed = Some(index);
+                    }
+                }
+
+                let existing = tags.clone();
+                let mut text_edit = TextEdit::singleline(&mut text)
+                    .id(text_id)
+                    .frame(false)
+                    .margin(Margin::ZERO)
+                    .desired_width(
+                        ui.available_width()
+                            .at_least(4.0 * ui.spacing().interact_size.y),
+                    );
+                if tags.is_empty() {
+                    text_edit = text_edit.hint_text(hint_text);
+                }
+                if let Some(suggest) = &mut suggest {
+                    text_edit = text_edit.autocomplete(move |word| {
+                        let mut suggestions = suggest(word);
+                        suggestions.retain(|suggestion| !existing.contains(suggestion));
+                        suggestions
+                    });
+                }
+                let response = ui.add(text_edit);
+
+                (response, removed)
+            })
+            .inner
+        });
+        let (text_response, removed) = inner.inner;
+
+        if let Some(index) = removed {
+            tags.remove(index);
+            changed = true;
+        }
+
+        let (mut new_tags, rest) = split_tags(&text, &separators);
+        let entered = text_response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+        text = if entered {
+            new_tags.push(rest.trim().to_owned());
+            // Keep the focus, to type the next tag:
+            ui.memory_mut(|mem| mem.request_focus(text_id));
+            String::new()
+        } else {
+            rest
+        };
+        for tag in new_tags {
+            changed |= add_tag(tags, tag, allow_duplicates, max_tags);
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, text));
+
+        let mut response = inner.response | text_response;
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// A tag with a button to remove it. Returns the response of the button.
+fn chip_ui(ui: &mut Ui, tag: &str) -> Response {
+    let visuals = &ui.visuals().widgets.inactive;
+    Frame::none()
+        .fill(visuals.weak_bg_fill)
+        .rounding(ui.spacing().interact_size.y)
+        .inner_margin(Margin::symmetric(6.0, 0.0))
+        .show(ui, |ui| {
+            ui.spacing_mut().item_spacing.x = 2.0;
+            ui.label(tag);
+            ui.add(Button::new(RichText::new("🗙").small()).frame(false))
+                .on_hover_text("Remove")
+        })
+        .inner
+}
+
+/// Split `text` at the separators.
+///
+/// Returns the trimmed, non-empty tags in front of the last separator, and the text after it.
+fn split_tags(text: &str, separators: &[char]) -> (Vec<String>, String) {
+    let mut parts: Vec<&str> = text.split(|c| separators.contains(&c)).collect();
+    let rest = parts.pop().unwrap_or_default().to_owned();
+    let tags = parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(ToOwned::to_owned)
+        .collect();
+    (tags, rest)
+}
+
+/// Returns `true` if the tag was added.
+fn add_tag(tags: &mut Vec<String>, tag: String, allow_duplicates: bool, max_tags: usize) -> bool {
+    let is_allowed =
+        !tag.is_empty() && tags.len() < max_tags && (allow_duplicates || !tags.contains(&tag));
+    if is_allowed {
+        tags.push(tag);
+    }
+    is_allowed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_tags() {
+        assert_eq!(split_tags("", &[',']), (vec![], String::new()));
+        assert_eq!(split_tags("rust", &[',']), (vec![], "rust".to_owned()));
+        assert_eq!(
+            split_tags("rust, gui,", &[',']),
+            (vec!["rust".to_owned(), "gui".to_owned()], String::new())
+        );
+        assert_eq!(
+            split_tags("a,,b; c", &[',', ';']),
+            (vec!["a".to_owned(), "b".to_owned()], " c".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_add_tag() {
+        let mut tags = vec!["a".to_owned()];
+        assert!(!add_tag(&mut tags, String::new(), false, 10));
+        assert!(!add_tag(&mut tags, "a".to_owned(), false, 10));
+        assert!(add_tag(&mut tags, "a".to_owned(), true, 10));
+        assert!(add_tag(&mut tags, "b".to_owned(), false, 3));
+        assert!(!add_tag(&mut tags, "c".to_owned(), false, 3));
+        assert_eq!(tags, ["a", "a", "b"]);
+    }
+}
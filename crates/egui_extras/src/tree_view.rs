@@ -0,0 +1,687 @@
+use std::{collections::HashMap, hash::Hash};
+
+use egui::{
+    collapsing_header::paint_default_icon, Context, DragAndDrop, Event, EventFilter, Id, Key,
+    Modifiers, Rect, Response, Sense, TextStyle, TextWrapMode, Ui, WidgetText,
+};
+
+/// What can be used to identify the nodes of a [`TreeView`], e.g. a path or an index.
+pub trait TreeNodeId: Clone + Eq + Hash + Send + Sync + 'static {}
+
+impl<T: Clone + Eq + Hash + Send + Sync + 'static> TreeNodeId for T {}
+
+/// Where a dragged node was dropped, relative to the node it was dropped on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DropPosition {
+    /// In front of the target, as a sibling.
+    Before,
+
+    /// After the target, as a sibling.
+    After,
+
+    /// As a child of the target. Only happens for nodes that can have children.
+    Inside,
+}
+
+/// A node was dragged and dropped in a [`TreeView`].
+///
+/// The [`TreeView`] doesn't move anything; that's up to you.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeViewDrop<NodeId> {
+    /// The node that was dragged.
+    pub node: NodeId,
+
+    /// The node it was dropped on.
+    pub target: NodeId,
+
+    pub position: DropPosition,
+}
+
+/// The response from [`TreeView::show`].
+pub struct TreeViewResponse<NodeId> {
+    /// The response of the whole tree. Its id is the one of the [`TreeViewState`].
+    pub response: Response,
+
+    /// The selected nodes, in the order they were selected.
+    pub selected: Vec<NodeId>,
+
+    /// Did the user change the selection this frame?
+    pub selection_changed: bool,
+
+    /// A node was double-clicked, or enter was pressed.
+    pub activated: Option<NodeId>,
+
+    /// A node was dropped. See [`TreeView::drag_and_drop`].
+    pub dropped: Option<TreeViewDrop<NodeId>>,
+}
+
+/// Which nodes of a [`TreeView`] are open and selected.
+///
+/// This is stored in egui memory, and can be changed from code:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = egui_extras::TreeView::new("my_tree").show(ui, |tree| {
+///     tree.leaf(1, "One");
+/// });
+///
+/// let id = response.response.id;
+/// let mut state = egui_extras::TreeViewState::load(ui.ctx(), id).unwrap_or_default();
+/// state.set_selected(vec![1]);
+/// state.store(ui.ctx(), id);
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct TreeViewState<NodeId> {
+    open: HashMap<NodeId, bool>,
+    selected: Vec<NodeId>,
+
+    /// The node moved with the keyboard.
+    cursor: Option<NodeId>,
+
+    /// Where a shift-click selection starts.
+    anchor: Option<NodeId>,
+
+    /// The cursor was moved with the keyboard, so it should be scrolled into view.
+    scroll_to_cursor: bool,
+}
+
+impl<NodeId> Default for TreeViewState<NodeId> {
+    fn default() -> Self {
+        Self {
+            open: HashMap::new(),
+            selected: Vec::new(),
+            cursor: None,
+            anchor: None,
+            scroll_to_cursor: false,
+        }
+    }
+}
+
+impl<NodeId: TreeNodeId> TreeViewState<NodeId> {
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_temp(id))
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+
+    /// `None` if the user never opened or closed the node, so it uses [`TreeView::default_open`].
+    pub fn is_open(&self, node: &NodeId) -> Option<bool> {
+        self.open.get(node).copied()
+    }
+
+    pub fn set_open(&mut self, node: NodeId, open: bool) {
+        self.open.insert(node, open);
+    }
+
+    /// The selected nodes, in the order they were selected.
+    pub fn selected(&self) -> &[NodeId] {
+        &self.selected
+    }
+
+    pub fn is_selected(&self, node: &NodeId) -> bool {
+        self.selected.contains(node)
+    }
+
+    pub fn set_selected(&mut self, selected: Vec<NodeId>) {
+        self.cursor = selected.last().cloned();
+        self.anchor = self.cursor.clone();
+        self.selected = selected;
+    }
+
+    /// Select a node that was clicked.
+    ///
+    /// With `multi_select`, shift selects a range of rows and command adds or removes the node.
+    fn click(
+        &mut self,
+        node: NodeId,
+        modifiers: Modifiers,
+        multi_select: bool,
+        rows: &[TreeRow<NodeId>],
+    ) {
+        let index = |node: &NodeId| rows.iter().position(|row| &row.node == node);
+        let range = self
+            .anchor
+            .as_ref()
+            .and_then(index)
+            .zip(index(&node))
+            .filter(|_| multi_select && modifiers.shift);
+
+        if let Some((anchor, index)) = range {
+            if !modifiers.command {
+                self.selected.clear();
+            }
+            for row in &rows[anchor.min(index)..=anchor.max(index)] {
+                if !self.selected.contains(&row.node) {
+                    self.selected.push(row.node.clone());
+                }
+            }
+        } else if multi_select && modifiers.command {
+            if let Some(i) = self.selected.iter().position(|selected| selected == &node) {
+                self.selected.remove(i);
+            } else {
+                self.selected.push(node.clone());
+            }
+            self.anchor = Some(node.clone());
+        } else {
+            self.selected = vec![node.clone()];
+            self.anchor = Some(node.clone());
+        }
+        self.cursor = Some(node);
+    }
+
+    /// Move the cursor with the arrow keys, home and end, and open and close nodes with left and right.
+    ///
+    /// Returns the node to activate if enter was pressed.
+    fn on_key(
+        &mut self,
+        key: Key,
+        modifiers: Modifiers,
+        multi_select: bool,
+        rows: &[TreeRow<NodeId>],
+    ) -> Option<NodeId> {
+        let last = rows.len().checked_sub(1)?;
+        let current = self
+            .cursor
+            .as_ref()
+            .and_then(|cursor| rows.iter().position(|row| &row.node == cursor));
+
+        let target = match key {
+            Key::ArrowUp => current.map_or(last, |i| i.saturating_sub(1)),
+            Key::ArrowDown => current.map_or(0, |i| (i + 1).min(last)),
+            Key::Home => 0,
+            Key::End => last,
+            Key::ArrowRight => {
+                let i = current?;
+                let row = &rows[i];
+                match row.open {
+                    Some(false) => {
+                        self.set_open(row.node.clone(), true);
+                        return None;
+                    }
+                    Some(true) if rows.get(i + 1).is_some_and(|next| next.depth > row.depth) => {
+                        i + 1
+                    }
+                    _ => return None,
+                }
+            }
+            Key::ArrowLeft => {
+                let i = current?;
+                let row = &rows[i];
+                if row.open == Some(true) {
+                    self.set_open(row.node.clone(), false);
+                    return None;
+                }
+                rows[..i]
+                    .iter()
+                    .rposition(|parent| parent.depth < row.depth)?
+            }
+            Key::Enter => return current.map(|i| rows[i].node.clone()),
+            _ => return None,
+        };
+
+        let modifiers = Modifiers {
+            shift: modifiers.shift
+                && matches!(key, Key::ArrowUp | Key::ArrowDown | Key::Home | Key::End),
+            ..Modifiers::NONE
+        };
+        self.click(rows[target].node.clone(), modifiers, multi_select, rows);
+        self.scroll_to_cursor = true;
+        None
+    }
+}
+
+/// A visible row of a [`TreeView`].
+#[derive(Clone, Debug)]
+struct TreeRow<NodeId> {
+    node: NodeId,
+    depth: usize,
+
+    /// `None` if the node can't have children.
+    open: Option<bool>,
+}
+
+/// What is dragged in a [`TreeView`] with [`TreeView::drag_and_drop`].
+struct DragPayload<NodeId> {
+    tree_id: Id,
+    node: NodeId,
+}
+
+// ----------------------------------------------------------------------------
+
+/// A tree of nodes that can be opened and closed, like the files in a file browser.
+///
+/// Nodes can be selected by clicking them, or with the arrow keys when the tree has focus.
+/// The left and right arrows close and open nodes.
+/// With [`Self::multi_select`], shift and command (ctrl) select more nodes.
+///
+/// The children of a node are only added when it is open,
+/// so you can load them lazily in the closure of [`TreeViewBuilder::dir`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = egui_extras::TreeView::new("files")
+///     .multi_select(true)
+///     .show(ui, |tree| {
+///         tree.dir("src", "src", |tree| {
+///             tree.leaf("src/lib.rs", "lib.rs");
+///             tree.leaf("src/main.rs", "main.rs");
+///         });
+///         tree.leaf("Cargo.toml", "Cargo.toml");
+///     });
+/// if let Some(file) = response.activated {
+///     // open the file…
+/// }
+/// # });
+/// ```
+pub struct TreeView {
+    id_source: Id,
+    multi_select: bool,
+    drag_and_drop: bool,
+    default_open: bool,
+    row_height: Option<f32>,
+}
+
+impl TreeView {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            multi_select: false,
+            drag_and_drop: false,
+            default_open: false,
+            row_height: None,
+        }
+    }
+
+    /// Allow selecting more than one node. Default: `false`.
+    #[inline]
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Allow dragging nodes onto other nodes. Default: `false`.
+    ///
+    /// Use [`TreeViewResponse::dropped`] to find out where a node was dropped, and move it.
+    #[inline]
+    pub fn drag_and_drop(mut self, drag_and_drop: bool) -> Self {
+        self.drag_and_drop = drag_and_drop;
+        self
+    }
+
+    /// Are nodes open before the user opens or closes them? Default: `false`.
+    #[inline]
+    pub fn default_open(mut self, default_open: bool) -> Self {
+        self.default_open = default_open;
+        self
+    }
+
+    /// Default: [`egui::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    pub fn show<NodeId: TreeNodeId>(
+        self,
+        ui: &mut Ui,
+        add_nodes: impl FnOnce(&mut TreeViewBuilder<'_, NodeId>),
+    ) -> TreeViewResponse<NodeId> {
+        let id = ui.make_persistent_id(self.id_source);
+        let mut state = TreeViewState::load(ui.ctx(), id).unwrap_or_default();
+        let has_focus = ui.memory(|mem| mem.has_focus(id));
+        let dragged = DragAndDrop::payload::<DragPayload<NodeId>>(ui.ctx())
+            .filter(|payload| payload.tree_id == id)
+            .map(|payload| payload.node.clone());
+
+        let inner = ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            let mut builder = TreeViewBuilder {
+                ui,
+                tree: &self,
+                tree_id: id,
+                state: &mut state,
+                has_focus,
+                depth: 0,
+                rows: Vec::new(),
+                dragged,
+                inside_dragged: false,
+                clicked: None,
+                activated: None,
+                dropped: None,
+            };
+            add_nodes(&mut builder);
+            (
+                builder.rows,
+                builder.clicked,
+                builder.activated,
+                builder.dropped,
+            )
+        });
+        let (rows, clicked, mut activated, dropped) = inner.inner;
+        let response = ui.interact(inner.response.rect, id, Sense::focusable_noninteractive());
+
+        let selected_before = state.selected.clone();
+        if let Some((node, modifiers)) = clicked {
+            state.click(node, modifiers, self.multi_select, &rows);
+            ui.memory_mut(|mem| mem.request_focus(id));
+        }
+
+        if has_focus {
+            ui.memory_mut(|mem| {
+                mem.set_focus_lock_filter(
+                    id,
+                    EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+
+            let keys: Vec<(Key, Modifiers)> = ui.input(|i| {
+                i.events
+                    .iter()
+                    .filter_map(|event| match event {
+                        Event::Key {
+                            key,
+                            pressed: true,
+                            modifiers,
+                            ..
+                        } => Some((*key, *modifiers)),
+                        _ => None,
+                    })
+                    .collect()
+            });
+            for (key, modifiers) in keys {
+                if let Some(node) = state.on_key(key, modifiers, self.multi_select, &rows) {
+                    activated = Some(node);
+                }
+            }
+            if state.scroll_to_cursor {
+                ui.ctx().request_repaint();
+            }
+        }
+
+        let selection_changed = state.selected != selected_before;
+        let selected = state.selected.clone();
+        state.store(ui.ctx(), id);
+
+        TreeViewResponse {
+            response,
+            selected,
+            selection_changed,
+            activated,
+            dropped,
+        }
+    }
+}
+
+/// Adds the nodes of a [`TreeView`]. See [`TreeView::show`].
+pub struct TreeViewBuilder<'a, NodeId> {
+    ui: &'a mut Ui,
+    tree: &'a TreeView,
+    tree_id: Id,
+    state: &'a mut TreeViewState<NodeId>,
+    has_focus: bool,
+    depth: usize,
+    rows: Vec<TreeRow<NodeId>>,
+
+    /// The node being dragged, if any.
+    dragged: Option<NodeId>,
+
+    /// Are we adding the children of the dragged node? They are not valid drop targets.
+    inside_dragged: bool,
+
+    clicked: Option<(NodeId, Modifiers)>,
+    activated: Option<NodeId>,
+    dropped: Option<TreeViewDrop<NodeId>>,
+}
+
+impl<'a, NodeId: TreeNodeId> TreeViewBuilder<'a, NodeId> {
+    /// Add a node without children.
+    ///
+    /// Returns the response of the row, e.g. for a context menu.
+    pub fn leaf(&mut self, node: NodeId, label: impl Into<WidgetText>) -> Response {
+        self.row(node, label.into(), None)
+    }
+
+    /// Add a node that can have children.
+    ///
+    /// `add_children` is only called when the node is open.
+    /// Returns the response of the row, e.g. for a context menu.
+    pub fn dir(
+        &mut self,
+        node: NodeId,
+        label: impl Into<WidgetText>,
+        add_children: impl FnOnce(&mut Self),
+    ) -> Response {
+        let open = self.state.is_open(&node).unwrap_or(self.tree.default_open);
+        let is_dragged = self.dragged.as_ref() == Some(&node);
+        let response = self.row(node, label.into(), Some(open));
+
+        if open {
+            let inside_dragged = self.inside_dragged;
+            self.inside_dragged |= is_dragged;
+            self.depth += 1;
+            add_children(self);
+            self.depth -= 1;
+            self.inside_dragged = inside_dragged;
+        }
+
+        response
+    }
+
+    fn row(&mut self, node: NodeId, label: WidgetText, open: Option<bool>) -> Response {
+        let ui = &mut *self.ui;
+        let row_id = self.tree_id.with(&node);
+        let row_height = self.tree.row_height.unwrap_or(ui.spacing().interact_size.y);
+        let (_, rect) = ui.allocate_space(egui::vec2(ui.available_width(), row_height));
+        let sense = if self.tree.drag_and_drop {
+            Sense::click_and_drag()
+        } else {
+            Sense::click()
+        };
+        let response = ui.interact(rect, row_id, sense);
+
+        let icon_width = ui.spacing().icon_width;
+        let left = rect.left() + self.depth as f32 * ui.spacing().indent;
+
+        if let Some(open) = open {
+            let icon_rect = Rect::from_center_size(
+                egui::pos2(left + 0.5 * icon_width, rect.center().y),
+                egui::vec2(icon_width, icon_width),
+            );
+            let icon_response = ui.interact(icon_rect, row_id.with("icon"), Sense::click());
+            if icon_response.clicked() || response.double_clicked() {
+                self.state.set_open(node.clone(), !open);
+            }
+            let openness = ui.ctx().animate_bool(row_id.with("icon"), open);
+            paint_default_icon(ui, openness, &icon_response);
+        }
+
+        let selected = self.state.is_selected(&node);
+        let visuals = ui.visuals();
+        if selected {
+            ui.painter()
+                .rect_filled(rect, 0.0, visuals.selection.bg_fill);
+        } else if response.hovered() {
+            ui.painter()
+                .rect_filled(rect, 0.0, visuals.widgets.hovered.weak_bg_fill);
+        }
+        if self.has_focus && self.state.cursor.as_ref() == Some(&node) {
+            ui.painter()
+                .rect_stroke(rect, 0.0, visuals.selection.stroke);
+        }
+
+        let text_left = left + icon_width + ui.spacing().icon_spacing;
+        let galley = label.into_galley(
+            ui,
+            Some(TextWrapMode::Truncate),
+            rect.right() - text_left,
+            TextStyle::Button,
+        );
+        let text_color = if selected {
+            visuals.selection.stroke.color
+        } else {
+            visuals.text_color()
+        };
+        let text_pos = egui::pos2(text_left, rect.center().y - 0.5 * galley.size().y);
+        ui.painter().galley(text_pos, galley, text_color);
+
+        if response.clicked() {
+            self.clicked = Some((node.clone(), ui.input(|i| i.modifiers)));
+        }
+        if response.double_clicked() {
+            self.activated = Some(node.clone());
+        }
+        if self.state.scroll_to_cursor && self.state.cursor.as_ref() == Some(&node) {
+            response.scroll_to_me(None);
+            self.state.scroll_to_cursor = false;
+        }
+
+        if self.tree.drag_and_drop {
+            response.dnd_set_drag_payload(DragPayload {
+                tree_id: self.tree_id,
+                node: node.clone(),
+            });
+
+            let is_target = !self.inside_dragged
+                && self
+                    .dragged
+                    .as_ref()
+                    .is_some_and(|dragged| dragged != &node);
+            if is_target
+                && response
+                    .dnd_hover_payload::<DragPayload<NodeId>>()
+                    .is_some()
+            {
+                let pointer_y = ui
+                    .input(|i| i.pointer.interact_pos())
+                    .map_or(rect.center().y, |pos| pos.y);
+                let t = (pointer_y - rect.top()) / rect.height();
+                let position = match open {
+                    Some(_) if t < 0.25 => DropPosition::Before,
+                    Some(_) if t < 0.75 => DropPosition::Inside,
+                    None if t < 0.5 => DropPosition::Before,
+                    _ => DropPosition::After,
+                };
+
+                let stroke = egui::Stroke::new(2.0, ui.visuals().selection.bg_fill);
+                match position {
+                    DropPosition::Before => {
+                        ui.painter().hline(left..=rect.right(), rect.top(), stroke);
+                    }
+                    DropPosition::After => {
+                        ui.painter()
+                            .hline(left..=rect.right(), rect.bottom(), stroke);
+                    }
+                    DropPosition::Inside => {
+                        ui.painter().rect_stroke(rect, 0.0, stroke);
+                    }
+                }
+
+                if let Some(payload) = response.dnd_release_payload::<DragPayload<NodeId>>() {
+                    self.dropped = Some(TreeViewDrop {
+                        node: payload.node.clone(),
+                        target: node.clone(),
+                        position,
+                    });
+                }
+            }
+        }
+
+        self.rows.push(TreeRow {
+            node,
+            depth: self.depth,
+            open,
+        });
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ```text
+    /// 0
+    /// ├ 1
+    /// │ └ 2
+    /// └ 3
+    /// 4
+    /// ```
+    fn rows() -> Vec<TreeRow<usize>> {
+        [
+            (0, Some(true)),
+            (1, Some(true)),
+            (2, None),
+            (1, None),
+            (0, Some(false)),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(node, (depth, open))| TreeRow { node, depth, open })
+        .collect()
+    }
+
+    const SHIFT: Modifiers = Modifiers {
+        shift: true,
+        ..Modifiers::NONE
+    };
+
+    #[test]
+    fn test_click() {
+        let rows = rows();
+        let mut state = TreeViewState::default();
+        state.click(1, Modifiers::NONE, true, &rows);
+        assert_eq!(state.selected(), &[1]);
+
+        state.click(3, SHIFT, true, &rows);
+        assert_eq!(state.selected(), &[1, 2, 3]);
+
+        state.click(0, Modifiers::COMMAND, true, &rows);
+        assert_eq!(state.selected(), &[1, 2, 3, 0]);
+        state.click(2, Modifiers::COMMAND, true, &rows);
+        assert_eq!(state.selected(), &[1, 3, 0]);
+
+        // Without multi-select, only one node is selected:
+        state.click(4, SHIFT, false, &rows);
+        assert_eq!(state.selected(), &[4]);
+    }
+
+    #[test]
+    fn test_keys() {
+        let rows = rows();
+        let mut state = TreeViewState::default();
+
+        state.on_key(Key::ArrowDown, Modifiers::NONE, false, &rows);
+        assert_eq!(state.selected(), &[0]);
+        state.on_key(Key::ArrowRight, Modifiers::NONE, false, &rows);
+        state.on_key(Key::ArrowRight, Modifiers::NONE, false, &rows);
+        assert_eq!(state.selected(), &[2]);
+
+        // Left goes to the parent, and then closes it:
+        state.on_key(Key::ArrowLeft, Modifiers::NONE, false, &rows);
+        assert_eq!(state.selected(), &[1]);
+        state.on_key(Key::ArrowLeft, Modifiers::NONE, false, &rows);
+        assert_eq!(state.is_open(&1), Some(false));
+
+        // Right opens a closed node:
+        state.on_key(Key::End, Modifiers::NONE, false, &rows);
+        assert_eq!(state.selected(), &[4]);
+        state.on_key(Key::ArrowRight, Modifiers::NONE, false, &rows);
+        assert_eq!(state.is_open(&4), Some(true));
+
+        state.on_key(Key::ArrowUp, SHIFT, true, &rows);
+        assert_eq!(state.selected(), &[3, 4]);
+        assert_eq!(
+            state.on_key(Key::Enter, Modifiers::NONE, true, &rows),
+            Some(3)
+        );
+    }
+}
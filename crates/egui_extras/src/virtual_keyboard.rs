@@ -0,0 +1,328 @@
+use egui::{Button, Event, Id, Key, Modifiers, Response, Sense, Ui, Vec2, Widget};
+
+/// The keys of a [`VirtualKeyboard`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum KeyboardLayout {
+    /// Letters, digits and some punctuation, with a shift key.
+    #[default]
+    Qwerty,
+
+    /// Only digits and a decimal point, e.g. for entering an amount or a PIN.
+    Numeric,
+}
+
+/// A key of a [`VirtualKeyboard`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum VirtualKey {
+    /// Types the first char, or the second one when shifted.
+    Char(char, char),
+    Shift,
+    Backspace,
+    Space,
+    Enter,
+}
+
+impl VirtualKey {
+    fn label(self, shift: bool) -> String {
+        match self {
+            Self::Char(c, shifted) => if shift { shifted } else { c }.to_string(),
+            Self::Shift => "⬆".to_owned(),
+            Self::Backspace => "⏴".to_owned(),
+            Self::Space => "Space".to_owned(),
+            Self::Enter => "Enter".to_owned(),
+        }
+    }
+
+    /// Width, relative to the width of a letter key.
+    fn width(self) -> f32 {
+        match self {
+            Self::Char(..) => 1.0,
+            Self::Shift | Self::Backspace => 1.5,
+            Self::Enter => 2.0,
+            Self::Space => 5.0,
+        }
+    }
+
+    /// The events to give to the focused widget when the key is clicked.
+    fn events(self, shift: bool) -> Vec<Event> {
+        let key_events = |key| {
+            [true, false]
+                .map(|pressed| Event::Key {
+                    key,
+                    physical_key: None,
+                    pressed,
+                    repeat: false,
+                    modifiers: Modifiers::NONE,
+                })
+                .to_vec()
+        };
+        match self {
+            Self::Char(c, shifted) => {
+                vec![Event::Text(if shift { shifted } else { c }.to_string())]
+            }
+            Self::Space => vec![Event::Text(" ".to_owned())],
+            Self::Backspace => key_events(Key::Backspace),
+            Self::Enter => key_events(Key::Enter),
+            Self::Shift => vec![],
+        }
+    }
+}
+
+fn char_keys(chars: &str, shifted: &str) -> Vec<VirtualKey> {
+    chars
+        .chars()
+        .zip(shifted.chars())
+        .map(|(c, shifted)| VirtualKey::Char(c, shifted))
+        .collect()
+}
+
+fn rows(layout: KeyboardLayout) -> Vec<Vec<VirtualKey>> {
+    use VirtualKey::{Backspace, Enter, Shift, Space};
+
+    match layout {
+        KeyboardLayout::Qwerty => vec![
+            char_keys("1234567890", "!@#$%^&*()"),
+            char_keys("qwertyuiop", "QWERTYUIOP"),
+            char_keys("asdfghjkl", "ASDFGHJKL"),
+            [
+                vec![Shift],
+                char_keys("zxcvbnm", "ZXCVBNM"),
+                vec![Backspace],
+            ]
+            .concat(),
+            [
+                char_keys(",", ";"),
+                vec![Space],
+                char_keys(".", ":"),
+                vec![Enter],
+            ]
+            .concat(),
+        ],
+        KeyboardLayout::Numeric => vec![
+            char_keys("789", "789"),
+            char_keys("456", "456"),
+            char_keys("123", "123"),
+            [char_keys(".0", ".0"), vec![Backspace]].concat(),
+            vec![Enter],
+        ],
+    }
+}
+
+/// Stored in temporary memory for each [`VirtualKeyboard`].
+#[derive(Clone, Default)]
+struct KeyboardState {
+    /// The widget to type into, i.e. the one that had keyboard focus before a key was pressed.
+    target: Option<Id>,
+
+    /// Is the shift key on? Turns off after typing a character.
+    shift: bool,
+
+    /// Events of the keys clicked last frame, to give to the target this frame.
+    pending: Vec<Event>,
+
+    /// Was a key held down last frame?
+    was_pressed: bool,
+}
+
+/// An on-screen keyboard, for touch screens without a physical keyboard.
+///
+/// Clicking a key types into the widget that has keyboard focus, e.g. a [`egui::TextEdit`],
+/// by adding [`Event::Text`] and [`Event::Key`] events to the input.
+/// The keys never take the keyboard focus themselves, so the [`egui::TextEdit`] keeps its cursor.
+///
+/// The events of a click are added to the input of the next frame, when the keyboard is shown.
+/// So show the keyboard _before_ the widgets it types into,
+/// e.g. in a [`egui::TopBottomPanel`] (which is added before the [`egui::CentralPanel`] anyway).
+///
+/// The keys are [`Button`]s, so they follow the [`egui::Style`].
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// # let mut text = String::new();
+/// egui::TopBottomPanel::bottom("keyboard").show(ctx, |ui| {
+///     ui.add(egui_extras::VirtualKeyboard::new());
+/// });
+/// egui::CentralPanel::default().show(ctx, |ui| {
+///     ui.text_edit_singleline(&mut text);
+/// });
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct VirtualKeyboard {
+    id_source: Option<Id>,
+    layout: KeyboardLayout,
+    key_height: Option<f32>,
+}
+
+impl Default for VirtualKeyboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VirtualKeyboard {
+    pub fn new() -> Self {
+        Self {
+            id_source: None,
+            layout: KeyboardLayout::default(),
+            key_height: None,
+        }
+    }
+
+    /// Must be set if the keyboard moves around in the [`Ui`].
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Default: [`KeyboardLayout::Qwerty`].
+    #[inline]
+    pub fn layout(mut self, layout: KeyboardLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// The height of the keys. Default: twice [`egui::style::Spacing::interact_size`] `.y`.
+    ///
+    /// The keys are as wide as fits in the available width.
+    #[inline]
+    pub fn key_height(mut self, key_height: f32) -> Self {
+        self.key_height = Some(key_height);
+        self
+    }
+}
+
+impl Widget for VirtualKeyboard {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            id_source,
+            layout,
+            key_height,
+        } = self;
+
+        let id = id_source.map_or_else(
+            || ui.next_auto_id().with("virtual_keyboard"),
+            |id_source| ui.make_persistent_id(id_source),
+        );
+        let mut state: KeyboardState = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+
+        // Type what was clicked last frame:
+        let pending = std::mem::take(&mut state.pending);
+        if let (Some(target), false) = (state.target, pending.is_empty()) {
+            ui.memory_mut(|mem| mem.request_focus(target));
+            ui.input_mut(|i| i.events.extend(pending));
+        }
+
+        let focused = ui.memory(|mem| mem.focused());
+        if focused.is_some() {
+            state.target = focused;
+        } else if !state.was_pressed {
+            // The user clicked somewhere else:
+            state.target = None;
+        }
+
+        let rows = rows(layout);
+        let spacing = ui.spacing().item_spacing;
+        let key_height = key_height.unwrap_or(2.0 * ui.spacing().interact_size.y);
+        let key_width = rows
+            .iter()
+            .map(|row| {
+                let units: f32 = row.iter().map(|key| key.width()).sum();
+                (ui.available_width() - spacing.x * (row.len() - 1) as f32) / units
+            })
+            .fold(f32::INFINITY, f32::min);
+        let key_sense = Sense {
+            click: true,
+            drag: false,
+            focusable: false,
+        };
+
+        let mut is_pressed = false;
+        let response = ui
+            .vertical(|ui| {
+                for row in &rows {
+                    ui.horizontal(|ui| {
+                        let row_width: f32 = row
+                            .iter()
+                            .map(|key| key.width() * key_width + spacing.x)
+                            .sum::<f32>()
+                            - spacing.x;
+                        ui.add_space(0.5 * (ui.available_width() - row_width).max(0.0));
+
+                        for &key in row {
+                            let size = Vec2::new(key.width() * key_width, key_height);
+                            let button = Button::new(key.label(state.shift))
+                                .sense(key_sense)
+                                .selected(key == VirtualKey::Shift && state.shift);
+                            let response = ui.add_sized(size, button);
+                            is_pressed |= response.is_pointer_button_down_on();
+
+                            if response.clicked() {
+                                if key == VirtualKey::Shift {
+                                    state.shift = !state.shift;
+                                } else {
+                                    state.pending.extend(key.events(state.shift));
+                                    if matches!(key, VirtualKey::Char(..)) {
+                                        state.shift = false;
+                                    }
+                                }
+                            }
+                        }
+                    });
+                }
+            })
+            .response;
+
+        if is_pressed || !state.pending.is_empty() {
+            // Keep the focus on the target, even though the pointer was pressed on a key:
+            if let Some(target) = state.target {
+                ui.memory_mut(|mem| mem.request_focus(target));
+            }
+            ui.ctx().request_repaint();
+        }
+        state.was_pressed = is_pressed || !state.pending.is_empty();
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_events() {
+        let a = VirtualKey::Char('a', 'A');
+        assert_eq!(a.events(false), vec![Event::Text("a".to_owned())]);
+        assert_eq!(a.events(true), vec![Event::Text("A".to_owned())]);
+        assert_eq!(VirtualKey::Shift.events(false), vec![]);
+
+        let backspace = VirtualKey::Backspace.events(false);
+        assert_eq!(backspace.len(), 2);
+        assert!(matches!(
+            backspace[0],
+            Event::Key {
+                key: Key::Backspace,
+                pressed: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_rows() {
+        for layout in [KeyboardLayout::Qwerty, KeyboardLayout::Numeric] {
+            let rows = rows(layout);
+            assert!(rows
+                .iter()
+                .flatten()
+                .any(|key| *key == VirtualKey::Backspace));
+            assert!(rows.iter().flatten().any(|key| *key == VirtualKey::Enter));
+        }
+        assert_eq!(rows(KeyboardLayout::Qwerty)[1].len(), 10);
+    }
+}
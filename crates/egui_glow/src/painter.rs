@@ -1,11 +1,14 @@
 #![allow(clippy::collapsible_else_if)]
 #![allow(unsafe_code)]
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use egui::{
     emath::Rect,
-    epaint::{Mesh, PaintCallbackInfo, Primitive, Vertex},
+    epaint::{Mesh, MeshId, PaintCallbackInfo, PrecomputedMeshPrimitive, Primitive, Vertex},
 };
 use glow::HasContext as _;
 use memoffset::offset_of;
@@ -82,7 +85,10 @@ pub struct Painter {
 
     program: glow::Program,
     u_screen_size: glow::UniformLocation,
+    u_transform: glow::UniformLocation,
     u_sampler: glow::UniformLocation,
+    u_sdf: glow::UniformLocation,
+    u_subpixel_mask: glow::UniformLocation,
     is_webgl_1: bool,
     vao: crate::vao::VertexArrayObject,
     srgb_textures: bool,
@@ -92,6 +98,19 @@ pub struct Painter {
 
     textures: HashMap<egui::TextureId, glow::Texture>,
 
+    /// Meshes registered with an [`egui::epaint::MeshManager`], kept on the GPU between frames.
+    precomputed_meshes: HashMap<MeshId, PrecomputedMeshBuffers>,
+
+    /// Font textures that contain signed distance fields instead of coverage.
+    ///
+    /// See [`egui::epaint::text::GlyphRasterization::SignedDistanceField`].
+    sdf_textures: HashSet<egui::TextureId>,
+
+    /// Font textures that contain the coverage of each color channel, which are drawn in two passes.
+    ///
+    /// See [`egui::epaint::text::GlyphRasterization::Subpixel`].
+    subpixel_textures: HashSet<egui::TextureId>,
+
     next_native_tex_id: u64,
 
     /// Stores outdated OpenGL textures that are yet to be deleted
@@ -101,6 +120,25 @@ pub struct Painter {
     destroyed: bool,
 }
 
+/// A [`Primitive::PrecomputedMesh`] uploaded to the GPU.
+struct PrecomputedMeshBuffers {
+    /// The mesh that was uploaded. Also keeps it alive, so that it isn't confused with a new one.
+    mesh: Arc<Mesh>,
+    vao: crate::vao::VertexArrayObject,
+    vbo: glow::Buffer,
+    element_array_buffer: glow::Buffer,
+}
+
+impl PrecomputedMeshBuffers {
+    unsafe fn destroy(&self, gl: &glow::Context) {
+        unsafe {
+            self.vao.destroy(gl);
+            gl.delete_buffer(self.vbo);
+            gl.delete_buffer(self.element_array_buffer);
+        }
+    }
+}
+
 /// A callback function that can be used to compose an [`egui::PaintCallback`] for custom rendering
 /// with [`glow`].
 ///
@@ -211,7 +249,10 @@ impl Painter {
             gl.delete_shader(vert);
             gl.delete_shader(frag);
             let u_screen_size = gl.get_uniform_location(program, "u_screen_size").unwrap();
+            let u_transform = gl.get_uniform_location(program, "u_transform").unwrap();
             let u_sampler = gl.get_uniform_location(program, "u_sampler").unwrap();
+            let u_sdf = gl.get_uniform_location(program, "u_sdf").unwrap();
+            let u_subpixel_mask = gl.get_uniform_location(program, "u_subpixel_mask").unwrap();
 
             let vbo = gl.create_buffer()?;
 
@@ -257,7 +298,10 @@ impl Painter {
                 max_texture_side,
                 program,
                 u_screen_size,
+                u_transform,
                 u_sampler,
+                u_sdf,
+                u_subpixel_mask,
                 is_webgl_1,
                 vao,
                 srgb_textures,
@@ -265,6 +309,9 @@ impl Painter {
                 vbo,
                 element_array_buffer,
                 textures: Default::default(),
+                precomputed_meshes: Default::default(),
+                sdf_textures: Default::default(),
+                subpixel_textures: Default::default(),
                 next_native_tex_id: 1 << 32,
                 textures_to_destroy: Vec::new(),
                 destroyed: false,
@@ -313,15 +360,7 @@ impl Painter {
             self.gl.enable(glow::BLEND);
             self.gl
                 .blend_equation_separate(glow::FUNC_ADD, glow::FUNC_ADD);
-            self.gl.blend_func_separate(
-                // egui outputs colors with premultiplied alpha:
-                glow::ONE,
-                glow::ONE_MINUS_SRC_ALPHA,
-                // Less important, but this is technically the correct alpha blend function
-                // when you want to make use of the framebuffer alpha (for screenshots, compositing, etc).
-                glow::ONE_MINUS_DST_ALPHA,
-                glow::ONE,
-            );
+            self.set_default_blend_func();
 
             if self.supports_srgb_framebuffer {
                 self.gl.disable(glow::FRAMEBUFFER_SRGB);
@@ -337,6 +376,8 @@ impl Painter {
 
             self.gl
                 .uniform_2_f32(Some(&self.u_screen_size), width_in_points, height_in_points);
+            self.gl
+                .uniform_3_f32(Some(&self.u_transform), 0.0, 0.0, 1.0);
             self.gl.uniform_1_i32(Some(&self.u_sampler), 0);
             self.gl.active_texture(glow::TEXTURE0);
 
@@ -362,6 +403,9 @@ impl Painter {
     ) {
         crate::profile_function!();
 
+        for &(id, size, options) in &textures_delta.allocate {
+            self.allocate_texture(id, size, options);
+        }
         for (id, image_delta) in &textures_delta.set {
             self.set_texture(*id, image_delta);
         }
@@ -415,6 +459,9 @@ impl Painter {
                 Primitive::Mesh(mesh) => {
                     self.paint_mesh(mesh);
                 }
+                Primitive::PrecomputedMesh(primitive) => {
+                    self.paint_precomputed_mesh(primitive);
+                }
                 Primitive::Callback(callback) => {
                     if callback.rect.is_positive() {
                         crate::profile_scope!("callback");
@@ -459,6 +506,30 @@ impl Painter {
 
             check_for_gl_error!(&self.gl, "painting");
         }
+
+        // If we hold the last reference, the mesh has been freed from the `MeshManager`:
+        let gl = &self.gl;
+        self.precomputed_meshes.retain(|_, buffers| {
+            let keep = Arc::strong_count(&buffers.mesh) > 1;
+            if !keep {
+                unsafe { buffers.destroy(gl) };
+            }
+            keep
+        });
+    }
+
+    fn set_default_blend_func(&self) {
+        unsafe {
+            self.gl.blend_func_separate(
+                // egui outputs colors with premultiplied alpha:
+                glow::ONE,
+                glow::ONE_MINUS_SRC_ALPHA,
+                // Less important, but this is technically the correct alpha blend function
+                // when you want to make use of the framebuffer alpha (for screenshots, compositing, etc).
+                glow::ONE_MINUS_DST_ALPHA,
+                glow::ONE,
+            );
+        }
     }
 
     #[inline(never)] // Easier profiling
@@ -480,38 +551,195 @@ impl Painter {
                     bytemuck::cast_slice(&mesh.indices),
                     glow::STREAM_DRAW,
                 );
+            }
+
+            self.draw_elements(mesh, texture);
+        } else {
+            log::warn!("Failed to find texture {:?}", mesh.texture_id);
+        }
+    }
+
+    /// Paint a mesh registered with an [`egui::epaint::MeshManager`],
+    /// uploading it only if it is new or has changed.
+    #[inline(never)] // Easier profiling
+    fn paint_precomputed_mesh(&mut self, primitive: &PrecomputedMeshPrimitive) {
+        let mesh = &primitive.mesh;
+        if mesh.is_empty() {
+            return;
+        }
+        let Some(texture) = self.texture(mesh.texture_id) else {
+            log::warn!("Failed to find texture {:?}", mesh.texture_id);
+            return;
+        };
 
-                self.gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+        let is_uploaded = self
+            .precomputed_meshes
+            .get(&primitive.id)
+            .is_some_and(|buffers| Arc::ptr_eq(&buffers.mesh, mesh));
+        if !is_uploaded {
+            crate::profile_scope!("upload precomputed mesh");
+            debug_assert!(mesh.is_valid());
+            let created = unsafe { (self.gl.create_buffer(), self.gl.create_buffer()) };
+            let (Ok(vbo), Ok(element_array_buffer)) = created else {
+                log::warn!("Failed to create buffers for a precomputed mesh");
+                return;
+            };
+            unsafe {
+                let vao = self.vao.with_vbo(&self.gl, vbo);
+
+                self.gl.bind_buffer(glow::ARRAY_BUFFER, Some(vbo));
+                self.gl.buffer_data_u8_slice(
+                    glow::ARRAY_BUFFER,
+                    bytemuck::cast_slice(&mesh.vertices),
+                    glow::STATIC_DRAW,
+                );
+                self.gl
+                    .bind_buffer(glow::ELEMENT_ARRAY_BUFFER, Some(element_array_buffer));
+                self.gl.buffer_data_u8_slice(
+                    glow::ELEMENT_ARRAY_BUFFER,
+                    bytemuck::cast_slice(&mesh.indices),
+                    glow::STATIC_DRAW,
+                );
+
+                let buffers = PrecomputedMeshBuffers {
+                    mesh: mesh.clone(),
+                    vao,
+                    vbo,
+                    element_array_buffer,
+                };
+                if let Some(old) = self.precomputed_meshes.insert(primitive.id, buffers) {
+                    old.destroy(&self.gl);
+                }
             }
+        }
+
+        let buffers = &self.precomputed_meshes[&primitive.id];
+        let transform = primitive.transform;
+        unsafe {
+            buffers.vao.bind(&self.gl);
+            self.gl.bind_buffer(
+                glow::ELEMENT_ARRAY_BUFFER,
+                Some(buffers.element_array_buffer),
+            );
+            self.gl.uniform_3_f32(
+                Some(&self.u_transform),
+                transform.translation.x,
+                transform.translation.y,
+                transform.scaling,
+            );
+        }
+
+        self.draw_elements(mesh, texture);
+
+        // Restore state:
+        unsafe {
+            self.gl
+                .uniform_3_f32(Some(&self.u_transform), 0.0, 0.0, 1.0);
+            self.vao.bind(&self.gl);
+            self.gl
+                .bind_buffer(glow::ELEMENT_ARRAY_BUFFER, Some(self.element_array_buffer));
+        }
+    }
 
+    /// Draw the mesh in the bound buffers.
+    fn draw_elements(&self, mesh: &Mesh, texture: glow::Texture) {
+        unsafe {
+            self.gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+
+            let is_sdf = self.sdf_textures.contains(&mesh.texture_id);
+            self.gl.uniform_1_i32(Some(&self.u_sdf), is_sdf as i32);
+        }
+
+        if self.subpixel_textures.contains(&mesh.texture_id) {
             unsafe {
+                // First darken by the coverage of each color channel: dst * (1 - src)
+                self.gl.uniform_1_i32(Some(&self.u_subpixel_mask), 1);
+                self.gl.blend_func_separate(
+                    glow::ZERO,
+                    glow::ONE_MINUS_SRC_COLOR,
+                    glow::ZERO,
+                    glow::ONE,
+                );
+                self.gl.draw_elements(
+                    glow::TRIANGLES,
+                    mesh.indices.len() as i32,
+                    glow::UNSIGNED_INT,
+                    0,
+                );
+
+                // Then add the color: dst + src
+                self.gl.uniform_1_i32(Some(&self.u_subpixel_mask), 0);
+                self.gl.blend_func_separate(
+                    glow::ONE,
+                    glow::ONE,
+                    glow::ONE_MINUS_DST_ALPHA,
+                    glow::ONE,
+                );
                 self.gl.draw_elements(
                     glow::TRIANGLES,
                     mesh.indices.len() as i32,
                     glow::UNSIGNED_INT,
                     0,
                 );
-            }
 
-            check_for_gl_error!(&self.gl, "paint_mesh");
+                self.set_default_blend_func();
+            }
         } else {
-            log::warn!("Failed to find texture {:?}", mesh.texture_id);
+            unsafe {
+                self.gl.draw_elements(
+                    glow::TRIANGLES,
+                    mesh.indices.len() as i32,
+                    glow::UNSIGNED_INT,
+                    0,
+                );
+            }
         }
+
+        check_for_gl_error!(&self.gl, "draw_elements");
     }
 
     // ------------------------------------------------------------------------
 
+    /// Allocate a color texture of the given size, leaving its contents uninitialized.
+    ///
+    /// See [`egui::TexturesDelta::allocate`].
+    pub fn allocate_texture(
+        &mut self,
+        tex_id: egui::TextureId,
+        size: [usize; 2],
+        options: egui::TextureOptions,
+    ) {
+        crate::profile_function!();
+
+        self.assert_not_destroyed();
+        self.bind_texture(tex_id);
+
+        self.sdf_textures.remove(&tex_id);
+        self.subpixel_textures.remove(&tex_id);
+
+        self.upload_texture_srgb(None, size, options, None);
+    }
+
     pub fn set_texture(&mut self, tex_id: egui::TextureId, delta: &egui::epaint::ImageDelta) {
         crate::profile_function!();
 
         self.assert_not_destroyed();
+        self.bind_texture(tex_id);
 
-        let glow_texture = *self
-            .textures
-            .entry(tex_id)
-            .or_insert_with(|| unsafe { self.gl.create_texture().unwrap() });
-        unsafe {
-            self.gl.bind_texture(glow::TEXTURE_2D, Some(glow_texture));
+        if delta.is_whole() {
+            let is_sdf = matches!(&delta.image, egui::ImageData::Font(image) if image.is_sdf());
+            if is_sdf {
+                self.sdf_textures.insert(tex_id);
+            } else {
+                self.sdf_textures.remove(&tex_id);
+            }
+            let is_subpixel =
+                matches!(&delta.image, egui::ImageData::Font(image) if image.is_subpixel());
+            if is_subpixel {
+                self.subpixel_textures.insert(tex_id);
+            } else {
+                self.subpixel_textures.remove(&tex_id);
+            }
         }
 
         match &delta.image {
@@ -522,9 +750,10 @@ impl Painter {
                     "Mismatch between texture size and texel count"
                 );
 
-                let data: &[u8] = bytemuck::cast_slice(image.pixels.as_ref());
+                let pixels = image.srgb_pixels();
+                let data: &[u8] = bytemuck::cast_slice(&pixels);
 
-                self.upload_texture_srgb(delta.pos, image.size, delta.options, data);
+                self.upload_texture_srgb(delta.pos, image.size, delta.options, Some(data));
             }
             egui::ImageData::Font(image) => {
                 assert_eq!(
@@ -541,20 +770,34 @@ impl Painter {
                         .collect()
                 };
 
-                self.upload_texture_srgb(delta.pos, image.size, delta.options, &data);
+                self.upload_texture_srgb(delta.pos, image.size, delta.options, Some(&data));
             }
         };
     }
 
+    /// Creates the texture if it doesn't exist yet, and binds it.
+    fn bind_texture(&mut self, tex_id: egui::TextureId) {
+        let glow_texture = *self
+            .textures
+            .entry(tex_id)
+            .or_insert_with(|| unsafe { self.gl.create_texture().unwrap() });
+        unsafe {
+            self.gl.bind_texture(glow::TEXTURE_2D, Some(glow_texture));
+        }
+    }
+
+    /// If `data` is `None`, the whole texture is (re)allocated without initializing it.
     fn upload_texture_srgb(
         &mut self,
         pos: Option<[usize; 2]>,
         [w, h]: [usize; 2],
         options: egui::TextureOptions,
-        data: &[u8],
+        data: Option<&[u8]>,
     ) {
         crate::profile_function!();
-        assert_eq!(data.len(), w * h * 4);
+        if let Some(data) = data {
+            assert_eq!(data.len(), w * h * 4);
+        }
         assert!(
             w <= self.max_texture_side && h <= self.max_texture_side,
             "Got a texture image of size {}x{}, but the maximum supported texture side is only {}",
@@ -603,7 +846,7 @@ impl Painter {
             self.gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, 1);
 
             let level = 0;
-            if let Some([x, y]) = pos {
+            if let (Some([x, y]), Some(data)) = (pos, data) {
                 crate::profile_scope!("gl.tex_sub_image_2d");
                 self.gl.tex_sub_image_2d(
                     glow::TEXTURE_2D,
@@ -629,7 +872,7 @@ impl Painter {
                     border,
                     src_format,
                     glow::UNSIGNED_BYTE,
-                    Some(data),
+                    data,
                 );
                 check_for_gl_error!(&self.gl, "tex_image_2d");
             }
@@ -637,6 +880,8 @@ impl Painter {
     }
 
     pub fn free_texture(&mut self, tex_id: egui::TextureId) {
+        self.sdf_textures.remove(&tex_id);
+        self.subpixel_textures.remove(&tex_id);
         if let Some(old_tex) = self.textures.remove(&tex_id) {
             unsafe { self.gl.delete_texture(old_tex) };
         }
@@ -682,10 +927,7 @@ impl Painter {
         for row in pixels.chunks_exact((w * 4) as usize).rev() {
             flipped.extend_from_slice(bytemuck::cast_slice(row));
         }
-        egui::ColorImage {
-            size: [w as usize, h as usize],
-            pixels: flipped,
-        }
+        egui::ColorImage::from_pixels([w as usize, h as usize], flipped)
     }
 
     pub fn read_screen_rgb(&self, [w, h]: [u32; 2]) -> Vec<u8> {
@@ -714,6 +956,9 @@ impl Painter {
             }
             self.gl.delete_buffer(self.vbo);
             self.gl.delete_buffer(self.element_array_buffer);
+            for buffers in self.precomputed_meshes.values() {
+                buffers.destroy(&self.gl);
+            }
             for t in &self.textures_to_destroy {
                 self.gl.delete_texture(*t);
             }
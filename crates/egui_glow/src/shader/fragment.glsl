@@ -1,8 +1,15 @@
+#if defined(GL_ES) && !NEW_SHADER_INTERFACE
+    // For `fwidth` on WebGL1:
+    #extension GL_OES_standard_derivatives : enable
+#endif
+
 #ifdef GL_ES
     precision mediump float;
 #endif
 
 uniform sampler2D u_sampler;
+uniform int u_sdf; // 1 if the texture is a signed distance field font atlas
+uniform int u_subpixel_mask; // 1 when darkening the framebuffer by the coverage of each color channel
 
 #if NEW_SHADER_INTERFACE
     in vec4 v_rgba_in_gamma;
@@ -29,13 +36,38 @@ vec4 srgba_gamma_from_linear(vec4 rgba) {
     return vec4(srgb_gamma_from_linear(rgba.rgb), rgba.a);
 }
 
+// The distance to the glyph edge is stored in alpha, with the edge at 0.5.
+// Turn that into coverage, anti-aliased over about one screen pixel.
+vec4 sdf_texture_in_gamma(float distance) {
+#if !defined(GL_ES) || NEW_SHADER_INTERFACE || defined(GL_OES_standard_derivatives)
+    float half_width = max(0.7 * fwidth(distance), 0.00001);
+#else
+    float half_width = 0.1;
+#endif
+    float coverage = smoothstep(0.5 - half_width, 0.5 + half_width, distance);
+    return vec4(coverage);
+}
+
 void main() {
+    vec4 texture_sample = texture2D(u_sampler, v_tc);
+
 #if SRGB_TEXTURES
-    vec4 texture_in_gamma = srgba_gamma_from_linear(texture2D(u_sampler, v_tc));
+    vec4 texture_in_gamma = srgba_gamma_from_linear(texture_sample);
 #else
-    vec4 texture_in_gamma = texture2D(u_sampler, v_tc);
+    vec4 texture_in_gamma = texture_sample;
 #endif
 
+    if (u_sdf == 1) {
+        texture_in_gamma = sdf_texture_in_gamma(texture_sample.a);
+    }
+
+    if (u_subpixel_mask == 1) {
+        // First pass of subpixel anti-aliased text, blended with `dst * (1 - src)`.
+        // The second pass adds the color as usual, blended with `dst + src`.
+        gl_FragColor = v_rgba_in_gamma.a * texture_in_gamma;
+        return;
+    }
+
     // We multiply the colors in gamma space, because that's the only way to get text to look right.
     gl_FragColor = v_rgba_in_gamma * texture_in_gamma;
 }
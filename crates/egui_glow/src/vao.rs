@@ -6,7 +6,7 @@ use crate::check_for_gl_error;
 
 // ----------------------------------------------------------------------------
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct BufferInfo {
     pub location: u32, //
     pub vector_size: i32,
@@ -72,6 +72,20 @@ impl VertexArrayObject {
         }
     }
 
+    /// A new vertex array object with the same layout, reading from another buffer.
+    pub(crate) unsafe fn with_vbo(&self, gl: &glow::Context, vbo: glow::Buffer) -> Self {
+        unsafe { Self::new(gl, vbo, self.buffer_infos.clone()) }
+    }
+
+    /// Delete the GL vertex array object, if any. Does not delete the buffer.
+    pub(crate) unsafe fn destroy(&self, gl: &glow::Context) {
+        unsafe {
+            if let Some(vao) = self.vao {
+                gl.delete_vertex_array(vao);
+            }
+        }
+    }
+
     pub(crate) unsafe fn bind(&self, gl: &glow::Context) {
         unsafe {
             if let Some(vao) = self.vao {
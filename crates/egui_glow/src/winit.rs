@@ -105,6 +105,9 @@ impl EguiGlow {
         let shapes = std::mem::take(&mut self.shapes);
         let mut textures_delta = std::mem::take(&mut self.textures_delta);
 
+        for (id, size, options) in textures_delta.allocate {
+            self.painter.allocate_texture(id, size, options);
+        }
         for (id, image_delta) in textures_delta.set {
             self.painter.set_texture(id, &image_delta);
         }
@@ -1474,6 +1474,7 @@ impl PlotItem for PlotImage {
                 tint: *tint,
                 rotation: Some((Rot2::from_angle(screen_rotation), Vec2::splat(0.5))),
                 rounding: Rounding::ZERO,
+                nine_slice: None,
             },
             &(*texture_id, image_screen_rect.size()).into(),
         );
@@ -0,0 +1,228 @@
+use crate::{Pos2, Rect, Rot2, TSTransform, Vec2};
+
+/// A 2D affine transform: any combination of translation, rotation, scaling, and shearing.
+///
+/// Transforms a point `p` to `p.x * x_axis + p.y * y_axis + translation`.
+///
+/// See also [`TSTransform`], which is a cheaper translation + uniform scaling.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Affine2 {
+    /// Where the x-axis ends up (the first column of the linear part).
+    pub x_axis: Vec2,
+
+    /// Where the y-axis ends up (the second column of the linear part).
+    pub y_axis: Vec2,
+
+    /// Translation amount, applied last.
+    pub translation: Vec2,
+}
+
+impl Eq for Affine2 {}
+
+impl Default for Affine2 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Affine2 {
+    pub const IDENTITY: Self = Self {
+        x_axis: Vec2::X,
+        y_axis: Vec2::Y,
+        translation: Vec2::ZERO,
+    };
+
+    #[inline]
+    pub fn new(x_axis: Vec2, y_axis: Vec2, translation: Vec2) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            translation,
+        }
+    }
+
+    #[inline]
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self::new(Vec2::X, Vec2::Y, translation)
+    }
+
+    /// Uniform scaling around `(0, 0)`.
+    #[inline]
+    pub fn from_scaling(scaling: f32) -> Self {
+        Self::new(scaling * Vec2::X, scaling * Vec2::Y, Vec2::ZERO)
+    }
+
+    /// Rotation (and scaling, if `rot` isn't normalized) around `(0, 0)`.
+    #[inline]
+    pub fn from_rotation(rot: Rot2) -> Self {
+        Self::new(rot * Vec2::X, rot * Vec2::Y, Vec2::ZERO)
+    }
+
+    /// Rotation (and scaling, if `rot` isn't normalized) around the given point.
+    ///
+    /// ```
+    /// # use emath::{pos2, Affine2, Rot2};
+    /// let t = Affine2::from_rotation_around(Rot2::from_angle(std::f32::consts::TAU / 4.0), pos2(1.0, 1.0));
+    /// assert!((t * pos2(2.0, 1.0) - pos2(1.0, 2.0)).length() < 1e-6);
+    /// ```
+    #[inline]
+    pub fn from_rotation_around(rot: Rot2, pivot: Pos2) -> Self {
+        Self::from_translation(pivot.to_vec2())
+            * Self::from_rotation(rot)
+            * Self::from_translation(-pivot.to_vec2())
+    }
+
+    /// The determinant of the linear part.
+    ///
+    /// Negative if the transform mirrors, zero if it collapses everything onto a line or point.
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.x * self.y_axis.y - self.y_axis.x * self.x_axis.y
+    }
+
+    /// How much areas are scaled, expressed as a single length factor.
+    ///
+    /// This is exact for transforms without non-uniform scaling or shearing.
+    #[inline]
+    pub fn scaling(&self) -> f32 {
+        self.determinant().abs().sqrt()
+    }
+
+    /// The clockwise rotation of the x-axis, in radians.
+    #[inline]
+    pub fn rotation(&self) -> f32 {
+        self.x_axis.angle()
+    }
+
+    /// Inverts the transform.
+    ///
+    /// The result is not finite if [`Self::determinant`] is zero.
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Affine2, Rot2};
+    /// let t = Affine2::from_translation(vec2(3.0, 4.0)) * Affine2::from_rotation(Rot2::from_angle(1.0));
+    /// let p = pos2(5.0, -2.0);
+    /// assert!((t.inverse() * (t * p) - p).length() < 1e-5);
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let inv_det = 1.0 / self.determinant();
+        let x_axis = inv_det * Vec2::new(self.y_axis.y, -self.x_axis.y);
+        let y_axis = inv_det * Vec2::new(-self.y_axis.x, self.x_axis.x);
+        let translation = -(self.translation.x * x_axis + self.translation.y * y_axis);
+        Self::new(x_axis, y_axis, translation)
+    }
+
+    /// Transforms the given coordinate.
+    #[inline]
+    pub fn mul_pos(&self, pos: Pos2) -> Pos2 {
+        (self.mul_vec(pos.to_vec2()) + self.translation).to_pos2()
+    }
+
+    /// Transforms the given direction, ignoring the translation.
+    #[inline]
+    pub fn mul_vec(&self, vec: Vec2) -> Vec2 {
+        vec.x * self.x_axis + vec.y * self.y_axis
+    }
+
+    /// The smallest axis-aligned rectangle containing the transformed rectangle.
+    ///
+    /// ```
+    /// # use emath::{pos2, Affine2, Rect, Rot2};
+    /// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(2.0, 1.0));
+    /// let t = Affine2::from_rotation(Rot2::from_angle(std::f32::consts::TAU / 4.0));
+    /// let bounds = t.bounding_rect_of(rect);
+    /// assert!((bounds.size() - emath::vec2(1.0, 2.0)).length() < 1e-6);
+    /// ```
+    pub fn bounding_rect_of(&self, rect: Rect) -> Rect {
+        if !rect.is_finite() {
+            return rect;
+        }
+        Rect::from_points(&[
+            self.mul_pos(rect.left_top()),
+            self.mul_pos(rect.right_top()),
+            self.mul_pos(rect.left_bottom()),
+            self.mul_pos(rect.right_bottom()),
+        ])
+    }
+}
+
+/// Transforms the position.
+impl std::ops::Mul<Pos2> for Affine2 {
+    type Output = Pos2;
+
+    #[inline]
+    fn mul(self, pos: Pos2) -> Pos2 {
+        self.mul_pos(pos)
+    }
+}
+
+impl std::ops::Mul<Self> for Affine2 {
+    type Output = Self;
+
+    /// Applies the right hand side transform, then the left hand side.
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Affine2};
+    /// let a = Affine2::from_translation(vec2(1.0, 0.0));
+    /// let b = Affine2::from_scaling(2.0);
+    /// assert_eq!((a * b) * pos2(1.0, 1.0), pos2(3.0, 2.0));
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            x_axis: self.mul_vec(rhs.x_axis),
+            y_axis: self.mul_vec(rhs.y_axis),
+            translation: self.mul_pos(rhs.translation.to_pos2()).to_vec2(),
+        }
+    }
+}
+
+impl From<TSTransform> for Affine2 {
+    #[inline]
+    fn from(ts: TSTransform) -> Self {
+        Self::new(ts.scaling * Vec2::X, ts.scaling * Vec2::Y, ts.translation)
+    }
+}
+
+impl From<Rot2> for Affine2 {
+    #[inline]
+    fn from(rot: Rot2) -> Self {
+        Self::from_rotation(rot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pos2, vec2};
+
+    #[test]
+    fn test_affine2_matches_ts_transform() {
+        let ts = TSTransform::new(vec2(3.0, -2.0), 1.5);
+        let affine = Affine2::from(ts);
+        for p in [pos2(0.0, 0.0), pos2(1.0, 2.0), pos2(-4.0, 7.5)] {
+            assert_eq!(affine * p, ts * p);
+        }
+        assert_eq!(affine.scaling(), 1.5);
+        assert_eq!(affine.rotation(), 0.0);
+        let p = pos2(5.0, 6.0);
+        assert!((affine.inverse() * p - ts.inverse() * p).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_affine2_rotation_and_mirroring() {
+        let rot = Affine2::from_rotation(Rot2::from_angle(0.5));
+        assert!((rot.rotation() - 0.5).abs() < 1e-6);
+        assert!((rot.scaling() - 1.0).abs() < 1e-6);
+        assert!(rot.determinant() > 0.0);
+
+        let mirror = Affine2::new(Vec2::X, -Vec2::Y, Vec2::ZERO);
+        assert!(mirror.determinant() < 0.0);
+        assert_eq!(mirror.scaling(), 1.0);
+    }
+}
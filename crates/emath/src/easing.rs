@@ -158,6 +158,32 @@ pub fn exponential_in_out(t: f32) -> f32 {
     }
 }
 
+/// <https://easings.net/#easeInElastic>
+///
+/// Modeled after the damped sine wave `y = sin(13π/2 * x) * 2^(10 * (x - 1))`
+#[inline]
+pub fn elastic_in(t: f32) -> f32 {
+    (13. * PI / 2. * t).sin() * powf(2.0, 10. * (t - 1.))
+}
+
+/// <https://easings.net/#easeOutElastic>
+///
+/// Same as `1.0 - elastic_in(1.0 - t)`.
+#[inline]
+pub fn elastic_out(t: f32) -> f32 {
+    (-13. * PI / 2. * (t + 1.)).sin() * powf(2.0, -10. * t) + 1.
+}
+
+/// <https://easings.net/#easeInOutElastic>
+#[inline]
+pub fn elastic_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        0.5 * elastic_in(2. * t)
+    } else {
+        0.5 * elastic_out(2. * t - 1.) + 0.5
+    }
+}
+
 /// <https://easings.net/#easeInBack>
 #[inline]
 pub fn back_in(t: f32) -> f32 {
@@ -228,3 +254,157 @@ pub fn bounce_in_out(t: f32) -> f32 {
         0.5 * bounce_out(t * 2. - 1.) + 0.5
     }
 }
+
+/// A custom easing curve, defined by a cubic Bézier curve from `(0, 0)` to `(1, 1)`.
+///
+/// This is the same as the CSS [`cubic-bezier()`](https://developer.mozilla.org/en-US/docs/Web/CSS/easing-function#cubic-bezier_easing_function)
+/// easing function, so you can use the same control points as on the web.
+///
+/// ```
+/// # use emath::easing::CubicBezierEasing;
+/// let ease = CubicBezierEasing::new(0.25, 0.1, 0.25, 1.0); // CSS `ease`
+/// assert_eq!(ease.ease(0.0), 0.0);
+/// assert_eq!(ease.ease(1.0), 1.0);
+/// assert!(0.5 < ease.ease(0.5));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CubicBezierEasing {
+    /// The first control point.
+    ///
+    /// `x` should be in `[0, 1]`, but `y` may be outside of it to overshoot.
+    pub p1: (f32, f32),
+
+    /// The second control point.
+    ///
+    /// `x` should be in `[0, 1]`, but `y` may be outside of it to overshoot.
+    pub p2: (f32, f32),
+}
+
+impl CubicBezierEasing {
+    /// Same as CSS `ease`.
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+
+    /// Same as CSS `ease-in`.
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+
+    /// Same as CSS `ease-out`.
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+
+    /// Same as CSS `ease-in-out`.
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    /// Same arguments as CSS `cubic-bezier(x1, y1, x2, y2)`.
+    #[inline]
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            p1: (x1, y1),
+            p2: (x2, y2),
+        }
+    }
+
+    /// Evaluate the easing curve at the given time `t` in `[0, 1]`.
+    pub fn ease(&self, t: f32) -> f32 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        if 1.0 <= t {
+            return 1.0;
+        }
+        let s = self.solve_for_x(t);
+        bezier_coordinate(s, self.p1.1, self.p2.1)
+    }
+
+    /// Find the curve parameter `s` where the x coordinate of the curve equals `x`.
+    fn solve_for_x(&self, x: f32) -> f32 {
+        let (x1, x2) = (self.p1.0.clamp(0.0, 1.0), self.p2.0.clamp(0.0, 1.0));
+
+        // Newton-Raphson usually converges in a few iterations:
+        let mut s = x;
+        for _ in 0..8 {
+            let error = bezier_coordinate(s, x1, x2) - x;
+            if error.abs() < 1e-6 {
+                return s;
+            }
+            let slope = bezier_slope(s, x1, x2);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            s -= error / slope;
+        }
+
+        // Fall back to bisection (x is monotonic in s since x1 and x2 are in [0, 1]):
+        let (mut lo, mut hi) = (0.0, 1.0);
+        s = x;
+        for _ in 0..32 {
+            let value = bezier_coordinate(s, x1, x2);
+            if (value - x).abs() < 1e-6 {
+                break;
+            }
+            if value < x {
+                lo = s;
+            } else {
+                hi = s;
+            }
+            s = 0.5 * (lo + hi);
+        }
+        s
+    }
+}
+
+/// One coordinate of a cubic Bézier curve from 0 to 1 with the given control coordinates.
+#[inline]
+fn bezier_coordinate(s: f32, c1: f32, c2: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * s * c1 + 3.0 * inv * s * s * c2 + s * s * s
+}
+
+/// Derivative of [`bezier_coordinate`] with respect to `s`.
+#[inline]
+fn bezier_slope(s: f32, c1: f32, c2: f32) -> f32 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * c1 + 6.0 * inv * s * (c2 - c1) + 3.0 * s * s * (1.0 - c2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_end_points() {
+        let functions: [fn(f32) -> f32; 7] = [
+            linear,
+            cubic_in_out,
+            elastic_in,
+            elastic_out,
+            elastic_in_out,
+            bounce_out,
+            back_in_out,
+        ];
+        for f in functions {
+            assert!(f(0.0).abs() < 1e-3);
+            assert!((f(1.0) - 1.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_cubic_bezier_easing() {
+        let linear = CubicBezierEasing::new(0.0, 0.0, 1.0, 1.0);
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            assert!((linear.ease(t) - t).abs() < 1e-4);
+        }
+
+        let ease = CubicBezierEasing::EASE_IN_OUT;
+        assert!((ease.ease(0.5) - 0.5).abs() < 1e-4);
+        assert!(ease.ease(0.25) < 0.25);
+        assert!(0.75 < ease.ease(0.75));
+
+        let mut last = 0.0;
+        for i in 0..=100 {
+            let value = CubicBezierEasing::EASE.ease(i as f32 / 100.0);
+            assert!(last <= value);
+            last = value;
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//! Evaluate simple math expressions, like `1920/2` or `3*1.5 + 2`. Used by `DragValue` text entry.
+//!
+//! Supports `+ - * / ^`, parentheses, numbers like `1.5e3`, and the constants `pi` and `tau`.
+//! A number can be followed by a unit, like `25cm`, which multiplies it by the value of the unit.
+
+/// Evaluate a math expression, like `(1 + 2) * 3`.
+///
+/// Returns `None` if the expression is invalid.
+///
+/// ```
+/// # use emath::expression::eval;
+/// assert_eq!(eval("1920/2"), Some(960.0));
+/// assert_eq!(eval("3*1.5 + 2"), Some(6.5));
+/// assert_eq!(eval("2^-1"), Some(0.5));
+/// assert_eq!(eval("1 +"), None);
+/// ```
+pub fn eval(text: &str) -> Option<f64> {
+    eval_with_units(text, |_| None)
+}
+
+/// Evaluate a math expression where numbers can have units, like `1m + 25cm`.
+///
+/// `unit` returns the value of a unit, e.g. `0.01` for `"cm"` if you want the result in meters.
+/// Units are made of letters and the symbols `% ° ' "`.
+/// They are also allowed on their own, so with the units above `m/2` is `0.5`.
+///
+/// ```
+/// # use emath::expression::eval_with_units;
+/// let length_in_meters = |unit: &str| match unit {
+///     "m" => Some(1.0),
+///     "cm" => Some(0.01),
+///     "mm" => Some(0.001),
+///     _ => None,
+/// };
+/// assert_eq!(eval_with_units("1m + 25cm", length_in_meters), Some(1.25));
+/// assert_eq!(eval_with_units("3 furlongs", length_in_meters), None);
+/// ```
+pub fn eval_with_units(text: &str, unit: impl Fn(&str) -> Option<f64>) -> Option<f64> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        pos: 0,
+        depth: 0,
+        unit: &unit,
+    };
+    let value = parser.expression()?;
+    parser.skip_whitespace();
+    (parser.pos == parser.chars.len()).then_some(value)
+}
+
+/// How deep parentheses and exponents can be nested, so that user input can't overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Recursive descent parser that evaluates as it goes.
+struct Parser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+
+    /// How many parentheses and exponents we are inside of.
+    depth: usize,
+
+    unit: &'a dyn Fn(&str) -> Option<f64>,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skip whitespace, then consume the next char if it is one of `options`.
+    fn eat(&mut self, options: &[char]) -> Option<char> {
+        self.skip_whitespace();
+        let c = *self.chars.get(self.pos)?;
+        if options.contains(&c) {
+            self.pos += 1;
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    /// Parse something nested, like the inside of parentheses.
+    ///
+    /// Returns `None` if it is nested deeper than [`MAX_DEPTH`].
+    fn nested(&mut self, parse: impl FnOnce(&mut Self) -> Option<f64>) -> Option<f64> {
+        if MAX_DEPTH <= self.depth {
+            return None;
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    /// `term (('+' | '-') term)*`
+    fn expression(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+        while let Some(op) = self.eat(&['+', '-', '−']) {
+            let rhs = self.term()?;
+            value = if op == '+' { value + rhs } else { value - rhs };
+        }
+        Some(value)
+    }
+
+    /// `unary (('*' | '/') unary)*`
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.unary()?;
+        while let Some(op) = self.eat(&['*', '/', '×', '÷']) {
+            let rhs = self.unary()?;
+            value = if matches!(op, '*' | '×') {
+                value * rhs
+            } else {
+                value / rhs
+            };
+        }
+        Some(value)
+    }
+
+    /// `('+' | '-')* power`
+    fn unary(&mut self) -> Option<f64> {
+        let mut negate = false;
+        while let Some(op) = self.eat(&['+', '-', '−']) {
+            if op != '+' {
+                negate = !negate;
+            }
+        }
+        let value = self.power()?;
+        Some(if negate { -value } else { value })
+    }
+
+    /// `with_unit ('^' unary)?`, which is right-associative.
+    fn power(&mut self) -> Option<f64> {
+        let base = self.with_unit()?;
+        if self.eat(&['^']).is_some() {
+            Some(base.powf(self.nested(Self::unary)?))
+        } else {
+            Some(base)
+        }
+    }
+
+    /// `primary unit?`
+    fn with_unit(&mut self) -> Option<f64> {
+        let value = self.primary()?;
+        self.skip_whitespace();
+        if self.chars.get(self.pos).copied().is_some_and(is_unit_char) {
+            Some(value * self.unit()?)
+        } else {
+            Some(value)
+        }
+    }
+
+    /// `number | '(' expression ')' | unit`
+    fn primary(&mut self) -> Option<f64> {
+        self.skip_whitespace();
+        let c = *self.chars.get(self.pos)?;
+        if c == '(' {
+            self.pos += 1;
+            let value = self.nested(Self::expression)?;
+            self.eat(&[')'])?;
+            Some(value)
+        } else if c.is_ascii_digit() || c == '.' {
+            self.number()
+        } else if is_unit_char(c) {
+            self.unit()
+        } else {
+            None
+        }
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        let start = self.pos;
+        let is_digit = |c: Option<&char>| c.is_some_and(|c| c.is_ascii_digit());
+
+        while is_digit(self.chars.get(self.pos)) || self.chars.get(self.pos) == Some(&'.') {
+            self.pos += 1;
+        }
+
+        // Exponent, unless the `e` is the start of a unit (like `em`):
+        if matches!(self.chars.get(self.pos), Some('e' | 'E')) {
+            let sign = usize::from(matches!(self.chars.get(self.pos + 1), Some('+' | '-')));
+            if is_digit(self.chars.get(self.pos + 1 + sign)) {
+                self.pos += 1 + sign;
+                while is_digit(self.chars.get(self.pos)) {
+                    self.pos += 1;
+                }
+            }
+        }
+
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse().ok()
+    }
+
+    fn unit(&mut self) -> Option<f64> {
+        let start = self.pos;
+        while self.chars.get(self.pos).copied().is_some_and(is_unit_char) {
+            self.pos += 1;
+        }
+        let name: String = self.chars[start..self.pos].iter().collect();
+        (self.unit)(&name).or(match name.as_str() {
+            "pi" | "π" => Some(std::f64::consts::PI),
+            "tau" | "τ" => Some(std::f64::consts::TAU),
+            _ => None,
+        })
+    }
+}
+
+fn is_unit_char(c: char) -> bool {
+    c.is_alphabetic() || matches!(c, '%' | '°' | '\'' | '"' | '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eval() {
+        assert_eq!(eval("42"), Some(42.0));
+        assert_eq!(eval(" 1.5e3 "), Some(1500.0));
+        assert_eq!(eval(".5"), Some(0.5));
+        assert_eq!(eval("1 + 2 * 3"), Some(7.0));
+        assert_eq!(eval("(1 + 2) * 3"), Some(9.0));
+        assert_eq!(eval("10 - 4 - 3"), Some(3.0));
+        assert_eq!(eval("8 / 4 / 2"), Some(1.0));
+        assert_eq!(eval("2 ^ 3 ^ 2"), Some(512.0));
+        assert_eq!(eval("-2 ^ 2"), Some(-4.0));
+        assert_eq!(eval("−3 × 2"), Some(-6.0));
+        assert_eq!(eval("--1"), Some(1.0));
+        assert_eq!(eval("2pi"), Some(std::f64::consts::TAU));
+
+        for invalid in ["", "1 +", "(1", "1)", "1 2", "1..2", "foo", "2 meters"] {
+            assert_eq!(eval(invalid), None, "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn test_deep_nesting() {
+        let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(eval(&nested(MAX_DEPTH)), Some(1.0));
+        assert_eq!(eval(&nested(MAX_DEPTH + 1)), None);
+        assert_eq!(eval(&"(".repeat(100_000)), None);
+
+        let power_tower = |depth: usize| vec!["1"; depth + 1].join("^");
+        assert_eq!(eval(&power_tower(MAX_DEPTH)), Some(1.0));
+        assert_eq!(eval(&power_tower(100_000)), None);
+
+        // Signs are not nested:
+        assert_eq!(eval(&format!("{}1", "-".repeat(100_000))), Some(1.0));
+        assert_eq!(eval(&format!("{}1", "-".repeat(100_001))), Some(-1.0));
+    }
+
+    #[test]
+    fn test_eval_with_units() {
+        let unit = |name: &str| match name {
+            "m" => Some(1.0),
+            "cm" | "%" => Some(0.01),
+            "em" => Some(16.0),
+            _ => None,
+        };
+        assert_eq!(eval_with_units("1m + 50 cm", unit), Some(1.5));
+        assert_eq!(eval_with_units("2em", unit), Some(32.0));
+        assert_eq!(eval_with_units("2e2", unit), Some(200.0));
+        assert_eq!(eval_with_units("50%", unit), Some(0.5));
+        assert_eq!(eval_with_units("(1 + 1)m", unit), Some(2.0));
+        assert_eq!(eval_with_units("3 ft", unit), None);
+    }
+}
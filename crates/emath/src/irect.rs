@@ -0,0 +1,193 @@
+use std::fmt;
+
+use crate::{IVec2, Rect};
+
+/// A rectangle of whole pixels, e.g. a region of a texture or an image.
+///
+/// `min` is inclusive and `max` is exclusive,
+/// so the width is `max.x - min.x` and the rectangle contains `width * height` pixels.
+///
+/// Convert to and from [`Rect`] (in points) with [`Self::from_points`] and [`Self::to_points`].
+#[repr(C)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct IRect {
+    /// The first pixel inside the rectangle.
+    pub min: IVec2,
+
+    /// One past the last pixel inside the rectangle.
+    pub max: IVec2,
+}
+
+impl IRect {
+    pub const ZERO: Self = Self {
+        min: IVec2::ZERO,
+        max: IVec2::ZERO,
+    };
+
+    #[inline(always)]
+    pub const fn from_min_max(min: IVec2, max: IVec2) -> Self {
+        Self { min, max }
+    }
+
+    #[inline(always)]
+    pub fn from_min_size(min: IVec2, size: IVec2) -> Self {
+        Self {
+            min,
+            max: min + size,
+        }
+    }
+
+    /// Round each corner of the rectangle to the nearest integer.
+    #[inline]
+    pub fn from_rect_round(rect: Rect) -> Self {
+        Self {
+            min: IVec2::from_vec2_round(rect.min.to_vec2()),
+            max: IVec2::from_vec2_round(rect.max.to_vec2()),
+        }
+    }
+
+    /// The smallest [`IRect`] that covers the given [`Rect`].
+    #[inline]
+    pub fn from_rect_round_out(rect: Rect) -> Self {
+        Self {
+            min: IVec2::from_vec2_floor(rect.min.to_vec2()),
+            max: IVec2::from_vec2_ceil(rect.max.to_vec2()),
+        }
+    }
+
+    /// Convert from points (logical pixels) to physical pixels,
+    /// rounding each corner to the nearest pixel.
+    #[inline]
+    pub fn from_points(rect: Rect, pixels_per_point: f32) -> Self {
+        Self::from_rect_round(rect * pixels_per_point)
+    }
+
+    /// Convert from physical pixels to points (logical pixels).
+    #[inline]
+    pub fn to_points(self, pixels_per_point: f32) -> Rect {
+        self.to_rect() / pixels_per_point
+    }
+
+    #[inline]
+    pub fn to_rect(self) -> Rect {
+        Rect::from_min_max(self.min.to_pos2(), self.max.to_pos2())
+    }
+
+    #[inline(always)]
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x
+    }
+
+    #[inline(always)]
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y
+    }
+
+    #[inline(always)]
+    pub fn size(&self) -> IVec2 {
+        self.max - self.min
+    }
+
+    /// Number of pixels in the rectangle, or zero if it is empty.
+    #[inline]
+    pub fn area(&self) -> usize {
+        let [w, h] = self.size().to_usize_array();
+        w * h
+    }
+
+    /// `width <= 0 || height <= 0`
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.max.x <= self.min.x || self.max.y <= self.min.y
+    }
+
+    /// Is the given pixel inside the rectangle?
+    #[inline]
+    pub fn contains(&self, p: IVec2) -> bool {
+        self.min.x <= p.x && p.x < self.max.x && self.min.y <= p.y && p.y < self.max.y
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn translate(self, delta: IVec2) -> Self {
+        Self::from_min_max(self.min + delta, self.max + delta)
+    }
+
+    /// Expand by this much in each direction.
+    #[must_use]
+    #[inline]
+    pub fn expand(self, amnt: i32) -> Self {
+        Self::from_min_max(self.min - IVec2::splat(amnt), self.max + IVec2::splat(amnt))
+    }
+
+    /// The smallest [`IRect`] containing both rectangles.
+    #[must_use]
+    #[inline]
+    pub fn union(self, other: Self) -> Self {
+        Self::from_min_max(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    /// The pixels covered by both rectangles.
+    ///
+    /// The result may be empty (see [`Self::is_empty`]).
+    #[must_use]
+    #[inline]
+    pub fn intersect(self, other: Self) -> Self {
+        Self::from_min_max(self.min.max(other.min), self.max.min(other.max))
+    }
+
+    /// Do the rectangles share at least one pixel?
+    #[inline]
+    pub fn intersects(self, other: Self) -> bool {
+        !self.intersect(other).is_empty()
+    }
+}
+
+impl From<IRect> for Rect {
+    #[inline(always)]
+    fn from(rect: IRect) -> Self {
+        rect.to_rect()
+    }
+}
+
+impl fmt::Debug for IRect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:?} - {:?}]", self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ivec2, pos2};
+
+    #[test]
+    fn test_irect() {
+        let rect = Rect::from_min_max(pos2(0.4, 1.6), pos2(10.5, 20.2));
+        assert_eq!(
+            IRect::from_rect_round_out(rect),
+            IRect::from_min_max(ivec2(0, 1), ivec2(11, 21))
+        );
+        assert_eq!(
+            IRect::from_points(rect, 2.0),
+            IRect::from_min_max(ivec2(1, 3), ivec2(21, 40))
+        );
+
+        let a = IRect::from_min_size(ivec2(0, 0), ivec2(4, 4));
+        let b = IRect::from_min_size(ivec2(4, 0), ivec2(4, 4));
+        assert_eq!(a.area(), 16);
+        assert!(a.contains(ivec2(3, 3)));
+        assert!(!a.contains(ivec2(4, 3)));
+        assert!(!a.intersects(b), "max is exclusive");
+        assert!(a.intersects(b.translate(-IVec2::X)));
+        assert_eq!(a.union(b).size(), ivec2(8, 4));
+        assert_eq!(a.intersect(b).area(), 0);
+
+        assert_eq!(
+            a.to_points(2.0),
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(2.0, 2.0))
+        );
+    }
+}
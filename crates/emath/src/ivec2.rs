@@ -0,0 +1,224 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{Pos2, Vec2};
+
+/// A vector of two integers, usually representing a position or size in physical pixels.
+///
+/// Use this instead of `[usize; 2]` when working with textures, images and screen pixels,
+/// and convert to and from [`Vec2`] with the `pixels_per_point`-aware helpers
+/// [`Self::from_points`] and [`Self::to_points`].
+#[repr(C)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct IVec2 {
+    /// Rightwards. Width.
+    pub x: i32,
+
+    /// Downwards. Height.
+    pub y: i32,
+}
+
+/// `ivec2(x, y) == IVec2::new(x, y)`
+#[inline(always)]
+pub const fn ivec2(x: i32, y: i32) -> IVec2 {
+    IVec2 { x, y }
+}
+
+impl IVec2 {
+    pub const ZERO: Self = Self { x: 0, y: 0 };
+    pub const ONE: Self = Self { x: 1, y: 1 };
+    pub const X: Self = Self { x: 1, y: 0 };
+    pub const Y: Self = Self { x: 0, y: 1 };
+
+    #[inline(always)]
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    #[inline(always)]
+    pub const fn splat(v: i32) -> Self {
+        Self { x: v, y: v }
+    }
+
+    /// Round each component to the nearest integer.
+    #[inline]
+    pub fn from_vec2_round(v: Vec2) -> Self {
+        Self::new(v.x.round() as i32, v.y.round() as i32)
+    }
+
+    /// Round each component down.
+    #[inline]
+    pub fn from_vec2_floor(v: Vec2) -> Self {
+        Self::new(v.x.floor() as i32, v.y.floor() as i32)
+    }
+
+    /// Round each component up.
+    #[inline]
+    pub fn from_vec2_ceil(v: Vec2) -> Self {
+        Self::new(v.x.ceil() as i32, v.y.ceil() as i32)
+    }
+
+    /// Convert from points (logical pixels) to the nearest physical pixel.
+    #[inline]
+    pub fn from_points(points: Vec2, pixels_per_point: f32) -> Self {
+        Self::from_vec2_round(points * pixels_per_point)
+    }
+
+    /// Convert from physical pixels to points (logical pixels).
+    #[inline]
+    pub fn to_points(self, pixels_per_point: f32) -> Vec2 {
+        self.to_vec2() / pixels_per_point
+    }
+
+    #[inline(always)]
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::new(self.x as f32, self.y as f32)
+    }
+
+    #[inline(always)]
+    pub fn to_pos2(self) -> Pos2 {
+        Pos2::new(self.x as f32, self.y as f32)
+    }
+
+    /// Convert to the `[width, height]` format used by e.g. `epaint::ColorImage`.
+    ///
+    /// Negative components are clamped to zero.
+    #[inline]
+    pub fn to_usize_array(self) -> [usize; 2] {
+        [self.x.max(0) as usize, self.y.max(0) as usize]
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::new(self.x.clamp(min.x, max.x), self.y.clamp(min.y, max.y))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+
+    /// The minimum component.
+    #[inline(always)]
+    pub fn min_elem(self) -> i32 {
+        self.x.min(self.y)
+    }
+
+    /// The maximum component.
+    #[inline(always)]
+    pub fn max_elem(self) -> i32 {
+        self.x.max(self.y)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Compatibility and convenience conversions:
+
+impl From<[i32; 2]> for IVec2 {
+    #[inline(always)]
+    fn from([x, y]: [i32; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<IVec2> for [i32; 2] {
+    #[inline(always)]
+    fn from(v: IVec2) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl From<(i32, i32)> for IVec2 {
+    #[inline(always)]
+    fn from((x, y): (i32, i32)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<[usize; 2]> for IVec2 {
+    #[inline(always)]
+    fn from([x, y]: [usize; 2]) -> Self {
+        Self::new(x as i32, y as i32)
+    }
+}
+
+impl From<IVec2> for Vec2 {
+    #[inline(always)]
+    fn from(v: IVec2) -> Self {
+        v.to_vec2()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Neg for IVec2 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Add for IVec2 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for IVec2 {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for IVec2 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for IVec2 {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for IVec2 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, factor: i32) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl fmt::Debug for IVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} {}]", self.x, self.y)
+    }
+}
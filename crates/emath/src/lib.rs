@@ -25,31 +25,45 @@ use std::ops::{Add, Div, Mul, RangeInclusive, Sub};
 
 // ----------------------------------------------------------------------------
 
+mod affine2;
 pub mod align;
 pub mod easing;
+pub mod expression;
 mod history;
+mod irect;
+mod ivec2;
+mod number_format;
 mod numeric;
 mod ordered_float;
+pub mod polygon;
 mod pos2;
 mod range;
+mod range_set;
 mod rect;
 mod rect_transform;
 mod rot2;
+mod rotated_rect;
 pub mod smart_aim;
 mod ts_transform;
 mod vec2;
 mod vec2b;
 
 pub use self::{
+    affine2::Affine2,
     align::{Align, Align2},
     history::History,
+    irect::IRect,
+    ivec2::*,
+    number_format::NumberFormat,
     numeric::*,
     ordered_float::*,
     pos2::*,
     range::Rangef,
+    range_set::RangeSet,
     rect::*,
     rect_transform::*,
     rot2::*,
+    rotated_rect::RotatedRect,
     ts_transform::*,
     vec2::*,
     vec2b::*,
@@ -0,0 +1,188 @@
+/// How to show numbers to the user: decimal separator, grouping of thousands, and digits.
+///
+/// Used by e.g. `DragValue` and `Slider` in `egui`.
+///
+/// The numbers are first formatted the usual way (e.g. with [`crate::format_with_decimals_in_range`]),
+/// and then localized with [`Self::localize`].
+/// Text entered by the user is turned back into something Rust can parse with [`Self::delocalize`].
+///
+/// ```
+/// # use emath::NumberFormat;
+/// assert_eq!(NumberFormat::GERMAN.localize("-1234.56"), "-1.234,56");
+/// assert_eq!(NumberFormat::GERMAN.delocalize("1.234,56"), "1234.56");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NumberFormat {
+    /// Separates the integer part from the fraction, e.g. `.` or `,`.
+    pub decimal_separator: char,
+
+    /// Put between each group of three digits in the integer part, e.g. `,` or a space.
+    ///
+    /// `None` means no grouping.
+    pub grouping_separator: Option<char>,
+
+    /// The digit for zero, followed by the other nine digits in Unicode, e.g. `'٠'` for Arabic-Indic digits.
+    ///
+    /// Make sure your font has the digits!
+    pub zero_digit: char,
+}
+
+impl Default for NumberFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
+
+impl NumberFormat {
+    /// `1234.56`: how Rust formats numbers.
+    pub const PLAIN: Self = Self {
+        decimal_separator: '.',
+        grouping_separator: None,
+        zero_digit: '0',
+    };
+
+    /// `1,234.56`: English.
+    pub const ENGLISH: Self = Self {
+        grouping_separator: Some(','),
+        ..Self::PLAIN
+    };
+
+    /// `1 234,56`: French, Swedish, Polish, …
+    ///
+    /// The grouping separator is a no-break space.
+    pub const EUROPEAN: Self = Self {
+        decimal_separator: ',',
+        grouping_separator: Some('\u{a0}'),
+        zero_digit: '0',
+    };
+
+    /// `1.234,56`: German, Spanish, Italian, …
+    pub const GERMAN: Self = Self {
+        decimal_separator: ',',
+        grouping_separator: Some('.'),
+        zero_digit: '0',
+    };
+
+    /// `١٬٢٣٤٫٥٦`: Arabic, with Arabic-Indic digits.
+    pub const ARABIC: Self = Self {
+        decimal_separator: '\u{66b}',
+        grouping_separator: Some('\u{66c}'),
+        zero_digit: '\u{660}',
+    };
+
+    /// Turn a number formatted by Rust (like `-1234.56`) into this format.
+    ///
+    /// Anything that isn't a plain number (like `inf`, or text around the number) is kept as is,
+    /// except that digits are always changed to [`Self::zero_digit`].
+    pub fn localize(&self, number: &str) -> String {
+        if *self == Self::PLAIN {
+            return number.to_owned();
+        }
+
+        // Only group the digits of the integer part:
+        let integer_start = number.find(|c: char| c.is_ascii_digit());
+        let integer_end = integer_start.map(|start| {
+            number[start..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(number.len(), |len| start + len)
+        });
+
+        let mut result = String::with_capacity(number.len() + number.len() / 3);
+        for (i, c) in number.char_indices() {
+            if let (Some(start), Some(end), Some(separator)) =
+                (integer_start, integer_end, self.grouping_separator)
+            {
+                if start < i && i < end && (end - i) % 3 == 0 {
+                    result.push(separator);
+                }
+            }
+            result.push(match c {
+                '.' if Some(i) == integer_end => self.decimal_separator,
+                '0'..='9' => self.digit(c as u32 - '0' as u32),
+                _ => c,
+            });
+        }
+        result
+    }
+
+    /// Turn text in this format into something Rust can parse (like `-1234.56`).
+    ///
+    /// This removes grouping separators and changes the decimal separator to `.`.
+    /// If the grouping separator is a space (like the no-break space of [`Self::EUROPEAN`]),
+    /// any whitespace is removed, since that is what users type.
+    /// Both ASCII digits and the digits of this format are understood.
+    pub fn delocalize(&self, text: &str) -> String {
+        if *self == Self::PLAIN {
+            return text.to_owned();
+        }
+
+        text.chars()
+            .filter(|&c| !self.is_grouping_separator(c))
+            .map(|c| {
+                if c == self.decimal_separator {
+                    '.'
+                } else if let Some(digit) = self.digit_value(c) {
+                    char::from(b'0' + digit as u8)
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+
+    fn is_grouping_separator(&self, c: char) -> bool {
+        self.grouping_separator.map_or(false, |separator| {
+            c == separator || (separator.is_whitespace() && c.is_whitespace())
+        })
+    }
+
+    fn digit(&self, value: u32) -> char {
+        char::from_u32(self.zero_digit as u32 + value).unwrap_or('?')
+    }
+
+    fn digit_value(&self, c: char) -> Option<u32> {
+        let value = (c as u32).checked_sub(self.zero_digit as u32)?;
+        (value < 10).then_some(value)
+    }
+}
+
+#[test]
+fn test_number_format() {
+    assert_eq!(NumberFormat::PLAIN.localize("1234567.5"), "1234567.5");
+    assert_eq!(NumberFormat::ENGLISH.localize("1234567.5"), "1,234,567.5");
+    assert_eq!(NumberFormat::ENGLISH.localize("-123456"), "-123,456");
+    assert_eq!(NumberFormat::ENGLISH.localize("123"), "123");
+    assert_eq!(NumberFormat::ENGLISH.localize("0.0001234"), "0.0001234");
+    assert_eq!(NumberFormat::ENGLISH.localize("inf"), "inf");
+    assert_eq!(NumberFormat::EUROPEAN.localize("1234.56"), "1\u{a0}234,56");
+    assert_eq!(NumberFormat::GERMAN.localize("$1234.5 USD"), "$1.234,5 USD");
+    assert_eq!(NumberFormat::ARABIC.localize("1234.56"), "١٬٢٣٤٫٥٦");
+
+    for format in [
+        NumberFormat::PLAIN,
+        NumberFormat::ENGLISH,
+        NumberFormat::EUROPEAN,
+        NumberFormat::GERMAN,
+        NumberFormat::ARABIC,
+    ] {
+        for number in ["0", "-1", "1234567.891", "-0.5", "1e-7"] {
+            assert_eq!(
+                format.delocalize(&format.localize(number)),
+                number,
+                "{format:?}"
+            );
+        }
+    }
+
+    // A plain space is as good as a no-break space:
+    assert_eq!(NumberFormat::EUROPEAN.delocalize("1 234,5"), "1234.5");
+    assert_eq!(
+        NumberFormat::EUROPEAN.delocalize("1\u{a0}234 567"),
+        "1234567"
+    );
+
+    // ASCII digits are always understood:
+    assert_eq!(NumberFormat::ARABIC.delocalize("12٫5"), "12.5");
+}
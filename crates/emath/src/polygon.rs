@@ -0,0 +1,221 @@
+//! Metrics and helpers for polygons, given as a slice of points.
+//!
+//! The polygons are implicitly closed, i.e. the last point connects back to the first one.
+//!
+//! Remember that Y+ is down, so what looks clockwise on screen is counter-clockwise in the usual math convention.
+
+use crate::Pos2;
+
+/// The order in which the points of a polygon go around it, as seen on screen (with Y+ down).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// The signed area of a polygon.
+///
+/// Positive if the points go clockwise on screen (with Y+ down), and negative if counter-clockwise.
+/// For self-intersecting polygons, the parts with opposite winding cancel out.
+pub fn signed_area(points: &[Pos2]) -> f32 {
+    let n = points.len();
+    if n < 3 {
+        return 0.0;
+    }
+    let twice_area: f32 = (0..n)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum();
+    0.5 * twice_area
+}
+
+/// The area of a (non-self-intersecting) polygon.
+pub fn area(points: &[Pos2]) -> f32 {
+    signed_area(points).abs()
+}
+
+/// Which way the points of the polygon go around it.
+///
+/// Returns `None` if the polygon has no area.
+pub fn winding(points: &[Pos2]) -> Option<Winding> {
+    let signed_area = signed_area(points);
+    if 0.0 < signed_area {
+        Some(Winding::Clockwise)
+    } else if signed_area < 0.0 {
+        Some(Winding::CounterClockwise)
+    } else {
+        None
+    }
+}
+
+/// The center of mass of a (non-self-intersecting) polygon.
+///
+/// Returns `None` if the polygon has no area.
+pub fn centroid(points: &[Pos2]) -> Option<Pos2> {
+    let signed_area = signed_area(points);
+    if signed_area == 0.0 || !signed_area.is_finite() {
+        return None;
+    }
+
+    let n = points.len();
+    let (mut cx, mut cy) = (0.0, 0.0);
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        let cross = a.x * b.y - b.x * a.y;
+        cx += (a.x + b.x) * cross;
+        cy += (a.y + b.y) * cross;
+    }
+    let factor = 1.0 / (6.0 * signed_area);
+    Some(Pos2::new(cx * factor, cy * factor))
+}
+
+/// Is the polygon convex?
+///
+/// Only convex polygons can be filled correctly by `epaint`.
+/// Collinear points are allowed, but a polygon that turns both ways, or goes around more than once, is not convex.
+pub fn is_convex(points: &[Pos2]) -> bool {
+    let n = points.len();
+    if n < 3 {
+        return false;
+    }
+
+    let mut sign = 0.0;
+    let mut total_turn = 0.0;
+    for i in 0..n {
+        let (a, b, c) = (points[i], points[(i + 1) % n], points[(i + 2) % n]);
+        let (ab, bc) = (b - a, c - b);
+        let cross = ab.x * bc.y - ab.y * bc.x;
+        if cross != 0.0 {
+            if sign * cross < 0.0 {
+                return false; // turns both ways
+            }
+            sign = cross.signum();
+        }
+        total_turn += cross.atan2(ab.dot(bc));
+    }
+
+    // Make sure we go around exactly once:
+    sign != 0.0 && (total_turn.abs() - std::f32::consts::TAU).abs() < 1e-3
+}
+
+/// The convex hull of a set of points, i.e. the smallest convex polygon containing all of them.
+///
+/// The result goes clockwise on screen (with Y+ down), and has no collinear points.
+/// Non-finite points are ignored.
+pub fn convex_hull(points: &[Pos2]) -> Vec<Pos2> {
+    let mut points: Vec<Pos2> = points.iter().copied().filter(|p| p.is_finite()).collect();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    // Andrew's monotone chain:
+    let turns_clockwise = |hull: &[Pos2], p: Pos2| {
+        let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+        let (ab, bp) = (b - a, p - b);
+        0.0 < ab.x * bp.y - ab.y * bp.x
+    };
+
+    let mut hull: Vec<Pos2> = Vec::with_capacity(points.len() + 1);
+
+    // Top half (on screen), left to right:
+    for &p in &points {
+        while 2 <= hull.len() && !turns_clockwise(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    // Bottom half, right to left:
+    let top_len = hull.len();
+    for &p in points.iter().rev().skip(1) {
+        while top_len < hull.len() && !turns_clockwise(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    hull.pop(); // Same as the first point
+    hull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pos2;
+
+    #[test]
+    fn test_polygon_metrics() {
+        // Clockwise on screen:
+        let square = [
+            pos2(0.0, 0.0),
+            pos2(2.0, 0.0),
+            pos2(2.0, 2.0),
+            pos2(0.0, 2.0),
+        ];
+        assert_eq!(signed_area(&square), 4.0);
+        assert_eq!(winding(&square), Some(Winding::Clockwise));
+        assert_eq!(centroid(&square), Some(pos2(1.0, 1.0)));
+        assert!(is_convex(&square));
+
+        let mut reversed = square;
+        reversed.reverse();
+        assert_eq!(signed_area(&reversed), -4.0);
+        assert_eq!(winding(&reversed), Some(Winding::CounterClockwise));
+        assert_eq!(centroid(&reversed), Some(pos2(1.0, 1.0)));
+        assert!(is_convex(&reversed));
+
+        let arrow = [
+            pos2(0.0, 0.0),
+            pos2(2.0, 1.0),
+            pos2(0.0, 2.0),
+            pos2(1.0, 1.0),
+        ];
+        assert!(!is_convex(&arrow));
+
+        let line = [pos2(0.0, 0.0), pos2(1.0, 1.0), pos2(2.0, 2.0)];
+        assert_eq!(winding(&line), None);
+        assert_eq!(centroid(&line), None);
+        assert!(!is_convex(&line));
+
+        // A pentagram turns the same way at every corner, but goes around twice:
+        let pentagram: Vec<Pos2> = (0..5)
+            .map(|i| {
+                let angle = i as f32 * 2.0 * std::f32::consts::TAU / 5.0;
+                pos2(angle.cos(), angle.sin())
+            })
+            .collect();
+        assert!(!is_convex(&pentagram));
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let points = [
+            pos2(0.0, 0.0),
+            pos2(1.0, 1.0), // inside
+            pos2(2.0, 0.0),
+            pos2(1.0, 0.0), // collinear
+            pos2(2.0, 2.0),
+            pos2(0.0, 2.0),
+            pos2(0.5, 1.5), // inside
+            pos2(2.0, 2.0), // duplicate
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(
+            hull,
+            vec![
+                pos2(0.0, 0.0),
+                pos2(2.0, 0.0),
+                pos2(2.0, 2.0),
+                pos2(0.0, 2.0)
+            ]
+        );
+        assert_eq!(winding(&hull), Some(Winding::Clockwise));
+        assert!(is_convex(&hull));
+
+        assert_eq!(convex_hull(&[pos2(1.0, 1.0)]), vec![pos2(1.0, 1.0)]);
+    }
+}
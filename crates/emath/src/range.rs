@@ -1,5 +1,7 @@
 use std::ops::{RangeFrom, RangeFull, RangeInclusive, RangeToInclusive};
 
+use crate::RangeSet;
+
 /// Inclusive range of floats, i.e. `min..=max`, but more ergonomic than [`RangeInclusive`].
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -61,6 +63,14 @@ impl Rangef {
         self.min <= x && x <= self.max
     }
 
+    /// `max < min`, i.e. the range contains nothing.
+    ///
+    /// Note that a range with `min == max` is not empty: it contains one value.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.max < self.min
+    }
+
     /// Equivalent to `x.clamp(min, max)`
     #[inline]
     #[must_use]
@@ -97,6 +107,32 @@ impl Rangef {
         }
     }
 
+    /// Move the range by this much.
+    #[inline]
+    #[must_use]
+    pub fn shift(self, amnt: f32) -> Self {
+        Self {
+            min: self.min + amnt,
+            max: self.max + amnt,
+        }
+    }
+
+    /// Expand the range just enough to contain `x`.
+    ///
+    /// ```
+    /// # use emath::Rangef;
+    /// assert_eq!(Rangef::new(0.0, 10.0).expand_to_include(15.0), Rangef::new(0.0, 15.0));
+    /// assert_eq!(Rangef::NOTHING.expand_to_include(3.0), Rangef::point(3.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn expand_to_include(self, x: f32) -> Self {
+        Self {
+            min: self.min.min(x),
+            max: self.max.max(x),
+        }
+    }
+
     /// Flip the min and the max
     #[inline]
     #[must_use]
@@ -140,6 +176,60 @@ impl Rangef {
     pub fn intersects(self, other: Self) -> bool {
         other.min <= self.max && self.min <= other.max
     }
+
+    /// Do the two ranges share more than a single point?
+    ///
+    /// Unlike [`Self::intersects`], ranges that only touch do not overlap.
+    ///
+    /// ```
+    /// # use emath::Rangef;
+    /// assert!(Rangef::new(0.0, 10.0).overlaps(Rangef::new(5.0, 15.0)));
+    /// assert!(!Rangef::new(0.0, 10.0).overlaps(Rangef::new(10.0, 20.0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overlaps(self, other: Self) -> bool {
+        other.min < self.max && self.min < other.max
+    }
+
+    /// All values in either range.
+    ///
+    /// This is one range if they intersect, and two otherwise.
+    ///
+    /// ```
+    /// # use emath::Rangef;
+    /// let union = Rangef::new(0.0, 10.0).union(Rangef::new(20.0, 30.0));
+    /// assert_eq!(union.ranges(), &[Rangef::new(0.0, 10.0), Rangef::new(20.0, 30.0)]);
+    /// ```
+    #[must_use]
+    pub fn union(self, other: Self) -> RangeSet {
+        [self, other].into_iter().collect()
+    }
+
+    /// All values in `self` that are not in `other`.
+    ///
+    /// This is two ranges if `other` is strictly inside `self`.
+    ///
+    /// ```
+    /// # use emath::Rangef;
+    /// let difference = Rangef::new(0.0, 10.0).difference(Rangef::new(4.0, 6.0));
+    /// assert_eq!(difference.ranges(), &[Rangef::new(0.0, 4.0), Rangef::new(6.0, 10.0)]);
+    /// ```
+    #[must_use]
+    pub fn difference(self, other: Self) -> RangeSet {
+        if self.is_empty() {
+            RangeSet::new()
+        } else if other.is_empty() || !self.overlaps(other) {
+            RangeSet::from(self)
+        } else {
+            let below = Self::new(self.min, other.min);
+            let above = Self::new(other.max, self.max);
+            [below, above]
+                .into_iter()
+                .filter(|r| 0.0 < r.span())
+                .collect()
+        }
+    }
 }
 
 impl From<Rangef> for RangeInclusive<f32> {
@@ -0,0 +1,205 @@
+use crate::Rangef;
+
+/// A set of disjoint [`Rangef`]s, kept sorted from low to high.
+///
+/// This is the result of e.g. [`Rangef::union`] and [`Rangef::difference`],
+/// which can produce more than one range.
+///
+/// Ranges that touch or overlap are merged, and empty ranges (`max < min`) are ignored.
+///
+/// ```
+/// # use emath::{Rangef, RangeSet};
+/// let mut visible = RangeSet::from(Rangef::new(0.0, 100.0));
+/// visible.remove(Rangef::new(0.0, 20.0)); // sticky header
+/// visible.remove(Rangef::new(50.0, 60.0)); // sticky section header
+/// assert_eq!(visible.ranges(), &[Rangef::new(20.0, 50.0), Rangef::new(60.0, 100.0)]);
+/// assert_eq!(visible.total_span(), 70.0);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RangeSet {
+    /// Sorted, non-empty and non-touching.
+    ranges: Vec<Rangef>,
+}
+
+impl RangeSet {
+    /// The empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// The disjoint ranges, sorted from low to high.
+    #[inline]
+    pub fn ranges(&self) -> &[Rangef] {
+        &self.ranges
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Rangef> + '_ {
+        self.ranges.iter().copied()
+    }
+
+    /// Number of disjoint ranges.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The smallest range containing all of the set, or [`Rangef::NOTHING`] if empty.
+    pub fn bounding_range(&self) -> Rangef {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => Rangef::new(first.min, last.max),
+            _ => Rangef::NOTHING,
+        }
+    }
+
+    /// Sum of the spans of all the ranges.
+    pub fn total_span(&self) -> f32 {
+        self.ranges.iter().map(|r| r.span()).sum()
+    }
+
+    /// Is `x` in any of the ranges?
+    pub fn contains(&self, x: f32) -> bool {
+        self.ranges.iter().any(|r| r.contains(x))
+    }
+
+    /// Add a range to the set, merging it with any ranges it touches.
+    pub fn insert(&mut self, range: Rangef) {
+        if range.is_empty() {
+            return;
+        }
+
+        let mut merged = range;
+        self.ranges.retain(|r| {
+            if r.intersects(merged) {
+                merged = Rangef::new(merged.min.min(r.min), merged.max.max(r.max));
+                false
+            } else {
+                true
+            }
+        });
+
+        let index = self.ranges.partition_point(|r| r.max < merged.min);
+        self.ranges.insert(index, merged);
+    }
+
+    /// Remove a range from the set, splitting any range it is in the middle of.
+    pub fn remove(&mut self, range: Rangef) {
+        if range.is_empty() {
+            return;
+        }
+
+        let old = std::mem::take(&mut self.ranges);
+        for r in old {
+            for piece in r.difference(range).ranges {
+                self.ranges.push(piece);
+            }
+        }
+    }
+
+    /// All values that are in either set.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.insert(range);
+        }
+        result
+    }
+
+    /// All values that are in `self` but not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.remove(range);
+        }
+        result
+    }
+
+    /// All values that are in both `self` and the given range.
+    #[must_use]
+    pub fn intersection(&self, range: Rangef) -> Self {
+        self.iter()
+            .map(|r| r.intersection(range))
+            .filter(|r| !r.is_empty())
+            .collect()
+    }
+}
+
+impl From<Rangef> for RangeSet {
+    #[inline]
+    fn from(range: Rangef) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<Rangef> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Rangef>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl IntoIterator for RangeSet {
+    type Item = Rangef;
+    type IntoIter = std::vec::IntoIter<Rangef>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_set() {
+        let r = Rangef::new;
+
+        let mut set = RangeSet::new();
+        set.insert(r(5.0, 6.0));
+        set.insert(r(0.0, 1.0));
+        set.insert(r(10.0, 20.0));
+        assert_eq!(set.ranges(), &[r(0.0, 1.0), r(5.0, 6.0), r(10.0, 20.0)]);
+
+        set.insert(r(6.0, 10.0));
+        assert_eq!(set.ranges(), &[r(0.0, 1.0), r(5.0, 20.0)]);
+
+        set.insert(Rangef::NOTHING);
+        assert_eq!(set.len(), 2);
+
+        set.remove(r(-1.0, 0.5));
+        set.remove(r(8.0, 9.0));
+        assert_eq!(set.ranges(), &[r(0.5, 1.0), r(5.0, 8.0), r(9.0, 20.0)]);
+        assert!(set.contains(7.0));
+        assert!(!set.contains(8.5));
+        assert_eq!(set.total_span(), 0.5 + 3.0 + 11.0);
+        assert_eq!(set.bounding_range(), r(0.5, 20.0));
+
+        assert_eq!(
+            set.intersection(r(6.0, 10.0)).ranges(),
+            &[r(6.0, 8.0), r(9.0, 10.0)]
+        );
+
+        let other: RangeSet = [r(0.0, 6.0), r(15.0, 30.0)].into_iter().collect();
+        assert_eq!(set.union(&other).ranges(), &[r(0.0, 8.0), r(9.0, 30.0)]);
+        assert_eq!(
+            set.difference(&other).ranges(),
+            &[r(6.0, 8.0), r(9.0, 15.0)]
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{Pos2, Rect, Rot2, Vec2};
+
+/// A rectangle that is rotated around its center.
+///
+/// Useful for rotated handles, labels and other things on an editable canvas.
+///
+/// An angle of zero gives an axis-aligned rectangle, just like a [`Rect`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct RotatedRect {
+    /// The center of the rectangle, which is also the pivot of the rotation.
+    pub center: Pos2,
+
+    /// Half the width and height of the rectangle, before rotation.
+    pub half_size: Vec2,
+
+    /// Clockwise rotation in radians.
+    pub angle: f32,
+}
+
+impl RotatedRect {
+    #[inline]
+    pub fn new(center: Pos2, half_size: Vec2, angle: f32) -> Self {
+        Self {
+            center,
+            half_size,
+            angle,
+        }
+    }
+
+    /// Rotate the given [`Rect`] around its center.
+    #[inline]
+    pub fn from_rect(rect: Rect, angle: f32) -> Self {
+        Self::new(rect.center(), 0.5 * rect.size(), angle)
+    }
+
+    /// The full width and height of the rectangle, before rotation.
+    #[inline]
+    pub fn size(&self) -> Vec2 {
+        2.0 * self.half_size
+    }
+
+    #[inline]
+    pub fn rot(&self) -> Rot2 {
+        Rot2::from_angle(self.angle)
+    }
+
+    /// The four corners, in clockwise order, starting with what was the left top corner before rotation.
+    pub fn corners(&self) -> [Pos2; 4] {
+        let rot = self.rot();
+        let Vec2 { x, y } = self.half_size;
+        [
+            self.center + rot * Vec2::new(-x, -y),
+            self.center + rot * Vec2::new(x, -y),
+            self.center + rot * Vec2::new(x, y),
+            self.center + rot * Vec2::new(-x, y),
+        ]
+    }
+
+    /// The smallest axis-aligned [`Rect`] that contains all of this rectangle.
+    pub fn bounding_rect(&self) -> Rect {
+        let (s, c) = self.angle.sin_cos();
+        let half_extent = Vec2::new(
+            (c * self.half_size.x).abs() + (s * self.half_size.y).abs(),
+            (s * self.half_size.x).abs() + (c * self.half_size.y).abs(),
+        );
+        Rect::from_center_size(self.center, 2.0 * half_extent)
+    }
+
+    /// Convert a position to the local (unrotated) coordinate system of the rectangle,
+    /// where the center is at the origin.
+    #[inline]
+    pub fn to_local(self, pos: Pos2) -> Vec2 {
+        self.rot().inverse() * (pos - self.center)
+    }
+
+    /// Is the given position inside the rectangle (or on its edge)?
+    #[inline]
+    pub fn contains(&self, pos: Pos2) -> bool {
+        let local = self.to_local(pos);
+        local.x.abs() <= self.half_size.x && local.y.abs() <= self.half_size.y
+    }
+
+    /// Distance from the given position to the rectangle. Zero if inside.
+    pub fn distance_to_pos(&self, pos: Pos2) -> f32 {
+        let local = self.to_local(pos);
+        let outside = (local.abs() - self.half_size).max(Vec2::ZERO);
+        outside.length()
+    }
+
+    /// Move the rectangle by the given amount.
+    #[inline]
+    #[must_use]
+    pub fn translate(self, delta: Vec2) -> Self {
+        Self {
+            center: self.center + delta,
+            ..self
+        }
+    }
+
+    /// Rotate the rectangle by an additional angle around its center.
+    #[inline]
+    #[must_use]
+    pub fn rotate(self, angle: f32) -> Self {
+        Self {
+            angle: self.angle + angle,
+            ..self
+        }
+    }
+}
+
+impl From<Rect> for RotatedRect {
+    #[inline]
+    fn from(rect: Rect) -> Self {
+        Self::from_rect(rect, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pos2, vec2};
+
+    #[test]
+    fn test_rotated_rect() {
+        let rect = Rect::from_center_size(pos2(10.0, 20.0), vec2(4.0, 2.0));
+
+        let unrotated = RotatedRect::from(rect);
+        assert_eq!(unrotated.bounding_rect(), rect);
+        assert!(unrotated.contains(pos2(11.9, 20.9)));
+        assert!(!unrotated.contains(pos2(12.1, 20.0)));
+
+        let quarter = RotatedRect::from_rect(rect, std::f32::consts::TAU / 4.0);
+        let bb = quarter.bounding_rect();
+        assert!((bb.width() - 2.0).abs() < 1e-4);
+        assert!((bb.height() - 4.0).abs() < 1e-4);
+        assert!(quarter.contains(pos2(10.0, 21.9)));
+        assert!(!quarter.contains(pos2(11.9, 20.0)));
+        assert!((quarter.distance_to_pos(pos2(13.0, 20.0)) - 2.0).abs() < 1e-4);
+
+        let eighth = RotatedRect::from_rect(rect, std::f32::consts::TAU / 8.0);
+        let bb = eighth.bounding_rect();
+        for corner in eighth.corners() {
+            assert!(bb.expand(1e-4).contains(corner));
+            assert!(eighth.contains(corner - 1e-3 * (corner - eighth.center)));
+        }
+    }
+}
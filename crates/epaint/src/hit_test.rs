@@ -0,0 +1,411 @@
+use std::f32::consts::TAU;
+
+use emath::{Pos2, Rot2, Vec2};
+
+use crate::{
+    ArcShape, CircleShape, Color32, EllipseShape, Mesh, PathShape, PieShape, RectShape, Shape,
+    SvgPathShape, TextShape,
+};
+
+/// Curves are flattened to lines this close to the real curve before measuring distances.
+const CURVE_TOLERANCE: f32 = 0.1;
+
+/// ## Hit testing
+impl Shape {
+    /// Is the given position on the painted part of the shape?
+    ///
+    /// Same as `self.distance_to(pos) <= 0.0`. See [`Self::distance_to`].
+    #[inline]
+    pub fn contains(&self, pos: Pos2) -> bool {
+        self.distance_to(pos) <= 0.0
+    }
+
+    /// The distance from the given position to the painted part of the shape.
+    ///
+    /// Zero if the position is inside the fill, or within the width of the stroke.
+    /// The interior only counts if the shape is filled.
+    ///
+    /// Thin lines are hard to hit exactly, so for picking you usually want to allow some slack:
+    ///
+    /// ```
+    /// # use epaint::{pos2, Color32, Shape, Stroke};
+    /// let line = Shape::line_segment([pos2(0.0, 0.0), pos2(100.0, 0.0)], Stroke::new(2.0, Color32::WHITE));
+    /// assert!(line.contains(pos2(50.0, 0.5)));
+    /// assert!(!line.contains(pos2(50.0, 3.0)));
+    /// assert!(line.distance_to(pos2(50.0, 3.0)) <= 4.0);
+    /// ```
+    ///
+    /// Ellipses are approximated, and curves are flattened first.
+    /// [`Shape::PrecomputedMesh`] is never hit, since its mesh is not known here.
+    pub fn distance_to(&self, pos: Pos2) -> f32 {
+        match self {
+            Self::Noop | Self::PrecomputedMesh(..) => f32::INFINITY,
+            Self::Vec(shapes) => shapes
+                .iter()
+                .map(|shape| shape.distance_to(pos))
+                .fold(f32::INFINITY, f32::min),
+            Self::Circle(circle) => circle_distance(circle, pos),
+            Self::Ellipse(ellipse) => ellipse_distance(ellipse, pos),
+            Self::Arc(arc) => arc_distance(arc, pos),
+            Self::Pie(pie) => pie_distance(pie, pos),
+            Self::LineSegment { points, stroke } => {
+                at_least_zero(segment_distance(pos, points[0], points[1]) - 0.5 * stroke.width)
+            }
+            Self::Path(path) => path_distance(path, pos),
+            Self::Rect(rect) => rect_distance(rect, pos),
+            Self::Text(text) => text_distance(text, pos),
+            Self::Mesh(mesh) => mesh_distance(mesh, pos),
+            Self::QuadraticBezier(bezier) => {
+                path_distance(&bezier.to_path_shape(Some(CURVE_TOLERANCE)), pos)
+            }
+            Self::CubicBezier(bezier) => bezier
+                .to_path_shapes(Some(CURVE_TOLERANCE), None)
+                .iter()
+                .map(|path| path_distance(path, pos))
+                .fold(f32::INFINITY, f32::min),
+            Self::CatmullRom(spline) => path_distance(&spline.to_path_shape(CURVE_TOLERANCE), pos),
+            Self::SvgPath(svg_path) => svg_path_distance(svg_path, pos),
+            Self::Callback(callback) => callback.rect.distance_to_pos(pos),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[inline]
+fn at_least_zero(distance: f32) -> f32 {
+    distance.max(0.0)
+}
+
+/// Combine the signed distance to the outline (negative inside) with the fill and stroke.
+fn fill_and_stroke_distance(signed_distance: f32, filled: bool, stroke_width: f32) -> f32 {
+    let distance = if filled {
+        signed_distance
+    } else {
+        signed_distance.abs()
+    };
+    at_least_zero(distance - 0.5 * stroke_width)
+}
+
+fn circle_distance(circle: &CircleShape, pos: Pos2) -> f32 {
+    let signed_distance = pos.distance(circle.center) - circle.radius;
+    fill_and_stroke_distance(
+        signed_distance,
+        circle.fill != Color32::TRANSPARENT,
+        circle.stroke.width,
+    )
+}
+
+fn ellipse_distance(ellipse: &EllipseShape, pos: Pos2) -> f32 {
+    let radius = ellipse.radius;
+    let signed_distance = if radius.x <= 0.0 || radius.y <= 0.0 {
+        // Degenerate: a line segment.
+        segment_distance(pos, ellipse.center - radius, ellipse.center + radius)
+    } else {
+        // A good approximation of the distance to an ellipse, see
+        // https://iquilezles.org/articles/ellipsedist/
+        let p = pos - ellipse.center;
+        let k0 = (p / radius).length();
+        let k1 = (p / (radius * radius)).length();
+        if k1 == 0.0 {
+            -radius.min_elem()
+        } else {
+            k0 * (k0 - 1.0) / k1
+        }
+    };
+    fill_and_stroke_distance(
+        signed_distance,
+        ellipse.fill != Color32::TRANSPARENT,
+        ellipse.stroke.width,
+    )
+}
+
+/// Is the direction from `center` to `pos` within the angle range (going either way)?
+fn is_within_angles(center: Pos2, pos: Pos2, start_angle: f32, end_angle: f32) -> bool {
+    let (from, to) = if start_angle <= end_angle {
+        (start_angle, end_angle)
+    } else {
+        (end_angle, start_angle)
+    };
+    if TAU <= to - from {
+        return true;
+    }
+    let angle = (pos - center).angle();
+    (angle - from).rem_euclid(TAU) <= to - from
+}
+
+fn point_on_circle(center: Pos2, radius: f32, angle: f32) -> Pos2 {
+    center + radius * Vec2::angled(angle)
+}
+
+/// Distance to the arc itself, ignoring stroke width.
+fn arc_outline_distance(
+    center: Pos2,
+    radius: f32,
+    start_angle: f32,
+    end_angle: f32,
+    pos: Pos2,
+) -> f32 {
+    if is_within_angles(center, pos, start_angle, end_angle) {
+        (pos.distance(center) - radius).abs()
+    } else {
+        let start = point_on_circle(center, radius, start_angle);
+        let end = point_on_circle(center, radius, end_angle);
+        pos.distance(start).min(pos.distance(end))
+    }
+}
+
+fn arc_distance(arc: &ArcShape, pos: Pos2) -> f32 {
+    let distance =
+        arc_outline_distance(arc.center, arc.radius, arc.start_angle, arc.end_angle, pos);
+    at_least_zero(distance - 0.5 * arc.stroke.width)
+}
+
+fn pie_distance(pie: &PieShape, pos: Pos2) -> f32 {
+    let PieShape {
+        center,
+        radius,
+        start_angle,
+        end_angle,
+        fill,
+        stroke,
+    } = *pie;
+
+    if TAU <= (end_angle - start_angle).abs() {
+        return circle_distance(
+            &CircleShape {
+                center,
+                radius,
+                fill,
+                stroke,
+            },
+            pos,
+        );
+    }
+
+    let is_inside =
+        pos.distance(center) <= radius && is_within_angles(center, pos, start_angle, end_angle);
+    if fill != Color32::TRANSPARENT && is_inside {
+        return 0.0;
+    }
+
+    let outline_distance = arc_outline_distance(center, radius, start_angle, end_angle, pos)
+        .min(segment_distance(
+            pos,
+            center,
+            point_on_circle(center, radius, start_angle),
+        ))
+        .min(segment_distance(
+            pos,
+            center,
+            point_on_circle(center, radius, end_angle),
+        ));
+    at_least_zero(outline_distance - 0.5 * stroke.width)
+}
+
+fn path_distance(path: &PathShape, pos: Pos2) -> f32 {
+    let points = &path.points;
+    if points.is_empty() {
+        return f32::INFINITY;
+    }
+    if path.closed && path.fill != Color32::TRANSPARENT && polygon_contains(points, pos) {
+        return 0.0;
+    }
+    at_least_zero(polyline_distance(points, path.closed, pos) - 0.5 * path.stroke.width)
+}
+
+fn svg_path_distance(svg_path: &SvgPathShape, pos: Pos2) -> f32 {
+    let subpaths = svg_path.path.flatten(CURVE_TOLERANCE);
+    if svg_path.fill != Color32::TRANSPARENT
+        && svg_path
+            .fill_rule
+            .is_inside(crate::svg_path::winding_number(&subpaths, pos))
+    {
+        return 0.0;
+    }
+    let outline_distance = subpaths
+        .iter()
+        .map(|(points, closed)| polyline_distance(points, *closed, pos))
+        .fold(f32::INFINITY, f32::min);
+    at_least_zero(outline_distance - 0.5 * svg_path.stroke.width)
+}
+
+fn rect_distance(rect_shape: &RectShape, pos: Pos2) -> f32 {
+    let rect = rect_shape.rect;
+    if !rect.is_finite() || rect.is_negative() {
+        return f32::INFINITY;
+    }
+
+    // Work in the coordinate system of the (unrotated) rectangle, centered on the origin:
+    let local = Rot2::from_angle(-rect_shape.angle) * (pos - rect.center());
+    let half_size = 0.5 * rect.size();
+
+    let rounding = rect_shape.rounding;
+    let corner_radius = match (local.x < 0.0, local.y < 0.0) {
+        (true, true) => rounding.nw,
+        (false, true) => rounding.ne,
+        (true, false) => rounding.sw,
+        (false, false) => rounding.se,
+    }
+    .clamp(0.0, half_size.min_elem());
+
+    // Signed distance to a rounded box:
+    let q = local.abs() - half_size + Vec2::splat(corner_radius);
+    let signed_distance = q.max(Vec2::ZERO).length() + q.max_elem().min(0.0) - corner_radius;
+
+    let filled =
+        rect_shape.fill != Color32::TRANSPARENT || rect_shape.fill_texture_id != Default::default();
+    fill_and_stroke_distance(signed_distance, filled, rect_shape.stroke.width)
+}
+
+fn text_distance(text: &TextShape, pos: Pos2) -> f32 {
+    // The text is rotated around `text.pos`:
+    let local = text.pos + Rot2::from_angle(-text.angle) * (pos - text.pos);
+    let rect = text.galley.rect.translate(text.pos.to_vec2());
+    rect.distance_to_pos(local)
+}
+
+fn mesh_distance(mesh: &Mesh, pos: Pos2) -> f32 {
+    let mut distance = f32::INFINITY;
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].pos);
+        if triangle_contains([a, b, c], pos) {
+            return 0.0;
+        }
+        distance = distance.min(polyline_distance(&[a, b, c], true, pos));
+    }
+    distance
+}
+
+// ----------------------------------------------------------------------------
+
+fn segment_distance(pos: Pos2, a: Pos2, b: Pos2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    if length_sq <= 0.0 {
+        return pos.distance(a);
+    }
+    let t = ((pos - a).dot(ab) / length_sq).clamp(0.0, 1.0);
+    pos.distance(a + t * ab)
+}
+
+fn polyline_distance(points: &[Pos2], closed: bool, pos: Pos2) -> f32 {
+    match points {
+        [] => f32::INFINITY,
+        [point] => pos.distance(*point),
+        _ => {
+            let closing_segment = closed.then(|| [points[points.len() - 1], points[0]]);
+            points
+                .windows(2)
+                .map(|segment| [segment[0], segment[1]])
+                .chain(closing_segment)
+                .map(|[a, b]| segment_distance(pos, a, b))
+                .fold(f32::INFINITY, f32::min)
+        }
+    }
+}
+
+/// Even-odd rule.
+fn polygon_contains(points: &[Pos2], pos: Pos2) -> bool {
+    let mut inside = false;
+    let mut previous = points[points.len() - 1];
+    for &point in points {
+        if (point.y > pos.y) != (previous.y > pos.y) {
+            let x = point.x + (pos.y - point.y) * (previous.x - point.x) / (previous.y - point.y);
+            if pos.x < x {
+                inside = !inside;
+            }
+        }
+        previous = point;
+    }
+    inside
+}
+
+/// Degenerate triangles (with zero area) contain nothing.
+fn triangle_contains([a, b, c]: [Pos2; 3], pos: Pos2) -> bool {
+    let area = (b - a).x * (c - a).y - (b - a).y * (c - a).x;
+    if area == 0.0 {
+        return false;
+    }
+
+    let cross = |p: Pos2, q: Pos2| (q - p).x * (pos - p).y - (q - p).y * (pos - p).x;
+    let (d0, d1, d2) = (cross(a, b), cross(b, c), cross(c, a));
+    let has_negative = d0 < 0.0 || d1 < 0.0 || d2 < 0.0;
+    let has_positive = d0 > 0.0 || d1 > 0.0 || d2 > 0.0;
+    !(has_negative && has_positive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pos2, vec2, Rect, Rounding, Stroke};
+
+    #[test]
+    fn test_distance_to_filled_and_stroked_shapes() {
+        let stroke = Stroke::new(2.0, Color32::WHITE);
+
+        let filled = Shape::circle_filled(pos2(0.0, 0.0), 10.0, Color32::RED);
+        assert!(filled.contains(pos2(3.0, 4.0)));
+        assert_eq!(filled.distance_to(pos2(0.0, 15.0)), 5.0);
+
+        let outline = Shape::circle_stroke(pos2(0.0, 0.0), 10.0, stroke);
+        assert!(!outline.contains(pos2(3.0, 4.0)));
+        assert!(outline.contains(pos2(0.0, 10.5)));
+        assert_eq!(outline.distance_to(pos2(0.0, 0.0)), 9.0);
+
+        let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(20.0, 10.0));
+        let rect_outline = Shape::rect_stroke(rect, Rounding::ZERO, stroke);
+        assert!(!rect_outline.contains(pos2(10.0, 5.0)));
+        assert!(rect_outline.contains(pos2(20.5, 5.0)));
+        assert_eq!(rect_outline.distance_to(pos2(25.0, 5.0)), 4.0);
+
+        // Rounded corners are not hit:
+        let rounded = Shape::rect_filled(rect, 5.0, Color32::RED);
+        assert!(rounded.contains(pos2(10.0, 5.0)));
+        assert!(!rounded.contains(pos2(0.5, 0.5)));
+    }
+
+    #[test]
+    fn test_distance_to_paths_and_curves() {
+        let stroke = Stroke::new(1.0, Color32::WHITE);
+        let triangle = vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(0.0, 10.0)];
+
+        let filled = Shape::convex_polygon(triangle.clone(), Color32::RED, stroke);
+        assert!(filled.contains(pos2(2.0, 2.0)));
+        assert!(!filled.contains(pos2(8.0, 8.0)));
+
+        let open = Shape::line(triangle, stroke);
+        assert!(!open.contains(pos2(2.0, 2.0)));
+        assert_eq!(open.distance_to(pos2(5.0, -3.0)), 2.5);
+        assert!(
+            2.5 < open.distance_to(pos2(-3.0, 5.0)),
+            "The open side is not painted"
+        );
+
+        let bezier = crate::QuadraticBezierShape::from_points_stroke(
+            [pos2(0.0, 0.0), pos2(10.0, 20.0), pos2(20.0, 0.0)],
+            false,
+            Color32::TRANSPARENT,
+            stroke,
+        );
+        let middle = bezier.sample(0.5);
+        let shape = Shape::QuadraticBezier(bezier);
+        assert!(shape.contains(middle));
+        assert!(!shape.contains(pos2(10.0, 0.0)));
+    }
+
+    #[test]
+    fn test_degenerate_triangles() {
+        let point = pos2(1.0, 1.0);
+        assert!(!triangle_contains([point; 3], point));
+        assert!(!triangle_contains([point; 3], pos2(5.0, 5.0)));
+
+        let line = [pos2(0.0, 0.0), pos2(5.0, 0.0), pos2(10.0, 0.0)];
+        assert!(!triangle_contains(line, pos2(2.0, 0.0)));
+
+        let mut mesh = crate::Mesh::default();
+        mesh.colored_vertex(point, Color32::RED);
+        mesh.add_triangle(0, 0, 0);
+        assert!(!Shape::mesh(mesh).contains(pos2(5.0, 5.0)));
+    }
+}
@@ -1,5 +1,9 @@
-use crate::{textures::TextureOptions, Color32};
-use std::sync::Arc;
+use crate::{
+    text::GlyphRasterization,
+    textures::{TextureFilter, TextureOptions},
+    Color32, ColorSpace, Rgba, ToneMapping,
+};
+use std::{borrow::Cow, sync::Arc};
 
 /// An image stored in RAM.
 ///
@@ -52,6 +56,10 @@ pub struct ColorImage {
 
     /// The pixels, row by row, from top to bottom.
     pub pixels: Vec<Color32>,
+
+    /// See [`Self::color_space`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    color_space: ColorSpace,
 }
 
 impl ColorImage {
@@ -60,6 +68,19 @@ impl ColorImage {
         Self {
             size,
             pixels: vec![color; size[0] * size[1]],
+            color_space: ColorSpace::Srgb,
+        }
+    }
+
+    /// Create an image from sRGBA pixels with premultiplied alpha, row by row from top to bottom.
+    ///
+    /// Panics if `size[0] * size[1] != pixels.len()`.
+    pub fn from_pixels(size: [usize; 2], pixels: Vec<Color32>) -> Self {
+        assert_eq!(size[0] * size[1], pixels.len());
+        Self {
+            size,
+            pixels,
+            color_space: ColorSpace::Srgb,
         }
     }
 
@@ -99,7 +120,11 @@ impl ColorImage {
             .chunks_exact(4)
             .map(|p| Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
             .collect();
-        Self { size, pixels }
+        Self {
+            size,
+            pixels,
+            color_space: ColorSpace::Srgb,
+        }
     }
 
     pub fn from_rgba_premultiplied(size: [usize; 2], rgba: &[u8]) -> Self {
@@ -108,7 +133,11 @@ impl ColorImage {
             .chunks_exact(4)
             .map(|p| Color32::from_rgba_premultiplied(p[0], p[1], p[2], p[3]))
             .collect();
-        Self { size, pixels }
+        Self {
+            size,
+            pixels,
+            color_space: ColorSpace::Srgb,
+        }
     }
 
     /// Create a [`ColorImage`] from flat opaque gray data.
@@ -117,7 +146,11 @@ impl ColorImage {
     pub fn from_gray(size: [usize; 2], gray: &[u8]) -> Self {
         assert_eq!(size[0] * size[1], gray.len());
         let pixels = gray.iter().map(|p| Color32::from_gray(*p)).collect();
-        Self { size, pixels }
+        Self {
+            size,
+            pixels,
+            color_space: ColorSpace::Srgb,
+        }
     }
 
     /// Alternative method to `from_gray`.
@@ -127,7 +160,11 @@ impl ColorImage {
     pub fn from_gray_iter(size: [usize; 2], gray_iter: impl Iterator<Item = u8>) -> Self {
         let pixels: Vec<_> = gray_iter.map(Color32::from_gray).collect();
         assert_eq!(size[0] * size[1], pixels.len());
-        Self { size, pixels }
+        Self {
+            size,
+            pixels,
+            color_space: ColorSpace::Srgb,
+        }
     }
 
     /// A view of the underlying data as `&[u8]`
@@ -149,12 +186,22 @@ impl ColorImage {
     /// Panics if `region.min.x > region.max.x || region.min.y > region.max.y`, or if a region larger than the image is passed.
     pub fn region(&self, region: &emath::Rect, pixels_per_point: Option<f32>) -> Self {
         let pixels_per_point = pixels_per_point.unwrap_or(1.0);
-        let min_x = (region.min.x * pixels_per_point) as usize;
-        let max_x = (region.max.x * pixels_per_point) as usize;
-        let min_y = (region.min.y * pixels_per_point) as usize;
-        let max_y = (region.max.y * pixels_per_point) as usize;
-        assert!(min_x <= max_x);
-        assert!(min_y <= max_y);
+        let region = *region * pixels_per_point;
+        self.pixel_region(emath::IRect::from_min_max(
+            emath::IVec2::from_vec2_floor(region.min.to_vec2()).max(emath::IVec2::ZERO),
+            emath::IVec2::from_vec2_floor(region.max.to_vec2()).max(emath::IVec2::ZERO),
+        ))
+    }
+
+    /// Create a new Image from a patch of the current image, given in pixels.
+    ///
+    /// Panics if `region` is negative or extends outside of the image.
+    pub fn pixel_region(&self, region: emath::IRect) -> Self {
+        assert!(0 <= region.min.x && 0 <= region.min.y);
+        assert!(region.min.x <= region.max.x);
+        assert!(region.min.y <= region.max.y);
+        let [min_x, min_y] = region.min.to_usize_array();
+        let [max_x, max_y] = region.max.to_usize_array();
         let width = max_x - min_x;
         let height = max_y - min_y;
         let mut output = Vec::with_capacity(width * height);
@@ -168,6 +215,7 @@ impl ColorImage {
         Self {
             size: [width, height],
             pixels: output,
+            color_space: self.color_space,
         }
     }
 
@@ -183,7 +231,75 @@ impl ColorImage {
             .chunks_exact(3)
             .map(|p| Color32::from_rgb(p[0], p[1], p[2]))
             .collect();
-        Self { size, pixels }
+        Self {
+            size,
+            pixels,
+            color_space: ColorSpace::Srgb,
+        }
+    }
+
+    /// Create a [`ColorImage`] from HDR pixels (linear, premultiplied alpha, channels may exceed `1.0`).
+    ///
+    /// The pixels are mapped into the displayable range with `tone_mapping`.
+    /// To show HDR content unchanged on an HDR display, upload it as a floating point
+    /// texture to your renderer instead (e.g. with `egui_wgpu::Renderer::register_native_texture`).
+    ///
+    /// Panics if `size[0] * size[1] != pixels.len()`.
+    pub fn from_hdr(size: [usize; 2], pixels: &[Rgba], tone_mapping: ToneMapping) -> Self {
+        crate::profile_function!();
+        assert_eq!(size[0] * size[1], pixels.len());
+        let pixels = pixels
+            .iter()
+            .map(|&rgba| tone_mapping.map_rgba(rgba).into())
+            .collect();
+        Self {
+            size,
+            pixels,
+            color_space: ColorSpace::Srgb,
+        }
+    }
+
+    /// The color space of [`Self::pixels`]. [`ColorSpace::Srgb`] unless set with [`Self::with_color_space`].
+    ///
+    /// The renderers convert the pixels to sRGB before uploading them, see [`Self::srgb_pixels`].
+    /// If you show the same image many times, it is better to convert it once with [`Self::convert_to`].
+    #[inline]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Set the color space of the pixels, without converting them.
+    ///
+    /// Use this to tag an image that you loaded or generated yourself, e.g. from a Display P3 photo.
+    #[inline]
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    /// Convert the pixels to the given color space.
+    pub fn convert_to(&mut self, color_space: ColorSpace) {
+        self.color_space
+            .convert_pixels(&mut self.pixels, color_space);
+        self.color_space = color_space;
+    }
+
+    /// The pixels in the given color space, converting them if needed.
+    pub fn pixels_in(&self, color_space: ColorSpace) -> Cow<'_, [Color32]> {
+        if self.color_space == color_space {
+            Cow::Borrowed(&self.pixels)
+        } else {
+            let mut pixels = self.pixels.clone();
+            self.color_space.convert_pixels(&mut pixels, color_space);
+            Cow::Owned(pixels)
+        }
+    }
+
+    /// The pixels in sRGB, which is what egui paints in.
+    ///
+    /// This is what the renderers upload to the GPU.
+    pub fn srgb_pixels(&self) -> Cow<'_, [Color32]> {
+        self.pixels_in(ColorSpace::Srgb)
     }
 
     /// An example color image, useful for tests.
@@ -212,6 +328,144 @@ impl ColorImage {
     pub fn height(&self) -> usize {
         self.size[1]
     }
+
+    /// Create a new image of size `[x, y]`, where each pixel is given by `pixel(x, y)`.
+    fn from_fn(size: [usize; 2], mut pixel: impl FnMut(usize, usize) -> Color32) -> Self {
+        let [width, height] = size;
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                pixels.push(pixel(x, y));
+            }
+        }
+        Self {
+            size,
+            pixels,
+            color_space: ColorSpace::Srgb,
+        }
+    }
+
+    /// Create a resized copy of this image.
+    ///
+    /// [`TextureFilter::Nearest`] picks the closest pixel, and is best for pixel art.
+    /// [`TextureFilter::Linear`] does bilinear interpolation, and is best for photos.
+    ///
+    /// Bilinear interpolation samples only the four nearest pixels,
+    /// so shrinking an image a lot will alias.
+    /// In that case, shrink it in several steps of at most half the size.
+    pub fn resize(&self, new_size: [usize; 2], filter: TextureFilter) -> Self {
+        let [src_w, src_h] = self.size;
+        let [dst_w, dst_h] = new_size;
+        if src_w == 0 || src_h == 0 {
+            return Self::new(new_size, Color32::TRANSPARENT).with_color_space(self.color_space);
+        }
+
+        let scale_x = src_w as f32 / dst_w as f32;
+        let scale_y = src_h as f32 / dst_h as f32;
+
+        match filter {
+            TextureFilter::Nearest => Self::from_fn(new_size, |x, y| {
+                let src_x = ((x as f32 + 0.5) * scale_x) as usize;
+                let src_y = ((y as f32 + 0.5) * scale_y) as usize;
+                self[(src_x.min(src_w - 1), src_y.min(src_h - 1))]
+            })
+            .with_color_space(self.color_space),
+            TextureFilter::Linear => Self::from_fn(new_size, |x, y| {
+                // Sample at the pixel centers:
+                let src_x = ((x as f32 + 0.5) * scale_x - 0.5).clamp(0.0, (src_w - 1) as f32);
+                let src_y = ((y as f32 + 0.5) * scale_y - 0.5).clamp(0.0, (src_h - 1) as f32);
+                let (x0, y0) = (src_x as usize, src_y as usize);
+                let (x1, y1) = ((x0 + 1).min(src_w - 1), (y0 + 1).min(src_h - 1));
+                let (tx, ty) = (src_x - x0 as f32, src_y - y0 as f32);
+
+                // The pixels are premultiplied, so we can interpolate them directly:
+                let top = lerp_color(self[(x0, y0)], self[(x1, y0)], tx);
+                let bottom = lerp_color(self[(x0, y1)], self[(x1, y1)], tx);
+                let [r, g, b, a] = lerp_rgba(top, bottom, ty);
+                Color32::from_rgba_premultiplied(
+                    r.round() as u8,
+                    g.round() as u8,
+                    b.round() as u8,
+                    a.round() as u8,
+                )
+            })
+            .with_color_space(self.color_space),
+        }
+    }
+
+    /// Create a new image from a patch of this image, given by its top-left corner and size in pixels.
+    ///
+    /// Panics if the patch extends outside of the image.
+    pub fn crop(&self, [x, y]: [usize; 2], [w, h]: [usize; 2]) -> Self {
+        assert!(
+            x + w <= self.width() && y + h <= self.height(),
+            "Crop region out of bounds"
+        );
+        Self::from_fn([w, h], |dx, dy| self[(x + dx, y + dy)]).with_color_space(self.color_space)
+    }
+
+    /// Rotate the image 90° clockwise.
+    pub fn rotate90(&self) -> Self {
+        let [w, h] = self.size;
+        Self::from_fn([h, w], |x, y| self[(y, h - 1 - x)]).with_color_space(self.color_space)
+    }
+
+    /// Rotate the image 180°.
+    pub fn rotate180(&self) -> Self {
+        let mut pixels = self.pixels.clone();
+        pixels.reverse();
+        Self {
+            size: self.size,
+            pixels,
+            color_space: self.color_space,
+        }
+    }
+
+    /// Rotate the image 270° clockwise (90° counter-clockwise).
+    pub fn rotate270(&self) -> Self {
+        let [w, h] = self.size;
+        Self::from_fn([h, w], |x, y| self[(w - 1 - y, x)]).with_color_space(self.color_space)
+    }
+
+    /// Mirror the image left-to-right.
+    pub fn flip_horizontal(&self) -> Self {
+        let [w, _] = self.size;
+        Self::from_fn(self.size, |x, y| self[(w - 1 - x, y)]).with_color_space(self.color_space)
+    }
+
+    /// Mirror the image top-to-bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let [_, h] = self.size;
+        Self::from_fn(self.size, |x, y| self[(x, h - 1 - y)]).with_color_space(self.color_space)
+    }
+
+    /// Copy `sub_image` into this image, with its top-left corner at `offset`.
+    ///
+    /// The pixels are replaced, not blended.
+    /// The parts of `sub_image` that fall outside of this image are ignored.
+    /// The pixels are converted to the color space of this image.
+    pub fn blit(&mut self, sub_image: &Self, offset: [usize; 2]) {
+        let sub_pixels = sub_image.pixels_in(self.color_space);
+        let [x, y] = offset;
+        let w = sub_image.width().min(self.width().saturating_sub(x));
+        let h = sub_image.height().min(self.height().saturating_sub(y));
+        let row_stride = self.size[0];
+        for row in 0..h {
+            let dst_start = (y + row) * row_stride + x;
+            let src_start = row * sub_image.width();
+            self.pixels[dst_start..dst_start + w]
+                .copy_from_slice(&sub_pixels[src_start..src_start + w]);
+        }
+    }
+}
+
+fn lerp_rgba(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    [0, 1, 2, 3].map(|i| emath::lerp(a[i]..=b[i], t))
+}
+
+fn lerp_color(a: Color32, b: Color32, t: f32) -> [f32; 4] {
+    let to_f32 = |c: Color32| c.to_array().map(f32::from);
+    lerp_rgba(to_f32(a), to_f32(b), t)
 }
 
 impl std::ops::Index<(usize, usize)> for ColorImage {
@@ -253,6 +507,7 @@ impl std::fmt::Debug for ColorImage {
         f.debug_struct("ColorImage")
             .field("size", &self.size)
             .field("pixel-count", &self.pixels.len())
+            .field("color_space", &self.color_space)
             .finish_non_exhaustive()
     }
 }
@@ -264,6 +519,12 @@ impl std::fmt::Debug for ColorImage {
 /// Each value represents "coverage", i.e. how much a texel is covered by a character.
 ///
 /// This is roughly interpreted as the opacity of a white image.
+///
+/// With [`GlyphRasterization::SignedDistanceField`] the glyphs instead store the distance to their edge,
+/// and the renderer needs to turn that into coverage.
+///
+/// With [`GlyphRasterization::Subpixel`] the glyphs also store the coverage of each color channel,
+/// see [`Self::set_subpixel_coverage`].
 #[derive(Clone, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct FontImage {
@@ -274,6 +535,16 @@ pub struct FontImage {
     ///
     /// Often you want to use [`Self::srgba_pixels`] instead.
     pub pixels: Vec<f32>,
+
+    /// How the glyphs in this image were rasterized.
+    rasterization: GlyphRasterization,
+
+    /// The coverage of the red, green and blue subpixels of each texel,
+    /// with [`GlyphRasterization::Subpixel`]. Empty otherwise.
+    ///
+    /// Texels that are all zero here use [`Self::pixels`] for all channels,
+    /// which is the case for everything in the font atlas that isn't a glyph.
+    pub(crate) subpixel_coverage: Vec<[f32; 3]>,
 }
 
 impl FontImage {
@@ -281,6 +552,68 @@ impl FontImage {
         Self {
             size,
             pixels: vec![0.0; size[0] * size[1]],
+            rasterization: GlyphRasterization::Coverage,
+            subpixel_coverage: Vec::new(),
+        }
+    }
+
+    /// Change how glyphs are rasterized into this image,
+    /// allocating room for the coverage of each color channel if needed.
+    pub fn set_rasterization(&mut self, rasterization: GlyphRasterization) {
+        self.rasterization = rasterization;
+        if self.is_subpixel() {
+            self.subpixel_coverage.resize(self.pixels.len(), [0.0; 3]);
+        } else {
+            self.subpixel_coverage = Vec::new();
+        }
+    }
+
+    /// How the glyphs in this image were rasterized.
+    #[inline]
+    pub fn rasterization(&self) -> GlyphRasterization {
+        self.rasterization
+    }
+
+    /// Is this a signed distance field, that the renderer needs to decode?
+    ///
+    /// See [`GlyphRasterization::SignedDistanceField`].
+    #[inline]
+    pub fn is_sdf(&self) -> bool {
+        self.rasterization == GlyphRasterization::SignedDistanceField
+    }
+
+    /// Does this store the coverage of each color channel, that the renderer needs to blend separately?
+    ///
+    /// See [`GlyphRasterization::Subpixel`].
+    #[inline]
+    pub fn is_subpixel(&self) -> bool {
+        matches!(self.rasterization, GlyphRasterization::Subpixel(_))
+    }
+
+    /// A copy with only the average coverage of the glyphs,
+    /// i.e. with [`GlyphRasterization::Coverage`].
+    ///
+    /// This is what everything but the glyphs is painted with.
+    pub fn without_subpixel_coverage(&self) -> Self {
+        Self {
+            size: self.size,
+            pixels: self.pixels.clone(),
+            rasterization: GlyphRasterization::Coverage,
+            subpixel_coverage: Vec::new(),
+        }
+    }
+
+    /// Set the coverage of the red, green and blue subpixels of a texel.
+    ///
+    /// Also sets [`Self::pixels`] to the average coverage, for renderers that don't support subpixel blending.
+    #[inline]
+    pub fn set_subpixel_coverage(&mut self, (x, y): (usize, usize), coverage: [f32; 3]) {
+        let [w, h] = self.size;
+        assert!(x < w && y < h);
+        let [r, g, b] = coverage;
+        self.pixels[y * w + x] = (r + g + b) / 3.0;
+        if let Some(texel) = self.subpixel_coverage.get_mut(y * w + x) {
+            *texel = coverage;
         }
     }
 
@@ -299,14 +632,27 @@ impl FontImage {
     /// `gamma` should normally be set to `None`.
     ///
     /// If you are having problems with text looking skinny and pixelated, try using a low gamma, e.g. `0.4`.
+    ///
+    /// The gamma is ignored for signed distance fields, which must be stored as-is.
     #[inline]
     pub fn srgba_pixels(&self, gamma: Option<f32>) -> impl ExactSizeIterator<Item = Color32> + '_ {
-        let gamma = gamma.unwrap_or(0.55); // TODO(emilk): this default coverage gamma is a magic constant, chosen by eye. I don't even know why we need it.
-        self.pixels.iter().map(move |coverage| {
+        let gamma = if self.is_sdf() {
+            1.0
+        } else {
+            gamma.unwrap_or(0.55) // TODO(emilk): this default coverage gamma is a magic constant, chosen by eye. I don't even know why we need it.
+        };
+        self.pixels.iter().enumerate().map(move |(i, &coverage)| {
             let alpha = coverage.powf(gamma);
             // We want to multiply with `vec4(alpha)` in the fragment shader:
             let a = fast_round(alpha * 255.0);
-            Color32::from_rgba_premultiplied(a, a, a, a)
+            match self.subpixel_coverage.get(i) {
+                Some(&[r, g, b]) if [r, g, b] != [0.0; 3] => {
+                    // One coverage per color channel, blended separately by the renderer:
+                    let [r, g, b] = [r, g, b].map(|c| fast_round(c.powf(gamma) * 255.0));
+                    Color32::from_rgba_premultiplied(r, g, b, a)
+                }
+                _ => Color32::from_rgba_premultiplied(a, a, a, a),
+            }
         })
     }
 
@@ -316,14 +662,20 @@ impl FontImage {
         assert!(y + h <= self.height());
 
         let mut pixels = Vec::with_capacity(w * h);
+        let mut subpixel_coverage = Vec::with_capacity(self.subpixel_coverage.len().min(w * h));
         for y in y..y + h {
             let offset = y * self.width() + x;
             pixels.extend(&self.pixels[offset..(offset + w)]);
+            if !self.subpixel_coverage.is_empty() {
+                subpixel_coverage.extend(&self.subpixel_coverage[offset..(offset + w)]);
+            }
         }
         assert_eq!(pixels.len(), w * h);
         Self {
             size: [w, h],
             pixels,
+            rasterization: self.rasterization,
+            subpixel_coverage,
         }
     }
 }
@@ -409,3 +761,113 @@ impl ImageDelta {
         self.pos.is_none()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(size: [usize; 2]) -> ColorImage {
+        let mut i = 0;
+        ColorImage::from_fn(size, |_, _| {
+            i += 1;
+            Color32::from_gray(i)
+        })
+    }
+
+    #[test]
+    fn test_rotate_and_flip() {
+        let image = numbered([3, 2]);
+        assert_eq!(image.rotate90().size, [2, 3]);
+        assert_eq!(image.rotate90()[(1, 0)], image[(0, 0)]);
+        assert_eq!(image.rotate90().rotate90(), image.rotate180());
+        assert_eq!(image.rotate90().rotate270(), image);
+        assert_eq!(image.flip_horizontal().flip_vertical(), image.rotate180());
+        assert_eq!(image.flip_horizontal()[(0, 0)], image[(2, 0)]);
+    }
+
+    #[test]
+    fn test_crop_and_blit() {
+        let image = numbered([4, 4]);
+        let crop = image.crop([1, 2], [3, 2]);
+        assert_eq!(
+            crop,
+            image.pixel_region(emath::IRect::from_min_max((1, 2).into(), (4, 4).into()))
+        );
+
+        let mut canvas = ColorImage::new([4, 4], Color32::TRANSPARENT);
+        canvas.blit(&crop, [1, 2]);
+        assert_eq!(canvas.crop([1, 2], [3, 2]), crop);
+        assert_eq!(canvas[(0, 0)], Color32::TRANSPARENT);
+
+        // Clipped at the edges:
+        canvas.blit(&image, [3, 3]);
+        assert_eq!(canvas[(3, 3)], image[(0, 0)]);
+    }
+
+    #[test]
+    fn test_resize() {
+        let image = numbered([2, 2]);
+        let nearest = image.resize([4, 4], TextureFilter::Nearest);
+        assert_eq!(nearest[(1, 1)], image[(0, 0)]);
+        assert_eq!(nearest[(3, 2)], image[(1, 1)]);
+        assert_eq!(nearest.resize([2, 2], TextureFilter::Nearest), image);
+
+        let uniform = ColorImage::new([3, 5], Color32::from_rgba_premultiplied(10, 20, 30, 40));
+        assert_eq!(
+            uniform.resize([7, 2], TextureFilter::Linear).pixels,
+            vec![uniform.pixels[0]; 14]
+        );
+
+        let gradient = ColorImage::from_gray([2, 1], &[0, 100]);
+        let linear = gradient.resize([4, 1], TextureFilter::Linear);
+        assert_eq!(linear[(0, 0)], Color32::from_gray(0));
+        assert_eq!(linear[(1, 0)], Color32::from_gray(25));
+        assert_eq!(linear[(2, 0)], Color32::from_gray(75));
+        assert_eq!(linear[(3, 0)], Color32::from_gray(100));
+    }
+
+    #[test]
+    fn test_subpixel_font_image() {
+        let mut image = FontImage::new([2, 2]);
+        image.set_rasterization(GlyphRasterization::Subpixel(Default::default()));
+        image[(0, 0)] = 1.0; // e.g. the white texel
+        image.set_subpixel_coverage((1, 0), [1.0, 0.5, 0.0]);
+        assert_eq!(image[(1, 0)], 0.5, "Average coverage");
+
+        let pixels: Vec<Color32> = image.srgba_pixels(Some(1.0)).collect();
+        assert_eq!(pixels[0], Color32::WHITE);
+        assert_eq!(
+            pixels[1],
+            Color32::from_rgba_premultiplied(255, 128, 0, 128)
+        );
+        assert_eq!(pixels[2], Color32::TRANSPARENT);
+
+        let region = image.region([1, 0], [1, 2]);
+        assert_eq!(region.subpixel_coverage, vec![[1.0, 0.5, 0.0], [0.0; 3]]);
+
+        let coverage = image.without_subpixel_coverage();
+        assert!(!coverage.is_subpixel());
+        assert_eq!(coverage.pixels, image.pixels);
+        assert_eq!(
+            coverage.srgba_pixels(Some(1.0)).nth(1),
+            Some(Color32::from_rgba_premultiplied(128, 128, 128, 128))
+        );
+    }
+
+    #[test]
+    fn test_color_space() {
+        let red = Color32::from_rgb(255, 0, 0);
+        let p3 = ColorImage::new([2, 1], red).with_color_space(ColorSpace::DisplayP3);
+        assert_eq!(p3.rotate90().color_space(), ColorSpace::DisplayP3);
+        assert_eq!(p3.srgb_pixels()[0], red, "P3 red is clamped to sRGB red");
+
+        let mut srgb = ColorImage::new([2, 1], red);
+        assert!(matches!(srgb.srgb_pixels(), Cow::Borrowed(_)));
+        srgb.convert_to(ColorSpace::DisplayP3);
+        assert_ne!(srgb[(0, 0)], red, "sRGB red is less saturated in P3");
+
+        let mut canvas = ColorImage::new([2, 1], Color32::BLACK);
+        canvas.blit(&srgb, [0, 0]);
+        assert_eq!(canvas[(0, 0)], red);
+    }
+}
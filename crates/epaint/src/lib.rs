@@ -25,15 +25,19 @@
 
 mod bezier;
 pub mod color;
+mod hit_test;
 pub mod image;
 mod margin;
 mod mesh;
+mod mesh_manager;
 pub mod mutex;
 mod shadow;
 mod shape;
 pub mod shape_transform;
+mod spline;
 pub mod stats;
 mod stroke;
+mod svg_path;
 pub mod tessellator;
 pub mod text;
 mod texture_atlas;
@@ -47,13 +51,18 @@ pub use self::{
     image::{ColorImage, FontImage, ImageData, ImageDelta},
     margin::Margin,
     mesh::{Mesh, Mesh16, Vertex},
-    shadow::Shadow,
+    mesh_manager::{
+        MeshHandle, MeshId, MeshManager, PrecomputedMeshPrimitive, PrecomputedMeshShape,
+    },
+    shadow::{Shadow, ShadowFalloff},
     shape::{
-        CircleShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape, RectShape,
-        Rounding, Shape, TextShape,
+        ArcShape, CircleShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape, PieShape,
+        RectShape, Rounding, Shape, TextShape,
     },
+    spline::CatmullRomShape,
     stats::PaintStats,
-    stroke::{PathStroke, Stroke},
+    stroke::{DashCap, DashPattern, PathStroke, Stroke},
+    svg_path::{FillRule, PathCommand, PathData, PathParseError, SvgPathShape},
     tessellator::{TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, Galley},
     texture_atlas::TextureAtlas,
@@ -64,7 +73,7 @@ pub use self::{
 #[allow(deprecated)]
 pub use tessellator::tessellate_shapes;
 
-pub use ecolor::{Color32, Hsva, HsvaGamma, Rgba};
+pub use ecolor::{Color32, ColorSpace, Hsva, HsvaGamma, Rgba, ToneMapping};
 pub use emath::{pos2, vec2, Pos2, Rect, Vec2};
 
 pub use ahash;
@@ -129,10 +138,11 @@ pub struct ClippedPrimitive {
     pub primitive: Primitive,
 }
 
-/// A rendering primitive - either a [`Mesh`] or a [`PaintCallback`].
+/// A rendering primitive - either a [`Mesh`], a [`PrecomputedMeshPrimitive`] or a [`PaintCallback`].
 #[derive(Clone, Debug)]
 pub enum Primitive {
     Mesh(Mesh),
+    PrecomputedMesh(PrecomputedMeshPrimitive),
     Callback(PaintCallback),
 }
 
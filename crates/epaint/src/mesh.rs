@@ -200,6 +200,80 @@ impl Mesh {
         });
     }
 
+    /// Rectangle with a texture whose borders keep their size while the middle is stretched,
+    /// a.k.a. "nine-slice" or "nine-patch" scaling.
+    ///
+    /// This is useful for scaling textured buttons, frames and speech bubbles without distorting their borders.
+    ///
+    /// * `insets`: the size of the borders in `rect`, in points.
+    /// * `uv_insets`: the size of the same borders in `uv`, in normalized texture coordinates.
+    ///
+    /// If `rect` is too small to fit the borders, they are scaled down to fit.
+    pub fn add_nine_slice_with_uv(
+        &mut self,
+        rect: Rect,
+        insets: Margin,
+        uv: Rect,
+        uv_insets: Margin,
+        color: Color32,
+    ) {
+        let fit = |size: f32, a: f32, b: f32| {
+            if size < a + b {
+                size.max(0.0) / (a + b)
+            } else {
+                1.0
+            }
+        };
+        let sx = fit(rect.width(), insets.left, insets.right);
+        let sy = fit(rect.height(), insets.top, insets.bottom);
+
+        let xs = [
+            rect.min.x,
+            rect.min.x + sx * insets.left,
+            rect.max.x - sx * insets.right,
+            rect.max.x,
+        ];
+        let ys = [
+            rect.min.y,
+            rect.min.y + sy * insets.top,
+            rect.max.y - sy * insets.bottom,
+            rect.max.y,
+        ];
+        let us = [
+            uv.min.x,
+            uv.min.x + uv_insets.left,
+            uv.max.x - uv_insets.right,
+            uv.max.x,
+        ];
+        let vs = [
+            uv.min.y,
+            uv.min.y + uv_insets.top,
+            uv.max.y - uv_insets.bottom,
+            uv.max.y,
+        ];
+
+        let idx = self.vertices.len() as u32;
+        self.reserve_vertices(16);
+        self.reserve_triangles(18);
+        for (&y, &v) in ys.iter().zip(&vs) {
+            for (&x, &u) in xs.iter().zip(&us) {
+                self.vertices.push(Vertex {
+                    pos: pos2(x, y),
+                    uv: pos2(u, v),
+                    color,
+                });
+            }
+        }
+        for row in 0..3 {
+            for col in 0..3 {
+                let top_left = idx + 4 * row + col;
+                let bottom_left = top_left + 4;
+                self.add_triangle(top_left, top_left + 1, bottom_left);
+                self.add_triangle(bottom_left, top_left + 1, bottom_left + 1);
+            }
+        }
+    }
+
     /// Uniformly colored rectangle.
     #[inline(always)]
     pub fn add_colored_rect(&mut self, rect: Rect, color: Color32) {
@@ -279,7 +353,8 @@ impl Mesh {
     }
 
     /// Transform the mesh in-place with the given transform.
-    pub fn transform(&mut self, transform: TSTransform) {
+    pub fn transform(&mut self, transform: impl Into<Affine2>) {
+        let transform: Affine2 = transform.into();
         for v in &mut self.vertices {
             v.pos = transform * v.pos;
         }
@@ -323,3 +398,35 @@ impl Mesh16 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nine_slice() {
+        let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+        let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+        let uv_insets = Margin::same(0.25);
+
+        let mut mesh = Mesh::with_texture(TextureId::Managed(1));
+        mesh.add_nine_slice_with_uv(rect, Margin::same(10.0), uv, uv_insets, Color32::WHITE);
+        assert!(mesh.is_valid());
+        assert_eq!(mesh.vertices.len(), 16);
+        assert_eq!(mesh.indices.len(), 9 * 6);
+        assert_eq!(mesh.calc_bounds(), rect);
+
+        // The borders keep their size, the middle stretches:
+        assert_eq!(mesh.vertices[5].pos, pos2(10.0, 10.0));
+        assert_eq!(mesh.vertices[5].uv, pos2(0.25, 0.25));
+        assert_eq!(mesh.vertices[10].pos, pos2(90.0, 40.0));
+        assert_eq!(mesh.vertices[10].uv, pos2(0.75, 0.75));
+
+        // Too small for the borders, so they shrink to fit:
+        let mut mesh = Mesh::default();
+        let small = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 40.0));
+        mesh.add_nine_slice_with_uv(small, Margin::same(10.0), uv, uv_insets, Color32::WHITE);
+        assert_eq!(mesh.vertices[5].pos, pos2(5.0, 10.0));
+        assert_eq!(mesh.vertices[6].pos, pos2(5.0, 10.0));
+    }
+}
@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use crate::{mutex::RwLock, Mesh, Rect, Shape};
+use emath::TSTransform;
+
+/// Identifies a [`Mesh`] registered with a [`MeshManager`].
+///
+/// Paint it with [`Shape::PrecomputedMesh`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MeshId(u64);
+
+struct MeshMeta {
+    mesh: Arc<Mesh>,
+
+    /// Cached, for culling.
+    bounds: Rect,
+
+    /// Free when this reaches zero.
+    retain_count: usize,
+}
+
+/// Paints a [`Mesh`] registered with a [`MeshManager`].
+///
+/// Create it with [`MeshHandle::shape`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrecomputedMeshShape {
+    pub id: MeshId,
+
+    /// The bounding rectangle of the untransformed mesh, for culling.
+    pub bounds: Rect,
+
+    /// Applied to the mesh when painting it.
+    ///
+    /// Rotation and shearing are not supported.
+    pub transform: TSTransform,
+}
+
+impl PrecomputedMeshShape {
+    /// The visual bounding rectangle, with the transform applied.
+    #[inline]
+    pub fn visual_bounding_rect(&self) -> Rect {
+        self.transform * self.bounds
+    }
+}
+
+/// A [`Mesh`] registered with a [`MeshManager`], for the renderer.
+///
+/// The renderer can upload the mesh once, and keep it on the GPU for as long as
+/// it gets the same [`Self::mesh`] (see [`Arc::ptr_eq`]) for the [`Self::id`].
+/// Once the renderer holds the last reference to [`Self::mesh`], the mesh has been freed,
+/// and the renderer can free its copy too.
+#[derive(Clone, Debug)]
+pub struct PrecomputedMeshPrimitive {
+    pub id: MeshId,
+
+    /// The untransformed mesh.
+    pub mesh: Arc<Mesh>,
+
+    /// Apply this to the positions of the vertices when painting.
+    pub transform: TSTransform,
+}
+
+/// Keeps already tessellated meshes around, so that static geometry
+/// doesn't need to be tessellated again each frame.
+///
+/// If you are using egui, use `egui::Context::register_mesh`.
+#[derive(Default)]
+pub struct MeshManager {
+    next_id: u64,
+    metas: ahash::HashMap<MeshId, MeshMeta>,
+}
+
+impl MeshManager {
+    /// Register a new mesh, with a retain count of one.
+    ///
+    /// You need to call [`Self::free`] when you are done with it, or use a [`MeshHandle`].
+    pub fn alloc(&mut self, mesh: Mesh) -> MeshId {
+        let id = MeshId(self.next_id);
+        self.next_id += 1;
+        self.metas.insert(
+            id,
+            MeshMeta {
+                bounds: mesh.calc_bounds(),
+                mesh: Arc::new(mesh),
+                retain_count: 1,
+            },
+        );
+        id
+    }
+
+    /// Replace the contents of an existing mesh.
+    pub fn set(&mut self, id: MeshId, mesh: Mesh) {
+        if let Some(meta) = self.metas.get_mut(&id) {
+            meta.bounds = mesh.calc_bounds();
+            meta.mesh = Arc::new(mesh);
+        } else {
+            debug_assert!(false, "Tried setting mesh {id:?} which is not allocated");
+        }
+    }
+
+    /// Increase the retain count of the mesh.
+    pub fn retain(&mut self, id: MeshId) {
+        if let Some(meta) = self.metas.get_mut(&id) {
+            meta.retain_count += 1;
+        } else {
+            debug_assert!(false, "Tried retaining mesh {id:?} which is not allocated");
+        }
+    }
+
+    /// Decrease the retain count of the mesh, and free it when it reaches zero.
+    pub fn free(&mut self, id: MeshId) {
+        if let std::collections::hash_map::Entry::Occupied(mut entry) = self.metas.entry(id) {
+            let meta = entry.get_mut();
+            meta.retain_count -= 1;
+            if meta.retain_count == 0 {
+                entry.remove();
+            }
+        } else {
+            debug_assert!(false, "Tried freeing mesh {id:?} which is not allocated");
+        }
+    }
+
+    /// The mesh, if it is still allocated.
+    pub fn get(&self, id: MeshId) -> Option<&Arc<Mesh>> {
+        self.metas.get(&id).map(|meta| &meta.mesh)
+    }
+
+    /// The bounding rectangle of the (untransformed) mesh.
+    pub fn bounds(&self, id: MeshId) -> Option<Rect> {
+        self.metas.get(&id).map(|meta| meta.bounds)
+    }
+
+    /// Total number of allocated meshes.
+    pub fn num_allocated(&self) -> usize {
+        self.metas.len()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Used to paint static geometry without tessellating it each frame.
+///
+/// If you are using egui, use `egui::Context::register_mesh`.
+///
+/// The [`MeshHandle`] can be cloned cheaply.
+/// When the last [`MeshHandle`] for a specific mesh is dropped, the mesh is freed.
+#[must_use]
+pub struct MeshHandle {
+    mesh_mngr: Arc<RwLock<MeshManager>>,
+    id: MeshId,
+}
+
+impl Drop for MeshHandle {
+    fn drop(&mut self) {
+        self.mesh_mngr.write().free(self.id);
+    }
+}
+
+impl Clone for MeshHandle {
+    fn clone(&self) -> Self {
+        self.mesh_mngr.write().retain(self.id);
+        Self {
+            mesh_mngr: self.mesh_mngr.clone(),
+            id: self.id,
+        }
+    }
+}
+
+impl PartialEq for MeshHandle {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for MeshHandle {}
+
+impl std::hash::Hash for MeshHandle {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl MeshHandle {
+    /// If you are using egui, use `egui::Context::register_mesh` instead.
+    pub fn new(mesh_mngr: Arc<RwLock<MeshManager>>, id: MeshId) -> Self {
+        Self { mesh_mngr, id }
+    }
+
+    #[inline]
+    pub fn id(&self) -> MeshId {
+        self.id
+    }
+
+    /// Replace the mesh, e.g. when the geometry changes.
+    pub fn set(&self, mesh: Mesh) {
+        self.mesh_mngr.write().set(self.id, mesh);
+    }
+
+    /// The bounding rectangle of the (untransformed) mesh.
+    pub fn bounds(&self) -> Rect {
+        self.mesh_mngr
+            .read()
+            .bounds(self.id)
+            .unwrap_or(Rect::NOTHING)
+    }
+
+    /// A shape painting the mesh with the given transform.
+    ///
+    /// Keep the handle alive for as long as you paint the shape.
+    /// If you replace the mesh with [`Self::set`], create a new shape, since the bounds are stored in it.
+    pub fn shape(&self, transform: TSTransform) -> Shape {
+        Shape::PrecomputedMesh(PrecomputedMeshShape {
+            id: self.id,
+            bounds: self.bounds(),
+            transform,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pos2, Color32};
+
+    #[test]
+    fn test_mesh_handle_frees_when_dropped() {
+        let mesh_mngr = Arc::new(RwLock::new(MeshManager::default()));
+
+        let mut mesh = Mesh::default();
+        mesh.add_colored_rect(
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 20.0)),
+            Color32::RED,
+        );
+        let id = mesh_mngr.write().alloc(mesh);
+        let handle = MeshHandle::new(mesh_mngr.clone(), id);
+        assert_eq!(handle.bounds().size(), emath::vec2(10.0, 20.0));
+
+        let clone = handle.clone();
+        drop(handle);
+        assert_eq!(mesh_mngr.read().num_allocated(), 1);
+        drop(clone);
+        assert_eq!(mesh_mngr.read().num_allocated(), 0);
+    }
+}
@@ -24,6 +24,24 @@ pub struct Shadow {
 
     /// Color of the opaque center of the shadow.
     pub color: Color32,
+
+    /// How the shadow fades out across [`Self::blur`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub falloff: ShadowFalloff,
+}
+
+/// How a [`Shadow`] fades out across its blur width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ShadowFalloff {
+    /// A linear fade. Cheap, but the edges of the fade are visible at large blur widths.
+    #[default]
+    Linear,
+
+    /// Approximates a true Gaussian blur, like a CSS `box-shadow`.
+    ///
+    /// This uses a few more triangles than [`Self::Linear`].
+    Gaussian,
 }
 
 impl Shadow {
@@ -33,9 +51,13 @@ impl Shadow {
         blur: 0.0,
         spread: 0.0,
         color: Color32::TRANSPARENT,
+        falloff: ShadowFalloff::Linear,
     };
 
     /// The argument is the rectangle of the shadow caster.
+    ///
+    /// This always uses a [`ShadowFalloff::Linear`] fade.
+    /// Use [`Self::paint`] to respect [`Self::falloff`].
     pub fn as_shape(&self, rect: Rect, rounding: impl Into<Rounding>) -> RectShape {
         // tessellator.clip_rect = clip_rect; // TODO(emilk): culling
 
@@ -44,6 +66,7 @@ impl Shadow {
             blur,
             spread,
             color,
+            falloff: _,
         } = *self;
 
         let rect = rect.translate(offset).expand(spread);
@@ -52,6 +75,30 @@ impl Shadow {
         RectShape::filled(rect, rounding, color).with_blur_width(blur)
     }
 
+    /// The argument is the rectangle of the shadow caster.
+    pub fn paint(&self, rect: Rect, rounding: impl Into<Rounding>) -> Shape {
+        match self.falloff {
+            ShadowFalloff::Linear => Shape::Rect(self.as_shape(rect, rounding)),
+            ShadowFalloff::Gaussian => {
+                let Self {
+                    offset,
+                    blur,
+                    spread,
+                    color,
+                    falloff: _,
+                } = *self;
+
+                let rect = rect.translate(offset).expand(spread);
+                let rounding = rounding.into() + Rounding::same(spread.abs());
+                if blur <= 0.0 {
+                    Shape::Rect(RectShape::filled(rect, rounding, color))
+                } else {
+                    Shape::mesh(gaussian_shadow_mesh(rect, rounding, blur, color))
+                }
+            }
+        }
+    }
+
     /// How much larger than the parent rect are we in each direction?
     pub fn margin(&self) -> Margin {
         let Self {
@@ -59,6 +106,7 @@ impl Shadow {
             blur,
             spread,
             color: _,
+            falloff: _,
         } = *self;
         Margin {
             left: spread + 0.5 * blur - offset.x,
@@ -68,3 +116,128 @@ impl Shadow {
         }
     }
 }
+
+/// A rounded rectangle whose edges fade out like a Gaussian blur with a total width of `blur`.
+///
+/// The fade is built from concentric rings of vertices, each colored by the error function.
+fn gaussian_shadow_mesh(rect: Rect, rounding: Rounding, blur: f32, color: Color32) -> Mesh {
+    /// Number of rings across the fade.
+    const NUM_RINGS: usize = 9;
+
+    /// Number of points on each rounded corner of a ring.
+    const POINTS_PER_CORNER: usize = 8;
+
+    // Like CSS, most of the fade happens within ±2σ of the edge:
+    let sigma = blur / 4.0;
+    let half_blur = 0.5 * blur;
+
+    // Don't let the inner rings turn inside-out:
+    let max_shrink = 0.5 * rect.size().min_elem();
+
+    let mut mesh = Mesh::default();
+    let points_per_ring = 4 * POINTS_PER_CORNER;
+
+    for ring in 0..NUM_RINGS {
+        // From the outside in:
+        let t = ring as f32 / (NUM_RINGS - 1) as f32;
+        let distance = emath::lerp(half_blur..=-half_blur, t); // positive is outside the rect
+        let alpha = if ring == 0 {
+            0.0 // make sure the outer edge is fully transparent
+        } else {
+            1.0 - normal_cdf(distance / sigma)
+        };
+        let ring_color = color.gamma_multiply(alpha);
+
+        let expansion = distance.max(-max_shrink);
+        let ring_rect = rect.expand(expansion);
+        let corners = [
+            (
+                ring_rect.left_top(),
+                rounding.nw,
+                vec2(1.0, 1.0),
+                std::f32::consts::PI,
+            ),
+            (
+                ring_rect.right_top(),
+                rounding.ne,
+                vec2(-1.0, 1.0),
+                1.5 * std::f32::consts::PI,
+            ),
+            (ring_rect.right_bottom(), rounding.se, vec2(-1.0, -1.0), 0.0),
+            (
+                ring_rect.left_bottom(),
+                rounding.sw,
+                vec2(1.0, -1.0),
+                0.5 * std::f32::consts::PI,
+            ),
+        ];
+        for (corner, radius, inward, start_angle) in corners {
+            let radius = (radius + expansion).clamp(0.0, max_shrink + expansion);
+            let center = corner + radius * inward;
+            for i in 0..POINTS_PER_CORNER {
+                let angle = start_angle
+                    + std::f32::consts::FRAC_PI_2 * i as f32 / (POINTS_PER_CORNER - 1) as f32;
+                mesh.colored_vertex(center + radius * Vec2::angled(angle), ring_color);
+            }
+        }
+
+        if 0 < ring {
+            // Connect with the previous (outer) ring:
+            let outer = ((ring - 1) * points_per_ring) as u32;
+            let inner = (ring * points_per_ring) as u32;
+            let n = points_per_ring as u32;
+            for i in 0..n {
+                let j = (i + 1) % n;
+                mesh.add_triangle(outer + i, outer + j, inner + i);
+                mesh.add_triangle(outer + j, inner + j, inner + i);
+            }
+        }
+    }
+
+    // Fill the innermost ring:
+    let inner = ((NUM_RINGS - 1) * points_per_ring) as u32;
+    for i in 1..points_per_ring as u32 - 1 {
+        mesh.add_triangle(inner, inner + i, inner + i + 1);
+    }
+
+    mesh
+}
+
+/// The cumulative distribution function of the standard normal distribution.
+fn normal_cdf(x: f32) -> f32 {
+    0.5 * (1.0 + erf(x / std::f32::consts::SQRT_2))
+}
+
+/// The error function, with a maximum error of 1.5e-7.
+///
+/// Abramowitz & Stegun, formula 7.1.26.
+fn erf(x: f32) -> f32 {
+    let t = 1.0 / (1.0 + 0.327_591_1 * x.abs());
+    let poly = t
+        * (0.254_829_6
+            + t * (-0.284_496_74 + t * (1.421_413_7 + t * (-1.453_152_1 + t * 1.061_405_4))));
+    let y = 1.0 - poly * (-x * x).exp();
+    y.copysign(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erf() {
+        assert!(erf(0.0).abs() < 1e-6);
+        assert!((erf(1.0) - 0.842_700_8).abs() < 1e-6);
+        assert!((erf(-1.0) + 0.842_700_8).abs() < 1e-6);
+        assert!((normal_cdf(2.0) - 0.977_25).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_gaussian_shadow_mesh() {
+        let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 50.0));
+        let mesh = gaussian_shadow_mesh(rect, Rounding::same(4.0), 16.0, Color32::BLACK);
+        assert!(mesh.is_valid());
+        let bounds = mesh.calc_bounds();
+        assert_eq!(bounds, rect.expand(8.0));
+    }
+}
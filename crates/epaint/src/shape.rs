@@ -9,7 +9,7 @@ use crate::{
 };
 use emath::*;
 
-pub use crate::{CubicBezierShape, QuadraticBezierShape};
+pub use crate::{CatmullRomShape, CubicBezierShape, PathData, QuadraticBezierShape, SvgPathShape};
 
 /// A paint primitive such as a circle or a piece of text.
 /// Coordinates are all screen space points (not physical pixels).
@@ -34,6 +34,12 @@ pub enum Shape {
     /// Ellipse with optional outline and fill.
     Ellipse(EllipseShape),
 
+    /// A part of a circle outline.
+    Arc(ArcShape),
+
+    /// A filled circle sector ("pie slice") with optional outline.
+    Pie(PieShape),
+
     /// A line between two points.
     LineSegment {
         points: [Pos2; 2],
@@ -57,12 +63,25 @@ pub enum Shape {
     /// Can be used to display images.
     Mesh(Mesh),
 
+    /// A [`Mesh`] registered with a [`crate::MeshManager`], painted with a transform.
+    ///
+    /// This avoids tessellating static geometry each frame,
+    /// and lets the renderer keep the mesh on the GPU.
+    /// See [`crate::MeshHandle`].
+    PrecomputedMesh(crate::PrecomputedMeshShape),
+
     /// A quadratic [Bézier Curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve).
     QuadraticBezier(QuadraticBezierShape),
 
     /// A cubic [Bézier Curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve).
     CubicBezier(CubicBezierShape),
 
+    /// A smooth curve through a series of points.
+    CatmullRom(CatmullRomShape),
+
+    /// Vector path data, e.g. an icon given in SVG path syntax.
+    SvgPath(SvgPathShape),
+
     /// Backend-specific painting.
     Callback(PaintCallback),
 }
@@ -131,6 +150,52 @@ impl Shape {
         Self::Path(PathShape::closed_line(points, stroke))
     }
 
+    /// A line through many points, simplified before tessellation.
+    ///
+    /// Points that deviate less than `tolerance` (in points) from the simplified line are removed
+    /// using the Ramer-Douglas-Peucker algorithm.
+    /// This is useful for dense data (e.g. a signal trace with a million samples),
+    /// where most points would otherwise end up in the same physical pixel.
+    ///
+    /// A `tolerance` of half a physical pixel (`0.5 / pixels_per_point`) is visually lossless.
+    pub fn line_simplified(points: &[Pos2], tolerance: f32, stroke: impl Into<PathStroke>) -> Self {
+        Self::line(simplify_line(points, tolerance), stroke)
+    }
+
+    /// A smooth curve passing through all the given points.
+    ///
+    /// A `tension` of `0.0` gives a Catmull-Rom spline, and `1.0` gives straight lines.
+    #[inline]
+    pub fn spline(points: Vec<Pos2>, tension: f32, stroke: impl Into<PathStroke>) -> Self {
+        Self::CatmullRom(CatmullRomShape::line(points, tension, stroke))
+    }
+
+    /// A smooth curve passing through all the given points, and then back to the start.
+    ///
+    /// Fill is only supported for convex shapes.
+    #[inline]
+    pub fn closed_spline(
+        points: Vec<Pos2>,
+        tension: f32,
+        fill: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        Self::CatmullRom(CatmullRomShape::closed(points, tension, fill, stroke))
+    }
+
+    /// A path given in SVG path syntax (the `d` attribute of an SVG `<path>`),
+    /// e.g. `"M 10 10 h 80 v 80 h -80 Z"`.
+    ///
+    /// Unlike [`Self::convex_polygon`], any fill is supported, including holes.
+    /// The path is re-parsed each time this is called,
+    /// so for icons that are painted every frame you may want to keep an `Arc<PathData>`
+    /// and use [`SvgPathShape`] instead.
+    ///
+    /// Everything before the first syntax error is painted, see [`PathData::parse_lossy`].
+    pub fn svg_path(d: &str, fill: impl Into<Color32>, stroke: impl Into<PathStroke>) -> Self {
+        Self::SvgPath(SvgPathShape::new(PathData::parse_lossy(d), fill, stroke))
+    }
+
     /// Turn a line into equally spaced dots.
     pub fn dotted_line(
         path: &[Pos2],
@@ -221,6 +286,34 @@ impl Shape {
         );
     }
 
+    /// The outline of a (rounded) rectangle.
+    ///
+    /// Unlike [`Shape::rect_stroke`], this takes a [`PathStroke`], so it can be dashed.
+    pub fn rect_outline(
+        rect: Rect,
+        rounding: impl Into<Rounding>,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        let mut points = Vec::new();
+        crate::tessellator::path::rounded_rectangle(&mut points, rect, rounding.into());
+        Self::closed_line(points, stroke)
+    }
+
+    /// The outline of a circle.
+    ///
+    /// Unlike [`Shape::circle_stroke`], this takes a [`PathStroke`], so it can be dashed.
+    pub fn circle_outline(center: Pos2, radius: f32, stroke: impl Into<PathStroke>) -> Self {
+        // Roughly one point per 2 points of circumference, but not too few or too many:
+        let n = (std::f32::consts::PI * radius).clamp(8.0, 128.0) as usize;
+        let points = (0..n)
+            .map(|i| {
+                let angle = std::f32::consts::TAU * i as f32 / n as f32;
+                center + radius * Vec2::angled(angle)
+            })
+            .collect();
+        Self::closed_line(points, stroke)
+    }
+
     /// A convex polygon with a fill and optional stroke.
     ///
     /// The most performant winding order is clockwise.
@@ -253,6 +346,48 @@ impl Shape {
         Self::Ellipse(EllipseShape::stroke(center, radius, stroke))
     }
 
+    /// A part of a circle outline, going clockwise from `start_angle` to `end_angle` (in radians).
+    ///
+    /// An angle of zero is to the right, and `TAU / 4` is straight down.
+    #[inline]
+    pub fn arc(
+        center: Pos2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        stroke: impl Into<Stroke>,
+    ) -> Self {
+        Self::Arc(ArcShape::new(
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            stroke,
+        ))
+    }
+
+    /// A filled circle sector ("pie slice") between `start_angle` and `end_angle` (in radians).
+    ///
+    /// An angle of zero is to the right, and `TAU / 4` is straight down.
+    #[inline]
+    pub fn pie(
+        center: Pos2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        fill_color: impl Into<Color32>,
+        stroke: impl Into<Stroke>,
+    ) -> Self {
+        Self::Pie(PieShape {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            fill: fill_color.into(),
+            stroke: stroke.into(),
+        })
+    }
+
     #[inline]
     pub fn rect_filled(
         rect: Rect,
@@ -271,6 +406,16 @@ impl Shape {
         Self::Rect(RectShape::stroke(rect, rounding, stroke))
     }
 
+    /// A rectangle rotated around its center, with a fill and optional stroke.
+    #[inline]
+    pub fn rotated_rect(
+        rect: RotatedRect,
+        fill_color: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        Self::convex_polygon(rect.corners().to_vec(), fill_color, stroke)
+    }
+
     #[allow(clippy::needless_pass_by_value)]
     pub fn text(
         fonts: &Fonts,
@@ -342,6 +487,8 @@ impl Shape {
             }
             Self::Circle(circle_shape) => circle_shape.visual_bounding_rect(),
             Self::Ellipse(ellipse_shape) => ellipse_shape.visual_bounding_rect(),
+            Self::Arc(arc_shape) => arc_shape.visual_bounding_rect(),
+            Self::Pie(pie_shape) => pie_shape.visual_bounding_rect(),
             Self::LineSegment { points, stroke } => {
                 if stroke.is_empty() {
                     Rect::NOTHING
@@ -353,8 +500,11 @@ impl Shape {
             Self::Rect(rect_shape) => rect_shape.visual_bounding_rect(),
             Self::Text(text_shape) => text_shape.visual_bounding_rect(),
             Self::Mesh(mesh) => mesh.calc_bounds(),
+            Self::PrecomputedMesh(mesh) => mesh.visual_bounding_rect(),
             Self::QuadraticBezier(bezier) => bezier.visual_bounding_rect(),
             Self::CubicBezier(bezier) => bezier.visual_bounding_rect(),
+            Self::CatmullRom(spline) => spline.visual_bounding_rect(),
+            Self::SvgPath(svg_path) => svg_path.visual_bounding_rect(),
             Self::Callback(custom) => custom.rect,
         }
     }
@@ -389,11 +539,23 @@ impl Shape {
         self.transform(TSTransform::from_translation(delta));
     }
 
-    /// Move the shape by this many points, in-place.
+    /// Transform the shape in-place.
+    ///
+    /// Accepts a [`TSTransform`], a [`Rot2`], or any other [`Affine2`].
     ///
-    /// If using a [`PaintCallback`], note that only the rect is scaled as opposed
-    /// to other shapes where the stroke is also scaled.
-    pub fn transform(&mut self, transform: TSTransform) {
+    /// Points (in paths, lines, curves, and meshes) are transformed exactly.
+    /// Rotation is kept in [`RectShape::angle`], [`TextShape::angle`], and the angles of arcs and pies.
+    /// Circles, ellipses, text, and stroke widths can't be sheared or scaled non-uniformly,
+    /// so for those the scaling is approximated by [`Affine2::scaling`].
+    /// Ellipses can't be rotated either.
+    ///
+    /// If using a [`PaintCallback`], note that only the rect is transformed (to its bounding rect),
+    /// as opposed to other shapes where the stroke is also scaled.
+    pub fn transform(&mut self, transform: impl Into<Affine2>) {
+        let transform: Affine2 = transform.into();
+        let scaling = transform.scaling();
+        let rotation = transform.rotation();
+
         match self {
             Self::Noop => {}
             Self::Vec(shapes) => {
@@ -403,68 +565,116 @@ impl Shape {
             }
             Self::Circle(circle_shape) => {
                 circle_shape.center = transform * circle_shape.center;
-                circle_shape.radius *= transform.scaling;
-                circle_shape.stroke.width *= transform.scaling;
+                circle_shape.radius *= scaling;
+                circle_shape.stroke.width *= scaling;
             }
             Self::Ellipse(ellipse_shape) => {
                 ellipse_shape.center = transform * ellipse_shape.center;
-                ellipse_shape.radius *= transform.scaling;
-                ellipse_shape.stroke.width *= transform.scaling;
+                ellipse_shape.radius.x *= transform.x_axis.length();
+                ellipse_shape.radius.y *= transform.y_axis.length();
+                ellipse_shape.stroke.width *= scaling;
+            }
+            Self::Arc(arc_shape) => {
+                arc_shape.center = transform * arc_shape.center;
+                arc_shape.radius *= scaling;
+                arc_shape.stroke.width *= scaling;
+                (arc_shape.start_angle, arc_shape.end_angle) =
+                    transform_angles(&transform, arc_shape.start_angle, arc_shape.end_angle);
+            }
+            Self::Pie(pie_shape) => {
+                pie_shape.center = transform * pie_shape.center;
+                pie_shape.radius *= scaling;
+                pie_shape.stroke.width *= scaling;
+                (pie_shape.start_angle, pie_shape.end_angle) =
+                    transform_angles(&transform, pie_shape.start_angle, pie_shape.end_angle);
             }
             Self::LineSegment { points, stroke } => {
                 for p in points {
                     *p = transform * *p;
                 }
-                stroke.width *= transform.scaling;
+                stroke.width *= scaling;
             }
             Self::Path(path_shape) => {
                 for p in &mut path_shape.points {
                     *p = transform * *p;
                 }
-                path_shape.stroke.width *= transform.scaling;
+                path_shape.stroke.width *= scaling;
             }
             Self::Rect(rect_shape) => {
-                rect_shape.rect = transform * rect_shape.rect;
-                rect_shape.stroke.width *= transform.scaling;
-                rect_shape.rounding *= transform.scaling;
+                let size = rect_shape.rect.size()
+                    * Vec2::new(transform.x_axis.length(), transform.y_axis.length());
+                rect_shape.rect =
+                    Rect::from_center_size(transform * rect_shape.rect.center(), size);
+                rect_shape.angle += rotation;
+                rect_shape.stroke.width *= scaling;
+                rect_shape.rounding *= scaling;
             }
             Self::Text(text_shape) => {
                 text_shape.pos = transform * text_shape.pos;
+                text_shape.angle += rotation;
 
                 // Scale text:
                 let galley = Arc::make_mut(&mut text_shape.galley);
                 for row in &mut galley.rows {
-                    row.visuals.mesh_bounds = transform.scaling * row.visuals.mesh_bounds;
+                    row.visuals.mesh_bounds = scaling * row.visuals.mesh_bounds;
                     for v in &mut row.visuals.mesh.vertices {
-                        v.pos = Pos2::new(transform.scaling * v.pos.x, transform.scaling * v.pos.y);
+                        v.pos = Pos2::new(scaling * v.pos.x, scaling * v.pos.y);
                     }
                 }
 
-                galley.mesh_bounds = transform.scaling * galley.mesh_bounds;
-                galley.rect = transform.scaling * galley.rect;
+                galley.mesh_bounds = scaling * galley.mesh_bounds;
+                galley.rect = scaling * galley.rect;
             }
             Self::Mesh(mesh) => {
                 mesh.transform(transform);
             }
+            Self::PrecomputedMesh(mesh) => {
+                // Rotation and shearing can't be represented here, and are ignored.
+                mesh.transform = TSTransform::new(
+                    (transform * mesh.transform.translation.to_pos2()).to_vec2(),
+                    scaling * mesh.transform.scaling,
+                );
+            }
             Self::QuadraticBezier(bezier_shape) => {
-                bezier_shape.points[0] = transform * bezier_shape.points[0];
-                bezier_shape.points[1] = transform * bezier_shape.points[1];
-                bezier_shape.points[2] = transform * bezier_shape.points[2];
-                bezier_shape.stroke.width *= transform.scaling;
+                for p in &mut bezier_shape.points {
+                    *p = transform * *p;
+                }
+                bezier_shape.stroke.width *= scaling;
             }
             Self::CubicBezier(cubic_curve) => {
                 for p in &mut cubic_curve.points {
                     *p = transform * *p;
                 }
-                cubic_curve.stroke.width *= transform.scaling;
+                cubic_curve.stroke.width *= scaling;
+            }
+            Self::CatmullRom(spline) => {
+                for p in &mut spline.points {
+                    *p = transform * *p;
+                }
+                spline.stroke.width *= scaling;
+            }
+            Self::SvgPath(svg_path) => {
+                Arc::make_mut(&mut svg_path.path).map_points(|p| transform * p);
+                svg_path.stroke.width *= scaling;
             }
             Self::Callback(shape) => {
-                shape.rect = transform * shape.rect;
+                shape.rect = transform.bounding_rect_of(shape.rect);
             }
         }
     }
 }
 
+/// Where the directions at `start` and `end` (clockwise radians) end up after the transform,
+/// keeping `start < end` even if the transform mirrors.
+fn transform_angles(transform: &Affine2, start: f32, end: f32) -> (f32, f32) {
+    let rotation = transform.rotation();
+    if transform.determinant() < 0.0 {
+        (rotation - end, rotation - start)
+    } else {
+        (rotation + start, rotation + end)
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// How to paint a circle.
@@ -575,6 +785,160 @@ impl From<EllipseShape> for Shape {
 
 // ----------------------------------------------------------------------------
 
+/// How to paint a part of a circle outline.
+///
+/// The arc goes clockwise (on screen) from `start_angle` to `end_angle`,
+/// or counter-clockwise if `end_angle < start_angle`.
+/// Angles are in radians, with zero to the right and `TAU / 4` straight down.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ArcShape {
+    pub center: Pos2,
+    pub radius: f32,
+    pub start_angle: f32,
+    pub end_angle: f32,
+    pub stroke: Stroke,
+}
+
+impl ArcShape {
+    #[inline]
+    pub fn new(
+        center: Pos2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        stroke: impl Into<Stroke>,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            stroke: stroke.into(),
+        }
+    }
+
+    /// The visual bounding rectangle (includes stroke width)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        if self.stroke.is_empty() {
+            Rect::NOTHING
+        } else {
+            arc_bounding_rect(self.center, self.radius, self.start_angle, self.end_angle)
+                .expand(self.stroke.width / 2.0)
+        }
+    }
+}
+
+impl From<ArcShape> for Shape {
+    #[inline(always)]
+    fn from(shape: ArcShape) -> Self {
+        Self::Arc(shape)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How to paint a circle sector ("pie slice"), e.g. for pie charts, gauges and radial menus.
+///
+/// Angles are in radians, with zero to the right and `TAU / 4` straight down.
+/// A sector spanning `TAU` or more is painted as a full circle.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PieShape {
+    pub center: Pos2,
+    pub radius: f32,
+    pub start_angle: f32,
+    pub end_angle: f32,
+    pub fill: Color32,
+    pub stroke: Stroke,
+}
+
+impl PieShape {
+    #[inline]
+    pub fn filled(
+        center: Pos2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        fill_color: impl Into<Color32>,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            fill: fill_color.into(),
+            stroke: Default::default(),
+        }
+    }
+
+    #[inline]
+    pub fn stroke(
+        center: Pos2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        stroke: impl Into<Stroke>,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            fill: Default::default(),
+            stroke: stroke.into(),
+        }
+    }
+
+    /// The visual bounding rectangle (includes stroke width)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
+            Rect::NOTHING
+        } else {
+            let mut rect =
+                arc_bounding_rect(self.center, self.radius, self.start_angle, self.end_angle);
+            rect.extend_with(self.center);
+            rect.expand(self.stroke.width / 2.0)
+        }
+    }
+}
+
+impl From<PieShape> for Shape {
+    #[inline(always)]
+    fn from(shape: PieShape) -> Self {
+        Self::Pie(shape)
+    }
+}
+
+/// The bounding rectangle of the arc of a circle, from `start_angle` to `end_angle`.
+fn arc_bounding_rect(center: Pos2, radius: f32, start_angle: f32, end_angle: f32) -> Rect {
+    use std::f32::consts::{FRAC_PI_2, TAU};
+
+    let (min_angle, max_angle) = if start_angle <= end_angle {
+        (start_angle, end_angle)
+    } else {
+        (end_angle, start_angle)
+    };
+    if TAU <= max_angle - min_angle {
+        return Rect::from_center_size(center, Vec2::splat(2.0 * radius));
+    }
+
+    let mut rect = Rect::from_two_pos(
+        center + radius * Vec2::angled(min_angle),
+        center + radius * Vec2::angled(max_angle),
+    );
+
+    // Include the extreme points (right, down, left, up) that the arc passes:
+    let mut angle = (min_angle / FRAC_PI_2).ceil() * FRAC_PI_2;
+    while angle < max_angle {
+        rect.extend_with(center + radius * Vec2::angled(angle));
+        angle += FRAC_PI_2;
+    }
+    rect
+}
+
+// ----------------------------------------------------------------------------
+
 /// A path which can be stroked and/or filled (if closed).
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -692,6 +1056,10 @@ pub struct RectShape {
     ///
     /// Use [`Rect::ZERO`] to turn off texturing.
     pub uv: Rect,
+
+    /// Rotate the rectangle by this many radians clockwise.
+    /// The pivot is the center of [`Self::rect`], which is the rectangle before rotation.
+    pub angle: f32,
 }
 
 impl RectShape {
@@ -710,6 +1078,7 @@ impl RectShape {
             blur_width: 0.0,
             fill_texture_id: Default::default(),
             uv: Rect::ZERO,
+            angle: 0.0,
         }
     }
 
@@ -727,6 +1096,7 @@ impl RectShape {
             blur_width: 0.0,
             fill_texture_id: Default::default(),
             uv: Rect::ZERO,
+            angle: 0.0,
         }
     }
 
@@ -740,6 +1110,7 @@ impl RectShape {
             blur_width: 0.0,
             fill_texture_id: Default::default(),
             uv: Rect::ZERO,
+            angle: 0.0,
         }
     }
 
@@ -755,14 +1126,27 @@ impl RectShape {
         self
     }
 
+    /// Rotate the rectangle by this many radians clockwise around its center.
+    #[inline]
+    pub fn with_angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+
     /// The visual bounding rectangle (includes stroke width)
     #[inline]
     pub fn visual_bounding_rect(&self) -> Rect {
         if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
             Rect::NOTHING
         } else {
-            self.rect
-                .expand((self.stroke.width + self.blur_width) / 2.0)
+            let rect = self
+                .rect
+                .expand((self.stroke.width + self.blur_width) / 2.0);
+            if self.angle == 0.0 {
+                rect
+            } else {
+                RotatedRect::from_rect(rect, self.angle).bounding_rect()
+            }
         }
     }
 }
@@ -1102,6 +1486,110 @@ fn points_from_line(
     });
 }
 
+/// Simplify a polyline so that no removed point is further than `tolerance` from the result.
+///
+/// First does a cheap radial-distance pass (dropping points too close to the previous kept point),
+/// then runs Ramer-Douglas-Peucker on what remains.
+/// The first and last points are always kept.
+fn simplify_line(points: &[Pos2], tolerance: f32) -> Vec<Pos2> {
+    if points.len() <= 2 || tolerance.is_nan() || tolerance <= 0.0 {
+        return points.to_vec();
+    }
+
+    let tolerance_sq = tolerance * tolerance;
+
+    // Radial distance pass:
+    let mut radial = Vec::with_capacity(points.len());
+    radial.push(points[0]);
+    for &p in &points[1..points.len() - 1] {
+        if radial
+            .last()
+            .map_or(true, |last: &Pos2| last.distance_sq(p) > tolerance_sq)
+        {
+            radial.push(p);
+        }
+    }
+    radial.push(points[points.len() - 1]);
+
+    if radial.len() <= 2 {
+        return radial;
+    }
+
+    // Ramer-Douglas-Peucker, using an explicit stack to avoid deep recursion:
+    let mut keep = vec![false; radial.len()];
+    keep[0] = true;
+    keep[radial.len() - 1] = true;
+
+    let mut stack = vec![(0, radial.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let (a, b) = (radial[first], radial[last]);
+        let mut max_dist_sq = 0.0;
+        let mut max_index = first;
+        for (i, &p) in radial.iter().enumerate().take(last).skip(first + 1) {
+            let dist_sq = distance_sq_to_segment(p, a, b);
+            if dist_sq > max_dist_sq {
+                max_dist_sq = dist_sq;
+                max_index = i;
+            }
+        }
+        if max_dist_sq > tolerance_sq {
+            keep[max_index] = true;
+            stack.push((first, max_index));
+            stack.push((max_index, last));
+        }
+    }
+
+    radial
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(p, keep)| keep.then_some(p))
+        .collect()
+}
+
+fn distance_sq_to_segment(p: Pos2, a: Pos2, b: Pos2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    if length_sq <= 0.0 {
+        return p.distance_sq(a);
+    }
+    let t = ((p - a).dot(ab) / length_sq).clamp(0.0, 1.0);
+    p.distance_sq(a + t * ab)
+}
+
+#[test]
+fn test_simplify_line() {
+    // Collinear points collapse to the end points:
+    let line: Vec<Pos2> = (0..=100).map(|i| pos2(i as f32, 0.0)).collect();
+    assert_eq!(
+        simplify_line(&line, 0.5),
+        vec![pos2(0.0, 0.0), pos2(100.0, 0.0)]
+    );
+
+    // A corner is preserved:
+    let corner = [
+        pos2(0.0, 0.0),
+        pos2(5.0, 0.1),
+        pos2(10.0, 0.0),
+        pos2(10.0, 10.0),
+    ];
+    assert_eq!(
+        simplify_line(&corner, 0.5),
+        vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(10.0, 10.0)]
+    );
+
+    // Dense noise within the tolerance collapses:
+    let noise: Vec<Pos2> = (0..10_000)
+        .map(|i| pos2(i as f32 * 0.01, if i % 2 == 0 { 0.1 } else { -0.1 }))
+        .collect();
+    let simplified = simplify_line(&noise, 0.5);
+    assert!(simplified.len() < 10);
+    assert_eq!(simplified.first(), noise.first());
+    assert_eq!(simplified.last(), noise.last());
+
+    // No tolerance means no simplification:
+    assert_eq!(simplify_line(&corner, 0.0), corner.to_vec());
+}
+
 /// Creates dashes from a line.
 fn dashes_from_line(
     path: &[Pos2],
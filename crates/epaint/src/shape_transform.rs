@@ -38,17 +38,48 @@ pub fn adjust_colors(
             closed: _,
             fill,
             stroke,
+        })
+        | Shape::CatmullRom(CatmullRomShape {
+            points: _,
+            tension: _,
+            closed: _,
+            fill,
+            stroke,
+        })
+        | Shape::SvgPath(SvgPathShape {
+            path: _,
+            fill,
+            fill_rule: _,
+            stroke,
         }) => {
             adjust_color(fill);
             adjust_color_mode(&mut stroke.color, adjust_color);
         }
 
+        Shape::Arc(ArcShape {
+            center: _,
+            radius: _,
+            start_angle: _,
+            end_angle: _,
+            stroke,
+        }) => {
+            adjust_color(&mut stroke.color);
+        }
+
         Shape::Circle(CircleShape {
             center: _,
             radius: _,
             fill,
             stroke,
         })
+        | Shape::Pie(PieShape {
+            center: _,
+            radius: _,
+            start_angle: _,
+            end_angle: _,
+            fill,
+            stroke,
+        })
         | Shape::Ellipse(EllipseShape {
             center: _,
             radius: _,
@@ -63,6 +94,7 @@ pub fn adjust_colors(
             blur_width: _,
             fill_texture_id: _,
             uv: _,
+            angle: _,
         }) => {
             adjust_color(fill);
             adjust_color(&mut stroke.color);
@@ -103,6 +135,10 @@ pub fn adjust_colors(
             }
         }
 
+        Shape::PrecomputedMesh(..) => {
+            // Shared with other shapes, so we can't tint it here.
+        }
+
         Shape::Callback(_) => {
             // Can't tint user callback code
         }
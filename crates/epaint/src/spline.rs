@@ -0,0 +1,186 @@
+use crate::{shape::Shape, Color32, CubicBezierShape, PathShape, PathStroke};
+use emath::*;
+
+// ----------------------------------------------------------------------------
+
+/// A smooth curve passing through all of its points.
+///
+/// This is a [cardinal spline](https://en.wikipedia.org/wiki/Cubic_Hermite_spline#Cardinal_spline),
+/// which with a `tension` of zero is a [Catmull-Rom spline](https://en.wikipedia.org/wiki/Centripetal_Catmull%E2%80%93Rom_spline).
+///
+/// Useful for hand-drawn annotations and smooth signal traces.
+/// See also [`CubicBezierShape`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CatmullRomShape {
+    /// The curve passes through all of these points.
+    pub points: Vec<Pos2>,
+
+    /// How tight the curve is around the points.
+    ///
+    /// `0.0` is a Catmull-Rom spline, and `1.0` gives straight lines between the points.
+    pub tension: f32,
+
+    /// If true, connect the last point smoothly back to the first one.
+    /// This is required if `fill != TRANSPARENT`.
+    pub closed: bool,
+
+    /// Fill is only supported for convex shapes.
+    pub fill: Color32,
+
+    pub stroke: PathStroke,
+}
+
+impl CatmullRomShape {
+    /// An open curve through the given points.
+    pub fn line(points: Vec<Pos2>, tension: f32, stroke: impl Into<PathStroke>) -> Self {
+        Self {
+            points,
+            tension,
+            closed: false,
+            fill: Color32::TRANSPARENT,
+            stroke: stroke.into(),
+        }
+    }
+
+    /// A closed curve through the given points, with an optional fill.
+    pub fn closed(
+        points: Vec<Pos2>,
+        tension: f32,
+        fill: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        Self {
+            points,
+            tension,
+            closed: true,
+            fill: fill.into(),
+            stroke: stroke.into(),
+        }
+    }
+
+    /// The curve as a series of cubic Bézier segments, one between each pair of consecutive points.
+    pub fn to_cubic_beziers(&self) -> Vec<CubicBezierShape> {
+        let n = self.points.len();
+        if n < 2 {
+            return vec![];
+        }
+
+        let point = |i: isize| -> Pos2 {
+            if self.closed {
+                self.points[i.rem_euclid(n as isize) as usize]
+            } else {
+                self.points[i.clamp(0, n as isize - 1) as usize]
+            }
+        };
+
+        // Tangent scale, divided by 3 to get the Bézier control point offsets:
+        let scale = (1.0 - self.tension) / 6.0;
+
+        let num_segments = if self.closed { n } else { n - 1 };
+        (0..num_segments as isize)
+            .map(|i| {
+                let (p0, p1, p2, p3) = (point(i - 1), point(i), point(i + 1), point(i + 2));
+                CubicBezierShape::from_points_stroke(
+                    [p1, p1 + scale * (p2 - p0), p2 - scale * (p3 - p1), p2],
+                    false,
+                    Color32::TRANSPARENT,
+                    self.stroke.clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// Find a set of points that approximate the curve.
+    ///
+    /// The `tolerance` is the maximum distance between the curve and the returned polyline.
+    /// For closed curves the first point is not repeated at the end.
+    pub fn flatten(&self, tolerance: f32) -> Vec<Pos2> {
+        let Some(&first) = self.points.first() else {
+            return vec![];
+        };
+        let mut result = vec![first];
+        for bezier in self.to_cubic_beziers() {
+            bezier.for_each_flattened_with_t(tolerance, &mut |p, _t| {
+                result.push(p);
+            });
+        }
+        if self.closed && 1 < result.len() {
+            result.pop();
+        }
+        result
+    }
+
+    /// Convert the curve to a [`PathShape`] with the given flattening tolerance.
+    pub fn to_path_shape(&self, tolerance: f32) -> PathShape {
+        PathShape {
+            points: self.flatten(tolerance),
+            closed: self.closed,
+            fill: self.fill,
+            stroke: self.stroke.clone(),
+        }
+    }
+
+    /// The visual bounding rectangle (includes stroke width)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
+            Rect::NOTHING
+        } else {
+            self.logical_bounding_rect().expand(self.stroke.width / 2.0)
+        }
+    }
+
+    /// Logical bounding rectangle (ignoring stroke width)
+    pub fn logical_bounding_rect(&self) -> Rect {
+        self.to_cubic_beziers()
+            .iter()
+            .fold(Rect::from_points(&self.points), |rect, bezier| {
+                rect.union(bezier.logical_bounding_rect())
+            })
+    }
+}
+
+impl From<CatmullRomShape> for Shape {
+    #[inline(always)]
+    fn from(shape: CatmullRomShape) -> Self {
+        Self::CatmullRom(shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passes_through_points() {
+        let points = vec![
+            pos2(0.0, 0.0),
+            pos2(10.0, 5.0),
+            pos2(20.0, -5.0),
+            pos2(30.0, 0.0),
+        ];
+        let spline = CatmullRomShape::line(points.clone(), 0.0, (1.0, Color32::WHITE));
+        let beziers = spline.to_cubic_beziers();
+        assert_eq!(beziers.len(), 3);
+        for (bezier, window) in beziers.iter().zip(points.windows(2)) {
+            assert_eq!(bezier.points[0], window[0]);
+            assert_eq!(bezier.points[3], window[1]);
+        }
+
+        let flattened = spline.flatten(0.1);
+        assert_eq!(flattened.first(), points.first());
+        assert_eq!(flattened.last(), points.last());
+        assert!(points.len() < flattened.len());
+
+        let closed = CatmullRomShape::closed(points, 0.0, Color32::WHITE, (1.0, Color32::WHITE));
+        assert_eq!(closed.to_cubic_beziers().len(), 4);
+    }
+
+    #[test]
+    fn test_full_tension_is_straight() {
+        let points = vec![pos2(0.0, 0.0), pos2(10.0, 10.0), pos2(20.0, 0.0)];
+        let spline = CatmullRomShape::line(points.clone(), 1.0, (1.0, Color32::WHITE));
+        let rect = spline.logical_bounding_rect();
+        assert_eq!(rect, Rect::from_points(&points));
+    }
+}
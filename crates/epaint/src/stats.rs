@@ -202,13 +202,22 @@ impl PaintStats {
             Shape::Noop
             | Shape::Circle { .. }
             | Shape::Ellipse { .. }
+            | Shape::Arc(_)
+            | Shape::Pie(_)
             | Shape::LineSegment { .. }
             | Shape::Rect { .. }
+            | Shape::PrecomputedMesh(..)
             | Shape::CubicBezier(_)
             | Shape::QuadraticBezier(_) => {}
             Shape::Path(path_shape) => {
                 self.shape_path += AllocInfo::from_slice(&path_shape.points);
             }
+            Shape::CatmullRom(spline) => {
+                self.shape_path += AllocInfo::from_slice(&spline.points);
+            }
+            Shape::SvgPath(svg_path) => {
+                self.shape_path += AllocInfo::from_slice(svg_path.path.commands());
+            }
             Shape::Text(text_shape) => {
                 self.shape_text += AllocInfo::from_galley(&text_shape.galley);
 
@@ -63,6 +63,9 @@ impl std::hash::Hash for Stroke {
 pub struct PathStroke {
     pub width: f32,
     pub color: ColorMode,
+
+    /// If set, the stroke is painted as dashes instead of a solid line.
+    pub dash: Option<DashPattern>,
 }
 
 impl PathStroke {
@@ -70,6 +73,7 @@ impl PathStroke {
     pub const NONE: Self = Self {
         width: 0.0,
         color: ColorMode::TRANSPARENT,
+        dash: None,
     };
 
     #[inline]
@@ -77,6 +81,7 @@ impl PathStroke {
         Self {
             width: width.into(),
             color: ColorMode::Solid(color.into()),
+            dash: None,
         }
     }
 
@@ -91,9 +96,17 @@ impl PathStroke {
         Self {
             width: width.into(),
             color: ColorMode::UV(Arc::new(callback)),
+            dash: None,
         }
     }
 
+    /// Paint the stroke as dashes, e.g. `PathStroke::new(1.0, color).dashed(DashPattern::new(4.0, 2.0))`.
+    #[inline]
+    pub fn dashed(mut self, dash: DashPattern) -> Self {
+        self.dash = Some(dash);
+        self
+    }
+
     /// True if width is zero or color is solid and transparent
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -116,6 +129,240 @@ impl From<Stroke> for PathStroke {
         Self {
             width: value.width,
             color: ColorMode::Solid(value.color),
+            dash: None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How to paint the ends of each dash of a [`DashPattern`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DashCap {
+    /// The dash ends exactly where the pattern says.
+    #[default]
+    Butt,
+
+    /// Each end of the dash is extended by half the stroke width,
+    /// so that short dashes look like squares.
+    Square,
+}
+
+/// Dash lengths for a dashed [`PathStroke`].
+///
+/// The pattern follows the path, across corners and around closed paths.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DashPattern {
+    /// Length of each dash, in points.
+    pub dash_length: f32,
+
+    /// Length of the gap between dashes, in points.
+    pub gap_length: f32,
+
+    /// How far into the pattern the path starts, in points.
+    ///
+    /// Animate this to get "marching ants".
+    pub offset: f32,
+
+    /// How to paint the ends of each dash.
+    pub cap: DashCap,
+}
+
+impl DashPattern {
+    /// Dashes and gaps are at least this long, in points.
+    pub const MIN_LENGTH: f32 = 0.1;
+
+    /// Segments of the path with more dashes than this are painted solid after the last one.
+    pub const MAX_DASHES_PER_SEGMENT: usize = 10_000;
+
+    #[inline]
+    pub fn new(dash_length: f32, gap_length: f32) -> Self {
+        Self {
+            dash_length,
+            gap_length,
+            offset: 0.0,
+            cap: DashCap::Butt,
+        }
+    }
+
+    /// How far into the pattern the path starts, in points.
+    #[inline]
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    #[inline]
+    pub fn cap(mut self, cap: DashCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// Split a polyline into the dashes of this pattern.
+    ///
+    /// If `closed`, the last point connects back to the first one.
+    /// Returns the whole line if the pattern has no gaps.
+    pub fn dashes(&self, points: &[Pos2], closed: bool) -> Vec<Vec<Pos2>> {
+        let Self {
+            dash_length,
+            gap_length,
+            offset,
+            cap: _,
+        } = *self;
+
+        if points.len() < 2 {
+            return vec![];
+        }
+        if !(0.0 < dash_length && 0.0 < gap_length && offset.is_finite()) {
+            // Nothing to split:
+            let mut line = points.to_vec();
+            if closed {
+                line.push(points[0]);
+            }
+            return if 0.0 < dash_length {
+                vec![line]
+            } else {
+                vec![]
+            };
+        }
+
+        // Shorter dashes and gaps are invisible anyway, and would make far too many dashes:
+        let dash_length = dash_length.max(Self::MIN_LENGTH);
+        let gap_length = gap_length.max(Self::MIN_LENGTH);
+
+        let period = dash_length + gap_length;
+        let mut phase = offset.rem_euclid(period); // How far into the pattern each segment starts
+        let mut drawing = phase < dash_length;
+        let started_drawing = drawing;
+
+        let mut dashes = vec![];
+        let mut current = if drawing { vec![points[0]] } else { vec![] };
+
+        let num_segments = if closed {
+            points.len()
+        } else {
+            points.len() - 1
+        };
+        for i in 0..num_segments {
+            let (a, b) = (points[i], points[(i + 1) % points.len()]);
+            let length = a.distance(b);
+            let dir = if 0.0 < length {
+                (b - a) / length
+            } else {
+                Vec2::ZERO
+            };
+
+            // Where along the segment each dash ends and the next one starts.
+            // Computed from the index rather than summed up, so that rounding errors don't add up:
+            let switches = (0..Self::MAX_DASHES_PER_SEGMENT).flat_map(|k| {
+                let period_start = k as f32 * period - phase;
+                [
+                    (period_start + dash_length, true),
+                    (period_start + period, false),
+                ]
+            });
+            let mut capped = true;
+            for (t, is_dash_end) in switches {
+                if length <= t {
+                    capped = false;
+                    break;
+                }
+                if t < 0.0 || is_dash_end != drawing {
+                    continue; // Before the start of the segment
+                }
+                current.push(a + t * dir);
+                if drawing {
+                    dashes.push(std::mem::take(&mut current));
+                }
+                drawing = !drawing;
+            }
+            if drawing {
+                current.push(b);
+            }
+
+            phase = if capped {
+                // The rest of the segment was painted like the last dash or gap, so start it over:
+                if drawing {
+                    0.0
+                } else {
+                    dash_length
+                }
+            } else {
+                (phase + length).rem_euclid(period)
+            };
+        }
+
+        if drawing {
+            if closed && started_drawing && !dashes.is_empty() {
+                // The last dash continues into the first one:
+                let first = dashes.remove(0);
+                current.extend(first.into_iter().skip(1));
+            }
+            dashes.push(current);
         }
+        dashes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dashes() {
+        let line = [pos2(0.0, 0.0), pos2(10.0, 0.0)];
+        let dashes = DashPattern::new(3.0, 1.0).dashes(&line, false);
+        assert_eq!(
+            dashes,
+            vec![
+                vec![pos2(0.0, 0.0), pos2(3.0, 0.0)],
+                vec![pos2(4.0, 0.0), pos2(7.0, 0.0)],
+                vec![pos2(8.0, 0.0), pos2(10.0, 0.0)],
+            ]
+        );
+
+        // Starting in the middle of a gap:
+        let dashes = DashPattern::new(3.0, 2.0).offset(4.0).dashes(&line, false);
+        assert_eq!(dashes[0], vec![pos2(1.0, 0.0), pos2(4.0, 0.0)]);
+
+        // Dashes follow the path around corners:
+        let corner = [pos2(0.0, 0.0), pos2(2.0, 0.0), pos2(2.0, 2.0)];
+        let dashes = DashPattern::new(3.0, 10.0).dashes(&corner, false);
+        assert_eq!(
+            dashes,
+            vec![vec![pos2(0.0, 0.0), pos2(2.0, 0.0), pos2(2.0, 1.0)]]
+        );
+
+        // The dash over the start of a closed path is joined:
+        let square = [
+            pos2(0.0, 0.0),
+            pos2(4.0, 0.0),
+            pos2(4.0, 4.0),
+            pos2(0.0, 4.0),
+        ];
+        let dashes = DashPattern::new(3.0, 1.0).offset(1.0).dashes(&square, true);
+        assert_eq!(dashes.len(), 4);
+        assert_eq!(
+            dashes[3],
+            vec![pos2(0.0, 1.0), pos2(0.0, 0.0), pos2(2.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn test_tiny_dashes() {
+        // Far from the origin, adding the tiny lengths to a position would not change it.
+        // After the maximum number of dashes, the rest of the segment is one long dash:
+        let line = [pos2(0.0, 0.0), pos2(4000.0, 0.0)];
+        let dashes = DashPattern::new(1e-5, 1e-5).dashes(&line, false);
+        assert_eq!(dashes.len(), DashPattern::MAX_DASHES_PER_SEGMENT + 1);
+        assert_eq!(dashes.last().unwrap().last(), Some(&pos2(4000.0, 0.0)));
+
+        // The dashes stay evenly spaced along long paths:
+        let line = [pos2(0.0, 0.0), pos2(1000.0, 0.0)];
+        let dashes = DashPattern::new(0.25, 0.25).dashes(&line, false);
+        assert_eq!(dashes.len(), 2000);
+        assert_eq!(dashes[1999], vec![pos2(999.5, 0.0), pos2(999.75, 0.0)]);
     }
 }
@@ -0,0 +1,723 @@
+use std::sync::Arc;
+
+use crate::{shape::Shape, Color32, CubicBezierShape, PathStroke, QuadraticBezierShape, Stroke};
+use emath::*;
+
+// ----------------------------------------------------------------------------
+
+/// One command of a [`PathData`]. All positions are absolute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PathCommand {
+    /// Start a new sub-path at this position.
+    MoveTo(Pos2),
+
+    /// A straight line to this position.
+    LineTo(Pos2),
+
+    /// A quadratic Bézier curve with the given control point and end position.
+    QuadraticTo(Pos2, Pos2),
+
+    /// A cubic Bézier curve with the given two control points and end position.
+    CubicTo(Pos2, Pos2, Pos2),
+
+    /// Close the current sub-path with a straight line back to its start.
+    Close,
+}
+
+/// Vector path data, like the `d` attribute of an SVG `<path>` element.
+///
+/// Either parse it from SVG path syntax with [`Self::parse`],
+/// or build it with [`Self::move_to`], [`Self::line_to`], [`Self::cubic_to`], etc.
+///
+/// Elliptical arcs are converted to cubic Bézier curves when added.
+///
+/// Paint it with [`Shape::svg_path`] or [`SvgPathShape`].
+/// Wrap it in an [`Arc`] to parse it once and paint it every frame.
+///
+/// ```
+/// # use epaint::PathData;
+/// let arrow = PathData::parse("M 0 5 L 10 5 M 6 1 L 10 5 L 6 9").unwrap();
+/// assert_eq!(arrow.commands().len(), 5);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PathData {
+    commands: Vec<PathCommand>,
+
+    /// Where the next command starts.
+    current: Pos2,
+
+    /// Where the current sub-path started.
+    start: Pos2,
+}
+
+impl PathData {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The commands of the path, with all positions absolute.
+    #[inline]
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Where the next command starts.
+    #[inline]
+    pub fn current_pos(&self) -> Pos2 {
+        self.current
+    }
+
+    /// Start a new sub-path.
+    pub fn move_to(&mut self, pos: Pos2) -> &mut Self {
+        self.commands.push(PathCommand::MoveTo(pos));
+        self.current = pos;
+        self.start = pos;
+        self
+    }
+
+    /// A straight line from the current position.
+    pub fn line_to(&mut self, pos: Pos2) -> &mut Self {
+        self.commands.push(PathCommand::LineTo(pos));
+        self.current = pos;
+        self
+    }
+
+    /// A quadratic Bézier curve from the current position.
+    pub fn quadratic_to(&mut self, control: Pos2, pos: Pos2) -> &mut Self {
+        self.commands.push(PathCommand::QuadraticTo(control, pos));
+        self.current = pos;
+        self
+    }
+
+    /// A cubic Bézier curve from the current position.
+    pub fn cubic_to(&mut self, control1: Pos2, control2: Pos2, pos: Pos2) -> &mut Self {
+        self.commands
+            .push(PathCommand::CubicTo(control1, control2, pos));
+        self.current = pos;
+        self
+    }
+
+    /// An elliptical arc from the current position, with the same parameters as the SVG `A` command.
+    ///
+    /// * `radii`: the radii of the ellipse. They are scaled up if they are too small to reach `pos`.
+    /// * `x_axis_rotation`: the rotation of the ellipse, in radians.
+    /// * `large_arc`: take the longer of the two possible arcs.
+    /// * `sweep`: go clockwise (in screen coordinates, where y points down).
+    pub fn arc_to(
+        &mut self,
+        radii: Vec2,
+        x_axis_rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        pos: Pos2,
+    ) -> &mut Self {
+        let from = self.current;
+        if from == pos {
+            return self;
+        }
+        let (mut rx, mut ry) = (radii.x.abs(), radii.y.abs());
+        if rx == 0.0 || ry == 0.0 {
+            return self.line_to(pos);
+        }
+
+        // Convert from endpoint to center parameterization,
+        // see https://www.w3.org/TR/SVG11/implnote.html#ArcConversionEndpointToCenter
+        let rot = Rot2::from_angle(x_axis_rotation);
+        let p1 = rot.inverse() * (0.5 * (from - pos));
+
+        // Scale up the radii if they can't reach:
+        let lambda = (p1.x / rx).powi(2) + (p1.y / ry).powi(2);
+        if 1.0 < lambda {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let numerator = (rx * ry).powi(2) - (rx * p1.y).powi(2) - (ry * p1.x).powi(2);
+        let denominator = (rx * p1.y).powi(2) + (ry * p1.x).powi(2);
+        let mut coefficient = (numerator / denominator).max(0.0).sqrt();
+        if large_arc == sweep {
+            coefficient = -coefficient;
+        }
+        let center_prime = vec2(coefficient * rx * p1.y / ry, -coefficient * ry * p1.x / rx);
+        let center = (rot * center_prime + 0.5 * (from.to_vec2() + pos.to_vec2())).to_pos2();
+
+        let u = vec2((p1.x - center_prime.x) / rx, (p1.y - center_prime.y) / ry);
+        let v = vec2((-p1.x - center_prime.x) / rx, (-p1.y - center_prime.y) / ry);
+        let start_angle = u.angle();
+        let mut sweep_angle = (u.x * v.y - u.y * v.x).atan2(u.dot(v));
+        if sweep && sweep_angle < 0.0 {
+            sweep_angle += std::f32::consts::TAU;
+        } else if !sweep && 0.0 < sweep_angle {
+            sweep_angle -= std::f32::consts::TAU;
+        }
+
+        // Approximate each quarter of the ellipse (or less) with a cubic Bézier curve:
+        let num_segments = (sweep_angle.abs() / std::f32::consts::FRAC_PI_2)
+            .ceil()
+            .max(1.0) as usize;
+        let step = sweep_angle / num_segments as f32;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+        let point = |angle: f32| center + rot * vec2(rx * angle.cos(), ry * angle.sin());
+        let tangent = |angle: f32| rot * vec2(-rx * angle.sin(), ry * angle.cos());
+
+        for i in 0..num_segments {
+            let a0 = start_angle + i as f32 * step;
+            let a1 = a0 + step;
+            let p0 = point(a0);
+            let p3 = if i + 1 == num_segments {
+                pos
+            } else {
+                point(a1)
+            };
+            self.cubic_to(p0 + k * tangent(a0), p3 - k * tangent(a1), p3);
+        }
+        self
+    }
+
+    /// Close the current sub-path with a straight line back to where it started.
+    pub fn close(&mut self) -> &mut Self {
+        self.commands.push(PathCommand::Close);
+        self.current = self.start;
+        self
+    }
+
+    /// Move all positions of the path, e.g. to scale and translate it into place.
+    pub fn map_points(&mut self, mut f: impl FnMut(Pos2) -> Pos2) {
+        for command in &mut self.commands {
+            match command {
+                PathCommand::MoveTo(p) | PathCommand::LineTo(p) => *p = f(*p),
+                PathCommand::QuadraticTo(c, p) => {
+                    *c = f(*c);
+                    *p = f(*p);
+                }
+                PathCommand::CubicTo(c1, c2, p) => {
+                    *c1 = f(*c1);
+                    *c2 = f(*c2);
+                    *p = f(*p);
+                }
+                PathCommand::Close => {}
+            }
+        }
+        self.current = f(self.current);
+        self.start = f(self.start);
+    }
+
+    /// Bounding rectangle of all positions and control points.
+    ///
+    /// This contains the whole path, but may be larger than it.
+    pub fn bounding_rect(&self) -> Rect {
+        let mut rect = Rect::NOTHING;
+        for command in &self.commands {
+            match *command {
+                PathCommand::MoveTo(p) | PathCommand::LineTo(p) => rect.extend_with(p),
+                PathCommand::QuadraticTo(c, p) => {
+                    rect.extend_with(c);
+                    rect.extend_with(p);
+                }
+                PathCommand::CubicTo(c1, c2, p) => {
+                    rect.extend_with(c1);
+                    rect.extend_with(c2);
+                    rect.extend_with(p);
+                }
+                PathCommand::Close => {}
+            }
+        }
+        rect
+    }
+
+    /// Approximate the path with straight lines.
+    ///
+    /// Returns the points of each sub-path, and whether or not it is closed.
+    /// The first point of a closed sub-path is not repeated at the end.
+    ///
+    /// The `tolerance` is the maximum distance between the curves and the lines.
+    pub fn flatten(&self, tolerance: f32) -> Vec<(Vec<Pos2>, bool)> {
+        let mut subpaths = vec![];
+        let mut points: Vec<Pos2> = vec![];
+        let mut current = Pos2::ZERO;
+
+        let mut finish = |points: &mut Vec<Pos2>, closed: bool| {
+            if 2 <= points.len() {
+                subpaths.push((std::mem::take(points), closed));
+            } else {
+                points.clear();
+            }
+        };
+
+        for command in &self.commands {
+            if points.is_empty() && !matches!(command, PathCommand::MoveTo(_)) {
+                points.push(current);
+            }
+            match *command {
+                PathCommand::MoveTo(p) => {
+                    finish(&mut points, false);
+                    points.push(p);
+                    current = p;
+                }
+                PathCommand::LineTo(p) => {
+                    points.push(p);
+                    current = p;
+                }
+                PathCommand::QuadraticTo(c, p) => {
+                    let curve = QuadraticBezierShape::from_points_stroke(
+                        [current, c, p],
+                        false,
+                        Color32::TRANSPARENT,
+                        Stroke::NONE,
+                    );
+                    curve.for_each_flattened_with_t(tolerance, &mut |p, _t| points.push(p));
+                    current = p;
+                }
+                PathCommand::CubicTo(c1, c2, p) => {
+                    let curve = CubicBezierShape::from_points_stroke(
+                        [current, c1, c2, p],
+                        false,
+                        Color32::TRANSPARENT,
+                        Stroke::NONE,
+                    );
+                    curve.for_each_flattened_with_t(tolerance, &mut |p, _t| points.push(p));
+                    current = p;
+                }
+                PathCommand::Close => {
+                    let start = points[0];
+                    if 2 <= points.len() && points.last() == Some(&start) {
+                        points.pop();
+                    }
+                    finish(&mut points, true);
+                    current = start;
+                }
+            }
+        }
+        finish(&mut points, false);
+
+        subpaths
+    }
+
+    /// Parse SVG path syntax, e.g. `"M10 10 h 80 v 80 h -80 Z"`.
+    ///
+    /// All SVG path commands are supported, both absolute (upper case) and relative (lower case).
+    ///
+    /// See also [`Self::parse_lossy`].
+    ///
+    /// # Errors
+    /// Fails on invalid syntax, or if the path doesn't start with a move-to command.
+    pub fn parse(d: &str) -> Result<Self, PathParseError> {
+        let mut path = Self::default();
+        parse_into(d, &mut path)?;
+        Ok(path)
+    }
+
+    /// Like [`Self::parse`], but on error everything before the error is kept,
+    /// which is what browsers do.
+    pub fn parse_lossy(d: &str) -> Self {
+        let mut path = Self::default();
+        parse_into(d, &mut path).ok();
+        path
+    }
+}
+
+/// Returned by [`PathData::parse`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathParseError {
+    /// Byte offset into the string where the error was found.
+    pub offset: usize,
+
+    pub message: &'static str,
+}
+
+impl std::fmt::Display for PathParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for PathParseError {}
+
+// ----------------------------------------------------------------------------
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &'static str) -> PathParseError {
+        PathParseError {
+            offset: self.offset,
+            message,
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(b) = self.bytes.get(self.offset) {
+            if b.is_ascii_whitespace() || *b == b',' {
+                self.offset += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.bytes.get(self.offset).copied()
+    }
+
+    fn starts_number(&mut self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'-' | b'+' | b'.'))
+    }
+
+    fn number(&mut self) -> Result<f32, PathParseError> {
+        self.skip_separators();
+        let start = self.offset;
+        let digits = |parser: &mut Self| {
+            let digits_start = parser.offset;
+            while parser
+                .bytes
+                .get(parser.offset)
+                .map_or(false, u8::is_ascii_digit)
+            {
+                parser.offset += 1;
+            }
+            parser.offset - digits_start
+        };
+
+        if matches!(self.bytes.get(self.offset), Some(b'-' | b'+')) {
+            self.offset += 1;
+        }
+        let mut num_digits = digits(self);
+        if self.bytes.get(self.offset) == Some(&b'.') {
+            self.offset += 1;
+            num_digits += digits(self);
+        }
+        if num_digits == 0 {
+            self.offset = start;
+            return Err(self.error("Expected a number"));
+        }
+        if matches!(self.bytes.get(self.offset), Some(b'e' | b'E')) {
+            let before_exponent = self.offset;
+            self.offset += 1;
+            if matches!(self.bytes.get(self.offset), Some(b'-' | b'+')) {
+                self.offset += 1;
+            }
+            if digits(self) == 0 {
+                self.offset = before_exponent;
+            }
+        }
+
+        std::str::from_utf8(&self.bytes[start..self.offset])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| self.error("Invalid number"))
+    }
+
+    fn pos(&mut self) -> Result<Pos2, PathParseError> {
+        Ok(pos2(self.number()?, self.number()?))
+    }
+
+    /// Arc flags are a single `0` or `1`, and need no separator after them.
+    fn flag(&mut self) -> Result<bool, PathParseError> {
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(self.error("Expected an arc flag (0 or 1)")),
+        };
+        self.offset += 1;
+        Ok(flag)
+    }
+}
+
+fn parse_into(d: &str, path: &mut PathData) -> Result<(), PathParseError> {
+    let mut parser = Parser {
+        bytes: d.as_bytes(),
+        offset: 0,
+    };
+
+    // For the smooth curve commands (S, T):
+    let mut last_cubic_control: Option<Pos2> = None;
+    let mut last_quadratic_control: Option<Pos2> = None;
+
+    while let Some(command) = parser.peek() {
+        if !command.is_ascii_alphabetic() {
+            return Err(parser.error("Expected a path command"));
+        }
+        if path.is_empty() && !matches!(command, b'M' | b'm') {
+            return Err(parser.error("A path must start with a move-to command"));
+        }
+        parser.offset += 1;
+
+        let relative = command.is_ascii_lowercase();
+        let mut first = true;
+
+        // Commands can be repeated by just giving more numbers:
+        while first || (!command.eq_ignore_ascii_case(&b'Z') && parser.starts_number()) {
+            let origin = if relative {
+                path.current_pos().to_vec2()
+            } else {
+                Vec2::ZERO
+            };
+            let mut cubic_control = None;
+            let mut quadratic_control = None;
+
+            match command.to_ascii_uppercase() {
+                b'M' => {
+                    let p = parser.pos()? + origin;
+                    if first {
+                        path.move_to(p);
+                    } else {
+                        // Following pairs are implicit line-to commands.
+                        path.line_to(p);
+                    }
+                }
+                b'L' => {
+                    path.line_to(parser.pos()? + origin);
+                }
+                b'H' => {
+                    let x = origin.x + parser.number()?;
+                    path.line_to(pos2(x, path.current_pos().y));
+                }
+                b'V' => {
+                    let y = origin.y + parser.number()?;
+                    path.line_to(pos2(path.current_pos().x, y));
+                }
+                b'C' => {
+                    let c1 = parser.pos()? + origin;
+                    let c2 = parser.pos()? + origin;
+                    let p = parser.pos()? + origin;
+                    path.cubic_to(c1, c2, p);
+                    cubic_control = Some(c2);
+                }
+                b'S' => {
+                    let current = path.current_pos();
+                    let c1 = last_cubic_control.map_or(current, |c| current + (current - c));
+                    let c2 = parser.pos()? + origin;
+                    let p = parser.pos()? + origin;
+                    path.cubic_to(c1, c2, p);
+                    cubic_control = Some(c2);
+                }
+                b'Q' => {
+                    let c = parser.pos()? + origin;
+                    let p = parser.pos()? + origin;
+                    path.quadratic_to(c, p);
+                    quadratic_control = Some(c);
+                }
+                b'T' => {
+                    let current = path.current_pos();
+                    let c = last_quadratic_control.map_or(current, |c| current + (current - c));
+                    let p = parser.pos()? + origin;
+                    path.quadratic_to(c, p);
+                    quadratic_control = Some(c);
+                }
+                b'A' => {
+                    let radii = vec2(parser.number()?, parser.number()?);
+                    let x_axis_rotation = parser.number()?.to_radians();
+                    let large_arc = parser.flag()?;
+                    let sweep = parser.flag()?;
+                    let p = parser.pos()? + origin;
+                    path.arc_to(radii, x_axis_rotation, large_arc, sweep, p);
+                }
+                b'Z' => {
+                    path.close();
+                }
+                _ => {
+                    parser.offset -= 1;
+                    return Err(parser.error("Unknown path command"));
+                }
+            }
+
+            last_cubic_control = cubic_control;
+            last_quadratic_control = quadratic_control;
+            first = false;
+        }
+    }
+
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+
+/// How to decide what is inside a path that intersects itself or has holes.
+///
+/// Same as the SVG `fill-rule` property.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FillRule {
+    /// Inside if the path winds around the point a non-zero number of times.
+    ///
+    /// Holes must wind the opposite way of their outline.
+    /// This is the SVG default.
+    #[default]
+    NonZero,
+
+    /// Inside if a ray from the point crosses the path an odd number of times.
+    ///
+    /// Holes can wind either way.
+    EvenOdd,
+}
+
+impl FillRule {
+    /// Is a point with this winding number inside?
+    #[inline]
+    pub fn is_inside(self, winding_number: i32) -> bool {
+        match self {
+            Self::NonZero => winding_number != 0,
+            Self::EvenOdd => winding_number % 2 != 0,
+        }
+    }
+}
+
+/// How many times the closed polygons wind around the given position.
+pub(crate) fn winding_number(polygons: &[(Vec<Pos2>, bool)], pos: Pos2) -> i32 {
+    let mut winding = 0;
+    for (points, _) in polygons {
+        for (i, &a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            // Which side of the edge the position is on:
+            let side = (b - a).x * (pos - a).y - (b - a).y * (pos - a).x;
+            if a.y <= pos.y && pos.y < b.y && 0.0 < side {
+                winding += 1;
+            } else if b.y <= pos.y && pos.y < a.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+    winding
+}
+
+// ----------------------------------------------------------------------------
+
+/// A filled and/or stroked [`PathData`], e.g. an icon from SVG path syntax.
+///
+/// Unlike [`crate::PathShape`], the fill can have any shape, including holes.
+/// The path is tessellated by epaint, so it stays sharp at any zoom.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SvgPathShape {
+    /// Shared, so that it only needs to be parsed once.
+    pub path: Arc<PathData>,
+
+    /// Every sub-path is filled as if it was closed.
+    pub fill: Color32,
+
+    pub fill_rule: FillRule,
+
+    pub stroke: PathStroke,
+}
+
+impl SvgPathShape {
+    pub fn new(
+        path: impl Into<Arc<PathData>>,
+        fill: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            fill: fill.into(),
+            fill_rule: FillRule::default(),
+            stroke: stroke.into(),
+        }
+    }
+
+    #[inline]
+    pub fn with_fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// The visual bounding rectangle (includes stroke width)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
+            Rect::NOTHING
+        } else {
+            self.path.bounding_rect().expand(self.stroke.width / 2.0)
+        }
+    }
+}
+
+impl From<SvgPathShape> for Shape {
+    #[inline(always)]
+    fn from(shape: SvgPathShape) -> Self {
+        Self::SvgPath(shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let path = PathData::parse("M10,10 h80v80 H10 z m5 5 l 1e1 0").unwrap();
+        assert_eq!(
+            path.commands(),
+            &[
+                PathCommand::MoveTo(pos2(10.0, 10.0)),
+                PathCommand::LineTo(pos2(90.0, 10.0)),
+                PathCommand::LineTo(pos2(90.0, 90.0)),
+                PathCommand::LineTo(pos2(10.0, 90.0)),
+                PathCommand::Close,
+                PathCommand::MoveTo(pos2(15.0, 15.0)),
+                PathCommand::LineTo(pos2(25.0, 15.0)),
+            ]
+        );
+
+        // Numbers without separators, implicit repeats, and smooth curves:
+        let path = PathData::parse("M0-1.5.5.5L1 1 2 2s3 0 3 3").unwrap();
+        assert_eq!(path.commands()[0], PathCommand::MoveTo(pos2(0.0, -1.5)));
+        assert_eq!(path.commands()[1], PathCommand::LineTo(pos2(0.5, 0.5)));
+        assert_eq!(path.commands()[3], PathCommand::LineTo(pos2(2.0, 2.0)));
+        assert_eq!(
+            path.commands()[4],
+            PathCommand::CubicTo(pos2(2.0, 2.0), pos2(5.0, 2.0), pos2(5.0, 5.0))
+        );
+
+        let err = PathData::parse("M 0 0 L 1").unwrap_err();
+        assert_eq!(err.offset, 9);
+        assert_eq!(PathData::parse_lossy("M 0 0 L 1 1 X").commands().len(), 2);
+        assert!(PathData::parse("L 0 0").is_err());
+    }
+
+    #[test]
+    fn test_arc() {
+        // A half circle with radius 10, from the left to the right, through the top:
+        let path = PathData::parse("M 0 10 A 10 10 0 0 1 20 10").unwrap();
+        assert_eq!(path.commands().len(), 3);
+        let (points, closed) = &path.flatten(0.01)[0];
+        assert!(!closed);
+        for p in points {
+            assert!((p.distance(pos2(10.0, 10.0)) - 10.0).abs() < 0.05, "{p:?}");
+            assert!(p.y <= 10.0 + 1e-4, "{p:?}");
+        }
+        assert_eq!(*points.last().unwrap(), pos2(20.0, 10.0));
+
+        // Flags without separators, and radii that are too small:
+        let path = PathData::parse("M 0 0 a1 1 0 1020 0").unwrap();
+        assert_eq!(path.current_pos(), pos2(20.0, 0.0));
+    }
+
+    #[test]
+    fn test_winding() {
+        // A square with a square hole, both clockwise:
+        let path = PathData::parse("M0 0H30V30H0Z M10 10H20V20H10Z").unwrap();
+        let polygons = path.flatten(0.1);
+        assert_eq!(polygons.len(), 2);
+        assert!(polygons
+            .iter()
+            .all(|(points, closed)| *closed && points.len() == 4));
+
+        let inside_ring = winding_number(&polygons, pos2(5.0, 15.0));
+        let inside_hole = winding_number(&polygons, pos2(15.0, 15.0));
+        assert!(FillRule::EvenOdd.is_inside(inside_ring));
+        assert!(!FillRule::EvenOdd.is_inside(inside_hole));
+        assert!(FillRule::NonZero.is_inside(inside_hole));
+        assert_eq!(winding_number(&polygons, pos2(50.0, 15.0)), 0);
+    }
+}
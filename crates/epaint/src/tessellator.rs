@@ -5,6 +5,8 @@
 
 #![allow(clippy::identity_op)]
 
+use std::sync::Arc;
+
 use crate::texture_atlas::PreparedDisc;
 use crate::*;
 use emath::*;
@@ -729,6 +731,140 @@ fn cw_signed_area(path: &[PathPoint]) -> f64 {
     }
 }
 
+/// Unit vectors along an arc of a circle with the given radius in physical pixels,
+/// from `start_angle` to `end_angle` (inclusive), spaced so that the polygon
+/// deviates less than a tenth of a pixel from the true circle.
+fn arc_directions(radius_px: f32, start_angle: f32, end_angle: f32) -> Vec<Vec2> {
+    const TOLERANCE_PX: f32 = 0.1;
+
+    let sweep = end_angle - start_angle;
+    let max_step = if TOLERANCE_PX < radius_px {
+        2.0 * (1.0 - TOLERANCE_PX / radius_px).acos()
+    } else {
+        std::f32::consts::FRAC_PI_2
+    };
+    let num_segments = ((sweep.abs() / max_step).ceil() as usize).clamp(1, 1024);
+
+    (0..=num_segments)
+        .map(|i| Vec2::angled(start_angle + sweep * i as f32 / num_segments as f32))
+        .collect()
+}
+
+/// An edge of a polygon, going down (`winding == 1`) or up (`winding == -1`).
+struct FillEdge {
+    top: Pos2,
+    bottom: Pos2,
+    winding: i32,
+}
+
+impl FillEdge {
+    fn x_at(&self, y: f32) -> f32 {
+        let t = (y - self.top.y) / (self.bottom.y - self.top.y);
+        lerp(self.top.x..=self.bottom.x, t)
+    }
+}
+
+/// Fill polygons of any shape, including holes and self-intersections,
+/// by cutting them into horizontal trapezoids.
+///
+/// All polygons are treated as closed. The edges are not anti-aliased.
+fn fill_polygons(
+    polygons: &[(Vec<Pos2>, bool)],
+    fill_rule: FillRule,
+    color: Color32,
+    out: &mut Mesh,
+) {
+    let mut edges = vec![];
+    for (points, _) in polygons {
+        for (i, &a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            if a.y < b.y {
+                edges.push(FillEdge {
+                    top: a,
+                    bottom: b,
+                    winding: 1,
+                });
+            } else if b.y < a.y {
+                edges.push(FillEdge {
+                    top: b,
+                    bottom: a,
+                    winding: -1,
+                });
+            }
+        }
+    }
+    edges.sort_by(|a, b| a.top.y.total_cmp(&b.top.y));
+
+    let mut ys: Vec<f32> = edges.iter().flat_map(|e| [e.top.y, e.bottom.y]).collect();
+    ys.sort_by(f32::total_cmp);
+    ys.dedup();
+
+    let mut active: Vec<&FillEdge> = vec![];
+    let mut next_edge = 0;
+    for band in ys.windows(2) {
+        let (y0, y1) = (band[0], band[1]);
+        active.retain(|edge| y0 < edge.bottom.y);
+        while next_edge < edges.len() && edges[next_edge].top.y <= y0 {
+            active.push(&edges[next_edge]);
+            next_edge += 1;
+        }
+        fill_band(&mut active, y0, y1, fill_rule, color, out, 0);
+    }
+}
+
+/// Fill the part of the polygons between `y0` and `y1`, where no vertices are.
+fn fill_band(
+    active: &mut [&FillEdge],
+    y0: f32,
+    y1: f32,
+    fill_rule: FillRule,
+    color: Color32,
+    out: &mut Mesh,
+    depth: usize,
+) {
+    let y_mid = 0.5 * (y0 + y1);
+    active.sort_by(|a, b| a.x_at(y_mid).total_cmp(&b.x_at(y_mid)));
+
+    // Edges that cross each other inside the band must be split there:
+    const MAX_SPLITS: usize = 8;
+    if depth < MAX_SPLITS {
+        for pair in active.windows(2) {
+            let d0 = pair[1].x_at(y0) - pair[0].x_at(y0);
+            let d1 = pair[1].x_at(y1) - pair[0].x_at(y1);
+            if d0 < -1e-3 || d1 < -1e-3 {
+                let y_cross = lerp(y0..=y1, d0 / (d0 - d1));
+                if y0 < y_cross && y_cross < y1 {
+                    fill_band(active, y0, y_cross, fill_rule, color, out, depth + 1);
+                    fill_band(active, y_cross, y1, fill_rule, color, out, depth + 1);
+                    return;
+                }
+            }
+        }
+    }
+
+    let mut winding = 0;
+    let mut left_edge = None;
+    for edge in active.iter() {
+        let was_inside = fill_rule.is_inside(winding);
+        winding += edge.winding;
+        let is_inside = fill_rule.is_inside(winding);
+
+        if !was_inside && is_inside {
+            left_edge = Some(edge);
+        } else if was_inside && !is_inside {
+            if let Some(left_edge) = left_edge.take() {
+                let idx = out.vertices.len() as u32;
+                out.colored_vertex(pos2(left_edge.x_at(y0), y0), color);
+                out.colored_vertex(pos2(edge.x_at(y0), y0), color);
+                out.colored_vertex(pos2(edge.x_at(y1), y1), color);
+                out.colored_vertex(pos2(left_edge.x_at(y1), y1), color);
+                out.add_triangle(idx, idx + 1, idx + 2);
+                out.add_triangle(idx, idx + 2, idx + 3);
+            }
+        }
+    }
+}
+
 /// Tessellate the given convex area into a polygon.
 ///
 /// Calling this may reverse the vertices in the path if they are wrong winding order.
@@ -876,18 +1012,87 @@ fn stroke_path(
     stroke: &PathStroke,
     out: &mut Mesh,
 ) {
-    let n = path.len() as u32;
-
-    if stroke.width <= 0.0 || stroke.color == ColorMode::TRANSPARENT || n < 2 {
+    if stroke.width <= 0.0 || stroke.color == ColorMode::TRANSPARENT || path.len() < 2 {
         return;
     }
 
-    let idx = out.vertices.len() as u32;
-
     // expand the bounding box to include the thickness of the path
     let bbox = Rect::from_points(&path.iter().map(|p| p.pos).collect::<Vec<Pos2>>())
         .expand((stroke.width / 2.0) + feathering);
 
+    if let Some(dash) = &stroke.dash {
+        stroke_dashed_path(feathering, path, path_type, stroke, dash, bbox, out);
+    } else {
+        stroke_solid_path(feathering, path, path_type, stroke, bbox, out);
+    }
+}
+
+/// Tessellate each dash of the path as a separate open stroke.
+///
+/// `bbox` is for the whole path, so that [`ColorMode::UV`] is continuous across the dashes.
+fn stroke_dashed_path(
+    feathering: f32,
+    path: &[PathPoint],
+    path_type: PathType,
+    stroke: &PathStroke,
+    dash: &DashPattern,
+    bbox: Rect,
+    out: &mut Mesh,
+) {
+    let points: Vec<Pos2> = path.iter().map(|p| p.pos).collect();
+    let solid_stroke = PathStroke {
+        dash: None,
+        ..stroke.clone()
+    };
+
+    let mut dash_path = Path::default();
+    for mut dash_points in dash.dashes(&points, path_type == PathType::Closed) {
+        if dash.cap == DashCap::Square {
+            extend_polyline_ends(&mut dash_points, 0.5 * stroke.width);
+        }
+        if dash_points.len() < 2 {
+            continue;
+        }
+
+        dash_path.clear();
+        dash_path.add_open_points(&dash_points);
+        stroke_solid_path(
+            feathering,
+            &dash_path.0,
+            PathType::Open,
+            &solid_stroke,
+            bbox,
+            out,
+        );
+    }
+}
+
+/// Move the first and last points of the polyline this much further out along the line.
+fn extend_polyline_ends(points: &mut [Pos2], amount: f32) {
+    if let Some((first, rest)) = points.split_first_mut() {
+        if let Some(next) = rest.iter().find(|p| *p != first) {
+            *first += amount * (*first - *next).normalized();
+        }
+    }
+    if let Some((last, rest)) = points.split_last_mut() {
+        if let Some(prev) = rest.iter().rev().find(|p| *p != last) {
+            *last += amount * (*last - *prev).normalized();
+        }
+    }
+}
+
+fn stroke_solid_path(
+    feathering: f32,
+    path: &[PathPoint],
+    path_type: PathType,
+    stroke: &PathStroke,
+    bbox: Rect,
+    out: &mut Mesh,
+) {
+    let n = path.len() as u32;
+
+    let idx = out.vertices.len() as u32;
+
     let get_color = |col: &ColorMode, pos: Pos2| match col {
         ColorMode::Solid(col) => *col,
         ColorMode::UV(fun) => fun(bbox, pos),
@@ -1174,6 +1379,12 @@ pub struct Tessellator {
     /// See [`TextureAtlas::prepared_discs`].
     prepared_discs: Vec<PreparedDisc>,
 
+    /// Where to find the meshes of [`Shape::PrecomputedMesh`].
+    mesh_mngr: Option<Arc<mutex::RwLock<MeshManager>>>,
+
+    /// See [`Self::with_subpixel_glyph_texture`].
+    subpixel_glyph_texture: Option<TextureId>,
+
     /// size of feathering in points. normally the size of a physical pixel. 0.0 if disabled
     feathering: f32,
 
@@ -1209,6 +1420,8 @@ impl Tessellator {
             options,
             font_tex_size,
             prepared_discs,
+            mesh_mngr: None,
+            subpixel_glyph_texture: None,
             feathering,
             clip_rect: Rect::EVERYTHING,
             scratchpad_points: Default::default(),
@@ -1216,6 +1429,30 @@ impl Tessellator {
         }
     }
 
+    /// Where to look up the meshes of [`Shape::PrecomputedMesh`].
+    ///
+    /// Without this, such shapes are not painted.
+    #[inline]
+    pub fn with_mesh_manager(mut self, mesh_mngr: Arc<mutex::RwLock<MeshManager>>) -> Self {
+        self.mesh_mngr = Some(mesh_mngr);
+        self
+    }
+
+    /// The texture with the glyphs of a font atlas with [`crate::text::GlyphRasterization::Subpixel`].
+    ///
+    /// The glyphs of text are put in meshes of their own with this texture,
+    /// so that the renderer can blend only them by the coverage of each color channel.
+    /// Everything else, including the backgrounds and underlines of text,
+    /// uses the font atlas without the subpixel coverage.
+    ///
+    /// Only [`Self::tessellate_clipped_shape`] does this;
+    /// [`Self::tessellate_shape`] puts everything in the same mesh.
+    #[inline]
+    pub fn with_subpixel_glyph_texture(mut self, texture_id: TextureId) -> Self {
+        self.subpixel_glyph_texture = Some(texture_id);
+        self
+    }
+
     /// Set the [`Rect`] to use for culling.
     pub fn set_clip_rect(&mut self, clip_rect: Rect) {
         self.clip_rect = clip_rect;
@@ -1242,6 +1479,17 @@ impl Tessellator {
             return; // skip empty clip rectangles
         }
 
+        if let Shape::PrecomputedMesh(mesh_shape) = shape {
+            // Handed to the renderer as is, so that it can keep the mesh on the GPU:
+            if let Some(primitive) = self.precomputed_mesh_primitive(clip_rect, &mesh_shape) {
+                out_primitives.push(ClippedPrimitive {
+                    clip_rect,
+                    primitive: Primitive::PrecomputedMesh(primitive),
+                });
+            }
+            return;
+        }
+
         if let Shape::Vec(shapes) = shape {
             for shape in shapes {
                 self.tessellate_clipped_shape(ClippedShape { clip_rect, shape }, out_primitives);
@@ -1257,34 +1505,24 @@ impl Tessellator {
             return;
         }
 
-        let start_new_mesh = match out_primitives.last() {
-            None => true,
-            Some(output_clipped_primitive) => {
-                output_clipped_primitive.clip_rect != clip_rect
-                    || match &output_clipped_primitive.primitive {
-                        Primitive::Mesh(output_mesh) => {
-                            output_mesh.texture_id != shape.texture_id()
-                        }
-                        Primitive::Callback(_) => true,
-                    }
-            }
-        };
+        self.clip_rect = clip_rect;
 
-        if start_new_mesh {
-            out_primitives.push(ClippedPrimitive {
-                clip_rect,
-                primitive: Primitive::Mesh(Mesh::default()),
-            });
+        if let (Shape::Text(text_shape), Some(glyph_texture)) =
+            (&shape, self.subpixel_glyph_texture)
+        {
+            let mut rest = Mesh::default();
+            let mut glyphs = Mesh::with_texture(glyph_texture);
+            self.tessellate_text_and_glyphs(text_shape, &mut rest, Some(&mut glyphs));
+            for mesh in [rest, glyphs] {
+                if !mesh.is_empty() {
+                    mesh_to_append_to(out_primitives, clip_rect, mesh.texture_id).append(mesh);
+                }
+            }
+            return;
         }
 
-        let out = out_primitives.last_mut().unwrap();
-
-        if let Primitive::Mesh(out_mesh) = &mut out.primitive {
-            self.clip_rect = clip_rect;
-            self.tessellate_shape(shape, out_mesh);
-        } else {
-            unreachable!();
-        }
+        let out_mesh = mesh_to_append_to(out_primitives, clip_rect, shape.texture_id());
+        self.tessellate_shape(shape, out_mesh);
     }
 
     /// Tessellate a single [`Shape`] into a [`Mesh`].
@@ -1307,6 +1545,12 @@ impl Tessellator {
             Shape::Ellipse(ellipse) => {
                 self.tessellate_ellipse(ellipse, out);
             }
+            Shape::Arc(arc) => {
+                self.tessellate_arc(arc, out);
+            }
+            Shape::Pie(pie) => {
+                self.tessellate_pie(pie, out);
+            }
             Shape::Mesh(mesh) => {
                 crate::profile_scope!("mesh");
 
@@ -1324,6 +1568,15 @@ impl Tessellator {
 
                 out.append(mesh);
             }
+            Shape::PrecomputedMesh(mesh_shape) => {
+                if let Some(primitive) =
+                    self.precomputed_mesh_primitive(self.clip_rect, &mesh_shape)
+                {
+                    let mut mesh = Mesh::clone(&primitive.mesh);
+                    mesh.transform(primitive.transform);
+                    out.append(mesh);
+                }
+            }
             Shape::LineSegment { points, stroke } => self.tessellate_line(points, stroke, out),
             Shape::Path(path_shape) => {
                 self.tessellate_path(&path_shape, out);
@@ -1345,12 +1598,48 @@ impl Tessellator {
                 self.tessellate_quadratic_bezier(&quadratic_shape, out);
             }
             Shape::CubicBezier(cubic_shape) => self.tessellate_cubic_bezier(&cubic_shape, out),
+            Shape::CatmullRom(spline) => self.tessellate_catmull_rom(&spline, out),
+            Shape::SvgPath(svg_path) => self.tessellate_svg_path(&svg_path, out),
             Shape::Callback(_) => {
                 panic!("Shape::Callback passed to Tessellator");
             }
         }
     }
 
+    /// Look up a registered mesh, or `None` if it is culled or missing.
+    fn precomputed_mesh_primitive(
+        &self,
+        clip_rect: Rect,
+        mesh_shape: &PrecomputedMeshShape,
+    ) -> Option<PrecomputedMeshPrimitive> {
+        let PrecomputedMeshShape {
+            id,
+            bounds: _,
+            transform,
+        } = *mesh_shape;
+
+        if self.options.coarse_tessellation_culling
+            && !clip_rect.intersects(mesh_shape.visual_bounding_rect())
+        {
+            return None;
+        }
+
+        let mesh_mngr = self.mesh_mngr.as_ref()?.read();
+        let Some(mesh) = mesh_mngr.get(id) else {
+            debug_assert!(
+                false,
+                "Shape::PrecomputedMesh refers to a freed mesh {id:?}"
+            );
+            return None;
+        };
+
+        Some(PrecomputedMeshPrimitive {
+            id,
+            mesh: mesh.clone(),
+            transform,
+        })
+    }
+
     /// Tessellate a single [`CircleShape`] into a [`Mesh`].
     ///
     /// * `shape`: the circle to tessellate.
@@ -1474,6 +1763,127 @@ impl Tessellator {
             .stroke_closed(self.feathering, &stroke.into(), out);
     }
 
+    /// Tessellate a single [`ArcShape`] into a [`Mesh`].
+    ///
+    /// * `shape`: the arc to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_arc(&mut self, shape: ArcShape, out: &mut Mesh) {
+        let ArcShape {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            stroke,
+        } = shape;
+
+        if radius <= 0.0 || stroke.is_empty() {
+            return;
+        }
+
+        if self.options.coarse_tessellation_culling
+            && !self
+                .clip_rect
+                .expand(radius + stroke.width)
+                .contains(center)
+        {
+            return;
+        }
+
+        if std::f32::consts::TAU <= (end_angle - start_angle).abs() {
+            self.tessellate_circle(CircleShape::stroke(center, radius, stroke), out);
+            return;
+        }
+
+        // The normals of an arc point straight out from the center,
+        // which gives an even feathering and butt ends that are square with the circle.
+        let directions = arc_directions(radius * self.pixels_per_point, start_angle, end_angle);
+        self.scratchpad_path.clear();
+        self.scratchpad_path.reserve(directions.len());
+        for dir in directions {
+            self.scratchpad_path.add_point(center + radius * dir, dir);
+        }
+        self.scratchpad_path
+            .stroke_open(self.feathering, &stroke.into(), out);
+    }
+
+    /// Tessellate a single [`PieShape`] into a [`Mesh`].
+    ///
+    /// * `shape`: the circle sector to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_pie(&mut self, shape: PieShape, out: &mut Mesh) {
+        let PieShape {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            fill,
+            stroke,
+        } = shape;
+
+        if radius <= 0.0 {
+            return;
+        }
+
+        if self.options.coarse_tessellation_culling
+            && !self
+                .clip_rect
+                .expand(radius + stroke.width)
+                .contains(center)
+        {
+            return;
+        }
+
+        if std::f32::consts::TAU <= (end_angle - start_angle).abs() {
+            self.tessellate_circle(
+                CircleShape {
+                    center,
+                    radius,
+                    fill,
+                    stroke,
+                },
+                out,
+            );
+            return;
+        }
+
+        // Go clockwise, so that the fill doesn't reverse the path.
+        // The fill is a triangle fan from the first point, so starting at the center
+        // fills sectors wider than half a circle correctly too, even though they aren't convex.
+        let (start_angle, end_angle) = if start_angle <= end_angle {
+            (start_angle, end_angle)
+        } else {
+            (end_angle, start_angle)
+        };
+        let directions = arc_directions(radius * self.pixels_per_point, start_angle, end_angle);
+
+        let mut points = Vec::with_capacity(directions.len() + 1);
+        points.push(center);
+        points.extend(directions.iter().map(|&dir| center + radius * dir));
+
+        self.scratchpad_path.clear();
+        self.scratchpad_path.add_line_loop(&points);
+
+        let path = &mut self.scratchpad_path.0;
+
+        // Use the exact normals along the curved edge:
+        let num_directions = directions.len();
+        for (point, &dir) in path[2..num_directions].iter_mut().zip(&directions[1..]) {
+            point.normal = dir;
+        }
+
+        // A thin sector has a very long miter at the center, which would make the feathering
+        // and stroke stick out like a spike. Limit it, like a miter limit:
+        const MAX_MITER_LENGTH: f32 = 2.0;
+        let center_normal = &mut path[0].normal;
+        if MAX_MITER_LENGTH * MAX_MITER_LENGTH < center_normal.length_sq() {
+            *center_normal = MAX_MITER_LENGTH * center_normal.normalized();
+        }
+
+        self.scratchpad_path.fill(self.feathering, fill, out);
+        self.scratchpad_path
+            .stroke_closed(self.feathering, &stroke.into(), out);
+    }
+
     /// Tessellate a single [`Mesh`] into a [`Mesh`].
     ///
     /// * `mesh`: the mesh to tessellate.
@@ -1582,12 +1992,30 @@ impl Tessellator {
             mut blur_width,
             fill_texture_id,
             uv,
+            angle,
         } = *rect;
 
-        if self.options.coarse_tessellation_culling
-            && !rect.expand(stroke.width).intersects(self.clip_rect)
-        {
-            return;
+        // Rotate around the center of the (unrotated) rect:
+        let center = rect.center();
+        let rot = Rot2::from_angle(angle);
+        let rotate = |p: Pos2| {
+            if angle == 0.0 {
+                p
+            } else {
+                center + rot * (p - center)
+            }
+        };
+
+        if self.options.coarse_tessellation_culling {
+            let bounds = rect.expand(stroke.width);
+            let bounds = if angle == 0.0 {
+                bounds
+            } else {
+                RotatedRect::from_rect(bounds, angle).bounding_rect()
+            };
+            if !bounds.intersects(self.clip_rect) {
+                return;
+            }
         }
         if rect.is_negative() {
             return;
@@ -1623,7 +2051,7 @@ impl Tessellator {
 
         if rect.width() < self.feathering {
             // Very thin - approximate by a vertical line-segment:
-            let line = [rect.center_top(), rect.center_bottom()];
+            let line = [rotate(rect.center_top()), rotate(rect.center_bottom())];
             if fill != Color32::TRANSPARENT {
                 self.tessellate_line(line, Stroke::new(rect.width(), fill), out);
             }
@@ -1633,7 +2061,7 @@ impl Tessellator {
             }
         } else if rect.height() < self.feathering {
             // Very thin - approximate by a horizontal line-segment:
-            let line = [rect.left_center(), rect.right_center()];
+            let line = [rotate(rect.left_center()), rotate(rect.right_center())];
             if fill != Color32::TRANSPARENT {
                 self.tessellate_line(line, Stroke::new(rect.height(), fill), out);
             }
@@ -1645,11 +2073,22 @@ impl Tessellator {
             let path = &mut self.scratchpad_path;
             path.clear();
             path::rounded_rectangle(&mut self.scratchpad_points, rect, rounding);
+            if angle != 0.0 {
+                for p in &mut self.scratchpad_points {
+                    *p = rotate(*p);
+                }
+            }
             path.add_line_loop(&self.scratchpad_points);
 
             if uv.is_positive() {
                 // Textured
+                let inv_rot = rot.inverse();
                 let uv_from_pos = |p: Pos2| {
+                    let p = if angle == 0.0 {
+                        p
+                    } else {
+                        center + inv_rot * (p - center)
+                    };
                     pos2(
                         remap(p.x, rect.x_range(), uv.x_range()),
                         remap(p.y, rect.y_range(), uv.y_range()),
@@ -1671,6 +2110,17 @@ impl Tessellator {
     /// * `text_shape`: the text to tessellate.
     /// * `out`: triangles are appended to this.
     pub fn tessellate_text(&mut self, text_shape: &TextShape, out: &mut Mesh) {
+        self.tessellate_text_and_glyphs(text_shape, out, None);
+    }
+
+    /// Like [`Self::tessellate_text`], but if `glyphs_out` is given,
+    /// the glyphs are appended to it instead of `out`.
+    fn tessellate_text_and_glyphs(
+        &mut self,
+        text_shape: &TextShape,
+        out: &mut Mesh,
+        mut glyphs_out: Option<&mut Mesh>,
+    ) {
         let TextShape {
             pos: galley_pos,
             galley,
@@ -1728,53 +2178,65 @@ impl Tessellator {
                 continue;
             }
 
-            let index_offset = out.vertices.len() as u32;
+            let transform_vertex = |i: usize, vertex: &Vertex| {
+                let Vertex { pos, uv, mut color } = *vertex;
 
-            out.indices.extend(
-                row.visuals
-                    .mesh
-                    .indices
-                    .iter()
-                    .map(|index| index + index_offset),
-            );
+                if let Some(override_text_color) = override_text_color {
+                    // Only override the glyph color (not background color, strike-through color, etc)
+                    if row.visuals.glyph_vertex_range.contains(&i) {
+                        color = *override_text_color;
+                    }
+                } else if color == Color32::PLACEHOLDER {
+                    color = *fallback_color;
+                }
 
-            out.vertices.extend(
-                row.visuals
-                    .mesh
-                    .vertices
-                    .iter()
-                    .enumerate()
-                    .map(|(i, vertex)| {
-                        let Vertex { pos, uv, mut color } = *vertex;
-
-                        if let Some(override_text_color) = override_text_color {
-                            // Only override the glyph color (not background color, strike-through color, etc)
-                            if row.visuals.glyph_vertex_range.contains(&i) {
-                                color = *override_text_color;
-                            }
-                        } else if color == Color32::PLACEHOLDER {
-                            color = *fallback_color;
-                        }
-
-                        if *opacity_factor < 1.0 {
-                            color = color.gamma_multiply(*opacity_factor);
-                        }
-
-                        debug_assert!(color != Color32::PLACEHOLDER, "A placeholder color made it to the tessellator. You forgot to set a fallback color.");
-
-                        let offset = if *angle == 0.0 {
-                            pos.to_vec2()
-                        } else {
-                            rotator * pos.to_vec2()
-                        };
-
-                        Vertex {
-                            pos: galley_pos + offset,
-                            uv: (uv.to_vec2() * uv_normalizer).to_pos2(),
-                            color,
-                        }
-                    }),
-            );
+                if *opacity_factor < 1.0 {
+                    color = color.gamma_multiply(*opacity_factor);
+                }
+
+                debug_assert!(color != Color32::PLACEHOLDER, "A placeholder color made it to the tessellator. You forgot to set a fallback color.");
+
+                let offset = if *angle == 0.0 {
+                    pos.to_vec2()
+                } else {
+                    rotator * pos.to_vec2()
+                };
+
+                Vertex {
+                    pos: galley_pos + offset,
+                    uv: (uv.to_vec2() * uv_normalizer).to_pos2(),
+                    color,
+                }
+            };
+
+            if let Some(glyphs_out) = glyphs_out.as_deref_mut() {
+                append_row_split(
+                    &row.visuals.mesh,
+                    row.visuals.glyph_vertex_range.clone(),
+                    transform_vertex,
+                    out,
+                    glyphs_out,
+                );
+            } else {
+                let index_offset = out.vertices.len() as u32;
+
+                out.indices.extend(
+                    row.visuals
+                        .mesh
+                        .indices
+                        .iter()
+                        .map(|index| index + index_offset),
+                );
+
+                out.vertices.extend(
+                    row.visuals
+                        .mesh
+                        .vertices
+                        .iter()
+                        .enumerate()
+                        .map(|(i, vertex)| transform_vertex(i, vertex)),
+                );
+            }
 
             if *underline != Stroke::NONE {
                 self.scratchpad_path.clear();
@@ -1845,6 +2307,70 @@ impl Tessellator {
         }
     }
 
+    /// Tessellate a single [`CatmullRomShape`] into a [`Mesh`].
+    ///
+    /// * `spline`: the spline to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_catmull_rom(&mut self, spline: &CatmullRomShape, out: &mut Mesh) {
+        let options = &self.options;
+        let clip_rect = self.clip_rect;
+        if options.coarse_tessellation_culling
+            && !spline.visual_bounding_rect().intersects(clip_rect)
+        {
+            return;
+        }
+
+        let points = spline.flatten(options.bezier_tolerance);
+        self.tessellate_bezier_complete(&points, spline.fill, spline.closed, &spline.stroke, out);
+    }
+
+    /// Tessellate a single [`SvgPathShape`] into a [`Mesh`].
+    ///
+    /// * `svg_path`: the path to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_svg_path(&mut self, svg_path: &SvgPathShape, out: &mut Mesh) {
+        let options = &self.options;
+        let clip_rect = self.clip_rect;
+        if options.coarse_tessellation_culling
+            && !svg_path.visual_bounding_rect().intersects(clip_rect)
+        {
+            return;
+        }
+
+        let subpaths = svg_path.path.flatten(options.bezier_tolerance);
+
+        if svg_path.fill != Color32::TRANSPARENT {
+            fill_polygons(&subpaths, svg_path.fill_rule, svg_path.fill, out);
+
+            if 0.0 < self.feathering {
+                // The fill itself is not anti-aliased, so we fade it out across the outline.
+                // This looks best with opaque fills.
+                let feathering = PathStroke::new(self.feathering, svg_path.fill);
+                for (points, _) in &subpaths {
+                    self.tessellate_bezier_complete(
+                        points,
+                        Color32::TRANSPARENT,
+                        true,
+                        &feathering,
+                        out,
+                    );
+                }
+            }
+        }
+
+        if !svg_path.stroke.is_empty() {
+            for (points, closed) in &subpaths {
+                self.tessellate_bezier_complete(
+                    points,
+                    Color32::TRANSPARENT,
+                    *closed,
+                    &svg_path.stroke,
+                    out,
+                );
+            }
+        }
+    }
+
     fn tessellate_bezier_complete(
         &mut self,
         points: &[Pos2],
@@ -1880,6 +2406,118 @@ impl Tessellator {
     }
 }
 
+/// The mesh to append a shape with this clip rectangle and texture to:
+/// the last one, or a new one if the last one doesn't match.
+fn mesh_to_append_to(
+    out_primitives: &mut Vec<ClippedPrimitive>,
+    clip_rect: Rect,
+    texture_id: TextureId,
+) -> &mut Mesh {
+    let start_new_mesh = match out_primitives.last() {
+        None => true,
+        Some(output_clipped_primitive) => {
+            output_clipped_primitive.clip_rect != clip_rect
+                || match &output_clipped_primitive.primitive {
+                    Primitive::Mesh(output_mesh) => output_mesh.texture_id != texture_id,
+                    Primitive::PrecomputedMesh(_) | Primitive::Callback(_) => true,
+                }
+        }
+    };
+
+    if start_new_mesh {
+        out_primitives.push(ClippedPrimitive {
+            clip_rect,
+            primitive: Primitive::Mesh(Mesh::with_texture(texture_id)),
+        });
+    }
+
+    match &mut out_primitives.last_mut().unwrap().primitive {
+        Primitive::Mesh(out_mesh) => out_mesh,
+        Primitive::PrecomputedMesh(_) | Primitive::Callback(_) => unreachable!(),
+    }
+}
+
+/// Append the triangles of a row of text, with the vertices in `glyph_vertex_range` to `glyphs_out`,
+/// and the rest (backgrounds, strikethrough, …) to `out`.
+fn append_row_split(
+    row_mesh: &Mesh,
+    glyph_vertex_range: std::ops::Range<usize>,
+    transform_vertex: impl Fn(usize, &Vertex) -> Vertex,
+    out: &mut Mesh,
+    glyphs_out: &mut Mesh,
+) {
+    let out_offset = out.vertices.len();
+    let glyphs_offset = glyphs_out.vertices.len();
+
+    for (i, vertex) in row_mesh.vertices.iter().enumerate() {
+        let vertex = transform_vertex(i, vertex);
+        if glyph_vertex_range.contains(&i) {
+            glyphs_out.vertices.push(vertex);
+        } else {
+            out.vertices.push(vertex);
+        }
+    }
+
+    // The triangles of a glyph only use the vertices of that glyph:
+    for triangle in row_mesh.indices.chunks_exact(3) {
+        let first = triangle[0] as usize;
+        if glyph_vertex_range.contains(&first) {
+            glyphs_out.indices.extend(
+                triangle
+                    .iter()
+                    .map(|&i| (i as usize - glyph_vertex_range.start + glyphs_offset) as u32),
+            );
+        } else {
+            out.indices.extend(triangle.iter().map(|&i| {
+                let i = i as usize;
+                let i = if i < glyph_vertex_range.start {
+                    i
+                } else {
+                    i - glyph_vertex_range.len()
+                };
+                (i + out_offset) as u32
+            }));
+        }
+    }
+}
+
+/// Below this many shapes per thread, it is not worth splitting the work into chunks.
+#[cfg(feature = "rayon")]
+const MIN_SHAPES_PER_CHUNK: usize = 256;
+
+/// Join the primitives of consecutive chunks of shapes,
+/// merging meshes at the boundaries like [`Tessellator::tessellate_clipped_shape`] would have.
+#[cfg(any(feature = "rayon", test))]
+fn concat_clipped_primitives(
+    chunks: impl IntoIterator<Item = Vec<ClippedPrimitive>>,
+) -> Vec<ClippedPrimitive> {
+    let mut result: Vec<ClippedPrimitive> = Vec::default();
+    for chunk in chunks {
+        let mut chunk = chunk.into_iter();
+        if let Some(first) = chunk.next() {
+            match (result.last_mut(), first) {
+                (
+                    Some(ClippedPrimitive {
+                        clip_rect,
+                        primitive: Primitive::Mesh(last_mesh),
+                    }),
+                    ClippedPrimitive {
+                        clip_rect: first_clip_rect,
+                        primitive: Primitive::Mesh(first_mesh),
+                    },
+                ) if *clip_rect == first_clip_rect
+                    && last_mesh.texture_id == first_mesh.texture_id =>
+                {
+                    last_mesh.append(first_mesh);
+                }
+                (_, first) => result.push(first),
+            }
+        }
+        result.extend(chunk);
+    }
+    result
+}
+
 #[deprecated = "Use `Tessellator::new(…).tessellate_shapes(…)` instead"]
 pub fn tessellate_shapes(
     pixels_per_point: f32,
@@ -1908,23 +2546,13 @@ impl Tessellator {
     ///
     /// ## Returns
     /// A list of clip rectangles with matching [`Mesh`].
-    #[allow(unused_mut)]
-    pub fn tessellate_shapes(&mut self, mut shapes: Vec<ClippedShape>) -> Vec<ClippedPrimitive> {
+    pub fn tessellate_shapes(&mut self, shapes: Vec<ClippedShape>) -> Vec<ClippedPrimitive> {
         crate::profile_function!();
 
-        #[cfg(feature = "rayon")]
-        if self.options.parallel_tessellation {
-            self.parallel_tessellation_of_large_shapes(&mut shapes);
-        }
-
-        let mut clipped_primitives: Vec<ClippedPrimitive> = Vec::default();
-
-        {
+        let mut clipped_primitives = {
             crate::profile_scope!("tessellate");
-            for clipped_shape in shapes {
-                self.tessellate_clipped_shape(clipped_shape, &mut clipped_primitives);
-            }
-        }
+            self.tessellate_clipped_shapes(shapes)
+        };
 
         if self.options.debug_paint_clip_rects {
             clipped_primitives = self.add_clip_rects(clipped_primitives);
@@ -1940,6 +2568,7 @@ impl Tessellator {
             p.clip_rect.is_positive()
                 && match &p.primitive {
                     Primitive::Mesh(mesh) => !mesh.is_empty(),
+                    Primitive::PrecomputedMesh(primitive) => !primitive.mesh.is_empty(),
                     Primitive::Callback(_) => true,
                 }
         });
@@ -1953,6 +2582,69 @@ impl Tessellator {
         clipped_primitives
     }
 
+    #[allow(unused_mut)]
+    fn tessellate_clipped_shapes(
+        &mut self,
+        mut shapes: Vec<ClippedShape>,
+    ) -> Vec<ClippedPrimitive> {
+        #[cfg(feature = "rayon")]
+        if self.options.parallel_tessellation {
+            let chunk_size = shapes
+                .len()
+                .div_ceil(rayon::current_num_threads())
+                .max(MIN_SHAPES_PER_CHUNK);
+            if chunk_size < shapes.len() {
+                return self.parallel_tessellation_in_chunks(shapes, chunk_size);
+            }
+
+            self.parallel_tessellation_of_large_shapes(&mut shapes);
+        }
+
+        let mut clipped_primitives = Vec::default();
+        for clipped_shape in shapes {
+            self.tessellate_clipped_shape(clipped_shape, &mut clipped_primitives);
+        }
+        clipped_primitives
+    }
+
+    /// Split the shapes into chunks, tessellate them on the rayon thread pool,
+    /// and then put the results back together in the original order.
+    #[cfg(feature = "rayon")]
+    fn parallel_tessellation_in_chunks(
+        &self,
+        shapes: Vec<ClippedShape>,
+        chunk_size: usize,
+    ) -> Vec<ClippedPrimitive> {
+        crate::profile_function!();
+
+        use rayon::prelude::*;
+
+        let mut chunks = Vec::with_capacity(shapes.len().div_ceil(chunk_size));
+        let mut shapes = shapes.into_iter();
+        loop {
+            let chunk: Vec<ClippedShape> = shapes.by_ref().take(chunk_size).collect();
+            if chunk.is_empty() {
+                break;
+            }
+            chunks.push(chunk);
+        }
+
+        let tessellated: Vec<Vec<ClippedPrimitive>> = chunks
+            .into_par_iter()
+            .map(|chunk| {
+                crate::profile_scope!("tessellate_chunk");
+                let mut tessellator = self.clone();
+                let mut clipped_primitives = Vec::default();
+                for clipped_shape in chunk {
+                    tessellator.tessellate_clipped_shape(clipped_shape, &mut clipped_primitives);
+                }
+                clipped_primitives
+            })
+            .collect();
+
+        concat_clipped_primitives(tessellated)
+    }
+
     /// Find large shapes and throw them on the rayon thread pool,
     /// then replace the original shape with their tessellated meshes.
     #[cfg(feature = "rayon")]
@@ -1970,12 +2662,19 @@ impl Tessellator {
 
                 Shape::Path(path_shape) => 32 < path_shape.points.len(),
 
-                Shape::QuadraticBezier(_) | Shape::CubicBezier(_) | Shape::Ellipse(_) => true,
+                Shape::QuadraticBezier(_)
+                | Shape::CubicBezier(_)
+                | Shape::CatmullRom(_)
+                | Shape::SvgPath(_)
+                | Shape::Ellipse(_) => true,
 
                 Shape::Noop
                 | Shape::Text(_)
                 | Shape::Circle(_)
+                | Shape::Arc(_)
+                | Shape::Pie(_)
                 | Shape::Mesh(_)
+                | Shape::PrecomputedMesh(..)
                 | Shape::LineSegment { .. }
                 | Shape::Rect(_)
                 | Shape::Callback(_) => false,
@@ -2062,6 +2761,63 @@ fn test_tessellator() {
     assert_eq!(primitives.len(), 2);
 }
 
+#[test]
+fn test_tessellate_pie() {
+    use crate::*;
+    use std::f32::consts::TAU;
+
+    let tessellator = || {
+        let options = TessellationOptions {
+            feathering: false,
+            coarse_tessellation_culling: false,
+            ..Default::default()
+        };
+        Tessellator::new(1.0, options, [1024, 1024], vec![])
+    };
+    let mesh_area = |mesh: &Mesh| -> f32 {
+        mesh.indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[t[i] as usize].pos);
+                0.5 * ((b - a).x * (c - a).y - (b - a).y * (c - a).x).abs()
+            })
+            .sum()
+    };
+
+    // Sectors wider than half a circle aren't convex, but must still be filled correctly,
+    // and the direction of the angles shouldn't matter:
+    let radius = 50.0;
+    for (start_angle, end_angle) in [(0.0, 0.25 * TAU), (0.1, 0.9 * TAU), (0.9 * TAU, 0.1)] {
+        let mut mesh = Mesh::default();
+        tessellator().tessellate_pie(
+            PieShape::filled(pos2(0.0, 0.0), radius, start_angle, end_angle, Color32::RED),
+            &mut mesh,
+        );
+        let expected_area = 0.5 * radius * radius * (end_angle - start_angle).abs();
+        let area = mesh_area(&mesh);
+        assert!(
+            (area - expected_area).abs() < 0.01 * expected_area,
+            "Expected area {expected_area}, got {area}"
+        );
+    }
+
+    // The arc should stay close to the circle:
+    let mut mesh = Mesh::default();
+    tessellator().tessellate_arc(
+        ArcShape::new(pos2(0.0, 0.0), radius, -1.0, 2.0, (2.0, Color32::RED)),
+        &mut mesh,
+    );
+    assert!(!mesh.is_empty());
+    for v in &mesh.vertices {
+        let distance = v.pos.to_vec2().length();
+        assert!(
+            (distance - radius).abs() <= 1.0 + 0.1,
+            "vertex {:?} at {distance} from the center",
+            v.pos
+        );
+    }
+}
+
 #[test]
 fn path_bounding_box() {
     use crate::*;
@@ -2106,3 +2862,249 @@ fn path_bounding_box() {
         );
     }
 }
+
+#[test]
+fn test_tessellate_rotated_rect() {
+    use crate::*;
+    use std::f32::consts::TAU;
+
+    let options = TessellationOptions {
+        feathering: false,
+        ..Default::default()
+    };
+    let mut tessellator = Tessellator::new(1.0, options, [1024, 1024], vec![]);
+
+    // Rotating a 40x10 rect a quarter turn around its center should make it 10x40:
+    let rect = Rect::from_center_size(pos2(100.0, 100.0), vec2(40.0, 10.0));
+    let mut shape = Shape::rect_filled(rect, 0.0, Color32::RED);
+    shape.transform(emath::Affine2::from_rotation_around(
+        emath::Rot2::from_angle(0.25 * TAU),
+        rect.center(),
+    ));
+    let Shape::Rect(rect_shape) = &shape else {
+        panic!("Expected a rect");
+    };
+    assert!((rect_shape.rect.center() - rect.center()).length() < 1e-3);
+    assert!((rect_shape.rect.size() - rect.size()).length() < 1e-3);
+    assert!((rect_shape.angle - 0.25 * TAU).abs() < 1e-6);
+
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_rect(rect_shape, &mut mesh);
+    let bounds = mesh.calc_bounds();
+    assert!(
+        (bounds.size() - vec2(10.0, 40.0)).length() < 1e-3,
+        "{bounds:?}"
+    );
+    assert!((bounds.center() - rect.center()).length() < 1e-3);
+}
+
+#[test]
+fn test_concat_clipped_primitives() {
+    use crate::*;
+
+    let shapes: Vec<ClippedShape> = (0..10)
+        .map(|i| {
+            let clip_rect = if i < 6 {
+                Rect::EVERYTHING
+            } else {
+                Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0))
+            };
+            let shape = Shape::circle_filled(pos2(10.0 * i as f32, 10.0), 5.0, Color32::RED);
+            ClippedShape { clip_rect, shape }
+        })
+        .collect();
+
+    let tessellate = |shapes: &[ClippedShape]| {
+        let mut tessellator = Tessellator::new(1.0, Default::default(), [1024, 1024], vec![]);
+        let mut clipped_primitives = vec![];
+        for clipped_shape in shapes {
+            tessellator.tessellate_clipped_shape(clipped_shape.clone(), &mut clipped_primitives);
+        }
+        clipped_primitives
+    };
+
+    let whole = tessellate(&shapes);
+    assert_eq!(whole.len(), 2);
+
+    for chunk_size in [1, 3, 4, 6] {
+        let chunked = concat_clipped_primitives(shapes.chunks(chunk_size).map(tessellate));
+        assert_eq!(chunked.len(), whole.len(), "chunk_size: {chunk_size}");
+        for (a, b) in chunked.iter().zip(&whole) {
+            assert_eq!(a.clip_rect, b.clip_rect);
+            let (Primitive::Mesh(a), Primitive::Mesh(b)) = (&a.primitive, &b.primitive) else {
+                panic!("Expected meshes");
+            };
+            assert_eq!(a.vertices.len(), b.vertices.len());
+            assert_eq!(a.indices, b.indices);
+        }
+    }
+}
+
+#[test]
+fn test_tessellate_svg_path() {
+    use crate::*;
+
+    let mut tessellator = Tessellator::new(
+        1.0,
+        TessellationOptions {
+            feathering: false,
+            ..Default::default()
+        },
+        [1024, 1024],
+        vec![],
+    );
+    let mut fill_area = |d: &str, fill_rule: FillRule| -> f32 {
+        let shape = SvgPathShape::new(PathData::parse(d).unwrap(), Color32::RED, Stroke::NONE)
+            .with_fill_rule(fill_rule);
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_svg_path(&shape, &mut mesh);
+        mesh.indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[t[i] as usize].pos);
+                0.5 * ((b - a).x * (c - a).y - (b - a).y * (c - a).x).abs()
+            })
+            .sum()
+    };
+
+    // A square with a square hole, both clockwise:
+    let square_with_hole = "M0 0H30V30H0Z M10 10H20V20H10Z";
+    assert_eq!(fill_area(square_with_hole, FillRule::EvenOdd), 800.0);
+    assert_eq!(fill_area(square_with_hole, FillRule::NonZero), 900.0);
+
+    // With the hole counter-clockwise, the non-zero rule leaves it out too:
+    let square_with_ccw_hole = "M0 0H30V30H0Z M10 10V20H20V10Z";
+    assert_eq!(fill_area(square_with_ccw_hole, FillRule::NonZero), 800.0);
+
+    // A self-intersecting bow tie is two triangles:
+    let bow_tie = "M0 0L20 20V0L0 20Z";
+    assert!((fill_area(bow_tie, FillRule::NonZero) - 200.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_precomputed_mesh_is_passed_on_and_culled() {
+    use crate::*;
+
+    let mesh_mngr = Arc::new(mutex::RwLock::new(MeshManager::default()));
+    let mut mesh = Mesh::default();
+    mesh.add_colored_rect(
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0)),
+        Color32::RED,
+    );
+    let handle = MeshHandle::new(mesh_mngr.clone(), mesh_mngr.write().alloc(mesh));
+
+    let shape = handle.shape(TSTransform::new(vec2(100.0, 0.0), 2.0));
+    assert_eq!(
+        shape.visual_bounding_rect(),
+        Rect::from_min_max(pos2(100.0, 0.0), pos2(120.0, 20.0))
+    );
+
+    let tessellate = |clip_rect: Rect| {
+        let mut tessellator = Tessellator::new(1.0, Default::default(), [1024, 1024], vec![])
+            .with_mesh_manager(mesh_mngr.clone());
+        let mut clipped_primitives = vec![];
+        tessellator.tessellate_clipped_shape(
+            ClippedShape {
+                clip_rect,
+                shape: shape.clone(),
+            },
+            &mut clipped_primitives,
+        );
+        clipped_primitives
+    };
+
+    let visible = tessellate(Rect::from_min_max(pos2(110.0, 0.0), pos2(200.0, 100.0)));
+    assert_eq!(visible.len(), 1);
+    let Primitive::PrecomputedMesh(primitive) = &visible[0].primitive else {
+        panic!("Expected a precomputed mesh");
+    };
+    assert_eq!(primitive.id, handle.id());
+    assert!(Arc::ptr_eq(
+        &primitive.mesh,
+        mesh_mngr.read().get(handle.id()).unwrap()
+    ));
+
+    let culled = tessellate(Rect::from_min_max(pos2(0.0, 0.0), pos2(90.0, 100.0)));
+    assert!(culled.is_empty());
+}
+
+#[test]
+fn test_subpixel_glyphs_get_a_mesh_of_their_own() {
+    use crate::text::{Fonts, GlyphRasterization, LayoutJob, SubpixelOrder, TextFormat};
+    use crate::*;
+
+    let fonts = Fonts::new_with_glyph_rasterization(
+        1.0,
+        1024,
+        Default::default(),
+        GlyphRasterization::Subpixel(SubpixelOrder::Rgb),
+    );
+    let mut job = LayoutJob::default();
+    job.append(
+        "Hello",
+        0.0,
+        TextFormat {
+            background: Color32::YELLOW,
+            ..Default::default()
+        },
+    );
+    let galley = fonts.layout_job(job);
+    let num_glyph_vertices: usize = galley
+        .rows
+        .iter()
+        .map(|row| row.visuals.glyph_vertex_range.len())
+        .sum();
+    assert!(0 < num_glyph_vertices);
+
+    let clip_rect = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+    let text = Shape::galley(Pos2::ZERO, galley, Color32::BLACK);
+    let shapes = vec![
+        ClippedShape {
+            clip_rect,
+            shape: text.clone(),
+        },
+        ClippedShape {
+            clip_rect,
+            shape: text.clone(),
+        },
+    ];
+
+    let glyph_texture = TextureId::Managed(1);
+    let primitives = Tessellator::new(1.0, Default::default(), fonts.font_image_size(), vec![])
+        .with_subpixel_glyph_texture(glyph_texture)
+        .tessellate_shapes(shapes);
+
+    let meshes: Vec<&Mesh> = primitives
+        .iter()
+        .map(|primitive| match &primitive.primitive {
+            Primitive::Mesh(mesh) => mesh,
+            _ => panic!("Expected a mesh"),
+        })
+        .collect();
+    let texture_ids: Vec<TextureId> = meshes.iter().map(|mesh| mesh.texture_id).collect();
+    assert_eq!(
+        texture_ids,
+        [
+            TextureId::default(),
+            glyph_texture,
+            TextureId::default(),
+            glyph_texture
+        ],
+        "The background of the second text is painted on top of the glyphs of the first"
+    );
+    assert!(meshes.iter().all(|mesh| mesh.is_valid()));
+    assert_eq!(meshes[1].vertices.len(), num_glyph_vertices);
+    assert_eq!(
+        meshes[0].vertices.len(),
+        4,
+        "Only the background rectangle (without feathering)"
+    );
+
+    // A single mesh can only have one texture, so it gets the glyphs too:
+    let mut mesh = Mesh::default();
+    Tessellator::new(1.0, Default::default(), fonts.font_image_size(), vec![])
+        .with_subpixel_glyph_texture(glyph_texture)
+        .tessellate_shape(text, &mut mesh);
+    assert_eq!(mesh.texture_id, TextureId::default());
+    assert_eq!(mesh.vertices.len(), 4 + num_glyph_vertices);
+}
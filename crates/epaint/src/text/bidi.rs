@@ -0,0 +1,379 @@
+//! Bidirectional text, following the [Unicode Bidirectional Algorithm](https://www.unicode.org/reports/tr9/) (UAX #9).
+//!
+//! This is a simplified implementation:
+//! * The bidi class of each character is approximated with a small table of the most common scripts.
+//! * Explicit embeddings, overrides and isolates (e.g. `U+202B`) are ignored.
+//! * Bracket pairs (rule N0) are not treated specially.
+//!
+//! This is enough to correctly lay out mixed Hebrew, Arabic and Latin text, including numbers.
+
+use super::BaseDirection;
+
+/// The bidirectional character type of a character.
+#[allow(clippy::upper_case_acronyms)] // Same names as in the Unicode standard
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BidiClass {
+    /// Left-to-right, e.g. Latin letters.
+    L,
+
+    /// Right-to-left, e.g. Hebrew letters.
+    R,
+
+    /// Arabic letter.
+    AL,
+
+    /// European number, e.g. `0-9`.
+    EN,
+
+    /// European separator, e.g. `+`.
+    ES,
+
+    /// European terminator, e.g. `%`.
+    ET,
+
+    /// Arabic number.
+    AN,
+
+    /// Common separator, e.g. `,`.
+    CS,
+
+    /// Non-spacing mark, e.g. combining accents.
+    NSM,
+
+    /// Boundary neutral, e.g. zero-width joiners.
+    BN,
+
+    /// Segment separator, i.e. tab.
+    S,
+
+    /// Whitespace.
+    WS,
+
+    /// Other neutrals, e.g. punctuation and emojis.
+    ON,
+}
+
+#[allow(clippy::match_same_arms)] // one arm per script or group of characters
+fn bidi_class(c: char) -> BidiClass {
+    use BidiClass::{AL, AN, BN, CS, EN, ES, ET, L, NSM, ON, R, S, WS};
+
+    match c {
+        '0'..='9' | '\u{B2}' | '\u{B3}' | '\u{B9}' | '\u{6F0}'..='\u{6F9}' => EN,
+        '\u{2070}'..='\u{2079}' | '\u{2080}'..='\u{2089}' | '\u{FF10}'..='\u{FF19}' => EN,
+        '+' | '-' | '\u{207A}' | '\u{207B}' | '\u{208A}' | '\u{208B}' | '\u{FB29}' => ES,
+        '\u{FE62}' | '\u{FE63}' | '\u{FF0B}' | '\u{FF0D}' => ES,
+        '#' | '$' | '%' | '\u{A2}'..='\u{A5}' | '\u{B0}' | '\u{B1}' | '\u{609}' | '\u{60A}' => ET,
+        '\u{66A}' | '\u{2030}'..='\u{2034}' | '\u{20A0}'..='\u{20CF}' | '\u{212E}' => ET,
+        '\u{2213}' | '\u{FE5F}' | '\u{FE69}' | '\u{FE6A}' | '\u{FF03}'..='\u{FF05}' => ET,
+        ',' | '.' | '/' | ':' | '\u{A0}' | '\u{60C}' | '\u{202F}' | '\u{2044}' => CS,
+        '\u{FE50}' | '\u{FE52}' | '\u{FE55}' | '\u{FF0C}' | '\u{FF0E}' | '\u{FF0F}' => CS,
+        '\u{FF1A}' => CS,
+        '\t' | '\u{B}' | '\u{1F}' => S,
+        ' ' | '\u{C}' | '\u{1680}' | '\u{2000}'..='\u{200A}' | '\u{2028}' | '\u{205F}' => WS,
+        '\u{3000}' => WS,
+        '\u{0}'..='\u{8}' | '\u{E}'..='\u{1B}' | '\u{7F}'..='\u{9F}' | '\u{AD}' => BN,
+        '\u{200B}'..='\u{200D}' | '\u{2060}'..='\u{2064}' | '\u{FEFF}' => BN,
+        '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}' => BN, // explicit formatting, which we ignore
+        '\u{200E}' => L,                                         // LEFT-TO-RIGHT MARK
+        '\u{200F}' => R,                                         // RIGHT-TO-LEFT MARK
+        '\u{61C}' => AL,                                         // ARABIC LETTER MARK
+        '\u{300}'..='\u{36F}' | '\u{483}'..='\u{489}' | '\u{20D0}'..='\u{20FF}' => NSM,
+        '\u{FE00}'..='\u{FE0F}' | '\u{FE20}'..='\u{FE2F}' => NSM,
+
+        // Hebrew:
+        '\u{591}'..='\u{5BD}' | '\u{5BF}' | '\u{5C1}' | '\u{5C2}' | '\u{5C4}' | '\u{5C5}' => NSM,
+        '\u{5C7}' | '\u{FB1E}' => NSM,
+        '\u{590}'..='\u{5FF}' | '\u{FB1D}'..='\u{FB4F}' => R,
+
+        // Arabic:
+        '\u{600}'..='\u{605}' | '\u{660}'..='\u{669}' | '\u{66B}' | '\u{66C}' | '\u{6DD}' => AN,
+        '\u{610}'..='\u{61A}' | '\u{64B}'..='\u{65F}' | '\u{670}' | '\u{6D6}'..='\u{6DC}' => NSM,
+        '\u{6DF}'..='\u{6E4}' | '\u{6E7}' | '\u{6E8}' | '\u{6EA}'..='\u{6ED}' => NSM,
+        '\u{7A6}'..='\u{7B0}' | '\u{8D3}'..='\u{8E1}' | '\u{8E3}'..='\u{8FF}' => NSM,
+        '\u{600}'..='\u{7BF}' | '\u{860}'..='\u{8FF}' => AL, // Arabic, Syriac, Thaana
+        '\u{FB50}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFE}' => AL,
+        '\u{1EE00}'..='\u{1EEFF}' => AL,
+
+        // NKo, Samaritan, Mandaic, and various historic scripts:
+        '\u{7C0}'..='\u{85F}' | '\u{10800}'..='\u{10FFF}' | '\u{1E800}'..='\u{1EDFF}' => R,
+        '\u{1EF00}'..='\u{1EFFF}' => R,
+
+        _ => {
+            if c.is_alphanumeric() {
+                L
+            } else {
+                ON
+            }
+        }
+    }
+}
+
+/// Does this text contain any characters that are right-to-left?
+///
+/// If not, and the base direction is left-to-right, all levels are zero.
+pub(crate) fn has_rtl(text: impl IntoIterator<Item = char>) -> bool {
+    text.into_iter()
+        .any(|c| matches!(bidi_class(c), BidiClass::R | BidiClass::AL | BidiClass::AN))
+}
+
+/// Resolve the embedding level of each character of a paragraph.
+///
+/// Even levels are left-to-right, odd levels are right-to-left.
+pub(crate) fn resolve_levels(chars: &[char], base_direction: BaseDirection) -> Vec<u8> {
+    use BidiClass::{AL, AN, BN, CS, EN, ES, ET, L, NSM, ON, R, S, WS};
+
+    let mut classes: Vec<BidiClass> = chars.iter().copied().map(bidi_class).collect();
+
+    let paragraph_level: u8 = match base_direction {
+        BaseDirection::LeftToRight => 0,
+        BaseDirection::RightToLeft => 1,
+        BaseDirection::Auto => classes
+            .iter()
+            .find_map(|class| match class {
+                L => Some(0),
+                R | AL => Some(1),
+                _ => None,
+            })
+            .unwrap_or(0),
+    };
+    let embedding_direction = if paragraph_level % 2 == 0 { L } else { R };
+    let sos = embedding_direction;
+    let eos = embedding_direction;
+
+    // W1: non-spacing marks (and boundary neutrals) get the type of the previous character:
+    let mut prev = sos;
+    for class in &mut classes {
+        if matches!(class, NSM | BN) {
+            *class = prev;
+        }
+        prev = *class;
+    }
+
+    // W2: European numbers after Arabic letters are Arabic numbers:
+    let mut last_strong = sos;
+    for class in &mut classes {
+        match *class {
+            L | R | AL => last_strong = *class,
+            EN if last_strong == AL => *class = AN,
+            _ => {}
+        }
+    }
+
+    // W3:
+    for class in &mut classes {
+        if *class == AL {
+            *class = R;
+        }
+    }
+
+    // W4: a single separator between two numbers of the same type becomes that type:
+    for i in 1..classes.len().saturating_sub(1) {
+        let (before, class, after) = (classes[i - 1], classes[i], classes[i + 1]);
+        match class {
+            ES if before == EN && after == EN => classes[i] = EN,
+            CS if before == after && matches!(before, EN | AN) => classes[i] = before,
+            _ => {}
+        }
+    }
+
+    // W5: terminators next to European numbers become European numbers:
+    let mut i = 0;
+    while i < classes.len() {
+        if classes[i] == ET {
+            let start = i;
+            while i < classes.len() && classes[i] == ET {
+                i += 1;
+            }
+            let next_to_number =
+                (0 < start && classes[start - 1] == EN) || classes.get(i) == Some(&EN);
+            if next_to_number {
+                classes[start..i].fill(EN);
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    // W6: remaining separators and terminators are neutral:
+    for class in &mut classes {
+        if matches!(class, ES | ET | CS) {
+            *class = ON;
+        }
+    }
+
+    // W7: European numbers in left-to-right context are left-to-right:
+    let mut last_strong = sos;
+    for class in &mut classes {
+        match *class {
+            L | R => last_strong = *class,
+            EN if last_strong == L => *class = L,
+            _ => {}
+        }
+    }
+
+    // N1, N2: neutrals take the direction of the surrounding text,
+    // or the embedding direction if it differs on either side:
+    let strong_direction = |class: BidiClass| match class {
+        L => Some(L),
+        R | EN | AN => Some(R),
+        _ => None,
+    };
+    let mut i = 0;
+    while i < classes.len() {
+        if strong_direction(classes[i]).is_none() {
+            let start = i;
+            while i < classes.len() && strong_direction(classes[i]).is_none() {
+                i += 1;
+            }
+            let before = if start == 0 {
+                sos
+            } else {
+                strong_direction(classes[start - 1]).unwrap_or(sos)
+            };
+            let after = classes
+                .get(i)
+                .map_or(eos, |&class| strong_direction(class).unwrap_or(eos));
+            let direction = if before == after {
+                before
+            } else {
+                embedding_direction
+            };
+            classes[start..i].fill(direction);
+        } else {
+            i += 1;
+        }
+    }
+
+    // I1, I2: implicit levels:
+    let mut levels: Vec<u8> = classes
+        .iter()
+        .map(|class| match (paragraph_level % 2 == 0, class) {
+            (true, R) | (false, L | EN | AN) => paragraph_level + 1,
+            (true, AN | EN) => paragraph_level + 2,
+            _ => paragraph_level,
+        })
+        .collect();
+
+    // L1: segment separators, and whitespace before them and at the end of the paragraph,
+    // get the paragraph level:
+    let mut at_end = true;
+    for (level, &c) in levels.iter_mut().zip(chars).rev() {
+        match bidi_class(c) {
+            S => {
+                *level = paragraph_level;
+                at_end = true;
+            }
+            WS | BN if at_end => *level = paragraph_level,
+            _ => at_end = false,
+        }
+    }
+
+    levels
+}
+
+/// The visual order of characters with the given embedding levels, from left to right.
+///
+/// Returns the logical index of each character, in visual order.
+pub(crate) fn visual_order(levels: &[u8]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+
+    let (Some(&min_level), Some(&max_level)) = (levels.iter().min(), levels.iter().max()) else {
+        return order;
+    };
+    let lowest_odd_level = min_level | 1;
+
+    // L2: from the highest level down to the lowest odd level,
+    // reverse any run of characters at that level or higher:
+    for level in (lowest_odd_level..=max_level).rev() {
+        let mut i = 0;
+        while i < order.len() {
+            if levels[order[i]] >= level {
+                let start = i;
+                while i < order.len() && levels[order[i]] >= level {
+                    i += 1;
+                }
+                order[start..i].reverse();
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    order
+}
+
+/// The mirrored version of a character, for characters like brackets
+/// that should be mirrored in right-to-left text.
+pub(crate) fn mirrored(c: char) -> Option<char> {
+    Some(match c {
+        '(' => ')',
+        ')' => '(',
+        '<' => '>',
+        '>' => '<',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '«' => '»',
+        '»' => '«',
+        '‹' => '›',
+        '›' => '‹',
+        '≤' => '≥',
+        '≥' => '≤',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reorder the text for display, with the given base direction.
+    fn visual(text: &str, base_direction: BaseDirection) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let levels = resolve_levels(&chars, base_direction);
+        visual_order(&levels)
+            .into_iter()
+            .map(|i| chars[i])
+            .collect()
+    }
+
+    #[test]
+    fn test_left_to_right() {
+        assert_eq!(visual("Hello world!", BaseDirection::Auto), "Hello world!");
+        assert!(resolve_levels(&['a', ' ', '1'], BaseDirection::Auto)
+            .iter()
+            .all(|&level| level == 0));
+    }
+
+    #[test]
+    fn test_mixed_direction() {
+        // Hebrew inside of English:
+        assert_eq!(visual("abc אבג def", BaseDirection::Auto), "abc גבא def");
+
+        // English inside of Hebrew:
+        assert_eq!(visual("אבג abc דהו", BaseDirection::Auto), "והד abc גבא");
+
+        // Numbers are still left-to-right:
+        assert_eq!(visual("אבג 123 דהו", BaseDirection::Auto), "והד 123 גבא");
+        assert_eq!(visual("אבג 1.5%", BaseDirection::Auto), "1.5% גבא");
+
+        // Arabic:
+        assert_eq!(visual("abc سلام", BaseDirection::Auto), "abc مالس");
+    }
+
+    #[test]
+    fn test_base_direction() {
+        // Auto picks the direction of the first strong character:
+        assert_eq!(visual("abc אבג!", BaseDirection::Auto), "abc גבא!");
+        assert_eq!(visual("abc אבג!", BaseDirection::RightToLeft), "!גבא abc");
+        assert_eq!(visual("אבג abc!", BaseDirection::LeftToRight), "גבא abc!");
+    }
+
+    #[test]
+    fn test_trailing_whitespace() {
+        let chars: Vec<char> = "אב  ".chars().collect();
+        let levels = resolve_levels(&chars, BaseDirection::LeftToRight);
+        assert_eq!(levels, [1, 1, 0, 0]);
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     text::FontTweak,
     TextureAtlas,
 };
-use emath::{vec2, Vec2};
+use emath::{pos2, vec2, Pos2, Rect, Vec2};
 use std::collections::BTreeSet;
 use std::sync::Arc;
 
@@ -30,6 +30,57 @@ impl UvRect {
     pub fn is_nothing(&self) -> bool {
         self.min == self.max
     }
+
+    /// Where to paint the glyph on screen (in points), given the position of its baseline.
+    ///
+    /// This is the left side of the glyph on the baseline, e.g. [`super::Glyph::pos`].
+    #[inline]
+    pub fn screen_rect(&self, baseline_pos: Pos2) -> Rect {
+        Rect::from_min_size(baseline_pos + self.offset, self.size)
+    }
+
+    /// The texture coordinates in the `0-1` range, given the size of the font atlas in texels.
+    ///
+    /// See [`super::Fonts::font_image_size`].
+    #[inline]
+    pub fn uv(&self, atlas_size: [usize; 2]) -> Rect {
+        let [w, h] = atlas_size;
+        let (inv_w, inv_h) = (1.0 / w as f32, 1.0 / h as f32);
+        Rect::from_min_max(
+            pos2(self.min[0] as f32 * inv_w, self.min[1] as f32 * inv_h),
+            pos2(self.max[0] as f32 * inv_w, self.max[1] as f32 * inv_h),
+        )
+    }
+}
+
+/// Everything needed to paint a single glyph from the font atlas yourself,
+/// e.g. in a game engine that wants to share the fonts of egui.
+///
+/// Get it with [`super::Fonts::glyph_metrics`].
+///
+/// The glyph is painted from the font atlas, which is [`crate::TextureId::default`].
+/// Calling [`super::Fonts::glyph_metrics`] may add the glyph to the atlas,
+/// so make sure to upload [`super::Fonts::font_image_delta`] (or the full [`super::Fonts::image`])
+/// before painting it.
+///
+/// For laying out whole strings (with kerning, wrapping etc), use [`super::Fonts::layout_job`]
+/// and paint the [`super::Glyph`]s of the resulting [`super::Galley`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GlyphMetrics {
+    /// How far to move right after this glyph. Unit: points.
+    pub advance_width: f32,
+
+    /// Distance from the top of the row to the baseline. Unit: points.
+    ///
+    /// This comes from the font that actually has the glyph, which may be a fallback font.
+    pub ascent: f32,
+
+    /// Height of one row of text. Unit: points.
+    pub row_height: f32,
+
+    /// Where in the font atlas the glyph is,
+    /// and where to paint it relative to the baseline.
+    pub uv_rect: UvRect,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -78,6 +129,16 @@ pub struct FontImpl {
     pixels_per_point: f32,
     glyph_info_cache: RwLock<ahash::HashMap<char, GlyphInfo>>, // TODO(emilk): standard Mutex
     atlas: Arc<Mutex<TextureAtlas>>,
+
+    /// Set with [`Self::with_shaping`].
+    #[cfg(feature = "rustybuzz")]
+    shaping_font: Option<Arc<super::shaping::ShapingFont>>,
+
+    /// Glyphs by id, which may not correspond to any single character.
+    ///
+    /// Shared by shaped and unshaped text, so that each glyph is only put in the atlas once.
+    #[cfg(feature = "rustybuzz")]
+    shaped_glyph_info_cache: RwLock<ahash::HashMap<ab_glyph::GlyphId, GlyphInfo>>,
 }
 
 impl FontImpl {
@@ -132,7 +193,66 @@ impl FontImpl {
             pixels_per_point,
             glyph_info_cache: Default::default(),
             atlas,
+            #[cfg(feature = "rustybuzz")]
+            shaping_font: None,
+            #[cfg(feature = "rustybuzz")]
+            shaped_glyph_info_cache: Default::default(),
+        }
+    }
+
+    /// Shape text with this font before laying it out.
+    ///
+    /// The `shaping_font` must be made from the same font file as this font.
+    #[cfg(feature = "rustybuzz")]
+    pub(crate) fn with_shaping(mut self, shaping_font: Arc<super::shaping::ShapingFont>) -> Self {
+        self.shaping_font = Some(shaping_font);
+        self
+    }
+
+    /// Can [`Self::shape`] be used with this font?
+    #[cfg(feature = "rustybuzz")]
+    pub(crate) fn can_shape(&self) -> bool {
+        self.shaping_font.is_some()
+    }
+
+    /// Shape a run of text, all of which is supported by this font.
+    ///
+    /// Returns no glyphs if [`Self::can_shape`] is `false`.
+    #[cfg(feature = "rustybuzz")]
+    pub(crate) fn shape(
+        &self,
+        text: &str,
+        features: super::FontFeatures,
+    ) -> Vec<super::shaping::ShapedGlyph> {
+        use ab_glyph::{Font as _, ScaleFont};
+
+        let Some(shaping_font) = &self.shaping_font else {
+            return vec![];
+        };
+        let points_per_unit = self
+            .ab_glyph_font
+            .as_scaled(self.scale_in_pixels as f32)
+            .h_scale_factor()
+            / self.pixels_per_point;
+        shaping_font.shape(text, points_per_unit, features)
+    }
+
+    /// Like [`Self::glyph_info`], but for a glyph produced by [`Self::shape`].
+    #[cfg(feature = "rustybuzz")]
+    pub(crate) fn shaped_glyph_info(&self, glyph_id: ab_glyph::GlyphId) -> GlyphInfo {
+        if glyph_id.0 == 0 {
+            return GlyphInfo::default(); // .notdef
         }
+
+        if let Some(glyph_info) = self.shaped_glyph_info_cache.read().get(&glyph_id) {
+            return *glyph_info;
+        }
+
+        let glyph_info = self.allocate_glyph(glyph_id);
+        self.shaped_glyph_info_cache
+            .write()
+            .insert(glyph_id, glyph_info);
+        glyph_info
     }
 
     /// Code points that will always be replaced by the replacement character.
@@ -225,6 +345,9 @@ impl FontImpl {
         if glyph_id.0 == 0 {
             None // unsupported character
         } else {
+            #[cfg(feature = "rustybuzz")]
+            let glyph_info = self.shaped_glyph_info(glyph_id);
+            #[cfg(not(feature = "rustybuzz"))]
             let glyph_info = self.allocate_glyph(glyph_id);
             self.glyph_info_cache.write().insert(c, glyph_info);
             Some(glyph_info)
@@ -244,6 +367,16 @@ impl FontImpl {
             / self.pixels_per_point
     }
 
+    /// The advance width of the widest of the digits `0-9`, in points.
+    ///
+    /// Used to emulate tabular figures when the font isn't shaped.
+    pub(crate) fn tabular_figure_width(&self) -> f32 {
+        ('0'..='9')
+            .filter_map(|digit| self.glyph_info(digit))
+            .map(|glyph_info| glyph_info.advance_width)
+            .fold(0.0, f32::max)
+    }
+
     /// Height of one row of text in points.
     #[inline(always)]
     pub fn row_height(&self) -> f32 {
@@ -265,7 +398,13 @@ impl FontImpl {
 
     fn allocate_glyph(&self, glyph_id: ab_glyph::GlyphId) -> GlyphInfo {
         assert!(glyph_id.0 != 0);
-        use ab_glyph::{Font as _, ScaleFont};
+        use ab_glyph::Font as _;
+
+        let rasterization = self.atlas.lock().glyph_rasterization();
+        if let super::GlyphRasterization::Subpixel(order) = rasterization {
+            let uv_rect = self.allocate_subpixel_glyph(glyph_id, order);
+            return self.glyph_info_with_uv(glyph_id, uv_rect.unwrap_or_default());
+        }
 
         let glyph = glyph_id.with_scale_and_position(
             self.scale_in_pixels as f32,
@@ -279,9 +418,30 @@ impl FontImpl {
             if glyph_width == 0 || glyph_height == 0 {
                 UvRect::default()
             } else {
-                let glyph_pos = {
-                    let atlas = &mut self.atlas.lock();
-                    let (glyph_pos, image) = atlas.allocate((glyph_width, glyph_height));
+                let atlas = &mut self.atlas.lock();
+
+                let sdf =
+                    atlas.glyph_rasterization() == super::GlyphRasterization::SignedDistanceField;
+                // Leave room for the distance field outside the glyph:
+                let padding = if sdf { super::sdf::SDF_SPREAD } else { 0 };
+
+                let glyph_width = glyph_width + 2 * padding;
+                let glyph_height = glyph_height + 2 * padding;
+
+                let (glyph_pos, image) = atlas.allocate((glyph_width, glyph_height));
+                if sdf {
+                    let mut coverage = vec![0.0; glyph_width * glyph_height];
+                    glyph.draw(|x, y, v| {
+                        coverage[(y as usize + padding) * glyph_width + x as usize + padding] = v;
+                    });
+                    let distances =
+                        super::sdf::sdf_from_coverage(&coverage, glyph_width, glyph_height);
+                    for (i, distance) in distances.into_iter().enumerate() {
+                        let px = glyph_pos.0 + i % glyph_width;
+                        let py = glyph_pos.1 + i / glyph_width;
+                        image[(px, py)] = distance;
+                    }
+                } else {
                     glyph.draw(|x, y, v| {
                         if 0.0 < v {
                             let px = glyph_pos.0 + x as usize;
@@ -289,10 +449,9 @@ impl FontImpl {
                             image[(px, py)] = v;
                         }
                     });
-                    glyph_pos
-                };
+                }
 
-                let offset_in_pixels = vec2(bb.min.x, bb.min.y);
+                let offset_in_pixels = vec2(bb.min.x, bb.min.y) - Vec2::splat(padding as f32);
                 let offset =
                     offset_in_pixels / self.pixels_per_point + self.y_offset_in_points * Vec2::Y;
                 UvRect {
@@ -306,7 +465,11 @@ impl FontImpl {
                 }
             }
         });
-        let uv_rect = uv_rect.unwrap_or_default();
+        self.glyph_info_with_uv(glyph_id, uv_rect.unwrap_or_default())
+    }
+
+    fn glyph_info_with_uv(&self, glyph_id: ab_glyph::GlyphId, uv_rect: UvRect) -> GlyphInfo {
+        use ab_glyph::{Font as _, ScaleFont};
 
         let advance_width_in_points = self
             .ab_glyph_font
@@ -320,6 +483,68 @@ impl FontImpl {
             uv_rect,
         }
     }
+
+    /// Rasterize a glyph at three times the horizontal resolution, i.e. once per color subpixel.
+    ///
+    /// See [`super::GlyphRasterization::Subpixel`].
+    fn allocate_subpixel_glyph(
+        &self,
+        glyph_id: ab_glyph::GlyphId,
+        order: super::SubpixelOrder,
+    ) -> Option<UvRect> {
+        use super::subpixel::{subpixel_coverage_from_samples, FILTER_SPREAD};
+        use ab_glyph::Font as _;
+
+        let scale = self.scale_in_pixels as f32;
+        let glyph = glyph_id.with_scale_and_position(
+            ab_glyph::PxScale {
+                x: 3.0 * scale,
+                y: scale,
+            },
+            ab_glyph::Point { x: 0.0, y: 0.0 },
+        );
+        let glyph = self.ab_glyph_font.outline_glyph(glyph)?;
+
+        let bb = glyph.px_bounds(); // in subpixels horizontally
+        let samples_wide = bb.width() as usize;
+        let glyph_height = bb.height() as usize;
+        if samples_wide == 0 || glyph_height == 0 {
+            return Some(UvRect::default());
+        }
+
+        // Round out to whole texels, leaving room for the filter to spread the coverage:
+        let spread = FILTER_SPREAD as i32;
+        let first_texel = (bb.min.x as i32 - spread).div_euclid(3);
+        let end_texel = (bb.min.x as i32 + samples_wide as i32 + spread + 2).div_euclid(3);
+        let glyph_width = (end_texel - first_texel) as usize;
+        let x_offset = (bb.min.x as i32 - 3 * first_texel) as usize;
+
+        let mut samples = vec![0.0; 3 * glyph_width * glyph_height];
+        glyph.draw(|x, y, v| {
+            samples[y as usize * 3 * glyph_width + x as usize + x_offset] = v;
+        });
+        let coverage = subpixel_coverage_from_samples(&samples, glyph_width, glyph_height, order);
+
+        let atlas = &mut self.atlas.lock();
+        let (glyph_pos, image) = atlas.allocate((glyph_width, glyph_height));
+        for (i, rgb) in coverage.into_iter().enumerate() {
+            let px = glyph_pos.0 + i % glyph_width;
+            let py = glyph_pos.1 + i / glyph_width;
+            image.set_subpixel_coverage((px, py), rgb);
+        }
+
+        let offset_in_pixels = vec2(first_texel as f32, bb.min.y);
+        let offset = offset_in_pixels / self.pixels_per_point + self.y_offset_in_points * Vec2::Y;
+        Some(UvRect {
+            offset,
+            size: vec2(glyph_width as f32, glyph_height as f32) / self.pixels_per_point,
+            min: [glyph_pos.0 as u16, glyph_pos.1 as u16],
+            max: [
+                (glyph_pos.0 + glyph_width) as u16,
+                (glyph_pos.1 + glyph_height) as u16,
+            ],
+        })
+    }
 }
 
 type FontIndex = usize;
@@ -432,6 +657,20 @@ impl Font {
         self.glyph_info(c).1.advance_width
     }
 
+    /// Metrics and atlas position of this character, adding it to the font atlas if needed.
+    ///
+    /// Unknown characters get the metrics of the replacement character.
+    pub fn glyph_metrics(&mut self, c: char) -> GlyphMetrics {
+        let row_height = self.row_height;
+        let (font_impl, glyph_info) = self.font_impl_and_glyph_info(c);
+        GlyphMetrics {
+            advance_width: glyph_info.advance_width,
+            ascent: font_impl.map_or(0.0, |font| font.ascent()),
+            row_height,
+            uv_rect: glyph_info.uv_rect,
+        }
+    }
+
     /// Can we display this glyph?
     pub fn has_glyph(&mut self, c: char) -> bool {
         self.glyph_info(c) != self.replacement_glyph // TODO(emilk): this is a false negative if the user asks about the replacement character itself 🤦‍♂️
@@ -464,6 +703,44 @@ impl Font {
         (Some(font_impl), glyph_info)
     }
 
+    /// Find the start of `text` that can be shaped as one run with a single [`FontImpl`].
+    ///
+    /// Returns the font and the byte length of the run,
+    /// or `None` if the first character should be laid out on its own,
+    /// e.g. because it is a tab, a newline, or not supported by any font.
+    #[cfg(feature = "rustybuzz")]
+    pub(crate) fn shaping_run(&mut self, text: &str) -> Option<(Arc<FontImpl>, usize)> {
+        let mut chars = text.char_indices();
+        let (_, first) = chars.next()?;
+        let font_index = self.shaping_font_index(first)?;
+
+        let mut run_len = first.len_utf8();
+        for (i, c) in chars {
+            // Joiners are invisible, but affect the shaping of their neighbors:
+            let is_joiner = matches!(c, '\u{200C}' | '\u{200D}');
+            if is_joiner || self.shaping_font_index(c) == Some(font_index) {
+                run_len = i + c.len_utf8();
+            } else {
+                break;
+            }
+        }
+
+        Some((self.fonts[font_index].clone(), run_len))
+    }
+
+    #[cfg(feature = "rustybuzz")]
+    fn shaping_font_index(&mut self, c: char) -> Option<FontIndex> {
+        if self.fonts.is_empty() || c == '\t' || c == '\u{2009}' || invisible_char(c) {
+            return None; // These are special-cased in `FontImpl::glyph_info`
+        }
+        let font_index_glyph_info = self.glyph_info(c);
+        if font_index_glyph_info == self.replacement_glyph {
+            return None;
+        }
+        let (font_index, _) = font_index_glyph_info;
+        self.fonts[font_index].can_shape().then_some(font_index)
+    }
+
     fn glyph_info_no_cache_or_fallback(&mut self, c: char) -> Option<(FontIndex, GlyphInfo)> {
         for (font_index, font_impl) in self.fonts.iter().enumerate() {
             if let Some(glyph_info) = font_impl.glyph_info(c) {
@@ -3,7 +3,7 @@ use std::{collections::BTreeMap, sync::Arc};
 use crate::{
     mutex::{Mutex, MutexGuard},
     text::{
-        font::{Font, FontImpl},
+        font::{Font, FontImpl, GlyphMetrics},
         Galley, LayoutJob,
     },
     TextureAtlas,
@@ -189,15 +189,83 @@ impl Default for FontTweak {
 
 // ----------------------------------------------------------------------------
 
-fn ab_glyph_font_from_font_data(name: &str, data: &FontData) -> ab_glyph::FontArc {
+/// How glyphs are rasterized into the font atlas.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GlyphRasterization {
+    /// Store how much each texel is covered by the glyph.
+    ///
+    /// This gives the best looking text at the size it was laid out at,
+    /// but text becomes blurry when it is scaled up, e.g. with [`emath::TSTransform`] layer transforms.
+    #[default]
+    Coverage,
+
+    /// Store the distance from each texel to the edge of the glyph (a signed distance field).
+    ///
+    /// Text stays sharp when scaled up, which is useful for zoomable canvases,
+    /// at the cost of slightly rounded corners on the glyphs.
+    ///
+    /// The renderer needs to support this, which `egui_glow` and `egui-wgpu` do.
+    SignedDistanceField,
+
+    /// Store how much each of the red, green and blue subpixels of each texel is covered by the glyph,
+    /// also known as subpixel or LCD anti-aliasing.
+    ///
+    /// This makes text noticeably sharper on standard-DPI LCD monitors,
+    /// but only if the [`SubpixelOrder`] matches that of the monitor.
+    /// On high-DPI monitors, or when text is scaled or rotated, prefer [`Self::Coverage`].
+    ///
+    /// The renderer needs to support this, which `egui_glow` and `egui-wgpu` do.
+    /// Other renderers will show slightly colored fringes around the text.
+    Subpixel(SubpixelOrder),
+}
+
+/// The order of the color subpixels of a monitor, from left to right.
+///
+/// See [`GlyphRasterization::Subpixel`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SubpixelOrder {
+    /// Red, green, blue. This is by far the most common.
+    #[default]
+    Rgb,
+
+    /// Blue, green, red.
+    Bgr,
+}
+
+// ----------------------------------------------------------------------------
+
+/// A font file loaded with `ab_glyph`.
+struct LoadedFont {
+    ab_glyph: ab_glyph::FontArc,
+
+    /// The bytes used by [`Self::ab_glyph`], for text shaping.
+    #[cfg(feature = "rustybuzz")]
+    bytes: super::shaping::FontBytes,
+}
+
+fn load_font(name: &str, data: &FontData) -> LoadedFont {
     match &data.font {
         std::borrow::Cow::Borrowed(bytes) => {
-            ab_glyph::FontRef::try_from_slice_and_index(bytes, data.index)
-                .map(ab_glyph::FontArc::from)
+            ab_glyph::FontRef::try_from_slice_and_index(bytes, data.index).map(|font| LoadedFont {
+                ab_glyph: font.into(),
+                #[cfg(feature = "rustybuzz")]
+                bytes: super::shaping::FontBytes::Static(bytes),
+            })
         }
         std::borrow::Cow::Owned(bytes) => {
-            ab_glyph::FontVec::try_from_vec_and_index(bytes.clone(), data.index)
-                .map(ab_glyph::FontArc::from)
+            ab_glyph::FontVec::try_from_vec_and_index(bytes.clone(), data.index).map(|font| {
+                // Shared with the shaping, so that the bytes are not copied again:
+                let font = Arc::new(font);
+                LoadedFont {
+                    #[cfg(feature = "rustybuzz")]
+                    bytes: super::shaping::FontBytes::Owned(font.clone()),
+                    ab_glyph: ab_glyph::FontArc::from(
+                        font as Arc<dyn ab_glyph::Font + Send + Sync>,
+                    ),
+                }
+            })
         }
     }
     .unwrap_or_else(|err| panic!("Error parsing {name:?} TTF/OTF font file: {err}"))
@@ -378,14 +446,40 @@ impl Fonts {
         pixels_per_point: f32,
         max_texture_side: usize,
         definitions: FontDefinitions,
+    ) -> Self {
+        Self::new_with_glyph_rasterization(
+            pixels_per_point,
+            max_texture_side,
+            definitions,
+            GlyphRasterization::Coverage,
+        )
+    }
+
+    /// Like [`Self::new`], but with a different [`GlyphRasterization`].
+    pub fn new_with_glyph_rasterization(
+        pixels_per_point: f32,
+        max_texture_side: usize,
+        definitions: FontDefinitions,
+        glyph_rasterization: GlyphRasterization,
     ) -> Self {
         let fonts_and_cache = FontsAndCache {
-            fonts: FontsImpl::new(pixels_per_point, max_texture_side, definitions),
+            fonts: FontsImpl::new_with_glyph_rasterization(
+                pixels_per_point,
+                max_texture_side,
+                definitions,
+                glyph_rasterization,
+            ),
             galley_cache: Default::default(),
         };
         Self(Arc::new(Mutex::new(fonts_and_cache)))
     }
 
+    /// How the glyphs are rasterized into the font atlas.
+    #[inline]
+    pub fn glyph_rasterization(&self) -> GlyphRasterization {
+        self.lock().fonts.atlas.lock().glyph_rasterization()
+    }
+
     /// Call at the start of each frame with the latest known
     /// `pixels_per_point` and `max_texture_side`.
     ///
@@ -460,6 +554,15 @@ impl Fonts {
         self.lock().fonts.glyph_width(font_id, c)
     }
 
+    /// Metrics and font atlas position of this character.
+    ///
+    /// Use this to paint egui-compatible text with your own renderer.
+    /// This may add the glyph to the font atlas, so check [`Self::font_image_delta`] afterwards.
+    #[inline]
+    pub fn glyph_metrics(&self, font_id: &FontId, c: char) -> GlyphMetrics {
+        self.lock().fonts.glyph_metrics(font_id, c)
+    }
+
     /// Can we display this glyph?
     #[inline]
     pub fn has_glyph(&self, font_id: &FontId, c: char) -> bool {
@@ -500,6 +603,40 @@ impl Fonts {
         self.lock().layout_job(job)
     }
 
+    /// Like [`Self::layout_job`], but large texts are laid out one paragraph at a time,
+    /// unlocking the [`Fonts`] in between.
+    ///
+    /// Use this when laying out on a background thread,
+    /// so that the other users of the [`Fonts`] (like the ui thread) don't have to wait for long.
+    pub fn layout_job_unblocking(&self, job: LayoutJob) -> Arc<Galley> {
+        let Some(paragraph_jobs) = super::text_layout::split_into_paragraphs(&job) else {
+            return self.layout_job(job);
+        };
+
+        // The paragraphs are cached like they are by `layout_job`, so they are reused later:
+        let paragraphs: Vec<(Arc<Galley>, u32)> = paragraph_jobs
+            .into_iter()
+            .map(|(paragraph_job, first_section_index)| {
+                (self.layout_job(paragraph_job), first_section_index)
+            })
+            .collect();
+
+        let pixels_per_point = paragraphs[0].0.pixels_per_point;
+        if paragraphs
+            .iter()
+            .any(|(galley, _)| galley.pixels_per_point != pixels_per_point)
+        {
+            // The fonts were recreated while we were at it:
+            return self.layout_job(job);
+        }
+
+        Arc::new(super::text_layout::galley_from_paragraphs(
+            job.into(),
+            &paragraphs,
+            pixels_per_point,
+        ))
+    }
+
     pub fn num_galleys_in_cache(&self) -> usize {
         self.lock().galley_cache.num_galleys_in_cache()
     }
@@ -586,6 +723,21 @@ impl FontsImpl {
         pixels_per_point: f32,
         max_texture_side: usize,
         definitions: FontDefinitions,
+    ) -> Self {
+        Self::new_with_glyph_rasterization(
+            pixels_per_point,
+            max_texture_side,
+            definitions,
+            GlyphRasterization::Coverage,
+        )
+    }
+
+    /// Like [`Self::new`], but with a different [`GlyphRasterization`].
+    pub fn new_with_glyph_rasterization(
+        pixels_per_point: f32,
+        max_texture_side: usize,
+        definitions: FontDefinitions,
+        glyph_rasterization: GlyphRasterization,
     ) -> Self {
         assert!(
             0.0 < pixels_per_point && pixels_per_point < 100.0,
@@ -594,7 +746,8 @@ impl FontsImpl {
 
         let texture_width = max_texture_side.at_most(8 * 1024);
         let initial_height = 32; // Keep initial font atlas small, so it is fast to upload to GPU. This will expand as needed anyways.
-        let atlas = TextureAtlas::new([texture_width, initial_height]);
+        let atlas = TextureAtlas::new([texture_width, initial_height])
+            .with_glyph_rasterization(glyph_rasterization);
 
         let atlas = Arc::new(Mutex::new(atlas));
 
@@ -646,6 +799,11 @@ impl FontsImpl {
         self.font(font_id).glyph_width(c)
     }
 
+    /// Metrics and font atlas position of this character.
+    pub fn glyph_metrics(&mut self, font_id: &FontId, c: char) -> GlyphMetrics {
+        self.font(font_id).glyph_metrics(c)
+    }
+
     /// Can we display this glyph?
     pub fn has_glyph(&mut self, font_id: &FontId, c: char) -> bool {
         self.font(font_id).has_glyph(c)
@@ -668,6 +826,12 @@ struct CachedGalley {
     /// When it was last used
     last_used: u32,
     galley: Arc<Galley>,
+
+    /// For large texts laid out one paragraph at a time: the hashes of the paragraph galleys.
+    ///
+    /// They are kept alive as long as this galley is used,
+    /// so that changing one paragraph only needs to lay out that paragraph again.
+    paragraph_hashes: Arc<[u64]>,
 }
 
 #[derive(Default)]
@@ -681,22 +845,70 @@ impl GalleyCache {
     fn layout(&mut self, fonts: &mut FontsImpl, job: LayoutJob) -> Arc<Galley> {
         let hash = crate::util::hash(&job); // TODO(emilk): even faster hasher?
 
-        match self.cache.entry(hash) {
-            std::collections::hash_map::Entry::Occupied(entry) => {
-                let cached = entry.into_mut();
-                cached.last_used = self.generation;
-                cached.galley.clone()
+        if let Some(cached) = self.cache.get_mut(&hash) {
+            cached.last_used = self.generation;
+            let galley = cached.galley.clone();
+            let paragraph_hashes = cached.paragraph_hashes.clone();
+            for paragraph_hash in paragraph_hashes.iter() {
+                if let Some(paragraph) = self.cache.get_mut(paragraph_hash) {
+                    paragraph.last_used = self.generation;
+                }
             }
-            std::collections::hash_map::Entry::Vacant(entry) => {
-                let galley = super::layout(fonts, job.into());
-                let galley = Arc::new(galley);
-                entry.insert(CachedGalley {
+            return galley;
+        }
+
+        let (galley, paragraph_hashes) =
+            if let Some(paragraph_jobs) = super::text_layout::split_into_paragraphs(&job) {
+                self.layout_paragraphs(fonts, job, paragraph_jobs)
+            } else {
+                (super::layout(fonts, job.into()), Default::default())
+            };
+
+        let galley = Arc::new(galley);
+        self.cache.insert(
+            hash,
+            CachedGalley {
+                last_used: self.generation,
+                galley: galley.clone(),
+                paragraph_hashes,
+            },
+        );
+        galley
+    }
+
+    /// Lay out each paragraph on its own (reusing cached paragraphs), then put them together.
+    fn layout_paragraphs(
+        &mut self,
+        fonts: &mut FontsImpl,
+        job: LayoutJob,
+        paragraph_jobs: Vec<(LayoutJob, u32)>,
+    ) -> (Galley, Arc<[u64]>) {
+        crate::profile_function!();
+
+        let mut paragraph_hashes = Vec::with_capacity(paragraph_jobs.len());
+        let mut paragraphs = Vec::with_capacity(paragraph_jobs.len());
+
+        for (paragraph_job, first_section_index) in paragraph_jobs {
+            let paragraph_hash = crate::util::hash(&paragraph_job);
+            let cached = self
+                .cache
+                .entry(paragraph_hash)
+                .or_insert_with(|| CachedGalley {
                     last_used: self.generation,
-                    galley: galley.clone(),
+                    galley: Arc::new(super::layout(fonts, paragraph_job.into())),
+                    paragraph_hashes: Default::default(),
                 });
-                galley
-            }
+            cached.last_used = self.generation;
+            paragraph_hashes.push(paragraph_hash);
+            paragraphs.push((cached.galley.clone(), first_section_index));
         }
+
+        let galley = super::text_layout::galley_from_paragraphs(
+            job.into(),
+            &paragraphs,
+            fonts.pixels_per_point(),
+        );
+        (galley, paragraph_hashes.into())
     }
 
     pub fn num_galleys_in_cache(&self) -> usize {
@@ -720,6 +932,10 @@ struct FontImplCache {
     pixels_per_point: f32,
     ab_glyph_fonts: BTreeMap<String, (FontTweak, ab_glyph::FontArc)>,
 
+    /// The fonts that can be used for text shaping, shared by all sizes.
+    #[cfg(feature = "rustybuzz")]
+    shaping_fonts: BTreeMap<String, Arc<super::shaping::ShapingFont>>,
+
     /// Map font pixel sizes and names to the cached [`FontImpl`].
     cache: ahash::HashMap<(u32, String), Arc<FontImpl>>,
 }
@@ -730,19 +946,27 @@ impl FontImplCache {
         pixels_per_point: f32,
         font_data: &BTreeMap<String, FontData>,
     ) -> Self {
-        let ab_glyph_fonts = font_data
-            .iter()
-            .map(|(name, font_data)| {
-                let tweak = font_data.tweak;
-                let ab_glyph = ab_glyph_font_from_font_data(name, font_data);
-                (name.clone(), (tweak, ab_glyph))
-            })
-            .collect();
+        let mut ab_glyph_fonts = BTreeMap::new();
+        #[cfg(feature = "rustybuzz")]
+        let mut shaping_fonts = BTreeMap::new();
+
+        for (name, font_data) in font_data {
+            let loaded = load_font(name, font_data);
+            #[cfg(feature = "rustybuzz")]
+            if let Some(shaping_font) =
+                super::shaping::ShapingFont::new(loaded.bytes, font_data.index)
+            {
+                shaping_fonts.insert(name.clone(), Arc::new(shaping_font));
+            }
+            ab_glyph_fonts.insert(name.clone(), (font_data.tweak, loaded.ab_glyph));
+        }
 
         Self {
             atlas,
             pixels_per_point,
             ab_glyph_fonts,
+            #[cfg(feature = "rustybuzz")]
+            shaping_fonts,
             cache: Default::default(),
         }
     }
@@ -771,14 +995,22 @@ impl FontImplCache {
                 font_name.to_owned(),
             ))
             .or_insert_with(|| {
-                Arc::new(FontImpl::new(
+                let font_impl = FontImpl::new(
                     self.atlas.clone(),
                     self.pixels_per_point,
                     font_name.to_owned(),
                     ab_glyph_font,
                     scale_in_pixels,
                     tweak,
-                ))
+                );
+
+                #[cfg(feature = "rustybuzz")]
+                let font_impl = match self.shaping_fonts.get(font_name) {
+                    Some(shaping_font) => font_impl.with_shaping(shaping_font.clone()),
+                    None => font_impl,
+                };
+
+                Arc::new(font_impl)
             })
             .clone()
     }
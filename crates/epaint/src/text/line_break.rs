@@ -0,0 +1,280 @@
+//! Line breaking, following the [Unicode Line Breaking Algorithm](https://www.unicode.org/reports/tr14/) (UAX #14).
+//!
+//! This is a simplified implementation:
+//! * The line break class of each character is approximated with a small table of the most common scripts.
+//! * Conditional Japanese starters (small kana) are treated as non-starters, i.e. the "strict" line breaking of CSS.
+//! * Hangul syllables are treated like ideographs, so Korean text may be broken between syllables.
+//! * Rules for emojis, regional indicators and Hebrew are not implemented.
+//!
+//! This is enough to break Chinese and Japanese text (which has no spaces) between characters,
+//! while never putting closing punctuation at the start of a line.
+
+/// The line breaking class of a character.
+#[allow(clippy::upper_case_acronyms)] // Same names as in the Unicode standard
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LineBreakClass {
+    /// Ordinary alphabetic characters, e.g. Latin letters.
+    AL,
+
+    /// Break after, e.g. tab and en dash.
+    BA,
+
+    /// Break opportunity before and after, i.e. em dash.
+    B2,
+
+    /// Closing punctuation, e.g. `}` and `」`.
+    CL,
+
+    /// Combining mark, e.g. combining accents.
+    CM,
+
+    /// Closing parenthesis, i.e. `)` and `]`.
+    CP,
+
+    /// Exclamation or interrogation, e.g. `!` and `？`.
+    EX,
+
+    /// Non-breaking ("glue"), e.g. no-break space.
+    GL,
+
+    /// Hyphen, i.e. `-`.
+    HY,
+
+    /// Ideographic, e.g. Chinese characters and kana.
+    ID,
+
+    /// Inseparable characters, e.g. `…`.
+    IN,
+
+    /// Infix numeric separator, e.g. `,` and `.`.
+    IS,
+
+    /// Non-starter, e.g. small kana and `々`.
+    NS,
+
+    /// Numeric.
+    NU,
+
+    /// Opening punctuation, e.g. `(` and `「`.
+    OP,
+
+    /// Postfix numeric, e.g. `%`.
+    PO,
+
+    /// Prefix numeric, e.g. `$`.
+    PR,
+
+    /// Quotation, e.g. `"` and `«`.
+    QU,
+
+    /// Space.
+    SP,
+
+    /// Symbols allowing break after, i.e. `/`.
+    SY,
+
+    /// Zero width space.
+    ZW,
+}
+
+#[allow(clippy::match_same_arms)] // one arm per script or group of characters
+fn line_break_class(c: char) -> LineBreakClass {
+    use LineBreakClass::{
+        AL, B2, BA, CL, CM, CP, EX, GL, HY, ID, IN, IS, NS, NU, OP, PO, PR, QU, SP, SY, ZW,
+    };
+
+    match c {
+        ' ' => SP,
+        '\t' | '\u{AD}' | '|' | '\u{1680}' | '\u{2000}'..='\u{2006}' | '\u{2008}'..='\u{200A}' => {
+            BA
+        }
+        '\u{2010}' | '\u{2012}' | '\u{2013}' | '\u{205F}' | '\u{3000}' => BA,
+        '\u{A0}' | '\u{2007}' | '\u{2011}' | '\u{202F}' | '\u{2060}' | '\u{FEFF}' => GL,
+        '\u{200B}' => ZW,
+        '\u{2014}' => B2,
+        '-' => HY,
+        '/' => SY,
+        ')' | ']' => CP,
+        '(' | '[' | '{' | '¡' | '¿' => OP,
+        '}' => CL,
+        '"' | '\'' | '«' | '»' | '\u{2018}'..='\u{201F}' | '‹' | '›' => QU,
+        '!' | '?' | '‼' | '⁇' | '⁈' | '⁉' | '！' | '？' => EX,
+        ',' | '.' | ':' | ';' | '\u{37E}' | '\u{589}' | '\u{60C}' | '\u{60D}' => IS,
+        '…' | '‥' => IN,
+        '%' | '¢' | '°' | '‰' | '′' | '″' | '℃' | '％' | '￠' => PO,
+        '$' | '+' | '\\' | '£' | '¥' | '€' | '₩' | '＄' | '￡' | '￥' => PR,
+        '0'..='9' | '\u{660}'..='\u{669}' | '\u{6F0}'..='\u{6F9}' => NU,
+
+        // Combining marks, variation selectors and zero width joiner:
+        '\u{300}'..='\u{36F}'
+        | '\u{483}'..='\u{489}'
+        | '\u{591}'..='\u{5BD}'
+        | '\u{610}'..='\u{61A}'
+        | '\u{64B}'..='\u{65F}'
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{200C}'
+        | '\u{200D}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{3099}'
+        | '\u{309A}'
+        | '\u{FE00}'..='\u{FE0F}'
+        | '\u{FE20}'..='\u{FE2F}' => CM,
+
+        // East Asian punctuation:
+        '「' | '『' | '（' | '【' | '〈' | '《' | '〔' | '〖' | '〘' | '〚' | '［' | '｛' | '｟' | '｢'
+        | '〝' => OP,
+        '」' | '』' | '）' | '】' | '〉' | '》' | '〕' | '〗' | '〙' | '〛' | '］' | '｝' | '｠' | '｣'
+        | '〞' | '〟' | '、' | '。' | '，' | '．' | '､' | '｡' => CL,
+
+        // Characters not permitted at the start of a line in Japanese:
+        'ヽ' | 'ヾ' | 'ー' | '々' | '〻' | '゠' | '〜' | '・' | 'ゝ' | 'ゞ' | '：' | '；' | '･' | 'ｰ' => {
+            NS
+        }
+        'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' | 'っ' | 'ゃ' | 'ゅ' | 'ょ' | 'ゎ' | 'ゕ' | 'ゖ' => NS,
+        'ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ' | 'ッ' | 'ャ' | 'ュ' | 'ョ' | 'ヮ' | 'ヵ' | 'ヶ' => NS,
+        '\u{31F0}'..='\u{31FF}' | '\u{FF67}'..='\u{FF70}' => NS, // Katakana phonetic extensions and halfwidth small kana
+
+        '\u{1100}'..='\u{115F}' // Hangul Jamo
+        | '\u{2E80}'..='\u{2FFF}' // CJK radicals
+        | '\u{3001}'..='\u{33FF}' // CJK symbols, kana, etc.
+        | '\u{3400}'..='\u{4DBF}' // CJK Unified Ideographs Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{A960}'..='\u{A97F}' // Hangul Jamo Extended-A
+        | '\u{AC00}'..='\u{D7AF}' // Hangul syllables
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+        | '\u{FE30}'..='\u{FE4F}' // CJK Compatibility Forms
+        | '\u{FF01}'..='\u{FF60}' // Fullwidth forms
+        | '\u{FF71}'..='\u{FF9F}' // Halfwidth katakana
+        | '\u{1F000}'..='\u{1FAFF}' // Emojis and other pictographs
+        | '\u{20000}'..='\u{3FFFD}' => ID, // Supplementary ideographic planes
+
+        _ => AL,
+    }
+}
+
+/// Is this an East Asian fullwidth or wide character?
+fn is_wide(c: char) -> bool {
+    '\u{2E80}' <= c
+}
+
+/// Is a line break allowed between the characters at `i` and `i + 1`?
+///
+/// `before_spaces` is the class of the last character before any spaces preceding `i + 1`.
+#[allow(clippy::match_same_arms)] // one arm per rule of UAX #14
+fn is_break_allowed(
+    chars: &[char],
+    classes: &[LineBreakClass],
+    i: usize,
+    before_spaces: LineBreakClass,
+) -> bool {
+    use LineBreakClass::{
+        AL, B2, BA, CL, CM, CP, EX, GL, HY, ID, IN, IS, NS, NU, OP, PO, PR, QU, SP, SY, ZW,
+    };
+
+    let (a, b) = (classes[i], classes[i + 1]);
+
+    if line_break_class(chars[i + 1]) == CM {
+        return false; // LB9: keep combining marks with their base character
+    }
+
+    match (before_spaces, a, b) {
+        (_, _, SP | ZW) => false,                              // LB7
+        (ZW, _, _) => true,                                    // LB8
+        (_, GL, _) => false,                                   // LB12
+        (_, a, GL) => matches!(a, SP | BA | HY),               // LB12a
+        (_, _, CL | CP | EX | IS | SY) => false,               // LB13
+        (OP, _, _) => false,                                   // LB14
+        (QU, _, OP) | (CL | CP, _, NS) | (B2, _, B2) => false, // LB15-17
+        (_, SP, _) => true,                                    // LB18
+        (_, QU, _) | (_, _, QU) => false,                      // LB19
+        (_, _, BA | HY | NS | IN) => false,                    // LB21-22
+        (_, AL, NU) | (_, NU, AL) => false,                    // LB23
+        (_, PR, ID) | (_, ID, PO) => false,                    // LB23a
+        (_, PR | PO, AL) | (_, AL, PR | PO) => false,          // LB24
+        (_, CL | CP | NU, PO | PR) => false,                   // LB25
+        (_, PO | PR, OP | NU) => false,                        // LB25
+        (_, HY | IS | NU | SY, NU) => false,                   // LB25
+        (_, AL | IS, AL) => false,                             // LB28-29
+        (_, AL | NU, OP) => is_wide(chars[i + 1]),             // LB30
+        (_, CP, AL | NU) => false,                             // LB30
+        _ => true,                                             // LB31
+    }
+}
+
+/// For each character: may the line be broken after it?
+///
+/// The last character never has a break opportunity after it,
+/// since that is the end of the paragraph.
+pub(crate) fn break_opportunities(chars: &[char]) -> Vec<bool> {
+    use LineBreakClass::{AL, CM, SP, ZW};
+
+    // LB9-10: combining marks take the class of their base character:
+    let mut classes: Vec<LineBreakClass> = Vec::with_capacity(chars.len());
+    for &c in chars {
+        let class = match (line_break_class(c), classes.last()) {
+            (CM, Some(&base)) if base != SP && base != ZW => base,
+            (CM, _) => AL,
+            (class, _) => class,
+        };
+        classes.push(class);
+    }
+
+    let mut opportunities = vec![false; chars.len()];
+    let mut before_spaces = SP;
+    for i in 0..chars.len().saturating_sub(1) {
+        if classes[i] != SP {
+            before_spaces = classes[i];
+        }
+        opportunities[i] = is_break_allowed(chars, &classes, i, before_spaces);
+    }
+    opportunities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Insert `|` at each break opportunity.
+    fn show_breaks(text: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let opportunities = break_opportunities(&chars);
+        let mut result = String::new();
+        for (c, opportunity) in chars.into_iter().zip(opportunities) {
+            result.push(c);
+            if opportunity {
+                result.push('|');
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_latin() {
+        assert_eq!(show_breaks("Hello world!"), "Hello |world!");
+        assert_eq!(show_breaks("Hello  world"), "Hello  |world");
+        assert_eq!(show_breaks("well-known"), "well-|known");
+        assert_eq!(show_breaks("-5 and 3.14"), "-5 |and |3.14");
+        assert_eq!(show_breaks("it costs $10 (20%)"), "it |costs |$10 |(20%)");
+        assert_eq!(show_breaks("yes/no"), "yes/|no");
+        assert_eq!(show_breaks("a\u{A0}b c"), "a\u{A0}b |c");
+        assert_eq!(show_breaks("( hello )"), "( hello )");
+    }
+
+    #[test]
+    fn test_cjk() {
+        assert_eq!(show_breaks("日本語"), "日|本|語");
+        assert_eq!(show_breaks("こんにちは。"), "こ|ん|に|ち|は。");
+        assert_eq!(show_breaks("「東京」へ"), "「東|京」|へ");
+        assert_eq!(show_breaks("ちょっと"), "ちょっ|と");
+        assert_eq!(show_breaks("中文，好"), "中|文，|好");
+        assert_eq!(show_breaks("egui的文本"), "egui|的|文|本");
+        assert_eq!(show_breaks("한국어"), "한|국|어");
+    }
+
+    #[test]
+    fn test_combining_marks() {
+        assert_eq!(show_breaks("e\u{301} a"), "e\u{301} |a");
+        assert_eq!(show_breaks("か\u{3099}き"), "か\u{3099}|き");
+    }
+}
@@ -1,8 +1,16 @@
 //! Everything related to text, fonts, text layout, cursors etc.
 
+mod bidi;
 pub mod cursor;
 mod font;
 mod fonts;
+mod line_break;
+mod sdf;
+#[cfg(feature = "rustybuzz")]
+mod shaping;
+mod subpixel;
+#[cfg(all(feature = "system_fonts", not(target_arch = "wasm32")))]
+mod system_fonts;
 mod text_layout;
 mod text_layout_types;
 
@@ -10,10 +18,17 @@ mod text_layout_types;
 pub const TAB_SIZE: usize = 4;
 
 pub use {
-    fonts::{FontData, FontDefinitions, FontFamily, FontId, FontTweak, Fonts, FontsImpl},
+    font::{GlyphMetrics, UvRect},
+    fonts::{
+        FontData, FontDefinitions, FontFamily, FontId, FontTweak, Fonts, FontsImpl,
+        GlyphRasterization, SubpixelOrder,
+    },
     text_layout::layout,
     text_layout_types::*,
 };
 
+#[cfg(all(feature = "system_fonts", not(target_arch = "wasm32")))]
+pub use system_fonts::Script;
+
 /// Suggested character to use to replace those in password text fields.
 pub const PASSWORD_REPLACEMENT_CHAR: char = '•';
@@ -0,0 +1,120 @@
+//! Turning glyph coverage into a signed distance field.
+//!
+//! See [`super::GlyphRasterization::SignedDistanceField`].
+
+/// How far from the edge of a glyph the distance field reaches, in texels.
+///
+/// Glyphs are padded by this much in the font atlas.
+pub(crate) const SDF_SPREAD: usize = 4;
+
+/// Convert a coverage image of `width x height` texels into a signed distance field of the same size.
+///
+/// The output is `0.5` on the edge of the glyph, and grows towards `1.0` inside it
+/// and shrinks towards `0.0` outside it, reaching those at [`SDF_SPREAD`] texels from the edge.
+///
+/// The coverage image should have at least [`SDF_SPREAD`] empty texels on each side.
+pub(crate) fn sdf_from_coverage(coverage: &[f32], width: usize, height: usize) -> Vec<f32> {
+    crate::profile_function!();
+
+    debug_assert_eq!(coverage.len(), width * height);
+
+    let at = |x: i32, y: i32| -> f32 {
+        if 0 <= x && 0 <= y && (x as usize) < width && (y as usize) < height {
+            coverage[y as usize * width + x as usize]
+        } else {
+            0.0
+        }
+    };
+    let is_inside = |x: i32, y: i32| 0.5 <= at(x, y);
+
+    // Texels on the edge of the glyph: either partially covered,
+    // or next to a texel on the other side of the edge.
+    // Each comes with its own distance to the edge, positive inside the glyph.
+    let mut edge = vec![None; width * height];
+    for y in 0..height as i32 {
+        for x in 0..width as i32 {
+            let c = at(x, y);
+            let inside = is_inside(x, y);
+            let is_edge = (0.0 < c && c < 1.0)
+                || [(-1, 0), (1, 0), (0, -1), (0, 1)]
+                    .iter()
+                    .any(|(dx, dy)| is_inside(x + dx, y + dy) != inside);
+            if is_edge {
+                edge[y as usize * width + x as usize] = Some(c - 0.5);
+            }
+        }
+    }
+
+    let spread = SDF_SPREAD as i32;
+    let mut sdf = Vec::with_capacity(width * height);
+    for y in 0..height as i32 {
+        for x in 0..width as i32 {
+            let inside = is_inside(x, y);
+            let mut closest = spread as f32;
+
+            for ny in (y - spread).max(0)..=(y + spread).min(height as i32 - 1) {
+                for nx in (x - spread).max(0)..=(x + spread).min(width as i32 - 1) {
+                    if let Some(edge_distance) = edge[ny as usize * width + nx as usize] {
+                        let dx = (nx - x) as f32;
+                        let dy = (ny - y) as f32;
+                        let to_neighbor = dx.hypot(dy);
+                        let distance = if inside {
+                            to_neighbor + edge_distance
+                        } else {
+                            to_neighbor - edge_distance
+                        };
+                        closest = closest.min(distance);
+                    }
+                }
+            }
+
+            let signed_distance = if inside { closest } else { -closest };
+            sdf.push((0.5 + 0.5 * signed_distance / spread as f32).clamp(0.0, 1.0));
+        }
+    }
+    sdf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sdf_of_square() {
+        let size = 4 * SDF_SPREAD;
+        let mut coverage = vec![0.0; size * size];
+        for y in SDF_SPREAD..size - SDF_SPREAD {
+            for x in SDF_SPREAD..size - SDF_SPREAD {
+                coverage[y * size + x] = 1.0;
+            }
+        }
+
+        let sdf = sdf_from_coverage(&coverage, size, size);
+        let at = |x: usize, y: usize| sdf[y * size + x];
+
+        assert_eq!(at(0, 0), 0.0, "Far outside");
+        assert!(0.5 < at(size / 2, size / 2), "Inside");
+        assert!(at(SDF_SPREAD - 1, size / 2) < 0.5, "Just outside");
+        assert!(0.5 < at(SDF_SPREAD, size / 2), "Just inside");
+        assert!(
+            at(SDF_SPREAD - 2, size / 2) < at(SDF_SPREAD - 1, size / 2),
+            "Should increase towards the inside"
+        );
+    }
+
+    #[test]
+    fn test_sdf_atlas_has_no_prerasterized_discs() {
+        use crate::{text::GlyphRasterization, TextureAtlas};
+
+        let atlas = TextureAtlas::new([1024, 32]);
+        assert!(!atlas.prepared_discs().is_empty());
+
+        let atlas = TextureAtlas::new([1024, 32])
+            .with_glyph_rasterization(GlyphRasterization::SignedDistanceField);
+        assert!(
+            atlas.prepared_discs().is_empty(),
+            "The anti-aliased discs would be decoded as distances"
+        );
+        assert_eq!(atlas.image().pixels[0], 1.0, "WHITE_UV");
+    }
+}
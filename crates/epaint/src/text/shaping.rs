@@ -0,0 +1,164 @@
+//! Complex text shaping with [`rustybuzz`](https://docs.rs/rustybuzz).
+//!
+//! Shaping turns a run of characters into positioned glyphs, taking care of
+//! ligatures, contextual forms (e.g. in Arabic), mark positioning and reordering (e.g. in Devanagari).
+//!
+//! Only used with the `rustybuzz` feature.
+
+use std::sync::Arc;
+
+use emath::{vec2, Vec2};
+
+use super::FontFeatures;
+
+/// A glyph produced by shaping a run of text.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ShapedGlyph {
+    /// Byte offset into the shaped text of the first character of the cluster this glyph belongs to.
+    ///
+    /// A cluster is the smallest group of characters that maps to one or more glyphs.
+    pub cluster: usize,
+
+    /// The glyph in the font. Not the same as the character!
+    pub glyph_id: ab_glyph::GlyphId,
+
+    /// How far to move right after this glyph. Unit: points.
+    pub x_advance: f32,
+
+    /// Where to paint the glyph, relative to the pen position. Unit: points, y down.
+    pub offset: Vec2,
+}
+
+/// The bytes of a font file, shared with `ab_glyph` so that they are not copied.
+#[derive(Clone)]
+pub(crate) enum FontBytes {
+    Static(&'static [u8]),
+    Owned(Arc<ab_glyph::FontVec>),
+}
+
+impl FontBytes {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Self::Static(slice) => slice,
+            Self::Owned(font) => font.as_slice(),
+        }
+    }
+}
+
+/// A font file that `rustybuzz` can parse, shared by all sizes of a font.
+///
+/// A [`rustybuzz::Face`] borrows the bytes it was parsed from,
+/// so we parse it again for each run of text we shape. That only reads the table directory.
+pub(crate) struct ShapingFont {
+    bytes: FontBytes,
+
+    /// Which font of a font collection (`.ttc`) to use.
+    index: u32,
+}
+
+impl ShapingFont {
+    /// Returns `None` if `rustybuzz` can't parse the font.
+    pub fn new(bytes: FontBytes, index: u32) -> Option<Self> {
+        rustybuzz::Face::from_slice(bytes.as_slice(), index)?;
+        Some(Self { bytes, index })
+    }
+
+    fn face(&self) -> Option<rustybuzz::Face<'_>> {
+        rustybuzz::Face::from_slice(self.bytes.as_slice(), self.index)
+    }
+
+    /// Shape `text` left-to-right.
+    ///
+    /// `points_per_unit` converts from font units to points.
+    /// The `features` are applied to the whole text.
+    ///
+    /// The returned glyphs are in the same order as the characters they came from.
+    /// Right-to-left runs are reordered afterwards, during bidi resolution of the laid out rows.
+    pub fn shape(
+        &self,
+        text: &str,
+        points_per_unit: f32,
+        features: FontFeatures,
+    ) -> Vec<ShapedGlyph> {
+        crate::profile_function!();
+
+        let Some(face) = self.face() else {
+            return vec![]; // Can't happen, since `new` parsed it
+        };
+
+        let mut buffer = rustybuzz::UnicodeBuffer::new();
+        buffer.push_str(text);
+        buffer.guess_segment_properties();
+        buffer.set_direction(rustybuzz::Direction::LeftToRight);
+
+        let features: Vec<_> = features
+            .tags()
+            .map(|tag| {
+                let tag = rustybuzz::ttf_parser::Tag::from_bytes(&tag);
+                rustybuzz::Feature::new(tag, 1, ..)
+            })
+            .collect();
+
+        let output = rustybuzz::shape(&face, &features, buffer);
+
+        output
+            .glyph_infos()
+            .iter()
+            .zip(output.glyph_positions())
+            .map(|(info, pos)| ShapedGlyph {
+                cluster: info.cluster as usize,
+                glyph_id: ab_glyph::GlyphId(info.glyph_id as u16),
+                x_advance: pos.x_advance as f32 * points_per_unit,
+                offset: vec2(pos.x_offset as f32, -pos.y_offset as f32) * points_per_unit,
+            })
+            .collect()
+    }
+}
+
+/// Group shaped glyphs into clusters.
+///
+/// Returns the byte range of each cluster in the shaped text, together with its glyphs.
+pub(crate) fn clusters<'a>(
+    text: &str,
+    glyphs: &'a [ShapedGlyph],
+) -> Vec<(std::ops::Range<usize>, &'a [ShapedGlyph])> {
+    let mut clusters = vec![];
+    let mut start = 0;
+    while start < glyphs.len() {
+        let cluster = glyphs[start].cluster;
+        let len = glyphs[start..]
+            .iter()
+            .take_while(|glyph| glyph.cluster == cluster)
+            .count();
+        let end = start + len;
+        let byte_end = glyphs.get(end).map_or(text.len(), |glyph| glyph.cluster);
+        clusters.push((cluster..byte_end, &glyphs[start..end]));
+        start = end;
+    }
+    clusters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clusters() {
+        let glyph = |cluster: usize, glyph_id: u16| ShapedGlyph {
+            cluster,
+            glyph_id: ab_glyph::GlyphId(glyph_id),
+            x_advance: 1.0,
+            offset: Vec2::ZERO,
+        };
+
+        // "ffi" as a ligature, followed by a base character with a combining mark:
+        let text = "ffie\u{301}";
+        let glyphs = [glyph(0, 1), glyph(3, 2), glyph(3, 3)];
+        let clusters = clusters(text, &glyphs);
+        let clusters: Vec<_> = clusters
+            .iter()
+            .map(|(range, glyphs)| (&text[range.clone()], glyphs.len()))
+            .collect();
+        assert_eq!(clusters, [("ffi", 1), ("e\u{301}", 2)]);
+    }
+}
@@ -0,0 +1,93 @@
+//! Turning glyph samples into the coverage of each color subpixel.
+//!
+//! See [`super::GlyphRasterization::Subpixel`].
+
+use super::SubpixelOrder;
+
+/// Spreads the coverage of each subpixel over its neighbors, to reduce color fringes.
+///
+/// This is the default LCD filter of `FreeType`. It sums to one.
+const FILTER: [f32; 5] = [
+    8.0 / 256.0,
+    77.0 / 256.0,
+    86.0 / 256.0,
+    77.0 / 256.0,
+    8.0 / 256.0,
+];
+
+/// How many subpixels [`FILTER`] reaches on each side.
+pub(crate) const FILTER_SPREAD: usize = FILTER.len() / 2;
+
+/// Turn coverage samples of `3 * width x height` subpixels into `width x height` texels,
+/// with the filtered coverage of the red, green and blue subpixel of each.
+///
+/// The samples should have at least [`FILTER_SPREAD`] empty subpixels on the left and right.
+pub(crate) fn subpixel_coverage_from_samples(
+    samples: &[f32],
+    width: usize,
+    height: usize,
+    order: SubpixelOrder,
+) -> Vec<[f32; 3]> {
+    crate::profile_function!();
+
+    debug_assert_eq!(samples.len(), 3 * width * height);
+
+    let samples_wide = 3 * width;
+    let mut coverage = Vec::with_capacity(width * height);
+    for row in samples.chunks_exact(samples_wide) {
+        let at = |x: isize| -> f32 {
+            if 0 <= x && (x as usize) < samples_wide {
+                row[x as usize]
+            } else {
+                0.0
+            }
+        };
+
+        for texel in 0..width {
+            let mut rgb = [0.0; 3];
+            for (channel, value) in rgb.iter_mut().enumerate() {
+                let center = (3 * texel + channel) as isize;
+                *value = FILTER
+                    .iter()
+                    .enumerate()
+                    .map(|(i, weight)| weight * at(center + i as isize - FILTER_SPREAD as isize))
+                    .sum::<f32>()
+                    .min(1.0);
+            }
+            if order == SubpixelOrder::Bgr {
+                rgb.reverse();
+            }
+            coverage.push(rgb);
+        }
+    }
+    coverage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subpixel_coverage() {
+        let width = 5;
+        let mut samples = vec![0.0; 3 * width];
+        samples[6] = 1.0; // The leftmost subpixel of the middle texel
+
+        let rgb = subpixel_coverage_from_samples(&samples, width, 1, SubpixelOrder::Rgb);
+        let [r, g, b] = rgb[2];
+        assert!(b < g && g < r, "Should be reddish: {:?}", rgb[2]);
+        assert_eq!(rgb[0], [0.0; 3], "Should not spread that far");
+
+        let bgr = subpixel_coverage_from_samples(&samples, width, 1, SubpixelOrder::Bgr);
+        assert_eq!(bgr[2], [b, g, r]);
+
+        let covered = subpixel_coverage_from_samples(&[1.0; 15], width, 1, SubpixelOrder::Rgb);
+        for channel in covered[2] {
+            assert!(
+                (channel - 1.0).abs() < 1e-6,
+                "Fully covered: {:?}",
+                covered[2]
+            );
+        }
+    }
+}
@@ -0,0 +1,518 @@
+//! Find fonts installed on the system, and use them as fallbacks for scripts the other fonts don't cover.
+//!
+//! The fonts are found by looking in the directories where the operating system keeps its fonts,
+//! i.e. the same directories that are indexed by the font APIs of Windows and macOS, and by fontconfig on Linux.
+//!
+//! Enabled with the `system_fonts` feature. Not available on the web.
+
+use std::path::{Path, PathBuf};
+
+use super::{FontData, FontDefinitions};
+
+/// A writing system, for which we may need a fallback font.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Script {
+    Arabic,
+    Armenian,
+    Bengali,
+    Cyrillic,
+    Devanagari,
+    Ethiopic,
+    Georgian,
+    Greek,
+
+    /// Chinese characters, also used in Japanese (kanji).
+    Han,
+
+    /// Korean.
+    Hangul,
+    Hebrew,
+
+    /// Japanese hiragana and katakana.
+    Kana,
+    Khmer,
+    Tamil,
+    Thai,
+}
+
+impl Script {
+    pub const ALL: [Self; 15] = [
+        Self::Arabic,
+        Self::Armenian,
+        Self::Bengali,
+        Self::Cyrillic,
+        Self::Devanagari,
+        Self::Ethiopic,
+        Self::Georgian,
+        Self::Greek,
+        Self::Han,
+        Self::Hangul,
+        Self::Hebrew,
+        Self::Kana,
+        Self::Khmer,
+        Self::Tamil,
+        Self::Thai,
+    ];
+
+    /// A font must have all of these characters to be used for this script.
+    fn sample_chars(self) -> &'static str {
+        match self {
+            Self::Arabic => "عربي",
+            Self::Armenian => "աբգ",
+            Self::Bengali => "কখগ",
+            Self::Cyrillic => "Жжя",
+            Self::Devanagari => "कखग",
+            Self::Ethiopic => "ሀለሐ",
+            Self::Georgian => "ქართ",
+            Self::Greek => "Ωωλ",
+            Self::Han => "漢字中",
+            Self::Hangul => "한국어",
+            Self::Hebrew => "אבג",
+            Self::Kana => "あアん",
+            Self::Khmer => "កខគ",
+            Self::Tamil => "கஙச",
+            Self::Thai => "กขค",
+        }
+    }
+
+    /// Well-known fonts for this script on Windows, macOS and Linux, in order of preference.
+    ///
+    /// These are prefixes of normalized file names (see [`normalized_file_stem`]).
+    fn preferred_fonts(self) -> &'static [&'static str] {
+        match self {
+            Self::Arabic => &[
+                "notosansarabic",
+                "notonaskharabic",
+                "segoeui",
+                "geezapro",
+                "arial",
+                "dejavusans",
+            ],
+            Self::Armenian => &["notosansarmenian", "segoeui", "sylfaen", "dejavusans"],
+            Self::Bengali => &[
+                "notosansbengali",
+                "nirmala",
+                "vrinda",
+                "kohinoorbangla",
+                "lohitbengali",
+            ],
+            Self::Cyrillic | Self::Greek => &[
+                "notosansregular",
+                "segoeui",
+                "helvetica",
+                "arial",
+                "dejavusans",
+                "liberationsans",
+            ],
+            Self::Devanagari => &[
+                "notosansdevanagari",
+                "nirmala",
+                "mangal",
+                "kohinoordevanagari",
+                "lohitdevanagari",
+            ],
+            Self::Ethiopic => &["notosansethiopic", "ebrima", "kefa", "abyssinica"],
+            Self::Georgian => &["notosansgeorgian", "segoeui", "sylfaen", "dejavusans"],
+            Self::Han => &[
+                "notosanscjk",
+                "notosanssc",
+                "sourcehansans",
+                "msyh",
+                "pingfang",
+                "hiraginosansgb",
+                "wqymicrohei",
+                "wqyzenhei",
+                "droidsansfallback",
+            ],
+            Self::Hangul => &[
+                "notosanscjk",
+                "notosanskr",
+                "malgun",
+                "applesdgothicneo",
+                "nanumgothic",
+                "droidsansfallback",
+            ],
+            Self::Hebrew => &["notosanshebrew", "segoeui", "arial", "dejavusans"],
+            Self::Kana => &[
+                "notosanscjk",
+                "notosansjp",
+                "yugoth",
+                "meiryo",
+                "msgothic",
+                "hiraginosans",
+                "droidsansfallback",
+            ],
+            Self::Khmer => &["notosanskhmer", "leelawui", "khmersangammn", "khmeros"],
+            Self::Tamil => &[
+                "notosanstamil",
+                "nirmala",
+                "latha",
+                "tamilsangammn",
+                "lohittamil",
+            ],
+            Self::Thai => &[
+                "notosansthai",
+                "leelawui",
+                "tahoma",
+                "thonburi",
+                "loma",
+                "garuda",
+            ],
+        }
+    }
+
+    /// Part of the (normalized) file name of fonts made for this script,
+    /// e.g. `notoserifthai`.
+    fn file_name_hint(self) -> &'static str {
+        match self {
+            Self::Arabic => "arabic",
+            Self::Armenian => "armenian",
+            Self::Bengali => "bengali",
+            Self::Cyrillic => "cyrillic",
+            Self::Devanagari => "devanagari",
+            Self::Ethiopic => "ethiopic",
+            Self::Georgian => "georgian",
+            Self::Greek => "greek",
+            Self::Han | Self::Hangul | Self::Kana => "cjk",
+            Self::Hebrew => "hebrew",
+            Self::Khmer => "khmer",
+            Self::Tamil => "tamil",
+            Self::Thai => "thai",
+        }
+    }
+
+    fn is_covered_by(self, font: &impl ab_glyph::Font) -> bool {
+        self.sample_chars().chars().all(|c| font.glyph_id(c).0 != 0)
+    }
+}
+
+/// The directories where the operating system keeps its fonts.
+///
+/// Only directories that exist are returned.
+fn system_font_dirs() -> Vec<PathBuf> {
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    let mut dirs: Vec<PathBuf> = vec![];
+
+    if cfg!(target_os = "windows") {
+        if let Some(windir) = std::env::var_os("WINDIR") {
+            dirs.push(Path::new(&windir).join("Fonts"));
+        }
+        if let Some(local_app_data) = std::env::var_os("LOCALAPPDATA") {
+            dirs.push(Path::new(&local_app_data).join("Microsoft/Windows/Fonts"));
+        }
+    } else if cfg!(target_os = "macos") {
+        dirs.push("/System/Library/Fonts".into());
+        dirs.push("/Library/Fonts".into());
+        dirs.extend(home.map(|home| home.join("Library/Fonts")));
+    } else {
+        // fontconfig follows the XDG base directory specification:
+        let data_home = std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|home| home.join(".local/share")));
+        dirs.extend(data_home.map(|data_home| data_home.join("fonts")));
+        dirs.extend(home.map(|home| home.join(".fonts")));
+        let data_dirs = std::env::var("XDG_DATA_DIRS")
+            .unwrap_or_else(|_| "/usr/local/share:/usr/share".to_owned());
+        dirs.extend(
+            data_dirs
+                .split(':')
+                .filter(|dir| !dir.is_empty())
+                .map(|dir| Path::new(dir).join("fonts")),
+        );
+    }
+
+    dirs.retain(|dir| dir.is_dir());
+    dirs.dedup();
+    dirs
+}
+
+/// All font files (`.ttf`, `.otf`, `.ttc` and `.otc`) in [`system_font_dirs`], including subdirectories.
+fn system_font_paths() -> Vec<PathBuf> {
+    crate::profile_function!();
+
+    fn visit(dir: &Path, depth: usize, paths: &mut Vec<PathBuf>) {
+        const MAX_DEPTH: usize = 8; // protection against symlink loops
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                if depth < MAX_DEPTH {
+                    visit(&path, depth + 1, paths);
+                }
+            } else if is_font_file(&path) {
+                paths.push(path);
+            }
+        }
+    }
+
+    let mut paths = vec![];
+    for dir in system_font_dirs() {
+        visit(&dir, 0, &mut paths);
+    }
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+fn is_font_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map_or(false, |ext| {
+            ["ttf", "otf", "ttc", "otc"]
+                .iter()
+                .any(|font_ext| ext.eq_ignore_ascii_case(font_ext))
+        })
+}
+
+/// Lowercase file name without extension, spaces, dashes or underscores,
+/// e.g. `notosanscjkregular` for `NotoSansCJK-Regular.ttc`.
+fn normalized_file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// We only want the regular style as a fallback.
+fn is_regular_style(normalized_stem: &str) -> bool {
+    const STYLES: [&str; 10] = [
+        "bold",
+        "italic",
+        "oblique",
+        "light",
+        "thin",
+        "medium",
+        "black",
+        "heavy",
+        "condensed",
+        "mono",
+    ];
+    !STYLES.iter().any(|style| normalized_stem.contains(style))
+}
+
+/// The faces in a font file, and which scripts each of them covers.
+struct FontFile {
+    bytes: Vec<u8>,
+
+    /// The index of each face in a font collection (`.ttc`), and the scripts it covers.
+    faces: Vec<(u32, Vec<Script>)>,
+}
+
+impl FontFile {
+    fn read(path: &Path) -> Option<Self> {
+        let bytes = std::fs::read(path).ok()?;
+
+        // A font collection has one or more faces, other font files have exactly one.
+        let faces = (0..)
+            .map_while(|index| {
+                let font = ab_glyph::FontRef::try_from_slice_and_index(&bytes, index).ok()?;
+                let scripts = Script::ALL
+                    .into_iter()
+                    .filter(|script| script.is_covered_by(&font))
+                    .collect();
+                Some((index, scripts))
+            })
+            .collect();
+
+        Some(Self { bytes, faces })
+    }
+
+    /// The first face that covers `script`.
+    fn face_covering(&self, script: Script) -> Option<&(u32, Vec<Script>)> {
+        self.faces
+            .iter()
+            .find(|(_, scripts)| scripts.contains(&script))
+    }
+}
+
+/// The system fonts to try for `script`, best first.
+///
+/// Well-known fonts for the script come first, then fonts named after the script, then all the others.
+fn candidates_for_script(script: Script, paths: &[(PathBuf, String)]) -> Vec<&Path> {
+    let preferred = script.preferred_fonts().iter().flat_map(|preferred| {
+        paths
+            .iter()
+            .filter(move |(_, stem)| stem.starts_with(preferred))
+    });
+    let hinted = paths
+        .iter()
+        .filter(|(_, stem)| stem.contains(script.file_name_hint()));
+
+    let mut candidates: Vec<&Path> = vec![];
+    for (path, _) in preferred.chain(hinted).chain(paths) {
+        if !candidates.contains(&path.as_path()) {
+            candidates.push(path);
+        }
+    }
+    candidates
+}
+
+impl FontDefinitions {
+    /// Add fonts installed on the system as fallbacks
+    /// for the scripts (Chinese, Cyrillic, Thai, …) that the current fonts don't cover.
+    ///
+    /// The fallbacks are added last to all font families, so they don't change how covered scripts look.
+    /// Returns the scripts that got a fallback font.
+    ///
+    /// This reads font files from disk, so only call this once, at startup.
+    ///
+    /// Requires the `system_fonts` feature.
+    ///
+    /// ```no_run
+    /// # use epaint::text::FontDefinitions;
+    /// let mut fonts = FontDefinitions::default();
+    /// fonts.add_system_fallbacks();
+    /// // egui_ctx.set_fonts(fonts);
+    /// ```
+    pub fn add_system_fallbacks(&mut self) -> Vec<Script> {
+        crate::profile_function!();
+
+        let mut uncovered: Vec<Script> = Script::ALL
+            .into_iter()
+            .filter(|&script| !self.covers(script))
+            .collect();
+        if uncovered.is_empty() {
+            return vec![];
+        }
+
+        let paths: Vec<(PathBuf, String)> = system_font_paths()
+            .into_iter()
+            .map(|path| {
+                let stem = normalized_file_stem(&path);
+                (path, stem)
+            })
+            .filter(|(_, stem)| is_regular_style(stem))
+            .collect();
+
+        // The faces of the files we have read so far, so we only read a file again if we are going to use it.
+        let mut read_faces: ahash::HashMap<&Path, Vec<(u32, Vec<Script>)>> = Default::default();
+        let mut covered = vec![];
+
+        while let Some(&script) = uncovered.first() {
+            let mut candidate = None;
+            for path in candidates_for_script(script, &paths) {
+                if let Some(faces) = read_faces.get(path) {
+                    if !faces.iter().any(|(_, scripts)| scripts.contains(&script)) {
+                        continue;
+                    }
+                }
+                let Some(file) = FontFile::read(path) else {
+                    read_faces.insert(path, vec![]);
+                    continue;
+                };
+                let face = file.face_covering(script).cloned();
+                read_faces.insert(path, file.faces);
+                if let Some((index, scripts)) = face {
+                    candidate = Some((path, index, file.bytes, scripts));
+                    break;
+                }
+            }
+
+            let Some((path, index, bytes, scripts)) = candidate else {
+                #[cfg(feature = "log")]
+                log::debug!("Found no system font for {script:?}");
+                uncovered.remove(0);
+                continue;
+            };
+
+            let scripts: Vec<Script> = scripts
+                .into_iter()
+                .filter(|script| uncovered.contains(script))
+                .collect();
+
+            #[cfg(feature = "log")]
+            log::debug!("Using {path:?} #{index} as fallback font for {scripts:?}");
+
+            let name = format!("system:{}#{index}", path.display());
+            self.font_data.insert(
+                name.clone(),
+                FontData {
+                    index,
+                    ..FontData::from_owned(bytes)
+                },
+            );
+            for fonts in self.families.values_mut() {
+                fonts.push(name.clone());
+            }
+
+            uncovered.retain(|script| !scripts.contains(script));
+            covered.extend(scripts);
+        }
+
+        covered.sort();
+        covered
+    }
+
+    /// Does the primary font family cover the given script?
+    fn covers(&self, script: Script) -> bool {
+        let Some(fonts) = self.families.get(&super::FontFamily::Proportional) else {
+            return false;
+        };
+        fonts.iter().any(|name| {
+            self.font_data.get(name).map_or(false, |data| {
+                ab_glyph::FontRef::try_from_slice_and_index(&data.font, data.index)
+                    .map_or(false, |font| script.is_covered_by(&font))
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "default_fonts")]
+    #[test]
+    fn test_default_fonts_cover() {
+        let fonts = FontDefinitions::default();
+        for script in [Script::Cyrillic, Script::Greek] {
+            assert!(fonts.covers(script), "{script:?}");
+        }
+        for script in [Script::Han, Script::Thai] {
+            assert!(!fonts.covers(script), "{script:?}");
+        }
+    }
+
+    #[test]
+    fn test_file_names() {
+        let stem = normalized_file_stem(Path::new("/usr/share/fonts/NotoSansCJK-Regular.ttc"));
+        assert_eq!(stem, "notosanscjkregular");
+        assert!(is_regular_style(&stem));
+        assert!(!is_regular_style(&normalized_file_stem(Path::new(
+            "NotoSansThai-Bold.ttf"
+        ))));
+        assert!(is_font_file(Path::new("msyh.TTC")));
+        assert!(!is_font_file(Path::new("fonts.conf")));
+
+        let paths: Vec<(PathBuf, String)> = [
+            "DejaVuSans.ttf",
+            "NotoSansThai-Regular.ttf",
+            "NotoSerifThai.ttf",
+        ]
+        .into_iter()
+        .map(|name| (PathBuf::from(name), normalized_file_stem(Path::new(name))))
+        .collect();
+        assert_eq!(
+            candidates_for_script(Script::Thai, &paths),
+            vec![
+                Path::new("NotoSansThai-Regular.ttf"),
+                Path::new("NotoSerifThai.ttf"),
+                Path::new("DejaVuSans.ttf"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_font_file() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("fonts/Ubuntu-Light.ttf");
+        let file = FontFile::read(&path).unwrap();
+        assert_eq!(file.faces.len(), 1, "Not a font collection");
+        assert_eq!(file.face_covering(Script::Cyrillic).unwrap().0, 0);
+        assert!(file.face_covering(Script::Han).is_none());
+    }
+}
@@ -1,11 +1,14 @@
-use std::ops::RangeInclusive;
+use std::ops::{Range, RangeInclusive};
 use std::sync::Arc;
 
 use emath::*;
 
 use crate::{stroke::PathStroke, text::font::Font, Color32, Mesh, Stroke, Vertex};
 
-use super::{FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, Row, RowVisuals};
+use super::{
+    bidi, line_break, BaseDirection, FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, Row,
+    RowVisuals, UvRect,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -39,6 +42,33 @@ impl PointScale {
 
 // ----------------------------------------------------------------------------
 
+/// A glyph that is painted together with one of the [`Row::glyphs`].
+#[derive(Copy, Clone, Debug)]
+struct ExtraGlyph {
+    /// Index into [`Row::glyphs`] of the glyph this is painted together with.
+    glyph_index: usize,
+
+    /// Position and size of the glyph in the font texture, in texels.
+    ///
+    /// The offset is relative to the position of the glyph at [`Self::glyph_index`].
+    uv_rect: UvRect,
+}
+
+/// A [`Row`] before it is tessellated.
+struct LaidOutRow {
+    row: Row,
+
+    /// Glyphs painted in addition to [`Row::glyphs`].
+    ///
+    /// Text shaping can turn a group of characters into more glyphs than there are characters.
+    /// The first glyphs go into [`Row::glyphs`], and the rest end up here.
+    /// Always empty without the `rustybuzz` feature.
+    extra_glyphs: Vec<ExtraGlyph>,
+
+    /// See [`RowVisuals::bidi_levels`].
+    bidi_levels: Vec<u8>,
+}
+
 /// Temporary storage before line-wrapping.
 #[derive(Clone)]
 struct Paragraph {
@@ -50,6 +80,12 @@ struct Paragraph {
 
     pub glyphs: Vec<Glyph>,
 
+    /// See [`LaidOutRow::extra_glyphs`].
+    pub extra_glyphs: Vec<ExtraGlyph>,
+
+    /// One for each glyph, or empty if the paragraph is all left-to-right.
+    pub bidi_levels: Vec<u8>,
+
     /// In case of an empty paragraph ("\n"), use this as height.
     pub empty_paragraph_height: f32,
 }
@@ -60,6 +96,8 @@ impl Paragraph {
             cursor_x: 0.0,
             section_index_at_start,
             glyphs: vec![],
+            extra_glyphs: vec![],
+            bidi_levels: vec![],
             empty_paragraph_height: 0.0,
         }
     }
@@ -91,13 +129,28 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
         layout_section(fonts, &job, section_index as u32, section, &mut paragraphs);
     }
 
+    for paragraph in &mut paragraphs {
+        resolve_bidi_levels(paragraph, job.base_direction);
+    }
+
     let point_scale = PointScale::new(fonts.pixels_per_point());
 
     let mut elided = false;
     let mut rows = rows_from_paragraphs(paragraphs, &job, &mut elided);
     if elided {
         if let Some(last_row) = rows.last_mut() {
-            replace_last_glyph_with_overflow_character(fonts, &job, last_row);
+            replace_last_glyph_with_overflow_character(fonts, &job, &mut last_row.row);
+
+            // Don't paint the extra glyphs of removed or replaced glyphs:
+            let num_glyphs = last_row.row.glyphs.len();
+            last_row
+                .extra_glyphs
+                .retain(|extra| extra.glyph_index + 1 < num_glyphs);
+
+            // An added overflow character gets the level of the glyph before it:
+            if let Some(&last_level) = last_row.bidi_levels.last() {
+                last_row.bidi_levels.resize(num_glyphs, last_level);
+            }
         }
     }
 
@@ -105,7 +158,7 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
 
     if justify || job.halign != Align::LEFT {
         let num_rows = rows.len();
-        for (i, row) in rows.iter_mut().enumerate() {
+        for (i, LaidOutRow { row, .. }) in rows.iter_mut().enumerate() {
             let is_last_row = i + 1 == num_rows;
             let justify_row = justify && !row.ends_with_newline && !is_last_row;
             halign_and_justify_row(
@@ -118,6 +171,13 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
         }
     }
 
+    for LaidOutRow {
+        row, bidi_levels, ..
+    } in &mut rows
+    {
+        reorder_bidi_row(fonts, &job, row, bidi_levels);
+    }
+
     // Calculate the Y positions and tessellate the text:
     galley_from_rows(point_scale, job, rows, elided)
 }
@@ -150,19 +210,55 @@ fn layout_section(
     paragraph.cursor_x += leading_space;
 
     let mut last_glyph_id = None;
+    let mut last_was_tabular = false;
+
+    let mut rest = &job.text[byte_range.clone()];
+
+    while let Some(chr) = rest.chars().next() {
+        #[cfg(feature = "rustybuzz")]
+        if let Some((font_impl, run_len)) = font.shaping_run(rest) {
+            if last_glyph_id.is_some() {
+                paragraph.cursor_x += extra_letter_spacing;
+            }
+            last_glyph_id = layout_shaped_run(
+                paragraph,
+                &font_impl,
+                &rest[..run_len],
+                line_height,
+                extra_letter_spacing,
+                format.font_features,
+                section_index,
+            )
+            .or(last_glyph_id);
+            paragraph.cursor_x = font.round_to_pixel(paragraph.cursor_x);
+            last_was_tabular = false;
+            rest = &rest[run_len..];
+            continue;
+        }
+
+        rest = &rest[chr.len_utf8()..];
 
-    for chr in job.text[byte_range.clone()].chars() {
         if job.break_on_newline && chr == '\n' {
             out_paragraphs.push(Paragraph::from_section_index(section_index));
             paragraph = out_paragraphs.last_mut().unwrap();
             paragraph.empty_paragraph_height = line_height; // TODO(emilk): replace this hack with actually including `\n` in the glyphs?
         } else {
-            let (font_impl, glyph_info) = font.font_impl_and_glyph_info(chr);
+            let (font_impl, mut glyph_info) = font.font_impl_and_glyph_info(chr);
+            let is_tabular = format.font_features.tabular_figures && chr.is_ascii_digit();
             if let Some(font_impl) = font_impl {
                 if let Some(last_glyph_id) = last_glyph_id {
-                    paragraph.cursor_x += font_impl.pair_kerning(last_glyph_id, glyph_info.id);
+                    if !is_tabular && !last_was_tabular {
+                        paragraph.cursor_x += font_impl.pair_kerning(last_glyph_id, glyph_info.id);
+                    }
                     paragraph.cursor_x += extra_letter_spacing;
                 }
+
+                if is_tabular {
+                    // Emulate tabular figures by centering each digit in the width of the widest one:
+                    let width = font_impl.tabular_figure_width();
+                    glyph_info.uv_rect.offset.x += (width - glyph_info.advance_width) / 2.0;
+                    glyph_info.advance_width = width;
+                }
             }
 
             paragraph.glyphs.push(Glyph {
@@ -177,6 +273,117 @@ fn layout_section(
             paragraph.cursor_x += glyph_info.advance_width;
             paragraph.cursor_x = font.round_to_pixel(paragraph.cursor_x);
             last_glyph_id = Some(glyph_info.id);
+            last_was_tabular = is_tabular;
+        }
+    }
+}
+
+/// Shape a run of text with a single font, and add one [`Glyph`] per character to the paragraph.
+///
+/// The glyphs of each cluster (e.g. a ligature) are painted by the first characters of the cluster,
+/// and the advance width of the cluster is divided evenly among its characters,
+/// so that the cursor can still be placed on each character.
+///
+/// Returns the id of the last glyph, if any.
+#[cfg(feature = "rustybuzz")]
+fn layout_shaped_run(
+    paragraph: &mut Paragraph,
+    font_impl: &crate::text::font::FontImpl,
+    run: &str,
+    line_height: f32,
+    extra_letter_spacing: f32,
+    font_features: super::FontFeatures,
+    section_index: u32,
+) -> Option<ab_glyph::GlyphId> {
+    let shaped = font_impl.shape(run, font_features);
+    let clusters = super::shaping::clusters(run, &shaped);
+
+    for (cluster_index, (byte_range, cluster_glyphs)) in clusters.iter().enumerate() {
+        if 0 < cluster_index {
+            paragraph.cursor_x += extra_letter_spacing;
+        }
+
+        let cluster_x = paragraph.cursor_x;
+        let advance: f32 = cluster_glyphs.iter().map(|glyph| glyph.x_advance).sum();
+        let num_chars = run[byte_range.clone()].chars().count();
+        let first_glyph_index = paragraph.glyphs.len();
+
+        for (i, chr) in run[byte_range.clone()].chars().enumerate() {
+            let char_advance = advance / num_chars as f32;
+            paragraph.glyphs.push(Glyph {
+                chr,
+                pos: pos2(cluster_x + i as f32 * char_advance, f32::NAN),
+                size: vec2(char_advance, line_height),
+                ascent: font_impl.ascent(),
+                uv_rect: super::UvRect::default(),
+                section_index,
+            });
+        }
+
+        let mut pen_x = cluster_x;
+        for (i, shaped_glyph) in cluster_glyphs.iter().enumerate() {
+            let glyph_index = first_glyph_index + i.min(num_chars - 1);
+            let glyph_x = paragraph.glyphs[glyph_index].pos.x;
+
+            let mut uv_rect = font_impl.shaped_glyph_info(shaped_glyph.glyph_id).uv_rect;
+            uv_rect.offset += vec2(pen_x - glyph_x, 0.0) + shaped_glyph.offset;
+
+            if i < num_chars {
+                paragraph.glyphs[glyph_index].uv_rect = uv_rect;
+            } else {
+                paragraph.extra_glyphs.push(ExtraGlyph {
+                    glyph_index,
+                    uv_rect,
+                });
+            }
+
+            pen_x += shaped_glyph.x_advance;
+        }
+
+        paragraph.cursor_x = cluster_x + advance;
+    }
+
+    shaped.last().map(|glyph| glyph.glyph_id)
+}
+
+/// Set [`Paragraph::bidi_levels`].
+fn resolve_bidi_levels(paragraph: &mut Paragraph, base_direction: BaseDirection) {
+    let chars = paragraph.glyphs.iter().map(|glyph| glyph.chr);
+    if base_direction != BaseDirection::RightToLeft && !bidi::has_rtl(chars.clone()) {
+        return; // Everything is left-to-right (level zero)
+    }
+
+    let chars: Vec<char> = chars.collect();
+    paragraph.bidi_levels = bidi::resolve_levels(&chars, base_direction);
+}
+
+/// Move the glyphs of a row to their visual positions, so that right-to-left text reads from right to left.
+///
+/// The glyphs are kept in logical order, and each glyph keeps its advance width.
+/// Characters like brackets are mirrored in right-to-left text.
+///
+/// Called after line breaking and justification, as the last step before calculating the Y positions.
+fn reorder_bidi_row(fonts: &mut FontsImpl, job: &LayoutJob, row: &mut Row, levels: &[u8]) {
+    if levels.iter().all(|&level| level == 0) {
+        return;
+    }
+
+    let advances: Vec<f32> = (0..row.glyphs.len())
+        .map(|i| match row.glyphs.get(i + 1) {
+            Some(next) => next.pos.x - row.glyphs[i].pos.x,
+            None => row.glyphs[i].size.x,
+        })
+        .collect();
+    let mut x = row.glyphs[0].pos.x;
+    for i in bidi::visual_order(levels) {
+        row.glyphs[i].pos.x = x;
+        x += advances[i];
+    }
+
+    for (glyph, level) in row.glyphs.iter_mut().zip(levels) {
+        if let Some(mirrored) = bidi::mirrored(glyph.chr).filter(|_| level % 2 == 1) {
+            let font = fonts.font(&job.sections[glyph.section_index as usize].format.font_id);
+            glyph.uv_rect = font.font_impl_and_glyph_info(mirrored).1.uv_rect;
         }
     }
 }
@@ -191,7 +398,7 @@ fn rows_from_paragraphs(
     paragraphs: Vec<Paragraph>,
     job: &LayoutJob,
     elided: &mut bool,
-) -> Vec<Row> {
+) -> Vec<LaidOutRow> {
     let num_paragraphs = paragraphs.len();
 
     let mut rows = vec![];
@@ -205,31 +412,39 @@ fn rows_from_paragraphs(
         let is_last_paragraph = (i + 1) == num_paragraphs;
 
         if paragraph.glyphs.is_empty() {
-            rows.push(Row {
-                section_index_at_start: paragraph.section_index_at_start,
-                glyphs: vec![],
-                visuals: Default::default(),
-                rect: Rect::from_min_size(
-                    pos2(paragraph.cursor_x, 0.0),
-                    vec2(0.0, paragraph.empty_paragraph_height),
-                ),
-                ends_with_newline: !is_last_paragraph,
+            rows.push(LaidOutRow {
+                row: Row {
+                    section_index_at_start: paragraph.section_index_at_start,
+                    glyphs: vec![],
+                    visuals: Default::default(),
+                    rect: Rect::from_min_size(
+                        pos2(paragraph.cursor_x, 0.0),
+                        vec2(0.0, paragraph.empty_paragraph_height),
+                    ),
+                    ends_with_newline: !is_last_paragraph,
+                },
+                extra_glyphs: vec![],
+                bidi_levels: vec![],
             });
         } else {
             let paragraph_max_x = paragraph.glyphs.last().unwrap().max_x();
             if paragraph_max_x <= job.wrap.max_width {
                 // Early-out optimization: the whole paragraph fits on one row.
                 let paragraph_min_x = paragraph.glyphs[0].pos.x;
-                rows.push(Row {
-                    section_index_at_start: paragraph.section_index_at_start,
-                    glyphs: paragraph.glyphs,
-                    visuals: Default::default(),
-                    rect: rect_from_x_range(paragraph_min_x..=paragraph_max_x),
-                    ends_with_newline: !is_last_paragraph,
+                rows.push(LaidOutRow {
+                    row: Row {
+                        section_index_at_start: paragraph.section_index_at_start,
+                        glyphs: paragraph.glyphs,
+                        visuals: Default::default(),
+                        rect: rect_from_x_range(paragraph_min_x..=paragraph_max_x),
+                        ends_with_newline: !is_last_paragraph,
+                    },
+                    extra_glyphs: paragraph.extra_glyphs,
+                    bidi_levels: paragraph.bidi_levels,
                 });
             } else {
                 line_break(&paragraph, job, &mut rows, elided);
-                rows.last_mut().unwrap().ends_with_newline = !is_last_paragraph;
+                rows.last_mut().unwrap().row.ends_with_newline = !is_last_paragraph;
             }
         }
     }
@@ -237,7 +452,12 @@ fn rows_from_paragraphs(
     rows
 }
 
-fn line_break(paragraph: &Paragraph, job: &LayoutJob, out_rows: &mut Vec<Row>, elided: &mut bool) {
+fn line_break(
+    paragraph: &Paragraph,
+    job: &LayoutJob,
+    out_rows: &mut Vec<LaidOutRow>,
+    elided: &mut bool,
+) {
     let wrap_width_margin = if job.round_output_size_to_nearest_ui_point {
         0.5
     } else {
@@ -247,6 +467,12 @@ fn line_break(paragraph: &Paragraph, job: &LayoutJob, out_rows: &mut Vec<Row>, e
     // Keeps track of good places to insert row break if we exceed `wrap_width`.
     let mut row_break_candidates = RowBreakCandidates::default();
 
+    let break_opportunities =
+        (job.wrap.unicode_line_breaks && !job.wrap.break_anywhere).then(|| {
+            let chars: Vec<char> = paragraph.glyphs.iter().map(|glyph| glyph.chr).collect();
+            line_break::break_opportunities(&chars)
+        });
+
     let mut first_row_indentation = paragraph.glyphs[0].pos.x;
     let mut row_start_x = 0.0;
     let mut row_start_idx = 0;
@@ -267,12 +493,16 @@ fn line_break(paragraph: &Paragraph, job: &LayoutJob, out_rows: &mut Vec<Row>, e
             {
                 // Allow the first row to be completely empty, because we know there will be more space on the next row:
                 // TODO(emilk): this records the height of this first row as zero, though that is probably fine since first_row_indentation usually comes with a first_row_min_height.
-                out_rows.push(Row {
-                    section_index_at_start: paragraph.section_index_at_start,
-                    glyphs: vec![],
-                    visuals: Default::default(),
-                    rect: rect_from_x_range(first_row_indentation..=first_row_indentation),
-                    ends_with_newline: false,
+                out_rows.push(LaidOutRow {
+                    row: Row {
+                        section_index_at_start: paragraph.section_index_at_start,
+                        glyphs: vec![],
+                        visuals: Default::default(),
+                        rect: rect_from_x_range(first_row_indentation..=first_row_indentation),
+                        ends_with_newline: false,
+                    },
+                    extra_glyphs: vec![],
+                    bidi_levels: vec![],
                 });
                 row_start_x += first_row_indentation;
                 first_row_indentation = 0.0;
@@ -291,12 +521,22 @@ fn line_break(paragraph: &Paragraph, job: &LayoutJob, out_rows: &mut Vec<Row>, e
                 let paragraph_min_x = glyphs[0].pos.x;
                 let paragraph_max_x = glyphs.last().unwrap().max_x();
 
-                out_rows.push(Row {
-                    section_index_at_start,
-                    glyphs,
-                    visuals: Default::default(),
-                    rect: rect_from_x_range(paragraph_min_x..=paragraph_max_x),
-                    ends_with_newline: false,
+                out_rows.push(LaidOutRow {
+                    row: Row {
+                        section_index_at_start,
+                        glyphs,
+                        visuals: Default::default(),
+                        rect: rect_from_x_range(paragraph_min_x..=paragraph_max_x),
+                        ends_with_newline: false,
+                    },
+                    extra_glyphs: extra_glyphs_in_range(
+                        paragraph,
+                        row_start_idx..last_kept_index + 1,
+                    ),
+                    bidi_levels: bidi_levels_in_range(
+                        paragraph,
+                        row_start_idx..last_kept_index + 1,
+                    ),
                 });
 
                 // Start a new row:
@@ -308,7 +548,13 @@ fn line_break(paragraph: &Paragraph, job: &LayoutJob, out_rows: &mut Vec<Row>, e
             }
         }
 
-        row_break_candidates.add(i, &paragraph.glyphs[i..]);
+        row_break_candidates.add(
+            i,
+            &paragraph.glyphs[i..],
+            break_opportunities
+                .as_ref()
+                .map(|opportunities| opportunities[i]),
+        );
     }
 
     if row_start_idx < paragraph.glyphs.len() {
@@ -330,17 +576,45 @@ fn line_break(paragraph: &Paragraph, job: &LayoutJob, out_rows: &mut Vec<Row>, e
             let paragraph_min_x = glyphs[0].pos.x;
             let paragraph_max_x = glyphs.last().unwrap().max_x();
 
-            out_rows.push(Row {
-                section_index_at_start,
-                glyphs,
-                visuals: Default::default(),
-                rect: rect_from_x_range(paragraph_min_x..=paragraph_max_x),
-                ends_with_newline: false,
+            out_rows.push(LaidOutRow {
+                row: Row {
+                    section_index_at_start,
+                    glyphs,
+                    visuals: Default::default(),
+                    rect: rect_from_x_range(paragraph_min_x..=paragraph_max_x),
+                    ends_with_newline: false,
+                },
+                extra_glyphs: extra_glyphs_in_range(
+                    paragraph,
+                    row_start_idx..paragraph.glyphs.len(),
+                ),
+                bidi_levels: bidi_levels_in_range(paragraph, row_start_idx..paragraph.glyphs.len()),
             });
         }
     }
 }
 
+/// The [`ExtraGlyph`]s of the glyphs in `glyph_range`, relative to the start of the range.
+fn extra_glyphs_in_range(paragraph: &Paragraph, glyph_range: Range<usize>) -> Vec<ExtraGlyph> {
+    paragraph
+        .extra_glyphs
+        .iter()
+        .filter(|extra| glyph_range.contains(&extra.glyph_index))
+        .map(|extra| ExtraGlyph {
+            glyph_index: extra.glyph_index - glyph_range.start,
+            ..*extra
+        })
+        .collect()
+}
+
+/// The [`Paragraph::bidi_levels`] of the glyphs in `glyph_range`.
+fn bidi_levels_in_range(paragraph: &Paragraph, glyph_range: Range<usize>) -> Vec<u8> {
+    paragraph
+        .bidi_levels
+        .get(glyph_range)
+        .map_or_else(Vec::new, <[u8]>::to_vec)
+}
+
 /// Trims the last glyphs in the row and replaces it with an overflow character (e.g. `…`).
 ///
 /// Called before we have any Y coordinates.
@@ -578,14 +852,14 @@ fn halign_and_justify_row(
 fn galley_from_rows(
     point_scale: PointScale,
     job: Arc<LayoutJob>,
-    mut rows: Vec<Row>,
+    mut rows: Vec<LaidOutRow>,
     elided: bool,
 ) -> Galley {
     let mut first_row_min_height = job.first_row_min_height;
     let mut cursor_y = 0.0;
     let mut min_x: f32 = 0.0;
     let mut max_x: f32 = 0.0;
-    for row in &mut rows {
+    for LaidOutRow { row, .. } in &mut rows {
         let mut line_height = first_row_min_height.max(row.rect.height());
         let mut row_ascent = 0.0f32;
         first_row_min_height = 0.0;
@@ -629,15 +903,48 @@ fn galley_from_rows(
     let mut num_vertices = 0;
     let mut num_indices = 0;
 
-    for row in &mut rows {
-        row.visuals = tessellate_row(point_scale, &job, &format_summary, row);
-        mesh_bounds = mesh_bounds.union(row.visuals.mesh_bounds);
-        num_vertices += row.visuals.mesh.vertices.len();
-        num_indices += row.visuals.mesh.indices.len();
-    }
+    let rows: Vec<Row> = rows
+        .into_iter()
+        .map(
+            |LaidOutRow {
+                 mut row,
+                 extra_glyphs,
+                 bidi_levels,
+             }| {
+                row.visuals = tessellate_row(
+                    point_scale,
+                    &job,
+                    &format_summary,
+                    &row,
+                    &extra_glyphs,
+                    bidi_levels,
+                );
+                mesh_bounds = mesh_bounds.union(row.visuals.mesh_bounds);
+                num_vertices += row.visuals.mesh.vertices.len();
+                num_indices += row.visuals.mesh.indices.len();
+                row
+            },
+        )
+        .collect();
+
+    let rect = round_galley_rect(
+        &job,
+        Rect::from_min_max(pos2(min_x, 0.0), pos2(max_x, cursor_y)),
+    );
 
-    let mut rect = Rect::from_min_max(pos2(min_x, 0.0), pos2(max_x, cursor_y));
+    Galley {
+        job,
+        rows,
+        elided,
+        rect,
+        mesh_bounds,
+        num_vertices,
+        num_indices,
+        pixels_per_point: point_scale.pixels_per_point,
+    }
+}
 
+fn round_galley_rect(job: &LayoutJob, mut rect: Rect) -> Rect {
     if job.round_output_size_to_nearest_ui_point {
         let did_exceed_wrap_width_by_a_lot = rect.width() > job.wrap.max_width + 1.0;
 
@@ -655,15 +962,181 @@ fn galley_from_rows(
         }
     }
 
+    rect
+}
+
+// ----------------------------------------------------------------------------
+// Laying out large texts one paragraph at a time.
+
+/// Texts with at least this many bytes are laid out one paragraph at a time by the galley cache,
+/// so that editing one paragraph of a large text only needs to lay out that paragraph again.
+pub(crate) const MIN_BYTES_FOR_PARAGRAPH_LAYOUT: usize = 1024;
+
+/// Split `job` into one job per paragraph (each ending with a `\n`, except maybe the last).
+///
+/// Each job comes with the index of its first section in `job.sections`.
+///
+/// Returns `None` if `job` should be laid out as a whole, e.g. because it is small,
+/// can be elided, or has sections that are not in order.
+pub(crate) fn split_into_paragraphs(job: &LayoutJob) -> Option<Vec<(LayoutJob, u32)>> {
+    if job.text.len() < MIN_BYTES_FOR_PARAGRAPH_LAYOUT
+        || !job.break_on_newline
+        || job.wrap.max_rows != usize::MAX
+    {
+        return None;
+    }
+
+    // The sections must cover the text in order, so we can divide them between the paragraphs:
+    let mut next_section_start = 0;
+    for section in &job.sections {
+        if section.byte_range.start != next_section_start
+            || section.byte_range.end <= section.byte_range.start
+        {
+            return None;
+        }
+        next_section_start = section.byte_range.end;
+    }
+    if next_section_start != job.text.len() {
+        return None;
+    }
+
+    let mut paragraphs = vec![];
+    let mut paragraph_start = 0;
+    let mut section_index = 0;
+
+    for paragraph_text in job.text.split_inclusive('\n') {
+        let paragraph_end = paragraph_start + paragraph_text.len();
+        let mut first_section_index = section_index;
+        let mut sections = vec![];
+
+        if 0 < paragraph_start && job.sections[section_index].byte_range.start == paragraph_start {
+            // Like in `layout`, a paragraph starts in the section of the preceding newline:
+            first_section_index -= 1;
+            sections.push(LayoutSection {
+                leading_space: 0.0,
+                byte_range: 0..0,
+                format: job.sections[first_section_index].format.clone(),
+            });
+        }
+
+        while let Some(section) = job.sections.get(section_index) {
+            if paragraph_end <= section.byte_range.start {
+                break;
+            }
+            let starts_in_paragraph = paragraph_start <= section.byte_range.start;
+            sections.push(LayoutSection {
+                leading_space: if starts_in_paragraph {
+                    section.leading_space
+                } else {
+                    0.0
+                },
+                byte_range: section.byte_range.start.max(paragraph_start) - paragraph_start
+                    ..section.byte_range.end.min(paragraph_end) - paragraph_start,
+                format: section.format.clone(),
+            });
+            if paragraph_end < section.byte_range.end {
+                break; // The section continues in the next paragraph
+            }
+            section_index += 1;
+        }
+
+        paragraphs.push((
+            LayoutJob {
+                text: paragraph_text.to_owned(),
+                sections,
+                wrap: job.wrap.clone(),
+                first_row_min_height: if paragraph_start == 0 {
+                    job.first_row_min_height
+                } else {
+                    0.0
+                },
+                break_on_newline: true,
+                halign: job.halign,
+                justify: job.justify,
+                base_direction: job.base_direction,
+                // This also affects where rows are wrapped, so it must be the same as for `job`:
+                round_output_size_to_nearest_ui_point: job.round_output_size_to_nearest_ui_point,
+            },
+            first_section_index as u32,
+        ));
+
+        paragraph_start = paragraph_end;
+    }
+
+    (2 <= paragraphs.len()).then_some(paragraphs)
+}
+
+/// Put together the galleys of the paragraphs of `job`, as split by [`split_into_paragraphs`].
+///
+/// Each galley comes with the index of its first section in `job.sections`.
+pub(crate) fn galley_from_paragraphs(
+    job: Arc<LayoutJob>,
+    paragraphs: &[(Arc<Galley>, u32)],
+    pixels_per_point: f32,
+) -> Galley {
+    crate::profile_function!();
+
+    let mut rows = vec![];
+    let mut cursor_y = 0.0;
+    let mut min_x: f32 = 0.0;
+    let mut max_x: f32 = 0.0;
+    let mut mesh_bounds = Rect::NOTHING;
+    let mut num_vertices = 0;
+    let mut num_indices = 0;
+
+    for (i, (galley, first_section_index)) in paragraphs.iter().enumerate() {
+        let is_last_paragraph = i + 1 == paragraphs.len();
+
+        // Each paragraph but the last ends with a newline, followed by an empty row.
+        // That row is where the next paragraph starts.
+        // We use the rows rather than `galley.rect`, which may have been rounded.
+        let (paragraph_rows, paragraph_height) = match galley.rows.split_last() {
+            Some((next_row, paragraph_rows)) if !is_last_paragraph => {
+                (paragraph_rows, next_row.rect.min.y)
+            }
+            _ => (
+                &galley.rows[..],
+                galley.rows.last().map_or(0.0, |row| row.rect.max.y),
+            ),
+        };
+
+        let delta = vec2(0.0, cursor_y);
+        for row in paragraph_rows {
+            let mut row = row.clone();
+            row.rect = row.rect.translate(delta);
+            row.section_index_at_start += first_section_index;
+            for glyph in &mut row.glyphs {
+                glyph.pos.y += cursor_y;
+                glyph.section_index += first_section_index;
+            }
+            row.visuals.mesh.translate(delta);
+            row.visuals.mesh_bounds = row.visuals.mesh_bounds.translate(delta);
+
+            min_x = min_x.min(row.rect.min.x);
+            max_x = max_x.max(row.rect.max.x);
+            mesh_bounds = mesh_bounds.union(row.visuals.mesh_bounds);
+            num_vertices += row.visuals.mesh.vertices.len();
+            num_indices += row.visuals.mesh.indices.len();
+            rows.push(row);
+        }
+
+        cursor_y += paragraph_height;
+    }
+
+    let rect = round_galley_rect(
+        &job,
+        Rect::from_min_max(pos2(min_x, 0.0), pos2(max_x, cursor_y)),
+    );
+
     Galley {
         job,
         rows,
-        elided,
+        elided: false,
         rect,
         mesh_bounds,
         num_vertices,
         num_indices,
-        pixels_per_point: point_scale.pixels_per_point,
+        pixels_per_point,
     }
 }
 
@@ -689,6 +1162,8 @@ fn tessellate_row(
     job: &LayoutJob,
     format_summary: &FormatSummary,
     row: &Row,
+    extra_glyphs: &[ExtraGlyph],
+    bidi_levels: Vec<u8>,
 ) -> RowVisuals {
     if row.glyphs.is_empty() {
         return Default::default();
@@ -704,7 +1179,7 @@ fn tessellate_row(
     }
 
     let glyph_vertex_start = mesh.vertices.len();
-    tessellate_glyphs(point_scale, job, row, &mut mesh);
+    tessellate_glyphs(point_scale, job, row, extra_glyphs, &mut mesh);
     let glyph_vertex_end = mesh.vertices.len();
 
     if format_summary.any_underline {
@@ -731,6 +1206,7 @@ fn tessellate_row(
         mesh,
         mesh_bounds,
         glyph_vertex_range: glyph_vertex_start..glyph_vertex_end,
+        bidi_levels,
     }
 }
 
@@ -779,9 +1255,18 @@ fn add_row_backgrounds(job: &LayoutJob, row: &Row, mesh: &mut Mesh) {
     end_run(run_start.take(), last_rect.right());
 }
 
-fn tessellate_glyphs(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh: &mut Mesh) {
-    for glyph in &row.glyphs {
-        let uv_rect = glyph.uv_rect;
+fn tessellate_glyphs(
+    point_scale: PointScale,
+    job: &LayoutJob,
+    row: &Row,
+    extra_glyphs: &[ExtraGlyph],
+    mesh: &mut Mesh,
+) {
+    let glyphs = row.glyphs.iter().map(|glyph| (glyph, glyph.uv_rect));
+    let extra_glyphs =
+        (extra_glyphs.iter()).map(|extra| (&row.glyphs[extra.glyph_index], extra.uv_rect));
+
+    for (glyph, uv_rect) in glyphs.chain(extra_glyphs) {
         if !uv_rect.is_nothing() {
             let mut left_top = glyph.pos + uv_rect.offset;
             left_top.x = point_scale.round_to_pixel(left_top.x);
@@ -897,9 +1382,13 @@ fn add_hline(point_scale: PointScale, [start, stop]: [Pos2; 2], stroke: Stroke,
 // ----------------------------------------------------------------------------
 
 /// Keeps track of good places to break a long row of text.
-/// Will focus primarily on spaces, secondarily on things like `-`
+/// Will focus primarily on spaces (or Unicode line break opportunities), secondarily on things like `-`
 #[derive(Clone, Copy, Default)]
 struct RowBreakCandidates {
+    /// A line break opportunity according to UAX #14,
+    /// if [`super::TextWrapping::unicode_line_breaks`] is on.
+    unicode: Option<usize>,
+
     /// Breaking at ` ` or other whitespace
     /// is always the primary candidate.
     space: Option<usize>,
@@ -924,10 +1413,20 @@ struct RowBreakCandidates {
 }
 
 impl RowBreakCandidates {
-    fn add(&mut self, index: usize, glyphs: &[Glyph]) {
+    /// `break_opportunity` is whether or not UAX #14 allows a break after the glyph,
+    /// or `None` to use our own simpler rules.
+    fn add(&mut self, index: usize, glyphs: &[Glyph], break_opportunity: Option<bool>) {
         let chr = glyphs[0].chr;
         const NON_BREAKING_SPACE: char = '\u{A0}';
-        if chr.is_whitespace() && chr != NON_BREAKING_SPACE {
+        if let Some(break_opportunity) = break_opportunity {
+            if break_opportunity {
+                self.unicode = Some(index);
+            } else if chr == '-' {
+                self.dash = Some(index);
+            } else if chr.is_ascii_punctuation() {
+                self.punctuation = Some(index);
+            }
+        } else if chr.is_whitespace() && chr != NON_BREAKING_SPACE {
             self.space = Some(index);
         } else if is_cjk(chr) && (glyphs.len() == 1 || is_cjk_break_allowed(glyphs[1].chr)) {
             self.cjk = Some(index);
@@ -942,7 +1441,7 @@ impl RowBreakCandidates {
     }
 
     fn word_boundary(&self) -> Option<usize> {
-        [self.space, self.cjk, self.pre_cjk]
+        [self.unicode, self.space, self.cjk, self.pre_cjk]
             .into_iter()
             .max()
             .flatten()
@@ -969,6 +1468,7 @@ impl RowBreakCandidates {
 
     fn forget_before_idx(&mut self, index: usize) {
         let Self {
+            unicode,
             space,
             cjk,
             pre_cjk,
@@ -976,6 +1476,9 @@ impl RowBreakCandidates {
             punctuation,
             any,
         } = self;
+        if unicode.map_or(false, |s| s < index) {
+            *unicode = None;
+        }
         if space.map_or(false, |s| s < index) {
             *space = None;
         }
@@ -1037,6 +1540,40 @@ mod tests {
         assert_eq!(galley.rows.len(), 1);
     }
 
+    #[test]
+    fn test_glyph_metrics_match_layout() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let font_id = FontId::proportional(14.0);
+        let layout_job =
+            LayoutJob::simple_singleline("W".into(), font_id.clone(), crate::Color32::WHITE);
+        let galley = layout(&mut fonts, layout_job.into());
+        let glyph = &galley.rows[0].glyphs[0];
+
+        let metrics = fonts.glyph_metrics(&font_id, 'W');
+        assert_eq!(metrics.advance_width, glyph.size.x);
+        assert_eq!(metrics.ascent, glyph.ascent);
+        assert_eq!(metrics.uv_rect, glyph.uv_rect);
+    }
+
+    #[test]
+    #[cfg(not(feature = "rustybuzz"))] // Then it is up to the font
+    fn test_tabular_figures() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let text_format = TextFormat {
+            font_features: FontFeatures::TABULAR_FIGURES,
+            ..Default::default()
+        };
+        let widths: Vec<f32> = ["1111.11", "8080.00", "4747.47"]
+            .into_iter()
+            .map(|text| {
+                let job = LayoutJob::single_section(text.into(), text_format.clone());
+                layout(&mut fonts, job.into()).rect.width()
+            })
+            .collect();
+        assert_eq!(widths[0], widths[1]);
+        assert_eq!(widths[0], widths[2]);
+    }
+
     #[test]
     fn test_truncate_with_newline() {
         // No matter where we wrap, we should be appending the newline character.
@@ -1113,4 +1650,77 @@ mod tests {
             vec!["日本語とEnglish", "の混在した文章"]
         );
     }
+
+    #[test]
+    fn test_unicode_line_breaks() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        for max_width in (60..200).step_by(5) {
+            let mut layout_job = LayoutJob::single_section(
+                "「東京」は、日本の首都です。ちょっと(test)です!".into(),
+                TextFormat::default(),
+            );
+            layout_job.wrap.max_width = max_width as f32;
+            let galley = layout(&mut fonts, layout_job.into());
+            for row in &galley.rows[1..] {
+                let first = row.glyphs[0].chr;
+                assert!(
+                    !"」、。っ)!".contains(first),
+                    "Row starts with {first:?} when wrapping at {max_width}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_paragraph_layout_matches_whole_layout() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+
+        let mut job = LayoutJob::default();
+        job.wrap.max_width = 120.0;
+        for i in 0..40 {
+            job.append(
+                &format!("Paragraph {i} is long enough to wrap onto more rows.\n"),
+                0.0,
+                TextFormat::default(),
+            );
+            // A section that starts in one paragraph and ends in the next:
+            job.append(&format!("Split {i}\nsection "), 4.0, TextFormat::default());
+        }
+        job.append("The end", 0.0, TextFormat::default());
+
+        let paragraphs = split_into_paragraphs(&job).expect("Should be split into paragraphs");
+        assert_eq!(paragraphs.len(), 81);
+
+        let paragraph_galleys: Vec<_> = paragraphs
+            .into_iter()
+            .map(|(paragraph_job, first_section_index)| {
+                let galley = Arc::new(layout(&mut fonts, paragraph_job.into()));
+                (galley, first_section_index)
+            })
+            .collect();
+        let job = Arc::new(job);
+        let combined = galley_from_paragraphs(job.clone(), &paragraph_galleys, 1.0);
+        let whole = layout(&mut fonts, job);
+
+        assert_eq!(combined.rect, whole.rect);
+        assert_eq!(combined.text(), whole.text());
+        assert_eq!(combined.rows.len(), whole.rows.len());
+        for (combined_row, whole_row) in combined.rows.iter().zip(&whole.rows) {
+            assert_eq!(combined_row.text(), whole_row.text());
+            assert_eq!(combined_row.rect, whole_row.rect);
+            assert_eq!(
+                combined_row.section_index_at_start,
+                whole_row.section_index_at_start
+            );
+            for (a, b) in combined_row.glyphs.iter().zip(&whole_row.glyphs) {
+                assert_eq!(a.section_index, b.section_index);
+                assert!(
+                    (a.pos - b.pos).length() < 1e-3,
+                    "{:?} != {:?}",
+                    a.pos,
+                    b.pos
+                );
+            }
+        }
+    }
 }
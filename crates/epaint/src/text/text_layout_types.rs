@@ -75,6 +75,11 @@ pub struct LayoutJob {
     /// Justify text so that word-wrapped rows fill the whole [`TextWrapping::max_width`].
     pub justify: bool,
 
+    /// The base direction of each paragraph, used when mixing left-to-right and right-to-left text.
+    ///
+    /// Default: [`BaseDirection::Auto`].
+    pub base_direction: BaseDirection,
+
     /// Rounding to the closest ui point (not pixel!) allows the rest of the
     /// layout code to run on perfect integers, avoiding rounding errors.
     pub round_output_size_to_nearest_ui_point: bool,
@@ -91,6 +96,7 @@ impl Default for LayoutJob {
             break_on_newline: true,
             halign: Align::LEFT,
             justify: false,
+            base_direction: BaseDirection::Auto,
             round_output_size_to_nearest_ui_point: true,
         }
     }
@@ -185,6 +191,7 @@ impl std::hash::Hash for LayoutJob {
             break_on_newline,
             halign,
             justify,
+            base_direction,
             round_output_size_to_nearest_ui_point,
         } = self;
 
@@ -195,12 +202,35 @@ impl std::hash::Hash for LayoutJob {
         break_on_newline.hash(state);
         halign.hash(state);
         justify.hash(state);
+        base_direction.hash(state);
         round_output_size_to_nearest_ui_point.hash(state);
     }
 }
 
 // ----------------------------------------------------------------------------
 
+/// The base direction of a paragraph of text.
+///
+/// This decides how runs of left-to-right and right-to-left text are ordered,
+/// e.g. whether an English word at the end of a Hebrew sentence ends up on the left or the right.
+///
+/// See [`LayoutJob::base_direction`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum BaseDirection {
+    /// Use the direction of the first letter in each paragraph.
+    #[default]
+    Auto,
+
+    /// Left-to-right, e.g. English.
+    LeftToRight,
+
+    /// Right-to-left, e.g. Hebrew or Arabic.
+    RightToLeft,
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct LayoutSection {
@@ -266,6 +296,8 @@ pub struct TextFormat {
     /// can get the effect of raised text.
     pub valign: Align,
     // TODO(emilk): lowered
+    /// OpenType features to use when picking glyphs, e.g. tabular figures.
+    pub font_features: FontFeatures,
 }
 
 impl Default for TextFormat {
@@ -281,6 +313,7 @@ impl Default for TextFormat {
             underline: Stroke::NONE,
             strikethrough: Stroke::NONE,
             valign: Align::BOTTOM,
+            font_features: FontFeatures::default(),
         }
     }
 }
@@ -298,6 +331,7 @@ impl std::hash::Hash for TextFormat {
             underline,
             strikethrough,
             valign,
+            font_features,
         } = self;
         font_id.hash(state);
         emath::OrderedFloat(*extra_letter_spacing).hash(state);
@@ -310,6 +344,7 @@ impl std::hash::Hash for TextFormat {
         underline.hash(state);
         strikethrough.hash(state);
         valign.hash(state);
+        font_features.hash(state);
     }
 }
 
@@ -326,6 +361,50 @@ impl TextFormat {
 
 // ----------------------------------------------------------------------------
 
+/// OpenType features to turn on for a section of text.
+///
+/// With the `rustybuzz` feature these are passed on to the shaper,
+/// and only have an effect if the font supports them.
+/// Without it, [`Self::tabular_figures`] is emulated by giving all digits the same width,
+/// and [`Self::small_caps`] is ignored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FontFeatures {
+    /// Use digits that all have the same width (`tnum`),
+    /// so that columns of numbers line up and changing numbers don't jitter.
+    pub tabular_figures: bool,
+
+    /// Replace lowercase letters with small capitals (`smcp`).
+    pub small_caps: bool,
+}
+
+impl FontFeatures {
+    /// No features turned on.
+    pub const NONE: Self = Self {
+        tabular_figures: false,
+        small_caps: false,
+    };
+
+    /// Only [`Self::tabular_figures`].
+    pub const TABULAR_FIGURES: Self = Self {
+        tabular_figures: true,
+        small_caps: false,
+    };
+
+    /// The OpenType tags of the features that are turned on.
+    pub fn tags(&self) -> impl Iterator<Item = [u8; 4]> {
+        let Self {
+            tabular_figures,
+            small_caps,
+        } = *self;
+        [(tabular_figures, *b"tnum"), (small_caps, *b"smcp")]
+            .into_iter()
+            .filter_map(|(enabled, tag)| enabled.then_some(tag))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// How to wrap and elide text.
 ///
 /// This enum is used in high-level APIs where providing a [`TextWrapping`] is too verbose.
@@ -383,6 +462,16 @@ pub struct TextWrapping {
     /// whenever [`Self::max_rows`] is set to `1`.
     pub break_anywhere: bool,
 
+    /// If `true` (default): break rows following the [Unicode Line Breaking Algorithm](https://www.unicode.org/reports/tr14/).
+    ///
+    /// This allows breaking Chinese and Japanese text between characters,
+    /// and avoids breaking before closing punctuation (e.g. `。` or `)`) or within numbers (e.g. `3.14`).
+    ///
+    /// If `false`: break mainly at whitespace, and before and after CJK characters.
+    ///
+    /// Has no effect if [`Self::break_anywhere`] is `true`.
+    pub unicode_line_breaks: bool,
+
     /// Character to use to represent elided text.
     ///
     /// The default is `…`.
@@ -398,11 +487,13 @@ impl std::hash::Hash for TextWrapping {
             max_width,
             max_rows,
             break_anywhere,
+            unicode_line_breaks,
             overflow_character,
         } = self;
         emath::OrderedFloat(*max_width).hash(state);
         max_rows.hash(state);
         break_anywhere.hash(state);
+        unicode_line_breaks.hash(state);
         overflow_character.hash(state);
     }
 }
@@ -413,6 +504,7 @@ impl Default for TextWrapping {
             max_width: f32::INFINITY,
             max_rows: usize::MAX,
             break_anywhere: false,
+            unicode_line_breaks: true,
             overflow_character: Some('…'),
         }
     }
@@ -523,7 +615,9 @@ pub struct Row {
     /// This is included in case there are no glyphs
     pub section_index_at_start: u32,
 
-    /// One for each `char`.
+    /// One for each `char`, in logical order.
+    ///
+    /// With right-to-left text, the glyphs are not necessarily in order of their x position.
     pub glyphs: Vec<Glyph>,
 
     /// Logical bounding rectangle based on font heights etc.
@@ -558,6 +652,12 @@ pub struct RowVisuals {
     ///
     /// The glyph vertices comes before backgrounds (if any), and after any underlines and strikethrough.
     pub glyph_vertex_range: Range<usize>,
+
+    /// The bidirectional embedding level of each of the [`Row::glyphs`]:
+    /// even for left-to-right text, odd for right-to-left text.
+    ///
+    /// Empty if the whole row is left-to-right. See [`LayoutJob::base_direction`].
+    pub bidi_levels: Vec<u8>,
 }
 
 impl Default for RowVisuals {
@@ -566,6 +666,7 @@ impl Default for RowVisuals {
             mesh: Default::default(),
             mesh_bounds: Rect::NOTHING,
             glyph_vertex_range: 0..0,
+            bidi_levels: Vec::new(),
         }
     }
 }
@@ -642,9 +743,38 @@ impl Row {
         self.rect.height()
     }
 
+    /// Does this row contain any right-to-left text?
+    #[inline]
+    pub fn has_rtl(&self) -> bool {
+        self.visuals.bidi_levels.iter().any(|level| level % 2 == 1)
+    }
+
+    /// Is the glyph at `index` part of right-to-left text, e.g. Hebrew or Arabic?
+    #[inline]
+    pub fn is_rtl(&self, index: usize) -> bool {
+        self.visuals
+            .bidi_levels
+            .get(index)
+            .map_or(false, |level| level % 2 == 1)
+    }
+
+    /// The x coordinate of a cursor placed before the glyph at `index`.
+    fn leading_x(&self, index: usize) -> f32 {
+        let glyph = &self.glyphs[index];
+        if self.is_rtl(index) {
+            glyph.max_x()
+        } else {
+            glyph.pos.x
+        }
+    }
+
     /// Closest char at the desired x coordinate.
     /// Returns something in the range `[0, char_count_excluding_newline()]`.
     pub fn char_at(&self, desired_x: f32) -> usize {
+        if self.has_rtl() {
+            return self.char_at_bidi(desired_x);
+        }
+
         for (i, glyph) in self.glyphs.iter().enumerate() {
             if desired_x < glyph.logical_rect().center().x {
                 return i;
@@ -653,9 +783,40 @@ impl Row {
         self.char_count_excluding_newline()
     }
 
+    /// [`Self::char_at`] for rows where the glyphs are not in visual order.
+    fn char_at_bidi(&self, desired_x: f32) -> usize {
+        let distance = |glyph: &Glyph| {
+            if desired_x < glyph.pos.x {
+                glyph.pos.x - desired_x
+            } else {
+                (desired_x - glyph.max_x()).max(0.0)
+            }
+        };
+        let closest = self
+            .glyphs
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)));
+        let Some((i, glyph)) = closest else {
+            return 0;
+        };
+
+        // Which side of the glyph are we on?
+        let is_on_right_half = glyph.logical_rect().center().x <= desired_x;
+        if is_on_right_half == self.is_rtl(i) {
+            i
+        } else {
+            i + 1
+        }
+    }
+
+    /// The x coordinate of a cursor placed before the char at `column`.
     pub fn x_offset(&self, column: usize) -> f32 {
-        if let Some(glyph) = self.glyphs.get(column) {
-            glyph.pos.x
+        let num_glyphs = self.glyphs.len();
+        if column < num_glyphs {
+            self.leading_x(column)
+        } else if 0 < num_glyphs && self.is_rtl(num_glyphs - 1) {
+            self.glyphs[num_glyphs - 1].pos.x
         } else {
             self.rect.right()
         }
@@ -678,6 +839,20 @@ impl Galley {
     pub fn size(&self) -> Vec2 {
         self.rect.size()
     }
+
+    /// The y coordinate of the baseline of the first row, relative to the galley.
+    ///
+    /// Useful for aligning text of different sizes.
+    pub fn first_row_baseline(&self) -> f32 {
+        let Some(row) = self.rows.first() else {
+            return 0.0;
+        };
+        row.glyphs
+            .iter()
+            .map(|glyph| glyph.pos.y)
+            .reduce(f32::max)
+            .unwrap_or(row.rect.bottom())
+    }
 }
 
 impl AsRef<str> for Galley {
@@ -1048,7 +1223,14 @@ impl Galley {
 
 /// ## Cursor positions
 impl Galley {
+    /// Move the cursor one character to the left.
+    ///
+    /// Within rows with right-to-left text, this moves visually to the left, not to the previous character.
     pub fn cursor_left_one_character(&self, cursor: &Cursor) -> Cursor {
+        if let Some(cursor) = self.cursor_visually_next_in_row(cursor, false) {
+            return cursor;
+        }
+
         if cursor.ccursor.index == 0 {
             Default::default()
         } else {
@@ -1060,7 +1242,14 @@ impl Galley {
         }
     }
 
+    /// Move the cursor one character to the right.
+    ///
+    /// Within rows with right-to-left text, this moves visually to the right, not to the next character.
     pub fn cursor_right_one_character(&self, cursor: &Cursor) -> Cursor {
+        if let Some(cursor) = self.cursor_visually_next_in_row(cursor, true) {
+            return cursor;
+        }
+
         let ccursor = CCursor {
             index: cursor.ccursor.index,
             prefer_next_row: true, // default to this when navigating. It is more often useful to put cursor at the begging of a row than at the end.
@@ -1068,6 +1257,37 @@ impl Galley {
         self.from_ccursor(ccursor + 1)
     }
 
+    /// The closest cursor position to the left or right of `cursor`, within the same row.
+    ///
+    /// Returns `None` if the row has no right-to-left text (so the glyphs are in visual order),
+    /// or if there is no such position.
+    fn cursor_visually_next_in_row(&self, cursor: &Cursor, rightwards: bool) -> Option<Cursor> {
+        let row_nr = cursor.rcursor.row;
+        let row = self.rows.get(row_nr)?;
+        if !row.has_rtl() {
+            return None;
+        }
+
+        let x = row.x_offset(cursor.rcursor.column);
+        let distance = |column: usize| {
+            let dx = row.x_offset(column) - x;
+            if rightwards {
+                dx
+            } else {
+                -dx
+            }
+        };
+
+        let column = (0..=row.char_count_excluding_newline())
+            .filter(|&column| 0.0 < distance(column))
+            .min_by(|&a, &b| distance(a).total_cmp(&distance(b)))?;
+
+        Some(self.from_rcursor(RCursor {
+            row: row_nr,
+            column,
+        }))
+    }
+
     pub fn cursor_up_one_row(&self, cursor: &Cursor) -> Cursor {
         if cursor.rcursor.row == 0 {
             Cursor::default()
@@ -1,6 +1,6 @@
 use emath::{remap_clamp, Rect};
 
-use crate::{FontImage, ImageDelta};
+use crate::{text::GlyphRasterization, FontImage, ImageDelta};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 struct Rectu {
@@ -86,7 +86,8 @@ impl TextureAtlas {
             discs: vec![], // will be filled in below
         };
 
-        // Make the top left pixel fully white for `WHITE_UV`, i.e. painting something with solid color:
+        // Make the top left pixel fully white for `WHITE_UV`, i.e. painting something with solid color.
+        // This is also fully covered when decoded as a signed distance field:
         let (pos, image) = atlas.allocate((1, 1));
         assert_eq!(pos, (0, 0));
         image[pos] = 1.0;
@@ -129,12 +130,33 @@ impl TextureAtlas {
         atlas
     }
 
+    /// How glyphs should be rasterized into this atlas.
+    ///
+    /// Default: [`GlyphRasterization::Coverage`].
+    #[inline]
+    pub fn with_glyph_rasterization(mut self, rasterization: GlyphRasterization) -> Self {
+        self.image.set_rasterization(rasterization);
+        self
+    }
+
+    #[inline]
+    pub fn glyph_rasterization(&self) -> GlyphRasterization {
+        self.image.rasterization()
+    }
+
     pub fn size(&self) -> [usize; 2] {
         self.image.size
     }
 
     /// Returns the locations and sizes of pre-rasterized discs (filled circles) in this atlas.
+    ///
+    /// This is empty with [`GlyphRasterization::SignedDistanceField`],
+    /// since the renderer would decode their anti-aliased edges as distances.
     pub fn prepared_discs(&self) -> Vec<PreparedDisc> {
+        if self.image.is_sdf() {
+            return vec![];
+        }
+
         let size = self.size();
         let inv_w = 1.0 / size[0] as f32;
         let inv_h = 1.0 / size[1] as f32;
@@ -257,6 +279,11 @@ fn resize_to_min_height(image: &mut FontImage, required_height: usize) -> bool {
 
     if image.width() * image.height() > image.pixels.len() {
         image.pixels.resize(image.width() * image.height(), 0.0);
+        if image.is_subpixel() {
+            image
+                .subpixel_coverage
+                .resize(image.width() * image.height(), [0.0; 3]);
+        }
         true
     } else {
         false
@@ -1,4 +1,6 @@
-use crate::{ImageData, ImageDelta, TextureId};
+use std::{collections::VecDeque, sync::Arc};
+
+use crate::{ColorImage, ImageData, ImageDelta, TextureId};
 
 // ----------------------------------------------------------------------------
 
@@ -14,6 +16,25 @@ pub struct TextureManager {
     metas: ahash::HashMap<TextureId, TextureMeta>,
 
     delta: TexturesDelta,
+
+    /// Max number of bytes to upload each frame, see [`Self::set_upload_budget`].
+    upload_budget: Option<usize>,
+
+    /// Images that are too large to upload in one frame, in the order they were set.
+    queued_uploads: VecDeque<QueuedUpload>,
+}
+
+/// An image that is being uploaded a few rows at a time.
+struct QueuedUpload {
+    id: TextureId,
+    image: Arc<ColorImage>,
+    options: TextureOptions,
+
+    /// The rows before this have been uploaded.
+    next_row: usize,
+
+    /// Partial updates to apply once the whole image has been uploaded.
+    deltas_after: Vec<ImageDelta>,
 }
 
 impl TextureManager {
@@ -40,7 +61,7 @@ impl TextureManager {
             options,
         });
 
-        self.delta.set.push((id, ImageDelta::full(image, options)));
+        self.push_delta(id, ImageDelta::full(image, options));
         id
     }
 
@@ -60,8 +81,9 @@ impl TextureManager {
                 meta.bytes_per_pixel = delta.image.bytes_per_pixel();
                 // since we update the whole image, we can discard all old enqueued deltas
                 self.delta.set.retain(|(x, _)| x != &id);
+                self.queued_uploads.retain(|upload| upload.id != id);
             }
-            self.delta.set.push((id, delta));
+            self.push_delta(id, delta);
         } else {
             debug_assert!(false, "Tried setting texture {id:?} which is not allocated");
         }
@@ -74,6 +96,7 @@ impl TextureManager {
             meta.retain_count -= 1;
             if meta.retain_count == 0 {
                 entry.remove();
+                self.queued_uploads.retain(|upload| upload.id != id);
                 self.delta.free.push(id);
             }
         } else {
@@ -95,10 +118,113 @@ impl TextureManager {
         }
     }
 
+    /// Limit how many bytes of image data are uploaded each frame.
+    ///
+    /// Images larger than this are split into chunks of rows that are uploaded over several frames,
+    /// so that loading a huge image doesn't freeze the ui.
+    /// Until it is done, the texture is only partially filled in.
+    /// Use [`Self::upload_progress`] to find out how far along it is.
+    ///
+    /// `None` (the default) means no limit.
+    pub fn set_upload_budget(&mut self, bytes_per_frame: Option<usize>) {
+        self.upload_budget = bytes_per_frame;
+    }
+
+    /// See [`Self::set_upload_budget`].
+    pub fn upload_budget(&self) -> Option<usize> {
+        self.upload_budget
+    }
+
+    /// How much of the texture has been uploaded, in the range `[0, 1)`.
+    ///
+    /// Returns `None` if the texture is not being uploaded in chunks,
+    /// or if it is done.
+    pub fn upload_progress(&self, id: TextureId) -> Option<f32> {
+        self.queued_uploads
+            .iter()
+            .find(|upload| upload.id == id)
+            .map(|upload| upload.next_row as f32 / upload.image.height() as f32)
+    }
+
+    /// Are there images that are being uploaded in chunks?
+    ///
+    /// If so, you need to keep calling [`Self::take_delta`] each frame.
+    pub fn has_queued_uploads(&self) -> bool {
+        !self.queued_uploads.is_empty()
+    }
+
+    fn push_delta(&mut self, id: TextureId, delta: ImageDelta) {
+        if let Some(upload) = self
+            .queued_uploads
+            .iter_mut()
+            .find(|upload| upload.id == id)
+        {
+            // Must wait until the whole image has been uploaded:
+            upload.deltas_after.push(delta);
+            return;
+        }
+
+        if let (None, ImageData::Color(image)) = (delta.pos, &delta.image) {
+            let num_bytes = image.pixels.len() * 4;
+            if self
+                .upload_budget
+                .map_or(false, |budget| budget < num_bytes)
+            {
+                self.queued_uploads.push_back(QueuedUpload {
+                    id,
+                    image: image.clone(),
+                    options: delta.options,
+                    next_row: 0,
+                    deltas_after: vec![],
+                });
+                return;
+            }
+        }
+
+        self.delta.set.push((id, delta));
+    }
+
     /// Take and reset changes since last frame.
     ///
     /// These should be applied to the painting subsystem each frame.
+    ///
+    /// This includes the next chunks of any queued uploads, see [`Self::set_upload_budget`].
     pub fn take_delta(&mut self) -> TexturesDelta {
+        let mut budget = self.upload_budget.unwrap_or(usize::MAX);
+
+        while let Some(upload) = self.queued_uploads.front_mut() {
+            let [width, height] = upload.image.size;
+            let bytes_per_row = 4 * width;
+
+            // Always make some progress, even if the budget is smaller than a row:
+            let num_rows = (budget / bytes_per_row.max(1)).clamp(1, height - upload.next_row);
+            let chunk = upload.image.crop([0, upload.next_row], [width, num_rows]);
+            if upload.next_row == 0 {
+                // Earlier sets of this texture are older than this image, but would be applied after the allocation:
+                self.delta.set.retain(|(id, _)| *id != upload.id);
+                self.delta
+                    .allocate
+                    .push((upload.id, upload.image.size, upload.options));
+            }
+            let delta = ImageDelta::partial([0, upload.next_row], chunk, upload.options);
+            self.delta.set.push((upload.id, delta));
+            upload.next_row += num_rows;
+            budget = budget.saturating_sub(num_rows * bytes_per_row);
+
+            if upload.next_row < height {
+                break; // Out of budget
+            }
+
+            let upload = self.queued_uploads.pop_front().unwrap();
+            for delta in upload.deltas_after {
+                self.push_delta(upload.id, delta);
+            }
+
+            if budget == 0 {
+                break;
+            }
+        }
+
         std::mem::take(&mut self.delta)
     }
 
@@ -252,6 +378,14 @@ pub enum TextureWrapMode {
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[must_use = "The painter must take care of this"]
 pub struct TexturesDelta {
+    /// Color textures to (re)allocate with the given size, leaving their contents uninitialized.
+    /// Apply before [`Self::set`].
+    ///
+    /// They are then filled in by partial updates in [`Self::set`], over one or more frames.
+    /// This is used for large images, see [`TextureManager::set_upload_budget`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub allocate: Vec<(TextureId, [usize; 2], TextureOptions)>,
+
     /// New or changed textures. Apply before painting.
     pub set: Vec<(TextureId, ImageDelta)>,
 
@@ -261,15 +395,24 @@ pub struct TexturesDelta {
 
 impl TexturesDelta {
     pub fn is_empty(&self) -> bool {
-        self.set.is_empty() && self.free.is_empty()
+        self.allocate.is_empty() && self.set.is_empty() && self.free.is_empty()
     }
 
     pub fn append(&mut self, mut newer: Self) {
+        // A new allocation discards what was set before:
+        self.set.retain(|(id, _)| {
+            !newer
+                .allocate
+                .iter()
+                .any(|(allocated_id, _, _)| allocated_id == id)
+        });
+        self.allocate.append(&mut newer.allocate);
         self.set.extend(newer.set);
         self.free.append(&mut newer.free);
     }
 
     pub fn clear(&mut self) {
+        self.allocate.clear();
         self.set.clear();
         self.free.clear();
     }
@@ -280,6 +423,13 @@ impl std::fmt::Debug for TexturesDelta {
         use std::fmt::Write as _;
 
         let mut debug_struct = f.debug_struct("TexturesDelta");
+        if !self.allocate.is_empty() {
+            let mut string = String::new();
+            for (tex_id, size, _) in &self.allocate {
+                write!(string, "{:?} {}x{}, ", tex_id, size[0], size[1]).ok();
+            }
+            debug_struct.field("allocate", &string);
+        }
         if !self.set.is_empty() {
             let mut string = String::new();
             for (tex_id, delta) in &self.set {
@@ -307,3 +457,63 @@ impl std::fmt::Debug for TexturesDelta {
         debug_struct.finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color32;
+
+    #[test]
+    fn test_chunked_upload() {
+        let mut manager = TextureManager::default();
+        let image = ColorImage::new([10, 10], Color32::RED);
+        manager.set_upload_budget(Some(4 * 10 * 4)); // four rows per frame
+        let id = manager.alloc("image".to_owned(), image.into(), TextureOptions::LINEAR);
+        assert_eq!(manager.upload_progress(id), Some(0.0));
+
+        let delta = manager.take_delta();
+        assert_eq!(delta.set.len(), 1);
+        assert_eq!(delta.allocate, vec![(id, [10, 10], TextureOptions::LINEAR)]);
+        assert_eq!(delta.set[0].1.pos, Some([0, 0]));
+        assert_eq!(delta.set[0].1.image.size(), [10, 4]);
+        assert_eq!(manager.upload_progress(id), Some(0.4));
+
+        // Partial updates wait for the upload to finish:
+        let patch = ColorImage::new([1, 1], Color32::BLUE);
+        manager.set(
+            id,
+            ImageDelta::partial([0, 0], patch, TextureOptions::LINEAR),
+        );
+        assert_eq!(manager.take_delta().set.len(), 1);
+
+        let delta = manager.take_delta();
+        assert_eq!(delta.set.len(), 2);
+        assert_eq!(delta.set[0].1.pos, Some([0, 8]));
+        assert_eq!(delta.set[0].1.image.size(), [10, 2]);
+        assert_eq!(delta.set[1].1.image.size(), [1, 1]);
+        assert_eq!(manager.upload_progress(id), None);
+        assert!(!manager.has_queued_uploads());
+    }
+
+    #[test]
+    fn test_chunked_upload_replaces_earlier_set() {
+        let mut manager = TextureManager::default();
+        manager.set_upload_budget(Some(4 * 10 * 4)); // four rows per frame
+        let small = ColorImage::new([2, 2], Color32::RED);
+        let id = manager.alloc("image".to_owned(), small.into(), TextureOptions::LINEAR);
+
+        // In the same frame, replace it with an image too large to upload at once:
+        let large = ColorImage::new([10, 10], Color32::BLUE);
+        manager.set(id, ImageDelta::full(large, TextureOptions::LINEAR));
+
+        let delta = manager.take_delta();
+        assert_eq!(delta.allocate, vec![(id, [10, 10], TextureOptions::LINEAR)]);
+        assert_eq!(
+            delta.set.len(),
+            1,
+            "The small image must not be set after the allocation"
+        );
+        assert_eq!(delta.set[0].1.pos, Some([0, 0]));
+        assert_eq!(delta.set[0].1.image.size(), [10, 4]);
+    }
+}